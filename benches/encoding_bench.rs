@@ -1,7 +1,10 @@
 #![feature(test)]
 
 extern crate test;
-use rust3270::encoding::{Encoding, decode_to_ascii, encode_ascii_to};
+use rust3270::encoding::{
+    Encoding, decode_into, decode_to_ascii, ebcdic_to_latin1, encode_ascii_to, encode_into,
+    latin1_to_ebcdic,
+};
 use test::Bencher;
 
 fn sample_ascii_string() -> String {
@@ -27,3 +30,54 @@ fn bench_decode_ascii_from_cp037(b: &mut Bencher) {
         let _result: Vec<char> = decode_to_ascii(encoded.iter().copied(), &encoding).collect();
     });
 }
+
+#[bench]
+fn bench_encode_into_cp037(b: &mut Bencher) {
+    let input = sample_ascii_string();
+    let mut output = Vec::with_capacity(input.len());
+    b.iter(|| {
+        output.clear();
+        encode_into(&input, Encoding::CP037, &mut output);
+    });
+}
+
+#[bench]
+fn bench_encode_into_cp1140_non_ascii(b: &mut Bencher) {
+    let input: String = "Prix: 12 € à payer, reçu ".chars().cycle().take(10_000).collect();
+    let mut output = Vec::with_capacity(input.len());
+    b.iter(|| {
+        output.clear();
+        encode_into(&input, Encoding::CP1140, &mut output);
+    });
+}
+
+#[bench]
+fn bench_encode_into_cp939_mixed(b: &mut Bencher) {
+    let input: String = "Order 1234 日本語 shipped ".chars().cycle().take(10_000).collect();
+    let mut output = Vec::with_capacity(input.len() * 2);
+    b.iter(|| {
+        output.clear();
+        encode_into(&input, Encoding::CP939, &mut output);
+    });
+}
+
+#[bench]
+fn bench_decode_into_cp037(b: &mut Bencher) {
+    let input = sample_ascii_string();
+    let encoded: Vec<u8> = encode_ascii_to(input.chars(), &Encoding::CP037).collect();
+    let mut output = String::with_capacity(encoded.len());
+    b.iter(|| {
+        output.clear();
+        decode_into(&encoded, Encoding::CP037, &mut output);
+    });
+}
+
+#[bench]
+fn bench_ebcdic_to_latin1_cp037(b: &mut Bencher) {
+    let input = sample_ascii_string();
+    let mut data: Vec<u8> = encode_ascii_to(input.chars(), &Encoding::CP037).collect();
+    b.iter(|| {
+        ebcdic_to_latin1(&mut data, Encoding::CP037);
+        latin1_to_ebcdic(&mut data, Encoding::CP037);
+    });
+}
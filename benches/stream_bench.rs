@@ -0,0 +1,51 @@
+#![feature(test)]
+
+extern crate test;
+use rust3270::server::stream::IncomingRecord;
+use rust3270::server::view::{OrderView, RecordView};
+use test::Bencher;
+
+fn sample_record() -> Vec<u8> {
+    // Read Modified reply of a 43x80 screen with a modified field on every row
+    let mut record = vec![0x7D, 0x40, 0x40];
+    for row in 0..43u16 {
+        let addr = row * 80 + 10;
+        record.extend_from_slice(&[0x11, (addr >> 8) as u8, addr as u8]);
+        record.extend((0xC1u8..=0xC9).cycle().take(60));
+    }
+    record
+}
+
+#[bench]
+fn bench_parse_incoming_record(b: &mut Bencher) {
+    let record = sample_record();
+    b.iter(|| IncomingRecord::parse_record(&record).unwrap());
+}
+
+#[bench]
+fn bench_parse_record_view(b: &mut Bencher) {
+    let record = sample_record();
+    b.iter(|| {
+        let view = RecordView::parse(&record).unwrap();
+        view.orders()
+            .map(|order| match order.unwrap() {
+                OrderView::Text(text) => text.len(),
+                _ => 0,
+            })
+            .sum::<usize>()
+    });
+}
+
+#[bench]
+fn bench_decode_record_view(b: &mut Bencher) {
+    let record = sample_record();
+    b.iter(|| {
+        let view = RecordView::parse(&record).unwrap();
+        view.orders()
+            .filter_map(|order| match order.unwrap() {
+                OrderView::Text(text) => Some(text.chars().filter(|ch| *ch == 'A').count()),
+                _ => None,
+            })
+            .sum::<usize>()
+    });
+}
@@ -0,0 +1,74 @@
+use rust3270::server::async_session::AsyncSession;
+use rust3270::server::extended_field_attributes::ExtendedFieldAttribute;
+use rust3270::server::screen::{Field, Screen};
+use rust3270::server::wcc::FieldAttribute;
+use structopt::StructOpt;
+use tokio::net::{TcpListener, TcpStream};
+
+#[derive(StructOpt)]
+pub struct Cli {
+    #[structopt(short = "h", long = "host", default_value = "::1")]
+    host: String,
+    #[structopt(short = "p", long = "port", default_value = "3270")]
+    port: u16,
+}
+
+async fn run(mut session: AsyncSession<TcpStream>) -> anyhow::Result<()> {
+    let mut name = "        ".to_string();
+    let mut passwd = "        ".to_string();
+
+    let result = Screen {
+        fields: vec![
+            Field::at(1, 32).ro_text("Please enter your data"),
+            Field::at(3, 10).ro_text("Name: "),
+            Field::at(3, 20).rw_text(&mut name),
+            Field::at(4, 10).ro_text("Password: "),
+            Field::at(4, 20)
+                .rw_text(&mut passwd)
+                .with_attr(ExtendedFieldAttribute::FieldAttribute(FieldAttribute::NON_DISPLAY)),
+        ],
+    }
+    .present_async(&mut session)
+    .await?;
+
+    let aid = format!("{:?}", result.aid);
+    Screen {
+        fields: vec![
+            Field::at(1, 32).ro_text("Your data"),
+            Field::at(3, 10).ro_text("Name: "),
+            Field::at(3, 20).ro_text(name.as_str()),
+            Field::at(5, 10).ro_text("You pressed: "),
+            Field::at(5, 25).ro_text(aid.as_str()),
+            Field::at(23, 32).ro_text("Press ENTER to exit"),
+        ],
+    }
+    .present_async(&mut session)
+    .await?;
+
+    Ok(())
+}
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    let options: Cli = Cli::from_args();
+    let server = TcpListener::bind((options.host.as_str(), options.port)).await?;
+
+    println!("Listening on {}:{}", options.host, options.port);
+
+    loop {
+        let (client, _) = server.accept().await?;
+        tokio::spawn(async move {
+            let session = match AsyncSession::new(client).await {
+                Ok(session) => session,
+                Err(err) => {
+                    eprintln!("Error accepting session: {}", err);
+                    return;
+                }
+            };
+
+            if let Err(err) = run(session).await {
+                eprintln!("Error in session: {}", err);
+            }
+        });
+    }
+}
@@ -16,14 +16,14 @@ pub struct Cli {
     port: u16,
 }
 
-static RUSTLOGO: [&'static str; 4] = [
+static RUSTLOGO: [&str; 4] = [
     r#"     _~^~^~_     "#,
     r#" \) /  o o  \ (/ "#,
     r#"   '_   ¬   _'   "#,
     r#"   / '-----' \   "#,
 ];
 
-static INTROLOGORUST: [&'static str; 6] = [
+static INTROLOGORUST: [&str; 6] = [
     r#" _______    _____  _____  _______ _________ "#,
     r#"|_   __ \  |_   _||_   _|/  ___  |  _   _  |"#,
     r#"  | |__) |   | |    | | |  (__ \_|_/ | | \_|"#,
@@ -32,7 +32,7 @@ static INTROLOGORUST: [&'static str; 6] = [
     r#"|____| |___|   \.__.'   |_______.' |_____|  "#,
 ];
 
-static INTROLOGO3270: [&'static str; 6] = [
+static INTROLOGO3270: [&str; 6] = [
     r#"  ______     _____    _______     ____   "#,
     r#" / ____ \.  / ___ \. |  ___  |  .'    '. "#,
     r#" \'  __) | |_/___) | |_/  / /  |  .--.  |"#,
@@ -172,28 +172,18 @@ fn run(mut session: rust3270::server::Session) -> anyhow::Result<()> {
 
 fn main() -> anyhow::Result<()> {
     let options: Cli = Cli::from_args();
-    let server = std::net::TcpListener::bind((options.host.as_str(), options.port))?;
+    let server = rust3270::server::Server::bind(
+        (options.host.as_str(), options.port),
+        rust3270::server::ServerConfig::default(),
+    )?;
 
     println!("Listening on {}:{}", options.host, options.port);
 
-    for client in server.incoming() {
-        let client = client?;
-        std::thread::spawn(move || {
-            let session = match rust3270::server::Session::new(client) {
-                Ok(session) => {
-                    eprintln!("New session established.");
-                    session
-                }
-                Err(err) => {
-                    eprintln!("Error accepting session: {}", err);
-                    return;
-                }
-            };
-
-            if let Err(err) = run(session) {
-                eprintln!("Error in session: {}", err);
-            }
-        });
-    }
+    server.run(|session, context: &rust3270::server::SessionContext| {
+        eprintln!("New session established with {}.", context.peer_addr());
+        if let Err(err) = run(session) {
+            eprintln!("Error in session: {}", err);
+        }
+    })?;
     Ok(())
 }
@@ -0,0 +1,14 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use rust3270::server::buffer::BufferSnapshot;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(snapshot) = BufferSnapshot::parse(data) {
+        let _ = snapshot.text();
+        for field in snapshot.fields.iter() {
+            let _ = snapshot.field_contents(field);
+            let _ = snapshot.field_at(field.addr);
+        }
+    }
+});
@@ -0,0 +1,18 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use rust3270::server::stream::{AddressMode, IncomingRecord, StreamOptions};
+
+fuzz_target!(|data: &[u8]| {
+    let sixteen = StreamOptions { mode: AddressMode::SixteenBit, ..StreamOptions::default() };
+    let _ = IncomingRecord::parse_record(data);
+    if let Ok(record) = IncomingRecord::parse_record_with(data, sixteen) {
+        // Whatever was parsed can be written again and reads back the same
+        let mut output = vec![];
+        record.serialize_with(&mut output, sixteen);
+        let reparsed = IncomingRecord::parse_record_with(&output, sixteen).unwrap();
+        let mut again = vec![];
+        reparsed.serialize_with(&mut again, sixteen);
+        assert_eq!(output, again);
+    }
+});
@@ -0,0 +1,10 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use rust3270::server::stream::WriteCommand;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(command) = WriteCommand::parse(data) {
+        let _ = Vec::<u8>::from(&command);
+    }
+});
@@ -1,24 +1,452 @@
+pub(crate) mod bulk;
 pub(crate) mod cp037;
+pub(crate) mod cp1047;
+pub(crate) mod cp1140;
+pub(crate) mod cp1141;
+pub(crate) mod cp1142;
+pub(crate) mod cp1143;
+pub(crate) mod cp1144;
+pub(crate) mod cp1145;
+pub(crate) mod cp1146;
+pub(crate) mod cp1147;
+pub(crate) mod cp1148;
+pub(crate) mod cp1149;
+pub(crate) mod cp273;
+pub(crate) mod cp277;
+pub(crate) mod cp278;
+pub(crate) mod cp280;
+pub(crate) mod cp284;
+pub(crate) mod cp285;
+pub(crate) mod cp297;
+pub(crate) mod cp300;
+pub(crate) mod cp500;
+pub(crate) mod cp834;
+pub(crate) mod cp835;
+pub(crate) mod cp837;
+pub(crate) mod cp871;
+pub(crate) mod cp930;
+pub(crate) mod cp933;
+pub(crate) mod cp935;
+pub(crate) mod cp937;
+pub(crate) mod cp939;
+mod custom;
+pub(crate) mod dbcs;
+mod unicode;
+
+pub use crate::encoding::bulk::{decode_into, ebcdic_to_latin1, encode_into, latin1_to_ebcdic};
+pub use crate::encoding::custom::{CodePage, CodePageError};
+use crate::encoding::dbcs::{
+    Code, DbcsTable, REPLACEMENT, encode_sbcs, latin1_tables, sbcs_encode_table,
+};
+pub use crate::encoding::dbcs::{DBCS_SPACE, SI, SO};
+pub use crate::encoding::unicode::{EncodeError, Encoded, Encoder, SUB, Substitute, decode};
+
+// The double byte halves of the mixed code pages, by CCSID
+static CP300: DbcsTable = DbcsTable::new(&cp300::DBCS_TBL, &[]);
+static CP834: DbcsTable = DbcsTable::new(&cp834::DBCS_TBL, &[]);
+static CP835: DbcsTable = DbcsTable::new(&cp835::DBCS_TBL, &cp835::DECODE_ONLY);
+static CP837: DbcsTable = DbcsTable::new(&cp837::DBCS_TBL, &[]);
+
+const CP930_LATIN1: ([u8; 256], [u8; 256]) = latin1_tables(&cp930::SBCS_TBL);
+const CP933_LATIN1: ([u8; 256], [u8; 256]) = latin1_tables(&cp933::SBCS_TBL);
+const CP935_LATIN1: ([u8; 256], [u8; 256]) = latin1_tables(&cp935::SBCS_TBL);
+const CP937_LATIN1: ([u8; 256], [u8; 256]) = latin1_tables(&cp937::SBCS_TBL);
+const CP939_LATIN1: ([u8; 256], [u8; 256]) = latin1_tables(&cp939::SBCS_TBL);
+const CP930_SBCS_ENCODE: [(u16, u8); 256] = sbcs_encode_table(&cp930::SBCS_TBL);
+const CP933_SBCS_ENCODE: [(u16, u8); 256] = sbcs_encode_table(&cp933::SBCS_TBL);
+const CP935_SBCS_ENCODE: [(u16, u8); 256] = sbcs_encode_table(&cp935::SBCS_TBL);
+const CP937_SBCS_ENCODE: [(u16, u8); 256] = sbcs_encode_table(&cp937::SBCS_TBL);
+const CP939_SBCS_ENCODE: [(u16, u8); 256] = sbcs_encode_table(&cp939::SBCS_TBL);
+
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub enum Encoding {
+    // USA, Canada, Netherlands, Portugal, Brazil
+    #[default]
     CP037,
+    // Germany, Austria
+    CP273,
+    // Denmark, Norway
+    CP277,
+    // Finland, Sweden
+    CP278,
+    // Italy
+    CP280,
+    // Spain, Latin America
+    CP284,
+    // United Kingdom
+    CP285,
+    // France
+    CP297,
+    // International Latin-1
+    CP500,
+    // Iceland
+    CP871,
+    // Latin-1 as used by z/OS Unix and C
+    CP1047,
+    // The euro pages replace the currency sign ¤ of the page with the same country by €
+    CP1140,
+    CP1141,
+    CP1142,
+    CP1143,
+    CP1144,
+    CP1145,
+    CP1146,
+    CP1147,
+    CP1148,
+    CP1149,
+    // Mixed code pages, single byte text with double byte runs between SO and SI
+    // Japan, Katakana in the single byte half
+    CP930,
+    // Korea
+    CP933,
+    // Simplified Chinese
+    CP935,
+    // Traditional Chinese
+    CP937,
+    // Japan, Latin in the single byte half
+    CP939,
+    // Loaded at runtime, see `CodePage`
+    Custom(&'static CodePage),
 }
 
 impl Encoding {
+    // The single byte code pages
+    pub const ALL: [Encoding; 21] = [
+        Encoding::CP037,
+        Encoding::CP273,
+        Encoding::CP277,
+        Encoding::CP278,
+        Encoding::CP280,
+        Encoding::CP284,
+        Encoding::CP285,
+        Encoding::CP297,
+        Encoding::CP500,
+        Encoding::CP871,
+        Encoding::CP1047,
+        Encoding::CP1140,
+        Encoding::CP1141,
+        Encoding::CP1142,
+        Encoding::CP1143,
+        Encoding::CP1144,
+        Encoding::CP1145,
+        Encoding::CP1146,
+        Encoding::CP1147,
+        Encoding::CP1148,
+        Encoding::CP1149,
+    ];
+
+    pub const MIXED: [Encoding; 5] =
+        [Encoding::CP930, Encoding::CP933, Encoding::CP935, Encoding::CP937, Encoding::CP939];
+
+    // The byte tables only hold Latin-1, so the euro pages have ¤ where the euro sign is and the
+    // mixed pages only have their Latin-1 characters. See `encode_char` and `decode_char` for the
+    // real mapping.
     pub fn encode_table(&self) -> &'static [u8; 256] {
         match self {
-            Encoding::CP037 => &crate::encoding::cp037::ENCODE_TBL,
-            // Add more encodings here
+            Encoding::CP037 => &cp037::ENCODE_TBL,
+            Encoding::CP273 => &cp273::ENCODE_TBL,
+            Encoding::CP277 => &cp277::ENCODE_TBL,
+            Encoding::CP278 => &cp278::ENCODE_TBL,
+            Encoding::CP280 => &cp280::ENCODE_TBL,
+            Encoding::CP284 => &cp284::ENCODE_TBL,
+            Encoding::CP285 => &cp285::ENCODE_TBL,
+            Encoding::CP297 => &cp297::ENCODE_TBL,
+            Encoding::CP500 => &cp500::ENCODE_TBL,
+            Encoding::CP871 => &cp871::ENCODE_TBL,
+            Encoding::CP1047 => &cp1047::ENCODE_TBL,
+            Encoding::CP1140 => &cp1140::ENCODE_TBL,
+            Encoding::CP1141 => &cp1141::ENCODE_TBL,
+            Encoding::CP1142 => &cp1142::ENCODE_TBL,
+            Encoding::CP1143 => &cp1143::ENCODE_TBL,
+            Encoding::CP1144 => &cp1144::ENCODE_TBL,
+            Encoding::CP1145 => &cp1145::ENCODE_TBL,
+            Encoding::CP1146 => &cp1146::ENCODE_TBL,
+            Encoding::CP1147 => &cp1147::ENCODE_TBL,
+            Encoding::CP1148 => &cp1148::ENCODE_TBL,
+            Encoding::CP1149 => &cp1149::ENCODE_TBL,
+            Encoding::CP930 => &CP930_LATIN1.0,
+            Encoding::CP933 => &CP933_LATIN1.0,
+            Encoding::CP935 => &CP935_LATIN1.0,
+            Encoding::CP937 => &CP937_LATIN1.0,
+            Encoding::CP939 => &CP939_LATIN1.0,
+            Encoding::Custom(page) => &page.latin1_tables().0,
         }
     }
+
     pub fn decode_table(&self) -> &'static [u8; 256] {
         match self {
-            Encoding::CP037 => &crate::encoding::cp037::DECODE_TBL,
-            // Add more encodings here
+            Encoding::CP037 => &cp037::DECODE_TBL,
+            Encoding::CP273 => &cp273::DECODE_TBL,
+            Encoding::CP277 => &cp277::DECODE_TBL,
+            Encoding::CP278 => &cp278::DECODE_TBL,
+            Encoding::CP280 => &cp280::DECODE_TBL,
+            Encoding::CP284 => &cp284::DECODE_TBL,
+            Encoding::CP285 => &cp285::DECODE_TBL,
+            Encoding::CP297 => &cp297::DECODE_TBL,
+            Encoding::CP500 => &cp500::DECODE_TBL,
+            Encoding::CP871 => &cp871::DECODE_TBL,
+            Encoding::CP1047 => &cp1047::DECODE_TBL,
+            Encoding::CP1140 => &cp1140::DECODE_TBL,
+            Encoding::CP1141 => &cp1141::DECODE_TBL,
+            Encoding::CP1142 => &cp1142::DECODE_TBL,
+            Encoding::CP1143 => &cp1143::DECODE_TBL,
+            Encoding::CP1144 => &cp1144::DECODE_TBL,
+            Encoding::CP1145 => &cp1145::DECODE_TBL,
+            Encoding::CP1146 => &cp1146::DECODE_TBL,
+            Encoding::CP1147 => &cp1147::DECODE_TBL,
+            Encoding::CP1148 => &cp1148::DECODE_TBL,
+            Encoding::CP1149 => &cp1149::DECODE_TBL,
+            Encoding::CP930 => &CP930_LATIN1.1,
+            Encoding::CP933 => &CP933_LATIN1.1,
+            Encoding::CP935 => &CP935_LATIN1.1,
+            Encoding::CP937 => &CP937_LATIN1.1,
+            Encoding::CP939 => &CP939_LATIN1.1,
+            Encoding::Custom(page) => &page.latin1_tables().1,
+        }
+    }
+
+    // The single byte half of a mixed code page, with U+FFFD for bytes without a character
+    fn sbcs_table(&self) -> Option<&'static [u16; 256]> {
+        match self {
+            Encoding::CP930 => Some(&cp930::SBCS_TBL),
+            Encoding::CP933 => Some(&cp933::SBCS_TBL),
+            Encoding::CP935 => Some(&cp935::SBCS_TBL),
+            Encoding::CP937 => Some(&cp937::SBCS_TBL),
+            Encoding::CP939 => Some(&cp939::SBCS_TBL),
+            _ => None,
+        }
+    }
+
+    fn sbcs_encode_table(&self) -> Option<&'static [(u16, u8); 256]> {
+        match self {
+            Encoding::CP930 => Some(&CP930_SBCS_ENCODE),
+            Encoding::CP933 => Some(&CP933_SBCS_ENCODE),
+            Encoding::CP935 => Some(&CP935_SBCS_ENCODE),
+            Encoding::CP937 => Some(&CP937_SBCS_ENCODE),
+            Encoding::CP939 => Some(&CP939_SBCS_ENCODE),
+            _ => None,
+        }
+    }
+
+    fn dbcs_table(&self) -> Option<&'static DbcsTable> {
+        match self {
+            Encoding::CP930 | Encoding::CP939 => Some(&CP300),
+            Encoding::CP933 => Some(&CP834),
+            Encoding::CP935 => Some(&CP837),
+            Encoding::CP937 => Some(&CP835),
+            Encoding::Custom(page) => page.dbcs_table(),
+            _ => None,
+        }
+    }
+
+    pub fn is_dbcs(&self) -> bool {
+        self.dbcs_table().is_some()
+    }
+
+    // The position of the euro sign, only in the euro pages
+    pub fn euro(&self) -> Option<u8> {
+        match self {
+            Encoding::CP1142 | Encoding::CP1143 => Some(0x5A),
+            Encoding::CP1140
+            | Encoding::CP1141
+            | Encoding::CP1144
+            | Encoding::CP1145
+            | Encoding::CP1146
+            | Encoding::CP1147
+            | Encoding::CP1148
+            | Encoding::CP1149 => Some(0x9F),
+            _ => None,
+        }
+    }
+
+    // The Unicode character of a byte
+    pub fn decode_char(&self, byte: u8) -> char {
+        if let Encoding::Custom(page) = self {
+            return page.decode_char(byte);
+        }
+        if let Some(sbcs) = self.sbcs_table() {
+            return char::from_u32(sbcs[byte as usize].into()).unwrap_or(REPLACEMENT);
+        }
+        match self.euro() {
+            Some(euro) if euro == byte => '€',
+            _ => self.decode_table()[byte as usize] as char,
         }
     }
+
+    // The byte of a Unicode character, if the code page has it
+    pub fn encode_char(&self, ch: char) -> Option<u8> {
+        if let Encoding::Custom(page) = self {
+            return page.encode_char(ch);
+        }
+        if let Some(table) = self.sbcs_encode_table() {
+            let ch = u16::try_from(u32::from(ch)).ok().filter(|&ch| ch != 0xFFFD)?;
+            return encode_sbcs(table, ch);
+        }
+        match ch {
+            '€' => self.euro(),
+            '¤' if self.euro().is_some() => None,
+            _ => {
+                let index = u8::try_from(u32::from(ch)).ok()?;
+                Some(self.encode_table()[index as usize])
+            }
+        }
+    }
+
+    // The character of a double byte code, only in the mixed pages
+    pub fn decode_dbcs(&self, code: u16) -> Option<char> {
+        self.dbcs_table()?.decode(code)
+    }
+
+    pub fn encode_dbcs(&self, ch: char) -> Option<u16> {
+        self.dbcs_table()?.encode(ch)
+    }
+
+    // Single byte codes are preferred, mixed pages have a few characters in both halves
+    pub(crate) fn encode_code(&self, ch: char) -> Option<Code> {
+        match self.encode_char(ch).filter(|&byte| byte >= 0x40) {
+            Some(byte) => Some(Code::Single(byte)),
+            None => self.encode_dbcs(ch).map(Code::Double),
+        }
+    }
+
+    // The number of buffer positions the text takes on the terminal. Double byte characters take
+    // two and every double byte run one more each for SO and SI.
+    pub fn cells(&self, text: &str) -> usize {
+        if !self.is_dbcs() {
+            return text.chars().count();
+        }
+        let mut output = Cells(0);
+        let mut shifter = dbcs::Shifter::new(false);
+        for ch in text.chars() {
+            shifter.push(self.encode_code(ch).unwrap_or(Code::Single(0x40)), &mut output);
+        }
+        shifter.finish(&mut output);
+        output.0
+    }
+
+    // The CCSID, which is also the code page ID reported in a CGCSGID
+    pub fn ccsid(&self) -> u16 {
+        match self {
+            Encoding::CP037 => 37,
+            Encoding::CP273 => 273,
+            Encoding::CP277 => 277,
+            Encoding::CP278 => 278,
+            Encoding::CP280 => 280,
+            Encoding::CP284 => 284,
+            Encoding::CP285 => 285,
+            Encoding::CP297 => 297,
+            Encoding::CP500 => 500,
+            Encoding::CP871 => 871,
+            Encoding::CP1047 => 1047,
+            Encoding::CP1140 => 1140,
+            Encoding::CP1141 => 1141,
+            Encoding::CP1142 => 1142,
+            Encoding::CP1143 => 1143,
+            Encoding::CP1144 => 1144,
+            Encoding::CP1145 => 1145,
+            Encoding::CP1146 => 1146,
+            Encoding::CP1147 => 1147,
+            Encoding::CP1148 => 1148,
+            Encoding::CP1149 => 1149,
+            Encoding::CP930 => 930,
+            Encoding::CP933 => 933,
+            Encoding::CP935 => 935,
+            Encoding::CP937 => 937,
+            Encoding::CP939 => 939,
+            Encoding::Custom(page) => page.ccsid,
+        }
+    }
+
+    // Registered pages come first, see `CodePage::register`
+    pub fn from_ccsid(ccsid: u16) -> Option<Self> {
+        Self::known().find(|encoding| encoding.ccsid() == ccsid)
+    }
+
+    fn known() -> impl Iterator<Item = Encoding> {
+        custom::registered().into_iter().chain(Self::ALL).chain(Self::MIXED)
+    }
+
+    // The code pages of the single and double byte halves, as a terminal reports them in the
+    // CGCSGIDs of its Character Sets query reply
+    pub fn code_pages(&self) -> (u16, Option<u16>) {
+        match self {
+            Encoding::CP930 => (290, Some(300)),
+            Encoding::CP933 => (833, Some(834)),
+            Encoding::CP935 => (836, Some(837)),
+            Encoding::CP937 => (37, Some(835)),
+            Encoding::CP939 => (1027, Some(300)),
+            Encoding::Custom(page) => page.code_pages,
+            encoding => (encoding.ccsid(), None),
+        }
+    }
+
+    pub fn from_code_pages(sbcs: u16, dbcs: Option<u16>) -> Option<Self> {
+        Self::known().find(|encoding| encoding.code_pages() == (sbcs, dbcs))
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Encoding::CP037 => "CP037",
+            Encoding::CP273 => "CP273",
+            Encoding::CP277 => "CP277",
+            Encoding::CP278 => "CP278",
+            Encoding::CP280 => "CP280",
+            Encoding::CP284 => "CP284",
+            Encoding::CP285 => "CP285",
+            Encoding::CP297 => "CP297",
+            Encoding::CP500 => "CP500",
+            Encoding::CP871 => "CP871",
+            Encoding::CP1047 => "CP1047",
+            Encoding::CP1140 => "CP1140",
+            Encoding::CP1141 => "CP1141",
+            Encoding::CP1142 => "CP1142",
+            Encoding::CP1143 => "CP1143",
+            Encoding::CP1144 => "CP1144",
+            Encoding::CP1145 => "CP1145",
+            Encoding::CP1146 => "CP1146",
+            Encoding::CP1147 => "CP1147",
+            Encoding::CP1148 => "CP1148",
+            Encoding::CP1149 => "CP1149",
+            Encoding::CP930 => "CP930",
+            Encoding::CP933 => "CP933",
+            Encoding::CP935 => "CP935",
+            Encoding::CP937 => "CP937",
+            Encoding::CP939 => "CP939",
+            Encoding::Custom(page) => &page.name,
+        }
+    }
+
+    // Accepts the usual spellings of a code page, e.g. `CP037`, `cp37`, `IBM-1047`, `CCSID 273`
+    // or just the number. Registered pages are also found by the name in their mapping file.
+    pub fn from_name(name: &str) -> Option<Self> {
+        let name = name.trim();
+        if let Some(encoding) = custom::registered()
+            .into_iter()
+            .find(|encoding| encoding.name().eq_ignore_ascii_case(name))
+        {
+            return Some(encoding);
+        }
+        let name = name.to_ascii_uppercase();
+        let number = ["CCSID", "IBM", "CP"]
+            .iter()
+            .find_map(|prefix| name.strip_prefix(prefix))
+            .unwrap_or(&name)
+            .trim_start_matches(['-', '_', ' ']);
+        Self::from_ccsid(number.parse().ok()?)
+    }
+}
+
+// Counts bytes instead of keeping them
+struct Cells(usize);
+
+impl Extend<u8> for Cells {
+    fn extend<T: IntoIterator<Item = u8>>(&mut self, iter: T) {
+        self.0 += iter.into_iter().count();
+    }
 }
 
-// Generic function to encode from ASCII to target encoding.
+// Generic function to encode from ASCII to target encoding. Unmappable characters silently become
+// spaces, see `Encoder` to substitute or detect them.
 pub fn encode_ascii_to(
     stream: impl Iterator<Item = char>,
     encoding: &Encoding,
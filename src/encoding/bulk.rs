@@ -0,0 +1,104 @@
+use crate::encoding::Encoding;
+use crate::encoding::dbcs::{decode_mixed, encode_mixed};
+
+// Latin-1 SUB, which the byte tables also hold for bytes without a Latin-1 character
+const LATIN1_SUB: u8 = 0x1A;
+
+// Appends the text encoded for the data stream. Characters the code page doesn't have and those
+// that map to the control codes below 0x40 become spaces, like in `WriteOrder::SendText`. Runs of
+// ASCII go straight through the byte table.
+pub fn encode_into(text: &str, encoding: Encoding, output: &mut Vec<u8>) {
+    output.reserve(text.len());
+    encode_to(text, encoding, false, output);
+}
+
+// Like `encode_into`, for outputs other than a Vec. Text of mixed code pages is double byte only
+// in DBCS fields.
+pub(crate) fn encode_to(
+    text: &str,
+    encoding: Encoding,
+    dbcs_field: bool,
+    output: &mut impl Extend<u8>,
+) {
+    if encoding.is_dbcs() {
+        return encode_mixed_to(text, encoding, dbcs_field, output);
+    }
+    // ASCII is the same in the byte table and `encode_char` for every single byte page
+    let table = encoding.encode_table();
+    let mut rest = text;
+    while !rest.is_empty() {
+        let ascii = rest.bytes().position(|v| !v.is_ascii()).unwrap_or(rest.len());
+        output.extend(rest.as_bytes()[..ascii].iter().map(|&v| match table[v as usize] {
+            code if code < 0x40 => 0x40,
+            code => code,
+        }));
+        rest = &rest[ascii..];
+        let Some(ch) = rest.chars().next() else {
+            break;
+        };
+        output.extend([encoding.encode_char(ch).filter(|&v| v >= 0x40).unwrap_or(0x40)]);
+        rest = &rest[ch.len_utf8()..];
+    }
+}
+
+// Outside DBCS fields, runs of ASCII that the single byte half has are written unshifted straight
+// through the byte table. Only the text between them goes through `encode_mixed`.
+fn encode_mixed_to(text: &str, encoding: Encoding, dbcs_field: bool, output: &mut impl Extend<u8>) {
+    if dbcs_field {
+        return encode_mixed(text, encoding, true, output);
+    }
+    let table = encoding.encode_table();
+    let single = |v: u8| v.is_ascii() && table[v as usize] >= 0x40;
+    let mut rest = text;
+    while !rest.is_empty() {
+        let ascii = rest.bytes().position(|v| !single(v)).unwrap_or(rest.len());
+        output.extend(rest.as_bytes()[..ascii].iter().map(|&v| table[v as usize]));
+        rest = &rest[ascii..];
+        // Ends before an ASCII byte, so always on a character boundary
+        let other = rest.bytes().position(single).unwrap_or(rest.len());
+        encode_mixed(&rest[..other], encoding, false, output);
+        rest = &rest[other..];
+    }
+}
+
+// Appends the decoded data, see `decode`. Chunks that are all ASCII in the byte table are copied
+// from it, the others go through `decode_char`.
+pub fn decode_into(data: &[u8], encoding: Encoding, output: &mut String) {
+    if encoding.is_dbcs() {
+        return output.extend(decode_mixed(data, encoding, false));
+    }
+    let table = encoding.decode_table();
+    output.reserve(data.len());
+    let mut ascii = [0; 64];
+    for chunk in data.chunks(ascii.len()) {
+        let ascii = &mut ascii[..chunk.len()];
+        let mut plain = true;
+        for (v, &byte) in ascii.iter_mut().zip(chunk) {
+            *v = table[byte as usize];
+            // SUB also stands for bytes the table has no Latin-1 character for
+            plain &= v.is_ascii() && *v != LATIN1_SUB;
+        }
+        match std::str::from_utf8(ascii) {
+            Ok(text) if plain => output.push_str(text),
+            _ => output.extend(chunk.iter().map(|&byte| encoding.decode_char(byte))),
+        }
+    }
+}
+
+// Translates EBCDIC to Latin-1 in place with `Encoding::decode_table`, so bytes without a Latin-1
+// character become SUB and the euro sign the currency sign
+pub fn ebcdic_to_latin1(data: &mut [u8], encoding: Encoding) {
+    let table = encoding.decode_table();
+    for byte in data {
+        *byte = table[*byte as usize];
+    }
+}
+
+// Translates Latin-1 to EBCDIC in place with `Encoding::encode_table`. Unlike `encode_into`, the
+// control codes are kept.
+pub fn latin1_to_ebcdic(data: &mut [u8], encoding: Encoding) {
+    let table = encoding.encode_table();
+    for byte in data {
+        *byte = table[*byte as usize];
+    }
+}
@@ -0,0 +1,1458 @@
+#[allow(unused)]
+pub static DBCS_TBL: [u32; 11635] = [
+    0x40403000, 0x414103b1, 0x414203b2, 0x414303b3, 0x414403b4, 0x414503b5, 0x414603b6, 0x414703b7,
+    0x414803b8, 0x414903b9, 0x414a03ba, 0x414b03bb, 0x414c03bc, 0x414d03bd, 0x414e03be, 0x414f03bf,
+    0x415003c0, 0x415103c1, 0x415203c3, 0x415303c4, 0x415403c5, 0x415503c6, 0x415603c7, 0x415703c8,
+    0x415803c9, 0x41610391, 0x41620392, 0x41630393, 0x41640394, 0x41650395, 0x41660396, 0x41670397,
+    0x41680398, 0x41690399, 0x416a039a, 0x416b039b, 0x416c039c, 0x416d039d, 0x416e039e, 0x416f039f,
+    0x417003a0, 0x417103a1, 0x417203a3, 0x417303a4, 0x417403a5, 0x417503a6, 0x417603a7, 0x417703a8,
+    0x417803a9, 0x41800430, 0x41810431, 0x41820432, 0x41830433, 0x41840434, 0x41850435, 0x41860451,
+    0x41870436, 0x41880437, 0x41890438, 0x418a0439, 0x418b043a, 0x418c043b, 0x418d043c, 0x418e043d,
+    0x418f043e, 0x4190043f, 0x41910440, 0x41920441, 0x41930442, 0x41940443, 0x41950444, 0x41960445,
+    0x41970446, 0x41980447, 0x41990448, 0x419a0449, 0x419b044a, 0x419c044b, 0x419d044c, 0x419e044d,
+    0x419f044e, 0x41a0044f, 0x41b12170, 0x41b22171, 0x41b32172, 0x41b42173, 0x41b52174, 0x41b62175,
+    0x41b72176, 0x41b82177, 0x41b92178, 0x41ba2179, 0x41c00410, 0x41c10411, 0x41c20412, 0x41c30413,
+    0x41c40414, 0x41c50415, 0x41c60401, 0x41c70416, 0x41c80417, 0x41c90418, 0x41ca0419, 0x41cb041a,
+    0x41cc041b, 0x41cd041c, 0x41ce041d, 0x41cf041e, 0x41d0041f, 0x41d10420, 0x41d20421, 0x41d30422,
+    0x41d40423, 0x41d50424, 0x41d60425, 0x41d70426, 0x41d80427, 0x41d90428, 0x41da0429, 0x41db042a,
+    0x41dc042b, 0x41dd042c, 0x41de042d, 0x41df042e, 0x41e0042f, 0x41f12160, 0x41f22161, 0x41f32162,
+    0x41f42163, 0x41f52164, 0x41f62165, 0x41f72166, 0x41f82167, 0x41f92168, 0x41fa2169, 0x424affe1,
+    0x424bff0e, 0x424cff1c, 0x424dff08, 0x424eff0b, 0x424fff5c, 0x4250ff06, 0x425aff01, 0x425bffe5,
+    0x425cff0a, 0x425dff09, 0x425eff1b, 0x425fffe2, 0x42602212, 0x4261ff0f, 0x426a00a6, 0x426bff0c,
+    0x426cff05, 0x426dff3f, 0x426eff1e, 0x426fff1f, 0x4279ff40, 0x427aff1a, 0x427bff03, 0x427cff20,
+    0x427dff07, 0x427eff1d, 0x427fff02, 0x4281ff41, 0x4282ff42, 0x4283ff43, 0x4284ff44, 0x4285ff45,
+    0x4286ff46, 0x4287ff47, 0x4288ff48, 0x4289ff49, 0x4291ff4a, 0x4292ff4b, 0x4293ff4c, 0x4294ff4d,
+    0x4295ff4e, 0x4296ff4f, 0x4297ff50, 0x4298ff51, 0x4299ff52, 0x42a1ffe3, 0x42a2ff53, 0x42a3ff54,
+    0x42a4ff55, 0x42a5ff56, 0x42a6ff57, 0x42a7ff58, 0x42a8ff59, 0x42a9ff5a, 0x42c0ff5b, 0x42c1ff21,
+    0x42c2ff22, 0x42c3ff23, 0x42c4ff24, 0x42c5ff25, 0x42c6ff26, 0x42c7ff27, 0x42c8ff28, 0x42c9ff29,
+    0x42d0ff5d, 0x42d1ff2a, 0x42d2ff2b, 0x42d3ff2c, 0x42d4ff2d, 0x42d5ff2e, 0x42d6ff2f, 0x42d7ff30,
+    0x42d8ff31, 0x42d9ff32, 0x42e0ff04, 0x42e2ff33, 0x42e3ff34, 0x42e4ff35, 0x42e5ff36, 0x42e6ff37,
+    0x42e7ff38, 0x42e8ff39, 0x42e9ff3a, 0x42f0ff10, 0x42f1ff11, 0x42f2ff12, 0x42f3ff13, 0x42f4ff14,
+    0x42f5ff15, 0x42f6ff16, 0x42f7ff17, 0x42f8ff18, 0x42f9ff19, 0x43413002, 0x4342300c, 0x4343300d,
+    0x43443001, 0x434530fb, 0x434630f2, 0x434730a1, 0x434830a3, 0x434930a5, 0x434affe0, 0x434b2220,
+    0x434c22a5, 0x434d2312, 0x434e2202, 0x434f2207, 0x435130a7, 0x435230a9, 0x435330e3, 0x435430e5,
+    0x435530e7, 0x435630c3, 0x435730ee, 0x435830fc, 0x435930f5, 0x435a30f6, 0x435b2261, 0x435c2252,
+    0x435d226a, 0x435e226b, 0x435f221a, 0x4360223d, 0x4361221d, 0x4362222b, 0x4363222c, 0x43642208,
+    0x4365220b, 0x43662286, 0x43672287, 0x43682282, 0x43692283, 0x436a222a, 0x436b2229, 0x436c2227,
+    0x436d2228, 0x436e21d2, 0x436f21d4, 0x43702200, 0x43712203, 0x4372212b, 0x43732030, 0x4374266f,
+    0x4375266d, 0x4376266a, 0x43772020, 0x43782021, 0x437900b6, 0x437a25ef, 0x437c2500, 0x437d2502,
+    0x437e250c, 0x437f2510, 0x438130a2, 0x438230a4, 0x438330a6, 0x438430a8, 0x438530aa, 0x438630ab,
+    0x438730ad, 0x438830af, 0x438930b1, 0x438a30b3, 0x438c30b5, 0x438d30b7, 0x438e30b9, 0x438f30bb,
+    0x439030bd, 0x439130bf, 0x439230c1, 0x439330c4, 0x439430c6, 0x439530c8, 0x439630ca, 0x439730cb,
+    0x439830cc, 0x439930cd, 0x439a30ce, 0x439d30cf, 0x439e30d2, 0x439f30d5, 0x43a1301c, 0x43a230d8,
+    0x43a330db, 0x43a430de, 0x43a530df, 0x43a630e0, 0x43a730e1, 0x43a830e2, 0x43a930e4, 0x43aa30e6,
+    0x43ac30e8, 0x43ad30e9, 0x43ae30ea, 0x43af30eb, 0x43b02518, 0x43b12514, 0x43b2251c, 0x43b3252c,
+    0x43b42524, 0x43b52534, 0x43b6253c, 0x43b72501, 0x43b82503, 0x43b9250f, 0x43ba30ec, 0x43bb30ed,
+    0x43bc30ef, 0x43bd30f3, 0x43be309b, 0x43bf309c, 0x43c030ac, 0x43c130ae, 0x43c230b0, 0x43c330b2,
+    0x43c430b4, 0x43c530b6, 0x43c630b8, 0x43c730ba, 0x43c830bc, 0x43c930be, 0x43ca30c0, 0x43cb30c2,
+    0x43cc30c5, 0x43cd30c7, 0x43ce30c9, 0x43cf30d0, 0x43d030d3, 0x43d130d6, 0x43d230d9, 0x43d330dc,
+    0x43d430f4, 0x43d530d1, 0x43d630d4, 0x43d730d7, 0x43d830da, 0x43d930dd, 0x43da30f0, 0x43db30f1,
+    0x43dc30fd, 0x43dd30fe, 0x43e0ff3c, 0x43e12513, 0x43e2251b, 0x43e32517, 0x43e42523, 0x43e52533,
+    0x43e6252b, 0x43e7253b, 0x43e8254b, 0x43e92520, 0x43ea252f, 0x43eb2528, 0x43ec2537, 0x43ed253f,
+    0x43ee251d, 0x43ef2530, 0x43f02525, 0x43f12538, 0x43f22542, 0x4442300e, 0x4443300f, 0x4444ff3b,
+    0x4445ff3d, 0x44463092, 0x44473041, 0x44483043, 0x44493045, 0x444a2014, 0x444b00b1, 0x444c2260,
+    0x444d221e, 0x444e2103, 0x445000b4, 0x44513047, 0x44523049, 0x44533083, 0x44543085, 0x44553087,
+    0x44563063, 0x4457308e, 0x445a2010, 0x445b3003, 0x445c4edd, 0x445d3005, 0x445e3006, 0x445f3007,
+    0x446000a8, 0x44612018, 0x4462201c, 0x44633014, 0x44643008, 0x4465300a, 0x44663010, 0x44672266,
+    0x44682234, 0x44692642, 0x446a00a7, 0x446b203b, 0x446c3012, 0x446d3231, 0x446e2116, 0x446f2121,
+    0x4470ff3e, 0x44712019, 0x4472201d, 0x44733015, 0x44743009, 0x4475300b, 0x44763011, 0x44772267,
+    0x44782235, 0x44792640, 0x447a00d7, 0x447b00f7, 0x447c2016, 0x447d3013, 0x447e2025, 0x447f2026,
+    0x44813042, 0x44823044, 0x44833046, 0x44843048, 0x4485304a, 0x4486304b, 0x4487304d, 0x4488304f,
+    0x44893051, 0x448a3053, 0x448c3055, 0x448d3057, 0x448e3059, 0x448f305b, 0x4490305d, 0x4491305f,
+    0x44923061, 0x44933064, 0x44943066, 0x44953068, 0x4496306a, 0x4497306b, 0x4498306c, 0x4499306d,
+    0x449a306e, 0x449d306f, 0x449e3072, 0x449f3075, 0x44a23078, 0x44a3307b, 0x44a4307e, 0x44a5307f,
+    0x44a63080, 0x44a73081, 0x44a83082, 0x44a93084, 0x44aa3086, 0x44ac3088, 0x44ad3089, 0x44ae308a,
+    0x44af308b, 0x44ba308c, 0x44bb308d, 0x44bc308f, 0x44bd3093, 0x44c0304c, 0x44c1304e, 0x44c23050,
+    0x44c33052, 0x44c43054, 0x44c53056, 0x44c63058, 0x44c7305a, 0x44c8305c, 0x44c9305e, 0x44ca3060,
+    0x44cb3062, 0x44cc3065, 0x44cd3067, 0x44ce3069, 0x44cf3070, 0x44d03073, 0x44d13076, 0x44d23079,
+    0x44d3307c, 0x44d53071, 0x44d63074, 0x44d73077, 0x44d8307a, 0x44d9307d, 0x44da3090, 0x44db3091,
+    0x44dc309d, 0x44dd309e, 0x44e025cb, 0x44e125cf, 0x44e225b3, 0x44e325b2, 0x44e425ce, 0x44e52606,
+    0x44e62605, 0x44e725c7, 0x44e825c6, 0x44e925a1, 0x44ea25a0, 0x44eb25bd, 0x44ec25bc, 0x44ed00b0,
+    0x44ee2032, 0x44ef2033, 0x44f02192, 0x44f12190, 0x44f22191, 0x44f32193, 0x45414e00, 0x45424e8c,
+    0x45434e09, 0x454456db, 0x45454e94, 0x4546516d, 0x45474e03, 0x4548516b, 0x45494e5d, 0x454a5341,
+    0x454b767e, 0x454c5343, 0x454d4e07, 0x454e5104, 0x454f90fd, 0x45509053, 0x45515e9c, 0x4552770c,
+    0x45535e02, 0x4554533a, 0x4555753a, 0x45566751, 0x45576771, 0x4558897f, 0x45595357, 0x455a5317,
+    0x455b5927, 0x455c4e2d, 0x455d5c0f, 0x455e4e0a, 0x455f4e0b, 0x45605e74, 0x45616708, 0x456265e5,
+    0x45637530, 0x45645b50, 0x45655c71, 0x4566672c, 0x45675ddd, 0x456885e4, 0x456991ce, 0x456a5de5,
+    0x456b696d, 0x456c6728, 0x456d4e95, 0x456e90ce, 0x456f5cf6, 0x457096c4, 0x45719ad8, 0x45725ca1,
+    0x4573592b, 0x4574539f, 0x45754eac, 0x45764f50, 0x45776b63, 0x4578677e, 0x45796a5f, 0x457a548c,
+    0x457b88fd, 0x457c7537, 0x457d7f8e, 0x457e5409, 0x457f5d0e, 0x458077f3, 0x45818c37, 0x458296fb,
+    0x45839577, 0x45846cbb, 0x45856ca2, 0x458691d1, 0x458765b0, 0x458853e3, 0x45896a4b, 0x458a4e45,
+    0x458b798f, 0x458c6240, 0x458d5e73, 0x458e5185, 0x458f56fd, 0x45905316, 0x4591962a, 0x45925bae,
+    0x45934eba, 0x45944f5c, 0x459590e8, 0x45966e05, 0x45976b21, 0x45987fa9, 0x4599751f, 0x459a4ee3,
+    0x459b51fa, 0x459c6c34, 0x459d68ee, 0x459e5149, 0x459f52a0, 0x45a05408, 0x45a1795e, 0x45a26797,
+    0x45a391cd, 0x45a4884c, 0x45a54fe1, 0x45a6660e, 0x45a76d77, 0x45a85b89, 0x45a95e78, 0x45aa4fdd,
+    0x45ab592a, 0x45ac5bcc, 0x45ad6c5f, 0x45ae9234, 0x45af524d, 0x45b077e5, 0x45b16b66, 0x45b24f0a,
+    0x45b3662d, 0x45b45206, 0x45b552dd, 0x45b67528, 0x45b75e83, 0x45b89020, 0x45b96c17, 0x45ba6210,
+    0x45bb898b, 0x45bc5229, 0x45bd4f1a, 0x45be5b66, 0x45bf5ca9, 0x45c07523, 0x45c19593, 0x45c25730,
+    0x45c381ea, 0x45c4826f, 0x45c595a2, 0x45c6611b, 0x45c7653f, 0x45c85c3e, 0x45c98a08, 0x45ca6587,
+    0x45cb624b, 0x45cc7236, 0x45cd65b9, 0x45ce4e8b, 0x45cf6238, 0x45d054c1, 0x45d1559c, 0x45d26e21,
+    0x45d35f18, 0x45d453e4, 0x45d58fba, 0x45d65009, 0x45d79244, 0x45d84e4b, 0x45d95834, 0x45da6d0b,
+    0x45db57ce, 0x45dc6d25, 0x45dd7acb, 0x45de5ea6, 0x45df5348, 0x45e04eca, 0x45e15f66, 0x45e28a2d,
+    0x45e3901a, 0x45e452d5, 0x45e55f8c, 0x45e65948, 0x45e75b9a, 0x45e86c60, 0x45e95c4b, 0x45ea6d5c,
+    0x45eb7406, 0x45ec5742, 0x45ed5b9f, 0x45ee82f1, 0x45ef7684, 0x45f053f8, 0x45f179c0, 0x45f26a2a,
+    0x45f3540d, 0x45f45b5d, 0x45f57af9, 0x45f6535a, 0x45f7529b, 0x45f85eab, 0x45f98449, 0x45fa6804,
+    0x45fb6c38, 0x45fc5668, 0x45fd7389, 0x45fe591a, 0x46418cc0, 0x4642771f, 0x46436075, 0x46449759,
+    0x46455186, 0x46468302, 0x4647654f, 0x46488c4a, 0x46495175, 0x464a6cd5, 0x464b767a, 0x464c9752,
+    0x464d5897, 0x464e6599, 0x464f5fe0, 0x46508cc7, 0x46516642, 0x46527269, 0x46538eca, 0x46545fb3,
+    0x46558981, 0x46565bfe, 0x4657585a, 0x465879cb, 0x4659767d, 0x465a6cb3, 0x465b702c, 0x465c6cb9,
+    0x465d9686, 0x465e8535, 0x465f5f53, 0x46604fca, 0x46615fd7, 0x46626625, 0x4663793e, 0x466499ac,
+    0x46655165, 0x46665efa, 0x46676839, 0x46686749, 0x46699032, 0x466a8208, 0x466b6d66, 0x466c7cbe,
+    0x466d540c, 0x466e6027, 0x466f7c73, 0x46708005, 0x467152a9, 0x4672679d, 0x46738fd1, 0x467476f4,
+    0x467576ee, 0x46766765, 0x4677753b, 0x467876f8, 0x46799ed2, 0x467a4e38, 0x467b8239, 0x467c7531,
+    0x467d58eb, 0x467e7b2c, 0x467f718a, 0x46807d19, 0x46815065, 0x468268b0, 0x468382b3, 0x4684571f,
+    0x46856709, 0x46865bb6, 0x46877dda, 0x46887d4c, 0x46898abf, 0x468a5929, 0x468b671f, 0x468c7f6e,
+    0x468d6d45, 0x468e6589, 0x468f5f0f, 0x46905f62, 0x46919762, 0x46927a2e, 0x46938f38, 0x46945916,
+    0x46955143, 0x46964f53, 0x46979e7f, 0x46985fa1, 0x46995973, 0x469a5eb7, 0x469b4e16, 0x469c52c7,
+    0x469d5800, 0x469e597d, 0x469f5150, 0x46a05bfa, 0x46a192fc, 0x46a27279, 0x46a357fc, 0x46a49054,
+    0x46a55411, 0x46a653d6, 0x46a77b49, 0x46a8667a, 0x46a956de, 0x46aa9580, 0x46ab904b, 0x46ac5099,
+    0x46ad601d, 0x46ae963f, 0x46af4e0d, 0x46b09808, 0x46b15168, 0x46b25bff, 0x46b35584, 0x46b4677f,
+    0x46b598ef, 0x46b68c9e, 0x46b773fe, 0x46b898df, 0x46b97d44, 0x46ba985e, 0x46bb516c, 0x46bc6750,
+    0x46bd9999, 0x46be5546, 0x46bf7d50, 0x46c08868, 0x46c177e2, 0x46c26f5f, 0x46c379c1, 0x46c45236,
+    0x46c590a6, 0x46c66cbc, 0x46c77cf8, 0x46c85b8f, 0x46c97b56, 0x46ca6ce2, 0x46cb54e1, 0x46cc6570,
+    0x46cd958b, 0x46ce6e96, 0x46cf6a39, 0x46d08cbb, 0x46d1660c, 0x46d25f37, 0x46d37814, 0x46d453cb,
+    0x46d55b87, 0x46d682e5, 0x46d783ca, 0x46d86301, 0x46d982b1, 0x46da5f15, 0x46db7d00, 0x46dc8352,
+    0x46dd5225, 0x46de4fee, 0x46df8d8a, 0x46e04f4f, 0x46e185ac, 0x46e26bdb, 0x46e39060, 0x46e4554f,
+    0x46e55965, 0x46e6578b, 0x46e75fc3, 0x46e8767b, 0x46e965e9, 0x46ea67f3, 0x46eb6d69, 0x46ec8cea,
+    0x46ed52d9, 0x46ee6cc9, 0x46ef5e38, 0x46f05b88, 0x46f157fa, 0x46f27ba1, 0x46f36cf0, 0x46f44f38,
+    0x46f56700, 0x46f64ee5, 0x46f76b4c, 0x46f888d5, 0x46f98d64, 0x46fa8db3, 0x46fb898f, 0x46fc6d41,
+    0x46fd8aa0, 0x46fe6607, 0x47415dde, 0x47427167, 0x47435869, 0x47449001, 0x474596c5, 0x4746672b,
+    0x474754f2, 0x47485cb8, 0x47494e5f, 0x474a5c90, 0x474b521d, 0x474c8328, 0x474d5247, 0x474e6bd4,
+    0x474f80fd, 0x47508a71, 0x47516295, 0x47528ee2, 0x475383c5, 0x47549023, 0x47554ed6, 0x47566c11,
+    0x47577d66, 0x47589152, 0x47597e41, 0x475a4fa1, 0x475b6e80, 0x475c671d, 0x475d4ed8, 0x475e6761,
+    0x475f7121, 0x47608003, 0x4761697d, 0x47624e3b, 0x4763610f, 0x47646226, 0x47655207, 0x47665264,
+    0x47677247, 0x47687d30, 0x47696e08, 0x476a7a32, 0x476b5e03, 0x476c91cc, 0x476d5c5e, 0x476e7ae0,
+    0x476f5909, 0x47704f55, 0x4771685c, 0x47725f7c, 0x477367fb, 0x477476ca, 0x477558f2, 0x47764ec1,
+    0x47776df1, 0x477853f0, 0x47799ce5, 0x477a9db4, 0x477b652f, 0x477c6574, 0x477d89d2, 0x477e5609,
+    0x477f5473, 0x4780885b, 0x47818b70, 0x47825727, 0x47837387, 0x47848def, 0x4785706b, 0x4786961c,
+    0x47878f1d, 0x478870b9, 0x47894e0e, 0x478a6e1b, 0x478b7551, 0x478c9280, 0x478d7a7a, 0x478e4ea4,
+    0x478f7fbd, 0x4790534a, 0x479153ce, 0x4792592e, 0x47937dcf, 0x47948a18, 0x47956674, 0x479669cb,
+    0x4797969b, 0x47986885, 0x47995370, 0x479a8a00, 0x479b6817, 0x479c8eab, 0x479d66f8, 0x479e514b,
+    0x479f7d20, 0x47a096c6, 0x47a17bc0, 0x47a25148, 0x47a36edd, 0x47a46c7a, 0x47a56559, 0x47a67d14,
+    0x47a767f4, 0x47a863a5, 0x47a9661f, 0x47aa7740, 0x47ab7559, 0x47ac6620, 0x47ad5df1, 0x47ae754c,
+    0x47af5177, 0x47b0656c, 0x47b17fa4, 0x47b29806, 0x47b35171, 0x47b46d3b, 0x47b591cf, 0x47b66307,
+    0x47b789e3, 0x47b85ba4, 0x47b9679c, 0x47ba5404, 0x47bb671b, 0x47bc9632, 0x47bd7d04, 0x47be61b2,
+    0x47bf967d, 0x47c04e80, 0x47c156f3, 0x47c24e88, 0x47c38272, 0x47c47a0e, 0x47c5690d, 0x47c653ef,
+    0x47c76052, 0x47c84f4d, 0x47c95178, 0x47ca5fc5, 0x47cb7d9a, 0x47cc6025, 0x47cd5728, 0x47ce57a3,
+    0x47cf541b, 0x47d05ef6, 0x47d15d8b, 0x47d24f01, 0x47d36803, 0x47d4670d, 0x47d571b1, 0x47d65272,
+    0x47d75354, 0x47d86b69, 0x47d953f2, 0x47da512a, 0x47db658e, 0x47dc623f, 0x47dd5b97, 0x47de683c,
+    0x47df8fb0, 0x47e07b20, 0x47e15712, 0x47e28af8, 0x47e38107, 0x47e45553, 0x47e58ce2, 0x47e65f25,
+    0x47e798a8, 0x47e85f97, 0x47e96613, 0x47ea6253, 0x47eb982d, 0x47ec65ed, 0x47ed6bb5, 0x47ee52e2,
+    0x47ef7136, 0x47f056e3, 0x47f1984d, 0x47f2843d, 0x47f3914d, 0x47f47a0b, 0x47f58fbb, 0x47f6543e,
+    0x47f7611f, 0x47f85bdb, 0x47f953cd, 0x47fa7a14, 0x47fb9700, 0x47fc6e90, 0x47fd6c96, 0x47fe984c,
+    0x48418fbc, 0x48428349, 0x48437b97, 0x484476db, 0x48458fb2, 0x484690a3, 0x48477701, 0x484869d8,
+    0x48496bbf, 0x484a5c11, 0x484b4ecb, 0x484c53d7, 0x484d97f3, 0x484e7de8, 0x484f59d4, 0x48505e84,
+    0x48514fc2, 0x485272b6, 0x4853793a, 0x48545e97, 0x48555a9b, 0x4856682a, 0x48576ecb, 0x485868a8,
+    0x48597e04, 0x485a53f3, 0x485b5de6, 0x485c53ca, 0x485d9078, 0x485e5c45, 0x485f60c5, 0x48607df4,
+    0x486170ad, 0x48629928, 0x48639271, 0x48646a21, 0x48656b8a, 0x48667e3e, 0x48674e9c, 0x48687e4a,
+    0x48694ef2, 0x486a5857, 0x486b6d88, 0x486c8853, 0x486d691c, 0x486e6717, 0x486f5b85, 0x4870529f,
+    0x48715c1a, 0x48728cbf, 0x487360a6, 0x48748102, 0x48757be0, 0x48764f73, 0x48777d21, 0x487851a8,
+    0x48796851, 0x487a78ba, 0x487b7267, 0x487c4e26, 0x487d5024, 0x487e89b3, 0x487f8cb4, 0x48807dad,
+    0x48817d71, 0x48825bbf, 0x48834e21, 0x48847cd6, 0x488589aa, 0x48869332, 0x48876f84, 0x488865bd,
+    0x48895bb9, 0x488a98db, 0x488b5c40, 0x488c7950, 0x488d904e, 0x488e6c0f, 0x488f6539, 0x489076e4,
+    0x48917a4d, 0x48926e0b, 0x48935dfb, 0x48946df3, 0x48955fdc, 0x48964e89, 0x48978ecd, 0x489888c5,
+    0x48999178, 0x489a7e54, 0x489b67d3, 0x489c5e1d, 0x489d7dbf, 0x489e7c89, 0x489f822a, 0x48a07532,
+    0x48a15468, 0x48a24ed9, 0x48a35f85, 0x48a44f4e, 0x48a57dd1, 0x48a68efd, 0x48a79ebb, 0x48a86176,
+    0x48a952b4, 0x48aa78ef, 0x48ab4e39, 0x48ac80b2, 0x48ad9650, 0x48ae5c0e, 0x48af653e, 0x48b06643,
+    0x48b15ea7, 0x48b24ef6, 0x48b360f3, 0x48b49a13, 0x48b54ed5, 0x48b64f7f, 0x48b78f2a, 0x48b89854,
+    0x48b9756a, 0x48ba5f35, 0x48bb805e, 0x48bc4f9b, 0x48bd6e6f, 0x48be6eb6, 0x48bf6821, 0x48c09285,
+    0x48c192f3, 0x48c2878d, 0x48c39756, 0x48c45199, 0x48c55b8c, 0x48c66e2f, 0x48c7935b, 0x48c8591c,
+    0x48c95145, 0x48ca9f8d, 0x48cb7db1, 0x48cc83f1, 0x48cd901f, 0x48ce52c9, 0x48cf5237, 0x48d08d77,
+    0x48d16469, 0x48d253c2, 0x48d355b6, 0x48d47a42, 0x48d563a8, 0x48d68fd4, 0x48d78077, 0x48d86b62,
+    0x48d94f1d, 0x48da5e79, 0x48db7403, 0x48dc6a29, 0x48dd5c55, 0x48de5e61, 0x48df845b, 0x48e05ead,
+    0x48e1975e, 0x48e253f7, 0x48e35358, 0x48e46b73, 0x48e562e1, 0x48e651e6, 0x48e78a9e, 0x48e86628,
+    0x48e957df, 0x48ea6df5, 0x48eb518d, 0x48ec50cd, 0x48ed79d1, 0x48ee9b5a, 0x48ef7aef, 0x48f09014,
+    0x48f16848, 0x48f25b57, 0x48f38ad6, 0x48f4517c, 0x48f553c8, 0x48f6632f, 0x48f76280, 0x48f85fb9,
+    0x48f9672d, 0x48fa7cfb, 0x48fb5f93, 0x48fc51b7, 0x48fd614b, 0x48fe5cf0, 0x49415931, 0x4942539a,
+    0x49435074, 0x49446ce8, 0x49456e2c, 0x49469803, 0x49474e57, 0x49488a66, 0x4949576a, 0x494a8429,
+    0x494b515a, 0x494c6c7d, 0x494d5b9d, 0x494e606d, 0x494f6a0b, 0x49506e29, 0x49516577, 0x49528aac,
+    0x495382b8, 0x4954544a, 0x49556b74, 0x4956822c, 0x495798fe, 0x4958793c, 0x49595c06, 0x495a96e3,
+    0x495b7802, 0x495c5224, 0x495d5f79, 0x495e5f71, 0x495f66fd, 0x49605e2f, 0x49619678, 0x4962938c,
+    0x49638ac7, 0x49645f70, 0x496560aa, 0x49666a19, 0x49677533, 0x49685bb3, 0x49696bcd, 0x496a88dc,
+    0x496b5e4c, 0x496c58f0, 0x496d9664, 0x496e7b39, 0x496f5a66, 0x49704e7e, 0x49717af6, 0x4972829d,
+    0x4973725b, 0x49748cb7, 0x497579fb, 0x4976785d, 0x49778336, 0x497852b9, 0x4979990a, 0x497a52f2,
+    0x497b80a5, 0x497c8b19, 0x497d7089, 0x497e590f, 0x497f5802, 0x498067cf, 0x49816255, 0x49825e30,
+    0x4983713c, 0x4984786b, 0x49858001, 0x49867a76, 0x49875be9, 0x498891dd, 0x498965ad, 0x498a5c04,
+    0x498b5dee, 0x498c5d50, 0x498d6298, 0x498e8010, 0x498f5ba3, 0x499059cb, 0x49915f8b, 0x49926b8b,
+    0x4993666f, 0x49948c61, 0x499590f7, 0x49965353, 0x499796e2, 0x499885ab, 0x49996b7b, 0x499a8015,
+    0x499b64cd, 0x499c4eae, 0x499d4e91, 0x499e90e1, 0x499f52e4, 0x49a06c42, 0x49a18cab, 0x49a25b98,
+    0x49a359bb, 0x49a488cf, 0x49a5773c, 0x49a64f2f, 0x49a77aaf, 0x49a87bc9, 0x49a9968e, 0x49aa63db,
+    0x49ab6842, 0x49ac99c5, 0x49ad68b6, 0x49ae5747, 0x49af8ca1, 0x49b0547d, 0x49b1738b, 0x49b284b2,
+    0x49b390c1, 0x49b478e8, 0x49b57b11, 0x49b666f2, 0x49b76975, 0x49b85831, 0x49b963d0, 0x49ba8a3c,
+    0x49bb96ea, 0x49bc9055, 0x49bd88c1, 0x49be9996, 0x49bf75c5, 0x49c06850, 0x49c14f59, 0x49c274e6,
+    0x49c34ee4, 0x49c45439, 0x49c5732a, 0x49c6672a, 0x49c7525b, 0x49c88ca0, 0x49c94f34, 0x49ca5100,
+    0x49cb542b, 0x49cc9069, 0x49cd8fc4, 0x49ce5c3b, 0x49cf5dcc, 0x49d07b54, 0x49d18ffd, 0x49d28a0e,
+    0x49d34e08, 0x49d4925b, 0x49d571c3, 0x49d68ab2, 0x49d770ba, 0x49d89662, 0x49d9679a, 0x49da76ae,
+    0x49db8b77, 0x49dc7dbe, 0x49dd96e8, 0x49de6211, 0x49df5bc4, 0x49e0837b, 0x49e162bc, 0x49e27d0d,
+    0x49e376e3, 0x49e47e2b, 0x49e5964d, 0x49e6572d, 0x49e77adc, 0x49e87bc4, 0x49e96bba, 0x49ea8c9d,
+    0x49eb698e, 0x49ec9047, 0x49ed6f14, 0x49ee5360, 0x49ef8feb, 0x49f05287, 0x49f1624d, 0x49f26566,
+    0x49f37d1a, 0x49f47d42, 0x49f56bce, 0x49f67d79, 0x49f77e2e, 0x49f8666e, 0x49f97965, 0x49fa500b,
+    0x49fb5c02, 0x49fc99d2, 0x49fd8a55, 0x49fe7560, 0x4a415b58, 0x4a428089, 0x4a4350be, 0x4a445e2b,
+    0x4a456db2, 0x4a464f8b, 0x4a4781e3, 0x4a4881f3, 0x4a4956e0, 0x4a4a7d99, 0x4a4b5df2, 0x4a4c899a,
+    0x4a4d6e9d, 0x4a4e6d17, 0x4a4f8aad, 0x4a508996, 0x4a51731b, 0x4a525de8, 0x4a537db2, 0x4a54888b,
+    0x4a554efb, 0x4a565bc6, 0x4a578896, 0x4a586cc1, 0x4a598457, 0x4a5a8f03, 0x4a5b6bc5, 0x4a5c97ff,
+    0x4a5d8ca9, 0x4a5e5e45, 0x4a5f82e6, 0x4a6063aa, 0x4a615f81, 0x4a6278c1, 0x4a63821e, 0x4a6452aa,
+    0x4a657aaa, 0x4a665999, 0x4a676297, 0x4a688f14, 0x4a697fd2, 0x4a6a4fc3, 0x4a6b54c9, 0x4a6c967a,
+    0x4a6d66f4, 0x4a6e8b1b, 0x4a6f5e72, 0x4a705fa9, 0x4a718a2a, 0x4a726d3e, 0x4a737763, 0x4a746483,
+    0x4a758b58, 0x4a76614e, 0x4a775a5a, 0x4a788d85, 0x4a7971d0, 0x4a7a983c, 0x4a7b72e9, 0x4a7c583a,
+    0x4a7d5dfe, 0x4a7e8a8d, 0x4a7f67c4, 0x4a807de0, 0x4a814f11, 0x4a8277ed, 0x4a834f0f, 0x4a845bc5,
+    0x4a85629c, 0x4a865c3c, 0x4a87533b, 0x4a886dc0, 0x4a8981fc, 0x4a8a96d1, 0x4a8b904a, 0x4a8c6d6e,
+    0x4a8d93e1, 0x4a8e5c64, 0x4a8f98fc, 0x4a90524a, 0x4a916dfb, 0x4a928584, 0x4a93968a, 0x4a9456fa,
+    0x4a955883, 0x4a967766, 0x4a979805, 0x4a984e73, 0x4a998c46, 0x4a9a8a31, 0x4a9b7dd2, 0x4a9c8ff0,
+    0x4a9d6d6a, 0x4a9e4f9d, 0x4a9f6b6f, 0x4aa06b27, 0x4aa162c5, 0x4aa2511f, 0x4aa39769, 0x4aa45374,
+    0x4aa59aa8, 0x4aa66775, 0x4aa7887f, 0x4aa85305, 0x4aa97570, 0x4aaa8d70, 0x4aab864e, 0x4aac5cef,
+    0x4aad8cde, 0x4aae5ff5, 0x4aaf725f, 0x4ab07686, 0x4ab1609f, 0x4ab280cc, 0x4ab359eb, 0x4ab48131,
+    0x4ab55e0c, 0x4ab68a17, 0x4ab79676, 0x4ab882d7, 0x4ab974b0, 0x4aba84b8, 0x4abb50d5, 0x4abc96f2,
+    0x4abd7248, 0x4abe7834, 0x4abf6dd1, 0x4ac06e09, 0x4ac167ff, 0x4ac26f54, 0x4ac35915, 0x4ac4500d,
+    0x4ac572ac, 0x4ac69ec4, 0x4ac77b46, 0x4ac89b3c, 0x4ac96563, 0x4aca53bb, 0x4acb8a98, 0x4acc91dc,
+    0x4acd9818, 0x4ace6fc3, 0x4acf65c5, 0x4ad0501f, 0x4ad17f8a, 0x4ad26f64, 0x4ad39031, 0x4ad45f3e,
+    0x4ad563f4, 0x4ad69038, 0x4ad78b66, 0x4ad87be4, 0x4ad97206, 0x4ada6843, 0x4adb72ec, 0x4adc65cf,
+    0x4add82a6, 0x4ade5ba2, 0x4adf6960, 0x4ae09ea6, 0x4ae152df, 0x4ae26790, 0x4ae3639b, 0x4ae47d75,
+    0x4ae59855, 0x4ae65df3, 0x4ae75805, 0x4ae88acb, 0x4ae995a3, 0x4aea8863, 0x4aeb8ca8, 0x4aec5b63,
+    0x4aed5e8a, 0x4aee5449, 0x4aef786c, 0x4af07d2b, 0x4af18ca2, 0x4af25352, 0x4af37d76, 0x4af48cb8,
+    0x4af57070, 0x4af6547c, 0x4af76545, 0x4af86676, 0x4af973b2, 0x4afa56f2, 0x4afb7bb1, 0x4afc58a8,
+    0x4afd7a81, 0x4afe66ae, 0x4b418087, 0x4b4259ff, 0x4b438840, 0x4b4456f0, 0x4b457b51, 0x4b466df7,
+    0x4b475f01, 0x4b48934b, 0x4b499000, 0x4b4a4fe3, 0x4b4b675f, 0x4b4c4fbf, 0x4b4d8cc3, 0x4b4e526f,
+    0x4b4f63a1, 0x4b505442, 0x4b518907, 0x4b52698a, 0x4b535e2d, 0x4b545a18, 0x4b557518, 0x4b56514d,
+    0x4b575e7e, 0x4b5850b5, 0x4b595bdd, 0x4b5a68d2, 0x4b5b745e, 0x4b5c69fb, 0x4b5d5fae, 0x4b5e55e3,
+    0x4b5f8a70, 0x4b605bf8, 0x4b615824, 0x4b628358, 0x4b635f13, 0x4b645e95, 0x4b65706f, 0x4b66751a,
+    0x4b677d05, 0x4b6860e3, 0x4b697e70, 0x4b6a5012, 0x4b6b5238, 0x4b6c83ef, 0x4b6d5373, 0x4b6e5f31,
+    0x4b6f6a2b, 0x4b709cf4, 0x4b7153cc, 0x4b726d32, 0x4b734eab, 0x4b744e92, 0x4b75842c, 0x4b768a8c,
+    0x4b7765e2, 0x4b786f01, 0x4b7980a9, 0x4b7a9df9, 0x4b7b8b72, 0x4b7c7b52, 0x4b7d9589, 0x4b7e6d74,
+    0x4b7f63a2, 0x4b806590, 0x4b815bd2, 0x4b826319, 0x4b838ab0, 0x4b8476df, 0x4b8599a8, 0x4b867a74,
+    0x4b878236, 0x4b888846, 0x4b898061, 0x4b8a6557, 0x4b8b5922, 0x4b8c9644, 0x4b8d88ab, 0x4b8e9326,
+    0x4b8f7b4b, 0x4b9062b5, 0x4b915371, 0x4b925e81, 0x4b935bdf, 0x4b944f75, 0x4b9558c1, 0x4b967058,
+    0x4b977dca, 0x4b985438, 0x4b9973e0, 0x4b9a52d8, 0x4b9b5208, 0x4b9c78d0, 0x4b9d6b23, 0x4b9e6838,
+    0x4b9f4e43, 0x4ba0690e, 0x4ba18377, 0x4ba26ed1, 0x4ba398f2, 0x4ba48170, 0x4ba58857, 0x4ba68ef8,
+    0x4ba7798e, 0x4ba883dc, 0x4ba98fce, 0x4baa7e01, 0x4bab5510, 0x4bac4ea8, 0x4bad8a33, 0x4bae9162,
+    0x4baf5efb, 0x4bb0606f, 0x4bb14e86, 0x4bb2664b, 0x4bb36368, 0x4bb45217, 0x4bb58056, 0x4bb651fd,
+    0x4bb77642, 0x4bb8821f, 0x4bb99685, 0x4bba50cf, 0x4bbb662f, 0x4bbc4f3c, 0x4bbd4e59, 0x4bbe6a3d,
+    0x4bbf4e71, 0x4bc0523a, 0x4bc18acf, 0x4bc26a58, 0x4bc366ff, 0x4bc4670b, 0x4bc5653b, 0x4bc69732,
+    0x4bc75ec3, 0x4bc88a13, 0x4bc95782, 0x4bca604b, 0x4bcb866b, 0x4bcc95d8, 0x4bcd60a9, 0x4bce4e01,
+    0x4bcf63cf, 0x4bd06fc0, 0x4bd1659c, 0x4bd28cac, 0x4bd38305, 0x4bd47ca7, 0x4bd56050, 0x4bd696f7,
+    0x4bd75fcd, 0x4bd8640d, 0x4bd95b54, 0x4bda900f, 0x4bdb62d3, 0x4bdc59b9, 0x4bdd7159, 0x4bde51ac,
+    0x4bdf79f0, 0x4be0552f, 0x4be15275, 0x4be26697, 0x4be380f8, 0x4be44e98, 0x4be54ecf, 0x4be651cd,
+    0x4be79d5c, 0x4be85144, 0x4be97a93, 0x4bea67f1, 0x4beb5841, 0x4bec7c21, 0x4bed8861, 0x4bee5c31,
+    0x4bef68da, 0x4bf091e7, 0x4bf19df2, 0x4bf263ee, 0x4bf36575, 0x4bf484ee, 0x4bf5523b, 0x4bf66b32,
+    0x4bf77c98, 0x4bf85982, 0x4bf9969c, 0x4bfa8987, 0x4bfb7c9f, 0x4bfc9006, 0x4bfd62db, 0x4bfe66dc,
+    0x4c416355, 0x4c426982, 0x4c4350ac, 0x4c44623b, 0x4c455fd8, 0x4c4663da, 0x4c4775db, 0x4c48627f,
+    0x4c49616e, 0x4c4a8266, 0x4c4b7c95, 0x4c4c716e, 0x4c4d96c7, 0x4c4e7f6a, 0x4c4f5426, 0x4c505200,
+    0x4c5183d3, 0x4c525211, 0x4c53594f, 0x4c549d28, 0x4c55574a, 0x4c5666c7, 0x4c579858, 0x4c58820e,
+    0x4c596614, 0x4c5a733f, 0x4c5b50b7, 0x4c5c6551, 0x4c5d5eb8, 0x4c5e5b6b, 0x4c5f55ac, 0x4c605feb,
+    0x4c616388, 0x4c628caf, 0x4c63676f, 0x4c645951, 0x4c655a01, 0x4c6671e5, 0x4c675de3, 0x4c688c6a,
+    0x4c696271, 0x4c6a81f4, 0x4c6b5c3a, 0x4c6c5f92, 0x4c6d9045, 0x4c6e7384, 0x4c6f7149, 0x4c7079d8,
+    0x4c71796d, 0x4c729003, 0x4c7383cc, 0x4c745fb4, 0x4c755b8d, 0x4c766279, 0x4c7764ae, 0x4c787d18,
+    0x4c79723e, 0x4c7a5bee, 0x4c7b65e7, 0x4c7c8d08, 0x4c7d9e78, 0x4c7e52e7, 0x4c7f5d07, 0x4c809f62,
+    0x4c816069, 0x4c82536f, 0x4c836681, 0x4c849663, 0x4c855e3d, 0x4c8662b1, 0x4c87722a, 0x4c886e4a,
+    0x4c8993ae, 0x4c8a79e6, 0x4c8b53e5, 0x4c8c809d, 0x4c8d88fe, 0x4c8e53b3, 0x4c8f6c88, 0x4c906e7f,
+    0x4c915141, 0x4c929091, 0x4c936f6e, 0x4c9484c4, 0x4c9585ea, 0x4c968129, 0x4c976bd2, 0x4c98663c,
+    0x4c997f72, 0x4c9a73c2, 0x4c9b5f1f, 0x4c9c790e, 0x4c9d60b2, 0x4c9e72ed, 0x4c9f58ee, 0x4ca08179,
+    0x4ca18e8d, 0x4ca25c65, 0x4ca35de7, 0x4ca46c37, 0x4ca56de1, 0x4ca6862d, 0x4ca772af, 0x4ca88e0a,
+    0x4ca97c92, 0x4caa8218, 0x4cab8033, 0x4cac63a7, 0x4cad9291, 0x4cae5019, 0x4caf8155, 0x4cb08a69,
+    0x4cb18edf, 0x4cb266b4, 0x4cb38133, 0x4cb47591, 0x4cb56b20, 0x4cb66669, 0x4cb790f5, 0x4cb84e32,
+    0x4cb973ea, 0x4cba693f, 0x4cbb7687, 0x4cbc707d, 0x4cbd7d3a, 0x4cbe6148, 0x4cbf8607, 0x4cc099ff,
+    0x4cc159c9, 0x4cc27832, 0x4cc37815, 0x4cc4907f, 0x4cc580a1, 0x4cc65c3f, 0x4cc766a2, 0x4cc89418,
+    0x4cc96d44, 0x4cca5e55, 0x4ccb5854, 0x4ccc7b95, 0x4ccd8de1, 0x4cce4ea1, 0x4ccf8c5a, 0x4cd081e8,
+    0x4cd189e6, 0x4cd29670, 0x4cd35263, 0x4cd474f6, 0x4cd59a5a, 0x4cd66012, 0x4cd7520a, 0x4cd87434,
+    0x4cd99801, 0x4cda907a, 0x4cdb5504, 0x4cdc7956, 0x4cdd5230, 0x4cde54b2, 0x4cdf8a34, 0x4ce096a3,
+    0x4ce14ff3, 0x4ce29283, 0x4ce391e3, 0x4ce47d39, 0x4ce59688, 0x4ce64f51, 0x4ce77d61, 0x4ce85dba,
+    0x4ce99bae, 0x4cea5f80, 0x4ceb795d, 0x4cec8597, 0x4ced8da3, 0x4cee7c60, 0x4cef5c0a, 0x4cf07565,
+    0x4cf185a9, 0x4cf263d6, 0x4cf39e97, 0x4cf47d22, 0x4cf55375, 0x4cf69aea, 0x4cf79042, 0x4cf86b3d,
+    0x4cf97d0b, 0x4cfa6392, 0x4cfb80aa, 0x4cfc7de9, 0x4cfd9f3b, 0x4cfe99c6, 0x4d416d78, 0x4d426731,
+    0x4d435531, 0x4d446398, 0x4d457825, 0x4d465cb3, 0x4d475de1, 0x4d4892ad, 0x4d4998fd, 0x4d4a9810,
+    0x4d4b6ce3, 0x4d4c6b64, 0x4d4d5321, 0x4d4e6b53, 0x4d4f5e8f, 0x4d507ae5, 0x4d51502b, 0x4d526e56,
+    0x4d5362bd, 0x4d548276, 0x4d556a9c, 0x4d564e18, 0x4d5757f7, 0x4d58752b, 0x4d597c97, 0x4d5a82eb,
+    0x4d5b9802, 0x4d5c811a, 0x4d5d73cd, 0x4d5e8f9b, 0x4d5f5c0b, 0x4d6063e1, 0x4d617372, 0x4d628150,
+    0x4d6380e1, 0x4d645b99, 0x4d6576d7, 0x4d666291, 0x4d6765ec, 0x4d688a3a, 0x4d695947, 0x4d6a65e8,
+    0x4d6b6e7e, 0x4d6c6696, 0x4d6d55ab, 0x4d6e8f09, 0x4d6f92ed, 0x4d709396, 0x4d714eee, 0x4d72755c,
+    0x4d736f38, 0x4d748f9e, 0x4d757981, 0x4d765c01, 0x4d7762e0, 0x4d789be8, 0x4d7991c8, 0x4d7a6276,
+    0x4d7b65cb, 0x4d7c8e0f, 0x4d7d8b21, 0x4d7e699b, 0x4d7f6216, 0x4d805a92, 0x4d8190b8, 0x4d8250da,
+    0x4d8379df, 0x4d846c41, 0x4d855270, 0x4d869175, 0x4d878b39, 0x4d88685d, 0x4d895875, 0x4d8a819c,
+    0x4d8b5b9c, 0x4d8c8a89, 0x4d8d8a72, 0x4d8e9d8f, 0x4d8f6377, 0x4d905974, 0x4d918aa4, 0x4d9252b1,
+    0x4d936962, 0x4d945c48, 0x4d959ce9, 0x4d96673a, 0x4d9775b2, 0x4d986d1e, 0x4d994f0d, 0x4d9a7e6d,
+    0x4d9b7b48, 0x4d9c7fcc, 0x4d9d65e6, 0x4d9e59a5, 0x4d9f79e9, 0x4da06212, 0x4da16ede, 0x4da2770b,
+    0x4da38ca7, 0x4da465bc, 0x4da5885d, 0x4da66adb, 0x4da75c4a, 0x4da88074, 0x4da99084, 0x4daa8ecc,
+    0x4dab65d7, 0x4dac57f9, 0x4dad708e, 0x4dae6f06, 0x4daf5e7c, 0x4db077ac, 0x4db14ff5, 0x4db25949,
+    0x4db381ed, 0x4db49b45, 0x4db57ffc, 0x4db68178, 0x4db769fd, 0x4db86cca, 0x4db969c7, 0x4dba79d2,
+    0x4dbb8b1d, 0x4dbc9ed9, 0x4dbd81d3, 0x4dbe7a3c, 0x4dbf7968, 0x4dc06f5c, 0x4dc163b2, 0x4dc28ddd,
+    0x4dc36383, 0x4dc46e9c, 0x4dc55e33, 0x4dc661f8, 0x4dc776bf, 0x4dc8642c, 0x4dc97db4, 0x4dca6247,
+    0x4dcb6458, 0x4dcc6816, 0x4dcd5f69, 0x4dce9022, 0x4dcf7a1a, 0x4dd082b9, 0x4dd170c8, 0x4dd29a12,
+    0x4dd36163, 0x4dd46fef, 0x4dd553eb, 0x4dd69d3b, 0x4dd762fe, 0x4dd860a0, 0x4dd99591, 0x4dda6d99,
+    0x4ddb6162, 0x4ddc9298, 0x4ddd635c, 0x4dde9707, 0x4ddf8972, 0x4de0683d, 0x4de151e1, 0x4de29b54,
+    0x4de3608c, 0x4de45b22, 0x4de599c4, 0x4de67126, 0x4de78a73, 0x4de8971c, 0x4de97396, 0x4dea67d4,
+    0x4deb60a3, 0x4dec4e11, 0x4ded4ef0, 0x4dee8cdb, 0x4def8cb0, 0x4df07912, 0x4df19774, 0x4df28986,
+    0x4df35146, 0x4df457dc, 0x4df599d0, 0x4df680c3, 0x4df78338, 0x4df878a7, 0x4df986cd, 0x4dfa7f85,
+    0x4dfb5049, 0x4dfc8247, 0x4dfd690b, 0x4dfe7c4d, 0x4e4153ea, 0x4e425f26, 0x4e436e25, 0x4e446881,
+    0x4e459375, 0x4e465dfd, 0x4e475347, 0x4e489727, 0x4e49643a, 0x4e4a75c7, 0x4e4b6fa4, 0x4e4c73a9,
+    0x4e4d77e9, 0x4e4e9451, 0x4e4f8b5c, 0x4e50808c, 0x4e51674e, 0x4e524ead, 0x4e53582f, 0x4e547573,
+    0x4e558ed2, 0x4e566ce5, 0x4e579320, 0x4e588ff7, 0x4e597d33, 0x4e5a72c2, 0x4e5b8217, 0x4e5c7422,
+    0x4e5d82c5, 0x4e5e9a30, 0x4e5f773a, 0x4e605f84, 0x4e619673, 0x4e6264ad, 0x4e63920d, 0x4e6474dc,
+    0x4e6560c7, 0x4e6686ed, 0x4e674ffa, 0x4e6852a3, 0x4e696a3a, 0x4e6a7720, 0x4e6b5320, 0x4e6c61b6,
+    0x4e6d5674, 0x4e6e8776, 0x4e6f6cbf, 0x4e70505c, 0x4e71602a, 0x4e728466, 0x4e736b96, 0x4e746dbc,
+    0x4e7597d3, 0x4e76968f, 0x4e776876, 0x4e7860d1, 0x4e795378, 0x4e7a64a4, 0x4e7b51a0, 0x4e7c9154,
+    0x4e7d5df4, 0x4e7e629e, 0x4e7f5e63, 0x4e80929a, 0x4e817693, 0x4e826c5a, 0x4e836597, 0x4e8450e7,
+    0x4e857c82, 0x4e865f6b, 0x4e876ce1, 0x4e885f6c, 0x4e895ac1, 0x4e8a6f2c, 0x4e8b852d, 0x4e8c6442,
+    0x4e8d5750, 0x4e8e58c7, 0x4e8f8cfc, 0x4e908a5e, 0x4e917a7f, 0x4e92689d, 0x4e937e26, 0x4e947a40,
+    0x4e957344, 0x4e968aeb, 0x4e974fd7, 0x4e987a63, 0x4e998036, 0x4e9a7def, 0x4e9b80c6, 0x4e9c8aed,
+    0x4e9d731f, 0x4e9e8fea, 0x4e9f4f0e, 0x4ea0758b, 0x4ea1518a, 0x4ea26734, 0x4ea35fd9, 0x4ea461c7,
+    0x4ea565af, 0x4ea69cf3, 0x4ea75eca, 0x4ea89262, 0x4ea968df, 0x4eaa6cb8, 0x4eab80f4, 0x4eac57cb,
+    0x4ead6c99, 0x4eae96a0, 0x4eaf5b64, 0x4eb058f1, 0x4eb168c4, 0x4eb25410, 0x4eb3982c, 0x4eb48a87,
+    0x4eb54e5e, 0x4eb66167, 0x4eb79bab, 0x4eb890aa, 0x4eb955b0, 0x4eba82bd, 0x4ebb596a, 0x4ebc66f3,
+    0x4ebd8299, 0x4ebe5893, 0x4ebf719f, 0x4ec06284, 0x4ec167d1, 0x4ec29063, 0x4ec35acc, 0x4ec46c57,
+    0x4ec57ce7, 0x4ec65851, 0x4ec764b2, 0x4ec858ca, 0x4ec9830e, 0x4eca5968, 0x4ecb5302, 0x4ecc5a46,
+    0x4ecd8702, 0x4ece6065, 0x4ecf72d9, 0x4ed089a7, 0x4ed16689, 0x4ed266f9, 0x4ed35d6f, 0x4ed45bb0,
+    0x4ed596bc, 0x4ed6636e, 0x4ed760dc, 0x4ed87948, 0x4ed951dd, 0x4eda8606, 0x4edb5ec9, 0x4edc7554,
+    0x4edd596e, 0x4ede6b04, 0x4edf4f43, 0x4ee07b94, 0x4ee167da, 0x4ee262dd, 0x4ee3628a, 0x4ee4971e,
+    0x4ee562ed, 0x4ee66ec5, 0x4ee7508d, 0x4ee867b6, 0x4ee980e4, 0x4eea9ebf, 0x4eeb5eb5, 0x4eec638c,
+    0x4eed85cd, 0x4eee9867, 0x4eef52c5, 0x4ef06016, 0x4ef168cb, 0x4ef261d0, 0x4ef35751, 0x4ef48f29,
+    0x4ef55faa, 0x4ef681a8, 0x4ef77d62, 0x4ef871c8, 0x4ef954c0, 0x4efa69cc, 0x4efb6b3e, 0x4efc65ac,
+    0x4efd63c3, 0x4efe4f46, 0x4f417b1b, 0x4f426b86, 0x4f4388f8, 0x4f445203, 0x4f45732e, 0x4f466687,
+    0x4f477d17, 0x4f4857f4, 0x4f49570f, 0x4f4a618e, 0x4f4b970a, 0x4f4c7c3f, 0x4f4d8b00, 0x4f4e7881,
+    0x4f4f8ce0, 0x4f50548b, 0x4f517b87, 0x4f52745b, 0x4f537c11, 0x4f548870, 0x4f555398, 0x4f565448,
+    0x4f576cf3, 0x4f586f22, 0x4f5953f6, 0x4f5a88b4, 0x4f5b5301, 0x4f5c7a6b, 0x4f5d8695, 0x4f5e586b,
+    0x4f5f5d29, 0x4f6088c2, 0x4f6162d2, 0x4f624e1e, 0x4f635036, 0x4f6496c0, 0x4f657363, 0x4f668a3b,
+    0x4f675176, 0x4f687199, 0x4f697fe0, 0x4f6a8888, 0x4f6b7e1e, 0x4f6c4e4f, 0x4f6d84cb, 0x4f6e6f2b,
+    0x4f6f5859, 0x4f70936c, 0x4f7153e9, 0x4f72865a, 0x4f739149, 0x4f7486ef, 0x4f755e06, 0x4f765507,
+    0x4f77902e, 0x4f786795, 0x4f79846c, 0x4f7a5ba5, 0x4f7b82a5, 0x4f7c8431, 0x4f7d6d8c, 0x4f7e63fa,
+    0x4f7f4ea5, 0x4f8051c6, 0x4f816328, 0x4f827f70, 0x4f835b5f, 0x4f845dbd, 0x4f8599c8, 0x4f8653ec,
+    0x4f877985, 0x4f888a54, 0x4f897962, 0x4f8a88df, 0x4f8b5b09, 0x4f8c4fb5, 0x4f8d4f91, 0x4f8e9b8e,
+    0x4f8f5192, 0x4f9096f0, 0x4f916daf, 0x4f92622f, 0x4f938490, 0x4f948cdc, 0x4f955075, 0x4f965ce0,
+    0x4f974e14, 0x4f984f83, 0x4f997c54, 0x4f9a84d1, 0x4f9b77b3, 0x4f9c8aee, 0x4f9d5ce8, 0x4f9e62f6,
+    0x4f9f663b, 0x4fa08a93, 0x4fa18526, 0x4fa28a95, 0x4fa365fa, 0x4fa46714, 0x4fa553d4, 0x4fa662ab,
+    0x4fa78ce6, 0x4fa888f3, 0x4fa95be7, 0x4faa868a, 0x4fab668e, 0x4fac582a, 0x4fad6170, 0x4fae696f,
+    0x4faf9f13, 0x4fb07a92, 0x4fb17893, 0x4fb26a7f, 0x4fb39017, 0x4fb49266, 0x4fb57d10, 0x4fb67bc7,
+    0x4fb76ef4, 0x4fb8821c, 0x4fb95c3d, 0x4fba62cd, 0x4fbb85c1, 0x4fbc6f02, 0x4fbd6e67, 0x4fbe6691,
+    0x4fbf85a6, 0x4fc0637a, 0x4fc1821b, 0x4fc24f8d, 0x4fc35091, 0x4fc48a02, 0x4fc562ec, 0x4fc69bc9,
+    0x4fc77a3d, 0x4fc87c9b, 0x4fc950c5, 0x4fca9019, 0x4fcb708a, 0x4fcc7c8b, 0x4fcd64ec, 0x4fce665f,
+    0x4fcf6562, 0x4fd0732b, 0x4fd15339, 0x4fd267a0, 0x4fd355a7, 0x4fd46d2a, 0x4fd57a3f, 0x4fd664e6,
+    0x4fd779a7, 0x4fd867d8, 0x4fd97b26, 0x4fda96bb, 0x4fdb6311, 0x4fdc72a0, 0x4fdd5c6f, 0x4fde7026,
+    0x4fdf97ee, 0x4fe060df, 0x4fe18afe, 0x4fe28b04, 0x4fe38494, 0x4fe49bd6, 0x4fe582af, 0x4fe6932c,
+    0x4fe76606, 0x4fe89640, 0x4fe95bc2, 0x4fea86c7, 0x4feb7949, 0x4fec8017, 0x4fed6919, 0x4fee7092,
+    0x4fef963b, 0x4ff07c7e, 0x4ff159d3, 0x4ff25b5c, 0x4ff37d1b, 0x4ff491d8, 0x4ff56a80, 0x4ff685e9,
+    0x4ff76905, 0x4ff86c93, 0x4ff9502d, 0x4ffa4ea6, 0x4ffb7fc1, 0x4ffc61a4, 0x4ffd8cca, 0x4ffe9665,
+    0x504193d1, 0x504253f1, 0x5043598a, 0x50448eac, 0x504562d8, 0x50466867, 0x504771d5, 0x50487b67,
+    0x5049504f, 0x504a67d0, 0x504b82d1, 0x504c978d, 0x504d748b, 0x504e80ba, 0x504f7336, 0x5050514e,
+    0x50518105, 0x505290ca, 0x5053584a, 0x505467fe, 0x50556ff1, 0x50565ffd, 0x505776c6, 0x50589a0e,
+    0x5059507d, 0x505a9694, 0x505b5ef7, 0x505c7bb8, 0x505d904d, 0x505e6c4e, 0x505f85fb, 0x5060819d,
+    0x506167af, 0x5062564c, 0x50635606, 0x50648c8c, 0x506556da, 0x506673ed, 0x50678cc4, 0x50688fc5,
+    0x506996f6, 0x506a6c50, 0x506b8944, 0x506c8f3f, 0x506d7d5e, 0x506e60e8, 0x506f72fc, 0x50707d9c,
+    0x50718463, 0x50725cfb, 0x50735446, 0x50745d16, 0x50756ca1, 0x507681b3, 0x507758fa, 0x50785bb4,
+    0x50798108, 0x507a541f, 0x507b8cbc, 0x507c6182, 0x507d78a9, 0x507e6fe1, 0x507f91a4, 0x508076f2,
+    0x50816020, 0x508276fe, 0x508384c9, 0x50847f36, 0x50854ec7, 0x5086755d, 0x50877a17, 0x508884ec,
+    0x508975f4, 0x508a4f3a, 0x508b676d, 0x508c7460, 0x508d62f3, 0x508e6f20, 0x508f79e4, 0x509087f9,
+    0x50916094, 0x50926234, 0x509366ab, 0x5094820c, 0x50958499, 0x5096723a, 0x50975fcc, 0x50986109,
+    0x509970cf, 0x509a7261, 0x509b7a50, 0x509c5098, 0x509d9aed, 0x509e5d69, 0x509f601c, 0x50a06667,
+    0x50a199b4, 0x50a25e7b, 0x50a3643e, 0x50a45830, 0x50a553c9, 0x50a67a9f, 0x50a7990c, 0x50a89b42,
+    0x50a98f5f, 0x50aa7aae, 0x50ab5b9b, 0x50ac68a2, 0x50ad6249, 0x50ae7984, 0x50af9dfa, 0x50b05451,
+    0x50b1932f, 0x50b28ac4, 0x50b35f90, 0x50b48df3, 0x50b55a2f, 0x50b680de, 0x50b76d29, 0x50b87a4f,
+    0x50b984bc, 0x50ba9d2b, 0x50bb9010, 0x50bc6d38, 0x50bd916a, 0x50be6fc1, 0x50bf9905, 0x50c06bbb,
+    0x50c15eb6, 0x50c291b8, 0x50c35076, 0x50c46f0f, 0x50c54e19, 0x50c6540f, 0x50c79675, 0x50c86c72,
+    0x50c951b4, 0x50ca5631, 0x50cb9f20, 0x50cc66a6, 0x50cd5f0a, 0x50ce75ab, 0x50cf51f8, 0x50d0674f,
+    0x50d18df5, 0x50d26c70, 0x50d38a6b, 0x50d4757f, 0x50d55cac, 0x50d66841, 0x50d78cd3, 0x50d89bdb,
+    0x50d98475, 0x50da6893, 0x50db840c, 0x50dc72db, 0x50dd7577, 0x50de8568, 0x50df783a, 0x50e0847a,
+    0x50e15f10, 0x50e2831c, 0x50e36813, 0x50e46e1a, 0x50e59daf, 0x50e651f9, 0x50e77980, 0x50e84e99,
+    0x50e95ee3, 0x50ea908a, 0x50eb80af, 0x50ec59a8, 0x50ed77db, 0x50ee8d74, 0x50ef8a1f, 0x50f0673d,
+    0x50f1533f, 0x50f28a0a, 0x50f35618, 0x50f46756, 0x50f553d9, 0x50f64f10, 0x50f77409, 0x50f85a41,
+    0x50f94ff8, 0x50fa79b0, 0x50fb9838, 0x50fc8e2a, 0x50fd9d60, 0x50fe8f44, 0x514165a5, 0x514275be,
+    0x5143906d, 0x5144867b, 0x514560bc, 0x514651b6, 0x51475937, 0x51487d2f, 0x5149916c, 0x514a69ae,
+    0x514b7ce0, 0x514c792a, 0x514d5d14, 0x514e64c1, 0x514f58ec, 0x5150589c, 0x51518d66, 0x515266d9,
+    0x515361f2, 0x5154912d, 0x51556e58, 0x51569435, 0x5157965b, 0x51587272, 0x51595f6a, 0x515a5e9a,
+    0x515b8f1b, 0x515c5b95, 0x515d5c39, 0x515e9013, 0x515f834f, 0x51607cce, 0x5161620a, 0x516290ed,
+    0x5163691b, 0x51646e15, 0x516565db, 0x516666fe, 0x51674e9f, 0x516855aa, 0x51697a83, 0x516a83e9,
+    0x516b8b83, 0x516c846d, 0x516d83f0, 0x516e7f50, 0x516f918d, 0x51709190, 0x5171758e, 0x517295a5,
+    0x517381e7, 0x517475e2, 0x517561a9, 0x51768a50, 0x517795b2, 0x517853a8, 0x517959f6, 0x517a9813,
+    0x517b7891, 0x517c7c17, 0x517d6b3a, 0x517e57e0, 0x517f620e, 0x518083d6, 0x51818ad2, 0x518275d4,
+    0x5183927e, 0x518459dc, 0x51855289, 0x51869087, 0x51876ffe, 0x51887473, 0x51895c09, 0x518a9d6c,
+    0x518b84fc, 0x518c7cdf, 0x518d7bad, 0x518e8a6e, 0x518f594e, 0x519056a2, 0x5191819a, 0x51927947,
+    0x51936636, 0x519453e1, 0x51957887, 0x519658cc, 0x51979397, 0x51986e13, 0x51995256, 0x519a828b,
+    0x519b9e9f, 0x519c9583, 0x519d658c, 0x519e9e93, 0x519f7345, 0x51a06e26, 0x51a19d07, 0x51a25983,
+    0x51a37dac, 0x51a496c1, 0x51a561be, 0x51a66762, 0x51a79ece, 0x51a890a8, 0x51a99187, 0x51aa9f0e,
+    0x51ab7c38, 0x51ac51f1, 0x51ad8599, 0x51ae524c, 0x51af540e, 0x51b07901, 0x51b1655e, 0x51b26668,
+    0x51b35ce1, 0x51b47566, 0x51b576c8, 0x51b68679, 0x51b7531d, 0x51b85506, 0x51b97926, 0x51ba8912,
+    0x51bb77ef, 0x51bc7cc0, 0x51bd570b, 0x51be515c, 0x51bf7e8a, 0x51c0535c, 0x51c18a60, 0x51c265a7,
+    0x51c38766, 0x51c45766, 0x51c56ae8, 0x51c687fb, 0x51c75e16, 0x51c87aea, 0x51c98d73, 0x51ca771e,
+    0x51cb737a, 0x51cc66e0, 0x51cd9410, 0x51ce816b, 0x51cf7b08, 0x51d091fc, 0x51d15737, 0x51d26fe4,
+    0x51d3856a, 0x51d47e55, 0x51d59957, 0x51d687ba, 0x51d7694a, 0x51d8818f, 0x51d95eff, 0x51da891c,
+    0x51db72d0, 0x51dc9846, 0x51dd9edb, 0x51de8d99, 0x51df5dd6, 0x51e062b9, 0x51e164ab, 0x51e24f76,
+    0x51e3613f, 0x51e468af, 0x51e55f14, 0x51e6800c, 0x51e792f8, 0x51e87bc1, 0x51e952fe, 0x51ea664f,
+    0x51eb9177, 0x51ec51f6, 0x51ed97a0, 0x51ee839e, 0x51ef647a, 0x51f09c3a, 0x51f167f5, 0x51f27c4f,
+    0x51f3685f, 0x51f49b6f, 0x51f59f4b, 0x51f67ffb, 0x51f79348, 0x51f84ff6, 0x51f99e92, 0x51fa9197,
+    0x51fb96db, 0x51fc5be6, 0x51fd6ccc, 0x51fe7cfe, 0x52419453, 0x52426822, 0x524366b9, 0x52445bd4,
+    0x524598f4, 0x52468ae6, 0x52478154, 0x52487827, 0x524974bd, 0x524a6ed3, 0x524b9288, 0x524c5a20,
+    0x524d5b8b, 0x524e86f8, 0x524f760d, 0x5250865c, 0x52516641, 0x525291c9, 0x52535589, 0x52547a4e,
+    0x525559e5, 0x52566042, 0x5257932b, 0x52585b5a, 0x5259849c, 0x525a5c91, 0x525b96cd, 0x525c62d9,
+    0x525d675c, 0x525e6787, 0x525f5e7d, 0x52608650, 0x52619eb9, 0x52625cb1, 0x526380ce, 0x52647a00,
+    0x52658abc, 0x52665700, 0x52678096, 0x52687d72, 0x52699211, 0x526a8098, 0x526b907c, 0x526c7761,
+    0x526d8737, 0x526e9075, 0x526f817a, 0x52707c3e, 0x52716ea2, 0x5272965e, 0x52737e90, 0x527472d7,
+    0x527558fd, 0x527660b3, 0x52779786, 0x52787e88, 0x5279587e, 0x527a6e20, 0x527b84dc, 0x527c6961,
+    0x527d77ad, 0x527e5197, 0x527f652a, 0x52806777, 0x52815dcd, 0x52826101, 0x5283932e, 0x52845954,
+    0x52856367, 0x5286798d, 0x52877aff, 0x528880d6, 0x528958b3, 0x528a6168, 0x528b6ac3, 0x528c7483,
+    0x528d9b92, 0x528e660a, 0x528f642d, 0x52905118, 0x52916763, 0x5292809b, 0x52939c10, 0x52944fc9,
+    0x52956953, 0x52967a1c, 0x529752ff, 0x52986055, 0x5299768e, 0x529a817f, 0x529b5642, 0x529c5f6d,
+    0x529d7194, 0x529e70bb, 0x529f7436, 0x52a08000, 0x52a1874b, 0x52a255da, 0x52a37435, 0x52a47690,
+    0x52a596eb, 0x52a666dd, 0x52a7751c, 0x52a8633d, 0x52a96ec9, 0x52aa7c64, 0x52ab7ca5, 0x52ac6d35,
+    0x52ad935c, 0x52ae7027, 0x52af5e25, 0x52b0701d, 0x52b154bd, 0x52b2611a, 0x52b36973, 0x52b46c6a,
+    0x52b5559a, 0x52b66d19, 0x52b796cc, 0x52b85be1, 0x52b959fb, 0x52ba697c, 0x52bb914c, 0x52bc7709,
+    0x52bd8500, 0x52be7a46, 0x52bf7872, 0x52c092e4, 0x52c18ced, 0x52c27cfa, 0x52c39d1b, 0x52c4814e,
+    0x52c59ac4, 0x52c668a0, 0x52c76dcb, 0x52c85918, 0x52c983b1, 0x52ca5629, 0x52cb9b41, 0x52cc6897,
+    0x52cd70b3, 0x52ce9771, 0x52cf9419, 0x52d067a2, 0x52d16802, 0x52d27895, 0x52d368a7, 0x52d450d6,
+    0x52d580b1, 0x52d65ef8, 0x52d782d4, 0x52d8797a, 0x52d967ca, 0x52da7e4d, 0x52db69cd, 0x52dc51c4,
+    0x52dd723d, 0x52de6829, 0x52df99b3, 0x52e05f3c, 0x52e18f61, 0x52e2682b, 0x52e36155, 0x52e46591,
+    0x52e58fb1, 0x52e67e1b, 0x52e79798, 0x52e89952, 0x52e98877, 0x52ea5b2c, 0x52eb6631, 0x52ec4fa0,
+    0x52ed6939, 0x52ee6afb, 0x52ef5bb5, 0x52f07ac8, 0x52f15026, 0x52f25944, 0x52f39059, 0x52f47b25,
+    0x52f57b4f, 0x52f68e74, 0x52f78543, 0x52f85858, 0x52f98b0e, 0x52fa5039, 0x52fb8654, 0x52fc97f6,
+    0x52fd7569, 0x52fe72f8, 0x53414ef7, 0x53429d89, 0x53435016, 0x534451cc, 0x534562cc, 0x534691c6,
+    0x53478755, 0x5348649a, 0x534988f4, 0x534a91e6, 0x534b6854, 0x534c695a, 0x534d6c40, 0x534e7b6c,
+    0x534f6741, 0x535077d7, 0x53518823, 0x53525384, 0x53538eaf, 0x53547280, 0x53558c6b, 0x5356788d,
+    0x53577165, 0x53588207, 0x535968b1, 0x535a8d04, 0x535b9077, 0x535c701e, 0x535d8fe6, 0x535e810a,
+    0x535f81bf, 0x536089dc, 0x536168b3, 0x53626adf, 0x536392ea, 0x536495c7, 0x53657957, 0x53667a20,
+    0x536753a9, 0x53688e5f, 0x5369786f, 0x536a79b9, 0x536b5f27, 0x536c5ed6, 0x536d6853, 0x536e93ac,
+    0x536f919c, 0x5370691a, 0x53715806, 0x537264b0, 0x53737e4b, 0x53747d8f, 0x537568f2, 0x53766ea5,
+    0x537782db, 0x53789192, 0x53795243, 0x537a8eb0, 0x537b9081, 0x537c721b, 0x537d7dcb, 0x537e7656,
+    0x537f59ac, 0x53806fe0, 0x53818b28, 0x538280a2, 0x53835544, 0x53846070, 0x53855f4a, 0x538668c8,
+    0x5387633a, 0x53889438, 0x53899b4f, 0x538a81e5, 0x538b6a17, 0x538c70dd, 0x538d69a7, 0x538e614c,
+    0x538f920e, 0x53909310, 0x53919bad, 0x539252d7, 0x5393925e, 0x539492f9, 0x53955993, 0x53967696,
+    0x539766fb, 0x53985769, 0x539973ca, 0x539a7678, 0x539b6a1f, 0x539c7e9c, 0x539d9811, 0x539e8cd1,
+    0x539f5840, 0x53a06349, 0x53a1871c, 0x53a262d0, 0x53a360b4, 0x53a46b89, 0x53a586ee, 0x53a65764,
+    0x53a7581d, 0x53a88549, 0x53a97235, 0x53aa7652, 0x53ab983b, 0x53ac8237, 0x53ad5351, 0x53ae5c24,
+    0x53af59be, 0x53b05815, 0x53b1901d, 0x53b269b4, 0x53b3834a, 0x53b49ea9, 0x53b5976b, 0x53b68086,
+    0x53b753ad, 0x53b86068, 0x53b94fae, 0x53ba76c3, 0x53bb6a05, 0x53bc689b, 0x53bd937e, 0x53be99d5,
+    0x53bf91c7, 0x53c05c16, 0x53c1585e, 0x53c261a7, 0x53c39699, 0x53c44fdf, 0x53c58278, 0x53c69c52,
+    0x53c75f45, 0x53c86108, 0x53c97c8d, 0x53ca806f, 0x53cb5df7, 0x53cc8d6b, 0x53cd57b0, 0x53ce98e2,
+    0x53cf5703, 0x53d079bf, 0x53d15996, 0x53d27941, 0x53d3540a, 0x53d483df, 0x53d59c39, 0x53d652d2,
+    0x53d76bd8, 0x53d886cb, 0x53d94ec0, 0x53da9a28, 0x53db5366, 0x53dc8006, 0x53dd7337, 0x53de6492,
+    0x53df8fed, 0x53e05ac9, 0x53e15420, 0x53e2537f, 0x53e34faf, 0x53e4807e, 0x53e5543b, 0x53e67515,
+    0x53e77b18, 0x53e88749, 0x53e954b3, 0x53ea704c, 0x53eb8997, 0x53ec6cab, 0x53ed85fa, 0x53ee7114,
+    0x53ef696e, 0x53f09328, 0x53f1745a, 0x53f259d1, 0x53f36e5b, 0x53f4617e, 0x53f553e2, 0x53f68317,
+    0x53f776e7, 0x53f8848b, 0x53f985af, 0x53fa6925, 0x53fb5c60, 0x53fc7259, 0x53fd75d5, 0x53fe8b90,
+    0x54416e07, 0x544282ad, 0x54435c4f, 0x54447bed, 0x54459784, 0x54466f70, 0x5447764c, 0x544888b7,
+    0x544992d2, 0x544a4f36, 0x544b5efe, 0x544c9061, 0x544d88e1, 0x544e8471, 0x544f711a, 0x54506d1b,
+    0x545180b4, 0x545274e2, 0x54537433, 0x54545a7f, 0x5455905c, 0x5456980c, 0x54575319, 0x5458906e,
+    0x54596bb4, 0x545a85aa, 0x545b7897, 0x545c7afa, 0x545d6aae, 0x545e8910, 0x545f958f, 0x5460620c,
+    0x54614f3d, 0x54624f7c, 0x546379be, 0x54649d0e, 0x54654ed4, 0x546657a2, 0x546751a5, 0x54686900,
+    0x54696089, 0x546a707c, 0x546b7ae3, 0x546c8956, 0x546d93a7, 0x546e9c2d, 0x546f5112, 0x547052fa,
+    0x54717cca, 0x547260f9, 0x54737078, 0x547481c6, 0x5475559d, 0x54766991, 0x547796c9, 0x5478553e,
+    0x5479805a, 0x547a8304, 0x547b8332, 0x547c54fa, 0x547d565b, 0x547e8fbf, 0x547f5634, 0x54806760,
+    0x54815265, 0x5482840e, 0x54835e5f, 0x54847b65, 0x54859035, 0x54868387, 0x54876b4e, 0x548858be,
+    0x54896309, 0x548a727d, 0x548b97ad, 0x548c69d0, 0x548d546a, 0x548e984e, 0x548f632b, 0x5490714e,
+    0x54918557, 0x54927cde, 0x54936372, 0x549468f9, 0x54957511, 0x54968602, 0x54976eba, 0x54985a3c,
+    0x54997a84, 0x549a851a, 0x549b95a4, 0x549c59d0, 0x549d60da, 0x549e51ea, 0x549f5a29, 0x54a07169,
+    0x54a16f15, 0x54a2696b, 0x54a363bb, 0x54a475e9, 0x54a54e4e, 0x54a67dbb, 0x54a76934, 0x54a88521,
+    0x54a98ffa, 0x54aa9354, 0x54ab9c3b, 0x54ac5f17, 0x54ad5ed3, 0x54ae8258, 0x54af895f, 0x54b082e7,
+    0x54b152c3, 0x54b25c51, 0x54b383ab, 0x54b47826, 0x54b579e1, 0x54b67ff0, 0x54b7626e, 0x54b860f0,
+    0x54b95ca8, 0x54ba6f97, 0x54bb71a8, 0x54bc9909, 0x54bd5132, 0x54be5e37, 0x54bf5f04, 0x54c0637b,
+    0x54c16753, 0x54c268d7, 0x54c36652, 0x54c49cf6, 0x54c588b0, 0x54c652ab, 0x54c74fc4, 0x54c84e3c,
+    0x54c967b3, 0x54ca7baa, 0x54cb7f4d, 0x54cc8a23, 0x54cd63b4, 0x54ce71e6, 0x54cf65a4, 0x54d06f09,
+    0x54d1853d, 0x54d25072, 0x54d37dba, 0x54d45516, 0x54d57b04, 0x54d672fd, 0x54d76cd3, 0x54d88422,
+    0x54d9621f, 0x54da50ad, 0x54db8235, 0x54dc8718, 0x54dd5919, 0x54de6028, 0x54df677c, 0x54e06f23,
+    0x54e175b9, 0x54e2695c, 0x54e3520e, 0x54e48018, 0x54e58b01, 0x54e671ed, 0x54e75713, 0x54e8660f,
+    0x54e983eb, 0x54ea7164, 0x54eb7d9b, 0x54ec5617, 0x54ed7d7d, 0x54ee8f4d, 0x54ef9318, 0x54f08569,
+    0x54f15d17, 0x54f2678c, 0x54f367de, 0x54f487c7, 0x54f579ae, 0x54f65835, 0x54f78404, 0x54f89041,
+    0x54f97fd4, 0x54fa6e8c, 0x54fb8a63, 0x54fc9d08, 0x54fd670f, 0x54fe939a, 0x554163ac, 0x5542602f,
+    0x554364e2, 0x5544608d, 0x554596b7, 0x55466357, 0x55478461, 0x5548914b, 0x554975d8, 0x554a60e7,
+    0x554b9913, 0x554c9c57, 0x554d5984, 0x554e6deb, 0x554f5e96, 0x55506d9c, 0x55519bf0, 0x555258bb,
+    0x55537977, 0x555460b6, 0x5555633f, 0x55565bf5, 0x55579812, 0x5558558b, 0x555982d3, 0x555a5147,
+    0x555b6190, 0x555c7953, 0x555d79bd, 0x555e6c5d, 0x555f9eba, 0x55609c48, 0x55618da8, 0x55625ee0,
+    0x55637d43, 0x55645efc, 0x5565854e, 0x55668ce4, 0x55675ae1, 0x556854e8, 0x55695023, 0x556a52be,
+    0x556b7dec, 0x556c8511, 0x556d6666, 0x556e6c3e, 0x556f724c, 0x55708adc, 0x55719c0d, 0x557277a5,
+    0x55738b02, 0x55748d05, 0x55756f11, 0x55769834, 0x557797fb, 0x557850fb, 0x55797f75, 0x557a5a03,
+    0x557b8513, 0x557c4fb6, 0x557d634c, 0x557e9d61, 0x557f808b, 0x55805294, 0x558165a1, 0x5582567a,
+    0x55835957, 0x55848d0b, 0x55856a35, 0x55866ad3, 0x558770f9, 0x5588865e, 0x55896fb1, 0x558a51e7,
+    0x558b7feb, 0x558c59ea, 0x558d5e87, 0x558e6b6a, 0x558f754f, 0x5590717d, 0x5591914e, 0x55927d2c,
+    0x55938c79, 0x55946062, 0x5595621a, 0x55967fa8, 0x55975f1b, 0x55986c8c, 0x559986fe, 0x559a7562,
+    0x559b7b86, 0x559c9ab8, 0x559d6627, 0x559e7aba, 0x559f844e, 0x55a06f81, 0x55a18b2c, 0x55a286a4,
+    0x55a36feb, 0x55a47b8b, 0x55a57f77, 0x55a68f2f, 0x55a78e44, 0x55a87e23, 0x55a94e4d, 0x55aa79a6,
+    0x55ab8afa, 0x55ac903c, 0x55ad50d1, 0x55ae9ecd, 0x55af5edf, 0x55b0758f, 0x55b1631f, 0x55b253db,
+    0x55b39910, 0x55b4826e, 0x55b562f7, 0x55b668fa, 0x55b7725d, 0x55b8803d, 0x55b958d5, 0x55ba5c4d,
+    0x55bb86d9, 0x55bc540b, 0x55bd8805, 0x55be92f2, 0x55bf9237, 0x55c05c61, 0x55c1985b, 0x55c286e4,
+    0x55c3966a, 0x55c47262, 0x55c56955, 0x55c66cd7, 0x55c76994, 0x55c89c2f, 0x55c977e7, 0x55ca68c9,
+    0x55cb8de8, 0x55cc6d6c, 0x55cd67c1, 0x55ce9baa, 0x55cf619a, 0x55d063a9, 0x55d17015, 0x55d29306,
+    0x55d3934d, 0x55d46a61, 0x55d56258, 0x55d65283, 0x55d77525, 0x55d85687, 0x55d96c83, 0x55da6834,
+    0x55db649e, 0x55dc4e9b, 0x55dd7252, 0x55de59e6, 0x55df8fc2, 0x55e05fbd, 0x55e16dd8, 0x55e285f7,
+    0x55e38a51, 0x55e49817, 0x55e599c1, 0x55e663a0, 0x55e77c81, 0x55e85b30, 0x55e98139, 0x55ea5403,
+    0x55eb7e82, 0x55ec8106, 0x55ed532a, 0x55ee6a8e, 0x55ef7f6b, 0x55f054e9, 0x55f15678, 0x55f28ab9,
+    0x55f36715, 0x55f45bd3, 0x55f56478, 0x55f664fe, 0x55f76b1d, 0x55f88cc2, 0x55f951cb, 0x55fa7e8f,
+    0x56415f0c, 0x56424e10, 0x56434e15, 0x56444e28, 0x56454e2a, 0x56464e31, 0x56474e36, 0x56484e3f,
+    0x56494e42, 0x564a4e56, 0x564b4e58, 0x564c4e62, 0x564d4e82, 0x564e4e85, 0x564f4e8a, 0x56504e8e,
+    0x56515f0d, 0x56524e9e, 0x56534ea0, 0x56544ea2, 0x56554eb0, 0x56564eb3, 0x56574eb6, 0x56584ece,
+    0x56594ecd, 0x565a4ec4, 0x565b4ec6, 0x565c4ec2, 0x565d4ee1, 0x565e4ed7, 0x565f4ede, 0x56604eed,
+    0x56614edf, 0x56624efc, 0x56634f09, 0x56644f1c, 0x56654f00, 0x56664f03, 0x56674f5a, 0x56684f30,
+    0x56694f5d, 0x566a4f39, 0x566b4f57, 0x566c4f47, 0x566d4f5e, 0x566e4f56, 0x566f4f5b, 0x56704f92,
+    0x56714f8a, 0x56724f88, 0x56734f8f, 0x56744f9a, 0x56754fad, 0x56764f98, 0x56774f7b, 0x56784fab,
+    0x56794f69, 0x567a4f70, 0x567b4f94, 0x567c4f6f, 0x567d4f86, 0x567e4f96, 0x567f4fd4, 0x56804fce,
+    0x56814fd8, 0x56824fdb, 0x56834fd1, 0x56844fda, 0x56854fd0, 0x56864fcd, 0x56874fe4, 0x56884fe5,
+    0x5689501a, 0x568a5040, 0x568b5028, 0x568c5014, 0x568d502a, 0x568e5025, 0x568f5005, 0x56905021,
+    0x56915022, 0x56925029, 0x5693502c, 0x56944fff, 0x56954ffe, 0x56964fef, 0x56975011, 0x5698501e,
+    0x56995006, 0x569a5043, 0x569b5047, 0x569c5055, 0x569d5050, 0x569e5048, 0x569f505a, 0x56a05056,
+    0x56a1500f, 0x56a25046, 0x56a35070, 0x56a45042, 0x56a5506c, 0x56a65078, 0x56a75080, 0x56a85094,
+    0x56a9509a, 0x56aa5085, 0x56ab50b4, 0x56ac6703, 0x56ad50b2, 0x56ae50c9, 0x56af50ca, 0x56b050b3,
+    0x56b150c2, 0x56b250f4, 0x56b350de, 0x56b450e5, 0x56b550d8, 0x56b650ed, 0x56b750e3, 0x56b850ee,
+    0x56b950f9, 0x56ba50f5, 0x56bb5109, 0x56bc5101, 0x56bd5102, 0x56be511a, 0x56bf5115, 0x56c05114,
+    0x56c15116, 0x56c25121, 0x56c3513a, 0x56c45137, 0x56c5513c, 0x56c6513b, 0x56c7513f, 0x56c85140,
+    0x56c9514a, 0x56ca514c, 0x56cb5152, 0x56cc5154, 0x56cd5162, 0x56ce5164, 0x56cf5169, 0x56d0516a,
+    0x56d1516e, 0x56d25180, 0x56d35182, 0x56d456d8, 0x56d5518c, 0x56d65189, 0x56d7518f, 0x56d85191,
+    0x56d95193, 0x56da5195, 0x56db5196, 0x56dc519d, 0x56dd51a4, 0x56de51a6, 0x56df51a2, 0x56e051a9,
+    0x56e151aa, 0x56e251ab, 0x56e351b3, 0x56e451b1, 0x56e551b2, 0x56e651b0, 0x56e751b5, 0x56e851be,
+    0x56e951bd, 0x56ea51c5, 0x56eb51c9, 0x56ec51db, 0x56ed51e0, 0x56ee51e9, 0x56ef51ec, 0x56f051ed,
+    0x56f151f0, 0x56f251f5, 0x56f351fe, 0x56f45204, 0x56f5520b, 0x56f65214, 0x56f75215, 0x56f85227,
+    0x56f9522a, 0x56fa522e, 0x56fb5233, 0x56fc5239, 0x56fd5244, 0x56fe524b, 0x5741524f, 0x5742525e,
+    0x57435254, 0x57445271, 0x5745526a, 0x57465273, 0x57475274, 0x57485269, 0x5749527f, 0x574a527d,
+    0x574b528d, 0x574c5288, 0x574d5292, 0x574e5291, 0x574f529c, 0x575052a6, 0x575152ac, 0x575252ad,
+    0x575352bc, 0x575452b5, 0x575552c1, 0x575652c0, 0x575752cd, 0x575852db, 0x575952de, 0x575a52e3,
+    0x575b52e6, 0x575c52e0, 0x575d52f3, 0x575e52f5, 0x575f52f8, 0x576052f9, 0x57615300, 0x57625306,
+    0x57635307, 0x57645308, 0x57657538, 0x5766530d, 0x57675310, 0x5768530f, 0x57695315, 0x576a531a,
+    0x576b5324, 0x576c5323, 0x576d532f, 0x576e5331, 0x576f5333, 0x57705338, 0x57715340, 0x57725345,
+    0x57735346, 0x57745349, 0x57754e17, 0x5776534d, 0x577751d6, 0x57788209, 0x5779535e, 0x577a5369,
+    0x577b536e, 0x577c5372, 0x577d5377, 0x577e537b, 0x577f5382, 0x57805393, 0x57815396, 0x578253a0,
+    0x578353a6, 0x578453a5, 0x578553ae, 0x578653b0, 0x578753b2, 0x578853b6, 0x578953c3, 0x578a7c12,
+    0x578b53dd, 0x578c53df, 0x578d66fc, 0x578efa0e, 0x578f71ee, 0x579053ee, 0x579153e8, 0x579253ed,
+    0x579353fa, 0x57945401, 0x5795543d, 0x57965440, 0x5797542c, 0x5798542d, 0x5799543c, 0x579a542e,
+    0x579b5436, 0x579c5429, 0x579d541d, 0x579e544e, 0x579f548f, 0x57a05475, 0x57a1548e, 0x57a2545f,
+    0x57a35471, 0x57a45477, 0x57a55470, 0x57a65492, 0x57a7547b, 0x57a85480, 0x57a9549c, 0x57aa5476,
+    0x57ab5484, 0x57ac5490, 0x57ad5486, 0x57ae548a, 0x57af54c7, 0x57b054bc, 0x57b154af, 0x57b254a2,
+    0x57b354b8, 0x57b454a5, 0x57b554ac, 0x57b654c4, 0x57b754d8, 0x57b854c8, 0x57b954a8, 0x57ba54ab,
+    0x57bb54c2, 0x57bc54a4, 0x57bd54a9, 0x57be54be, 0x57bf54e5, 0x57c054ff, 0x57c154e6, 0x57c2550f,
+    0x57c35514, 0x57c454fd, 0x57c554ee, 0x57c654ed, 0x57c754e2, 0x57c85539, 0x57c95540, 0x57ca5563,
+    0x57cb554c, 0x57cc552e, 0x57cd555c, 0x57ce5545, 0x57cf5556, 0x57d05557, 0x57d15538, 0x57d25533,
+    0x57d3555d, 0x57d45599, 0x57d55580, 0x57d6558a, 0x57d7559f, 0x57d8557b, 0x57d9557e, 0x57da5598,
+    0x57db559e, 0x57dc55ae, 0x57dd557c, 0x57de5586, 0x57df5583, 0x57e055a9, 0x57e15587, 0x57e255a8,
+    0x57e355c5, 0x57e455df, 0x57e555c4, 0x57e655dc, 0x57e755e4, 0x57e855d4, 0x57e955f9, 0x57ea5614,
+    0x57eb55f7, 0x57ec5616, 0x57ed55fe, 0x57ee55fd, 0x57ef561b, 0x57f0564e, 0x57f15650, 0x57f25636,
+    0x57f35632, 0x57f45638, 0x57f5566b, 0x57f65664, 0x57f75686, 0x57f8562f, 0x57f9566c, 0x57fa566a,
+    0x57fb71df, 0x57fc5694, 0x57fd568f, 0x57fe5680, 0x5841568a, 0x584256a0, 0x584356a5, 0x584456ae,
+    0x584556b6, 0x584656b4, 0x584756c8, 0x584856c2, 0x584956bc, 0x584a56c1, 0x584b56c3, 0x584c56c0,
+    0x584d56ce, 0x584e56d3, 0x584f56d1, 0x585056d7, 0x585156ee, 0x585256f9, 0x585356ff, 0x58545704,
+    0x58555709, 0x58565708, 0x5857570d, 0x585855c7, 0x58595718, 0x585a5716, 0x585b571c, 0x585c5726,
+    0x585d5738, 0x585e574e, 0x585f573b, 0x58605759, 0x58615740, 0x5862574f, 0x58635765, 0x58645788,
+    0x58655761, 0x5866577f, 0x58675789, 0x58685793, 0x586957a0, 0x586a57a4, 0x586b57b3, 0x586c57ac,
+    0x586d57aa, 0x586e57c3, 0x586f57c6, 0x587057c8, 0x587157c0, 0x587257d4, 0x587357c7, 0x587457d2,
+    0x587557d3, 0x587657d6, 0x5877fa0f, 0x5878580a, 0x587957e3, 0x587a580b, 0x587b5819, 0x587c5821,
+    0x587d584b, 0x587e5862, 0x587f6bc0, 0x5880583d, 0x58815852, 0x5882fa10, 0x58835870, 0x58845879,
+    0x58855885, 0x58865872, 0x5887589f, 0x588858ab, 0x588958b8, 0x588a589e, 0x588b58ae, 0x588c58b2,
+    0x588d58b9, 0x588e58ba, 0x588f58c5, 0x589058d3, 0x589158d1, 0x589258d7, 0x589358d9, 0x589458d8,
+    0x589558de, 0x589658dc, 0x589758df, 0x589858e4, 0x589958e5, 0x589a58ef, 0x589b58f7, 0x589c58f9,
+    0x589d58fb, 0x589e58fc, 0x589f5902, 0x58a0590a, 0x58a1590b, 0x58a25910, 0x58a3591b, 0x58a468a6,
+    0x58a55925, 0x58a6592c, 0x58a7592d, 0x58a85932, 0x58a95938, 0x58aa593e, 0x58ab5955, 0x58ac5950,
+    0x58ad5953, 0x58ae595a, 0x58af5958, 0x58b0595b, 0x58b1595d, 0x58b25963, 0x58b35962, 0x58b45960,
+    0x58b55967, 0x58b6596c, 0x58b75969, 0x58b85978, 0x58b95981, 0x58ba598d, 0x58bb599b, 0x58bc599d,
+    0x58bd59a3, 0x58be59a4, 0x58bf59b2, 0x58c059ba, 0x58c159c6, 0x58c259e8, 0x58c359d9, 0x58c459da,
+    0x58c55a25, 0x58c65a1f, 0x58c75a11, 0x58c85a1c, 0x58c95a1a, 0x58ca5a09, 0x58cb5a40, 0x58cc5a6c,
+    0x58cd5a49, 0x58ce5a35, 0x58cf5a36, 0x58d05a62, 0x58d15a6a, 0x58d25a9a, 0x58d35abc, 0x58d45abe,
+    0x58d55ad0, 0x58d65acb, 0x58d75ac2, 0x58d85abd, 0x58d95ae3, 0x58da5ad7, 0x58db5ae6, 0x58dc5ae9,
+    0x58dd5ad6, 0x58de5afa, 0x58df5afb, 0x58e05b0c, 0x58e15b0b, 0x58e25b16, 0x58e35b32, 0x58e45b2a,
+    0x58e55b36, 0x58e65b3e, 0x58e75b43, 0x58e85b45, 0x58e95b40, 0x58ea5b51, 0x58eb5b55, 0x58ec5b56,
+    0x58ed6588, 0x58ee5b5b, 0x58ef5b65, 0x58f05b69, 0x58f15b70, 0x58f25b73, 0x58f35b75, 0x58f45b78,
+    0x58f55b7a, 0x58f65b80, 0x58f75b83, 0x58f85ba6, 0x58f95bb8, 0x58fa5bc3, 0x58fb5bc7, 0x58fc5bc0,
+    0x58fd5bc9, 0x58fe752f, 0x59415bd0, 0x59425bd8, 0x59435bde, 0x59445bec, 0x59455be4, 0x59465be2,
+    0x59475be5, 0x59485beb, 0x59495bf0, 0x594a5bf3, 0x594b5bf6, 0x594c5c05, 0x594d5c07, 0x594e5c08,
+    0x594f5c0d, 0x59505c13, 0x59515c1e, 0x59525c20, 0x59535c22, 0x59545c28, 0x59555c38, 0x59565c41,
+    0x59575c46, 0x59585c4e, 0x59595c53, 0x595a5c50, 0x595b5b71, 0x595c5c6c, 0x595d5c6e, 0x595e5c76,
+    0x595f5c79, 0x59605c8c, 0x59615c94, 0x59625cbe, 0x59635cab, 0x59645cbb, 0x59655cb6, 0x59665cb7,
+    0x59675ca6, 0x59685cba, 0x59695cc5, 0x596a5cbc, 0x596b5cc7, 0x596c5cd9, 0x596d5ce9, 0x596e5cfd,
+    0x596f5cfa, 0x59705cf5, 0x59715ced, 0x59725cea, 0x59735d0b, 0x59745d15, 0x59755d1f, 0x59765d1b,
+    0x59775d11, 0x59785d27, 0x59795d22, 0x597a5d1a, 0x597b5d19, 0x597c5d18, 0x597d5d4c, 0x597e5d52,
+    0x597f5d53, 0x5980fa11, 0x59815d5c, 0x59825d4e, 0x59835d4b, 0x59845d42, 0x59855d6c, 0x59865d73,
+    0x59875d6d, 0x59885d76, 0x59895d87, 0x598a5d84, 0x598b5d82, 0x598c5d8c, 0x598d5da2, 0x598e5d9d,
+    0x598f5d90, 0x59905dac, 0x59915dae, 0x59925db7, 0x59935db8, 0x59945dbc, 0x59955db9, 0x59965dc9,
+    0x59975dd0, 0x59985dd3, 0x59995dd2, 0x599a5ddb, 0x599b5deb, 0x599c5df5, 0x599d5e0b, 0x599e5e1a,
+    0x599f5e19, 0x59a05e11, 0x59a15e1b, 0x59a25e36, 0x59a35e44, 0x59a45e43, 0x59a55e40, 0x59a65e47,
+    0x59a75e4e, 0x59a85e57, 0x59a95e54, 0x59aa5e62, 0x59ab5e64, 0x59ac5e75, 0x59ad5e76, 0x59ae5e7a,
+    0x59af5e7f, 0x59b05ea0, 0x59b15ec1, 0x59b25ec2, 0x59b35ec8, 0x59b45ed0, 0x59b55ecf, 0x59b65edd,
+    0x59b75eda, 0x59b85edb, 0x59b95ee2, 0x59ba5ee1, 0x59bb5ee8, 0x59bc5ee9, 0x59bd5eec, 0x59be5ef0,
+    0x59bf5ef1, 0x59c05ef3, 0x59c15ef4, 0x59c25f03, 0x59c35f09, 0x59c45f0b, 0x59c55f11, 0x59c65f16,
+    0x59c75f21, 0x59c85f29, 0x59c95f2d, 0x59ca5f2f, 0x59cb5f34, 0x59cc5f38, 0x59cd5f41, 0x59ce5f48,
+    0x59cf5f4c, 0x59d05f4e, 0x59d15f51, 0x59d25f56, 0x59d35f57, 0x59d45f59, 0x59d55f5c, 0x59d65f5d,
+    0x59d75f61, 0x59d85f67, 0x59d95f73, 0x59da5f77, 0x59db5f83, 0x59dc5f82, 0x59dd5f7f, 0x59de5f8a,
+    0x59df5f88, 0x59e05f87, 0x59e15f91, 0x59e25f99, 0x59e35f9e, 0x59e45f98, 0x59e55fa0, 0x59e65fa8,
+    0x59e75fad, 0x59e85fb7, 0x59e95fbc, 0x59ea5fd6, 0x59eb5ffb, 0x59ec5fe4, 0x59ed5ff8, 0x59ee5ff1,
+    0x59ef5ff0, 0x59f05fdd, 0x59f15fde, 0x59f25fff, 0x59f36021, 0x59f46019, 0x59f56010, 0x59f66029,
+    0x59f7600e, 0x59f86031, 0x59f9601b, 0x59fa6015, 0x59fb602b, 0x59fc6026, 0x59fd600f, 0x59fe603a,
+    0x5a41605a, 0x5a426041, 0x5a436060, 0x5a44605d, 0x5a45606a, 0x5a466077, 0x5a47605f, 0x5a48604a,
+    0x5a496046, 0x5a4a604d, 0x5a4b6063, 0x5a4c6043, 0x5a4d6064, 0x5a4e606c, 0x5a4f606b, 0x5a506059,
+    0x5a516085, 0x5a526081, 0x5a536083, 0x5a54609a, 0x5a556084, 0x5a56609b, 0x5a57608a, 0x5a586096,
+    0x5a596097, 0x5a5a6092, 0x5a5b60a7, 0x5a5c608b, 0x5a5d60e1, 0x5a5e60b8, 0x5a5f60de, 0x5a6060e0,
+    0x5a6160d3, 0x5a6260bd, 0x5a6360c6, 0x5a6460b5, 0x5a6560d5, 0x5a6660d8, 0x5a676120, 0x5a6860f2,
+    0x5a696115, 0x5a6a6106, 0x5a6b60f6, 0x5a6c60f7, 0x5a6d6100, 0x5a6e60f4, 0x5a6f60fa, 0x5a706103,
+    0x5a716121, 0x5a7260fb, 0x5a7360f1, 0x5a74610d, 0x5a75610e, 0x5a766111, 0x5a776147, 0x5a78614d,
+    0x5a796137, 0x5a7a6128, 0x5a7b6127, 0x5a7c613e, 0x5a7d614a, 0x5a7e6130, 0x5a7f613c, 0x5a80612c,
+    0x5a816134, 0x5a826165, 0x5a83615d, 0x5a84613d, 0x5a856142, 0x5a866144, 0x5a876173, 0x5a886187,
+    0x5a896177, 0x5a8a6158, 0x5a8b6159, 0x5a8c615a, 0x5a8d616b, 0x5a8e6174, 0x5a8f616f, 0x5a906171,
+    0x5a91615f, 0x5a926153, 0x5a936175, 0x5a946198, 0x5a956199, 0x5a966196, 0x5a9761ac, 0x5a986194,
+    0x5a99618a, 0x5a9a6191, 0x5a9b61ab, 0x5a9c61ae, 0x5a9d61cc, 0x5a9e61ca, 0x5a9f61c9, 0x5aa061c8,
+    0x5aa161c3, 0x5aa261c6, 0x5aa361ba, 0x5aa461cb, 0x5aa57f79, 0x5aa661cd, 0x5aa761e6, 0x5aa861e3,
+    0x5aa961f4, 0x5aaa61f7, 0x5aab61f6, 0x5aac61fd, 0x5aad61fa, 0x5aae61ff, 0x5aaf61fc, 0x5ab061fe,
+    0x5ab16200, 0x5ab26208, 0x5ab36209, 0x5ab4620d, 0x5ab56213, 0x5ab66214, 0x5ab7621b, 0x5ab8621e,
+    0x5ab96221, 0x5aba622a, 0x5abb622e, 0x5abc6230, 0x5abd6232, 0x5abe6233, 0x5abf6241, 0x5ac0624e,
+    0x5ac1625e, 0x5ac26263, 0x5ac3625b, 0x5ac46260, 0x5ac56268, 0x5ac6627c, 0x5ac76282, 0x5ac86289,
+    0x5ac96292, 0x5aca627e, 0x5acb6293, 0x5acc6296, 0x5acd6283, 0x5ace6294, 0x5acf62d7, 0x5ad062d1,
+    0x5ad162bb, 0x5ad262cf, 0x5ad362ac, 0x5ad462c6, 0x5ad562c8, 0x5ad662dc, 0x5ad762d4, 0x5ad862ca,
+    0x5ad962c2, 0x5ada62a6, 0x5adb62c7, 0x5adc629b, 0x5add62c9, 0x5ade630c, 0x5adf62ee, 0x5ae062f1,
+    0x5ae16327, 0x5ae26302, 0x5ae36308, 0x5ae462ef, 0x5ae562f5, 0x5ae662ff, 0x5ae76350, 0x5ae8634d,
+    0x5ae9633e, 0x5aea634f, 0x5aeb6396, 0x5aec638e, 0x5aed6380, 0x5aee63ab, 0x5aef6376, 0x5af063a3,
+    0x5af1638f, 0x5af26389, 0x5af3639f, 0x5af4636b, 0x5af56369, 0x5af663b5, 0x5af763be, 0x5af863e9,
+    0x5af963c0, 0x5afa63c6, 0x5afb63f5, 0x5afc63e3, 0x5afd63c9, 0x5afe63d2, 0x5b4163f6, 0x5b4263c4,
+    0x5b436434, 0x5b446406, 0x5b456413, 0x5b466426, 0x5b476436, 0x5b48641c, 0x5b496417, 0x5b4a6428,
+    0x5b4b640f, 0x5b4c6416, 0x5b4d644e, 0x5b4e6467, 0x5b4f646f, 0x5b506460, 0x5b516476, 0x5b5264b9,
+    0x5b53649d, 0x5b5464ce, 0x5b556495, 0x5b5664bb, 0x5b576493, 0x5b5864a5, 0x5b5964a9, 0x5b5a6488,
+    0x5b5b64bc, 0x5b5c64da, 0x5b5d64d2, 0x5b5e64c5, 0x5b5f64c7, 0x5b6064d4, 0x5b6164d8, 0x5b6264c2,
+    0x5b6364f1, 0x5b6464e7, 0x5b6564e0, 0x5b6664e1, 0x5b6764e3, 0x5b6864ef, 0x5b6964f4, 0x5b6a64f6,
+    0x5b6b64f2, 0x5b6c64fa, 0x5b6d6500, 0x5b6e64fd, 0x5b6f6518, 0x5b70651c, 0x5b71651d, 0x5b726505,
+    0x5b736524, 0x5b746523, 0x5b75652b, 0x5b76652c, 0x5b776534, 0x5b786535, 0x5b796537, 0x5b7a6536,
+    0x5b7b6538, 0x5b7c754b, 0x5b7d6548, 0x5b7e654e, 0x5b7f6556, 0x5b80654d, 0x5b816558, 0x5b826555,
+    0x5b83655d, 0x5b846572, 0x5b856578, 0x5b866582, 0x5b876583, 0x5b888b8a, 0x5b89659b, 0x5b8a659f,
+    0x5b8b65ab, 0x5b8c65b7, 0x5b8d65c3, 0x5b8e65c6, 0x5b8f65c1, 0x5b9065c4, 0x5b9165cc, 0x5b9265d2,
+    0x5b9365d9, 0x5b9465e1, 0x5b9565e0, 0x5b9665f1, 0x5b976600, 0x5b986615, 0x5b996602, 0x5b9a6772,
+    0x5b9b6603, 0x5b9c65fb, 0x5b9d6609, 0x5b9e663f, 0x5b9f6635, 0x5ba0662e, 0x5ba1661e, 0x5ba26634,
+    0x5ba3661c, 0x5ba46624, 0x5ba56644, 0x5ba66649, 0x5ba76665, 0x5ba86657, 0x5ba9665e, 0x5baa6664,
+    0x5bab6659, 0x5bac6662, 0x5bad665d, 0x5baefa12, 0x5baf6673, 0x5bb06670, 0x5bb16683, 0x5bb26688,
+    0x5bb36684, 0x5bb46699, 0x5bb56698, 0x5bb666a0, 0x5bb7669d, 0x5bb866b2, 0x5bb966c4, 0x5bba66c1,
+    0x5bbb66bf, 0x5bbc66c9, 0x5bbd66be, 0x5bbe66bc, 0x5bbf66b8, 0x5bc066d6, 0x5bc166da, 0x5bc266e6,
+    0x5bc366e9, 0x5bc466f0, 0x5bc566f5, 0x5bc666f7, 0x5bc766fa, 0x5bc8670e, 0x5bc9f929, 0x5bca6716,
+    0x5bcb671e, 0x5bcc7e22, 0x5bcd6726, 0x5bce6727, 0x5bcf9738, 0x5bd0672e, 0x5bd1673f, 0x5bd26736,
+    0x5bd36737, 0x5bd46738, 0x5bd56746, 0x5bd6675e, 0x5bd76759, 0x5bd86766, 0x5bd96764, 0x5bda6789,
+    0x5bdb6785, 0x5bdc6770, 0x5bdd67a9, 0x5bde676a, 0x5bdf678b, 0x5be06773, 0x5be167a6, 0x5be267a1,
+    0x5be367bb, 0x5be467b7, 0x5be567ef, 0x5be667b4, 0x5be767ec, 0x5be867e9, 0x5be967b8, 0x5bea67e7,
+    0x5beb67e4, 0x5bec6852, 0x5bed67dd, 0x5bee67e2, 0x5bef67ee, 0x5bf067c0, 0x5bf167ce, 0x5bf267b9,
+    0x5bf36801, 0x5bf467c6, 0x5bf5681e, 0x5bf66846, 0x5bf7684d, 0x5bf86840, 0x5bf96844, 0x5bfa6832,
+    0x5bfb684e, 0x5bfc6863, 0x5bfd6859, 0x5bfe688d, 0x5c416877, 0x5c42687f, 0x5c43689f, 0x5c44687e,
+    0x5c45688f, 0x5c4668ad, 0x5c476894, 0x5c486883, 0x5c4968bc, 0x5c4a68b9, 0x5c4b6874, 0x5c4c68b5,
+    0x5c4d68ba, 0x5c4e690f, 0x5c4f6901, 0x5c5068ca, 0x5c516908, 0x5c5268d8, 0x5c536926, 0x5c5468e1,
+    0x5c55690c, 0x5c5668cd, 0x5c5768d4, 0x5c5868e7, 0x5c5968d5, 0x5c5a6912, 0x5c5b68ef, 0x5c5c6904,
+    0x5c5d68e3, 0x5c5e68e0, 0x5c5f68cf, 0x5c6068c6, 0x5c616922, 0x5c62692a, 0x5c636921, 0x5c646923,
+    0x5c656928, 0x5c66fa13, 0x5c676979, 0x5c686977, 0x5c696936, 0x5c6a6978, 0x5c6b6954, 0x5c6c696a,
+    0x5c6d6974, 0x5c6e6968, 0x5c6f693d, 0x5c706959, 0x5c716930, 0x5c72695e, 0x5c73695d, 0x5c74697e,
+    0x5c756981, 0x5c7669b2, 0x5c7769bf, 0x5c78fa14, 0x5c796998, 0x5c7a69c1, 0x5c7b69d3, 0x5c7c69be,
+    0x5c7d69ce, 0x5c7e5be8, 0x5c7f69ca, 0x5c8069b1, 0x5c8169dd, 0x5c8269bb, 0x5c8369c3, 0x5c8469a0,
+    0x5c85699c, 0x5c866995, 0x5c8769de, 0x5c886a2e, 0x5c8969e8, 0x5c8a6a02, 0x5c8b6a1b, 0x5c8c69ff,
+    0x5c8d69f9, 0x5c8e69f2, 0x5c8f69e7, 0x5c9069e2, 0x5c916a1e, 0x5c9269ed, 0x5c936a14, 0x5c9469eb,
+    0x5c956a0a, 0x5c966a22, 0x5c976a12, 0x5c986a23, 0x5c996a13, 0x5c9a6a30, 0x5c9b6a6b, 0x5c9c6a44,
+    0x5c9d6a0c, 0x5c9e6aa0, 0x5c9f6a36, 0x5ca06a78, 0x5ca16a47, 0x5ca26a62, 0x5ca36a59, 0x5ca46a66,
+    0x5ca56a48, 0x5ca66a46, 0x5ca76a38, 0x5ca86a72, 0x5ca96a73, 0x5caa6a90, 0x5cab6a8d, 0x5cac6a84,
+    0x5cad6aa2, 0x5cae6aa3, 0x5caf6a7e, 0x5cb06a97, 0x5cb16aac, 0x5cb26aaa, 0x5cb36abb, 0x5cb46ac2,
+    0x5cb56ab8, 0x5cb66ab3, 0x5cb76ac1, 0x5cb86ade, 0x5cb96ae2, 0x5cba6ad1, 0x5cbb6ada, 0x5cbc6ae4,
+    0x5cbd8616, 0x5cbe8617, 0x5cbf6aea, 0x5cc06b05, 0x5cc16b0a, 0x5cc26afa, 0x5cc36b12, 0x5cc46b16,
+    0x5cc56b1f, 0x5cc66b38, 0x5cc76b37, 0x5cc86b39, 0x5cc976dc, 0x5cca98ee, 0x5ccb6b47, 0x5ccc6b43,
+    0x5ccd6b49, 0x5cce6b50, 0x5ccf6b59, 0x5cd06b54, 0x5cd16b5b, 0x5cd26b5f, 0x5cd36b61, 0x5cd46b78,
+    0x5cd56b79, 0x5cd66b7f, 0x5cd76b80, 0x5cd86b84, 0x5cd96b83, 0x5cda6b8d, 0x5cdb6b98, 0x5cdc6b95,
+    0x5cdd6b9e, 0x5cde6ba4, 0x5cdf6baa, 0x5ce06bab, 0x5ce16baf, 0x5ce26bb1, 0x5ce36bb2, 0x5ce46bb3,
+    0x5ce56bb7, 0x5ce66bbc, 0x5ce76bc6, 0x5ce86bcb, 0x5ce96bd3, 0x5cea6bd6, 0x5ceb6bdf, 0x5cec6bec,
+    0x5ced6beb, 0x5cee6bf3, 0x5cef6bef, 0x5cf06c08, 0x5cf16c13, 0x5cf26c14, 0x5cf36c1b, 0x5cf46c24,
+    0x5cf56c23, 0x5cf66c3f, 0x5cf76c5e, 0x5cf86c55, 0x5cf96c5c, 0x5cfa6c62, 0x5cfb6c82, 0x5cfc6c8d,
+    0x5cfd6c86, 0x5cfe6c6f, 0x5d416c9a, 0x5d426c81, 0x5d436c9b, 0x5d446c7e, 0x5d456c68, 0x5d466c73,
+    0x5d476c92, 0x5d486c90, 0x5d496cc4, 0x5d4a6cf1, 0x5d4b6cbd, 0x5d4c6cc5, 0x5d4d6cae, 0x5d4e6cda,
+    0x5d4f6cdd, 0x5d506cb1, 0x5d516cbe, 0x5d526cba, 0x5d536cdb, 0x5d546cef, 0x5d556cd9, 0x5d566cea,
+    0x5d576d1f, 0x5d586d04, 0x5d596d36, 0x5d5a6d2b, 0x5d5b6d3d, 0x5d5c6d33, 0x5d5d6d12, 0x5d5e6d0c,
+    0x5d5f6d63, 0x5d606d87, 0x5d616d93, 0x5d626d6f, 0x5d636d64, 0x5d646d5a, 0x5d656d79, 0x5d666d59,
+    0x5d676d8e, 0x5d686d95, 0x5d696d9b, 0x5d6a6d85, 0x5d6b6d96, 0x5d6c6df9, 0x5d6d6e0a, 0x5d6e6e2e,
+    0x5d6f6db5, 0x5d706de6, 0x5d716dc7, 0x5d726dac, 0x5d736db8, 0x5d746dcf, 0x5d756dc6, 0x5d766dec,
+    0x5d776dde, 0x5d786dcc, 0x5d796de8, 0x5d7a6df8, 0x5d7b6dd2, 0x5d7c6dc5, 0x5d7d6dfa, 0x5d7e6dd9,
+    0x5d7f6df2, 0x5d806dfc, 0x5d816de4, 0x5d826dd5, 0x5d836dea, 0x5d846dee, 0x5d856e2d, 0x5d866e6e,
+    0x5d876e19, 0x5d886e72, 0x5d896e5f, 0x5d8a6e39, 0x5d8b6e3e, 0x5d8c6e23, 0x5d8d6e6b, 0x5d8e6e5c,
+    0x5d8f6e2b, 0x5d906e76, 0x5d916e4d, 0x5d926e1f, 0x5d936e27, 0x5d946e43, 0x5d956e3c, 0x5d966e3a,
+    0x5d976e4e, 0x5d986e24, 0x5d996e1d, 0x5d9a6e38, 0x5d9b6e82, 0x5d9c6eaa, 0x5d9d6e98, 0x5d9e6eb7,
+    0x5d9f6ebd, 0x5da06eaf, 0x5da16ec4, 0x5da26eb2, 0x5da36ed4, 0x5da46ed5, 0x5da56e8f, 0x5da66ebf,
+    0x5da76ec2, 0x5da86e9f, 0x5da96f41, 0x5daa6f45, 0x5dab6eec, 0x5dac6ef8, 0x5dad6efe, 0x5dae6f3f,
+    0x5daf6ef2, 0x5db06f31, 0x5db16eef, 0x5db26f32, 0x5db36ecc, 0x5db46eff, 0x5db56f3e, 0x5db66f13,
+    0x5db76ef7, 0x5db86f86, 0x5db96f7a, 0x5dba6f78, 0x5dbb6f80, 0x5dbc6f6f, 0x5dbd6f5b, 0x5dbe6f6d,
+    0x5dbf6f74, 0x5dc06f82, 0x5dc16f88, 0x5dc26f7c, 0x5dc36f58, 0x5dc46fc6, 0x5dc56f8e, 0x5dc66f91,
+    0x5dc76f66, 0x5dc86fb3, 0x5dc96fa3, 0x5dca6fb5, 0x5dcb6fa1, 0x5dcc6fb9, 0x5dcd6fdb, 0x5dce6faa,
+    0x5dcf6fc2, 0x5dd06fdf, 0x5dd16fd5, 0x5dd26fec, 0x5dd36fd8, 0x5dd46fd4, 0x5dd56ff5, 0x5dd66fee,
+    0x5dd77005, 0x5dd87007, 0x5dd97009, 0x5dda700b, 0x5ddb6ffa, 0x5ddc7011, 0x5ddd7001, 0x5dde700f,
+    0x5ddf701b, 0x5de0701a, 0x5de1701f, 0x5de26ff3, 0x5de37028, 0x5de47018, 0x5de57030, 0x5de6703e,
+    0x5de77032, 0x5de87051, 0x5de97063, 0x5dea7085, 0x5deb7099, 0x5dec70af, 0x5ded70ab, 0x5dee70ac,
+    0x5def70b8, 0x5df070ae, 0x5df170df, 0x5df270cb, 0x5df370d9, 0x5df47109, 0x5df5710f, 0x5df67104,
+    0x5df770f1, 0x5df870fd, 0x5df9711c, 0x5dfa7119, 0x5dfb715c, 0x5dfc7146, 0x5dfd7147, 0x5dfe7166,
+    0x5e417162, 0x5e42714c, 0x5e437156, 0x5e44716c, 0x5e457188, 0x5e46718f, 0x5e477184, 0x5e487195,
+    0x5e49fa15, 0x5e4a71ac, 0x5e4b71c1, 0x5e4c71b9, 0x5e4d71be, 0x5e4e71d2, 0x5e4f71e7, 0x5e5071c9,
+    0x5e5171d4, 0x5e5271d7, 0x5e5371ce, 0x5e5471f5, 0x5e5571e0, 0x5e5671ec, 0x5e5771fb, 0x5e5871fc,
+    0x5e5971f9, 0x5e5a71fe, 0x5e5b71ff, 0x5e5c720d, 0x5e5d7210, 0x5e5e7228, 0x5e5f722d, 0x5e60722c,
+    0x5e617230, 0x5e627232, 0x5e63723b, 0x5e64723c, 0x5e65723f, 0x5e667240, 0x5e677246, 0x5e68724b,
+    0x5e697258, 0x5e6a7274, 0x5e6b727e, 0x5e6c7281, 0x5e6d7287, 0x5e6e7282, 0x5e6f7292, 0x5e707296,
+    0x5e7172a2, 0x5e7272a7, 0x5e7372b1, 0x5e7472b2, 0x5e7572be, 0x5e7672c3, 0x5e7772c6, 0x5e7872c4,
+    0x5e7972b9, 0x5e7a72ce, 0x5e7b72d2, 0x5e7c72e2, 0x5e7d72e0, 0x5e7e72e1, 0x5e7f72f9, 0x5e8072f7,
+    0x5e817317, 0x5e82730a, 0x5e83731c, 0x5e847316, 0x5e85731d, 0x5e867324, 0x5e877334, 0x5e887329,
+    0x5e89732f, 0x5e8afa16, 0x5e8b7325, 0x5e8c733e, 0x5e8d734f, 0x5e8e734e, 0x5e8f7357, 0x5e909ed8,
+    0x5e91736a, 0x5e927368, 0x5e937370, 0x5e947377, 0x5e957378, 0x5e967375, 0x5e97737b, 0x5e9873c8,
+    0x5e9973bd, 0x5e9a73b3, 0x5e9b73ce, 0x5e9c73bb, 0x5e9d73c0, 0x5e9e73c9, 0x5e9f73d6, 0x5ea073e5,
+    0x5ea173e3, 0x5ea273d2, 0x5ea373ee, 0x5ea473f1, 0x5ea573de, 0x5ea673f8, 0x5ea77407, 0x5ea873f5,
+    0x5ea97405, 0x5eaa7426, 0x5eab742a, 0x5eac7425, 0x5ead7429, 0x5eae742e, 0x5eaf7432, 0x5eb0743a,
+    0x5eb17455, 0x5eb2743f, 0x5eb3745f, 0x5eb47459, 0x5eb57441, 0x5eb6745c, 0x5eb77469, 0x5eb87470,
+    0x5eb97463, 0x5eba746a, 0x5ebb7464, 0x5ebc7462, 0x5ebd7489, 0x5ebe746f, 0x5ebf747e, 0x5ec0749f,
+    0x5ec1749e, 0x5ec274a2, 0x5ec374a7, 0x5ec474ca, 0x5ec574cf, 0x5ec674d4, 0x5ec774e0, 0x5ec874e3,
+    0x5ec974e7, 0x5eca74e9, 0x5ecb74ee, 0x5ecc74f0, 0x5ecd74f2, 0x5ece74f1, 0x5ecf74f7, 0x5ed074f8,
+    0x5ed17501, 0x5ed27504, 0x5ed37503, 0x5ed47505, 0x5ed5750d, 0x5ed6750c, 0x5ed7750e, 0x5ed87513,
+    0x5ed9751e, 0x5eda7526, 0x5edb752c, 0x5edc753c, 0x5edd7544, 0x5ede754d, 0x5edf754a, 0x5ee07549,
+    0x5ee17546, 0x5ee2755b, 0x5ee3755a, 0x5ee47564, 0x5ee57567, 0x5ee6756b, 0x5ee7756f, 0x5ee87574,
+    0x5ee9756d, 0x5eea7578, 0x5eeb7576, 0x5eec7582, 0x5eed7586, 0x5eee7587, 0x5eef758a, 0x5ef07589,
+    0x5ef17594, 0x5ef2759a, 0x5ef3759d, 0x5ef475a5, 0x5ef575a3, 0x5ef675c2, 0x5ef775b3, 0x5ef875c3,
+    0x5ef975b5, 0x5efa75bd, 0x5efb75b8, 0x5efc75bc, 0x5efd75b1, 0x5efe75cd, 0x5f4175ca, 0x5f4275d2,
+    0x5f4375d9, 0x5f4475e3, 0x5f4575de, 0x5f4675fe, 0x5f4775ff, 0x5f4875fc, 0x5f497601, 0x5f4a75f0,
+    0x5f4b75fa, 0x5f4c75f2, 0x5f4d75f3, 0x5f4e760b, 0x5f4f7609, 0x5f50761f, 0x5f517627, 0x5f527620,
+    0x5f537621, 0x5f547622, 0x5f557624, 0x5f567634, 0x5f577630, 0x5f58763b, 0x5f597647, 0x5f5a7648,
+    0x5f5b7658, 0x5f5c7646, 0x5f5d765c, 0x5f5e7661, 0x5f5f7662, 0x5f607668, 0x5f617669, 0x5f627667,
+    0x5f63766a, 0x5f64766c, 0x5f657670, 0x5f667672, 0x5f677676, 0x5f68767c, 0x5f697682, 0x5f6a7680,
+    0x5f6b7683, 0x5f6c7688, 0x5f6d768b, 0x5f6e7699, 0x5f6f769a, 0x5f70769c, 0x5f71769e, 0x5f72769b,
+    0x5f7376a6, 0x5f7476b0, 0x5f7576b4, 0x5f7676b8, 0x5f7776b9, 0x5f7876ba, 0x5f7976c2, 0x5f7afa17,
+    0x5f7b76cd, 0x5f7c76d6, 0x5f7d76d2, 0x5f7e76de, 0x5f7f76e1, 0x5f8076e5, 0x5f8176ea, 0x5f82862f,
+    0x5f8376fb, 0x5f847708, 0x5f857707, 0x5f867704, 0x5f877724, 0x5f887729, 0x5f897725, 0x5f8a7726,
+    0x5f8b771b, 0x5f8c7737, 0x5f8d7738, 0x5f8e7746, 0x5f8f7747, 0x5f90775a, 0x5f917768, 0x5f92776b,
+    0x5f93775b, 0x5f947765, 0x5f95777f, 0x5f96777e, 0x5f977779, 0x5f98778e, 0x5f99778b, 0x5f9a7791,
+    0x5f9b77a0, 0x5f9c779e, 0x5f9d77b0, 0x5f9e77b6, 0x5f9f77b9, 0x5fa077bf, 0x5fa177bc, 0x5fa277bd,
+    0x5fa377bb, 0x5fa477c7, 0x5fa577cd, 0x5fa677da, 0x5fa777dc, 0x5fa877e3, 0x5fa977ee, 0x5faa52af,
+    0x5fab77fc, 0x5fac780c, 0x5fad7812, 0x5fae7821, 0x5faf783f, 0x5fb07820, 0x5fb17845, 0x5fb2784e,
+    0x5fb37864, 0x5fb47874, 0x5fb5788e, 0x5fb6787a, 0x5fb77886, 0x5fb8789a, 0x5fb9787c, 0x5fba788c,
+    0x5fbb78a3, 0x5fbc78b5, 0x5fbd78aa, 0x5fbe78af, 0x5fbf78d1, 0x5fc078c6, 0x5fc178cb, 0x5fc278d4,
+    0x5fc378be, 0x5fc478bc, 0x5fc578c5, 0x5fc678ca, 0x5fc778ec, 0x5fc878e7, 0x5fc978da, 0x5fca78fd,
+    0x5fcb78f4, 0x5fcc7907, 0x5fcd7911, 0x5fce7919, 0x5fcf792c, 0x5fd0792b, 0x5fd17930, 0x5fd2fa18,
+    0x5fd37940, 0x5fd47960, 0x5fd5fa19, 0x5fd6795f, 0x5fd7795a, 0x5fd87955, 0x5fd9fa1a, 0x5fda797f,
+    0x5fdb798a, 0x5fdc7994, 0x5fddfa1b, 0x5fde799d, 0x5fdf799b, 0x5fe079aa, 0x5fe179b3, 0x5fe279ba,
+    0x5fe379c9, 0x5fe479d5, 0x5fe579e7, 0x5fe679ec, 0x5fe779e3, 0x5fe87a08, 0x5fe97a0d, 0x5fea7a18,
+    0x5feb7a19, 0x5fec7a1f, 0x5fed7a31, 0x5fee7a3e, 0x5fef7a37, 0x5ff07a3b, 0x5ff17a43, 0x5ff27a57,
+    0x5ff37a49, 0x5ff47a62, 0x5ff57a61, 0x5ff67a69, 0x5ff79f9d, 0x5ff87a70, 0x5ff97a79, 0x5ffa7a7d,
+    0x5ffb7a88, 0x5ffc7a95, 0x5ffd7a98, 0x5ffe7a96, 0x60417a97, 0x60427aa9, 0x60437ab0, 0x60447ab6,
+    0x60459083, 0x60467ac3, 0x60477abf, 0x60487ac5, 0x60497ac4, 0x604a7ac7, 0x604b7aca, 0x604c7acd,
+    0x604d7acf, 0x604e7ad2, 0x604f7ad1, 0x60507ad5, 0x60517ad3, 0x60527ad9, 0x60537ada, 0x60547add,
+    0x60557ae1, 0x60567ae2, 0x60577ae6, 0x60587ae7, 0x6059fa1c, 0x605a7aeb, 0x605b7aed, 0x605c7af0,
+    0x605d7af8, 0x605e7b02, 0x605f7b0f, 0x60607b0b, 0x60617b0a, 0x60627b06, 0x60637b33, 0x60647b36,
+    0x60657b19, 0x60667b1e, 0x60677b35, 0x60687b28, 0x60697b50, 0x606a7b4d, 0x606b7b4c, 0x606c7b45,
+    0x606d7b5d, 0x606e7b75, 0x606f7b7a, 0x60707b74, 0x60717b70, 0x60727b71, 0x60737b6e, 0x60747b9d,
+    0x60757b98, 0x60767b9f, 0x60777b8d, 0x60787b9c, 0x60797b9a, 0x607a7b92, 0x607b7b8f, 0x607c7b99,
+    0x607d7bcf, 0x607e7bcb, 0x607f7bcc, 0x60807bb4, 0x60817bc6, 0x60827b9e, 0x60837bdd, 0x60847be9,
+    0x60857be6, 0x60867bf7, 0x60877be5, 0x60887c14, 0x60897c00, 0x608a7c13, 0x608b7c07, 0x608c7bf3,
+    0x608d7c0d, 0x608e7bf6, 0x608f7c23, 0x60907c27, 0x60917c2a, 0x60927c1f, 0x60937c37, 0x60947c2b,
+    0x60957c3d, 0x60967c40, 0x60977c4c, 0x60987c43, 0x60997c56, 0x609a7c50, 0x609b7c58, 0x609c7c5f,
+    0x609d7c65, 0x609e7c6c, 0x609f7c75, 0x60a07c83, 0x60a17c90, 0x60a27ca4, 0x60a37ca2, 0x60a47cab,
+    0x60a57ca1, 0x60a67cad, 0x60a77ca8, 0x60a87cb3, 0x60a97cb2, 0x60aa7cb1, 0x60ab7cae, 0x60ac7cb9,
+    0x60adfa1d, 0x60ae7cbd, 0x60af7cc5, 0x60b07cc2, 0x60b17cd2, 0x60b27ce2, 0x60b37cd8, 0x60b47cdc,
+    0x60b57cef, 0x60b67cf2, 0x60b77cf4, 0x60b87cf6, 0x60b97d06, 0x60ba7d02, 0x60bb7d1c, 0x60bc7d15,
+    0x60bd7d0a, 0x60be7d45, 0x60bf7d4b, 0x60c07d2e, 0x60c17d32, 0x60c27d3f, 0x60c37d35, 0x60c47d48,
+    0x60c57d46, 0x60c67d5c, 0x60c77d73, 0x60c87d56, 0x60c97d4e, 0x60ca7d68, 0x60cb7d6e, 0x60cc7d4f,
+    0x60cd7d63, 0x60ce7d93, 0x60cf7d89, 0x60d07d5b, 0x60d17dae, 0x60d27da3, 0x60d37db5, 0x60d47db7,
+    0x60d57dc7, 0x60d67dbd, 0x60d77dab, 0x60d87da2, 0x60d97daf, 0x60da7da0, 0x60db7db8, 0x60dc7d9f,
+    0x60dd7db0, 0x60de7dd5, 0x60df7dd8, 0x60e07ddd, 0x60e17dd6, 0x60e27de4, 0x60e37dde, 0x60e47dfb,
+    0x60e57e0b, 0x60e67df2, 0x60e77de1, 0x60e87ddc, 0x60e97e05, 0x60ea7e0a, 0x60eb7e21, 0x60ec7e12,
+    0x60ed7e1f, 0x60ee7e09, 0x60ef7e3a, 0x60f07e46, 0x60f17e66, 0x60f27e31, 0x60f37e3d, 0x60f47e35,
+    0x60f57e3b, 0x60f67e39, 0x60f77e43, 0x60f87e37, 0x60f97e32, 0x60fa7e5d, 0x60fb7e56, 0x60fc7e5e,
+    0x60fd7e52, 0x60fe7e59, 0x61417e5a, 0x61427e67, 0x61437e79, 0x61447e6a, 0x61457e69, 0x61467e7c,
+    0x61477e7b, 0x61487e7d, 0x61498fae, 0x614a7e7f, 0x614b7e83, 0x614c7e89, 0x614d7e8e, 0x614e7e8c,
+    0x614f7e92, 0x61507e93, 0x61517e94, 0x61527e96, 0x61537e9b, 0x61547f38, 0x61557f3a, 0x61567f45,
+    0x61577f47, 0x61587f4c, 0x61597f4e, 0x615a7f51, 0x615b7f55, 0x615c7f54, 0x615d7f58, 0x615e7f5f,
+    0x615f7f60, 0x61607f68, 0x61617f67, 0x61627f69, 0x61637f78, 0x61647f82, 0x61657f86, 0x61667f83,
+    0x61677f87, 0x61687f88, 0x61697f8c, 0x616a7f94, 0x616b7f9e, 0x616c7f9d, 0x616d7f9a, 0x616e7fa1,
+    0x616f7fa3, 0x61707faf, 0x61717fae, 0x61727fb2, 0x61737fb9, 0x61747fb6, 0x61757fb8, 0x61768b71,
+    0x6177fa1e, 0x61787fc5, 0x61797fc6, 0x617a7fca, 0x617b7fd5, 0x617c7fe1, 0x617d7fe6, 0x617e7fe9,
+    0x617f7ff3, 0x61807ff9, 0x61818004, 0x6182800b, 0x61838012, 0x61848019, 0x6185801c, 0x61868021,
+    0x61878028, 0x6188803f, 0x6189803b, 0x618a804a, 0x618b8046, 0x618c8052, 0x618d8058, 0x618e805f,
+    0x618f8062, 0x61908068, 0x61918073, 0x61928072, 0x61938070, 0x61948076, 0x61958079, 0x6196807d,
+    0x6197807f, 0x61988084, 0x61998085, 0x619a8093, 0x619b809a, 0x619c80ad, 0x619d5190, 0x619e80ac,
+    0x619f80db, 0x61a080e5, 0x61a180d9, 0x61a280dd, 0x61a380c4, 0x61a480da, 0x61a58109, 0x61a680ef,
+    0x61a780f1, 0x61a8811b, 0x61a98123, 0x61aa812f, 0x61ab814b, 0x61ac8146, 0x61ad813e, 0x61ae8153,
+    0x61af8151, 0x61b080fc, 0x61b18171, 0x61b2816e, 0x61b38165, 0x61b4815f, 0x61b58166, 0x61b68174,
+    0x61b78183, 0x61b88188, 0x61b9818a, 0x61ba8180, 0x61bb8182, 0x61bc81a0, 0x61bd8195, 0x61be81a3,
+    0x61bf8193, 0x61c081b5, 0x61c181a4, 0x61c281a9, 0x61c381b8, 0x61c481b0, 0x61c581c8, 0x61c681be,
+    0x61c781bd, 0x61c881c0, 0x61c981c2, 0x61ca81ba, 0x61cb81c9, 0x61cc81cd, 0x61cd81d1, 0x61ce81d8,
+    0x61cf81d9, 0x61d081da, 0x61d181df, 0x61d281e0, 0x61d381fa, 0x61d481fb, 0x61d581fe, 0x61d68201,
+    0x61d78202, 0x61d88205, 0x61d9820d, 0x61da8210, 0x61db8212, 0x61dc8216, 0x61dd8229, 0x61de822b,
+    0x61df822e, 0x61e08238, 0x61e18233, 0x61e28240, 0x61e38259, 0x61e4825a, 0x61e5825d, 0x61e6825f,
+    0x61e78264, 0x61e88262, 0x61e98268, 0x61ea826a, 0x61eb826b, 0x61ec8271, 0x61ed8277, 0x61ee827e,
+    0x61ef828d, 0x61f08292, 0x61f182ab, 0x61f2829f, 0x61f382bb, 0x61f482ac, 0x61f582e1, 0x61f682e3,
+    0x61f782df, 0x61f88301, 0x61f982d2, 0x61fa82f4, 0x61fb82f3, 0x61fc8303, 0x61fd82fb, 0x61fe82f9,
+    0x624182de, 0x62428306, 0x624382dc, 0x624482fa, 0x62458309, 0x624682d9, 0x62478335, 0x62488362,
+    0x62498334, 0x624a8316, 0x624b8331, 0x624c8340, 0x624d8339, 0x624e8350, 0x624f8345, 0x6250832f,
+    0x6251832b, 0x62528318, 0x6253839a, 0x625483aa, 0x6255839f, 0x625683a2, 0x62578396, 0x62588323,
+    0x6259838e, 0x625a8375, 0x625b837f, 0x625c838a, 0x625d837c, 0x625e83b5, 0x625f8373, 0x62608393,
+    0x626183a0, 0x62628385, 0x62638389, 0x626483a8, 0x626583f4, 0x62668413, 0x626783c7, 0x626883ce,
+    0x626983f7, 0x626a83fd, 0x626b8403, 0x626c83d8, 0x626d840b, 0x626e83c1, 0x626f8407, 0x627083e0,
+    0x627183f2, 0x6272840d, 0x62738420, 0x627483f6, 0x627583bd, 0x627683fb, 0x6277842a, 0x62788462,
+    0x6279843c, 0x627a8484, 0x627b8477, 0x627c846b, 0x627d8479, 0x627e8448, 0x627f846e, 0x62808482,
+    0x62818469, 0x62828446, 0x6283846f, 0x62848438, 0x62858435, 0x628684ca, 0x628784b9, 0x628884bf,
+    0x6289849f, 0x628a84b4, 0x628b84cd, 0x628c84bb, 0x628d84da, 0x628e84d0, 0x628f84c1, 0x629084ad,
+    0x629184c6, 0x629284d6, 0x629384a1, 0x629484d9, 0x629584ff, 0x629684f4, 0x62978517, 0x62988518,
+    0x6299852c, 0x629a851f, 0x629b8515, 0x629c8514, 0x629d8506, 0x629e8553, 0x629f855a, 0x62a08540,
+    0x62a18559, 0x62a28563, 0x62a38558, 0x62a48548, 0x62a58541, 0x62a6854a, 0x62a7854b, 0x62a8856b,
+    0x62a98555, 0x62aa8580, 0x62ab85a4, 0x62ac8588, 0x62ad8591, 0x62ae858a, 0x62af85a8, 0x62b0856d,
+    0x62b18594, 0x62b2859b, 0x62b385ae, 0x62b48587, 0x62b5859c, 0x62b68577, 0x62b7857e, 0x62b88590,
+    0x62b9fa1f, 0x62ba820a, 0x62bb85b0, 0x62bc85c9, 0x62bd85ba, 0x62be85cf, 0x62bf85b9, 0x62c085d0,
+    0x62c185d5, 0x62c285dd, 0x62c385e5, 0x62c485dc, 0x62c585f9, 0x62c6860a, 0x62c78613, 0x62c8860b,
+    0x62c985fe, 0x62ca8622, 0x62cb861a, 0x62cc8630, 0x62cd863f, 0x62cefa20, 0x62cf864d, 0x62d04e55,
+    0x62d18655, 0x62d2865f, 0x62d38667, 0x62d48671, 0x62d58693, 0x62d686a3, 0x62d786a9, 0x62d8868b,
+    0x62d986aa, 0x62da868c, 0x62db86b6, 0x62dc86af, 0x62dd86c4, 0x62de86c6, 0x62df86b0, 0x62e086c9,
+    0x62e186ce, 0x62e2fa21, 0x62e386ab, 0x62e486d4, 0x62e586de, 0x62e686e9, 0x62e786ec, 0x62e886df,
+    0x62e986db, 0x62ea8712, 0x62eb8706, 0x62ec8708, 0x62ed8700, 0x62ee8703, 0x62ef86fb, 0x62f08711,
+    0x62f18709, 0x62f2870d, 0x62f386f9, 0x62f4870a, 0x62f58734, 0x62f6873f, 0x62f7873b, 0x62f88725,
+    0x62f98729, 0x62fa871a, 0x62fb875f, 0x62fc8778, 0x62fd874c, 0x62fe874e, 0x63418774, 0x63428757,
+    0x63438768, 0x63448782, 0x6345876a, 0x63468760, 0x6347876e, 0x63488759, 0x63498753, 0x634a8763,
+    0x634b877f, 0x634c87a2, 0x634d87c6, 0x634e879f, 0x634f87af, 0x635087cb, 0x635187bd, 0x635287c0,
+    0x635387d0, 0x635496d6, 0x635587ab, 0x635687c4, 0x635787b3, 0x635887d2, 0x635987bb, 0x635a87ef,
+    0x635b87f2, 0x635c87e0, 0x635d880e, 0x635e8807, 0x635f880f, 0x63608816, 0x6361880d, 0x636287fe,
+    0x636387f6, 0x636487f7, 0x63658811, 0x63668815, 0x63678822, 0x63688821, 0x63698827, 0x636a8831,
+    0x636b8836, 0x636c8839, 0x636d883b, 0x636e8842, 0x636f8844, 0x6370884d, 0x63718852, 0x63728859,
+    0x6373885e, 0x63748862, 0x6375886b, 0x63768881, 0x6377887e, 0x63788875, 0x6379887d, 0x637a8872,
+    0x637b8882, 0x637c889e, 0x637d8897, 0x637e8892, 0x637f88ae, 0x63808899, 0x638188a2, 0x6382888d,
+    0x638388a4, 0x638488bf, 0x638588b5, 0x638688b1, 0x638788c3, 0x638888c4, 0x638988d4, 0x638a88d8,
+    0x638b88d9, 0x638c88dd, 0x638d88f9, 0x638e8902, 0x638f88fc, 0x639088f5, 0x639188e8, 0x639288f2,
+    0x63938904, 0x6394890c, 0x6395892a, 0x6396891d, 0x6397890a, 0x63988913, 0x6399891e, 0x639a8925,
+    0x639b892b, 0x639c8941, 0x639d893b, 0x639e8936, 0x639f8943, 0x63a08938, 0x63a1894d, 0x63a2894c,
+    0x63a38960, 0x63a4895e, 0x63a58966, 0x63a6896a, 0x63a78964, 0x63a8896d, 0x63a9896f, 0x63aa8974,
+    0x63ab8977, 0x63ac897e, 0x63ad8983, 0x63ae8988, 0x63af898a, 0x63b08993, 0x63b18998, 0x63b289a1,
+    0x63b389a9, 0x63b489a6, 0x63b589ac, 0x63b689af, 0x63b789b2, 0x63b889ba, 0x63b989bf, 0x63ba89bd,
+    0x63bb89c0, 0x63bc89da, 0x63bd89dd, 0x63be89e7, 0x63bf89f4, 0x63c089f8, 0x63c18a03, 0x63c28a16,
+    0x63c38a10, 0x63c48a0c, 0x63c58a12, 0x63c68a1b, 0x63c78a1d, 0x63c88a25, 0x63c98a36, 0x63ca8a41,
+    0x63cb8a37, 0x63cc8a5b, 0x63cd8a52, 0x63ce8a46, 0x63cf8a48, 0x63d08a7c, 0x63d18a6d, 0x63d28a6c,
+    0x63d38a62, 0x63d48a79, 0x63d58a85, 0x63d68a82, 0x63d78a84, 0x63d88aa8, 0x63d98aa1, 0x63da8a91,
+    0x63db8aa5, 0x63dc8aa6, 0x63dd8a9a, 0x63de8aa3, 0x63df8aa7, 0x63e08acc, 0x63e18abe, 0x63e28acd,
+    0x63e38ac2, 0x63e48ada, 0x63e58af3, 0x63e68ae7, 0x63e78ae4, 0x63e88af1, 0x63e98b14, 0x63ea8ae0,
+    0x63eb8ae2, 0x63ec8ae1, 0x63ed8adf, 0x63eefa22, 0x63ef8af6, 0x63f08af7, 0x63f18ade, 0x63f28adb,
+    0x63f38b0c, 0x63f48b07, 0x63f58b1a, 0x63f68b16, 0x63f78b10, 0x63f88b17, 0x63f98b20, 0x63fa8b33,
+    0x63fb8b41, 0x63fc97ab, 0x63fd8b26, 0x63fe8b2b, 0x64418b3e, 0x64428b4c, 0x64438b4f, 0x64448b4e,
+    0x64458b53, 0x64468b49, 0x64478b56, 0x64488b5b, 0x64498b5a, 0x644a8b74, 0x644b8b6b, 0x644c8b5f,
+    0x644d8b6c, 0x644e8b6f, 0x644f8b7d, 0x64508b7f, 0x64518b80, 0x64528b8c, 0x64538b8e, 0x64548b99,
+    0x64558b92, 0x64568b93, 0x64578b96, 0x64588b9a, 0x64598c3a, 0x645a8c41, 0x645b8c3f, 0x645c8c48,
+    0x645d8c4c, 0x645e8c4e, 0x645f8c50, 0x64608c55, 0x64618c62, 0x64628c6c, 0x64638c78, 0x64648c7a,
+    0x64658c7c, 0x64668c82, 0x64678c89, 0x64688c85, 0x64698c8a, 0x646a8c8d, 0x646b8c8e, 0x646c8c98,
+    0x646d8c94, 0x646e621d, 0x646f8cad, 0x64708caa, 0x64718cae, 0x64728cbd, 0x64738cb2, 0x64748cb3,
+    0x64758cc1, 0x64768cb6, 0x64778cc8, 0x64788cce, 0x64798ccd, 0x647a8ce3, 0x647b8cda, 0x647c8cf0,
+    0x647d8cf4, 0x647e8cfd, 0x647f8cfa, 0x64808cfb, 0x64818d07, 0x64828d0a, 0x64838d0f, 0x64848d0d,
+    0x64858d12, 0x64868d10, 0x64878d13, 0x64888d14, 0x64898d16, 0x648a8d67, 0x648b8d6d, 0x648c8d71,
+    0x648d8d76, 0x648efa23, 0x648f8d81, 0x64908dc2, 0x64918dbe, 0x64928dba, 0x64938dcf, 0x64948dda,
+    0x64958dd6, 0x64968dcc, 0x64978ddb, 0x64988dcb, 0x64998dea, 0x649a8deb, 0x649b8ddf, 0x649c8de3,
+    0x649d8dfc, 0x649e8e08, 0x649f8dff, 0x64a08e09, 0x64a18e1d, 0x64a28e1e, 0x64a38e10, 0x64a48e1f,
+    0x64a58e42, 0x64a68e35, 0x64a78e30, 0x64a88e34, 0x64a98e4a, 0x64aa8e47, 0x64ab8e49, 0x64ac8e4c,
+    0x64ad8e50, 0x64ae8e48, 0x64af8e59, 0x64b08e64, 0x64b18e60, 0x64b28e55, 0x64b38e63, 0x64b48e76,
+    0x64b58e72, 0x64b68e87, 0x64b78e7c, 0x64b88e81, 0x64b98e85, 0x64ba8e84, 0x64bb8e8b, 0x64bc8e8a,
+    0x64bd8e93, 0x64be8e91, 0x64bf8e94, 0x64c08e99, 0x64c18ea1, 0x64c28eaa, 0x64c38eb1, 0x64c48ebe,
+    0x64c58ec6, 0x64c68ec5, 0x64c78ec8, 0x64c88ecb, 0x64c98ecf, 0x64ca8edb, 0x64cb8ee3, 0x64cc8efc,
+    0x64cd8efb, 0x64ce8eeb, 0x64cf8efe, 0x64d08f0a, 0x64d18f0c, 0x64d28f05, 0x64d38f15, 0x64d48f12,
+    0x64d58f13, 0x64d68f1c, 0x64d78f19, 0x64d88f1f, 0x64d98f26, 0x64da8f33, 0x64db8f3b, 0x64dc8f39,
+    0x64dd8f45, 0x64de8f42, 0x64df8f3e, 0x64e08f49, 0x64e18f46, 0x64e28f4c, 0x64e38f4e, 0x64e48f57,
+    0x64e58f5c, 0x64e68f62, 0x64e78f63, 0x64e88f64, 0x64e98f9c, 0x64ea8f9f, 0x64eb8fa3, 0x64ec8fa8,
+    0x64ed8fa7, 0x64ee8fad, 0x64ef8faf, 0x64f08fb7, 0x64f1fa24, 0x64f28fda, 0x64f38fe5, 0x64f48fe2,
+    0x64f58fef, 0x64f68fe9, 0x64f78ff4, 0x64f89005, 0x64f98ff9, 0x64fa8ff8, 0x64fb9011, 0x64fc9015,
+    0x64fd900e, 0x64fe9021, 0x6541900d, 0x6542901e, 0x65439016, 0x6544900b, 0x65459027, 0x65469036,
+    0x65479039, 0x6548904f, 0x6549fa25, 0x654a9050, 0x654b9051, 0x654c9052, 0x654d9049, 0x654e903e,
+    0x654f9056, 0x65509058, 0x6551905e, 0x65529068, 0x65539067, 0x6554906f, 0x65559076, 0x655696a8,
+    0x65579072, 0x65589082, 0x6559907d, 0x655a9089, 0x655b9080, 0x655c908f, 0x655d6248, 0x655e90af,
+    0x655f90b1, 0x656090b5, 0x656190e2, 0x656290e4, 0x656390db, 0x656490de, 0x65659102, 0x6566fa26,
+    0x65679115, 0x65689112, 0x65699119, 0x656a9132, 0x656b9127, 0x656c9130, 0x656d914a, 0x656e9156,
+    0x656f9158, 0x65709163, 0x65719165, 0x65729169, 0x65739173, 0x65749172, 0x6575918b, 0x65769189,
+    0x65779182, 0x657891a2, 0x657991ab, 0x657a91af, 0x657b91aa, 0x657c91b5, 0x657d91b4, 0x657e91ba,
+    0x657f91c0, 0x658091c1, 0x658191cb, 0x658291d0, 0x658391da, 0x658491db, 0x658591d7, 0x658691de,
+    0x658791d6, 0x658891df, 0x658991e1, 0x658a91ed, 0x658b91f5, 0x658c91ee, 0x658d91e4, 0x658e91f6,
+    0x658f91e5, 0x65909206, 0x6591921e, 0x659291ff, 0x65939210, 0x65949214, 0x6595920a, 0x6596922c,
+    0x65979215, 0x65989229, 0x65999257, 0x659a9245, 0x659b923a, 0x659c9249, 0x659d9264, 0x659e9240,
+    0x659f923c, 0x65a09248, 0x65a1924e, 0x65a29250, 0x65a39259, 0x65a4923f, 0x65a59251, 0x65a69239,
+    0x65a7924b, 0x65a89267, 0x65a9925a, 0x65aa929c, 0x65ab92a7, 0x65ac9277, 0x65ad9278, 0x65ae9296,
+    0x65af9293, 0x65b0929b, 0x65b19295, 0x65b292e9, 0x65b392cf, 0x65b492e7, 0x65b592d7, 0x65b692d9,
+    0x65b792d0, 0x65b8fa27, 0x65b992d5, 0x65ba92b9, 0x65bb92b7, 0x65bc92e0, 0x65bd92d3, 0x65be933a,
+    0x65bf9335, 0x65c0930f, 0x65c19325, 0x65c292fa, 0x65c39321, 0x65c49344, 0x65c592fb, 0x65c6fa28,
+    0x65c79319, 0x65c8931e, 0x65c992ff, 0x65ca9322, 0x65cb931a, 0x65cc931d, 0x65cd9323, 0x65ce9302,
+    0x65cf933b, 0x65d09370, 0x65d19360, 0x65d2937c, 0x65d3936e, 0x65d49356, 0x65d59357, 0x65d693b9,
+    0x65d793b0, 0x65d893a4, 0x65d993ad, 0x65da9394, 0x65db93c8, 0x65dc93d6, 0x65dd93c6, 0x65de93d7,
+    0x65df93e8, 0x65e093e5, 0x65e193d8, 0x65e293c3, 0x65e393dd, 0x65e493de, 0x65e593d0, 0x65e693e4,
+    0x65e7941a, 0x65e893f8, 0x65e99414, 0x65ea9413, 0x65eb9421, 0x65ec9403, 0x65ed9407, 0x65ee9436,
+    0x65ef942b, 0x65f09431, 0x65f1943a, 0x65f29441, 0x65f39452, 0x65f49445, 0x65f59444, 0x65f69448,
+    0x65f7945b, 0x65f8945a, 0x65f99460, 0x65fa9462, 0x65fb945e, 0x65fc946a, 0x65fd9475, 0x65fe9470,
+    0x66419477, 0x6642947f, 0x6643947d, 0x6644947c, 0x6645947e, 0x66469481, 0x66479582, 0x66489587,
+    0x6649958a, 0x664a9592, 0x664b9594, 0x664c9596, 0x664d9598, 0x664e9599, 0x664f95a0, 0x665095a8,
+    0x665195a7, 0x665295ad, 0x665395bc, 0x665495bb, 0x665595b9, 0x665695be, 0x665795ca, 0x66586ff6,
+    0x665995c3, 0x665a95cd, 0x665b95cc, 0x665c95d5, 0x665d95d4, 0x665e95d6, 0x665f95dc, 0x666095e1,
+    0x666195e5, 0x666295e2, 0x66639621, 0x66649628, 0x6665962e, 0x6666962f, 0x66679642, 0x6668964f,
+    0x6669964c, 0x666a964b, 0x666b965c, 0x666c965d, 0x666d965f, 0x666e9666, 0x666f9677, 0x66709672,
+    0x6671966c, 0x6672968d, 0x6673968b, 0x6674f9dc, 0x66759698, 0x66769695, 0x66779697, 0x6678fa29,
+    0x6679969d, 0x667a96a7, 0x667b96aa, 0x667c96b1, 0x667d96b2, 0x667e96b0, 0x667f96af, 0x668096b4,
+    0x668196b6, 0x668296b8, 0x668396b9, 0x668496ce, 0x668596cb, 0x668696d5, 0x668796dc, 0x668896d9,
+    0x668996f9, 0x668a9704, 0x668b9706, 0x668c9708, 0x668d9719, 0x668e970d, 0x668f9713, 0x6690970e,
+    0x66919711, 0x6692970f, 0x66939716, 0x66949724, 0x6695972a, 0x66969730, 0x66979733, 0x66989739,
+    0x6699973b, 0x669a973d, 0x669b973e, 0x669c9746, 0x669d9744, 0x669e9743, 0x669f9748, 0x66a09742,
+    0x66a19749, 0x66a2974d, 0x66a3974f, 0x66a49751, 0x66a59755, 0x66a6975c, 0x66a79760, 0x66a89764,
+    0x66a99766, 0x66aa9768, 0x66ab976d, 0x66ac9779, 0x66ad9785, 0x66ae977c, 0x66af9781, 0x66b0977a,
+    0x66b1978b, 0x66b2978f, 0x66b39790, 0x66b4979c, 0x66b597a8, 0x66b697a6, 0x66b797a3, 0x66b897b3,
+    0x66b997b4, 0x66ba97c3, 0x66bb97c6, 0x66bc97c8, 0x66bd97cb, 0x66be97dc, 0x66bf97ed, 0x66c097f2,
+    0x66c17adf, 0x66c297f5, 0x66c3980f, 0x66c4981a, 0x66c59824, 0x66c69821, 0x66c79837, 0x66c8983d,
+    0x66c9984f, 0x66ca984b, 0x66cb9857, 0x66cc9865, 0x66cd986b, 0x66ce986f, 0x66cf9870, 0x66d09871,
+    0x66d19874, 0x66d29873, 0x66d398aa, 0x66d498af, 0x66d598b1, 0x66d698b6, 0x66d798c4, 0x66d898c3,
+    0x66d998c6, 0x66da98dc, 0x66db98ed, 0x66dc98e9, 0x66ddfa2a, 0x66de98eb, 0x66dffa2b, 0x66e09903,
+    0x66e1991d, 0x66e29912, 0x66e39914, 0x66e49918, 0x66e59927, 0x66e6fa2c, 0x66e79921, 0x66e8991e,
+    0x66e99924, 0x66ea9920, 0x66eb992c, 0x66ec992e, 0x66ed993d, 0x66ee993e, 0x66ef9942, 0x66f09949,
+    0x66f19945, 0x66f29950, 0x66f3994b, 0x66f49951, 0x66f5994c, 0x66f69955, 0x66f79997, 0x66f89998,
+    0x66f9999e, 0x66fa99a5, 0x66fb99ad, 0x66fc99ae, 0x66fd99bc, 0x66fe99df, 0x674199db, 0x674299dd,
+    0x674399d8, 0x674499d1, 0x674599ed, 0x674699ee, 0x674799e2, 0x674899f1, 0x674999f2, 0x674a99fb,
+    0x674b99f8, 0x674c9a01, 0x674d9a0f, 0x674e9a05, 0x674f9a19, 0x67509a2b, 0x67519a37, 0x67529a40,
+    0x67539a45, 0x67549a42, 0x67559a43, 0x67569a3e, 0x67579a55, 0x67589a4d, 0x67599a4e, 0x675a9a5b,
+    0x675b9a57, 0x675c9a5f, 0x675d9a62, 0x675e9a69, 0x675f9a65, 0x67609a64, 0x67619a6a, 0x67629a6b,
+    0x67639aad, 0x67649ab0, 0x67659abc, 0x67669ac0, 0x67679acf, 0x67689ad3, 0x67699ad4, 0x676a9ad1,
+    0x676b9ad9, 0x676c9adc, 0x676d9ade, 0x676e9adf, 0x676f9ae2, 0x67709ae3, 0x67719ae6, 0x67729aef,
+    0x67739aeb, 0x67749aee, 0x67759af4, 0x67769af1, 0x67779af7, 0x67789afb, 0x67799b06, 0x677a9b18,
+    0x677b9b1a, 0x677c9b1f, 0x677d9b22, 0x677e9b23, 0x677f9b25, 0x67809b27, 0x67819b28, 0x67829b29,
+    0x67839b2a, 0x67849b2e, 0x67859b2f, 0x67869b31, 0x67879b32, 0x67889b3b, 0x67899b44, 0x678a9b43,
+    0x678b9b4d, 0x678c9b4e, 0x678d9b51, 0x678e9b58, 0x678f9b75, 0x67909b74, 0x67919b72, 0x67929b93,
+    0x67939b8f, 0x67949b83, 0x67959b91, 0x67969b96, 0x67979b97, 0x67989b9f, 0x67999ba0, 0x679a9ba8,
+    0x679b9bb1, 0x679c9bb4, 0x679d9bc0, 0x679e9bca, 0x679f9bbb, 0x67a09bb9, 0x67a19bc6, 0x67a29bcf,
+    0x67a39bd1, 0x67a49bd2, 0x67a59be3, 0x67a69be2, 0x67a79be4, 0x67a89bd4, 0x67a99be1, 0x67aa9bf5,
+    0x67ab9bf1, 0x67ac9bf2, 0x67ad9c04, 0x67ae9c1b, 0x67af9c15, 0x67b09c14, 0x67b19c00, 0x67b29c09,
+    0x67b39c13, 0x67b49c0c, 0x67b59c06, 0x67b69c08, 0x67b79c12, 0x67b89c0a, 0x67b99c2e, 0x67ba9c25,
+    0x67bb9c24, 0x67bc9c21, 0x67bd9c30, 0x67be9c47, 0x67bf9c32, 0x67c09c46, 0x67c19c3e, 0x67c29c5a,
+    0x67c39c60, 0x67c49c67, 0x67c59c76, 0x67c69c78, 0x67c79ceb, 0x67c89ce7, 0x67c99cec, 0x67ca9cf0,
+    0x67cb9d09, 0x67cc9d03, 0x67cd9d06, 0x67ce9d2a, 0x67cf9d26, 0x67d09d2c, 0x67d19d23, 0x67d29d1f,
+    0x67d39d15, 0x67d49d12, 0x67d59d41, 0x67d69d3f, 0x67d79d44, 0x67d89d3e, 0x67d99d46, 0x67da9d48,
+    0x67db9d5d, 0x67dc9d5e, 0x67dd9d59, 0x67de9d51, 0x67df9d50, 0x67e09d64, 0x67e19d72, 0x67e29d70,
+    0x67e39d87, 0x67e49d6b, 0x67e59d6f, 0x67e69d7a, 0x67e79d9a, 0x67e89da4, 0x67e99da9, 0x67ea9dab,
+    0x67eb9db2, 0x67ec9dc4, 0x67ed9dc1, 0x67ee9dbb, 0x67ef9db8, 0x67f09dba, 0x67f19dc6, 0x67f29dcf,
+    0x67f39dc2, 0x67f4fa2d, 0x67f59dd9, 0x67f69dd3, 0x67f79df8, 0x67f89de6, 0x67f99ded, 0x67fa9def,
+    0x67fb9dfd, 0x67fc9e1a, 0x67fd9e1b, 0x67fe9e19, 0x68419e1e, 0x68429e75, 0x68439e79, 0x68449e7d,
+    0x68459e81, 0x68469e88, 0x68479e8b, 0x68489e8c, 0x68499e95, 0x684a9e91, 0x684b9e9d, 0x684c9ea5,
+    0x684d9eb8, 0x684e9eaa, 0x684f9ead, 0x68509ebc, 0x68519ebe, 0x68529761, 0x68539ecc, 0x68549ecf,
+    0x68559ed0, 0x68569ed1, 0x68579ed4, 0x68589edc, 0x68599ede, 0x685a9edd, 0x685b9ee0, 0x685c9ee5,
+    0x685d9ee8, 0x685e9eef, 0x685f9ef4, 0x68609ef6, 0x68619ef7, 0x68629ef9, 0x68639efb, 0x68649efc,
+    0x68659efd, 0x68669f07, 0x68679f08, 0x686876b7, 0x68699f15, 0x686a9f21, 0x686b9f2c, 0x686c9f3e,
+    0x686d9f4a, 0x686e9f4e, 0x686f9f4f, 0x68709f52, 0x68719f54, 0x68729f63, 0x68739f5f, 0x68749f60,
+    0x68759f61, 0x68769f66, 0x68779f67, 0x68789f6c, 0x68799f6a, 0x687a9f77, 0x687b9f72, 0x687c9f76,
+    0x687d9f95, 0x687e9f9c, 0x687f9fa0, 0x68805c2d, 0x688169d9, 0x68829065, 0x68837476, 0x688451dc,
+    0x68857155, 0x6941e000, 0x6942e001, 0x6943e002, 0x6944e003, 0x6945e004, 0x6946e005, 0x6947e006,
+    0x6948e007, 0x6949e008, 0x694ae009, 0x694be00a, 0x694ce00b, 0x694de00c, 0x694ee00d, 0x694fe00e,
+    0x6950e00f, 0x6951e010, 0x6952e011, 0x6953e012, 0x6954e013, 0x6955e014, 0x6956e015, 0x6957e016,
+    0x6958e017, 0x6959e018, 0x695ae019, 0x695be01a, 0x695ce01b, 0x695de01c, 0x695ee01d, 0x695fe01e,
+    0x6960e01f, 0x6961e020, 0x6962e021, 0x6963e022, 0x6964e023, 0x6965e024, 0x6966e025, 0x6967e026,
+    0x6968e027, 0x6969e028, 0x696ae029, 0x696be02a, 0x696ce02b, 0x696de02c, 0x696ee02d, 0x696fe02e,
+    0x6970e02f, 0x6971e030, 0x6972e031, 0x6973e032, 0x6974e033, 0x6975e034, 0x6976e035, 0x6977e036,
+    0x6978e037, 0x6979e038, 0x697ae039, 0x697be03a, 0x697ce03b, 0x697de03c, 0x697ee03d, 0x697fe03e,
+    0x6980e03f, 0x6981e040, 0x6982e041, 0x6983e042, 0x6984e043, 0x6985e044, 0x6986e045, 0x6987e046,
+    0x6988e047, 0x6989e048, 0x698ae049, 0x698be04a, 0x698ce04b, 0x698de04c, 0x698ee04d, 0x698fe04e,
+    0x6990e04f, 0x6991e050, 0x6992e051, 0x6993e052, 0x6994e053, 0x6995e054, 0x6996e055, 0x6997e056,
+    0x6998e057, 0x6999e058, 0x699ae059, 0x699be05a, 0x699ce05b, 0x699de05c, 0x699ee05d, 0x699fe05e,
+    0x69a0e05f, 0x69a1e060, 0x69a2e061, 0x69a3e062, 0x69a4e063, 0x69a5e064, 0x69a6e065, 0x69a7e066,
+    0x69a8e067, 0x69a9e068, 0x69aae069, 0x69abe06a, 0x69ace06b, 0x69ade06c, 0x69aee06d, 0x69afe06e,
+    0x69b0e06f, 0x69b1e070, 0x69b2e071, 0x69b3e072, 0x69b4e073, 0x69b5e074, 0x69b6e075, 0x69b7e076,
+    0x69b8e077, 0x69b9e078, 0x69bae079, 0x69bbe07a, 0x69bce07b, 0x69bde07c, 0x69bee07d, 0x69bfe07e,
+    0x69c0e07f, 0x69c1e080, 0x69c2e081, 0x69c3e082, 0x69c4e083, 0x69c5e084, 0x69c6e085, 0x69c7e086,
+    0x69c8e087, 0x69c9e088, 0x69cae089, 0x69cbe08a, 0x69cce08b, 0x69cde08c, 0x69cee08d, 0x69cfe08e,
+    0x69d0e08f, 0x69d1e090, 0x69d2e091, 0x69d3e092, 0x69d4e093, 0x69d5e094, 0x69d6e095, 0x69d7e096,
+    0x69d8e097, 0x69d9e098, 0x69dae099, 0x69dbe09a, 0x69dce09b, 0x69dde09c, 0x69dee09d, 0x69dfe09e,
+    0x69e0e09f, 0x69e1e0a0, 0x69e2e0a1, 0x69e3e0a2, 0x69e4e0a3, 0x69e5e0a4, 0x69e6e0a5, 0x69e7e0a6,
+    0x69e8e0a7, 0x69e9e0a8, 0x69eae0a9, 0x69ebe0aa, 0x69ece0ab, 0x69ede0ac, 0x69eee0ad, 0x69efe0ae,
+    0x69f0e0af, 0x69f1e0b0, 0x69f2e0b1, 0x69f3e0b2, 0x69f4e0b3, 0x69f5e0b4, 0x69f6e0b5, 0x69f7e0b6,
+    0x69f8e0b7, 0x69f9e0b8, 0x69fae0b9, 0x69fbe0ba, 0x69fce0bb, 0x69fde0bc, 0x69fee0bd, 0x6a41e0be,
+    0x6a42e0bf, 0x6a43e0c0, 0x6a44e0c1, 0x6a45e0c2, 0x6a46e0c3, 0x6a47e0c4, 0x6a48e0c5, 0x6a49e0c6,
+    0x6a4ae0c7, 0x6a4be0c8, 0x6a4ce0c9, 0x6a4de0ca, 0x6a4ee0cb, 0x6a4fe0cc, 0x6a50e0cd, 0x6a51e0ce,
+    0x6a52e0cf, 0x6a53e0d0, 0x6a54e0d1, 0x6a55e0d2, 0x6a56e0d3, 0x6a57e0d4, 0x6a58e0d5, 0x6a59e0d6,
+    0x6a5ae0d7, 0x6a5be0d8, 0x6a5ce0d9, 0x6a5de0da, 0x6a5ee0db, 0x6a5fe0dc, 0x6a60e0dd, 0x6a61e0de,
+    0x6a62e0df, 0x6a63e0e0, 0x6a64e0e1, 0x6a65e0e2, 0x6a66e0e3, 0x6a67e0e4, 0x6a68e0e5, 0x6a69e0e6,
+    0x6a6ae0e7, 0x6a6be0e8, 0x6a6ce0e9, 0x6a6de0ea, 0x6a6ee0eb, 0x6a6fe0ec, 0x6a70e0ed, 0x6a71e0ee,
+    0x6a72e0ef, 0x6a73e0f0, 0x6a74e0f1, 0x6a75e0f2, 0x6a76e0f3, 0x6a77e0f4, 0x6a78e0f5, 0x6a79e0f6,
+    0x6a7ae0f7, 0x6a7be0f8, 0x6a7ce0f9, 0x6a7de0fa, 0x6a7ee0fb, 0x6a7fe0fc, 0x6a80e0fd, 0x6a81e0fe,
+    0x6a82e0ff, 0x6a83e100, 0x6a84e101, 0x6a85e102, 0x6a86e103, 0x6a87e104, 0x6a88e105, 0x6a89e106,
+    0x6a8ae107, 0x6a8be108, 0x6a8ce109, 0x6a8de10a, 0x6a8ee10b, 0x6a8fe10c, 0x6a90e10d, 0x6a91e10e,
+    0x6a92e10f, 0x6a93e110, 0x6a94e111, 0x6a95e112, 0x6a96e113, 0x6a97e114, 0x6a98e115, 0x6a99e116,
+    0x6a9ae117, 0x6a9be118, 0x6a9ce119, 0x6a9de11a, 0x6a9ee11b, 0x6a9fe11c, 0x6aa0e11d, 0x6aa1e11e,
+    0x6aa2e11f, 0x6aa3e120, 0x6aa4e121, 0x6aa5e122, 0x6aa6e123, 0x6aa7e124, 0x6aa8e125, 0x6aa9e126,
+    0x6aaae127, 0x6aabe128, 0x6aace129, 0x6aade12a, 0x6aaee12b, 0x6aafe12c, 0x6ab0e12d, 0x6ab1e12e,
+    0x6ab2e12f, 0x6ab3e130, 0x6ab4e131, 0x6ab5e132, 0x6ab6e133, 0x6ab7e134, 0x6ab8e135, 0x6ab9e136,
+    0x6abae137, 0x6abbe138, 0x6abce139, 0x6abde13a, 0x6abee13b, 0x6abfe13c, 0x6ac0e13d, 0x6ac1e13e,
+    0x6ac2e13f, 0x6ac3e140, 0x6ac4e141, 0x6ac5e142, 0x6ac6e143, 0x6ac7e144, 0x6ac8e145, 0x6ac9e146,
+    0x6acae147, 0x6acbe148, 0x6acce149, 0x6acde14a, 0x6acee14b, 0x6acfe14c, 0x6ad0e14d, 0x6ad1e14e,
+    0x6ad2e14f, 0x6ad3e150, 0x6ad4e151, 0x6ad5e152, 0x6ad6e153, 0x6ad7e154, 0x6ad8e155, 0x6ad9e156,
+    0x6adae157, 0x6adbe158, 0x6adce159, 0x6adde15a, 0x6adee15b, 0x6adfe15c, 0x6ae0e15d, 0x6ae1e15e,
+    0x6ae2e15f, 0x6ae3e160, 0x6ae4e161, 0x6ae5e162, 0x6ae6e163, 0x6ae7e164, 0x6ae8e165, 0x6ae9e166,
+    0x6aeae167, 0x6aebe168, 0x6aece169, 0x6aede16a, 0x6aeee16b, 0x6aefe16c, 0x6af0e16d, 0x6af1e16e,
+    0x6af2e16f, 0x6af3e170, 0x6af4e171, 0x6af5e172, 0x6af6e173, 0x6af7e174, 0x6af8e175, 0x6af9e176,
+    0x6afae177, 0x6afbe178, 0x6afce179, 0x6afde17a, 0x6afee17b, 0x6b41e17c, 0x6b42e17d, 0x6b43e17e,
+    0x6b44e17f, 0x6b45e180, 0x6b46e181, 0x6b47e182, 0x6b48e183, 0x6b49e184, 0x6b4ae185, 0x6b4be186,
+    0x6b4ce187, 0x6b4de188, 0x6b4ee189, 0x6b4fe18a, 0x6b50e18b, 0x6b51e18c, 0x6b52e18d, 0x6b53e18e,
+    0x6b54e18f, 0x6b55e190, 0x6b56e191, 0x6b57e192, 0x6b58e193, 0x6b59e194, 0x6b5ae195, 0x6b5be196,
+    0x6b5ce197, 0x6b5de198, 0x6b5ee199, 0x6b5fe19a, 0x6b60e19b, 0x6b61e19c, 0x6b62e19d, 0x6b63e19e,
+    0x6b64e19f, 0x6b65e1a0, 0x6b66e1a1, 0x6b67e1a2, 0x6b68e1a3, 0x6b69e1a4, 0x6b6ae1a5, 0x6b6be1a6,
+    0x6b6ce1a7, 0x6b6de1a8, 0x6b6ee1a9, 0x6b6fe1aa, 0x6b70e1ab, 0x6b71e1ac, 0x6b72e1ad, 0x6b73e1ae,
+    0x6b74e1af, 0x6b75e1b0, 0x6b76e1b1, 0x6b77e1b2, 0x6b78e1b3, 0x6b79e1b4, 0x6b7ae1b5, 0x6b7be1b6,
+    0x6b7ce1b7, 0x6b7de1b8, 0x6b7ee1b9, 0x6b7fe1ba, 0x6b80e1bb, 0x6b81e1bc, 0x6b82e1bd, 0x6b83e1be,
+    0x6b84e1bf, 0x6b85e1c0, 0x6b86e1c1, 0x6b87e1c2, 0x6b88e1c3, 0x6b89e1c4, 0x6b8ae1c5, 0x6b8be1c6,
+    0x6b8ce1c7, 0x6b8de1c8, 0x6b8ee1c9, 0x6b8fe1ca, 0x6b90e1cb, 0x6b91e1cc, 0x6b92e1cd, 0x6b93e1ce,
+    0x6b94e1cf, 0x6b95e1d0, 0x6b96e1d1, 0x6b97e1d2, 0x6b98e1d3, 0x6b99e1d4, 0x6b9ae1d5, 0x6b9be1d6,
+    0x6b9ce1d7, 0x6b9de1d8, 0x6b9ee1d9, 0x6b9fe1da, 0x6ba0e1db, 0x6ba1e1dc, 0x6ba2e1dd, 0x6ba3e1de,
+    0x6ba4e1df, 0x6ba5e1e0, 0x6ba6e1e1, 0x6ba7e1e2, 0x6ba8e1e3, 0x6ba9e1e4, 0x6baae1e5, 0x6babe1e6,
+    0x6bace1e7, 0x6bade1e8, 0x6baee1e9, 0x6bafe1ea, 0x6bb0e1eb, 0x6bb1e1ec, 0x6bb2e1ed, 0x6bb3e1ee,
+    0x6bb4e1ef, 0x6bb5e1f0, 0x6bb6e1f1, 0x6bb7e1f2, 0x6bb8e1f3, 0x6bb9e1f4, 0x6bbae1f5, 0x6bbbe1f6,
+    0x6bbce1f7, 0x6bbde1f8, 0x6bbee1f9, 0x6bbfe1fa, 0x6bc0e1fb, 0x6bc1e1fc, 0x6bc2e1fd, 0x6bc3e1fe,
+    0x6bc4e1ff, 0x6bc5e200, 0x6bc6e201, 0x6bc7e202, 0x6bc8e203, 0x6bc9e204, 0x6bcae205, 0x6bcbe206,
+    0x6bcce207, 0x6bcde208, 0x6bcee209, 0x6bcfe20a, 0x6bd0e20b, 0x6bd1e20c, 0x6bd2e20d, 0x6bd3e20e,
+    0x6bd4e20f, 0x6bd5e210, 0x6bd6e211, 0x6bd7e212, 0x6bd8e213, 0x6bd9e214, 0x6bdae215, 0x6bdbe216,
+    0x6bdce217, 0x6bdde218, 0x6bdee219, 0x6bdfe21a, 0x6be0e21b, 0x6be1e21c, 0x6be2e21d, 0x6be3e21e,
+    0x6be4e21f, 0x6be5e220, 0x6be6e221, 0x6be7e222, 0x6be8e223, 0x6be9e224, 0x6beae225, 0x6bebe226,
+    0x6bece227, 0x6bede228, 0x6beee229, 0x6befe22a, 0x6bf0e22b, 0x6bf1e22c, 0x6bf2e22d, 0x6bf3e22e,
+    0x6bf4e22f, 0x6bf5e230, 0x6bf6e231, 0x6bf7e232, 0x6bf8e233, 0x6bf9e234, 0x6bfae235, 0x6bfbe236,
+    0x6bfce237, 0x6bfde238, 0x6bfee239, 0x6c41e23a, 0x6c42e23b, 0x6c43e23c, 0x6c44e23d, 0x6c45e23e,
+    0x6c46e23f, 0x6c47e240, 0x6c48e241, 0x6c49e242, 0x6c4ae243, 0x6c4be244, 0x6c4ce245, 0x6c4de246,
+    0x6c4ee247, 0x6c4fe248, 0x6c50e249, 0x6c51e24a, 0x6c52e24b, 0x6c53e24c, 0x6c54e24d, 0x6c55e24e,
+    0x6c56e24f, 0x6c57e250, 0x6c58e251, 0x6c59e252, 0x6c5ae253, 0x6c5be254, 0x6c5ce255, 0x6c5de256,
+    0x6c5ee257, 0x6c5fe258, 0x6c60e259, 0x6c61e25a, 0x6c62e25b, 0x6c63e25c, 0x6c64e25d, 0x6c65e25e,
+    0x6c66e25f, 0x6c67e260, 0x6c68e261, 0x6c69e262, 0x6c6ae263, 0x6c6be264, 0x6c6ce265, 0x6c6de266,
+    0x6c6ee267, 0x6c6fe268, 0x6c70e269, 0x6c71e26a, 0x6c72e26b, 0x6c73e26c, 0x6c74e26d, 0x6c75e26e,
+    0x6c76e26f, 0x6c77e270, 0x6c78e271, 0x6c79e272, 0x6c7ae273, 0x6c7be274, 0x6c7ce275, 0x6c7de276,
+    0x6c7ee277, 0x6c7fe278, 0x6c80e279, 0x6c81e27a, 0x6c82e27b, 0x6c83e27c, 0x6c84e27d, 0x6c85e27e,
+    0x6c86e27f, 0x6c87e280, 0x6c88e281, 0x6c89e282, 0x6c8ae283, 0x6c8be284, 0x6c8ce285, 0x6c8de286,
+    0x6c8ee287, 0x6c8fe288, 0x6c90e289, 0x6c91e28a, 0x6c92e28b, 0x6c93e28c, 0x6c94e28d, 0x6c95e28e,
+    0x6c96e28f, 0x6c97e290, 0x6c98e291, 0x6c99e292, 0x6c9ae293, 0x6c9be294, 0x6c9ce295, 0x6c9de296,
+    0x6c9ee297, 0x6c9fe298, 0x6ca0e299, 0x6ca1e29a, 0x6ca2e29b, 0x6ca3e29c, 0x6ca4e29d, 0x6ca5e29e,
+    0x6ca6e29f, 0x6ca7e2a0, 0x6ca8e2a1, 0x6ca9e2a2, 0x6caae2a3, 0x6cabe2a4, 0x6cace2a5, 0x6cade2a6,
+    0x6caee2a7, 0x6cafe2a8, 0x6cb0e2a9, 0x6cb1e2aa, 0x6cb2e2ab, 0x6cb3e2ac, 0x6cb4e2ad, 0x6cb5e2ae,
+    0x6cb6e2af, 0x6cb7e2b0, 0x6cb8e2b1, 0x6cb9e2b2, 0x6cbae2b3, 0x6cbbe2b4, 0x6cbce2b5, 0x6cbde2b6,
+    0x6cbee2b7, 0x6cbfe2b8, 0x6cc0e2b9, 0x6cc1e2ba, 0x6cc2e2bb, 0x6cc3e2bc, 0x6cc4e2bd, 0x6cc5e2be,
+    0x6cc6e2bf, 0x6cc7e2c0, 0x6cc8e2c1, 0x6cc9e2c2, 0x6ccae2c3, 0x6ccbe2c4, 0x6ccce2c5, 0x6ccde2c6,
+    0x6ccee2c7, 0x6ccfe2c8, 0x6cd0e2c9, 0x6cd1e2ca, 0x6cd2e2cb, 0x6cd3e2cc, 0x6cd4e2cd, 0x6cd5e2ce,
+    0x6cd6e2cf, 0x6cd7e2d0, 0x6cd8e2d1, 0x6cd9e2d2, 0x6cdae2d3, 0x6cdbe2d4, 0x6cdce2d5, 0x6cdde2d6,
+    0x6cdee2d7, 0x6cdfe2d8, 0x6ce0e2d9, 0x6ce1e2da, 0x6ce2e2db, 0x6ce3e2dc, 0x6ce4e2dd, 0x6ce5e2de,
+    0x6ce6e2df, 0x6ce7e2e0, 0x6ce8e2e1, 0x6ce9e2e2, 0x6ceae2e3, 0x6cebe2e4, 0x6cece2e5, 0x6cede2e6,
+    0x6ceee2e7, 0x6cefe2e8, 0x6cf0e2e9, 0x6cf1e2ea, 0x6cf2e2eb, 0x6cf3e2ec, 0x6cf4e2ed, 0x6cf5e2ee,
+    0x6cf6e2ef, 0x6cf7e2f0, 0x6cf8e2f1, 0x6cf9e2f2, 0x6cfae2f3, 0x6cfbe2f4, 0x6cfce2f5, 0x6cfde2f6,
+    0x6cfee2f7, 0x6d41e2f8, 0x6d42e2f9, 0x6d43e2fa, 0x6d44e2fb, 0x6d45e2fc, 0x6d46e2fd, 0x6d47e2fe,
+    0x6d48e2ff, 0x6d49e300, 0x6d4ae301, 0x6d4be302, 0x6d4ce303, 0x6d4de304, 0x6d4ee305, 0x6d4fe306,
+    0x6d50e307, 0x6d51e308, 0x6d52e309, 0x6d53e30a, 0x6d54e30b, 0x6d55e30c, 0x6d56e30d, 0x6d57e30e,
+    0x6d58e30f, 0x6d59e310, 0x6d5ae311, 0x6d5be312, 0x6d5ce313, 0x6d5de314, 0x6d5ee315, 0x6d5fe316,
+    0x6d60e317, 0x6d61e318, 0x6d62e319, 0x6d63e31a, 0x6d64e31b, 0x6d65e31c, 0x6d66e31d, 0x6d67e31e,
+    0x6d68e31f, 0x6d69e320, 0x6d6ae321, 0x6d6be322, 0x6d6ce323, 0x6d6de324, 0x6d6ee325, 0x6d6fe326,
+    0x6d70e327, 0x6d71e328, 0x6d72e329, 0x6d73e32a, 0x6d74e32b, 0x6d75e32c, 0x6d76e32d, 0x6d77e32e,
+    0x6d78e32f, 0x6d79e330, 0x6d7ae331, 0x6d7be332, 0x6d7ce333, 0x6d7de334, 0x6d7ee335, 0x6d7fe336,
+    0x6d80e337, 0x6d81e338, 0x6d82e339, 0x6d83e33a, 0x6d84e33b, 0x6d85e33c, 0x6d86e33d, 0x6d87e33e,
+    0x6d88e33f, 0x6d89e340, 0x6d8ae341, 0x6d8be342, 0x6d8ce343, 0x6d8de344, 0x6d8ee345, 0x6d8fe346,
+    0x6d90e347, 0x6d91e348, 0x6d92e349, 0x6d93e34a, 0x6d94e34b, 0x6d95e34c, 0x6d96e34d, 0x6d97e34e,
+    0x6d98e34f, 0x6d99e350, 0x6d9ae351, 0x6d9be352, 0x6d9ce353, 0x6d9de354, 0x6d9ee355, 0x6d9fe356,
+    0x6da0e357, 0x6da1e358, 0x6da2e359, 0x6da3e35a, 0x6da4e35b, 0x6da5e35c, 0x6da6e35d, 0x6da7e35e,
+    0x6da8e35f, 0x6da9e360, 0x6daae361, 0x6dabe362, 0x6dace363, 0x6dade364, 0x6daee365, 0x6dafe366,
+    0x6db0e367, 0x6db1e368, 0x6db2e369, 0x6db3e36a, 0x6db4e36b, 0x6db5e36c, 0x6db6e36d, 0x6db7e36e,
+    0x6db8e36f, 0x6db9e370, 0x6dbae371, 0x6dbbe372, 0x6dbce373, 0x6dbde374, 0x6dbee375, 0x6dbfe376,
+    0x6dc0e377, 0x6dc1e378, 0x6dc2e379, 0x6dc3e37a, 0x6dc4e37b, 0x6dc5e37c, 0x6dc6e37d, 0x6dc7e37e,
+    0x6dc8e37f, 0x6dc9e380, 0x6dcae381, 0x6dcbe382, 0x6dcce383, 0x6dcde384, 0x6dcee385, 0x6dcfe386,
+    0x6dd0e387, 0x6dd1e388, 0x6dd2e389, 0x6dd3e38a, 0x6dd4e38b, 0x6dd5e38c, 0x6dd6e38d, 0x6dd7e38e,
+    0x6dd8e38f, 0x6dd9e390, 0x6ddae391, 0x6ddbe392, 0x6ddce393, 0x6ddde394, 0x6ddee395, 0x6ddfe396,
+    0x6de0e397, 0x6de1e398, 0x6de2e399, 0x6de3e39a, 0x6de4e39b, 0x6de5e39c, 0x6de6e39d, 0x6de7e39e,
+    0x6de8e39f, 0x6de9e3a0, 0x6deae3a1, 0x6debe3a2, 0x6dece3a3, 0x6dede3a4, 0x6deee3a5, 0x6defe3a6,
+    0x6df0e3a7, 0x6df1e3a8, 0x6df2e3a9, 0x6df3e3aa, 0x6df4e3ab, 0x6df5e3ac, 0x6df6e3ad, 0x6df7e3ae,
+    0x6df8e3af, 0x6df9e3b0, 0x6dfae3b1, 0x6dfbe3b2, 0x6dfce3b3, 0x6dfde3b4, 0x6dfee3b5, 0x6e41e3b6,
+    0x6e42e3b7, 0x6e43e3b8, 0x6e44e3b9, 0x6e45e3ba, 0x6e46e3bb, 0x6e47e3bc, 0x6e48e3bd, 0x6e49e3be,
+    0x6e4ae3bf, 0x6e4be3c0, 0x6e4ce3c1, 0x6e4de3c2, 0x6e4ee3c3, 0x6e4fe3c4, 0x6e50e3c5, 0x6e51e3c6,
+    0x6e52e3c7, 0x6e53e3c8, 0x6e54e3c9, 0x6e55e3ca, 0x6e56e3cb, 0x6e57e3cc, 0x6e58e3cd, 0x6e59e3ce,
+    0x6e5ae3cf, 0x6e5be3d0, 0x6e5ce3d1, 0x6e5de3d2, 0x6e5ee3d3, 0x6e5fe3d4, 0x6e60e3d5, 0x6e61e3d6,
+    0x6e62e3d7, 0x6e63e3d8, 0x6e64e3d9, 0x6e65e3da, 0x6e66e3db, 0x6e67e3dc, 0x6e68e3dd, 0x6e69e3de,
+    0x6e6ae3df, 0x6e6be3e0, 0x6e6ce3e1, 0x6e6de3e2, 0x6e6ee3e3, 0x6e6fe3e4, 0x6e70e3e5, 0x6e71e3e6,
+    0x6e72e3e7, 0x6e73e3e8, 0x6e74e3e9, 0x6e75e3ea, 0x6e76e3eb, 0x6e77e3ec, 0x6e78e3ed, 0x6e79e3ee,
+    0x6e7ae3ef, 0x6e7be3f0, 0x6e7ce3f1, 0x6e7de3f2, 0x6e7ee3f3, 0x6e7fe3f4, 0x6e80e3f5, 0x6e81e3f6,
+    0x6e82e3f7, 0x6e83e3f8, 0x6e84e3f9, 0x6e85e3fa, 0x6e86e3fb, 0x6e87e3fc, 0x6e88e3fd, 0x6e89e3fe,
+    0x6e8ae3ff, 0x6e8be400, 0x6e8ce401, 0x6e8de402, 0x6e8ee403, 0x6e8fe404, 0x6e90e405, 0x6e91e406,
+    0x6e92e407, 0x6e93e408, 0x6e94e409, 0x6e95e40a, 0x6e96e40b, 0x6e97e40c, 0x6e98e40d, 0x6e99e40e,
+    0x6e9ae40f, 0x6e9be410, 0x6e9ce411, 0x6e9de412, 0x6e9ee413, 0x6e9fe414, 0x6ea0e415, 0x6ea1e416,
+    0x6ea2e417, 0x6ea3e418, 0x6ea4e419, 0x6ea5e41a, 0x6ea6e41b, 0x6ea7e41c, 0x6ea8e41d, 0x6ea9e41e,
+    0x6eaae41f, 0x6eabe420, 0x6eace421, 0x6eade422, 0x6eaee423, 0x6eafe424, 0x6eb0e425, 0x6eb1e426,
+    0x6eb2e427, 0x6eb3e428, 0x6eb4e429, 0x6eb5e42a, 0x6eb6e42b, 0x6eb7e42c, 0x6eb8e42d, 0x6eb9e42e,
+    0x6ebae42f, 0x6ebbe430, 0x6ebce431, 0x6ebde432, 0x6ebee433, 0x6ebfe434, 0x6ec0e435, 0x6ec1e436,
+    0x6ec2e437, 0x6ec3e438, 0x6ec4e439, 0x6ec5e43a, 0x6ec6e43b, 0x6ec7e43c, 0x6ec8e43d, 0x6ec9e43e,
+    0x6ecae43f, 0x6ecbe440, 0x6ecce441, 0x6ecde442, 0x6ecee443, 0x6ecfe444, 0x6ed0e445, 0x6ed1e446,
+    0x6ed2e447, 0x6ed3e448, 0x6ed4e449, 0x6ed5e44a, 0x6ed6e44b, 0x6ed7e44c, 0x6ed8e44d, 0x6ed9e44e,
+    0x6edae44f, 0x6edbe450, 0x6edce451, 0x6edde452, 0x6edee453, 0x6edfe454, 0x6ee0e455, 0x6ee1e456,
+    0x6ee2e457, 0x6ee3e458, 0x6ee4e459, 0x6ee5e45a, 0x6ee6e45b, 0x6ee7e45c, 0x6ee8e45d, 0x6ee9e45e,
+    0x6eeae45f, 0x6eebe460, 0x6eece461, 0x6eede462, 0x6eeee463, 0x6eefe464, 0x6ef0e465, 0x6ef1e466,
+    0x6ef2e467, 0x6ef3e468, 0x6ef4e469, 0x6ef5e46a, 0x6ef6e46b, 0x6ef7e46c, 0x6ef8e46d, 0x6ef9e46e,
+    0x6efae46f, 0x6efbe470, 0x6efce471, 0x6efde472, 0x6efee473, 0x6f41e474, 0x6f42e475, 0x6f43e476,
+    0x6f44e477, 0x6f45e478, 0x6f46e479, 0x6f47e47a, 0x6f48e47b, 0x6f49e47c, 0x6f4ae47d, 0x6f4be47e,
+    0x6f4ce47f, 0x6f4de480, 0x6f4ee481, 0x6f4fe482, 0x6f50e483, 0x6f51e484, 0x6f52e485, 0x6f53e486,
+    0x6f54e487, 0x6f55e488, 0x6f56e489, 0x6f57e48a, 0x6f58e48b, 0x6f59e48c, 0x6f5ae48d, 0x6f5be48e,
+    0x6f5ce48f, 0x6f5de490, 0x6f5ee491, 0x6f5fe492, 0x6f60e493, 0x6f61e494, 0x6f62e495, 0x6f63e496,
+    0x6f64e497, 0x6f65e498, 0x6f66e499, 0x6f67e49a, 0x6f68e49b, 0x6f69e49c, 0x6f6ae49d, 0x6f6be49e,
+    0x6f6ce49f, 0x6f6de4a0, 0x6f6ee4a1, 0x6f6fe4a2, 0x6f70e4a3, 0x6f71e4a4, 0x6f72e4a5, 0x6f73e4a6,
+    0x6f74e4a7, 0x6f75e4a8, 0x6f76e4a9, 0x6f77e4aa, 0x6f78e4ab, 0x6f79e4ac, 0x6f7ae4ad, 0x6f7be4ae,
+    0x6f7ce4af, 0x6f7de4b0, 0x6f7ee4b1, 0x6f7fe4b2, 0x6f80e4b3, 0x6f81e4b4, 0x6f82e4b5, 0x6f83e4b6,
+    0x6f84e4b7, 0x6f85e4b8, 0x6f86e4b9, 0x6f87e4ba, 0x6f88e4bb, 0x6f89e4bc, 0x6f8ae4bd, 0x6f8be4be,
+    0x6f8ce4bf, 0x6f8de4c0, 0x6f8ee4c1, 0x6f8fe4c2, 0x6f90e4c3, 0x6f91e4c4, 0x6f92e4c5, 0x6f93e4c6,
+    0x6f94e4c7, 0x6f95e4c8, 0x6f96e4c9, 0x6f97e4ca, 0x6f98e4cb, 0x6f99e4cc, 0x6f9ae4cd, 0x6f9be4ce,
+    0x6f9ce4cf, 0x6f9de4d0, 0x6f9ee4d1, 0x6f9fe4d2, 0x6fa0e4d3, 0x6fa1e4d4, 0x6fa2e4d5, 0x6fa3e4d6,
+    0x6fa4e4d7, 0x6fa5e4d8, 0x6fa6e4d9, 0x6fa7e4da, 0x6fa8e4db, 0x6fa9e4dc, 0x6faae4dd, 0x6fabe4de,
+    0x6face4df, 0x6fade4e0, 0x6faee4e1, 0x6fafe4e2, 0x6fb0e4e3, 0x6fb1e4e4, 0x6fb2e4e5, 0x6fb3e4e6,
+    0x6fb4e4e7, 0x6fb5e4e8, 0x6fb6e4e9, 0x6fb7e4ea, 0x6fb8e4eb, 0x6fb9e4ec, 0x6fbae4ed, 0x6fbbe4ee,
+    0x6fbce4ef, 0x6fbde4f0, 0x6fbee4f1, 0x6fbfe4f2, 0x6fc0e4f3, 0x6fc1e4f4, 0x6fc2e4f5, 0x6fc3e4f6,
+    0x6fc4e4f7, 0x6fc5e4f8, 0x6fc6e4f9, 0x6fc7e4fa, 0x6fc8e4fb, 0x6fc9e4fc, 0x6fcae4fd, 0x6fcbe4fe,
+    0x6fcce4ff, 0x6fcde500, 0x6fcee501, 0x6fcfe502, 0x6fd0e503, 0x6fd1e504, 0x6fd2e505, 0x6fd3e506,
+    0x6fd4e507, 0x6fd5e508, 0x6fd6e509, 0x6fd7e50a, 0x6fd8e50b, 0x6fd9e50c, 0x6fdae50d, 0x6fdbe50e,
+    0x6fdce50f, 0x6fdde510, 0x6fdee511, 0x6fdfe512, 0x6fe0e513, 0x6fe1e514, 0x6fe2e515, 0x6fe3e516,
+    0x6fe4e517, 0x6fe5e518, 0x6fe6e519, 0x6fe7e51a, 0x6fe8e51b, 0x6fe9e51c, 0x6feae51d, 0x6febe51e,
+    0x6fece51f, 0x6fede520, 0x6feee521, 0x6fefe522, 0x6ff0e523, 0x6ff1e524, 0x6ff2e525, 0x6ff3e526,
+    0x6ff4e527, 0x6ff5e528, 0x6ff6e529, 0x6ff7e52a, 0x6ff8e52b, 0x6ff9e52c, 0x6ffae52d, 0x6ffbe52e,
+    0x6ffce52f, 0x6ffde530, 0x6ffee531, 0x7041e532, 0x7042e533, 0x7043e534, 0x7044e535, 0x7045e536,
+    0x7046e537, 0x7047e538, 0x7048e539, 0x7049e53a, 0x704ae53b, 0x704be53c, 0x704ce53d, 0x704de53e,
+    0x704ee53f, 0x704fe540, 0x7050e541, 0x7051e542, 0x7052e543, 0x7053e544, 0x7054e545, 0x7055e546,
+    0x7056e547, 0x7057e548, 0x7058e549, 0x7059e54a, 0x705ae54b, 0x705be54c, 0x705ce54d, 0x705de54e,
+    0x705ee54f, 0x705fe550, 0x7060e551, 0x7061e552, 0x7062e553, 0x7063e554, 0x7064e555, 0x7065e556,
+    0x7066e557, 0x7067e558, 0x7068e559, 0x7069e55a, 0x706ae55b, 0x706be55c, 0x706ce55d, 0x706de55e,
+    0x706ee55f, 0x706fe560, 0x7070e561, 0x7071e562, 0x7072e563, 0x7073e564, 0x7074e565, 0x7075e566,
+    0x7076e567, 0x7077e568, 0x7078e569, 0x7079e56a, 0x707ae56b, 0x707be56c, 0x707ce56d, 0x707de56e,
+    0x707ee56f, 0x707fe570, 0x7080e571, 0x7081e572, 0x7082e573, 0x7083e574, 0x7084e575, 0x7085e576,
+    0x7086e577, 0x7087e578, 0x7088e579, 0x7089e57a, 0x708ae57b, 0x708be57c, 0x708ce57d, 0x708de57e,
+    0x708ee57f, 0x708fe580, 0x7090e581, 0x7091e582, 0x7092e583, 0x7093e584, 0x7094e585, 0x7095e586,
+    0x7096e587, 0x7097e588, 0x7098e589, 0x7099e58a, 0x709ae58b, 0x709be58c, 0x709ce58d, 0x709de58e,
+    0x709ee58f, 0x709fe590, 0x70a0e591, 0x70a1e592, 0x70a2e593, 0x70a3e594, 0x70a4e595, 0x70a5e596,
+    0x70a6e597, 0x70a7e598, 0x70a8e599, 0x70a9e59a, 0x70aae59b, 0x70abe59c, 0x70ace59d, 0x70ade59e,
+    0x70aee59f, 0x70afe5a0, 0x70b0e5a1, 0x70b1e5a2, 0x70b2e5a3, 0x70b3e5a4, 0x70b4e5a5, 0x70b5e5a6,
+    0x70b6e5a7, 0x70b7e5a8, 0x70b8e5a9, 0x70b9e5aa, 0x70bae5ab, 0x70bbe5ac, 0x70bce5ad, 0x70bde5ae,
+    0x70bee5af, 0x70bfe5b0, 0x70c0e5b1, 0x70c1e5b2, 0x70c2e5b3, 0x70c3e5b4, 0x70c4e5b5, 0x70c5e5b6,
+    0x70c6e5b7, 0x70c7e5b8, 0x70c8e5b9, 0x70c9e5ba, 0x70cae5bb, 0x70cbe5bc, 0x70cce5bd, 0x70cde5be,
+    0x70cee5bf, 0x70cfe5c0, 0x70d0e5c1, 0x70d1e5c2, 0x70d2e5c3, 0x70d3e5c4, 0x70d4e5c5, 0x70d5e5c6,
+    0x70d6e5c7, 0x70d7e5c8, 0x70d8e5c9, 0x70d9e5ca, 0x70dae5cb, 0x70dbe5cc, 0x70dce5cd, 0x70dde5ce,
+    0x70dee5cf, 0x70dfe5d0, 0x70e0e5d1, 0x70e1e5d2, 0x70e2e5d3, 0x70e3e5d4, 0x70e4e5d5, 0x70e5e5d6,
+    0x70e6e5d7, 0x70e7e5d8, 0x70e8e5d9, 0x70e9e5da, 0x70eae5db, 0x70ebe5dc, 0x70ece5dd, 0x70ede5de,
+    0x70eee5df, 0x70efe5e0, 0x70f0e5e1, 0x70f1e5e2, 0x70f2e5e3, 0x70f3e5e4, 0x70f4e5e5, 0x70f5e5e6,
+    0x70f6e5e7, 0x70f7e5e8, 0x70f8e5e9, 0x70f9e5ea, 0x70fae5eb, 0x70fbe5ec, 0x70fce5ed, 0x70fde5ee,
+    0x70fee5ef, 0x7141e5f0, 0x7142e5f1, 0x7143e5f2, 0x7144e5f3, 0x7145e5f4, 0x7146e5f5, 0x7147e5f6,
+    0x7148e5f7, 0x7149e5f8, 0x714ae5f9, 0x714be5fa, 0x714ce5fb, 0x714de5fc, 0x714ee5fd, 0x714fe5fe,
+    0x7150e5ff, 0x7151e600, 0x7152e601, 0x7153e602, 0x7154e603, 0x7155e604, 0x7156e605, 0x7157e606,
+    0x7158e607, 0x7159e608, 0x715ae609, 0x715be60a, 0x715ce60b, 0x715de60c, 0x715ee60d, 0x715fe60e,
+    0x7160e60f, 0x7161e610, 0x7162e611, 0x7163e612, 0x7164e613, 0x7165e614, 0x7166e615, 0x7167e616,
+    0x7168e617, 0x7169e618, 0x716ae619, 0x716be61a, 0x716ce61b, 0x716de61c, 0x716ee61d, 0x716fe61e,
+    0x7170e61f, 0x7171e620, 0x7172e621, 0x7173e622, 0x7174e623, 0x7175e624, 0x7176e625, 0x7177e626,
+    0x7178e627, 0x7179e628, 0x717ae629, 0x717be62a, 0x717ce62b, 0x717de62c, 0x717ee62d, 0x717fe62e,
+    0x7180e62f, 0x7181e630, 0x7182e631, 0x7183e632, 0x7184e633, 0x7185e634, 0x7186e635, 0x7187e636,
+    0x7188e637, 0x7189e638, 0x718ae639, 0x718be63a, 0x718ce63b, 0x718de63c, 0x718ee63d, 0x718fe63e,
+    0x7190e63f, 0x7191e640, 0x7192e641, 0x7193e642, 0x7194e643, 0x7195e644, 0x7196e645, 0x7197e646,
+    0x7198e647, 0x7199e648, 0x719ae649, 0x719be64a, 0x719ce64b, 0x719de64c, 0x719ee64d, 0x719fe64e,
+    0x71a0e64f, 0x71a1e650, 0x71a2e651, 0x71a3e652, 0x71a4e653, 0x71a5e654, 0x71a6e655, 0x71a7e656,
+    0x71a8e657, 0x71a9e658, 0x71aae659, 0x71abe65a, 0x71ace65b, 0x71ade65c, 0x71aee65d, 0x71afe65e,
+    0x71b0e65f, 0x71b1e660, 0x71b2e661, 0x71b3e662, 0x71b4e663, 0x71b5e664, 0x71b6e665, 0x71b7e666,
+    0x71b8e667, 0x71b9e668, 0x71bae669, 0x71bbe66a, 0x71bce66b, 0x71bde66c, 0x71bee66d, 0x71bfe66e,
+    0x71c0e66f, 0x71c1e670, 0x71c2e671, 0x71c3e672, 0x71c4e673, 0x71c5e674, 0x71c6e675, 0x71c7e676,
+    0x71c8e677, 0x71c9e678, 0x71cae679, 0x71cbe67a, 0x71cce67b, 0x71cde67c, 0x71cee67d, 0x71cfe67e,
+    0x71d0e67f, 0x71d1e680, 0x71d2e681, 0x71d3e682, 0x71d4e683, 0x71d5e684, 0x71d6e685, 0x71d7e686,
+    0x71d8e687, 0x71d9e688, 0x71dae689, 0x71dbe68a, 0x71dce68b, 0x71dde68c, 0x71dee68d, 0x71dfe68e,
+    0x71e0e68f, 0x71e1e690, 0x71e2e691, 0x71e3e692, 0x71e4e693, 0x71e5e694, 0x71e6e695, 0x71e7e696,
+    0x71e8e697, 0x71e9e698, 0x71eae699, 0x71ebe69a, 0x71ece69b, 0x71ede69c, 0x71eee69d, 0x71efe69e,
+    0x71f0e69f, 0x71f1e6a0, 0x71f2e6a1, 0x71f3e6a2, 0x71f4e6a3, 0x71f5e6a4, 0x71f6e6a5, 0x71f7e6a6,
+    0x71f8e6a7, 0x71f9e6a8, 0x71fae6a9, 0x71fbe6aa, 0x71fce6ab, 0x71fde6ac, 0x71fee6ad, 0x7241e6ae,
+    0x7242e6af, 0x7243e6b0, 0x7244e6b1, 0x7245e6b2, 0x7246e6b3, 0x7247e6b4, 0x7248e6b5, 0x7249e6b6,
+    0x724ae6b7, 0x724be6b8, 0x724ce6b9, 0x724de6ba, 0x724ee6bb, 0x724fe6bc, 0x7250e6bd, 0x7251e6be,
+    0x7252e6bf, 0x7253e6c0, 0x7254e6c1, 0x7255e6c2, 0x7256e6c3, 0x7257e6c4, 0x7258e6c5, 0x7259e6c6,
+    0x725ae6c7, 0x725be6c8, 0x725ce6c9, 0x725de6ca, 0x725ee6cb, 0x725fe6cc, 0x7260e6cd, 0x7261e6ce,
+    0x7262e6cf, 0x7263e6d0, 0x7264e6d1, 0x7265e6d2, 0x7266e6d3, 0x7267e6d4, 0x7268e6d5, 0x7269e6d6,
+    0x726ae6d7, 0x726be6d8, 0x726ce6d9, 0x726de6da, 0x726ee6db, 0x726fe6dc, 0x7270e6dd, 0x7271e6de,
+    0x7272e6df, 0x7273e6e0, 0x7274e6e1, 0x7275e6e2, 0x7276e6e3, 0x7277e6e4, 0x7278e6e5, 0x7279e6e6,
+    0x727ae6e7, 0x727be6e8, 0x727ce6e9, 0x727de6ea, 0x727ee6eb, 0x727fe6ec, 0x7280e6ed, 0x7281e6ee,
+    0x7282e6ef, 0x7283e6f0, 0x7284e6f1, 0x7285e6f2, 0x7286e6f3, 0x7287e6f4, 0x7288e6f5, 0x7289e6f6,
+    0x728ae6f7, 0x728be6f8, 0x728ce6f9, 0x728de6fa, 0x728ee6fb, 0x728fe6fc, 0x7290e6fd, 0x7291e6fe,
+    0x7292e6ff, 0x7293e700, 0x7294e701, 0x7295e702, 0x7296e703, 0x7297e704, 0x7298e705, 0x7299e706,
+    0x729ae707, 0x729be708, 0x729ce709, 0x729de70a, 0x729ee70b, 0x729fe70c, 0x72a0e70d, 0x72a1e70e,
+    0x72a2e70f, 0x72a3e710, 0x72a4e711, 0x72a5e712, 0x72a6e713, 0x72a7e714, 0x72a8e715, 0x72a9e716,
+    0x72aae717, 0x72abe718, 0x72ace719, 0x72ade71a, 0x72aee71b, 0x72afe71c, 0x72b0e71d, 0x72b1e71e,
+    0x72b2e71f, 0x72b3e720, 0x72b4e721, 0x72b5e722, 0x72b6e723, 0x72b7e724, 0x72b8e725, 0x72b9e726,
+    0x72bae727, 0x72bbe728, 0x72bce729, 0x72bde72a, 0x72bee72b, 0x72bfe72c, 0x72c0e72d, 0x72c1e72e,
+    0x72c2e72f, 0x72c3e730, 0x72c4e731, 0x72c5e732, 0x72c6e733, 0x72c7e734, 0x72c8e735, 0x72c9e736,
+    0x72cae737, 0x72cbe738, 0x72cce739, 0x72cde73a, 0x72cee73b, 0x72cfe73c, 0x72d0e73d, 0x72d1e73e,
+    0x72d2e73f, 0x72d3e740, 0x72d4e741, 0x72d5e742, 0x72d6e743, 0x72d7e744, 0x72d8e745, 0x72d9e746,
+    0x72dae747, 0x72dbe748, 0x72dce749, 0x72dde74a, 0x72dee74b, 0x72dfe74c, 0x72e0e74d, 0x72e1e74e,
+    0x72e2e74f, 0x72e3e750, 0x72e4e751, 0x72e5e752, 0x72e6e753, 0x72e7e754, 0x72e8e755, 0x72e9e756,
+    0x72eae757, 0x72ebe758, 0x72ece759, 0x72ede75a, 0x72eee75b, 0x72efe75c, 0x72f0e75d, 0x72f1e75e,
+    0x72f2e75f, 0x72f3e760, 0x72f4e761, 0x72f5e762, 0x72f6e763, 0x72f7e764, 0x72f8e765, 0x72f9e766,
+    0x72fae767, 0x72fbe768, 0x72fce769, 0x72fde76a, 0x72fee76b, 0x7341e76c, 0x7342e76d, 0x7343e76e,
+    0x7344e76f, 0x7345e770, 0x7346e771, 0x7347e772, 0x7348e773, 0x7349e774, 0x734ae775, 0x734be776,
+    0x734ce777, 0x734de778, 0x734ee779, 0x734fe77a, 0x7350e77b, 0x7351e77c, 0x7352e77d, 0x7353e77e,
+    0x7354e77f, 0x7355e780, 0x7356e781, 0x7357e782, 0x7358e783, 0x7359e784, 0x735ae785, 0x735be786,
+    0x735ce787, 0x735de788, 0x735ee789, 0x735fe78a, 0x7360e78b, 0x7361e78c, 0x7362e78d, 0x7363e78e,
+    0x7364e78f, 0x7365e790, 0x7366e791, 0x7367e792, 0x7368e793, 0x7369e794, 0x736ae795, 0x736be796,
+    0x736ce797, 0x736de798, 0x736ee799, 0x736fe79a, 0x7370e79b, 0x7371e79c, 0x7372e79d, 0x7373e79e,
+    0x7374e79f, 0x7375e7a0, 0x7376e7a1, 0x7377e7a2, 0x7378e7a3, 0x7379e7a4, 0x737ae7a5, 0x737be7a6,
+    0x737ce7a7, 0x737de7a8, 0x737ee7a9, 0x737fe7aa, 0x7380e7ab, 0x7381e7ac, 0x7382e7ad, 0x7383e7ae,
+    0x7384e7af, 0x7385e7b0, 0x7386e7b1, 0x7387e7b2, 0x7388e7b3, 0x7389e7b4, 0x738ae7b5, 0x738be7b6,
+    0x738ce7b7, 0x738de7b8, 0x738ee7b9, 0x738fe7ba, 0x7390e7bb, 0x7391e7bc, 0x7392e7bd, 0x7393e7be,
+    0x7394e7bf, 0x7395e7c0, 0x7396e7c1, 0x7397e7c2, 0x7398e7c3, 0x7399e7c4, 0x739ae7c5, 0x739be7c6,
+    0x739ce7c7, 0x739de7c8, 0x739ee7c9, 0x739fe7ca, 0x73a0e7cb, 0x73a1e7cc, 0x73a2e7cd, 0x73a3e7ce,
+    0x73a4e7cf, 0x73a5e7d0, 0x73a6e7d1, 0x73a7e7d2, 0x73a8e7d3, 0x73a9e7d4, 0x73aae7d5, 0x73abe7d6,
+    0x73ace7d7, 0x73ade7d8, 0x73aee7d9, 0x73afe7da, 0x73b0e7db, 0x73b1e7dc, 0x73b2e7dd, 0x73b3e7de,
+    0x73b4e7df, 0x73b5e7e0, 0x73b6e7e1, 0x73b7e7e2, 0x73b8e7e3, 0x73b9e7e4, 0x73bae7e5, 0x73bbe7e6,
+    0x73bce7e7, 0x73bde7e8, 0x73bee7e9, 0x73bfe7ea, 0x73c0e7eb, 0x73c1e7ec, 0x73c2e7ed, 0x73c3e7ee,
+    0x73c4e7ef, 0x73c5e7f0, 0x73c6e7f1, 0x73c7e7f2, 0x73c8e7f3, 0x73c9e7f4, 0x73cae7f5, 0x73cbe7f6,
+    0x73cce7f7, 0x73cde7f8, 0x73cee7f9, 0x73cfe7fa, 0x73d0e7fb, 0x73d1e7fc, 0x73d2e7fd, 0x73d3e7fe,
+    0x73d4e7ff, 0x73d5e800, 0x73d6e801, 0x73d7e802, 0x73d8e803, 0x73d9e804, 0x73dae805, 0x73dbe806,
+    0x73dce807, 0x73dde808, 0x73dee809, 0x73dfe80a, 0x73e0e80b, 0x73e1e80c, 0x73e2e80d, 0x73e3e80e,
+    0x73e4e80f, 0x73e5e810, 0x73e6e811, 0x73e7e812, 0x73e8e813, 0x73e9e814, 0x73eae815, 0x73ebe816,
+    0x73ece817, 0x73ede818, 0x73eee819, 0x73efe81a, 0x73f0e81b, 0x73f1e81c, 0x73f2e81d, 0x73f3e81e,
+    0x73f4e81f, 0x73f5e820, 0x73f6e821, 0x73f7e822, 0x73f8e823, 0x73f9e824, 0x73fae825, 0x73fbe826,
+    0x73fce827, 0x73fde828, 0x73fee829, 0x7441e82a, 0x7442e82b, 0x7443e82c, 0x7444e82d, 0x7445e82e,
+    0x7446e82f, 0x7447e830, 0x7448e831, 0x7449e832, 0x744ae833, 0x744be834, 0x744ce835, 0x744de836,
+    0x744ee837, 0x744fe838, 0x7450e839, 0x7451e83a, 0x7452e83b, 0x7453e83c, 0x7454e83d, 0x7455e83e,
+    0x7456e83f, 0x7457e840, 0x7458e841, 0x7459e842, 0x745ae843, 0x745be844, 0x745ce845, 0x745de846,
+    0x745ee847, 0x745fe848, 0x7460e849, 0x7461e84a, 0x7462e84b, 0x7463e84c, 0x7464e84d, 0x7465e84e,
+    0x7466e84f, 0x7467e850, 0x7468e851, 0x7469e852, 0x746ae853, 0x746be854, 0x746ce855, 0x746de856,
+    0x746ee857, 0x746fe858, 0x7470e859, 0x7471e85a, 0x7472e85b, 0x7473e85c, 0x7474e85d, 0x7475e85e,
+    0x7476e85f, 0x7477e860, 0x7478e861, 0x7479e862, 0x747ae863, 0x747be864, 0x747ce865, 0x747de866,
+    0x747ee867, 0x747fe868, 0x7480e869, 0x7481e86a, 0x7482e86b, 0x7483e86c, 0x7484e86d, 0x7485e86e,
+    0x7486e86f, 0x7487e870, 0x7488e871, 0x7489e872, 0x748ae873, 0x748be874, 0x748ce875, 0x748de876,
+    0x748ee877, 0x748fe878, 0x7490e879, 0x7491e87a, 0x7492e87b, 0x7493e87c, 0x7494e87d, 0x7495e87e,
+    0x7496e87f, 0x7497e880, 0x7498e881, 0x7499e882, 0x749ae883, 0x749be884, 0x749ce885, 0x749de886,
+    0x749ee887, 0x749fe888, 0x74a0e889, 0x74a1e88a, 0x74a2e88b, 0x74a3e88c, 0x74a4e88d, 0x74a5e88e,
+    0x74a6e88f, 0x74a7e890, 0x74a8e891, 0x74a9e892, 0x74aae893, 0x74abe894, 0x74ace895, 0x74ade896,
+    0x74aee897, 0x74afe898, 0x74b0e899, 0x74b1e89a, 0x74b2e89b, 0x74b3e89c, 0x74b4e89d, 0x74b5e89e,
+    0x74b6e89f, 0x74b7e8a0, 0x74b8e8a1, 0x74b9e8a2, 0x74bae8a3, 0x74bbe8a4, 0x74bce8a5, 0x74bde8a6,
+    0x74bee8a7, 0x74bfe8a8, 0x74c0e8a9, 0x74c1e8aa, 0x74c2e8ab, 0x74c3e8ac, 0x74c4e8ad, 0x74c5e8ae,
+    0x74c6e8af, 0x74c7e8b0, 0x74c8e8b1, 0x74c9e8b2, 0x74cae8b3, 0x74cbe8b4, 0x74cce8b5, 0x74cde8b6,
+    0x74cee8b7, 0x74cfe8b8, 0x74d0e8b9, 0x74d1e8ba, 0x74d2e8bb, 0x74d3e8bc, 0x74d4e8bd, 0x74d5e8be,
+    0x74d6e8bf, 0x74d7e8c0, 0x74d8e8c1, 0x74d9e8c2, 0x74dae8c3, 0x74dbe8c4, 0x74dce8c5, 0x74dde8c6,
+    0x74dee8c7, 0x74dfe8c8, 0x74e0e8c9, 0x74e1e8ca, 0x74e2e8cb, 0x74e3e8cc, 0x74e4e8cd, 0x74e5e8ce,
+    0x74e6e8cf, 0x74e7e8d0, 0x74e8e8d1, 0x74e9e8d2, 0x74eae8d3, 0x74ebe8d4, 0x74ece8d5, 0x74ede8d6,
+    0x74eee8d7, 0x74efe8d8, 0x74f0e8d9, 0x74f1e8da, 0x74f2e8db, 0x74f3e8dc, 0x74f4e8dd, 0x74f5e8de,
+    0x74f6e8df, 0x74f7e8e0, 0x74f8e8e1, 0x74f9e8e2, 0x74fae8e3, 0x74fbe8e4, 0x74fce8e5, 0x74fde8e6,
+    0x74fee8e7, 0x7541e8e8, 0x7542e8e9, 0x7543e8ea, 0x7544e8eb, 0x7545e8ec, 0x7546e8ed, 0x7547e8ee,
+    0x7548e8ef, 0x7549e8f0, 0x754ae8f1, 0x754be8f2, 0x754ce8f3, 0x754de8f4, 0x754ee8f5, 0x754fe8f6,
+    0x7550e8f7, 0x7551e8f8, 0x7552e8f9, 0x7553e8fa, 0x7554e8fb, 0x7555e8fc, 0x7556e8fd, 0x7557e8fe,
+    0x7558e8ff, 0x7559e900, 0x755ae901, 0x755be902, 0x755ce903, 0x755de904, 0x755ee905, 0x755fe906,
+    0x7560e907, 0x7561e908, 0x7562e909, 0x7563e90a, 0x7564e90b, 0x7565e90c, 0x7566e90d, 0x7567e90e,
+    0x7568e90f, 0x7569e910, 0x756ae911, 0x756be912, 0x756ce913, 0x756de914, 0x756ee915, 0x756fe916,
+    0x7570e917, 0x7571e918, 0x7572e919, 0x7573e91a, 0x7574e91b, 0x7575e91c, 0x7576e91d, 0x7577e91e,
+    0x7578e91f, 0x7579e920, 0x757ae921, 0x757be922, 0x757ce923, 0x757de924, 0x757ee925, 0x757fe926,
+    0x7580e927, 0x7581e928, 0x7582e929, 0x7583e92a, 0x7584e92b, 0x7585e92c, 0x7586e92d, 0x7587e92e,
+    0x7588e92f, 0x7589e930, 0x758ae931, 0x758be932, 0x758ce933, 0x758de934, 0x758ee935, 0x758fe936,
+    0x7590e937, 0x7591e938, 0x7592e939, 0x7593e93a, 0x7594e93b, 0x7595e93c, 0x7596e93d, 0x7597e93e,
+    0x7598e93f, 0x7599e940, 0x759ae941, 0x759be942, 0x759ce943, 0x759de944, 0x759ee945, 0x759fe946,
+    0x75a0e947, 0x75a1e948, 0x75a2e949, 0x75a3e94a, 0x75a4e94b, 0x75a5e94c, 0x75a6e94d, 0x75a7e94e,
+    0x75a8e94f, 0x75a9e950, 0x75aae951, 0x75abe952, 0x75ace953, 0x75ade954, 0x75aee955, 0x75afe956,
+    0x75b0e957, 0x75b1e958, 0x75b2e959, 0x75b3e95a, 0x75b4e95b, 0x75b5e95c, 0x75b6e95d, 0x75b7e95e,
+    0x75b8e95f, 0x75b9e960, 0x75bae961, 0x75bbe962, 0x75bce963, 0x75bde964, 0x75bee965, 0x75bfe966,
+    0x75c0e967, 0x75c1e968, 0x75c2e969, 0x75c3e96a, 0x75c4e96b, 0x75c5e96c, 0x75c6e96d, 0x75c7e96e,
+    0x75c8e96f, 0x75c9e970, 0x75cae971, 0x75cbe972, 0x75cce973, 0x75cde974, 0x75cee975, 0x75cfe976,
+    0x75d0e977, 0x75d1e978, 0x75d2e979, 0x75d3e97a, 0x75d4e97b, 0x75d5e97c, 0x75d6e97d, 0x75d7e97e,
+    0x75d8e97f, 0x75d9e980, 0x75dae981, 0x75dbe982, 0x75dce983, 0x75dde984, 0x75dee985, 0x75dfe986,
+    0x75e0e987, 0x75e1e988, 0x75e2e989, 0x75e3e98a, 0x75e4e98b, 0x75e5e98c, 0x75e6e98d, 0x75e7e98e,
+    0x75e8e98f, 0x75e9e990, 0x75eae991, 0x75ebe992, 0x75ece993, 0x75ede994, 0x75eee995, 0x75efe996,
+    0x75f0e997, 0x75f1e998, 0x75f2e999, 0x75f3e99a, 0x75f4e99b, 0x75f5e99c, 0x75f6e99d, 0x75f7e99e,
+    0x75f8e99f, 0x75f9e9a0, 0x75fae9a1, 0x75fbe9a2, 0x75fce9a3, 0x75fde9a4, 0x75fee9a5, 0x7641e9a6,
+    0x7642e9a7, 0x7643e9a8, 0x7644e9a9, 0x7645e9aa, 0x7646e9ab, 0x7647e9ac, 0x7648e9ad, 0x7649e9ae,
+    0x764ae9af, 0x764be9b0, 0x764ce9b1, 0x764de9b2, 0x764ee9b3, 0x764fe9b4, 0x7650e9b5, 0x7651e9b6,
+    0x7652e9b7, 0x7653e9b8, 0x7654e9b9, 0x7655e9ba, 0x7656e9bb, 0x7657e9bc, 0x7658e9bd, 0x7659e9be,
+    0x765ae9bf, 0x765be9c0, 0x765ce9c1, 0x765de9c2, 0x765ee9c3, 0x765fe9c4, 0x7660e9c5, 0x7661e9c6,
+    0x7662e9c7, 0x7663e9c8, 0x7664e9c9, 0x7665e9ca, 0x7666e9cb, 0x7667e9cc, 0x7668e9cd, 0x7669e9ce,
+    0x766ae9cf, 0x766be9d0, 0x766ce9d1, 0x766de9d2, 0x766ee9d3, 0x766fe9d4, 0x7670e9d5, 0x7671e9d6,
+    0x7672e9d7, 0x7673e9d8, 0x7674e9d9, 0x7675e9da, 0x7676e9db, 0x7677e9dc, 0x7678e9dd, 0x7679e9de,
+    0x767ae9df, 0x767be9e0, 0x767ce9e1, 0x767de9e2, 0x767ee9e3, 0x767fe9e4, 0x7680e9e5, 0x7681e9e6,
+    0x7682e9e7, 0x7683e9e8, 0x7684e9e9, 0x7685e9ea, 0x7686e9eb, 0x7687e9ec, 0x7688e9ed, 0x7689e9ee,
+    0x768ae9ef, 0x768be9f0, 0x768ce9f1, 0x768de9f2, 0x768ee9f3, 0x768fe9f4, 0x7690e9f5, 0x7691e9f6,
+    0x7692e9f7, 0x7693e9f8, 0x7694e9f9, 0x7695e9fa, 0x7696e9fb, 0x7697e9fc, 0x7698e9fd, 0x7699e9fe,
+    0x769ae9ff, 0x769bea00, 0x769cea01, 0x769dea02, 0x769eea03, 0x769fea04, 0x76a0ea05, 0x76a1ea06,
+    0x76a2ea07, 0x76a3ea08, 0x76a4ea09, 0x76a5ea0a, 0x76a6ea0b, 0x76a7ea0c, 0x76a8ea0d, 0x76a9ea0e,
+    0x76aaea0f, 0x76abea10, 0x76acea11, 0x76adea12, 0x76aeea13, 0x76afea14, 0x76b0ea15, 0x76b1ea16,
+    0x76b2ea17, 0x76b3ea18, 0x76b4ea19, 0x76b5ea1a, 0x76b6ea1b, 0x76b7ea1c, 0x76b8ea1d, 0x76b9ea1e,
+    0x76baea1f, 0x76bbea20, 0x76bcea21, 0x76bdea22, 0x76beea23, 0x76bfea24, 0x76c0ea25, 0x76c1ea26,
+    0x76c2ea27, 0x76c3ea28, 0x76c4ea29, 0x76c5ea2a, 0x76c6ea2b, 0x76c7ea2c, 0x76c8ea2d, 0x76c9ea2e,
+    0x76caea2f, 0x76cbea30, 0x76ccea31, 0x76cdea32, 0x76ceea33, 0x76cfea34, 0x76d0ea35, 0x76d1ea36,
+    0x76d2ea37, 0x76d3ea38, 0x76d4ea39, 0x76d5ea3a, 0x76d6ea3b, 0x76d7ea3c, 0x76d8ea3d, 0x76d9ea3e,
+    0x76daea3f, 0x76dbea40, 0x76dcea41, 0x76ddea42, 0x76deea43, 0x76dfea44, 0x76e0ea45, 0x76e1ea46,
+    0x76e2ea47, 0x76e3ea48, 0x76e4ea49, 0x76e5ea4a, 0x76e6ea4b, 0x76e7ea4c, 0x76e8ea4d, 0x76e9ea4e,
+    0x76eaea4f, 0x76ebea50, 0x76ecea51, 0x76edea52, 0x76eeea53, 0x76efea54, 0x76f0ea55, 0x76f1ea56,
+    0x76f2ea57, 0x76f3ea58, 0x76f4ea59, 0x76f5ea5a, 0x76f6ea5b, 0x76f7ea5c, 0x76f8ea5d, 0x76f9ea5e,
+    0x76faea5f, 0x76fbea60, 0x76fcea61, 0x76fdea62, 0x76feea63, 0x7741ea64, 0x7742ea65, 0x7743ea66,
+    0x7744ea67, 0x7745ea68, 0x7746ea69, 0x7747ea6a, 0x7748ea6b, 0x7749ea6c, 0x774aea6d, 0x774bea6e,
+    0x774cea6f, 0x774dea70, 0x774eea71, 0x774fea72, 0x7750ea73, 0x7751ea74, 0x7752ea75, 0x7753ea76,
+    0x7754ea77, 0x7755ea78, 0x7756ea79, 0x7757ea7a, 0x7758ea7b, 0x7759ea7c, 0x775aea7d, 0x775bea7e,
+    0x775cea7f, 0x775dea80, 0x775eea81, 0x775fea82, 0x7760ea83, 0x7761ea84, 0x7762ea85, 0x7763ea86,
+    0x7764ea87, 0x7765ea88, 0x7766ea89, 0x7767ea8a, 0x7768ea8b, 0x7769ea8c, 0x776aea8d, 0x776bea8e,
+    0x776cea8f, 0x776dea90, 0x776eea91, 0x776fea92, 0x7770ea93, 0x7771ea94, 0x7772ea95, 0x7773ea96,
+    0x7774ea97, 0x7775ea98, 0x7776ea99, 0x7777ea9a, 0x7778ea9b, 0x7779ea9c, 0x777aea9d, 0x777bea9e,
+    0x777cea9f, 0x777deaa0, 0x777eeaa1, 0x777feaa2, 0x7780eaa3, 0x7781eaa4, 0x7782eaa5, 0x7783eaa6,
+    0x7784eaa7, 0x7785eaa8, 0x7786eaa9, 0x7787eaaa, 0x7788eaab, 0x7789eaac, 0x778aeaad, 0x778beaae,
+    0x778ceaaf, 0x778deab0, 0x778eeab1, 0x778feab2, 0x7790eab3, 0x7791eab4, 0x7792eab5, 0x7793eab6,
+    0x7794eab7, 0x7795eab8, 0x7796eab9, 0x7797eaba, 0x7798eabb, 0x7799eabc, 0x779aeabd, 0x779beabe,
+    0x779ceabf, 0x779deac0, 0x779eeac1, 0x779feac2, 0x77a0eac3, 0x77a1eac4, 0x77a2eac5, 0x77a3eac6,
+    0x77a4eac7, 0x77a5eac8, 0x77a6eac9, 0x77a7eaca, 0x77a8eacb, 0x77a9eacc, 0x77aaeacd, 0x77abeace,
+    0x77aceacf, 0x77adead0, 0x77aeead1, 0x77afead2, 0x77b0ead3, 0x77b1ead4, 0x77b2ead5, 0x77b3ead6,
+    0x77b4ead7, 0x77b5ead8, 0x77b6ead9, 0x77b7eada, 0x77b8eadb, 0x77b9eadc, 0x77baeadd, 0x77bbeade,
+    0x77bceadf, 0x77bdeae0, 0x77beeae1, 0x77bfeae2, 0x77c0eae3, 0x77c1eae4, 0x77c2eae5, 0x77c3eae6,
+    0x77c4eae7, 0x77c5eae8, 0x77c6eae9, 0x77c7eaea, 0x77c8eaeb, 0x77c9eaec, 0x77caeaed, 0x77cbeaee,
+    0x77cceaef, 0x77cdeaf0, 0x77ceeaf1, 0x77cfeaf2, 0x77d0eaf3, 0x77d1eaf4, 0x77d2eaf5, 0x77d3eaf6,
+    0x77d4eaf7, 0x77d5eaf8, 0x77d6eaf9, 0x77d7eafa, 0x77d8eafb, 0x77d9eafc, 0x77daeafd, 0x77dbeafe,
+    0x77dceaff, 0x77ddeb00, 0x77deeb01, 0x77dfeb02, 0x77e0eb03, 0x77e1eb04, 0x77e2eb05, 0x77e3eb06,
+    0x77e4eb07, 0x77e5eb08, 0x77e6eb09, 0x77e7eb0a, 0x77e8eb0b, 0x77e9eb0c, 0x77eaeb0d, 0x77ebeb0e,
+    0x77eceb0f, 0x77edeb10, 0x77eeeb11, 0x77efeb12, 0x77f0eb13, 0x77f1eb14, 0x77f2eb15, 0x77f3eb16,
+    0x77f4eb17, 0x77f5eb18, 0x77f6eb19, 0x77f7eb1a, 0x77f8eb1b, 0x77f9eb1c, 0x77faeb1d, 0x77fbeb1e,
+    0x77fceb1f, 0x77fdeb20, 0x77feeb21, 0x7841eb22, 0x7842eb23, 0x7843eb24, 0x7844eb25, 0x7845eb26,
+    0x7846eb27, 0x7847eb28, 0x7848eb29, 0x7849eb2a, 0x784aeb2b, 0x784beb2c, 0x784ceb2d, 0x784deb2e,
+    0x784eeb2f, 0x784feb30, 0x7850eb31, 0x7851eb32, 0x7852eb33, 0x7853eb34, 0x7854eb35, 0x7855eb36,
+    0x7856eb37, 0x7857eb38, 0x7858eb39, 0x7859eb3a, 0x785aeb3b, 0x785beb3c, 0x785ceb3d, 0x785deb3e,
+    0x785eeb3f, 0x785feb40, 0x7860eb41, 0x7861eb42, 0x7862eb43, 0x7863eb44, 0x7864eb45, 0x7865eb46,
+    0x7866eb47, 0x7867eb48, 0x7868eb49, 0x7869eb4a, 0x786aeb4b, 0x786beb4c, 0x786ceb4d, 0x786deb4e,
+    0x786eeb4f, 0x786feb50, 0x7870eb51, 0x7871eb52, 0x7872eb53, 0x7873eb54, 0x7874eb55, 0x7875eb56,
+    0x7876eb57, 0x7877eb58, 0x7878eb59, 0x7879eb5a, 0x787aeb5b, 0x787beb5c, 0x787ceb5d, 0x787deb5e,
+    0x787eeb5f, 0x787feb60, 0x7880eb61, 0x7881eb62, 0x7882eb63, 0x7883eb64, 0x7884eb65, 0x7885eb66,
+    0x7886eb67, 0x7887eb68, 0x7888eb69, 0x7889eb6a, 0x788aeb6b, 0x788beb6c, 0x788ceb6d, 0x788deb6e,
+    0x788eeb6f, 0x788feb70, 0x7890eb71, 0x7891eb72, 0x7892eb73, 0x7893eb74, 0x7894eb75, 0x7895eb76,
+    0x7896eb77, 0x7897eb78, 0x7898eb79, 0x7899eb7a, 0x789aeb7b, 0x789beb7c, 0x789ceb7d, 0x789deb7e,
+    0x789eeb7f, 0x789feb80, 0x78a0eb81, 0x78a1eb82, 0x78a2eb83, 0x78a3eb84, 0x78a4eb85, 0x78a5eb86,
+    0x78a6eb87, 0x78a7eb88, 0x78a8eb89, 0x78a9eb8a, 0x78aaeb8b, 0x78abeb8c, 0x78aceb8d, 0x78adeb8e,
+    0x78aeeb8f, 0x78afeb90, 0x78b0eb91, 0x78b1eb92, 0x78b2eb93, 0x78b3eb94, 0x78b4eb95, 0x78b5eb96,
+    0x78b6eb97, 0x78b7eb98, 0x78b8eb99, 0x78b9eb9a, 0x78baeb9b, 0x78bbeb9c, 0x78bceb9d, 0x78bdeb9e,
+    0x78beeb9f, 0x78bfeba0, 0x78c0eba1, 0x78c1eba2, 0x78c2eba3, 0x78c3eba4, 0x78c4eba5, 0x78c5eba6,
+    0x78c6eba7, 0x78c7eba8, 0x78c8eba9, 0x78c9ebaa, 0x78caebab, 0x78cbebac, 0x78ccebad, 0x78cdebae,
+    0x78ceebaf, 0x78cfebb0, 0x78d0ebb1, 0x78d1ebb2, 0x78d2ebb3, 0x78d3ebb4, 0x78d4ebb5, 0x78d5ebb6,
+    0x78d6ebb7, 0x78d7ebb8, 0x78d8ebb9, 0x78d9ebba, 0x78daebbb, 0x78dbebbc, 0x78dcebbd, 0x78ddebbe,
+    0x78deebbf, 0x78dfebc0, 0x78e0ebc1, 0x78e1ebc2, 0x78e2ebc3, 0x78e3ebc4, 0x78e4ebc5, 0x78e5ebc6,
+    0x78e6ebc7, 0x78e7ebc8, 0x78e8ebc9, 0x78e9ebca, 0x78eaebcb, 0x78ebebcc, 0x78ecebcd, 0x78edebce,
+    0x78eeebcf, 0x78efebd0, 0x78f0ebd1, 0x78f1ebd2, 0x78f2ebd3, 0x78f3ebd4, 0x78f4ebd5, 0x78f5ebd6,
+    0x78f6ebd7, 0x78f7ebd8, 0x78f8ebd9, 0x78f9ebda, 0x78faebdb, 0x78fbebdc, 0x78fcebdd, 0x78fdebde,
+    0x78feebdf, 0x7941ebe0, 0x7942ebe1, 0x7943ebe2, 0x7944ebe3, 0x7945ebe4, 0x7946ebe5, 0x7947ebe6,
+    0x7948ebe7, 0x7949ebe8, 0x794aebe9, 0x794bebea, 0x794cebeb, 0x794debec, 0x794eebed, 0x794febee,
+    0x7950ebef, 0x7951ebf0, 0x7952ebf1, 0x7953ebf2, 0x7954ebf3, 0x7955ebf4, 0x7956ebf5, 0x7957ebf6,
+    0x7958ebf7, 0x7959ebf8, 0x795aebf9, 0x795bebfa, 0x795cebfb, 0x795debfc, 0x795eebfd, 0x795febfe,
+    0x7960ebff, 0x7961ec00, 0x7962ec01, 0x7963ec02, 0x7964ec03, 0x7965ec04, 0x7966ec05, 0x7967ec06,
+    0x7968ec07, 0x7969ec08, 0x796aec09, 0x796bec0a, 0x796cec0b, 0x796dec0c, 0x796eec0d, 0x796fec0e,
+    0x7970ec0f, 0x7971ec10, 0x7972ec11, 0x7973ec12, 0x7974ec13, 0x7975ec14, 0x7976ec15, 0x7977ec16,
+    0x7978ec17, 0x7979ec18, 0x797aec19, 0x797bec1a, 0x797cec1b, 0x797dec1c, 0x797eec1d, 0x797fec1e,
+    0x7980ec1f, 0x7981ec20, 0x7982ec21, 0x7983ec22, 0x7984ec23, 0x7985ec24, 0x7986ec25, 0x7987ec26,
+    0x7988ec27, 0x7989ec28, 0x798aec29, 0x798bec2a, 0x798cec2b, 0x798dec2c, 0x798eec2d, 0x798fec2e,
+    0x7990ec2f, 0x7991ec30, 0x7992ec31, 0x7993ec32, 0x7994ec33, 0x7995ec34, 0x7996ec35, 0x7997ec36,
+    0x7998ec37, 0x7999ec38, 0x799aec39, 0x799bec3a, 0x799cec3b, 0x799dec3c, 0x799eec3d, 0x799fec3e,
+    0x79a0ec3f, 0x79a1ec40, 0x79a2ec41, 0x79a3ec42, 0x79a4ec43, 0x79a5ec44, 0x79a6ec45, 0x79a7ec46,
+    0x79a8ec47, 0x79a9ec48, 0x79aaec49, 0x79abec4a, 0x79acec4b, 0x79adec4c, 0x79aeec4d, 0x79afec4e,
+    0x79b0ec4f, 0x79b1ec50, 0x79b2ec51, 0x79b3ec52, 0x79b4ec53, 0x79b5ec54, 0x79b6ec55, 0x79b7ec56,
+    0x79b8ec57, 0x79b9ec58, 0x79baec59, 0x79bbec5a, 0x79bcec5b, 0x79bdec5c, 0x79beec5d, 0x79bfec5e,
+    0x79c0ec5f, 0x79c1ec60, 0x79c2ec61, 0x79c3ec62, 0x79c4ec63, 0x79c5ec64, 0x79c6ec65, 0x79c7ec66,
+    0x79c8ec67, 0x79c9ec68, 0x79caec69, 0x79cbec6a, 0x79ccec6b, 0x79cdec6c, 0x79ceec6d, 0x79cfec6e,
+    0x79d0ec6f, 0x79d1ec70, 0x79d2ec71, 0x79d3ec72, 0x79d4ec73, 0x79d5ec74, 0x79d6ec75, 0x79d7ec76,
+    0x79d8ec77, 0x79d9ec78, 0x79daec79, 0x79dbec7a, 0x79dcec7b, 0x79ddec7c, 0x79deec7d, 0x79dfec7e,
+    0x79e0ec7f, 0x79e1ec80, 0x79e2ec81, 0x79e3ec82, 0x79e4ec83, 0x79e5ec84, 0x79e6ec85, 0x79e7ec86,
+    0x79e8ec87, 0x79e9ec88, 0x79eaec89, 0x79ebec8a, 0x79ecec8b, 0x79edec8c, 0x79eeec8d, 0x79efec8e,
+    0x79f0ec8f, 0x79f1ec90, 0x79f2ec91, 0x79f3ec92, 0x79f4ec93, 0x79f5ec94, 0x79f6ec95, 0x79f7ec96,
+    0x79f8ec97, 0x79f9ec98, 0x79faec99, 0x79fbec9a, 0x79fcec9b, 0x79fdec9c, 0x79feec9d, 0x7a41ec9e,
+    0x7a42ec9f, 0x7a43eca0, 0x7a44eca1, 0x7a45eca2, 0x7a46eca3, 0x7a47eca4, 0x7a48eca5, 0x7a49eca6,
+    0x7a4aeca7, 0x7a4beca8, 0x7a4ceca9, 0x7a4decaa, 0x7a4eecab, 0x7a4fecac, 0x7a50ecad, 0x7a51ecae,
+    0x7a52ecaf, 0x7a53ecb0, 0x7a54ecb1, 0x7a55ecb2, 0x7a56ecb3, 0x7a57ecb4, 0x7a58ecb5, 0x7a59ecb6,
+    0x7a5aecb7, 0x7a5becb8, 0x7a5cecb9, 0x7a5decba, 0x7a5eecbb, 0x7a5fecbc, 0x7a60ecbd, 0x7a61ecbe,
+    0x7a62ecbf, 0x7a63ecc0, 0x7a64ecc1, 0x7a65ecc2, 0x7a66ecc3, 0x7a67ecc4, 0x7a68ecc5, 0x7a69ecc6,
+    0x7a6aecc7, 0x7a6becc8, 0x7a6cecc9, 0x7a6decca, 0x7a6eeccb, 0x7a6feccc, 0x7a70eccd, 0x7a71ecce,
+    0x7a72eccf, 0x7a73ecd0, 0x7a74ecd1, 0x7a75ecd2, 0x7a76ecd3, 0x7a77ecd4, 0x7a78ecd5, 0x7a79ecd6,
+    0x7a7aecd7, 0x7a7becd8, 0x7a7cecd9, 0x7a7decda, 0x7a7eecdb, 0x7a7fecdc, 0x7a80ecdd, 0x7a81ecde,
+    0x7a82ecdf, 0x7a83ece0, 0x7a84ece1, 0x7a85ece2, 0x7a86ece3, 0x7a87ece4, 0x7a88ece5, 0x7a89ece6,
+    0x7a8aece7, 0x7a8bece8, 0x7a8cece9, 0x7a8decea, 0x7a8eeceb, 0x7a8fecec, 0x7a90eced, 0x7a91ecee,
+    0x7a92ecef, 0x7a93ecf0, 0x7a94ecf1, 0x7a95ecf2, 0x7a96ecf3, 0x7a97ecf4, 0x7a98ecf5, 0x7a99ecf6,
+    0x7a9aecf7, 0x7a9becf8, 0x7a9cecf9, 0x7a9decfa, 0x7a9eecfb, 0x7a9fecfc, 0x7aa0ecfd, 0x7aa1ecfe,
+    0x7aa2ecff, 0x7aa3ed00, 0x7aa4ed01, 0x7aa5ed02, 0x7aa6ed03, 0x7aa7ed04, 0x7aa8ed05, 0x7aa9ed06,
+    0x7aaaed07, 0x7aabed08, 0x7aaced09, 0x7aaded0a, 0x7aaeed0b, 0x7aafed0c, 0x7ab0ed0d, 0x7ab1ed0e,
+    0x7ab2ed0f, 0x7ab3ed10, 0x7ab4ed11, 0x7ab5ed12, 0x7ab6ed13, 0x7ab7ed14, 0x7ab8ed15, 0x7ab9ed16,
+    0x7abaed17, 0x7abbed18, 0x7abced19, 0x7abded1a, 0x7abeed1b, 0x7abfed1c, 0x7ac0ed1d, 0x7ac1ed1e,
+    0x7ac2ed1f, 0x7ac3ed20, 0x7ac4ed21, 0x7ac5ed22, 0x7ac6ed23, 0x7ac7ed24, 0x7ac8ed25, 0x7ac9ed26,
+    0x7acaed27, 0x7acbed28, 0x7acced29, 0x7acded2a, 0x7aceed2b, 0x7acfed2c, 0x7ad0ed2d, 0x7ad1ed2e,
+    0x7ad2ed2f, 0x7ad3ed30, 0x7ad4ed31, 0x7ad5ed32, 0x7ad6ed33, 0x7ad7ed34, 0x7ad8ed35, 0x7ad9ed36,
+    0x7adaed37, 0x7adbed38, 0x7adced39, 0x7added3a, 0x7adeed3b, 0x7adfed3c, 0x7ae0ed3d, 0x7ae1ed3e,
+    0x7ae2ed3f, 0x7ae3ed40, 0x7ae4ed41, 0x7ae5ed42, 0x7ae6ed43, 0x7ae7ed44, 0x7ae8ed45, 0x7ae9ed46,
+    0x7aeaed47, 0x7aebed48, 0x7aeced49, 0x7aeded4a, 0x7aeeed4b, 0x7aefed4c, 0x7af0ed4d, 0x7af1ed4e,
+    0x7af2ed4f, 0x7af3ed50, 0x7af4ed51, 0x7af5ed52, 0x7af6ed53, 0x7af7ed54, 0x7af8ed55, 0x7af9ed56,
+    0x7afaed57, 0x7afbed58, 0x7afced59, 0x7afded5a, 0x7afeed5b, 0x7b41ed5c, 0x7b42ed5d, 0x7b43ed5e,
+    0x7b44ed5f, 0x7b45ed60, 0x7b46ed61, 0x7b47ed62, 0x7b48ed63, 0x7b49ed64, 0x7b4aed65, 0x7b4bed66,
+    0x7b4ced67, 0x7b4ded68, 0x7b4eed69, 0x7b4fed6a, 0x7b50ed6b, 0x7b51ed6c, 0x7b52ed6d, 0x7b53ed6e,
+    0x7b54ed6f, 0x7b55ed70, 0x7b56ed71, 0x7b57ed72, 0x7b58ed73, 0x7b59ed74, 0x7b5aed75, 0x7b5bed76,
+    0x7b5ced77, 0x7b5ded78, 0x7b5eed79, 0x7b5fed7a, 0x7b60ed7b, 0x7b61ed7c, 0x7b62ed7d, 0x7b63ed7e,
+    0x7b64ed7f, 0x7b65ed80, 0x7b66ed81, 0x7b67ed82, 0x7b68ed83, 0x7b69ed84, 0x7b6aed85, 0x7b6bed86,
+    0x7b6ced87, 0x7b6ded88, 0x7b6eed89, 0x7b6fed8a, 0x7b70ed8b, 0x7b71ed8c, 0x7b72ed8d, 0x7b73ed8e,
+    0x7b74ed8f, 0x7b75ed90, 0x7b76ed91, 0x7b77ed92, 0x7b78ed93, 0x7b79ed94, 0x7b7aed95, 0x7b7bed96,
+    0x7b7ced97, 0x7b7ded98, 0x7b7eed99, 0x7b7fed9a, 0x7b80ed9b, 0x7b81ed9c, 0x7b82ed9d, 0x7b83ed9e,
+    0x7b84ed9f, 0x7b85eda0, 0x7b86eda1, 0x7b87eda2, 0x7b88eda3, 0x7b89eda4, 0x7b8aeda5, 0x7b8beda6,
+    0x7b8ceda7, 0x7b8deda8, 0x7b8eeda9, 0x7b8fedaa, 0x7b90edab, 0x7b91edac, 0x7b92edad, 0x7b93edae,
+    0x7b94edaf, 0x7b95edb0, 0x7b96edb1, 0x7b97edb2, 0x7b98edb3, 0x7b99edb4, 0x7b9aedb5, 0x7b9bedb6,
+    0x7b9cedb7, 0x7b9dedb8, 0x7b9eedb9, 0x7b9fedba, 0x7ba0edbb, 0x7ba1edbc, 0x7ba2edbd, 0x7ba3edbe,
+    0x7ba4edbf, 0x7ba5edc0, 0x7ba6edc1, 0x7ba7edc2, 0x7ba8edc3, 0x7ba9edc4, 0x7baaedc5, 0x7babedc6,
+    0x7bacedc7, 0x7badedc8, 0x7baeedc9, 0x7bafedca, 0x7bb0edcb, 0x7bb1edcc, 0x7bb2edcd, 0x7bb3edce,
+    0x7bb4edcf, 0x7bb5edd0, 0x7bb6edd1, 0x7bb7edd2, 0x7bb8edd3, 0x7bb9edd4, 0x7bbaedd5, 0x7bbbedd6,
+    0x7bbcedd7, 0x7bbdedd8, 0x7bbeedd9, 0x7bbfedda, 0x7bc0eddb, 0x7bc1eddc, 0x7bc2eddd, 0x7bc3edde,
+    0x7bc4eddf, 0x7bc5ede0, 0x7bc6ede1, 0x7bc7ede2, 0x7bc8ede3, 0x7bc9ede4, 0x7bcaede5, 0x7bcbede6,
+    0x7bccede7, 0x7bcdede8, 0x7bceede9, 0x7bcfedea, 0x7bd0edeb, 0x7bd1edec, 0x7bd2eded, 0x7bd3edee,
+    0x7bd4edef, 0x7bd5edf0, 0x7bd6edf1, 0x7bd7edf2, 0x7bd8edf3, 0x7bd9edf4, 0x7bdaedf5, 0x7bdbedf6,
+    0x7bdcedf7, 0x7bddedf8, 0x7bdeedf9, 0x7bdfedfa, 0x7be0edfb, 0x7be1edfc, 0x7be2edfd, 0x7be3edfe,
+    0x7be4edff, 0x7be5ee00, 0x7be6ee01, 0x7be7ee02, 0x7be8ee03, 0x7be9ee04, 0x7beaee05, 0x7bebee06,
+    0x7becee07, 0x7bedee08, 0x7beeee09, 0x7befee0a, 0x7bf0ee0b, 0x7bf1ee0c, 0x7bf2ee0d, 0x7bf3ee0e,
+    0x7bf4ee0f, 0x7bf5ee10, 0x7bf6ee11, 0x7bf7ee12, 0x7bf8ee13, 0x7bf9ee14, 0x7bfaee15, 0x7bfbee16,
+    0x7bfcee17, 0x7bfdee18, 0x7bfeee19, 0x7c41ee1a, 0x7c42ee1b, 0x7c43ee1c, 0x7c44ee1d, 0x7c45ee1e,
+    0x7c46ee1f, 0x7c47ee20, 0x7c48ee21, 0x7c49ee22, 0x7c4aee23, 0x7c4bee24, 0x7c4cee25, 0x7c4dee26,
+    0x7c4eee27, 0x7c4fee28, 0x7c50ee29, 0x7c51ee2a, 0x7c52ee2b, 0x7c53ee2c, 0x7c54ee2d, 0x7c55ee2e,
+    0x7c56ee2f, 0x7c57ee30, 0x7c58ee31, 0x7c59ee32, 0x7c5aee33, 0x7c5bee34, 0x7c5cee35, 0x7c5dee36,
+    0x7c5eee37, 0x7c5fee38, 0x7c60ee39, 0x7c61ee3a, 0x7c62ee3b, 0x7c63ee3c, 0x7c64ee3d, 0x7c65ee3e,
+    0x7c66ee3f, 0x7c67ee40, 0x7c68ee41, 0x7c69ee42, 0x7c6aee43, 0x7c6bee44, 0x7c6cee45, 0x7c6dee46,
+    0x7c6eee47, 0x7c6fee48, 0x7c70ee49, 0x7c71ee4a, 0x7c72ee4b, 0x7c73ee4c, 0x7c74ee4d, 0x7c75ee4e,
+    0x7c76ee4f, 0x7c77ee50, 0x7c78ee51, 0x7c79ee52, 0x7c7aee53, 0x7c7bee54, 0x7c7cee55, 0x7c7dee56,
+    0x7c7eee57, 0x7c7fee58, 0x7c80ee59, 0x7c81ee5a, 0x7c82ee5b, 0x7c83ee5c, 0x7c84ee5d, 0x7c85ee5e,
+    0x7c86ee5f, 0x7c87ee60, 0x7c88ee61, 0x7c89ee62, 0x7c8aee63, 0x7c8bee64, 0x7c8cee65, 0x7c8dee66,
+    0x7c8eee67, 0x7c8fee68, 0x7c90ee69, 0x7c91ee6a, 0x7c92ee6b, 0x7c93ee6c, 0x7c94ee6d, 0x7c95ee6e,
+    0x7c96ee6f, 0x7c97ee70, 0x7c98ee71, 0x7c99ee72, 0x7c9aee73, 0x7c9bee74, 0x7c9cee75, 0x7c9dee76,
+    0x7c9eee77, 0x7c9fee78, 0x7ca0ee79, 0x7ca1ee7a, 0x7ca2ee7b, 0x7ca3ee7c, 0x7ca4ee7d, 0x7ca5ee7e,
+    0x7ca6ee7f, 0x7ca7ee80, 0x7ca8ee81, 0x7ca9ee82, 0x7caaee83, 0x7cabee84, 0x7cacee85, 0x7cadee86,
+    0x7caeee87, 0x7cafee88, 0x7cb0ee89, 0x7cb1ee8a, 0x7cb2ee8b, 0x7cb3ee8c, 0x7cb4ee8d, 0x7cb5ee8e,
+    0x7cb6ee8f, 0x7cb7ee90, 0x7cb8ee91, 0x7cb9ee92, 0x7cbaee93, 0x7cbbee94, 0x7cbcee95, 0x7cbdee96,
+    0x7cbeee97, 0x7cbfee98, 0x7cc0ee99, 0x7cc1ee9a, 0x7cc2ee9b, 0x7cc3ee9c, 0x7cc4ee9d, 0x7cc5ee9e,
+    0x7cc6ee9f, 0x7cc7eea0, 0x7cc8eea1, 0x7cc9eea2, 0x7ccaeea3, 0x7ccbeea4, 0x7ccceea5, 0x7ccdeea6,
+    0x7cceeea7, 0x7ccfeea8, 0x7cd0eea9, 0x7cd1eeaa, 0x7cd2eeab, 0x7cd3eeac, 0x7cd4eead, 0x7cd5eeae,
+    0x7cd6eeaf, 0x7cd7eeb0, 0x7cd8eeb1, 0x7cd9eeb2, 0x7cdaeeb3, 0x7cdbeeb4, 0x7cdceeb5, 0x7cddeeb6,
+    0x7cdeeeb7, 0x7cdfeeb8, 0x7ce0eeb9, 0x7ce1eeba, 0x7ce2eebb, 0x7ce3eebc, 0x7ce4eebd, 0x7ce5eebe,
+    0x7ce6eebf, 0x7ce7eec0, 0x7ce8eec1, 0x7ce9eec2, 0x7ceaeec3, 0x7cebeec4, 0x7ceceec5, 0x7cedeec6,
+    0x7ceeeec7, 0x7cefeec8, 0x7cf0eec9, 0x7cf1eeca, 0x7cf2eecb, 0x7cf3eecc, 0x7cf4eecd, 0x7cf5eece,
+    0x7cf6eecf, 0x7cf7eed0, 0x7cf8eed1, 0x7cf9eed2, 0x7cfaeed3, 0x7cfbeed4, 0x7cfceed5, 0x7cfdeed6,
+    0x7cfeeed7, 0x7d41eed8, 0x7d42eed9, 0x7d43eeda, 0x7d44eedb, 0x7d45eedc, 0x7d46eedd, 0x7d47eede,
+    0x7d48eedf, 0x7d49eee0, 0x7d4aeee1, 0x7d4beee2, 0x7d4ceee3, 0x7d4deee4, 0x7d4eeee5, 0x7d4feee6,
+    0x7d50eee7, 0x7d51eee8, 0x7d52eee9, 0x7d53eeea, 0x7d54eeeb, 0x7d55eeec, 0x7d56eeed, 0x7d57eeee,
+    0x7d58eeef, 0x7d59eef0, 0x7d5aeef1, 0x7d5beef2, 0x7d5ceef3, 0x7d5deef4, 0x7d5eeef5, 0x7d5feef6,
+    0x7d60eef7, 0x7d61eef8, 0x7d62eef9, 0x7d63eefa, 0x7d64eefb, 0x7d65eefc, 0x7d66eefd, 0x7d67eefe,
+    0x7d68eeff, 0x7d69ef00, 0x7d6aef01, 0x7d6bef02, 0x7d6cef03, 0x7d6def04, 0x7d6eef05, 0x7d6fef06,
+    0x7d70ef07, 0x7d71ef08, 0x7d72ef09, 0x7d73ef0a, 0x7d74ef0b, 0x7d75ef0c, 0x7d76ef0d, 0x7d77ef0e,
+    0x7d78ef0f, 0x7d79ef10, 0x7d7aef11, 0x7d7bef12, 0x7d7cef13, 0x7d7def14, 0x7d7eef15, 0x7d7fef16,
+    0x7d80ef17, 0x7d81ef18, 0x7d82ef19, 0x7d83ef1a, 0x7d84ef1b, 0x7d85ef1c, 0x7d86ef1d, 0x7d87ef1e,
+    0x7d88ef1f, 0x7d89ef20, 0x7d8aef21, 0x7d8bef22, 0x7d8cef23, 0x7d8def24, 0x7d8eef25, 0x7d8fef26,
+    0x7d90ef27, 0x7d91ef28, 0x7d92ef29, 0x7d93ef2a, 0x7d94ef2b, 0x7d95ef2c, 0x7d96ef2d, 0x7d97ef2e,
+    0x7d98ef2f, 0x7d99ef30, 0x7d9aef31, 0x7d9bef32, 0x7d9cef33, 0x7d9def34, 0x7d9eef35, 0x7d9fef36,
+    0x7da0ef37, 0x7da1ef38, 0x7da2ef39, 0x7da3ef3a, 0x7da4ef3b, 0x7da5ef3c, 0x7da6ef3d, 0x7da7ef3e,
+    0x7da8ef3f, 0x7da9ef40, 0x7daaef41, 0x7dabef42, 0x7dacef43, 0x7dadef44, 0x7daeef45, 0x7dafef46,
+    0x7db0ef47, 0x7db1ef48, 0x7db2ef49, 0x7db3ef4a, 0x7db4ef4b, 0x7db5ef4c, 0x7db6ef4d, 0x7db7ef4e,
+    0x7db8ef4f, 0x7db9ef50, 0x7dbaef51, 0x7dbbef52, 0x7dbcef53, 0x7dbdef54, 0x7dbeef55, 0x7dbfef56,
+    0x7dc0ef57, 0x7dc1ef58, 0x7dc2ef59, 0x7dc3ef5a, 0x7dc4ef5b, 0x7dc5ef5c, 0x7dc6ef5d, 0x7dc7ef5e,
+    0x7dc8ef5f, 0x7dc9ef60, 0x7dcaef61, 0x7dcbef62, 0x7dccef63, 0x7dcdef64, 0x7dceef65, 0x7dcfef66,
+    0x7dd0ef67, 0x7dd1ef68, 0x7dd2ef69, 0x7dd3ef6a, 0x7dd4ef6b, 0x7dd5ef6c, 0x7dd6ef6d, 0x7dd7ef6e,
+    0x7dd8ef6f, 0x7dd9ef70, 0x7ddaef71, 0x7ddbef72, 0x7ddcef73, 0x7dddef74, 0x7ddeef75, 0x7ddfef76,
+    0x7de0ef77, 0x7de1ef78, 0x7de2ef79, 0x7de3ef7a, 0x7de4ef7b, 0x7de5ef7c, 0x7de6ef7d, 0x7de7ef7e,
+    0x7de8ef7f, 0x7de9ef80, 0x7deaef81, 0x7debef82, 0x7decef83, 0x7dedef84, 0x7deeef85, 0x7defef86,
+    0x7df0ef87, 0x7df1ef88, 0x7df2ef89, 0x7df3ef8a, 0x7df4ef8b, 0x7df5ef8c, 0x7df6ef8d, 0x7df7ef8e,
+    0x7df8ef8f, 0x7df9ef90, 0x7dfaef91, 0x7dfbef92, 0x7dfcef93, 0x7dfdef94, 0x7dfeef95, 0x7e41ef96,
+    0x7e42ef97, 0x7e43ef98, 0x7e44ef99, 0x7e45ef9a, 0x7e46ef9b, 0x7e47ef9c, 0x7e48ef9d, 0x7e49ef9e,
+    0x7e4aef9f, 0x7e4befa0, 0x7e4cefa1, 0x7e4defa2, 0x7e4eefa3, 0x7e4fefa4, 0x7e50efa5, 0x7e51efa6,
+    0x7e52efa7, 0x7e53efa8, 0x7e54efa9, 0x7e55efaa, 0x7e56efab, 0x7e57efac, 0x7e58efad, 0x7e59efae,
+    0x7e5aefaf, 0x7e5befb0, 0x7e5cefb1, 0x7e5defb2, 0x7e5eefb3, 0x7e5fefb4, 0x7e60efb5, 0x7e61efb6,
+    0x7e62efb7, 0x7e63efb8, 0x7e64efb9, 0x7e65efba, 0x7e66efbb, 0x7e67efbc, 0x7e68efbd, 0x7e69efbe,
+    0x7e6aefbf, 0x7e6befc0, 0x7e6cefc1, 0x7e6defc2, 0x7e6eefc3, 0x7e6fefc4, 0x7e70efc5, 0x7e71efc6,
+    0x7e72efc7, 0x7e73efc8, 0x7e74efc9, 0x7e75efca, 0x7e76efcb, 0x7e77efcc, 0x7e78efcd, 0x7e79efce,
+    0x7e7aefcf, 0x7e7befd0, 0x7e7cefd1, 0x7e7defd2, 0x7e7eefd3, 0x7e7fefd4, 0x7e80efd5, 0x7e81efd6,
+    0x7e82efd7, 0x7e83efd8, 0x7e84efd9, 0x7e85efda, 0x7e86efdb, 0x7e87efdc, 0x7e88efdd, 0x7e89efde,
+    0x7e8aefdf, 0x7e8befe0, 0x7e8cefe1, 0x7e8defe2, 0x7e8eefe3, 0x7e8fefe4, 0x7e90efe5, 0x7e91efe6,
+    0x7e92efe7, 0x7e93efe8, 0x7e94efe9, 0x7e95efea, 0x7e96efeb, 0x7e97efec, 0x7e98efed, 0x7e99efee,
+    0x7e9aefef, 0x7e9beff0, 0x7e9ceff1, 0x7e9deff2, 0x7e9eeff3, 0x7e9feff4, 0x7ea0eff5, 0x7ea1eff6,
+    0x7ea2eff7, 0x7ea3eff8, 0x7ea4eff9, 0x7ea5effa, 0x7ea6effb, 0x7ea7effc, 0x7ea8effd, 0x7ea9effe,
+    0x7eaaefff, 0x7eabf000, 0x7eacf001, 0x7eadf002, 0x7eaef003, 0x7eaff004, 0x7eb0f005, 0x7eb1f006,
+    0x7eb2f007, 0x7eb3f008, 0x7eb4f009, 0x7eb5f00a, 0x7eb6f00b, 0x7eb7f00c, 0x7eb8f00d, 0x7eb9f00e,
+    0x7ebaf00f, 0x7ebbf010, 0x7ebcf011, 0x7ebdf012, 0x7ebef013, 0x7ebff014, 0x7ec0f015, 0x7ec1f016,
+    0x7ec2f017, 0x7ec3f018, 0x7ec4f019, 0x7ec5f01a, 0x7ec6f01b, 0x7ec7f01c, 0x7ec8f01d, 0x7ec9f01e,
+    0x7ecaf01f, 0x7ecbf020, 0x7eccf021, 0x7ecdf022, 0x7ecef023, 0x7ecff024, 0x7ed0f025, 0x7ed1f026,
+    0x7ed2f027, 0x7ed3f028, 0x7ed4f029, 0x7ed5f02a, 0x7ed6f02b, 0x7ed7f02c, 0x7ed8f02d, 0x7ed9f02e,
+    0x7edaf02f, 0x7edbf030, 0x7edcf031, 0x7eddf032, 0x7edef033, 0x7edff034, 0x7ee0f035, 0x7ee1f036,
+    0x7ee2f037, 0x7ee3f038, 0x7ee4f039, 0x7ee5f03a, 0x7ee6f03b, 0x7ee7f03c, 0x7ee8f03d, 0x7ee9f03e,
+    0x7eeaf03f, 0x7eebf040, 0x7eecf041, 0x7eedf042, 0x7eeef043, 0x7eeff044, 0x7ef0f045, 0x7ef1f046,
+    0x7ef2f047, 0x7ef3f048, 0x7ef4f049, 0x7ef5f04a, 0x7ef6f04b, 0x7ef7f04c, 0x7ef8f04d, 0x7ef9f04e,
+    0x7efaf04f, 0x7efbf050, 0x7efcf051, 0x7efdf052, 0x7efef053, 0x7f41f054, 0x7f42f055, 0x7f43f056,
+    0x7f44f057, 0x7f45f058, 0x7f46f059, 0x7f47f05a, 0x7f48f05b, 0x7f49f05c, 0x7f4af05d, 0x7f4bf05e,
+    0x7f4cf05f, 0x7f4df060, 0x7f4ef061, 0x7f4ff062, 0x7f50f063, 0x7f51f064, 0x7f52f065, 0x7f53f066,
+    0x7f54f067, 0x7f55f068, 0x7f56f069, 0x7f57f06a, 0x7f58f06b, 0x7f59f06c, 0x7f5af06d, 0x7f5bf06e,
+    0x7f5cf06f, 0x7f5df070, 0x7f5ef071, 0x7f5ff072, 0x7f60f073, 0x7f61f074, 0x7f62f075, 0x7f63f076,
+    0x7f64f077, 0x7f65f078, 0x7f66f079, 0x7f67f07a, 0x7f68f07b, 0x7f69f07c, 0x7f6af07d, 0x7f6bf07e,
+    0x7f6cf07f, 0x7f6df080, 0x7f6ef081, 0x7f6ff082, 0x7f70f083, 0x7f71f084, 0x7f72f085, 0x7f73f086,
+    0x7f74f087, 0x7f75f088, 0x7f76f089, 0x7f77f08a, 0x7f78f08b, 0x7f79f08c, 0x7f7af08d, 0x7f7bf08e,
+    0x7f7cf08f, 0x7f7df090, 0x7f7ef091, 0x7f7ff092, 0x7f80f093, 0x7f81f094, 0x7f82f095, 0x7f83f096,
+    0x7f84f097, 0x7f85f098, 0x7f86f099, 0x7f87f09a, 0x7f88f09b, 0x7f89f09c, 0x7f8af09d, 0x7f8bf09e,
+    0x7f8cf09f, 0x7f8df0a0, 0x7f8ef0a1, 0x7f8ff0a2, 0x7f90f0a3, 0x7f91f0a4, 0x7f92f0a5, 0x7f93f0a6,
+    0x7f94f0a7, 0x7f95f0a8, 0x7f96f0a9, 0x7f97f0aa, 0x7f98f0ab, 0x7f99f0ac, 0x7f9af0ad, 0x7f9bf0ae,
+    0x7f9cf0af, 0x7f9df0b0, 0x7f9ef0b1, 0x7f9ff0b2, 0x7fa0f0b3, 0x7fa1f0b4, 0x7fa2f0b5, 0x7fa3f0b6,
+    0x7fa4f0b7, 0x7fa5f0b8, 0x7fa6f0b9, 0x7fa7f0ba, 0x7fa8f0bb, 0x7fa9f0bc, 0x7faaf0bd, 0x7fabf0be,
+    0x7facf0bf, 0x7fadf0c0, 0x7faef0c1, 0x7faff0c2, 0x7fb0f0c3, 0x7fb1f0c4, 0x7fb2f0c5, 0x7fb3f0c6,
+    0x7fb4f0c7, 0x7fb5f0c8, 0x7fb6f0c9, 0x7fb7f0ca, 0x7fb8f0cb, 0x7fb9f0cc, 0x7fbaf0cd, 0x7fbbf0ce,
+    0x7fbcf0cf, 0x7fbdf0d0, 0x7fbef0d1, 0x7fbff0d2, 0x7fc0f0d3, 0x7fc1f0d4, 0x7fc2f0d5, 0x7fc3f0d6,
+    0x7fc4f0d7, 0x7fc5f0d8, 0x7fc6f0d9, 0x7fc7f0da, 0x7fc8f0db, 0x7fc9f0dc, 0x7fcaf0dd, 0x7fcbf0de,
+    0x7fccf0df, 0x7fcdf0e0, 0x7fcef0e1, 0x7fcff0e2, 0x7fd0f0e3, 0x7fd1f0e4, 0x7fd2f0e5, 0x7fd3f0e6,
+    0x7fd4f0e7, 0x7fd5f0e8, 0x7fd6f0e9, 0x7fd7f0ea, 0x7fd8f0eb, 0x7fd9f0ec, 0x7fdaf0ed, 0x7fdbf0ee,
+    0x7fdcf0ef, 0x7fddf0f0, 0x7fdef0f1, 0x7fdff0f2, 0x7fe0f0f3, 0x7fe1f0f4, 0x7fe2f0f5, 0x7fe3f0f6,
+    0x7fe4f0f7, 0x7fe5f0f8, 0x7fe6f0f9, 0x7fe7f0fa, 0x7fe8f0fb, 0x7fe9f0fc, 0x7feaf0fd, 0x7febf0fe,
+    0x7fecf0ff, 0x7fedf100, 0x7feef101, 0x7feff102, 0x7ff0f103, 0x7ff1f104, 0x7ff2f105, 0x7ff3f106,
+    0x7ff4f107, 0x7ff5f108, 0x7ff6f109, 0x7ff7f10a, 0x7ff8f10b, 0x7ff9f10c, 0x7ffaf10d, 0x7ffbf10e,
+    0x7ffcf10f, 0x7ffdf110, 0x7ffef111,
+];
@@ -0,0 +1,1348 @@
+#[allow(unused)]
+pub static DBCS_TBL: [u32; 10757] = [
+    0x40403000, 0x41413001, 0x41423002, 0x414330fb, 0x41442025, 0x41452026, 0x414600a8, 0x41473003,
+    0x41482010, 0x41492014, 0x414a2225, 0x414bff3c, 0x414cffe3, 0x414d2018, 0x414e2019, 0x414f201c,
+    0x4150201d, 0x41513014, 0x41523015, 0x41533008, 0x41543009, 0x4155300a, 0x4156300b, 0x4157300c,
+    0x4158300d, 0x4159300e, 0x415a300f, 0x415b3010, 0x415c3011, 0x415d00b1, 0x415e00d7, 0x415f00f7,
+    0x416001c2, 0x41612266, 0x41622267, 0x4163221e, 0x41642234, 0x416500b0, 0x41662032, 0x41672033,
+    0x41682103, 0x4169212a, 0x416aff3e, 0x416bffe1, 0x416cffe5, 0x416d3396, 0x416e3397, 0x416f2113,
+    0x417033c4, 0x4171339c, 0x4172339d, 0x4173339e, 0x4174338e, 0x4175338f, 0x417600a7, 0x4177203b,
+    0x41782606, 0x41792605, 0x417a25cb, 0x417b25cf, 0x417c25ce, 0x417d25c7, 0x417e25c6, 0x417f25a1,
+    0x418025a0, 0x418125b3, 0x418225b2, 0x418325bd, 0x418425bc, 0x41852192, 0x41862190, 0x41872191,
+    0x41882193, 0x41892194, 0x418a3013, 0x418bff3b, 0x418cff3d, 0x418d2260, 0x418e2264, 0x418f2265,
+    0x4190212b, 0x41912642, 0x41922640, 0x41932220, 0x419422a5, 0x41952312, 0x41962202, 0x41972207,
+    0x41982261, 0x41992252, 0x419a226a, 0x419b226b, 0x419c221a, 0x419d223d, 0x424affe0, 0x424bff0e,
+    0x424cff1c, 0x424dff08, 0x424eff0b, 0x424fff5c, 0x4250ff06, 0x425aff01, 0x425bff04, 0x425cff0a,
+    0x425dff09, 0x425eff1b, 0x425fffe2, 0x4260ff0d, 0x4261ff0f, 0x426affe4, 0x426bff0c, 0x426cff05,
+    0x426dff3f, 0x426eff1e, 0x426fff1f, 0x4279ff40, 0x427aff1a, 0x427bff03, 0x427cff20, 0x427dff07,
+    0x427eff1d, 0x427fff02, 0x4281ff41, 0x4282ff42, 0x4283ff43, 0x4284ff44, 0x4285ff45, 0x4286ff46,
+    0x4287ff47, 0x4288ff48, 0x4289ff49, 0x4291ff4a, 0x4292ff4b, 0x4293ff4c, 0x4294ff4d, 0x4295ff4e,
+    0x4296ff4f, 0x4297ff50, 0x4298ff51, 0x4299ff52, 0x42a1301c, 0x42a2ff53, 0x42a3ff54, 0x42a4ff55,
+    0x42a5ff56, 0x42a6ff57, 0x42a7ff58, 0x42a8ff59, 0x42a9ff5a, 0x42c0ff5b, 0x42c1ff21, 0x42c2ff22,
+    0x42c3ff23, 0x42c4ff24, 0x42c5ff25, 0x42c6ff26, 0x42c7ff27, 0x42c8ff28, 0x42c9ff29, 0x42d0ff5d,
+    0x42d1ff2a, 0x42d2ff2b, 0x42d3ff2c, 0x42d4ff2d, 0x42d5ff2e, 0x42d6ff2f, 0x42d7ff30, 0x42d8ff31,
+    0x42d9ff32, 0x42e0ffe6, 0x42e2ff33, 0x42e3ff34, 0x42e4ff35, 0x42e5ff36, 0x42e6ff37, 0x42e7ff38,
+    0x42e8ff39, 0x42e9ff3a, 0x42f0ff10, 0x42f1ff11, 0x42f2ff12, 0x42f3ff13, 0x42f4ff14, 0x42f5ff15,
+    0x42f6ff16, 0x42f7ff17, 0x42f8ff18, 0x42f9ff19, 0x43413165, 0x43423166, 0x43433167, 0x43443168,
+    0x43453169, 0x4346316a, 0x4347316b, 0x4348316c, 0x4349316d, 0x434a316e, 0x434b316f, 0x434c3170,
+    0x434d3171, 0x434e3172, 0x434f3173, 0x43503174, 0x43513175, 0x43523176, 0x43533177, 0x43543178,
+    0x43553179, 0x4356317a, 0x4357317b, 0x4358317c, 0x4359317d, 0x435a317e, 0x435b317f, 0x435c3180,
+    0x435d3181, 0x435e3182, 0x435f3183, 0x43603184, 0x43613185, 0x43623186, 0x43633187, 0x43643188,
+    0x43653189, 0x4366318a, 0x4367318b, 0x4368318c, 0x4369318d, 0x436a318e, 0x44413041, 0x44423042,
+    0x44433043, 0x44443044, 0x44453045, 0x44463046, 0x44473047, 0x44483048, 0x44493049, 0x444a304a,
+    0x444b304b, 0x444c304c, 0x444d304d, 0x444e304e, 0x444f304f, 0x44503050, 0x44513051, 0x44523052,
+    0x44533053, 0x44543054, 0x44553055, 0x44563056, 0x44573057, 0x44583058, 0x44593059, 0x445a305a,
+    0x445b305b, 0x445c305c, 0x445d305d, 0x445e305e, 0x445f305f, 0x44603060, 0x44613061, 0x44623062,
+    0x44633063, 0x44643064, 0x44653065, 0x44663066, 0x44673067, 0x44683068, 0x44693069, 0x446a306a,
+    0x446b306b, 0x446c306c, 0x446d306d, 0x446e306e, 0x446f306f, 0x44703070, 0x44713071, 0x44723072,
+    0x44733073, 0x44743074, 0x44753075, 0x44763076, 0x44773077, 0x44783078, 0x44793079, 0x447a307a,
+    0x447b307b, 0x447c307c, 0x447d307d, 0x447e307e, 0x447f307f, 0x44803080, 0x44813081, 0x44823082,
+    0x44833083, 0x44843084, 0x44853085, 0x44863086, 0x44873087, 0x44883088, 0x44893089, 0x448a308a,
+    0x448b308b, 0x448c308c, 0x448d308d, 0x448e308e, 0x448f308f, 0x44903090, 0x44913091, 0x44923092,
+    0x44933093, 0x454130a1, 0x454230a2, 0x454330a3, 0x454430a4, 0x454530a5, 0x454630a6, 0x454730a7,
+    0x454830a8, 0x454930a9, 0x454a30aa, 0x454b30ab, 0x454c30ac, 0x454d30ad, 0x454e30ae, 0x454f30af,
+    0x455030b0, 0x455130b1, 0x455230b2, 0x455330b3, 0x455430b4, 0x455530b5, 0x455630b6, 0x455730b7,
+    0x455830b8, 0x455930b9, 0x455a30ba, 0x455b30bb, 0x455c30bc, 0x455d30bd, 0x455e30be, 0x455f30bf,
+    0x456030c0, 0x456130c1, 0x456230c2, 0x456330c3, 0x456430c4, 0x456530c5, 0x456630c6, 0x456730c7,
+    0x456830c8, 0x456930c9, 0x456a30ca, 0x456b30cb, 0x456c30cc, 0x456d30cd, 0x456e30ce, 0x456f30cf,
+    0x457030d0, 0x457130d1, 0x457230d2, 0x457330d3, 0x457430d4, 0x457530d5, 0x457630d6, 0x457730d7,
+    0x457830d8, 0x457930d9, 0x457a30da, 0x457b30db, 0x457c30dc, 0x457d30dd, 0x457e30de, 0x457f30df,
+    0x458030e0, 0x458130e1, 0x458230e2, 0x458330e3, 0x458430e4, 0x458530e5, 0x458630e6, 0x458730e7,
+    0x458830e8, 0x458930e9, 0x458a30ea, 0x458b30eb, 0x458c30ec, 0x458d30ed, 0x458e30ee, 0x458f30ef,
+    0x459030f0, 0x459130f1, 0x459230f2, 0x459330f3, 0x459430f4, 0x459530f5, 0x459630f6, 0x46412170,
+    0x46422171, 0x46432172, 0x46442173, 0x46452174, 0x46462175, 0x46472176, 0x46482177, 0x46492178,
+    0x464a2179, 0x46502160, 0x46512161, 0x46522162, 0x46532163, 0x46542164, 0x46552165, 0x46562166,
+    0x46572167, 0x46582168, 0x46592169, 0x46610391, 0x46620392, 0x46630393, 0x46640394, 0x46650395,
+    0x46660396, 0x46670397, 0x46680398, 0x46690399, 0x466a039a, 0x466b039b, 0x466c039c, 0x466d039d,
+    0x466e039e, 0x466f039f, 0x467003a0, 0x467103a1, 0x467203a3, 0x467303a4, 0x467403a5, 0x467503a6,
+    0x467603a7, 0x467703a8, 0x467803a9, 0x468103b1, 0x468203b2, 0x468303b3, 0x468403b4, 0x468503b5,
+    0x468603b6, 0x468703b7, 0x468803b8, 0x468903b9, 0x468a03ba, 0x468b03bb, 0x468c03bc, 0x468d03bd,
+    0x468e03be, 0x468f03bf, 0x469003c0, 0x469103c1, 0x469203c3, 0x469303c4, 0x469403c5, 0x469503c6,
+    0x469603c7, 0x469703c8, 0x469803c9, 0x47412500, 0x47422502, 0x4743250c, 0x47442510, 0x47452518,
+    0x47462514, 0x4747251c, 0x4748252c, 0x47492524, 0x474a2534, 0x474b253c, 0x474c2501, 0x474d2503,
+    0x474e250f, 0x474f2513, 0x4750251b, 0x47512517, 0x47522523, 0x47532533, 0x4754252b, 0x4755253b,
+    0x4756254b, 0x47572520, 0x4758252f, 0x47592528, 0x475a2537, 0x475b253f, 0x475c251d, 0x475d2530,
+    0x475e2525, 0x475f2538, 0x47602542, 0x47612512, 0x47622511, 0x4763251a, 0x47642519, 0x47652516,
+    0x47662515, 0x4767250e, 0x4768250d, 0x4769251e, 0x476a251f, 0x476b2521, 0x476c2522, 0x476d2526,
+    0x476e2527, 0x476f2529, 0x4770252a, 0x4771252d, 0x4772252e, 0x47732531, 0x47742532, 0x47752535,
+    0x47762536, 0x47772539, 0x4778253a, 0x4779253d, 0x477a253e, 0x477b2540, 0x477c2541, 0x477d2543,
+    0x477e2544, 0x477f2545, 0x47802546, 0x47812547, 0x47822548, 0x47832549, 0x4784254a, 0x48413395,
+    0x48423398, 0x484333a3, 0x484433a4, 0x484533a5, 0x484633a6, 0x48473399, 0x4848339a, 0x4849339b,
+    0x484a339f, 0x484b33a0, 0x484c33a1, 0x484d33a2, 0x484e33ca, 0x484f338d, 0x485033cf, 0x48513388,
+    0x48523389, 0x485333c8, 0x485433a7, 0x485533a8, 0x485633b0, 0x485733b1, 0x485833b2, 0x485933b3,
+    0x485a33b4, 0x485b33b5, 0x485c33b6, 0x485d33b7, 0x485e33b8, 0x485f33b9, 0x48603380, 0x48613381,
+    0x48623382, 0x48633383, 0x48643384, 0x486533ba, 0x486633bb, 0x486733bc, 0x486833bd, 0x486933be,
+    0x486a33bf, 0x486b3390, 0x486c3391, 0x486d3392, 0x486e3393, 0x486f3394, 0x48702126, 0x487133c0,
+    0x487233c1, 0x4873338a, 0x4874338b, 0x4875338c, 0x487633d6, 0x487733c5, 0x487833ad, 0x487933ae,
+    0x487a33af, 0x487b33db, 0x487c33a9, 0x487d33aa, 0x487e33ab, 0x487f33ac, 0x488033dd, 0x488133d0,
+    0x488233d3, 0x488333c3, 0x488433c9, 0x488533dc, 0x488633c6, 0x4941221d, 0x49422235, 0x4943222b,
+    0x4944222c, 0x49452208, 0x4946220b, 0x49472286, 0x49482287, 0x49492282, 0x494a2283, 0x494b222a,
+    0x494c2229, 0x494d2227, 0x494e2228, 0x494f21d2, 0x495021d4, 0x49512200, 0x49522203, 0x495300b4,
+    0x495402dc, 0x495502c7, 0x495602d8, 0x495702dd, 0x495802da, 0x495902d9, 0x495a00b8, 0x495b02db,
+    0x495c00a1, 0x495d00bf, 0x495e02d0, 0x495f222e, 0x49602211, 0x4961220f, 0x496200a4, 0x49632109,
+    0x49642030, 0x496525c1, 0x496625c0, 0x496725b7, 0x496825b6, 0x49692664, 0x496a2660, 0x496b2661,
+    0x496c2665, 0x496d2667, 0x496e2663, 0x496f25c9, 0x497025c8, 0x497125a3, 0x497225d0, 0x497325d1,
+    0x49742592, 0x497525a4, 0x497625a5, 0x497725a8, 0x497825a7, 0x497925a6, 0x497a25a9, 0x497b2668,
+    0x497c260f, 0x497d260e, 0x497e261c, 0x497f261e, 0x498000b6, 0x49812020, 0x49822021, 0x49832195,
+    0x49842197, 0x49852199, 0x49862196, 0x49872198, 0x4988266d, 0x49892669, 0x498a266a, 0x498b266c,
+    0x498c327f, 0x498d321c, 0x498e2116, 0x498f33c7, 0x49902122, 0x499133c2, 0x499233d8, 0x49932121,
+    0x499402ba, 0x4a410410, 0x4a420411, 0x4a430412, 0x4a440413, 0x4a450414, 0x4a460415, 0x4a470401,
+    0x4a480416, 0x4a490417, 0x4a4a0418, 0x4a4b0419, 0x4a4c041a, 0x4a4d041b, 0x4a4e041c, 0x4a4f041d,
+    0x4a50041e, 0x4a51041f, 0x4a520420, 0x4a530421, 0x4a540422, 0x4a550423, 0x4a560424, 0x4a570425,
+    0x4a580426, 0x4a590427, 0x4a5a0428, 0x4a5b0429, 0x4a5c042a, 0x4a5d042b, 0x4a5e042c, 0x4a5f042d,
+    0x4a60042e, 0x4a61042f, 0x4a620430, 0x4a630431, 0x4a640432, 0x4a650433, 0x4a660434, 0x4a670435,
+    0x4a680451, 0x4a690436, 0x4a6a0437, 0x4a6b0438, 0x4a6c0439, 0x4a6d043a, 0x4a6e043b, 0x4a6f043c,
+    0x4a70043d, 0x4a71043e, 0x4a72043f, 0x4a730440, 0x4a740441, 0x4a750442, 0x4a760443, 0x4a770444,
+    0x4a780445, 0x4a790446, 0x4a7a0447, 0x4a7b0448, 0x4a7c0449, 0x4a7d044a, 0x4a7e044b, 0x4a7f044c,
+    0x4a80044d, 0x4a81044e, 0x4a82044f, 0x4b4100c6, 0x4b4200d0, 0x4b4300aa, 0x4b440126, 0x4b460132,
+    0x4b48013f, 0x4b490141, 0x4b4a00d8, 0x4b4b0152, 0x4b4c00ba, 0x4b4d00de, 0x4b4e0166, 0x4b4f014a,
+    0x4b513260, 0x4b523261, 0x4b533262, 0x4b543263, 0x4b553264, 0x4b563265, 0x4b573266, 0x4b583267,
+    0x4b593268, 0x4b5a3269, 0x4b5b326a, 0x4b5c326b, 0x4b5d326c, 0x4b5e326d, 0x4b5f326e, 0x4b60326f,
+    0x4b613270, 0x4b623271, 0x4b633272, 0x4b643273, 0x4b653274, 0x4b663275, 0x4b673276, 0x4b683277,
+    0x4b693278, 0x4b6a3279, 0x4b6b327a, 0x4b6c327b, 0x4b6d24d0, 0x4b6e24d1, 0x4b6f24d2, 0x4b7024d3,
+    0x4b7124d4, 0x4b7224d5, 0x4b7324d6, 0x4b7424d7, 0x4b7524d8, 0x4b7624d9, 0x4b7724da, 0x4b7824db,
+    0x4b7924dc, 0x4b7a24dd, 0x4b7b24de, 0x4b7c24df, 0x4b7d24e0, 0x4b7e24e1, 0x4b7f24e2, 0x4b8024e3,
+    0x4b8124e4, 0x4b8224e5, 0x4b8324e6, 0x4b8424e7, 0x4b8524e8, 0x4b8624e9, 0x4b872460, 0x4b882461,
+    0x4b892462, 0x4b8a2463, 0x4b8b2464, 0x4b8c2465, 0x4b8d2466, 0x4b8e2467, 0x4b8f2468, 0x4b902469,
+    0x4b91246a, 0x4b92246b, 0x4b93246c, 0x4b94246d, 0x4b95246e, 0x4b9600bd, 0x4b972153, 0x4b982154,
+    0x4b9900bc, 0x4b9a00be, 0x4b9b215b, 0x4b9c215c, 0x4b9d215d, 0x4b9e215e, 0x4ba000e6, 0x4ba10111,
+    0x4ba200f0, 0x4ba30127, 0x4ba40131, 0x4ba50133, 0x4ba60138, 0x4ba70140, 0x4ba80142, 0x4ba900f8,
+    0x4baa0153, 0x4bab00df, 0x4bac00fe, 0x4bad0167, 0x4bae014b, 0x4baf0149, 0x4bb03200, 0x4bb13201,
+    0x4bb23202, 0x4bb33203, 0x4bb43204, 0x4bb53205, 0x4bb63206, 0x4bb73207, 0x4bb83208, 0x4bb93209,
+    0x4bba320a, 0x4bbb320b, 0x4bbc320c, 0x4bbd320d, 0x4bbe320e, 0x4bbf320f, 0x4bc03210, 0x4bc13211,
+    0x4bc23212, 0x4bc33213, 0x4bc43214, 0x4bc53215, 0x4bc63216, 0x4bc73217, 0x4bc83218, 0x4bc93219,
+    0x4bca321a, 0x4bcb321b, 0x4bcc249c, 0x4bcd249d, 0x4bce249e, 0x4bcf249f, 0x4bd024a0, 0x4bd124a1,
+    0x4bd224a2, 0x4bd324a3, 0x4bd424a4, 0x4bd524a5, 0x4bd624a6, 0x4bd724a7, 0x4bd824a8, 0x4bd924a9,
+    0x4bda24aa, 0x4bdb24ab, 0x4bdc24ac, 0x4bdd24ad, 0x4bde24ae, 0x4bdf24af, 0x4be024b0, 0x4be124b1,
+    0x4be224b2, 0x4be324b3, 0x4be424b4, 0x4be524b5, 0x4be62474, 0x4be72475, 0x4be82476, 0x4be92477,
+    0x4bea2478, 0x4beb2479, 0x4bec247a, 0x4bed247b, 0x4bee247c, 0x4bef247d, 0x4bf0247e, 0x4bf1247f,
+    0x4bf22480, 0x4bf32481, 0x4bf42482, 0x4bf500b9, 0x4bf600b2, 0x4bf700b3, 0x4bf82074, 0x4bf9207f,
+    0x4bfa2081, 0x4bfb2082, 0x4bfc2083, 0x4bfd2084, 0x50414f3d, 0x50424f73, 0x50435047, 0x504450f9,
+    0x504552a0, 0x504653ef, 0x50475475, 0x504854e5, 0x50495609, 0x504a5ac1, 0x504b5bb6, 0x504c6687,
+    0x504d67ef, 0x504e67b6, 0x504f6b4c, 0x50507a3c, 0x505182db, 0x50528304, 0x50538857, 0x50548888,
+    0x50558a36, 0x50568cc8, 0x50578dcf, 0x50588efb, 0x50598fe6, 0x505a99d5, 0x505b523b, 0x505c5374,
+    0x505d5404, 0x505ef843, 0x505f606a, 0x506064f1, 0x50616bbc, 0x506273cf, 0x5063811a, 0x506489ba,
+    0x506589d2, 0x506695a3, 0x5067520a, 0x506858be, 0x50695978, 0x506a59e6, 0x506b5e72, 0x506c5e79,
+    0x506d61c7, 0x506e63c0, 0x506f6746, 0x50706f97, 0x5071764e, 0x5072770b, 0x50737a08, 0x50747aff,
+    0x50757c21, 0x5076826e, 0x50778271, 0x5078809d, 0x50798aeb, 0x507a9593, 0x507b4e6b, 0x507c559d,
+    0x507d66f7, 0x507e6e34, 0x507f78a3, 0x50807aed, 0x50818910, 0x50827faf, 0x5083845b, 0x508497a8,
+    0x508552d8, 0x5086574e, 0x5087582a, 0x50885d4c, 0x5089611f, 0x508a61be, 0x508b6562, 0x508c67d1,
+    0x508d6a44, 0x508e6e1b, 0x508f7518, 0x509075b3, 0x509176e3, 0x50927d3a, 0x50939163, 0x50949451,
+    0x50959f95, 0x50965323, 0x50975cac, 0x50987532, 0x509980db, 0x509a9240, 0x509b9598, 0x509c5ca1,
+    0x509d525b, 0x509e59dc, 0x509f5d17, 0x50a05eb7, 0x50a15f3a, 0x50a25f4a, 0x50a36177, 0x50a46c5f,
+    0x50a57586, 0x50a67db1, 0x50a78941, 0x50a87ce0, 0x50a98154, 0x50aa8591, 0x50ab8b1b, 0x50ac92fc,
+    0x50ad964d, 0x50ae4ecb, 0x50af4ef7, 0x50b0500b, 0x50b151f1, 0x50b26137, 0x50b3613e, 0x50b46168,
+    0x50b56539, 0x50b669ea, 0x50b76f11, 0x50b875a5, 0x50b97686, 0x50ba76d6, 0x50bb7b87, 0x50bc82a5,
+    0x50bd84cb, 0x50be958b, 0x50bf5ba2, 0x50c05751, 0x50c1f901, 0x50c27cb3, 0x50c37fb9, 0x50c491b5,
+    0x50c553bb, 0x50c65c45, 0x50c75de8, 0x50c862d2, 0x50c9636e, 0x50ca64da, 0x50cb6e20, 0x50cc70ac,
+    0x50cd64e7, 0x50ce8ddd, 0x50cf907d, 0x50d0f902, 0x50d1f844, 0x50d292f8, 0x50d34e7e, 0x50d44ef6,
+    0x50d55065, 0x50d65dfe, 0x50d75efa, 0x50d86106, 0x50d98654, 0x50da8e47, 0x50db9375, 0x50dc9a2b,
+    0x50dd4e5e, 0x50de5091, 0x50df6770, 0x50e05109, 0x50e1528d, 0x50e26aa2, 0x50e377bc, 0x50e49ed4,
+    0x50e552ab, 0x50e6602f, 0x50e75048, 0x50e861a9, 0x50e963ed, 0x50ea64ca, 0x50eb683c, 0x50ec6a84,
+    0x50ed6fc0, 0x50ee89a1, 0x50ef9694, 0x50f09abc, 0x50f15805, 0x50f280a9, 0x50f3727d, 0x50f472ac,
+    0x50f57504, 0x50f67d79, 0x50f77e6d, 0x50f8898b, 0x50f98b74, 0x50fa9063, 0x50fb9d51, 0x50fc6f54,
+    0x50fd6c7a, 0x50fe7d50, 0x51417f3a, 0x51428a23, 0x5143517c, 0x5144614a, 0x51457b9d, 0x514684b9,
+    0x51478b19, 0x5148938c, 0x51494eac, 0x514a50be, 0x514b52c1, 0x514c52cd, 0x514d537f, 0x514e54fd,
+    0x514f5883, 0x51505e9a, 0x51515f91, 0x51526176, 0x515366bb, 0x51546243, 0x515564ce, 0x5156656c,
+    0x5157666f, 0x515866f4, 0x51596897, 0x515a6aa0, 0x515b6d87, 0x515c7085, 0x515d71b2, 0x515e749f,
+    0x515f74ca, 0x5160754a, 0x516175d9, 0x5162786c, 0x516378ec, 0x51647adf, 0x51657af6, 0x51667d45,
+    0x51677d93, 0x51688015, 0x5169803f, 0x516a811b, 0x516b8396, 0x516c8b66, 0x516d8f15, 0x516e9015,
+    0x516f93e1, 0x51709803, 0x51719838, 0x51729a5a, 0x51739be8, 0x51744fc2, 0x51755553, 0x51765951,
+    0x51775b63, 0x51785c46, 0x517960b8, 0x517a6212, 0x517b6842, 0x517c68b0, 0x517d6eaa, 0x517e754c,
+    0x517f7678, 0x518078ce, 0x5181f845, 0x51827a3d, 0x51837cfb, 0x51847e6b, 0x51857e7c, 0x51868a08,
+    0x51878aa1, 0x5188968e, 0x51898c3f, 0x518a96de, 0x518b9dc4, 0x518c53e9, 0x518d53e4, 0x518e544a,
+    0x518f5471, 0x519056fa, 0x519159d1, 0x51925b64, 0x51935eab, 0x519462f7, 0x51956537, 0x51966545,
+    0x51976572, 0x519867af, 0x51996772, 0x519a6cbd, 0x519b75fc, 0x519c7690, 0x519d8831, 0x519e77bd,
+    0x519f7a3f, 0x51a0777e, 0x51a18003, 0x51a280a1, 0x51a3818f, 0x51a482e6, 0x51a585c1, 0x51a688b4,
+    0x51a78a41, 0x51a88aa5, 0x51a9f903, 0x51aa8f9c, 0x51ab932e, 0x51ac96c7, 0x51ad9867, 0x51ae9ad8,
+    0x51af9f13, 0x51b054ed, 0x51b1659b, 0x51b266f2, 0x51b3688f, 0x51b47a40, 0x51b58c37, 0x51b69d60,
+    0x51b756f0, 0x51b85764, 0x51b95d11, 0x51ba6606, 0x51bb68b1, 0x51bc68cd, 0x51bd6efe, 0x51be889e,
+    0x51bf6c68, 0x51c0f904, 0x51c19aa8, 0x51c24f9b, 0x51c3516c, 0x51c45171, 0x51c5529f, 0x51c65b54,
+    0x51c75de5, 0x51c88ca2, 0x51c9606d, 0x51ca6050, 0x51cb62f1, 0x51cc63a7, 0x51cd653b, 0x51ce6831,
+    0x51cf69d3, 0x51d07a7a, 0x51d17b9c, 0x51d25be1, 0x51d36208, 0x51d4679c, 0x51d574dc, 0x51d679d1,
+    0x51d783d3, 0x51d8874c, 0x51d98a87, 0x51da8ab2, 0x51db904e, 0x51dc9846, 0x51dd5ed3, 0x51de69e8,
+    0x51df85ff, 0x51e090ed, 0x51e1970d, 0x51e2f905, 0x51e351a0, 0x51e45b98, 0x51e55bec, 0x51e66163,
+    0x51e768fa, 0x51e874d8, 0x51e96b3e, 0x51ea704c, 0x51eb76e5, 0x51ec7ba1, 0x51ed7f50, 0x51ee83c5,
+    0x51ef89c0, 0x51f08cab, 0x51f195dc, 0x51f29928, 0x51f39e1b, 0x51f4522e, 0x51f5605d, 0x51f662ec,
+    0x51f79002, 0x51f85149, 0x51f95321, 0x51fa58d9, 0x51fb5ee3, 0x51fc66e0, 0x51fd6d38, 0x51fe72c2,
+    0x524173d6, 0x52427b50, 0x524380f1, 0x5244945b, 0x52455366, 0x5246639b, 0x52477f6b, 0x52484e56,
+    0x52495080, 0x524a584a, 0x524b58de, 0x524c602a, 0x524d6127, 0x524e62d0, 0x524f69d0, 0x52509b41,
+    0x52515b8f, 0x52527d18, 0x525380b1, 0x52548f5f, 0x52554ea4, 0x525650d1, 0x525754ac, 0x525855ac,
+    0x52595b0c, 0x525a5da0, 0x525b5de7, 0x525c654e, 0x525d652a, 0x525e6821, 0x525f6a4b, 0x526072e1,
+    0x5261768e, 0x526277ef, 0x52637d5e, 0x52647ff9, 0x526581a0, 0x5266854e, 0x526786df, 0x52688f03,
+    0x52698f4e, 0x526a90ca, 0x526b9278, 0x526c9903, 0x526d9a55, 0x526e9bab, 0x526f4e18, 0x52704e45,
+    0x52714e5d, 0x52724ec7, 0x52734f5d, 0x52744ff1, 0x527550b4, 0x52765177, 0x527752fe, 0x52785340,
+    0x527953e3, 0x527a53e5, 0x527b548e, 0x527c5614, 0x527d57a2, 0x527e5abe, 0x527f5ad7, 0x52805bc7,
+    0x52815d87, 0x52825ed0, 0x528361fc, 0x528462d8, 0x52856551, 0x5286f846, 0x528767b8, 0x528867e9,
+    0x528969cb, 0x528a6b50, 0x528b6bc6, 0x528c6bec, 0x528d6c42, 0x528e6e9d, 0x528f7078, 0x529072d7,
+    0x52917396, 0x52927403, 0x5293750c, 0x529477bf, 0x529577e9, 0x52967a76, 0x52977d7f, 0x52988cfc,
+    0x529981fc, 0x529a8205, 0x529b820a, 0x529c82df, 0x529d8862, 0x529e89af, 0x529f8b33, 0x52a08ec0,
+    0x52a19011, 0x52a290b1, 0x52a39264, 0x52a498b6, 0x52a599d2, 0x52a69a45, 0x52a79ce9, 0x52a89dd7,
+    0x52a99f9c, 0x52aa570b, 0x52ab5c40, 0x52ac63ac, 0x52ad83ca, 0x52ae97a0, 0x52af9eb4, 0x52b07a98,
+    0x52b17fa4, 0x52b28ecd, 0x52b3541b, 0x52b490e1, 0x52b55800, 0x52b65c48, 0x52b76398, 0x52b87a9f,
+    0x52b95bae, 0x52ba5f13, 0x52bb7a79, 0x52bc7aae, 0x52bd8eac, 0x52be5026, 0x52bf5238, 0x52c052f8,
+    0x52c15377, 0x52c25708, 0x52c362f3, 0x52c46b0a, 0x52c57737, 0x52c653a5, 0x52c78e76, 0x52c895d5,
+    0x52c9673a, 0x52ca6ac3, 0x52cb6f70, 0x52cc8a6d, 0x52cd8dea, 0x52ce8ecc, 0x52cf994b, 0x52d06b78,
+    0x52d18cb4, 0x52d29b3c, 0x52d3f907, 0x52d453eb, 0x52d5572d, 0x52d6594e, 0x52d763c6, 0x52d873ea,
+    0x52d97845, 0x52da7aba, 0x52db8475, 0x52dc7cfe, 0x52dd898f, 0x52de8d73, 0x52df9035, 0x52e095a8,
+    0x52e15747, 0x52e27b60, 0x52e383cc, 0x52e4921e, 0x52e5f908, 0x52e66a58, 0x52e7514b, 0x52e8524b,
+    0x52e95287, 0x52ea621f, 0x52eb6975, 0x52ec68d8, 0x52ed9699, 0x52ee50c5, 0x52ef52e4, 0x52f05df9,
+    0x52f161c3, 0x52f265a4, 0x52f36839, 0x52f469ff, 0x52f56f0c, 0x52f6747e, 0x52f77b4b, 0x52f882b9,
+    0x52f983eb, 0x52fa89b2, 0x52fb8b39, 0x52fc8fd1, 0x52fd9949, 0x52fef909, 0x53414eca, 0x534264d2,
+    0x53436a8e, 0x53447434, 0x53457981, 0x534679bd, 0x5347887f, 0x5348887e, 0x5349895f, 0x534af90a,
+    0x534b9326, 0x534c53ca, 0x534d5c8c, 0x534e6025, 0x534f6271, 0x53506c72, 0x53517b08, 0x53527d1a,
+    0x53537d66, 0x53544e98, 0x53555162, 0x535677dc, 0x535780af, 0x53584f01, 0x53594f0e, 0x535a5176,
+    0x535b5180, 0x535c55dc, 0x535d5668, 0x535e573b, 0x535f57fa, 0x53605914, 0x53615947, 0x53625993,
+    0x53635bc4, 0x53645c90, 0x53658c48, 0x53665d0e, 0x53675e7e, 0x53685df1, 0x53695fcc, 0x536a6280,
+    0x536b65d7, 0x536c65e3, 0x536d671f, 0x536e675e, 0x536f68cb, 0x537068c4, 0x53716a5f, 0x53726b3a,
+    0x53736c23, 0x53746c7d, 0x53756c82, 0x53766dc7, 0x53777426, 0x5378742a, 0x537974a3, 0x537a7578,
+    0x537b757f, 0x537c7881, 0x537d78ef, 0x537e7947, 0x537f7948, 0x5380797a, 0x53817b95, 0x53827d00,
+    0x53837dba, 0x5384f847, 0x53858006, 0x5386802d, 0x5387808c, 0x5388671e, 0x53898a18, 0x538a8b4f,
+    0x538b8d77, 0x538c9321, 0x538d98e2, 0x538e9951, 0x538f9a0e, 0x53909a0f, 0x53919a65, 0x53929c2d,
+    0x53939e92, 0x53947dca, 0x53954f76, 0x53965409, 0x539762ee, 0x53986854, 0x539991d1, 0x539af848,
+    0x539b55ab, 0x539c513a, 0x539d61e6, 0x539e62cf, 0x539f62ff, 0x53a07cef, 0x53a190a3, 0x53a28afe,
+    0x53a36696, 0x53a47156, 0x53a596e3, 0x53a6637a, 0x53a7634f, 0x53a85357, 0x53a95583, 0x53aa7537,
+    0x53ab7d0d, 0x53ac56ca, 0x53ad5a18, 0x53ae66e9, 0x53af4e43, 0x53b05167, 0x53b15948, 0x53b267f0,
+    0x53b38010, 0x53b48ffa, 0x53b55973, 0x53b65e74, 0x53b779ca, 0x53b85ff5, 0x53b9606c, 0x53ba62c8,
+    0x53bb637b, 0x53bc4f5e, 0x53bd5be7, 0x53bef849, 0x53bf52aa, 0x53c05974, 0x53c15b65, 0x53c25f29,
+    0x53c36012, 0x53c47459, 0x53c56fc3, 0x53c681bf, 0x53c78fb2, 0x53c860f1, 0x53c98166, 0x53ca5c3f,
+    0x53cbf84a, 0x53cc5ae9, 0x53cd8a25, 0x53ce7d10, 0x53cf80fd, 0x53d05c3c, 0x53d16ce5, 0x53d2533f,
+    0x53d36eba, 0x53d4591a, 0x53d58336, 0x53d64e39, 0x53d74f46, 0x53d855ae, 0x53d95718, 0x53da58c7,
+    0x53db65b7, 0x53dc65e6, 0x53dd6a80, 0x53de6bb5, 0x53df6e4d, 0x53e077ed, 0x53e17aef, 0x53e27c1e,
+    0x53e37dde, 0x53e486cb, 0x53e58892, 0x53e69132, 0x53e7935b, 0x53e8977c, 0x53e9601b, 0x53ea64bb,
+    0x53eb737a, 0x53ec75b8, 0x53ed9054, 0x53ee95e5, 0x53ef97c3, 0x53f05556, 0x53f164d4, 0x53f266c7,
+    0x53f36de1, 0x53f46f6d, 0x53f56fb9, 0x53f675f0, 0x53f78043, 0x53f881bd, 0x53f98983, 0x53fa8ac7,
+    0x53fb8b5a, 0x53fc6c93, 0x53fd7b54, 0x53fe8e0f, 0x5441905d, 0x5442515a, 0x54435802, 0x54445858,
+    0x54455e62, 0x54465510, 0x5447649e, 0x544868e0, 0x54497576, 0x544a7cd6, 0x544b87f7, 0x544c9ee8,
+    0x544d4ee3, 0x544e5788, 0x544f5927, 0x54505c0d, 0x54515cb1, 0x54525e36, 0x54535f85, 0x54546234,
+    0x545564e1, 0x545673b3, 0x54577893, 0x545881fa, 0x5459888b, 0x545a8cb8, 0x545b968a, 0x545c9edb,
+    0x545d5b85, 0x545e5fb7, 0x545f60b3, 0x54605012, 0x54615200, 0x54625230, 0x54635716, 0x54645835,
+    0x54655857, 0x54665c0e, 0x54675c60, 0x54685cf6, 0x54695ea6, 0x546a5f92, 0x546b60bc, 0x546c6311,
+    0x546d6417, 0x546e6843, 0x546f68f9, 0x54706dd8, 0x54716e21, 0x54726ed4, 0x54736fe4, 0x547471fe,
+    0x547576dc, 0x54767779, 0x547779b1, 0x54787a3b, 0x54798404, 0x547a83df, 0x547b8ced, 0x547c8df3,
+    0x547d8e48, 0x547e9003, 0x547f9014, 0x54809053, 0x548190fd, 0x5482934d, 0x54839676, 0x548497dc,
+    0x54856bd2, 0x54867006, 0x54877258, 0x548872a2, 0x54897368, 0x548a7763, 0x548b79bf, 0x548c7be4,
+    0x548d7e9b, 0x548e8b80, 0x548f58a9, 0x549060c7, 0x54916566, 0x549266be, 0x54936c8c, 0x549471c9,
+    0x54958c5a, 0x54969813, 0x54975484, 0x54984e6d, 0x54997a81, 0x549a4edd, 0x549b51ac, 0x549c51cd,
+    0x549d52d5, 0x549e540c, 0x549f578c, 0x54a061a7, 0x54a16771, 0x54a26850, 0x54a368df, 0x54a46d1e,
+    0x54a56f7c, 0x54a67ae5, 0x54a775bc, 0x54a877b3, 0x54a98463, 0x54aa80f4, 0x54ab9285, 0x54ac515c,
+    0x54ad6597, 0x54ae675c, 0x54af6793, 0x54b075d8, 0x54b17ac7, 0x54b28373, 0x54b3809a, 0x54b486aa,
+    0x54b5f95a, 0x54b68c46, 0x54b7982d, 0x54b85c6f, 0x54b981c0, 0x54ba9041, 0x54bb906f, 0x54bc920d,
+    0x54bd5f97, 0x54be6a59, 0x54bf6ed5, 0x54c0706f, 0x54c171c8, 0x54c2767b, 0x54c37b49, 0x54c485e4,
+    0x54c58b04, 0x54c69127, 0x54c79419, 0x54c89a30, 0x54c95587, 0x54ca61f6, 0x54cb7669, 0x54cc7f85,
+    0x54cd863f, 0x54ce87ba, 0x54cf88f8, 0x54d0908f, 0x54d1f95c, 0x54d26d1b, 0x54d370d9, 0x54d47296,
+    0x54d573de, 0x54d67d61, 0x54d7843d, 0x54d8916a, 0x54d999f1, 0x54da4e82, 0x54db5375, 0x54dc5e71,
+    0x54dd6b04, 0x54de6b12, 0x54df703e, 0x54e0721b, 0x54e1862d, 0x54e29e1e, 0x54e3524c, 0x54e457d3,
+    0x54e58fa3, 0x54e65d50, 0x54e764e5, 0x54e8652c, 0x54e96b16, 0x54ea6feb, 0x54eb7c43, 0x54ec7e9c,
+    0x54ed85cd, 0x54ee8964, 0x54ef89bd, 0x54f062c9, 0x54f181d8, 0x54f2881f, 0x54f35eca, 0x54f46717,
+    0x54f56994, 0x54f66d6a, 0x54f772fc, 0x54f87405, 0x54f9746f, 0x54fa8782, 0x54fb90de, 0x54fc4f86,
+    0x54fd840a, 0x54fe51b7, 0x554163a0, 0x55427565, 0x55434eae, 0x55445169, 0x55455006, 0x554651c9,
+    0x55476881, 0x55486dbc, 0x55496a11, 0x554a7cae, 0x554b7cb1, 0x554c7ce7, 0x554d826f, 0x554e8ad2,
+    0x554f8f1b, 0x555091cf, 0x55519b4e, 0x55524fb6, 0x555352f5, 0x55545442, 0x55555eec, 0x5556623e,
+    0x555765c5, 0x55586ada, 0x55596ffe, 0x555a7658, 0x555b792a, 0x555c8182, 0x555d85dc, 0x555e616e,
+    0x555f8823, 0x55608821, 0x55619462, 0x556295ad, 0x55639a62, 0x55649a6a, 0x55659e97, 0x55669ece,
+    0x5567529b, 0x55686b77, 0x556966c6, 0x556a6adf, 0x556b701d, 0x556c792b, 0x556d8f62, 0x556e9742,
+    0x556f6190, 0x55706200, 0x55716523, 0x55726f23, 0x55737149, 0x55747df4, 0x5575806f, 0x557684ee,
+    0x55778f26, 0x55789023, 0x5579934a, 0x557a51bd, 0x557b52a3, 0x557c5217, 0x557d6d0c, 0x557e70c8,
+    0x557f88c2, 0x55805ec9, 0x55816582, 0x55826bae, 0x55836fc2, 0x55847c3e, 0x55857375, 0x55869b23,
+    0x55874ee4, 0x55884f36, 0x558956f9, 0x558a5dba, 0x558b601c, 0x558c6624, 0x558d6ce0, 0x558e73b2,
+    0x558f7f9a, 0x55907fce, 0x559182d3, 0x559286c9, 0x5593901e, 0x559496f6, 0x55959748, 0x55969234,
+    0x55979818, 0x55989f61, 0x55994f8b, 0x559a79ae, 0x559b91b4, 0x559c96b7, 0x559d52de, 0x559e6488,
+    0x559f6ad3, 0x55a06f66, 0x55a17210, 0x55a27018, 0x55a376e7, 0x55a48001, 0x55a5826b, 0x55a68606,
+    0x55a7865c, 0x55a88def, 0x55a98f05, 0x55aa8f64, 0x55ab9732, 0x55ac9b6f, 0x55ad9dfa, 0x55ae9e75,
+    0x55af6f09, 0x55b0788c, 0x55b1f84b, 0x55b27da0, 0x55b38f46, 0x55b49304, 0x55b59e93, 0x55b69e7f,
+    0x55b78ad6, 0x55b858df, 0x55b95f04, 0x55ba6727, 0x55bb7027, 0x55bc74cf, 0x55bd7c60, 0x55be807e,
+    0x55bf5121, 0x55c07262, 0x55c178ca, 0x55c27c5f, 0x55c3857e, 0x55c48a84, 0x55c58cf4, 0x55c68cc2,
+    0x55c796f7, 0x55c84e86, 0x55c950da, 0x55caf84c, 0x55cb5bee, 0x55cc5ed6, 0x55cd71ce, 0x55ce7642,
+    0x55cf77ad, 0x55d06599, 0x55d1804a, 0x55d284fc, 0x55d3907c, 0x55d49f8d, 0x55d550c2, 0x55d658d8,
+    0x55d75c62, 0x55d86a13, 0x55d96dda, 0x55da6f0f, 0x55db7d2f, 0x55dc7e37, 0x55dd8938, 0x55de964b,
+    0x55df9acf, 0x55e05289, 0x55e167f3, 0x55e269b4, 0x55e36d41, 0x55e46e9c, 0x55e57409, 0x55e67460,
+    0x55e77559, 0x55e87624, 0x55e9786b, 0x55ea8b2c, 0x55eb985e, 0x55ec516d, 0x55ed622e, 0x55ee9678,
+    0x55ef502b, 0x55f05d19, 0x55f16dea, 0x55f27db8, 0x55f38f2a, 0x55f45f8b, 0x55f56144, 0x55f66817,
+    0x55f7f961, 0x55f87abf, 0x55f99686, 0x55fa808b, 0x55fb52d2, 0x55fc51db, 0x55fd5ee9, 0x55fe51cc,
+    0x5641695e, 0x56427a1c, 0x56437dbe, 0x564483f1, 0x56459675, 0x56464fda, 0x56475229, 0x56485398,
+    0x5649540f, 0x564a5c65, 0x564b60a7, 0x564cf84d, 0x564d674e, 0x564e68a8, 0x564f6f13, 0x56507281,
+    0x565172f8, 0x56527406, 0x56537483, 0x565475e2, 0x565579bb, 0x56567f79, 0x56577c6c, 0x565887ad,
+    0x565988cf, 0x565a91cc, 0x565b91d0, 0x565c96e2, 0x565d9b51, 0x565e9bc9, 0x565f541d, 0x566071d0,
+    0x56617498, 0x566285fa, 0x56638eaa, 0x566496a3, 0x56659c57, 0x56669e9f, 0x56676797, 0x56686dcb,
+    0x56697433, 0x566a75f3, 0x566b81e8, 0x566c9716, 0x566d5ca6, 0x566e782c, 0x566f7acb, 0x56707b20,
+    0x56717c92, 0x56725abd, 0x56736469, 0x5674746a, 0x567575f2, 0x567678e8, 0x567787c7, 0x567899ac,
+    0x56799b54, 0x567a9ebb, 0x567b5bde, 0x567c5e55, 0x567d6f20, 0x567e819c, 0x567f83ab, 0x56805a29,
+    0x56815dd2, 0x56825f4e, 0x56836162, 0x5684633d, 0x56856669, 0x568666fc, 0x56876eff, 0x56886f2b,
+    0x56897063, 0x568a779e, 0x568b842c, 0x568c8513, 0x568d883b, 0x568e8b3e, 0x568f8f13, 0x569093dd,
+    0x56919945, 0x56929b18, 0x56939c3b, 0x569462b9, 0x5695672b, 0x56966cab, 0x5697896a, 0x5698977a,
+    0x56994ea1, 0x569a5984, 0x569b5fd8, 0x569c5fd9, 0x569d671b, 0x569e7db2, 0x569f7f54, 0x56a08292,
+    0x56a1832b, 0x56a283bd, 0x56a39099, 0x56a49b4d, 0x56a557cb, 0x56a659b9, 0x56a75a92, 0x56a85bd0,
+    0x56a96627, 0x56aa679a, 0x56ab6885, 0x56ac6bcf, 0x56ad7164, 0x56ae771b, 0x56af7f75, 0x56b082fa,
+    0x56b18cb7, 0x56b28ce3, 0x56b39081, 0x56b49b45, 0x56b58108, 0x56b68109, 0x56b78c8a, 0x56b8964c,
+    0x56b99a40, 0x56ba9ea5, 0x56bb5b5f, 0x56bc6c13, 0x56bd731b, 0x56be76df, 0x56bf76f2, 0x56c0840c,
+    0x56c151aa, 0x56c28993, 0x56c34fdb, 0x56c4514d, 0x56c552c9, 0x56c65195, 0x56c768c9, 0x56c87704,
+    0x56c97720, 0x56ca7dbf, 0x56cb7dec, 0x56cc9762, 0x56cd9eb5, 0x56ce6ec5, 0x56cf8511, 0x56d0547d,
+    0x56d151a5, 0x56d2540d, 0x56d39cf4, 0x56d4660e, 0x56d5669d, 0x56d66e9f, 0x56d776bf, 0x56d88317,
+    0x56d9879f, 0x56da9169, 0x56db9298, 0x56dc8882, 0x56dd4fae, 0x56de52df, 0x56df5e3d, 0x56e059c6,
+    0x56e16155, 0x56e26478, 0x56e366ae, 0x56e45192, 0x56e567d0, 0x56e66a21, 0x56e76bcd, 0x56e86bdb,
+    0x56e9725f, 0x56ea7261, 0x56eb7738, 0x56ec77db, 0x56ed8004, 0x56ee8017, 0x56ef8305, 0x56f08b00,
+    0x56f18b28, 0x56f28c8c, 0x56f3927e, 0x56f46728, 0x56f56c90, 0x56f67267, 0x56f776ee, 0x56f87766,
+    0x56f97a46, 0x56fa6b7f, 0x56fb6c92, 0x56fc5922, 0x56fd6726, 0x56fe6fdb, 0x574177c7, 0x57428499,
+    0x5743536f, 0x57445893, 0x57455999, 0x57465edf, 0x574763cf, 0x57486634, 0x57496773, 0x574a6e3a,
+    0x574b732b, 0x574c82d7, 0x574d9328, 0x574e52d9, 0x574f5deb, 0x575061cb, 0x5751620a, 0x575262c7,
+    0x575364ab, 0x575465e0, 0x57556b66, 0x57566bcb, 0x57577121, 0x5758755d, 0x57597e46, 0x575a821e,
+    0x575b8302, 0x575c856a, 0x575d8aa3, 0x575e8cbf, 0x575f9727, 0x57609d61, 0x576158a8, 0x57629ed8,
+    0x5763520e, 0x5764543b, 0x57656587, 0x57666c76, 0x57677d0b, 0x57687d0a, 0x5769868a, 0x576a9580,
+    0x576b554f, 0x576c805e, 0x576d52ff, 0x576e7269, 0x576f5473, 0x57705a9a, 0x57715c3e, 0x57725d4b,
+    0x57739761, 0x57745f4c, 0x57755fae, 0x5776672a, 0x57776e3c, 0x57787030, 0x57797709, 0x577a7c73,
+    0x577b7cdc, 0x577c7f8e, 0x577d8587, 0x577e8b0e, 0x577f8ff7, 0x578060b6, 0x5781610d, 0x578261ab,
+    0x5783654f, 0x578465fc, 0x578565fb, 0x57866c11, 0x57876cef, 0x5788739f, 0x578973c9, 0x578a9594,
+    0x578b95a9, 0x578c5bc6, 0x578d871c, 0x578e8b10, 0x578ff84e, 0x5790525d, 0x5791535a, 0x579262cd,
+    0x5793640f, 0x579464b2, 0x57956734, 0x57966a38, 0x57976b02, 0x57986cca, 0x57997254, 0x579a73c0,
+    0x579b749e, 0x579c7b94, 0x579d7e1b, 0x579e7c95, 0x579f818a, 0x57a08236, 0x57a18584, 0x57a28feb,
+    0x57a396f9, 0x57a499c1, 0x57a54f34, 0x57a6534a, 0x57a753cd, 0x57a853db, 0x57a962cc, 0x57aa642c,
+    0x57ab6500, 0x57ac6591, 0x57ad69c3, 0x57ae6f58, 0x57af6cee, 0x57b073ed, 0x57b17554, 0x57b276e4,
+    0x57b378fb, 0x57b4792c, 0x57b57d46, 0x57b680d6, 0x57b787e0, 0x57b8822c, 0x57b98fd4, 0x57ba9812,
+    0x57bb98ef, 0x57bc52c3, 0x57bd62d4, 0x57be6f51, 0x57bf64a5, 0x57c0767c, 0x57c18dcb, 0x57c291b1,
+    0x57c39262, 0x57c49aee, 0x57c59b43, 0x57c65023, 0x57c7508d, 0x57c8574a, 0x57c959a8, 0x57ca5c28,
+    0x57cb5e47, 0x57cc5f77, 0x57cd623f, 0x57ce653e, 0x57cf65b9, 0x57d065c1, 0x57d16609, 0x57d2699c,
+    0x57d37d21, 0x57d480aa, 0x57d58180, 0x57d6822b, 0x57d782b3, 0x57d884a1, 0x57d9868c, 0x57da8a2a,
+    0x57db8b17, 0x57dc90a6, 0x57dd9632, 0x57de9ae3, 0x57df9b74, 0x57e09f90, 0x57e14ff3, 0x57e2500d,
+    0x57e3f963, 0x57e457f9, 0x57e55f98, 0x57e662dc, 0x57e76392, 0x57e8676f, 0x57e96e43, 0x57ea76c3,
+    0x57eb80da, 0x57ec80cc, 0x57ed88f5, 0x57ee88f4, 0x57ef8919, 0x57f08ce0, 0x57f18f29, 0x57f2914d,
+    0x57f3966a, 0x57f44f2f, 0x57f54f70, 0x57f65e1b, 0x57f767cf, 0x57f86822, 0x57f9767d, 0x57fa767e,
+    0x57fb9b44, 0x57fc6a0a, 0x57fdf84f, 0x57fe7169, 0x5841756a, 0x5842f964, 0x58437e41, 0x58447ffb,
+    0x58458543, 0x584685e9, 0x584798dc, 0x58484f10, 0x58497b4f, 0x584a7f70, 0x584b95a5, 0x584c51e1,
+    0x584d5e06, 0x584e68b5, 0x584f6c3e, 0x58506c4e, 0x58516cdb, 0x585272af, 0x58537bc4, 0x58548303,
+    0x58556cd5, 0x5856743a, 0x585750fb, 0x58585288, 0x585958c1, 0x585a64d8, 0x585b74a7, 0x585c7656,
+    0x585d78a7, 0x585e8f9f, 0x585f95e2, 0x58609739, 0x5861f965, 0x5862535e, 0x58635f01, 0x58646c74,
+    0x58658fae, 0x58668b8a, 0x58678fa8, 0x58688faf, 0x5869f850, 0x586a908a, 0x586b99e2, 0x586c5225,
+    0x586d5f46, 0x586e77a5, 0x586f9c49, 0x58709f08, 0x58714e19, 0x58725002, 0x58735175, 0x58745c5b,
+    0x58755e77, 0x5876661e, 0x5877663a, 0x587867c4, 0x587970b3, 0x587a7501, 0x587b75c5, 0x587c7add,
+    0x587d79c9, 0x587e8f27, 0x587f8ff8, 0x58809920, 0x58814fdd, 0x58825821, 0x58835831, 0x58845bf6,
+    0x5885666e, 0x58866b65, 0x58876e7a, 0x58886d11, 0x58896f7d, 0x588a752b, 0x588b88dc, 0x588c8913,
+    0x588d8b5c, 0x588e8f14, 0x588f9d07, 0x58909efc, 0x58914f0f, 0x589250d5, 0x58935310, 0x5894535c,
+    0x58955b93, 0x58965fa9, 0x5897798f, 0x5898670d, 0x58998179, 0x589a832f, 0x589b8514, 0x589c8986,
+    0x589d8760, 0x589e8907, 0x589f8f3b, 0x58a099a5, 0x58a19c12, 0x58a2672c, 0x58a34e76, 0x58a44e30,
+    0x58a54ff8, 0x58a65949, 0x58a75c01, 0x58a85cf0, 0x58a95cef, 0x58aa6367, 0x58ab742b, 0x58ac68d2,
+    0x58ad70fd, 0x58ae7e2b, 0x58af8451, 0x58b084ec, 0x58b18702, 0x58b29022, 0x58b392d2, 0x58b49cf3,
+    0x58b54ec6, 0x58b64ed8, 0x58b75085, 0x58b85256, 0x58b9526f, 0x58ba5426, 0x58bb57e0, 0x58bc592b,
+    0x58bd5a66, 0x58be5b75, 0x58bf5bcc, 0x58c05e9c, 0x58c1f966, 0x58c26276, 0x58c36577, 0x58c465a7,
+    0x58c56d6e, 0x58c66ea5, 0x58c77236, 0x58c87c3f, 0x58c97f36, 0x58ca7b26, 0x58cb7f58, 0x58cc8151,
+    0x58cd8150, 0x58ce819a, 0x58cf8299, 0x58d082fb, 0x58d18709, 0x58d28a03, 0x58d38ca0, 0x58d48ce6,
+    0x58d58cfb, 0x58d68d74, 0x58d78dba, 0x58d890e8, 0x58d991dc, 0x58da961c, 0x58db9644, 0x58dc982b,
+    0x58dd99d9, 0x58de9b92, 0x58df9ce7, 0x58e05317, 0x58e15206, 0x58e25674, 0x58e3f851, 0x58e458b3,
+    0x58e55954, 0x58e6596e, 0x58e75fff, 0x58e861a4, 0x58e9626e, 0x58ea6c7e, 0x58eb711a, 0x58ec76c6,
+    0x58ed7c89, 0x58ee7cde, 0x58ef7d1b, 0x58f082ac, 0x58f18561, 0x58f296f0, 0x58f3f967, 0x58f44f5b,
+    0x58f5f852, 0x58f65f17, 0x58f762c2, 0x58f89af4, 0x58f99efb, 0x58fa5d29, 0x58fb670b, 0x58fc68da,
+    0x58fd787c, 0x58fe7e43, 0x59419d6c, 0x59424e15, 0x59435099, 0x59445315, 0x5945532a, 0x59465351,
+    0x59475983, 0x594859a3, 0x59495a62, 0x594a5e87, 0x594b60b2, 0x594c618a, 0x594d6249, 0x594e6279,
+    0x594f6590, 0x5950f853, 0x595169a7, 0x59526c98, 0x5953f968, 0x59546cb8, 0x59556bd4, 0x59566bd8,
+    0x59577435, 0x595875fa, 0x59597765, 0x595a7812, 0x595b7891, 0x595c79d8, 0x595d7be6, 0x595e7dcb,
+    0x595f7fe1, 0x596080a5, 0x59618153, 0x5962813e, 0x596381c2, 0x596483f2, 0x5965871a, 0x596688e8,
+    0x59678ab9, 0x59688b6c, 0x59698cbb, 0x596a8f61, 0x596b9119, 0x596c975e, 0x596d98db, 0x596e9ac0,
+    0x596f9f3b, 0x59705b2a, 0x59718cd3, 0x59725f6c, 0x597364ef, 0x5974658c, 0x59756ab3, 0x59766baf,
+    0x59776ff1, 0x59787015, 0x5979725d, 0x597a860b, 0x597b8ca7, 0x597cf854, 0x597d983b, 0x597e9870,
+    0x597f9b22, 0x59806191, 0x59816c37, 0x59828058, 0x59839a01, 0x59844e4d, 0x59854e8b, 0x59864e9b,
+    0x59874ed5, 0x59884f3c, 0x59894f3a, 0x598a820d, 0x598b4f7f, 0x598c50ff, 0x598d53f2, 0x598e53f8,
+    0x598f56db, 0x59905506, 0x599155e3, 0x599258eb, 0x59935962, 0x599459d2, 0x59955a11, 0x59965beb,
+    0x59975bfa, 0x59985df3, 0x59995e2b, 0x599af855, 0x599b5f99, 0x599c601d, 0x599d6368, 0x599e8d66,
+    0x599f65af, 0x59a0659c, 0x59a167fb, 0x59a268ad, 0x59a36942, 0x59a469ce, 0x59a56c99, 0x59a66cd7,
+    0x59a76e23, 0x59a87009, 0x59a990aa, 0x59aa7345, 0x59ab7940, 0x59ac793e, 0x59ad7960, 0x59ae6b7b,
+    0x59af7802, 0x59b079c1, 0x59b17b25, 0x59b27be9, 0x59b384d1, 0x59b47d17, 0x59b57d72, 0x59b6838e,
+    0x59b786c7, 0x59b888df, 0x59b98a50, 0x59ba8a5e, 0x59bb8b1d, 0x59bc8cdc, 0x59bd5c04, 0x59be8fad,
+    0x59bf8086, 0x59c098fc, 0x59c199df, 0x59c2524a, 0x59c3f969, 0x59c469ca, 0x59c56714, 0x59c6f96a,
+    0x59c79460, 0x59c85098, 0x59c9522a, 0x59ca5c71, 0x59cb6563, 0x59cc6c55, 0x59cd73ca, 0x59ce7523,
+    0x59cf759d, 0x59d07b97, 0x59d1849c, 0x59d29178, 0x59d36492, 0x59d46bba, 0x59d54e77, 0x59d685a9,
+    0x59d74e09, 0x59d8f96b, 0x59d96749, 0x59da68ee, 0x59db6e17, 0x59dc886b, 0x59dd829f, 0x59de8518,
+    0x59df6b43, 0x59e063f7, 0x59e16f81, 0x59e298af, 0x59e39364, 0x59e4970e, 0x59e54e0a, 0x59e650b7,
+    0x59e750cf, 0x59e8511f, 0x59e95546, 0x59ea55aa, 0x59eb5617, 0x59ec723d, 0x59ed5b40, 0x59ee5c19,
+    0x59ef5e8a, 0x59f05ea0, 0x59f15ec2, 0x59f25e38, 0x59f360f3, 0x59f476f8, 0x59f56851, 0x59f66a61,
+    0x59f76ba4, 0x59f86e58, 0x59f972c0, 0x59fa7240, 0x59fb7965, 0x59fc7bb1, 0x59fd7fd4, 0x59fe88f3,
+    0x5a4189f4, 0x5a428c61, 0x5a438cde, 0x5a448a73, 0x5a45971c, 0x5a46585e, 0x5a4774bd, 0x5a488cfd,
+    0x5a499c13, 0x5a4af96c, 0x5a4b7a61, 0x5a4c7d22, 0x5a4d8272, 0x5a4e7272, 0x5a4f751f, 0x5a507525,
+    0x5a51f96d, 0x5a527b19, 0x5a53566c, 0x5a5458fb, 0x5a555885, 0x5a565a7f, 0x5a577280, 0x5a585dbc,
+    0x5a595e8f, 0x5a5a5eb6, 0x5a5b5f90, 0x5a5c6055, 0x5a5d6292, 0x5a5e654d, 0x5a5f6691, 0x5a6066d9,
+    0x5a6166f8, 0x5a626816, 0x5a6368f2, 0x5a64745e, 0x5a657d6e, 0x5a667dd6, 0x5a677f72, 0x5a6880e5,
+    0x5a698212, 0x5a6a85af, 0x5a6b897f, 0x5a6c8a93, 0x5a6d92e4, 0x5a6e901d, 0x5a6f9ecd, 0x5a70f856,
+    0x5a719f20, 0x5a725915, 0x5a73596d, 0x5a74f857, 0x5a755e2d, 0x5a7660dc, 0x5a776614, 0x5a786673,
+    0x5a796790, 0x5a7a6c50, 0x5a7b6dc5, 0x5a7c6f5f, 0x5a7d77f3, 0x5a7e78a9, 0x5a7f84c6, 0x5a8091cb,
+    0x5a81932b, 0x5a825148, 0x5a834ed9, 0x5a8450ca, 0x5a85f858, 0x5a865584, 0x5a875ba3, 0x5a885c20,
+    0x5a896103, 0x5a8a6247, 0x5a8b65cb, 0x5a8cf859, 0x5a8d717d, 0x5a8e71f9, 0x5a8f79aa, 0x5a907444,
+    0x5a917487, 0x5a9274bf, 0x5a93766c, 0x5a947dda, 0x5a957e55, 0x5a967fa8, 0x5a97817a, 0x5a9881b3,
+    0x5a998239, 0x5a9a861a, 0x5a9b87ec, 0x5a9c8a75, 0x5a9d8de3, 0x5a9e9078, 0x5a9f9291, 0x5aa0f85a,
+    0x5aa19bae, 0x5aa25070, 0x5aa35368, 0x5aa4820c, 0x5aa5f85b, 0x5aa65c51, 0x5aa76308, 0x5aa86954,
+    0x5aa96d29, 0x5aaa6e2b, 0x5aab859b, 0x5aac8a2d, 0x5aad8aaa, 0x5aae96ea, 0x5aaf6bb2, 0x5ab07e96,
+    0x5ab187fe, 0x5ab28d0d, 0x5ab366b9, 0x5ab49583, 0x5ab571ee, 0x5ab6651d, 0x5ab76d89, 0x5ab8f96e,
+    0x5ab957ce, 0x5aba59d3, 0x5abb6027, 0x5abc60fa, 0x5abd6210, 0x5abe661f, 0x5abf665f, 0x5ac07329,
+    0x5ac176db, 0x5ac27701, 0x5ac37b6c, 0x5ac48056, 0x5ac58072, 0x5ac68165, 0x5ac78aa0, 0x5ac89192,
+    0x5ac94e16, 0x5aca52e2, 0x5acb6b72, 0x5acc6d17, 0x5acd7a05, 0x5ace7b39, 0x5acf7d30, 0x5ad0f96f,
+    0x5ad18cb0, 0x5ad253ec, 0x5ad3562f, 0x5ad45851, 0x5ad55c0f, 0x5ad65c11, 0x5ad75bb5, 0x5ad85de2,
+    0x5ad96383, 0x5ada6414, 0x5adb6240, 0x5adc662d, 0x5add68b3, 0x5ade6cbc, 0x5adf6d88, 0x5ae06eaf,
+    0x5ae1701f, 0x5ae270a4, 0x5ae371d2, 0x5ae47526, 0x5ae5758e, 0x5ae6758f, 0x5ae77b11, 0x5ae87c2b,
+    0x5ae97d20, 0x5aea7d39, 0x5aeb8258, 0x5aec852c, 0x5aed856d, 0x5aee8607, 0x5aef8a34, 0x5af0900d,
+    0x5af19061, 0x5af290b5, 0x5af392b7, 0x5af49704, 0x5af597f6, 0x5af69a37, 0x5af74fd7, 0x5af85c6c,
+    0x5af9675f, 0x5afa7c9f, 0x5afb7e8c, 0x5afc901f, 0x5afd5b6b, 0x5afe640d, 0x5b41905c, 0x5b427387,
+    0x5b4387c0, 0x5b445b8b, 0x5b45677e, 0x5b468a1f, 0x5b478aa6, 0x5b489001, 0x5b49980c, 0x5b4a5237,
+    0x5b4bf970, 0x5b4c7051, 0x5b4d7463, 0x5b4e788e, 0x5b4f9396, 0x5b508870, 0x5b5191d7, 0x5b524fee,
+    0x5b5353d7, 0x5b5453df, 0x5b5556da, 0x5b5655fd, 0x5b575782, 0x5b5858fd, 0x5b595ac2, 0x5b5a5b88,
+    0x5b5bf85c, 0x5b5c5cab, 0x5b5d5e25, 0x5b5e620d, 0x5b5f6101, 0x5b60624b, 0x5b616388, 0x5b62641c,
+    0x5b636536, 0x5b646578, 0x5b65666c, 0x5b666a39, 0x5b676b8a, 0x5b6871e7, 0x5b696c34, 0x5b6a6d19,
+    0x5b6b6eb2, 0x5b6c6f31, 0x5b6d72e9, 0x5b6e7378, 0x5b6f795f, 0x5b707626, 0x5b717761, 0x5b72f85d,
+    0x5b7379c0, 0x5b747a57, 0x5b757aea, 0x5b767cb9, 0x5b777d8f, 0x5b787dac, 0x5b797e61, 0x5b7a7f9e,
+    0x5b7b8129, 0x5b7c8331, 0x5b7d8490, 0x5b7e84da, 0x5b7f85ea, 0x5b808896, 0x5b818ab0, 0x5b828f38,
+    0x5b839042, 0x5b849083, 0x5b85916c, 0x5b869296, 0x5b8792b9, 0x5b88968b, 0x5b8996a8, 0x5b8a96d6,
+    0x5b8b9700, 0x5b8c9808, 0x5b8d9996, 0x5b8e9ad3, 0x5b8f9b1a, 0x5b9053d4, 0x5b91587e, 0x5b925919,
+    0x5b935b70, 0x5b945bbf, 0x5b956dd1, 0x5b96719f, 0x5b978085, 0x5b9883fd, 0x5b995faa, 0x5b9a6042,
+    0x5b9b65ec, 0x5b9c696f, 0x5b9d6b89, 0x5b9e6d35, 0x5b9f6df3, 0x5ba073e3, 0x5ba176fe, 0x5ba277ac,
+    0x5ba37b4d, 0x5ba47d14, 0x5ba58123, 0x5ba6821c, 0x5ba78340, 0x5ba88a62, 0x5ba95de1, 0x5baa9187,
+    0x5bab931e, 0x5bac9806, 0x5bad99b4, 0x5bae9d89, 0x5baf620c, 0x5bb08853, 0x5bb18ff0, 0x5bb25d07,
+    0x5bb35d27, 0x5bb45d69, 0x5bb5745f, 0x5bb6819d, 0x5bb78671, 0x5bb88768, 0x5bb962fe, 0x5bba6fd5,
+    0x5bbb7fd2, 0x5bbc8936, 0x5bbd8972, 0x5bbe4e1e, 0x5bbf4e58, 0x5bc050e7, 0x5bc15347, 0x5bc2627f,
+    0x5bc36607, 0x5bc452dd, 0x5bc57e69, 0x5bc68805, 0x5bc7965e, 0x5bc84f8d, 0x5bc95319, 0x5bca557b,
+    0x5bcb5636, 0x5bcc59cb, 0x5bcd5aa4, 0x5bcef85e, 0x5bcf5c38, 0x5bd05c4d, 0x5bd15e02, 0x5bd25f11,
+    0x5bd36043, 0x5bd465bd, 0x5bd5662f, 0x5bd66642, 0x5bd767be, 0x5bd867f4, 0x5bd9731c, 0x5bda77e2,
+    0x5bdb793a, 0x5bdc8996, 0x5bdd7de6, 0x5bde7fc5, 0x5bdf8494, 0x5be084cd, 0x5be18a69, 0x5be28a66,
+    0x5be38ae1, 0x5be48c7a, 0x5be557f4, 0x5be65bd4, 0x5be75f0f, 0x5be8606f, 0x5be962ed, 0x5bea690d,
+    0x5beb6b96, 0x5bec6e5c, 0x5bed7184, 0x5bee8b58, 0x5bef8efe, 0x5bf098df, 0x5bf198fe, 0x5bf24f38,
+    0x5bf34fe1, 0x5bf4547b, 0x5bf55a20, 0x5bf65bb8, 0x5bf7613c, 0x5bf865b0, 0x5bf96668, 0x5bfa71fc,
+    0x5bfb7533, 0x5bfc77e7, 0x5bfd795e, 0x5bfe7d33, 0x5c41814e, 0x5c4281e3, 0x5c438398, 0x5c4485aa,
+    0x5c458703, 0x5c468a0a, 0x5c478eab, 0x5c488f9b, 0x5c49f971, 0x5c4a8fc5, 0x5c4b5931, 0x5c4c5ba4,
+    0x5c4d5be6, 0x5c4e6089, 0x5c4ff85f, 0x5c505be9, 0x5c515c0b, 0x5c525fc3, 0x5c53f972, 0x5c546c81,
+    0x5c556df1, 0x5c56700b, 0x5c57751a, 0x5c5882af, 0x5c594ec0, 0x5c5a5341, 0x5c5bf973, 0x5c5c96d9,
+    0x5c5d6c0f, 0x5c5e4e9e, 0x5c5f4fc4, 0x5c605152, 0x5c615a25, 0x5c625ce8, 0x5c636211, 0x5c647259,
+    0x5c6582bd, 0x5c6686fe, 0x5c678859, 0x5c688a1d, 0x5c69963f, 0x5c6a96c5, 0x5c6b9913, 0x5c6c9d09,
+    0x5c6d9d5d, 0x5c6e580a, 0x5c6f5cb3, 0x5c705dbd, 0x5c716115, 0x5c7260e1, 0x5c7363e1, 0x5c746a02,
+    0x5c756e25, 0x5c76843c, 0x5c77984e, 0x5c789f77, 0x5c795b89, 0x5c7a5cb8, 0x5c7b6309, 0x5c7c664f,
+    0x5c7d6848, 0x5c7e773c, 0x5c7f96c1, 0x5c808d0b, 0x5c81978d, 0x5c829854, 0x5c839d08, 0x5c84621e,
+    0x5c8565a1, 0x5c868b01, 0x5c878ecb, 0x5c88904f, 0x5c8995bc, 0x5c8a5d52, 0x5c8b5dd6, 0x5c8c5eb5,
+    0x5c8d6697, 0x5c8e764c, 0x5c8f83f4, 0x5c908af3, 0x5c9195c7, 0x5c929eef, 0x5c9358d3, 0x5c9462bc,
+    0x5c9572ce, 0x5c969d28, 0x5c974ef0, 0x5c98592e, 0x5c99600f, 0x5c9a663b, 0x5c9b6b83, 0x5c9c79e7,
+    0x5c9d9785, 0x5c9e9d26, 0x5c9f5393, 0x5ca057c3, 0x5ca15d16, 0x5ca2611b, 0x5ca36b38, 0x5ca46daf,
+    0x5ca5769a, 0x5ca666d6, 0x5ca7788d, 0x5ca87919, 0x5ca9827e, 0x5caa54c0, 0x5cab9698, 0x5cac9744,
+    0x5cad9749, 0x5cae5384, 0x5caf6396, 0x5cb06db2, 0x5cb1814b, 0x5cb29628, 0x5cb3984d, 0x5cb46afb,
+    0x5cb57f4c, 0x5cb69daf, 0x5cb79e1a, 0x5cb84e5f, 0x5cb9503b, 0x5cba51b6, 0x5cbb591c, 0x5cbcf860,
+    0x5cbd63f6, 0x5cbe6930, 0x5cbf723a, 0x5cc08036, 0x5cc1f974, 0x5cc260f9, 0x5cc391ce, 0x5cc45f31,
+    0x5cc57bdb, 0x5cc67c65, 0x5cc77d04, 0x5cc882e5, 0x5cc9846f, 0x5cca85e5, 0x5ccb8e8d, 0x5ccc4f6f,
+    0x5ccd58e4, 0x5cce5b43, 0x5ccf6059, 0x5cd063da, 0x5cd16518, 0x5cd2656d, 0x5cd36698, 0x5cd4694a,
+    0x5cd56a23, 0x5cd66d0b, 0x5cd77001, 0x5cd8716c, 0x5cd97a70, 0x5cda75d2, 0x5cdb760d, 0x5cdc7f8a,
+    0x5cdd7f98, 0x5cde8944, 0x5cdf8b93, 0x5ce091c0, 0x5ce1967d, 0x5ce2990a, 0x5ce35704, 0x5ce45fa1,
+    0x5ce56554, 0x5ce665bc, 0x5ce76f01, 0x5ce879a6, 0x5ce98a9e, 0x5cea99ad, 0x5ceb9b5a, 0x5cec9f6c,
+    0x5ced5104, 0x5cee61b6, 0x5cef6291, 0x5cf06a8d, 0x5cf181c6, 0x5cf25043, 0x5cf35830, 0x5cf45f66,
+    0x5cf57109, 0x5cf68a00, 0x5cf78afa, 0x5cf85b7c, 0x5cf9513c, 0x5cfa56b4, 0x5cfb5944, 0x5cfc63a9,
+    0x5cfd6df9, 0x5cfe5daa, 0x5d41696d, 0x5d42605a, 0x5d434e88, 0x5d444f59, 0x5d455982, 0x5d466b5f,
+    0x5d476c5d, 0x5d488207, 0x5d499918, 0x5d4a4ea6, 0x5d4b57df, 0x5d4cf861, 0x5d4d5f79, 0x5d4e6613,
+    0x5d4f75ab, 0x5d507e79, 0x5d518b6f, 0x5d529006, 0x5d539a5b, 0x5d5456a5, 0x5d555827, 0x5d5659f8,
+    0x5d575a1f, 0x5d585bb4, 0x5d595ef6, 0x5d5a6350, 0x5d5bf991, 0x5d5c693d, 0x5d5d6cbf, 0x5d5e6d93,
+    0x5d5f6d8e, 0x5d606df5, 0x5d616f14, 0x5d6270df, 0x5d637136, 0x5d647159, 0x5d6571c3, 0x5d6671d5,
+    0x5d67784f, 0x5d68786f, 0x5d697b75, 0x5d6a7de3, 0x5d6bf862, 0x5d6c81d9, 0x5d6d8815, 0x5d6e884d,
+    0x5d6f8b8c, 0x5d708edf, 0x5d71925b, 0x5d729cf6, 0x5d73f99e, 0x5d746085, 0x5d756d85, 0x5d7671b1,
+    0x5d77f9a1, 0x5d7895b1, 0x5d7953ad, 0x5d7a5869, 0x5d7b67d3, 0x5d7c708e, 0x5d7d7130, 0x5d7e9e7d,
+    0x5d7f82d2, 0x5d808276, 0x5d8195bb, 0x5d82995c, 0x5d839ae5, 0x5d8466c4, 0x5d8571c1, 0x5d868449,
+    0x5d87584b, 0x5d885b30, 0x5d895f71, 0x5d8a6620, 0x5d8b668e, 0x5d8c6979, 0x5d8d69ae, 0x5d8e6c38,
+    0x5d8f6cf3, 0x5d906f41, 0x5d91701b, 0x5d9271df, 0x5d93745b, 0x5d94f9ae, 0x5d9574d4, 0x5d9676c8,
+    0x5d977e93, 0x5d9882f1, 0x5d998a60, 0x5d9a8fce, 0x5d9b502a, 0x5d9c5208, 0x5d9d53e1, 0x5d9e66f3,
+    0x5d9f6fca, 0x5da0730a, 0x5da17768, 0x5da2777f, 0x5da37ff3, 0x5da482ae, 0x5da5854b, 0x5da685dd,
+    0x5da78602, 0x5da888d4, 0x5da98a63, 0x5daa8b7d, 0x5dab8c6b, 0x5dac92b3, 0x5dad9713, 0x5dae9810,
+    0x5daf4e94, 0x5db04f0d, 0x5db150b2, 0x5db25348, 0x5db35433, 0x5db455da, 0x5db558ba, 0x5db65967,
+    0x5db75a1b, 0x5db85be4, 0x5db9609f, 0x5dbaf9b9, 0x5dbb65ff, 0x5dbc6664, 0x5dbd68a7, 0x5dbe6c5a,
+    0x5dbf70cf, 0x5dc07352, 0x5dc18708, 0x5dc28aa4, 0x5dc39068, 0x5dc4543e, 0x5dc55c4b, 0x5dc66c83,
+    0x5dc77344, 0x5dc87389, 0x5dc9923a, 0x5dca5abc, 0x5dcb6eab, 0x5dcc7465, 0x5dcd761f, 0x5dce7a69,
+    0x5dcf8580, 0x5dd0860a, 0x5dd19c2e, 0x5dd25140, 0x5dd358c5, 0x5dd474ee, 0x5dd57670, 0x5dd664c1,
+    0x5dd77515, 0x5dd87fc1, 0x5dd99095, 0x5dda96cd, 0x5ddb6e26, 0x5ddc74e6, 0x5ddd7aa9, 0x5dde86d9,
+    0x5ddf8778, 0x5de08a1b, 0x5de181e5, 0x5de25a49, 0x5de35b8c, 0x5de45b9b, 0x5de56d63, 0x5de673a9,
+    0x5de7742c, 0x5de87897, 0x5de97de9, 0x5dea7feb, 0x5deb8155, 0x5dec839e, 0x5ded8c4c, 0x5dee962e,
+    0x5def9811, 0x5df066f0, 0x5df15f80, 0x5df265fa, 0x5df36789, 0x5df46c6a, 0x5df5738b, 0x5df6502d,
+    0x5df76b6a, 0x5df877ee, 0x5df95916, 0x5dfa5dcd, 0x5dfb7325, 0x5dfc754f, 0x5dfd50e5, 0x5dfe51f9,
+    0x5e41582f, 0x5e42592d, 0x5e435996, 0x5e4459da, 0x5e455da2, 0x5e4662d7, 0x5e476416, 0x5e4864fe,
+    0x5e4966dc, 0x5e4a8170, 0x5e4bf9bf, 0x5e4c6a48, 0x5e4d7464, 0x5e4e7a88, 0x5e4f7aaf, 0x5e507e47,
+    0x5e517e5e, 0x5e528000, 0x5e538558, 0x5e5487ef, 0x5e558981, 0x5e568b20, 0x5e579059, 0x5e589080,
+    0x5e599952, 0x5e5a8fb1, 0x5e5b617e, 0x5e5c6b32, 0x5e5d6d74, 0x5e5e8925, 0x5e5f50ad, 0x5e6052c7,
+    0x5e615bb9, 0x5e625eb8, 0x5e635197, 0x5e646995, 0x5e656e67, 0x5e666eb6, 0x5e677194, 0x5e687462,
+    0x5e697528, 0x5e6a8073, 0x5e6b8202, 0x5e6c8338, 0x5e6d84c9, 0x5e6e86f9, 0x5e6f8e0a, 0x5e709394,
+    0x5e7193de, 0x5e724f51, 0x5e735076, 0x5e74512a, 0x5e7553c8, 0x5e7653cb, 0x5e7753f3, 0x5e785401,
+    0x5e795b87, 0x5e7a5bd3, 0x5e7b5c24, 0x5e7c4e8e, 0x5e7d611a, 0x5e7e6182, 0x5e7f725b, 0x5e8076c2,
+    0x5e817950, 0x5e827991, 0x5e8379b9, 0x5e847fbd, 0x5e85828b, 0x5e86865e, 0x5e878fc2, 0x5e889047,
+    0x5e8990f5, 0x5e8a9685, 0x5e8b96e8, 0x5e8c96e9, 0x5e8d65ed, 0x5e8e6631, 0x5e8f715c, 0x5e907a36,
+    0x5e9190c1, 0x5e92980a, 0x5e934e91, 0x5e94f9c5, 0x5e956b9e, 0x5e968018, 0x5e9782b8, 0x5e98904b,
+    0x5e999695, 0x5e9a96f2, 0x5e9b97fb, 0x5e9c71a8, 0x5e9d851a, 0x5e9e9b31, 0x5e9f718a, 0x5ea096c4,
+    0x5ea15143, 0x5ea2539f, 0x5ea354e1, 0x5ea45712, 0x5ea55713, 0x5ea67230, 0x5ea757a3, 0x5ea85a9b,
+    0x5ea95bc3, 0x5eaa6028, 0x5eab613f, 0x5eac63f4, 0x5ead6e90, 0x5eae733f, 0x5eaf7457, 0x5eb082d1,
+    0x5eb1873f, 0x5eb28881, 0x5eb38f45, 0x5eb49060, 0x5eb59662, 0x5eb69858, 0x5eb79d1b, 0x5eb86708,
+    0x5eb98d8a, 0x5eba925e, 0x5ebb4f4d, 0x5ebc5049, 0x5ebd50de, 0x5ebe5371, 0x5ebf570d, 0x5ec059d4,
+    0x5ec15c09, 0x5ec25e43, 0x5ec36170, 0x5ec45a01, 0x5ec56e2d, 0x5ec67232, 0x5ec7744b, 0x5ec87def,
+    0x5ec980c3, 0x5eca840e, 0x5ecb8466, 0x5ecc875f, 0x5ecd885b, 0x5ece885e, 0x5ecf8b02, 0x5ed09055,
+    0x5ed197cb, 0x5ed29b4f, 0x5ed34e73, 0x5ed46538, 0x5ed54f91, 0x5ed65112, 0x5ed7516a, 0x5ed85e7c,
+    0x5ed9552f, 0x5eda55a9, 0x5edb56ff, 0x5edc5b7a, 0x5edd5ba5, 0x5ede5e37, 0x5edf5e7d, 0x5ee05ebe,
+    0x5ee160a0, 0x5ee260df, 0x5ee36109, 0x5ee46108, 0x5ee563c4, 0x5ee66709, 0x5ee767d4, 0x5ee867da,
+    0x5ee96961, 0x5eea6cb9, 0x5eeb6e38, 0x5eec6fe1, 0x5eed7336, 0x5eee745c, 0x5eef7531, 0x5ef07652,
+    0x5ef17dad, 0x5ef281fe, 0x5ef38438, 0x5ef48564, 0x5ef588d5, 0x5ef68a98, 0x5ef78adb, 0x5ef88aed,
+    0x5ef98e42, 0x5efa8e30, 0x5efb904a, 0x5efc903e, 0x5efd907a, 0x5efe9149, 0x5f4191c9, 0x5f42936e,
+    0x5f439edd, 0x5f446bd3, 0x5f458089, 0x5f4680b2, 0x5f479b3b, 0x5f485141, 0x5f49596b, 0x5f4a5c39,
+    0x5f4b6f64, 0x5f4c73a7, 0x5f4d80e4, 0x5f4e958f, 0x5f4f807f, 0x5f50620e, 0x5f517d68, 0x5f52878d,
+    0x5f5357a0, 0x5f546069, 0x5f556147, 0x5f566abc, 0x5f576bb7, 0x5f5873e2, 0x5f599280, 0x5f5a8abe,
+    0x5f5b96b1, 0x5f5c9f66, 0x5f5d4e59, 0x5f5e541f, 0x5f5f6deb, 0x5f60852d, 0x5f619670, 0x5f6298ee,
+    0x5f6397f3, 0x5f646339, 0x5f6563d6, 0x5f666ce3, 0x5f679091, 0x5f6851dd, 0x5f6961c9, 0x5f6a81ba,
+    0x5f6b9df9, 0x5f6c4f9d, 0x5f6d501a, 0x5f6e5100, 0x5f6f7591, 0x5f7077e3, 0x5f715b9c, 0x5f72610f,
+    0x5f7361ff, 0x5f7464ec, 0x5f756bc5, 0x5f767fa9, 0x5f778264, 0x5f7887fb, 0x5f798863, 0x5f7a8abc,
+    0x5f7b8b70, 0x5f7c91ab, 0x5f7d4e8c, 0x5f7e4ee5, 0x5f7f4f0a, 0x5f805937, 0x5f8159e8, 0x5f825df2,
+    0x5f838cb3, 0x5f845f1b, 0x5f855f5b, 0x5f866021, 0x5f87f9e0, 0x5f88682e, 0x5f89723e, 0x5f8a73e5,
+    0x5f8b7570, 0x5f8c75cd, 0x5f8d79fb, 0x5f8e800c, 0x5f8f8033, 0x5f908351, 0x5f9198f4, 0x5f92990c,
+    0x5f939823, 0x5f947037, 0x5f957ffc, 0x5f9676ca, 0x5f977fca, 0x5f987fcc, 0x5f994eba, 0x5f9a4ec1,
+    0x5f9b4ede, 0x5f9c5203, 0x5f9d5370, 0x5f9e54bd, 0x5f9f56e0, 0x5fa059fb, 0x5fa15bc5, 0x5fa25f15,
+    0x5fa35fcd, 0x5fa46e6e, 0x5fa58a8d, 0x5fa6976d, 0x5fa79777, 0x5fa84e00, 0x5fa94f7e, 0x5faa58f9,
+    0x5fab65e5, 0x5fac6ea2, 0x5fad9038, 0x5fae93b0, 0x5faf99b9, 0x5fb04efb, 0x5fb158ec, 0x5fb2598a,
+    0x5fb359d9, 0x5fb47a14, 0x5fb5834f, 0x5fb68cc3, 0x5fb75165, 0x5fb84ecd, 0x5fb95b55, 0x5fba5269,
+    0x5fbb4ed4, 0x5fbc523a, 0x5fbd54a8, 0x5fbe59c9, 0x5fbf59ff, 0x5fc05b50, 0x5fc15b5c, 0x5fc25b57,
+    0x5fc36063, 0x5fc46148, 0x5fc56ecb, 0x5fc67099, 0x5fc7716e, 0x5fc87386, 0x5fc974f7, 0x5fca75b5,
+    0x5fcb7725, 0x5fcc78c1, 0x5fcd7ca2, 0x5fce7d2b, 0x5fcf8005, 0x5fd08014, 0x5fd181ea, 0x5fd28517,
+    0x5fd385c9, 0x5fd489dc, 0x5fd58aee, 0x5fd68cc7, 0x5fd78d6d, 0x5fd896cc, 0x5fd94f5c, 0x5fda52fa,
+    0x5fdb56bc, 0x5fdc65ab, 0x5fdd6628, 0x5fde67de, 0x5fdf707c, 0x5fe070b8, 0x5fe17235, 0x5fe27dbd,
+    0x5fe3828d, 0x5fe4914c, 0x5fe596c0, 0x5fe69d72, 0x5fe768e7, 0x5fe86b98, 0x5fe96f7a, 0x5fea76de,
+    0x5feb5c91, 0x5fec66ab, 0x5fed6f5b, 0x5fee6f5c, 0x5fef7bb4, 0x5ff07c2a, 0x5ff18695, 0x5ff28836,
+    0x5ff396dc, 0x5ff44e08, 0x5ff54ed7, 0x5ff65320, 0x5ff75834, 0x5ff858bb, 0x5ff9596c, 0x5ffa5d82,
+    0x5ffb5e33, 0x5ffc5e84, 0x5ffd5f35, 0x5ffe638c, 0x604166b2, 0x60426756, 0x60436a1f, 0x60446aa3,
+    0x60456b0c, 0x60466f3f, 0x604758ef, 0x60485c07, 0x60497246, 0x604af9fa, 0x604b7350, 0x604c748b,
+    0x604d7634, 0x604e7ae0, 0x604f7ca7, 0x60508178, 0x605181df, 0x6052838a, 0x6053846c, 0x60548523,
+    0x60558594, 0x605685cf, 0x605788c5, 0x605888dd, 0x60598d13, 0x605a91ac, 0x605b9577, 0x605c969c,
+    0x605d518d, 0x605e54c9, 0x605f5728, 0x60605bb0, 0x6061624d, 0x60626750, 0x6063683d, 0x60646893,
+    0x60656ed3, 0x6066707d, 0x60677e94, 0x606888c1, 0x60698ca1, 0x606a8f09, 0x606b9f4b, 0x606c9f4e,
+    0x606d722d, 0x606e7b8f, 0x606f8acd, 0x6070931a, 0x60714f4e, 0x60724f47, 0x60735132, 0x60745480,
+    0x607559d0, 0x60765e95, 0x607762b5, 0x60786775, 0x607967e2, 0x607a696e, 0x607b6a17, 0x607c6cae,
+    0x607d6e1a, 0x607e72d9, 0x607f732a, 0x608075bd, 0x60817bb8, 0x608282e7, 0x60838457, 0x608485f7,
+    0x608586c6, 0x60868a5b, 0x60878c6c, 0x60888caf, 0x60898e87, 0x608a9019, 0x608b90b8, 0x608c52e3,
+    0x608d5ae1, 0x608e5bc2, 0x608f6458, 0x60906575, 0x60916ef4, 0x609272c4, 0x60937684, 0x60947a4d,
+    0x60957b1b, 0x60967c4d, 0x60977cf4, 0x60987e3e, 0x6099837b, 0x609a8b2b, 0x609b8cca, 0x609c8d64,
+    0x609d8de1, 0x609e8e5f, 0x609f8fea, 0x60a08ff9, 0x60a19069, 0x60a250b3, 0x60a35168, 0x60a45178,
+    0x60a5524d, 0x60a6526a, 0x60a756c0, 0x60a85861, 0x60a95960, 0x60aa5c08, 0x60ab5c55, 0x60ac5dd3,
+    0x60ad5edb, 0x60ae609b, 0x60af6230, 0x60b06813, 0x60b16bbf, 0x60b26c08, 0x60b36fb1, 0x60b4714e,
+    0x60b57530, 0x60b67538, 0x60b77672, 0x60b878da, 0x60b97b8b, 0x60ba7bad, 0x60bb7bc6, 0x60bc7e8f,
+    0x60bd7fe6, 0x60be8a6e, 0x60bf8f3e, 0x60c08f49, 0x60c1923f, 0x60c29293, 0x60c39322, 0x60c496fb,
+    0x60c5985a, 0x60c6986b, 0x60c7991e, 0x60c85207, 0x60c9622a, 0x60ca6298, 0x60cb6d59, 0x60cc7a83,
+    0x60cd7aca, 0x60ce7bc0, 0x60cf7d76, 0x60d05360, 0x60d15cbe, 0x60d25e97, 0x60d36f38, 0x60d470b9,
+    0x60d59ede, 0x60d67c98, 0x60d79711, 0x60d863a5, 0x60d9647a, 0x60da6904, 0x60db8776, 0x60dc4e01,
+    0x60dd4e95, 0x60de4ead, 0x60df505c, 0x60e05075, 0x60e15448, 0x60e259c3, 0x60e35e40, 0x60e45ef7,
+    0x60e55f81, 0x60e65b9a, 0x60e75ead, 0x60e860c5, 0x60e9633a, 0x60ea653f, 0x60eb6574, 0x60ec65cc,
+    0x60ed6676, 0x60ee6678, 0x60ef6883, 0x60f06968, 0x60f16b63, 0x60f26c40, 0x60f36de8, 0x60f46e1f,
+    0x60f5753a, 0x60f6775b, 0x60f77887, 0x60f8798e, 0x60f97a0b, 0x60fa7a7d, 0x60fb9756, 0x60fc7cbe,
+    0x60fd8247, 0x60fe8a02, 0x61418aea, 0x61428c9e, 0x6143912d, 0x6144914a, 0x614591d8, 0x61469266,
+    0x61479320, 0x61489706, 0x6149975c, 0x614a9802, 0x614b9f0e, 0x614c5236, 0x614d5291, 0x614e557c,
+    0x614f5824, 0x61505e1d, 0x61515f1f, 0x6152608c, 0x615363d0, 0x61546662, 0x615568af, 0x61566fdf,
+    0x61577445, 0x615881cd, 0x6159796d, 0x615a7b2c, 0x615b85ba, 0x615c88fd, 0x615d8af8, 0x615e8e36,
+    0x615f8e44, 0x6160918d, 0x61619664, 0x6162969b, 0x6163973d, 0x6164984c, 0x61659f4a, 0x61664fce,
+    0x616751cb, 0x61685146, 0x616952a9, 0x616af863, 0x616b5632, 0x616c566a, 0x616d5f14, 0x616e5f6b,
+    0x616f63aa, 0x617064cd, 0x617165e9, 0x61726641, 0x617366fa, 0x617466f9, 0x6175671d, 0x6176689d,
+    0x617768d7, 0x617869fd, 0x61796f15, 0x617a6f6e, 0x617b7167, 0x617c71e5, 0x617d722a, 0x617e7681,
+    0x617f773a, 0x61807956, 0x6181795a, 0x618279df, 0x61837a20, 0x61847a95, 0x61857ac8, 0x61867b0a,
+    0x61877c97, 0x61887cdf, 0x61897cf6, 0x618a7d44, 0x618b7d5b, 0x618c7e70, 0x618d8087, 0x618e85fb,
+    0x618f86a4, 0x61908a54, 0x61918abf, 0x61928d99, 0x61938e81, 0x61949020, 0x6195906d, 0x619691e3,
+    0x6197963b, 0x61989bdb, 0x61999ce5, 0x619a65cf, 0x619b7c07, 0x619c8db3, 0x619d93c3, 0x619e5b58,
+    0x619f5c0a, 0x61a05352, 0x61a162d9, 0x61a2731d, 0x61a35027, 0x61a45b97, 0x61a55f9e, 0x61a6616b,
+    0x61a768d5, 0x61a86a05, 0x61a96dd9, 0x61aa742e, 0x61ab7a2e, 0x61ac7d42, 0x61ad7d9c, 0x61ae7e31,
+    0x61af816b, 0x61b08e35, 0x61b18e64, 0x61b2937e, 0x61b39418, 0x61b44f50, 0x61b55750, 0x61b65de6,
+    0x61b75ea7, 0x61b8632b, 0x61b97f6a, 0x61ba4e3b, 0x61bb4f4f, 0x61bc4f8f, 0x61bd505a, 0x61be5eda,
+    0x61bf546a, 0x61c05468, 0x61c155fe, 0x61c2594f, 0x61c35b99, 0x61c45dde, 0x61c5665d, 0x61c66731,
+    0x61c767f1, 0x61c8682a, 0x61c970b7, 0x61ca6ce8, 0x61cb6d32, 0x61cc9152, 0x61cd73e0, 0x61ce7587,
+    0x61cf851f, 0x61d07c4c, 0x61d17d02, 0x61d27d2c, 0x61d37da2, 0x61d48098, 0x61d580c4, 0x61d6821f,
+    0x61d786db, 0x61d88a3b, 0x61d98a85, 0x61da8d70, 0x61db8e8a, 0x61dc8f33, 0x61dd914e, 0x61de9031,
+    0x61df9444, 0x61e099d0, 0x61e17af9, 0x61e27ca5, 0x61e34fca, 0x61e45101, 0x61e551c6, 0x61e657c8,
+    0x61e75cfb, 0x61e86659, 0x61e96a3d, 0x61ea6d5a, 0x61eb6e96, 0x61ec6fec, 0x61ed756f, 0x61ee7ae3,
+    0x61ef8822, 0x61f09021, 0x61f19075, 0x61f296bc, 0x61f399ff, 0x61f48301, 0x61f54e2d, 0x61f64ef2,
+    0x61f78846, 0x61f891cd, 0x61f9f864, 0x61fa537d, 0x61fb6adb, 0x61fc696b, 0x61fd6c41, 0x61fe847a,
+    0x6241589e, 0x6242618e, 0x624362ef, 0x624470dd, 0x624566fe, 0x62467511, 0x624775c7, 0x624884b8,
+    0x62498a3c, 0x624a8b49, 0x624b8d08, 0x624c4e4b, 0x624d53ea, 0x624e54ab, 0x624f5730, 0x62505740,
+    0x62515fd7, 0x62526301, 0x62536307, 0x6254646f, 0x6255652f, 0x625665e8, 0x6257667a, 0x6258679d,
+    0x625967b3, 0x625a6b62, 0x625b6c60, 0x625c6f2c, 0x625d75e3, 0x625e77e5, 0x625f7825, 0x62607957,
+    0x62617949, 0x62627d19, 0x626380a2, 0x62648102, 0x626581f3, 0x6266829d, 0x62678718, 0x62688a8c,
+    0x6269f9fc, 0x626a8d04, 0x626b8dbe, 0x626c9072, 0x626d76f4, 0x626e7a19, 0x626f7a37, 0x62707e54,
+    0x62718077, 0x62728fb0, 0x627355d4, 0x62745875, 0x6275632f, 0x62766649, 0x6277699b, 0x62786d25,
+    0x62796eb1, 0x627a73cd, 0x627b7468, 0x627c74a1, 0x627d75b9, 0x627e76e1, 0x627f771e, 0x6280778b,
+    0x628179e6, 0x62827e09, 0x62838a3a, 0x62848cd1, 0x62858eeb, 0x62869032, 0x628793ad, 0x62889663,
+    0x62899673, 0x628a9707, 0x628b53f1, 0x628c7a92, 0x628d59ea, 0x628e5ac9, 0x628f5e19, 0x6290684e,
+    0x629175be, 0x629279e9, 0x629381a3, 0x629486ed, 0x62958cea, 0x62968dcc, 0x62978fed, 0x6298659f,
+    0x62996715, 0x629a57f7, 0x629b96c6, 0x629c7ddd, 0x629d8f2f, 0x629e5fb5, 0x629f61f2, 0x62a06f84,
+    0x62a14e14, 0x62a2501f, 0x62a37b9a, 0x62a453c9, 0x62a555df, 0x62a65dee, 0x62a75d6f, 0x62a86b21,
+    0x62a96b64, 0x62aa78cb, 0x62abf9fe, 0x62ac8e49, 0x62ad906e, 0x62ae8eca, 0x62af6349, 0x62b0643e,
+    0x62b17740, 0x62b27a84, 0x62b3932f, 0x62b4947f, 0x62b59f6a, 0x62b664b0, 0x62b76faf, 0x62b871e6,
+    0x62b974a8, 0x62ba74da, 0x62bb7ac4, 0x62bc7e82, 0x62bd7cb2, 0x62be7e98, 0x62bf8b9a, 0x62c08d0a,
+    0x62c1947d, 0x62c2f865, 0x62c39910, 0x62c4994c, 0x62c55239, 0x62c65bdf, 0x62c764e6, 0x62c8672d,
+    0x62c950ed, 0x62ca53c3, 0x62cb5879, 0x62cc6158, 0x62cd6159, 0x62ce615a, 0x62cf61fa, 0x62d065ac,
+    0x62d17ad9, 0x62d28b92, 0x62d35021, 0x62d45009, 0x62d55231, 0x62d65275, 0x62d75531, 0x62d85a3c,
+    0x62d95ee0, 0x62da5f70, 0x62db60b5, 0x62dc655e, 0x62dd660c, 0x62de6636, 0x62df66a2, 0x62e069cd,
+    0x62e16c05, 0x62e26ec4, 0x62e36f32, 0x62e47621, 0x62e57a93, 0x62e68139, 0x62e78259, 0x62e883d6,
+    0x62e984bc, 0x62ea50b5, 0x62eb57f0, 0x62ec5f69, 0x62ed63a1, 0x62ee7826, 0x62ef7db5, 0x62f083dc,
+    0x62f18521, 0x62f291c7, 0x62f391f5, 0x62f4518a, 0x62f567f5, 0x62f67b56, 0x62f77c00, 0x62f88cac,
+    0x62f951c4, 0x62fa59bb, 0x62fb60bd, 0x62fc8655, 0x62fdf9ff, 0x62fe5254, 0x63415c3a, 0x6342617d,
+    0x6343621a, 0x634462d3, 0x634564f2, 0x634665a5, 0x63476ecc, 0x63487620, 0x6349810a, 0x634a8734,
+    0x634b8e91, 0x634c965f, 0x634d96bb, 0x634e4edf, 0x634f5343, 0x63505598, 0x63515929, 0x63525ddd,
+    0x635364c5, 0x63546cc9, 0x63556dfa, 0x63566ffa, 0x63577a7f, 0x6358821b, 0x635985a6, 0x635a8ce4,
+    0x635b8e10, 0x635c9077, 0x635d91e7, 0x635e95e1, 0x635f9621, 0x636097c6, 0x636151f8, 0x63625fb9,
+    0x636354f2, 0x63645586, 0x636564a4, 0x63666f88, 0x63677db4, 0x63688f4d, 0x63699435, 0x636a50c9,
+    0x636b5c16, 0x636c6cbe, 0x636d6dfb, 0x636e751b, 0x636f77bb, 0x63707c37, 0x63717c64, 0x63728ac2,
+    0x637359be, 0x63745e16, 0x63756377, 0x63767252, 0x6377758a, 0x6378776b, 0x63798adc, 0x637a8cbc,
+    0x637b8f12, 0x637c5ef3, 0x637d6674, 0x637e6df8, 0x637f807d, 0x638083c1, 0x6381873b, 0x63828acb,
+    0x63839751, 0x63849bd6, 0x6385fa00, 0x63865243, 0x638766ff, 0x63886d95, 0x63896eef, 0x638a780c,
+    0x638b7de0, 0x638c8ae6, 0x638d902e, 0x638e905e, 0x638f9746, 0x63909ad4, 0x6391521d, 0x639254e8,
+    0x63935ced, 0x63946194, 0x63956284, 0x639662db, 0x639768a2, 0x63986912, 0x6399695a, 0x639a6a35,
+    0x639b7092, 0x639c7126, 0x639d785d, 0x639e7901, 0x639f790e, 0x63a079d2, 0x63a17a0d, 0x63a27d83,
+    0x63a38096, 0x63a48349, 0x63a58549, 0x63a68c82, 0x63a78d85, 0x63a89162, 0x63a9918b, 0x63aa91ae,
+    0x63ab9214, 0x63ac9798, 0x63ad4fc3, 0x63ae56d1, 0x63aff866, 0x63b071ed, 0x63b177d7, 0x63b28700,
+    0x63b389f8, 0x63b48e85, 0x63b59ad1, 0x63b65bf8, 0x63b75fd6, 0x63b86751, 0x63b953e2, 0x63ba585a,
+    0x63bb5bf5, 0x63bc6031, 0x63bd6460, 0x63be7e3d, 0x63bf8070, 0x63c08471, 0x63c19283, 0x63c264ae,
+    0x63c350ac, 0x63c45d14, 0x63c56467, 0x63c66700, 0x63c7589c, 0x63c862bd, 0x63c963a8, 0x63ca690e,
+    0x63cb6978, 0x63ccf867, 0x63cd6a1e, 0x63ce6e6b, 0x63cf7503, 0x63d076ba, 0x63d179cb, 0x63d27b92,
+    0x63d382bb, 0x63d48429, 0x63d58da8, 0x63d68ffd, 0x63d79112, 0x63d8914b, 0x63d9919c, 0x63da9318,
+    0x63db9310, 0x63dc96db, 0x63dd97a6, 0x63de9c0d, 0x63df9e81, 0x63e09ea4, 0x63e14e11, 0x63e2f868,
+    0x63e3795d, 0x63e4755c, 0x63e57afa, 0x63e67b51, 0x63e77bc9, 0x63e87e2e, 0x63e98233, 0x63ea84c4,
+    0x63eb8e74, 0x63ec8ef8, 0x63ed9010, 0x63ee6625, 0x63ef693f, 0x63f051fa, 0x63f19edc, 0x63f25145,
+    0x63f351b2, 0x63f45fe0, 0x63f56c96, 0x63f687f2, 0x63f7885d, 0x63f88877, 0x63f960b4, 0x63fa81b5,
+    0x63fb8403, 0x63fc53d6, 0x63fd5439, 0x63fe5634, 0x64415a36, 0x64425c31, 0x64436a47, 0x6444708a,
+    0x64457fe0, 0x6446805a, 0x64478106, 0x644881ed, 0x6449f869, 0x644a8da3, 0x644b9189, 0x644c9a5f,
+    0x644d9df2, 0x644e4ec4, 0x644f5074, 0x645060fb, 0x64516e2c, 0x64525c64, 0x64534f88, 0x64545024,
+    0x645555e4, 0x64565cd9, 0x64575df5, 0x64585e5f, 0x64595fb4, 0x645a6894, 0x645b6cbb, 0x645c6dc4,
+    0x645d71be, 0x645e75d4, 0x645f75f4, 0x64607661, 0x646196c9, 0x64627a1a, 0x64637dc7, 0x64647dfb,
+    0x64657f6e, 0x64666065, 0x646781f4, 0x64688f1c, 0x646999b3, 0x646a9d44, 0x646b9f52, 0x646c5247,
+    0x646d52c5, 0x646e98ed, 0x646f89aa, 0x64704e03, 0x647167d2, 0x64726f06, 0x64734fb5, 0x64745be2,
+    0x64755ff1, 0x64766795, 0x64776c88, 0x64786d78, 0x64797827, 0x647a91dd, 0x647b937c, 0x647c87c4,
+    0x647d79e4, 0x647e7a31, 0x647f5feb, 0x64804ed6, 0x6481553e, 0x648258ae, 0x648359a5, 0x648460f0,
+    0x64856253, 0x64866736, 0x64876955, 0x64888235, 0x64899640, 0x648a99dd, 0x648b502c, 0x648c5353,
+    0x648d5544, 0x648efa01, 0x648f6258, 0x649062c6, 0x6491fa02, 0x649264e2, 0x649367dd, 0x64946a50,
+    0x64956fc1, 0x64966fef, 0x64977422, 0x64988a17, 0x64999438, 0x649a5451, 0x649b5606, 0x649c5766,
+    0x649d5f48, 0x649e619a, 0x649f6b4e, 0x64a07058, 0x64a170ad, 0x64a27dbb, 0x64a38a95, 0x64a4596a,
+    0x64a5812b, 0x64a663a2, 0x64a7803d, 0x64a88caa, 0x64a95854, 0x64aa642d, 0x64ab69bb, 0x64ac5b95,
+    0x64ad5e11, 0x64ae6e6f, 0x64af8569, 0x64b0514c, 0x64b153f0, 0x64b2592a, 0x64b36020, 0x64b4614b,
+    0x64b56b86, 0x64b66c70, 0x64b76cf0, 0x64b87b1e, 0x64b980ce, 0x64ba82d4, 0x64bb8dc6, 0x64bc90b0,
+    0x64bd98b1, 0x64be99c4, 0x64bffa04, 0x64c064c7, 0x64c16fa4, 0x64c2f86a, 0x64c36491, 0x64c46504,
+    0x64c5514e, 0x64c65410, 0x64c7571f, 0x64c88a0e, 0x64c9615f, 0x64ca6876, 0x64cbfa05, 0x64cc75db,
+    0x64cd7b52, 0x64ce7d71, 0x64cf901a, 0x64d05806, 0x64d1817f, 0x64d2892a, 0x64d39000, 0x64d49839,
+    0x64d55078, 0x64d65957, 0x64d759ac, 0x64d86295, 0x64d9900f, 0x64da9b2a, 0x64db615d, 0x64dc7279,
+    0x64dd5a46, 0x64de5761, 0x64df5df4, 0x64e0628a, 0x64e164ad, 0x64e26777, 0x64e36ce2, 0x64e46d3e,
+    0x64e5722c, 0x64e67436, 0x64e77834, 0x64e87f77, 0x64e982ad, 0x64ea8ddb, 0x64eb9817, 0x64ec5742,
+    0x64ed5224, 0x64ee677f, 0x64ef7248, 0x64f08ca9, 0x64f18fa6, 0x64f274e3, 0x64f3962a, 0x64f4516b,
+    0x64f553ed, 0x64f6634c, 0x64f74f69, 0x64f85504, 0x64f96096, 0x64fa6c9b, 0x64fb6d7f, 0x64fc724c,
+    0x64fd72fd, 0x64fe7a17, 0x65418c9d, 0x65426557, 0x65438987, 0x65445f6d, 0x65456f8e, 0x654670f9,
+    0x654781a8, 0x6548610e, 0x65494fbf, 0x654a504f, 0x654b6241, 0x654c7247, 0x654d7bc7, 0x654e7de8,
+    0x654f7fe9, 0x65508759, 0x6551904d, 0x655297ad, 0x65538cb6, 0x6554576a, 0x65555e73, 0x6556840d,
+    0x65578a55, 0x65585420, 0x65595b16, 0x655a5e63, 0x655b5ee2, 0x655c5f0a, 0x655d6583, 0x655e80ba,
+    0x655f853d, 0x65609589, 0x6561965b, 0x65624f48, 0x65635305, 0x6564530d, 0x6565530f, 0x65665486,
+    0x656754fa, 0x65685703, 0x65695e96, 0x656a5e03, 0x656b6016, 0x656c62b1, 0x656d629b, 0x656e6355,
+    0x656f6ce1, 0x65706d66, 0x657170ae, 0x657275b1, 0x65737832, 0x657480de, 0x6575812f, 0x65768216,
+    0x65778461, 0x657884b2, 0x6579888d, 0x657a8912, 0x657b924b, 0x657c92ea, 0x657d9784, 0x657e98fd,
+    0x657f9b91, 0x65805e45, 0x658166b4, 0x65827011, 0x65837206, 0x65844ff5, 0x6585527d, 0x65865f6a,
+    0x65876153, 0x65886753, 0x65896a19, 0x658a6f02, 0x658b74e2, 0x658c7968, 0x658d8868, 0x658e8c79,
+    0x658f98c4, 0x6590f86b, 0x659154c1, 0x65927a1f, 0x659399ae, 0x65946953, 0x65958af7, 0x65968c4a,
+    0x659798a8, 0x65985f7c, 0x659962ab, 0x659a75b2, 0x659b76ae, 0x659c84d6, 0x659d88ab, 0x659e907f,
+    0x659f5339, 0x65a05f3c, 0x65a15fc5, 0x65a26ccc, 0x65a37562, 0x65a4758b, 0x65a57b46, 0x65a68e55,
+    0x65a74e4f, 0x65a8903c, 0x65a94e0b, 0x65aa4f55, 0x65ab590f, 0x65ac5ec8, 0x65ad6630, 0x65ae6cb3,
+    0x65af7455, 0x65b08766, 0x65b18377, 0x65b28cc0, 0x65b39050, 0x65b4971e, 0x65b59c15, 0x65b658d1,
+    0x65b75b78, 0x65b87627, 0x65b98650, 0x65ba8b14, 0x65bb9db4, 0x65bc5bd2, 0x65bd6068, 0x65be608d,
+    0x65bf65f1, 0x65c06c57, 0x65c16f22, 0x65c26fa3, 0x65c37f55, 0x65c47ff0, 0x65c5f86c, 0x65c69592,
+    0x65c79591, 0x65c89650, 0x65c997d3, 0x65ca9df3, 0x65cb5272, 0x65cc8f44, 0x65cd51fd, 0x65ce542b,
+    0x65cf54b8, 0x65d05563, 0x65d1558a, 0x65d26abb, 0x65d36db5, 0x65d47dd8, 0x65d58266, 0x65d69677,
+    0x65d75408, 0x65d854c8, 0x65d976d2, 0x65da86e4, 0x65db95a4, 0x65dc965c, 0x65dd4ea2, 0x65de4f09,
+    0x65df59ee, 0x65e05df7, 0x65e16052, 0x65e26297, 0x65e3676d, 0x65e46841, 0x65e56c86, 0x65e66e2f,
+    0x65e77095, 0x65e87f38, 0x65e9809b, 0x65ea822a, 0x65ebfa09, 0x65ec9805, 0x65ed4ea5, 0x65ee5055,
+    0x65ef54b3, 0x65f0595a, 0x65f15793, 0x65f25b69, 0x65f35bb3, 0x65f45ee8, 0x65f561c8, 0x65f66977,
+    0x65f76d77, 0x65f887f9, 0x65f989e3, 0x65fa8a72, 0x65fb8ae7, 0x65fc9082, 0x65fd99ed, 0x65fe9ab8,
+    0x664152be, 0x66426838, 0x66435016, 0x66445e78, 0x6645674f, 0x6646884c, 0x66474eab, 0x66485411,
+    0x664956ae, 0x664a9115, 0x664b73e6, 0x664c97ff, 0x664d9909, 0x664e9957, 0x664f9999, 0x66505653,
+    0x6651589f, 0x6652865b, 0x66538a31, 0x665461b2, 0x66558ed2, 0x6656737b, 0x66576b47, 0x665896aa,
+    0x66599a57, 0x665a5955, 0x665b7200, 0x665c8d6b, 0x665d9769, 0x665e5cf4, 0x665f5f26, 0x666061f8,
+    0x666170ab, 0x666273fe, 0x66637384, 0x66647729, 0x66657d43, 0x66667d62, 0x66677e23, 0x66688237,
+    0x66698852, 0x666afa0a, 0x666b8ce2, 0x666c9249, 0x666d986f, 0x666e5b51, 0x666f7a74, 0x66708840,
+    0x66719801, 0x66725acc, 0x6673f86d, 0x66744fe0, 0x66755354, 0x6676593e, 0x66775cfd, 0x6678633e,
+    0x66796d79, 0x667a72f9, 0x667b7bcb, 0x667c8107, 0x667d8105, 0x667e83a2, 0x667ff86e, 0x668092cf,
+    0x66819830, 0x66824ea8, 0x66835211, 0x6684578b, 0x66855f62, 0x66865144, 0x66877005, 0x668870af,
+    0x668973e9, 0x668a834a, 0x668b87a2, 0x668c8861, 0x668d90a2, 0x668e93a3, 0x668f99a8, 0x6690516e,
+    0x66915f57, 0x669260e0, 0x66936167, 0x66948559, 0x669591af, 0x6696978b, 0x66974e4e, 0x66984e92,
+    0x66998c6a, 0x669a547c, 0x669b58fa, 0x669c58d5, 0x669d597d, 0x669e5f27, 0x669f660a, 0x66a06236,
+    0x66a16beb, 0x66a26d69, 0x66a36dcf, 0x66a46e56, 0x66a56ef8, 0x66a66f94, 0x66a76fe9, 0x66a86fe0,
+    0x66a9705d, 0x66aa72d0, 0x66ab745a, 0x66ac74e0, 0x66ad7693, 0x66ae769e, 0x66af795c, 0x66b07cca,
+    0x66b17e1e, 0x66b280e1, 0x66b3846b, 0x66b4864e, 0x66b5865f, 0x66b68774, 0x66b78b77, 0x66b86248,
+    0x66b99190, 0x66ba93ac, 0x66bb9800, 0x66bc9865, 0x66bd60d1, 0x66be6216, 0x66bf9177, 0x66c05a5a,
+    0x66c1660f, 0x66c26df7, 0x66c36e3e, 0x66c4743f, 0x66c59b42, 0x66c65ffd, 0x66c760da, 0x66c87b0f,
+    0x66c99dbb, 0x66ca5f18, 0x66cb54c4, 0x66cc6c5e, 0x66cd6cd3, 0x66ce6d2a, 0x66cf9d3b, 0x66d07d05,
+    0x66d18679, 0x66d28a0c, 0x66d35316, 0x66d45b05, 0x66d56a3a, 0x66d6706b, 0x66d7798d, 0x66d87575,
+    0x66d979be, 0x66da548c, 0x66db82b1, 0x66dc83ef, 0x66dd8a71, 0x66de9774, 0x66df8ca8, 0x66e0fa0b,
+    0x66e164f4, 0x66e2652b, 0x66e378ba, 0x66e478bb, 0x66e57a6b, 0x66e6944a, 0x66e74e38, 0x66e8559a,
+    0x66e9571c, 0x66ea5ba6, 0x66eb5e7b, 0x66ec60a3, 0x66ed61fd, 0x66ee63db, 0x66ef6853, 0x66f06b61,
+    0x66f16e19, 0x66f27165, 0x66f374b0, 0x66f47d08, 0x66f59084, 0x66f69a69, 0x66f79c25, 0x66f89b1f,
+    0x66f96d3b, 0x66fa6ed1, 0x66fb733e, 0x66fc8c41, 0x66fd95ca, 0x66fe51f0, 0x67415a93, 0x67425fa8,
+    0x67436033, 0x6744604d, 0x6745614c, 0x674660f6, 0x67476643, 0x67486cc1, 0x67496ec9, 0x674a714c,
+    0x674b7687, 0x674c7bc1, 0x674d8352, 0x674e8757, 0x674f9051, 0x6750968d, 0x67519ec3, 0x675256de,
+    0x67535efb, 0x67545f8a, 0x67556062, 0x67566094, 0x675761f7, 0x67586666, 0x67596703, 0x675a6a9c,
+    0x675b6dee, 0x675c6fae, 0x675d7070, 0x675e736a, 0x675f7e6a, 0x676081be, 0x676186d4, 0x67628aa8,
+    0x67638cc4, 0x67645283, 0x67657372, 0x67666a6b, 0x676754ee, 0x67685686, 0x676956c2, 0x676a5b5d,
+    0x676b6548, 0x676c66c9, 0x676d689f, 0x676e723b, 0x676f80b4, 0x67709175, 0x67714faf, 0x67725019,
+    0x6773539a, 0x6774540e, 0x6775543c, 0x67765589, 0x677755c5, 0x67785f8c, 0x6779673d, 0x677a7334,
+    0x677b9005, 0x677c7bcc, 0x677d52f3, 0x677e5864, 0x677f58ce, 0x67806688, 0x67817104, 0x6782718f,
+    0x678371fb, 0x678485b0, 0x67858a13, 0x678685a8, 0x678755a7, 0x67888431, 0x67895349, 0x678a5599,
+    0x678b6bc1, 0x678c5f59, 0x678d5fbd, 0x678e63ee, 0x678f6689, 0x67907147, 0x67918af1, 0x67928f1d,
+    0x67939ebe, 0x67944f11, 0x6795643a, 0x679670cb, 0x67977566, 0x67988667, 0x67996064, 0x679a8b4e,
+    0x679b9df8, 0x679c51f6, 0x679d5308, 0x679e5147, 0x679f6d36, 0x67a080f8, 0x67a19ed1, 0x67a25ffb,
+    0x67a36615, 0x67a46b23, 0x67a575d5, 0x67a65403, 0x67a75c79, 0x67a87d07, 0x67a98fc4, 0x67aa6b20,
+    0x67ab6b46, 0x67ac6b3d, 0x67ad5438, 0x67ae6070, 0x67af6d3d, 0x67b08208, 0x67b150d6, 0x67b2559c,
+    0x67b3566b, 0x67b459ec, 0x67b55b09, 0x67b65e0c, 0x67b766e6, 0x67b86199, 0x67b96231, 0x67ba72a7,
+    0x67bb7199, 0x67bc51de, 0x67bd71b9, 0x67be79a7, 0x67bf7a00, 0x67c07fb2, 0x67c18a70, 0x67c29821,
+    0x684167b7, 0x684273c2, 0x684375c2, 0x68446164, 0x68454f83, 0x684667ec, 0x6847687f, 0x684878f5,
+    0x6849874e, 0x684a6221, 0x684b77b0, 0x684c90af, 0x684d9452, 0x684e5808, 0x684f757a, 0x68507d73,
+    0x68517f8c, 0x68528221, 0x68539c47, 0x6854584f, 0x6855f900, 0x685693a7, 0x68575580, 0x68585028,
+    0x6859795b, 0x685a8e1e, 0x685b9245, 0x685c6957, 0x685d8171, 0x685e6840, 0x685f5292, 0x68609210,
+    0x68618ff2, 0x68628188, 0x68636289, 0x68649257, 0x68654fd3, 0x6866501e, 0x68675106, 0x68685770,
+    0x686961ac, 0x686a70f1, 0x686b74a5, 0x686c583a, 0x686d68e8, 0x686e5c3b, 0x686f66a0, 0x687069c1,
+    0x68717f94, 0x687282fd, 0x687383f0, 0x68747428, 0x68759be4, 0x687673d9, 0x687786a3, 0x6878978f,
+    0x68794e32, 0x687a8de8, 0x687b934b, 0x687c742f, 0x687d4f8a, 0x687e709a, 0x687f5775, 0x68808009,
+    0x688192b6, 0x688297ab, 0x688388d9, 0x6884828e, 0x68856372, 0x68866dc3, 0x68877357, 0x68888568,
+    0x6889f906, 0x688a6677, 0x688b69fb, 0x688c7ac5, 0x688d52fb, 0x688e7547, 0x688f52a4, 0x68905997,
+    0x68916611, 0x689282a9, 0x68934f0b, 0x689457fc, 0x68957398, 0x68967482, 0x68977941, 0x68987f88,
+    0x68999324, 0x689af90b, 0x689bf90c, 0x689c5a1c, 0x689df90d, 0x689ef90e, 0x689ff90f, 0x68a0f910,
+    0x68a1f911, 0x68a2f912, 0x68a3f913, 0x68a4f914, 0x68a5f915, 0x68a6f916, 0x68a7f917, 0x68a8f918,
+    0x68a9f919, 0x68aaf91a, 0x68abf91b, 0x68acf91c, 0x68adf91d, 0x68aef91e, 0x68aff91f, 0x68b0f920,
+    0x68b1f921, 0x68b2678f, 0x68b36960, 0x68b46e73, 0x68b5f922, 0x68b6f923, 0x68b7f924, 0x68b8f925,
+    0x68b9f926, 0x68baf927, 0x68bb8872, 0x68bcf928, 0x68bdf929, 0x68bef92a, 0x68bff92b, 0x68c0f92c,
+    0x68c1f92d, 0x68c2f92e, 0x68c3649a, 0x68c45bd7, 0x68c5f92f, 0x68c6f930, 0x68c7f931, 0x68c8f932,
+    0x68c9f933, 0x68caf934, 0x68cbf935, 0x68ccf936, 0x68cdf937, 0x68cef938, 0x68cf99d1, 0x68d0f939,
+    0x68d1f93a, 0x68d2f93b, 0x68d3f93c, 0x68d4f93d, 0x68d5f93e, 0x68d6f93f, 0x68d7f940, 0x68d8f941,
+    0x68d9f942, 0x68daf943, 0x68dbf944, 0x68dcf945, 0x68ddf946, 0x68def947, 0x68dff948, 0x68e0f949,
+    0x68e1f94a, 0x68e2f94b, 0x68e3f94c, 0x68e4f94d, 0x68e5f94e, 0x68e6f94f, 0x68e7f950, 0x68e8f951,
+    0x68e9677b, 0x68eaf952, 0x68ebf953, 0x68ecf954, 0x68edf955, 0x68eef956, 0x68eff957, 0x68f0f958,
+    0x68f1f959, 0x68f24eb6, 0x68f35f56, 0x68f46fbe, 0x68f5574d, 0x68f661ba, 0x68f76e5b, 0x68f88541,
+    0x68f9931f, 0x68fa7553, 0x68fb6207, 0x68fc87b3, 0x68fd576e, 0x68fe5d8b, 0x69416389, 0x69426ac2,
+    0x694389a9, 0x694465fd, 0x6945711e, 0x69469017, 0x6947829a, 0x69485d9d, 0x6949f95b, 0x694af95d,
+    0x694bf95e, 0x694c5d0d, 0x694d5fa0, 0x694e5137, 0x694f7489, 0x6950f95f, 0x69515cba, 0x69527b2d,
+    0x69538046, 0x69546fa7, 0x6955f960, 0x695664c4, 0x69576f5e, 0x6958797f, 0x695983c9, 0x695a7028,
+    0x695b8cda, 0x695c9b27, 0x695d5a41, 0x695e763b, 0x695f851e, 0x696093e4, 0x696165d2, 0x6962700f,
+    0x69634f96, 0x696451dc, 0x6965550e, 0x69666d6c, 0x6967f962, 0x69687fb8, 0x69698389, 0x696a88e1,
+    0x696b6f7e, 0x696c78bc, 0x696d9088, 0x696e4e07, 0x696f534d, 0x6970551c, 0x69718309, 0x69728f1e,
+    0x69736c94, 0x69746927, 0x69757791, 0x697684c2, 0x69776479, 0x69787441, 0x697982bc, 0x697a9da9,
+    0x697b7ad7, 0x697c61ae, 0x697d6959, 0x697e73f7, 0x697f5011, 0x698096ef, 0x69816c95, 0x698268b6,
+    0x69836963, 0x69846e44, 0x69859ef4, 0x69865cb7, 0x69877de1, 0x69887622, 0x698976fc, 0x698a78d0,
+    0x698b6e24, 0x698c678b, 0x698d6ec2, 0x698e78c5, 0x698f7119, 0x69905e61, 0x699171d4, 0x69926a97,
+    0x69938617, 0x699468c5, 0x69959a08, 0x699673e4, 0x699783e9, 0x69988f39, 0x699971a2, 0x699a4e0d,
+    0x699b4fef, 0x699c5490, 0x699d5b5a, 0x699e8240, 0x699f83a9, 0x69a05429, 0x69a16610, 0x69a28cc1,
+    0x69a35f7f, 0x69a46787, 0x69a56bd6, 0x69a66bd7, 0x69a779d5, 0x69a87c83, 0x69a956ac, 0x69aa6d5c,
+    0x69ab73ad, 0x69ac4fdf, 0x69ad67f6, 0x69ae9e9d, 0x69af9730, 0x69b0715e, 0x69b19212, 0x69b25ce0,
+    0x69b355c7, 0x69b4637f, 0x69b57b6e, 0x69b65b0b, 0x69b7657e, 0x69b86e32, 0x69b97401, 0x69ba9425,
+    0x69bb994d, 0x69bc6cc4, 0x69bd893b, 0x69be9f67, 0x69bf5261, 0x69c0965d, 0x69c15bac, 0x69c273f9,
+    0x69c37619, 0x69c47be0, 0x69c56d91, 0x69c68b16, 0x69c78d16, 0x69c85dfd, 0x69c984c0, 0x69ca98e1,
+    0x69cb609a, 0x69cc6dde, 0x69cd5cc0, 0x69ce7407, 0x69cf74b2, 0x69d08b90, 0x69d196a7, 0x69d26f5a,
+    0x69d37421, 0x69d474b9, 0x69d55f87, 0x69d66812, 0x69d76a53, 0x69d884f4, 0x69d98563, 0x69da8ac4,
+    0x69db9265, 0x69dc5c4e, 0x69dd8c55, 0x69de7bd2, 0x69df8755, 0x69e04f81, 0x69e185ce, 0x69e28af6,
+    0x69e3555e, 0x69e483aa, 0x69e55e44, 0x69e69102, 0x69e79354, 0x69e89c10, 0x69e99b9f, 0x69ea5535,
+    0x69eb5ca9, 0x69ec627c, 0x69ed7e0a, 0x69eef975, 0x69eff976, 0x69f084bb, 0x69f1f977, 0x69f2f978,
+    0x69f3f979, 0x69f4f97a, 0x69f579b3, 0x69f6f97b, 0x69f7f97c, 0x69f8f97d, 0x69f9f97e, 0x69fa7600,
+    0x69fb8616, 0x69fc4ffa, 0x69fd5186, 0x69fef97f, 0x6a41f980, 0x6a42f981, 0x6a43f982, 0x6a44f983,
+    0x6a45f984, 0x6a4674b5, 0x6a477916, 0x6a48f985, 0x6a498245, 0x6a4a8339, 0x6a4b8f3f, 0x6a4c8f5d,
+    0x6a4df986, 0x6a4ef987, 0x6a4ff988, 0x6a50f989, 0x6a51f98a, 0x6a52f98b, 0x6a53f98c, 0x6a54f98d,
+    0x6a55f98e, 0x6a56f98f, 0x6a57f990, 0x6a58633b, 0x6a596c87, 0x6a5af992, 0x6a5bf993, 0x6a5cf994,
+    0x6a5df995, 0x6a5ef996, 0x6a5f7e2f, 0x6a60f997, 0x6a61f998, 0x6a62f999, 0x6a63f99a, 0x6a64f99b,
+    0x6a65f99c, 0x6a66f99d, 0x6a67f99f, 0x6a68f9a0, 0x6a69f9a2, 0x6a6af9a3, 0x6a6bf9a4, 0x6a6cf9a5,
+    0x6a6d7430, 0x6a6ef9a6, 0x6a6ff9a7, 0x6a70f9a8, 0x6a71f9a9, 0x6a72f9aa, 0x6a73f9ab, 0x6a745db8,
+    0x6a75f9ac, 0x6a766e36, 0x6a776fda, 0x6a78702f, 0x6a797150, 0x6a7a7370, 0x6a7bf9ad, 0x6a7c7a4e,
+    0x6a7df9af, 0x6a7ef9b0, 0x6a7ff9b1, 0x6a809348, 0x6a81f9b2, 0x6a829719, 0x6a83f9b3, 0x6a84f9b4,
+    0x6a854e42, 0x6a86f9b5, 0x6a876c6d, 0x6a887a62, 0x6a89f9b6, 0x6a8af9b7, 0x6a8bf9b8, 0x6a8c4fc9,
+    0x6a8d5862, 0x6a8e61ca, 0x6a8f6556, 0x6a906fb3, 0x6a9171ac, 0x6a927b7d, 0x6a939c32, 0x6a949f07,
+    0x6a957e15, 0x6a969954, 0x6a977aaa, 0x6a9868a1, 0x6a996900, 0x6a9a7413, 0x6a9b8118, 0x6a9c5a03,
+    0x6a9d5d6c, 0x6a9ef9ba, 0x6a9ff9bb, 0x6aa05be5, 0x6aa1f9bc, 0x6aa2f9bd, 0x6aa36493, 0x6aa4f9be,
+    0x6aa5f9c0, 0x6aa671ff, 0x6aa7f9c1, 0x6aa8f9c2, 0x6aa9f9c3, 0x6aaa7e1f, 0x6aab4fd1, 0x6aac57c7,
+    0x6aad5889, 0x6aae6142, 0x6aaf6d8c, 0x6ab0752c, 0x6ab1f9c4, 0x6ab265f4, 0x6ab37397, 0x6ab47440,
+    0x6ab57d06, 0x6ab685d5, 0x6ab791ea, 0x6ab852d6, 0x6ab95f67, 0x6aba682f, 0x6abb6a52, 0x6abc6f90,
+    0x6abd7189, 0x6abe8553, 0x6abf4e90, 0x6ac05ac4, 0x6ac16c85, 0x6ac26d39, 0x6ac36e72, 0x6ac4f9c6,
+    0x6ac56690, 0x6ac6853f, 0x6ac78918, 0x6ac8f9c7, 0x6ac9f9c8, 0x6acaf9c9, 0x6acb6962, 0x6acc6d27,
+    0x6acdf9ca, 0x6acef9cb, 0x6acf7337, 0x6ad0f9cc, 0x6ad1f9cd, 0x6ad2f9ce, 0x6ad3f9cf, 0x6ad4f9d0,
+    0x6ad5f9d1, 0x6ad65809, 0x6ad7f9d2, 0x6ad8f9d3, 0x6ad9f9d4, 0x6adaf9d5, 0x6adbf9d6, 0x6adc8d07,
+    0x6addf9d7, 0x6ade9217, 0x6adff9d8, 0x6ae0f9d9, 0x6ae1f9da, 0x6ae2f9db, 0x6ae3701c, 0x6ae4f9dc,
+    0x6ae56905, 0x6ae6858f, 0x6ae7f9dd, 0x6ae8f9de, 0x6ae9f9df, 0x6aeaf9e1, 0x6aebf9e2, 0x6aecf9e3,
+    0x6aedf9e4, 0x6aeef9e5, 0x6aeff9e6, 0x6af08084, 0x6af182e1, 0x6af2f9e7, 0x6af3f9e8, 0x6af48cbd,
+    0x6af59087, 0x6af6f9e9, 0x6af7f9ea, 0x6af8f9eb, 0x6af9f9ec, 0x6afa8b1a, 0x6afbf9ed, 0x6afcf9ee,
+    0x6afdf9ef, 0x6afe7d6a, 0x6b418335, 0x6b42f9f0, 0x6b438693, 0x6b44f9f1, 0x6b45f9f2, 0x6b46f9f3,
+    0x6b474f5a, 0x6b486041, 0x6b49f9f4, 0x6b4af9f5, 0x6b4bf9f6, 0x6b4c5344, 0x6b4df9f7, 0x6b4ef9f8,
+    0x6b4ff9f9, 0x6b5082bf, 0x6b518328, 0x6b525b71, 0x6b5381e7, 0x6b546e3d, 0x6b557e21, 0x6b567d35,
+    0x6b5783f9, 0x6b5896ce, 0x6b599f5f, 0x6b5a540a, 0x6b5bf9fb, 0x6b5c7fdf, 0x6b5d93d1, 0x6b5e4f43,
+    0x6b5f4f7a, 0x6b60587c, 0x6b617420, 0x6b627551, 0x6b637b4c, 0x6b64942b, 0x6b657664, 0x6b669b8e,
+    0x6b6767fe, 0x6b686a89, 0x6b696dc0, 0x6b6a6e5e, 0x6b6b701e, 0x6b6c70a1, 0x6b6d738e, 0x6b6e73fd,
+    0x6b6f7d8e, 0x6b7092cc, 0x6b7174aa, 0x6b7296d5, 0x6b7360b0, 0x6b748e2a, 0x6b7559dd, 0x6b766e4a,
+    0x6b776f8d, 0x6b785bef, 0x6b79710c, 0x6b7a96cb, 0x6b7b7e52, 0x6b7c6c9a, 0x6b7d82b7, 0x6b7e5507,
+    0x6b7f6422, 0x6b80664b, 0x6b81686d, 0x6b826b84, 0x6b83755b, 0x6b847e1d, 0x6b8581fb, 0x6b86852f,
+    0x6b878897, 0x6b884f84, 0x6b8974c6, 0x6b8af9fd, 0x6b8b6f57, 0x6b8c93f6, 0x6b8d4f98, 0x6b8e7c12,
+    0x6b8f7d2e, 0x6b908b96, 0x6b916134, 0x6b927316, 0x6b935bc0, 0x6b945be8, 0x6b95501c, 0x6b968e60,
+    0x6b977394, 0x6b988f1f, 0x6b997c3d, 0x6b9a8a79, 0x6b9b581e, 0x6b9c527f, 0x6b9d8278, 0x6b9e82d5,
+    0x6b9f90a8, 0x6ba060a4, 0x6ba16181, 0x6ba28525, 0x6ba38acf, 0x6ba4939a, 0x6ba59a36, 0x6ba68e59,
+    0x6ba77443, 0x6ba8672e, 0x6ba98d05, 0x6baa53a0, 0x6bab7a49, 0x6bac86a9, 0x6bad741b, 0x6bae54a4,
+    0x6baf62d6, 0x6bb099b1, 0x6bb1577c, 0x6bb2666b, 0x6bb37438, 0x6bb47708, 0x6bb5fa03, 0x6bb669cc,
+    0x6bb795d6, 0x6bb864fa, 0x6bb99211, 0x6bba9a19, 0x6bbb67b0, 0x6bbcfa06, 0x6bbd82de, 0x6bbe900b,
+    0x6bbf66dd, 0x6bc0fa07, 0x6bc198c7, 0x6bc29a43, 0x6bc39642, 0x6bc473cc, 0x6bc582fe, 0x6bc6999d,
+    0x6bc753a6, 0x6bc8701a, 0x6bc9929c, 0x6bca9e79, 0x6bcb95d4, 0x6bcc5ae6, 0x6bcdfa08, 0x6bce7023,
+    0x6bcf8347, 0x6bd06af6, 0x6bd14fd4, 0x6bd2774d, 0x6bd36ceb, 0x6bd473b9, 0x6bd5665b, 0x6bd66cc2,
+    0x6bd76ece, 0x6bd87050, 0x6bd97192, 0x6bda7469, 0x6bdb9008, 0x6bdc66b3, 0x6bdd8e4a, 0x6bde5cb5,
+    0x6bdf6667, 0x6be07425, 0x6be182a6, 0x6be284bf, 0x6be370d8, 0x6be48b41, 0x6be55950, 0x6be66665,
+    0x6be75e4c, 0x6be86130, 0x6be96644, 0x6bea69a5, 0x6beb6e5f, 0x6bec6f62, 0x6bed749c, 0x6bee7c27,
+    0x6bef532f, 0x6bf08334, 0x6bf15b96, 0x6bf29404, 0x6bf36585, 0x6bf46d8d, 0x6bf56dc6, 0x6bf69a4d,
+    0x6bf75e3f, 0x6bf87166, 0x6bf973dd, 0x6bfa52db, 0x6bfb6684, 0x6bfc714a, 0x6bfd7098, 0x6bfe8a16,
+    0x6c417fd5, 0x6c4256cd, 0x6c436198, 0x6c44665e, 0x6c4571ba, 0x84413164, 0x84443133, 0x84463135,
+    0x84473136, 0x844a313a, 0x844b313b, 0x844c313c, 0x844d313d, 0x844e313e, 0x844f313f, 0x84503140,
+    0x84543144, 0x8461314f, 0x84813150, 0x84a13151, 0x84c13152, 0x84e13153, 0x85413154, 0x85613155,
+    0x85813156, 0x85a13157, 0x85c13158, 0x85e13159, 0x8641315a, 0x8661315b, 0x8681315c, 0x86a1315d,
+    0x86c1315e, 0x86e1315f, 0x87413160, 0x87613161, 0x87813162, 0x87a13163, 0x88413131, 0x8861ac00,
+    0x8862ac01, 0x8863ac02, 0x8865ac04, 0x8868ac07, 0x8869ac08, 0x886aac09, 0x886bac0a, 0x886cac0b,
+    0x8871ac10, 0x8873ac11, 0x8874ac12, 0x8875ac13, 0x8876ac14, 0x8877ac15, 0x8878ac16, 0x8879ac17,
+    0x887bac19, 0x887cac1a, 0x887dac1b, 0x8881ac1c, 0x8882ac1d, 0x8885ac20, 0x8889ac24, 0x8891ac2c,
+    0x8893ac2d, 0x8895ac2f, 0x8896ac30, 0x8897ac31, 0x88a1ac38, 0x88a2ac39, 0x88a5ac3c, 0x88a9ac40,
+    0x88b5ac4b, 0x88b7ac4d, 0x88c1ac54, 0x88c5ac58, 0x88c9ac5c, 0x88e1ac70, 0x88e2ac71, 0x88e5ac74,
+    0x88e8ac77, 0x88e9ac78, 0x88eaac79, 0x88ebac7a, 0x88f1ac80, 0x88f3ac81, 0x88f5ac83, 0x88f6ac84,
+    0x88f7ac85, 0x88f8ac86, 0x88f9ac87, 0x88fbac89, 0x88fcac8a, 0x88fdac8b, 0x8941ac8c, 0x8945ac90,
+    0x8948ac93, 0x8949ac94, 0x8951ac9c, 0x8953ac9d, 0x8955ac9f, 0x8956aca0, 0x8957aca1, 0x8961aca8,
+    0x8962aca9, 0x8963acaa, 0x8965acac, 0x8968acaf, 0x8969acb0, 0x8971acb8, 0x8973acb9, 0x8975acbb,
+    0x8976acbc, 0x8977acbd, 0x897bacc1, 0x8981acc4, 0x8985acc8, 0x8989accc, 0x8993acd5, 0x8995acd7,
+    0x89a1ace0, 0x89a2ace1, 0x89a5ace4, 0x89a8ace7, 0x89a9ace8, 0x89aaace9, 0x89abacea, 0x89adacec,
+    0x89b0acef, 0x89b1acf0, 0x89b3acf1, 0x89b5acf3, 0x89b7acf5, 0x89b8acf6, 0x89bcacfa, 0x89c1acfc,
+    0x89c2acfd, 0x89c5ad00, 0x89c9ad04, 0x89cbad06, 0x89d1ad0c, 0x89d3ad0d, 0x89d5ad0f, 0x89d7ad11,
+    0x89e1ad18, 0x89e2ad19, 0x89e5ad1c, 0x89e9ad20, 0x89f1ad28, 0x89f3ad29, 0x89f5ad2b, 0x89f6ad2c,
+    0x89f7ad2d, 0x8a41ad34, 0x8a42ad35, 0x8a45ad38, 0x8a49ad3c, 0x8a51ad44, 0x8a53ad45, 0x8a55ad47,
+    0x8a57ad49, 0x8a61ad50, 0x8a65ad54, 0x8a69ad58, 0x8a73ad61, 0x8a75ad63, 0x8a81ad6c, 0x8a82ad6d,
+    0x8a85ad70, 0x8a88ad73, 0x8a89ad74, 0x8a8aad75, 0x8a8bad76, 0x8a90ad7b, 0x8a91ad7c, 0x8a93ad7d,
+    0x8a95ad7f, 0x8a97ad81, 0x8a98ad82, 0x8aa1ad88, 0x8aa2ad89, 0x8aa5ad8c, 0x8aa9ad90, 0x8ab5ad9b,
+    0x8ab6ad9c, 0x8ab7ad9d, 0x8ac1ada4, 0x8ad5adb7, 0x8ae1adc0, 0x8ae2adc1, 0x8ae5adc4, 0x8ae9adc8,
+    0x8af1add0, 0x8af3add1, 0x8af5add3, 0x8af7add5, 0x8b41addc, 0x8b45ade0, 0x8b49ade4, 0x8b51adec,
+    0x8b61adf8, 0x8b62adf9, 0x8b65adfc, 0x8b68adff, 0x8b69ae00, 0x8b6aae01, 0x8b6bae02, 0x8b71ae08,
+    0x8b73ae09, 0x8b75ae0b, 0x8b77ae0d, 0x8b79ae0f, 0x8b7bae11, 0x8b81ae14, 0x8b95ae27, 0x8ba1ae30,
+    0x8ba2ae31, 0x8ba5ae34, 0x8ba8ae37, 0x8ba9ae38, 0x8babae3a, 0x8badae3c, 0x8bb1ae40, 0x8bb3ae41,
+    0x8bb5ae43, 0x8bb6ae44, 0x8bb7ae45, 0x8bb8ae46, 0x8bbbae49, 0x8bbcae4a, 0x8c413132, 0x8c61ae4c,
+    0x8c62ae4d, 0x8c63ae4e, 0x8c65ae50, 0x8c69ae54, 0x8c6bae56, 0x8c71ae5c, 0x8c73ae5d, 0x8c75ae5f,
+    0x8c76ae60, 0x8c77ae61, 0x8c78ae62, 0x8c7bae65, 0x8c81ae68, 0x8c82ae69, 0x8c85ae6c, 0x8c89ae70,
+    0x8c91ae78, 0x8c93ae79, 0x8c95ae7b, 0x8c96ae7c, 0x8c97ae7d, 0x8ca1ae84, 0x8ca2ae85, 0x8ca9ae8c,
+    0x8cc1aea0, 0x8ce1aebc, 0x8ce2aebd, 0x8ce3aebe, 0x8ce5aec0, 0x8ce9aec4, 0x8cf1aecc, 0x8cf3aecd,
+    0x8cf5aecf, 0x8cf6aed0, 0x8cf7aed1, 0x8d41aed8, 0x8d42aed9, 0x8d45aedc, 0x8d51aee8, 0x8d55aeeb,
+    0x8d57aeed, 0x8d61aef4, 0x8d65aef8, 0x8d69aefc, 0x8d71af04, 0x8d75af07, 0x8d76af08, 0x8d7baf0d,
+    0x8d81af10, 0x8da1af2c, 0x8da2af2d, 0x8da5af30, 0x8da7af32, 0x8da8af33, 0x8da9af34, 0x8db1af3c,
+    0x8db3af3d, 0x8db5af3f, 0x8db7af41, 0x8db8af42, 0x8db9af43, 0x8dc1af48, 0x8dc2af49, 0x8dc5af4c,
+    0x8dc9af50, 0x8dd1af58, 0x8dd5af5b, 0x8dd6af5c, 0x8dd7af5d, 0x8de1af64, 0x8de2af65, 0x8de5af68,
+    0x8df7af79, 0x8e41af80, 0x8e45af84, 0x8e49af88, 0x8e51af90, 0x8e53af91, 0x8e55af93, 0x8e57af95,
+    0x8e61af9c, 0x8e78afb2, 0x8e81afb8, 0x8e82afb9, 0x8e85afbc, 0x8e88afbf, 0x8e89afc0, 0x8e90afc7,
+    0x8e91afc8, 0x8e93afc9, 0x8e95afcb, 0x8e97afcd, 0x8e98afce, 0x8ea1afd4, 0x8ea5afd8, 0x8ea9afdc,
+    0x8eb5afe7, 0x8eb6afe8, 0x8eb7afe9, 0x8ec1aff0, 0x8ec2aff1, 0x8ec5aff4, 0x8ec9aff8, 0x8ed1b000,
+    0x8ed3b001, 0x8ed6b004, 0x8ee1b00c, 0x8ee2b00d, 0x8ee5b010, 0x8ee9b014, 0x8ef1b01c, 0x8ef3b01d,
+    0x8ef7b021, 0x8f41b028, 0x8f61b044, 0x8f62b045, 0x8f65b048, 0x8f67b04a, 0x8f69b04c, 0x8f6bb04e,
+    0x8f70b053, 0x8f71b054, 0x8f73b055, 0x8f75b057, 0x8f77b059, 0x8f7bb05d, 0x8f81b060, 0x8fa1b07c,
+    0x8fa2b07d, 0x8fa5b080, 0x8fa9b084, 0x8fb1b08c, 0x8fb3b08d, 0x8fb5b08f, 0x8fb6b090, 0x8fb7b091,
+    0x90413134, 0x9061b098, 0x9062b099, 0x9063b09a, 0x9065b09c, 0x9068b09f, 0x9069b0a0, 0x906ab0a1,
+    0x906bb0a2, 0x9071b0a8, 0x9073b0a9, 0x9075b0ab, 0x9076b0ac, 0x9077b0ad, 0x9078b0ae, 0x9079b0af,
+    0x907bb0b1, 0x907db0b3, 0x9081b0b4, 0x9082b0b5, 0x9085b0b8, 0x9088b0bb, 0x9089b0bc, 0x9091b0c4,
+    0x9093b0c5, 0x9095b0c7, 0x9096b0c8, 0x9097b0c9, 0x90a1b0d0, 0x90a2b0d1, 0x90a5b0d4, 0x90a9b0d8,
+    0x90b1b0e0, 0x90b7b0e5, 0x90c1b0ec, 0x90e1b108, 0x90e2b109, 0x90e4b10b, 0x90e5b10c, 0x90e8b10f,
+    0x90e9b110, 0x90ebb112, 0x90ecb113, 0x90f1b118, 0x90f3b119, 0x90f5b11b, 0x90f6b11c, 0x90f7b11d,
+    0x90f8b11e, 0x90fdb123, 0x9141b124, 0x9142b125, 0x9145b128, 0x9149b12c, 0x9151b134, 0x9153b135,
+    0x9155b137, 0x9156b138, 0x9157b139, 0x9161b140, 0x9162b141, 0x9165b144, 0x9168b147, 0x9169b148,
+    0x9171b150, 0x9173b151, 0x9175b153, 0x9176b154, 0x9177b155, 0x917ab158, 0x917bb159, 0x9181b15c,
+    0x9185b160, 0x9195b16f, 0x91a1b178, 0x91a2b179, 0x91a3b17a, 0x91a5b17c, 0x91a9b180, 0x91abb182,
+    0x91b1b188, 0x91b3b189, 0x91b5b18b, 0x91b7b18d, 0x91bcb192, 0x91bdb193, 0x91c1b194, 0x91c5b198,
+    0x91c9b19c, 0x91d5b1a7, 0x91d6b1a8, 0x91e1b1b0, 0x9241b1cc, 0x9245b1d0, 0x9249b1d4, 0x9251b1dc,
+    0x9253b1dd, 0x9255b1df, 0x9261b1e8, 0x9262b1e9, 0x9265b1ec, 0x9269b1f0, 0x9273b1f9, 0x9275b1fb,
+    0x9277b1fd, 0x9281b204, 0x9282b205, 0x9285b208, 0x9288b20b, 0x9289b20c, 0x9291b214, 0x9293b215,
+    0x9295b217, 0x9297b219, 0x92a1b220, 0x92b5b233, 0x92b6b234, 0x92c1b23c, 0x92e1b258, 0x92e5b25c,
+    0x92e9b260, 0x92f1b268, 0x92f3b269, 0x9341b274, 0x9342b275, 0x9349b27c, 0x9351b284, 0x9353b285,
+    0x9357b289, 0x9361b290, 0x9362b291, 0x9365b294, 0x9369b298, 0x936ab299, 0x936bb29a, 0x9371b2a0,
+    0x9373b2a1, 0x9375b2a3, 0x9377b2a5, 0x9378b2a6, 0x9379b2a7, 0x937cb2aa, 0x9381b2ac, 0x9385b2b0,
+    0x9389b2b4, 0x9397b2c1, 0x93a1b2c8, 0x93a2b2c9, 0x93a5b2cc, 0x93a9b2d0, 0x93aab2d1, 0x93abb2d2,
+    0x93b1b2d8, 0x93b3b2d9, 0x93b5b2db, 0x93b7b2dd, 0x93bab2e0, 0x93bcb2e2, 0x94413137, 0x9461b2e4,
+    0x9462b2e5, 0x9463b2e6, 0x9465b2e8, 0x9468b2eb, 0x9469b2ec, 0x946ab2ed, 0x946bb2ee, 0x946cb2ef,
+    0x9470b2f3, 0x9471b2f4, 0x9473b2f5, 0x9475b2f7, 0x9476b2f8, 0x9477b2f9, 0x9478b2fa, 0x9479b2fb,
+    0x947db2ff, 0x9481b300, 0x9482b301, 0x9485b304, 0x9489b308, 0x9491b310, 0x9493b311, 0x9495b313,
+    0x9496b314, 0x9497b315, 0x94a1b31c, 0x94b7b331, 0x94c1b338, 0x94e1b354, 0x94e2b355, 0x94e3b356,
+    0x94e5b358, 0x94e8b35b, 0x94e9b35c, 0x94ebb35e, 0x94ecb35f, 0x94f1b364, 0x94f3b365, 0x94f5b367,
+    0x94f6b368, 0x94f7b369, 0x94f8b36a, 0x94f9b36b, 0x94fcb36e, 0x9541b370, 0x9542b371, 0x9545b374,
+    0x9549b378, 0x9551b380, 0x9553b381, 0x9555b383, 0x9556b384, 0x9557b385, 0x9561b38c, 0x9565b390,
+    0x9569b394, 0x9571b39c, 0x9576b3a0, 0x9577b3a1, 0x9581b3a8, 0x9585b3ac, 0x95a1b3c4, 0x95a2b3c5,
+    0x95a5b3c8, 0x95a8b3cb, 0x95a9b3cc, 0x95abb3ce, 0x95adb3d0, 0x95b0b3d3, 0x95b1b3d4, 0x95b3b3d5,
+    0x95b5b3d7, 0x95b7b3d9, 0x95b9b3db, 0x95bbb3dd, 0x95c1b3e0, 0x95c5b3e4, 0x95c9b3e8, 0x95e1b3fc,
+    0x95e5b400, 0x95f5b40f, 0x95f6b410, 0x9641b418, 0x9645b41c, 0x9649b420, 0x9651b428, 0x9653b429,
+    0x9655b42b, 0x9656b42c, 0x9661b434, 0x9681b450, 0x9682b451, 0x9685b454, 0x9688b457, 0x9689b458,
+    0x9691b460, 0x9693b461, 0x9695b463, 0x9697b465, 0x96a1b46c, 0x96b5b47f, 0x96b6b480, 0x96c1b488,
+    0x96d7b49d, 0x96e1b4a4, 0x96e5b4a8, 0x96e9b4ac, 0x96f1b4b4, 0x96f3b4b5, 0x96f5b4b7, 0x96f7b4b9,
+    0x9741b4c0, 0x9742b4c1, 0x9745b4c4, 0x9749b4c8, 0x9751b4d0, 0x9757b4d5, 0x9761b4dc, 0x9762b4dd,
+    0x9765b4e0, 0x9768b4e3, 0x9769b4e4, 0x976bb4e6, 0x976cb4e7, 0x9771b4ec, 0x9773b4ed, 0x9775b4ef,
+    0x9777b4f1, 0x9781b4f8, 0x97a1b514, 0x97a2b515, 0x97a5b518, 0x97a8b51b, 0x97a9b51c, 0x97b1b524,
+    0x97b3b525, 0x97b5b527, 0x97b6b528, 0x97b7b529, 0x97b8b52a, 0x97bcb52e, 0x98413138, 0x9861b530,
+    0x9862b531, 0x9863b532, 0x9865b534, 0x9868b537, 0x9869b538, 0x9870b53f, 0x9871b540, 0x9873b541,
+    0x9875b543, 0x9876b544, 0x9877b545, 0x987db54b, 0x9881b54c, 0x9882b54d, 0x9885b550, 0x9889b554,
+    0x9891b55c, 0x9893b55d, 0x9895b55f, 0x9896b560, 0x9897b561, 0x98a1b568, 0x98c1b584, 0x98e1b5a0,
+    0x98e2b5a1, 0x98e5b5a4, 0x98e9b5a8, 0x98ebb5aa, 0x98ecb5ab, 0x98f1b5b0, 0x98f3b5b1, 0x98f5b5b3,
+    0x98f6b5b4, 0x98f7b5b5, 0x98fdb5bb, 0x9941b5bc, 0x9942b5bd, 0x9945b5c0, 0x9949b5c4, 0x9951b5cc,
+    0x9953b5cd, 0x9955b5cf, 0x9956b5d0, 0x9957b5d1, 0x9961b5d8, 0x9976b5ec, 0x9981b5f4, 0x99a1b610,
+    0x99a2b611, 0x99a5b614, 0x99a9b618, 0x99b7b625, 0x99c1b62c, 0x99c9b634, 0x99e1b648, 0x9a41b664,
+    0x9a45b668, 0x9a61b680, 0x9a81b69c, 0x9a82b69d, 0x9a85b6a0, 0x9a89b6a4, 0x9a90b6ab, 0x9a91b6ac,
+    0x9a97b6b1, 0x9aa1b6b8, 0x9ac1b6d4, 0x9ae1b6f0, 0x9ae5b6f4, 0x9ae9b6f8, 0x9af1b700, 0x9af3b701,
+    0x9af7b705, 0x9b41b70c, 0x9b61b728, 0x9b62b729, 0x9b65b72c, 0x9b68b72f, 0x9b69b730, 0x9b71b738,
+    0x9b73b739, 0x9b75b73b, 0x9b81b744, 0x9b85b748, 0x9b89b74c, 0x9b91b754, 0x9b93b755, 0x9ba1b760,
+    0x9ba5b764, 0x9ba9b768, 0x9bb1b770, 0x9bb3b771, 0x9bb5b773, 0x9bb7b775, 0x9c413139, 0x9c61b77c,
+    0x9c62b77d, 0x9c65b780, 0x9c69b784, 0x9c71b78c, 0x9c73b78d, 0x9c75b78f, 0x9c76b790, 0x9c77b791,
+    0x9c78b792, 0x9c7cb796, 0x9c7db797, 0x9c81b798, 0x9c82b799, 0x9c85b79c, 0x9c89b7a0, 0x9c91b7a8,
+    0x9c93b7a9, 0x9c95b7ab, 0x9c96b7ac, 0x9c97b7ad, 0x9ca1b7b4, 0x9ca2b7b5, 0x9ca5b7b8, 0x9cb5b7c7,
+    0x9cb7b7c9, 0x9cc1b7d0, 0x9ce1b7ec, 0x9ce2b7ed, 0x9ce5b7f0, 0x9ce9b7f4, 0x9cf1b7fc, 0x9cf3b7fd,
+    0x9cf5b7ff, 0x9cf6b800, 0x9cf7b801, 0x9cfdb807, 0x9d41b808, 0x9d42b809, 0x9d45b80c, 0x9d48b80f,
+    0x9d49b810, 0x9d51b818, 0x9d53b819, 0x9d55b81b, 0x9d57b81d, 0x9d61b824, 0x9d62b825, 0x9d65b828,
+    0x9d69b82c, 0x9d71b834, 0x9d73b835, 0x9d75b837, 0x9d76b838, 0x9d77b839, 0x9d81b840, 0x9d85b844,
+    0x9d93b851, 0x9d95b853, 0x9da1b85c, 0x9da2b85d, 0x9da5b860, 0x9da9b864, 0x9db1b86c, 0x9db3b86d,
+    0x9db5b86f, 0x9db7b871, 0x9dc1b878, 0x9dc5b87c, 0x9dd7b88d, 0x9de1b894, 0x9df6b8a8, 0x9e41b8b0,
+    0x9e45b8b4, 0x9e49b8b8, 0x9e51b8c0, 0x9e53b8c1, 0x9e55b8c3, 0x9e57b8c5, 0x9e61b8cc, 0x9e65b8d0,
+    0x9e69b8d4, 0x9e71b8dc, 0x9e73b8dd, 0x9e75b8df, 0x9e77b8e1, 0x9e81b8e8, 0x9e82b8e9, 0x9e85b8ec,
+    0x9e89b8f0, 0x9e91b8f8, 0x9e93b8f9, 0x9e95b8fb, 0x9e97b8fd, 0x9ea1b904, 0x9eb5b917, 0x9eb6b918,
+    0x9ec1b920, 0x9ee1b93c, 0x9ee2b93d, 0x9ee5b940, 0x9ee9b944, 0x9ef1b94c, 0x9ef5b94f, 0x9ef7b951,
+    0x9f41b958, 0x9f42b959, 0x9f45b95c, 0x9f49b960, 0x9f51b968, 0x9f53b969, 0x9f55b96b, 0x9f57b96d,
+    0x9f61b974, 0x9f62b975, 0x9f65b978, 0x9f69b97c, 0x9f71b984, 0x9f73b985, 0x9f75b987, 0x9f77b989,
+    0x9f78b98a, 0x9f7bb98d, 0x9f7cb98e, 0x9f81b990, 0x9fa1b9ac, 0x9fa2b9ad, 0x9fa5b9b0, 0x9fa9b9b4,
+    0x9fb1b9bc, 0x9fb3b9bd, 0x9fb5b9bf, 0x9fb7b9c1, 0xa0413141, 0xa061b9c8, 0xa062b9c9, 0xa065b9cc,
+    0xa067b9ce, 0xa068b9cf, 0xa069b9d0, 0xa06ab9d1, 0xa06bb9d2, 0xa071b9d8, 0xa073b9d9, 0xa075b9db,
+    0xa077b9dd, 0xa078b9de, 0xa079b9df, 0xa07bb9e1, 0xa07db9e3, 0xa081b9e4, 0xa082b9e5, 0xa085b9e8,
+    0xa089b9ec, 0xa091b9f4, 0xa093b9f5, 0xa095b9f7, 0xa096b9f8, 0xa097b9f9, 0xa098b9fa, 0xa099b9fb,
+    0xa0a1ba00, 0xa0a2ba01, 0xa0a9ba08, 0xa0b7ba15, 0xa0c1ba1c, 0xa0e1ba38, 0xa0e2ba39, 0xa0e5ba3c,
+    0xa0e9ba40, 0xa0ebba42, 0xa0f1ba48, 0xa0f3ba49, 0xa0f5ba4b, 0xa0f7ba4d, 0xa0f8ba4e, 0xa0fdba53,
+    0xa141ba54, 0xa142ba55, 0xa145ba58, 0xa149ba5c, 0xa151ba64, 0xa153ba65, 0xa155ba67, 0xa156ba68,
+    0xa157ba69, 0xa159ba6b, 0xa15bba6d, 0xa161ba70, 0xa162ba71, 0xa165ba74, 0xa169ba78, 0xa171ba80,
+    0xa175ba83, 0xa176ba84, 0xa177ba85, 0xa179ba87, 0xa181ba8c, 0xa1a1baa8, 0xa1a2baa9, 0xa1a4baab,
+    0xa1a5baac, 0xa1a8baaf, 0xa1a9bab0, 0xa1abbab2, 0xa1b1bab8, 0xa1b3bab9, 0xa1b5babb, 0xa1b7babd,
+    0xa1bdbac3, 0xa1c1bac4, 0xa1c5bac8, 0xa1d6bad8, 0xa1d7bad9, 0xa1e1bae0, 0xa241bafc, 0xa245bb00,
+    0xa249bb04, 0xa253bb0d, 0xa255bb0f, 0xa257bb11, 0xa261bb18, 0xa265bb1c, 0xa269bb20, 0xa273bb29,
+    0xa275bb2b, 0xa281bb34, 0xa282bb35, 0xa283bb36, 0xa285bb38, 0xa288bb3b, 0xa289bb3c, 0xa28abb3d,
+    0xa28bbb3e, 0xa291bb44, 0xa293bb45, 0xa295bb47, 0xa297bb49, 0xa29bbb4d, 0xa29dbb4f, 0xa2a1bb50,
+    0xa2a5bb54, 0xa2a9bb58, 0xa2b3bb61, 0xa2b5bb63, 0xa2c1bb6c, 0xa2e1bb88, 0xa2e5bb8c, 0xa2e9bb90,
+    0xa341bba4, 0xa345bba8, 0xa349bbac, 0xa351bbb4, 0xa355bbb7, 0xa361bbc0, 0xa362bbc1, 0xa365bbc4,
+    0xa369bbc8, 0xa371bbd0, 0xa375bbd3, 0xa377bbd5, 0xa381bbdc, 0xa385bbe0, 0xa3a1bbf8, 0xa3a2bbf9,
+    0xa3a5bbfc, 0xa3a8bbff, 0xa3a9bc00, 0xa3abbc02, 0xa3b1bc08, 0xa3b3bc09, 0xa3b5bc0b, 0xa3b6bc0c,
+    0xa3b7bc0d, 0xa3b8bc0e, 0xa3b9bc0f, 0xa3bbbc11, 0xa4413142, 0xa461bc14, 0xa462bc15, 0xa463bc16,
+    0xa464bc17, 0xa465bc18, 0xa468bc1b, 0xa469bc1c, 0xa46abc1d, 0xa46bbc1e, 0xa46cbc1f, 0xa471bc24,
+    0xa473bc25, 0xa475bc27, 0xa476bc28, 0xa477bc29, 0xa47bbc2d, 0xa481bc30, 0xa482bc31, 0xa485bc34,
+    0xa488bc37, 0xa489bc38, 0xa491bc40, 0xa493bc41, 0xa495bc43, 0xa496bc44, 0xa497bc45, 0xa49bbc49,
+    0xa4a1bc4c, 0xa4a2bc4d, 0xa4a5bc50, 0xa4b1bc5c, 0xa4b3bc5d, 0xa4c1bc68, 0xa4e1bc84, 0xa4e2bc85,
+    0xa4e5bc88, 0xa4e8bc8b, 0xa4e9bc8c, 0xa4ebbc8e, 0xa4f1bc94, 0xa4f3bc95, 0xa4f5bc97, 0xa4f6bc98,
+    0xa4f7bc99, 0xa4f8bc9a, 0xa4fabc9c, 0xa541bca0, 0xa542bca1, 0xa545bca4, 0xa548bca7, 0xa549bca8,
+    0xa551bcb0, 0xa553bcb1, 0xa555bcb3, 0xa556bcb4, 0xa557bcb5, 0xa55bbcb9, 0xa561bcbc, 0xa562bcbd,
+    0xa565bcc0, 0xa569bcc4, 0xa571bccc, 0xa573bccd, 0xa575bccf, 0xa576bcd0, 0xa577bcd1, 0xa578bcd2,
+    0xa579bcd3, 0xa57abcd4, 0xa57bbcd5, 0xa581bcd8, 0xa585bcdc, 0xa5a1bcf4, 0xa5a2bcf5, 0xa5a3bcf6,
+    0xa5a5bcf8, 0xa5a9bcfc, 0xa5b1bd04, 0xa5b3bd05, 0xa5b5bd07, 0xa5b7bd09, 0xa5c1bd10, 0xa5c5bd14,
+    0xa5d5bd23, 0xa5d6bd24, 0xa5e1bd2c, 0xa5f6bd40, 0xa641bd48, 0xa642bd49, 0xa645bd4c, 0xa649bd50,
+    0xa651bd58, 0xa653bd59, 0xa661bd64, 0xa665bd68, 0xa681bd80, 0xa682bd81, 0xa685bd84, 0xa688bd87,
+    0xa689bd88, 0xa68abd89, 0xa68bbd8a, 0xa691bd90, 0xa693bd91, 0xa695bd93, 0xa697bd95, 0xa699bd97,
+    0xa69bbd99, 0xa69cbd9a, 0xa6a1bd9c, 0xa6a9bda4, 0xa6b6bdb0, 0xa6babdb4, 0xa6c1bdb8, 0xa6e1bdd4,
+    0xa6e2bdd5, 0xa6e5bdd8, 0xa6e9bddc, 0xa6f7bde9, 0xa741bdf0, 0xa745bdf4, 0xa749bdf8, 0xa751be00,
+    0xa755be03, 0xa757be05, 0xa761be0c, 0xa762be0d, 0xa765be10, 0xa769be14, 0xa76dbe18, 0xa771be1c,
+    0xa773be1d, 0xa775be1f, 0xa777be21, 0xa781be28, 0xa7a1be44, 0xa7a2be45, 0xa7a5be48, 0xa7a8be4b,
+    0xa7a9be4c, 0xa7abbe4e, 0xa7b1be54, 0xa7b3be55, 0xa7b5be57, 0xa7b7be59, 0xa7b8be5a, 0xa7b9be5b,
+    0xa8413143, 0xa861be60, 0xa862be61, 0xa865be64, 0xa869be68, 0xa86bbe6a, 0xa871be70, 0xa873be71,
+    0xa875be73, 0xa876be74, 0xa877be75, 0xa87dbe7b, 0xa881be7c, 0xa882be7d, 0xa885be80, 0xa889be84,
+    0xa891be8c, 0xa893be8d, 0xa895be8f, 0xa896be90, 0xa897be91, 0xa8a1be98, 0xa8a2be99, 0xa8a5be9c,
+    0xa8b1bea8, 0xa8c1beb4, 0xa8e1bed0, 0xa8e2bed1, 0xa8e5bed4, 0xa8e8bed7, 0xa8e9bed8, 0xa8f1bee0,
+    0xa8f5bee3, 0xa8f6bee4, 0xa8f7bee5, 0xa941beec, 0xa942beed, 0xa945bef0, 0xa949bef4, 0xa955beff,
+    0xa957bf01, 0xa961bf08, 0xa962bf09, 0xa971bf18, 0xa973bf19, 0xa975bf1b, 0xa976bf1c, 0xa977bf1d,
+    0xa981bf24, 0xa9a1bf40, 0xa9a2bf41, 0xa9a5bf44, 0xa9a9bf48, 0xa9b1bf50, 0xa9b3bf51, 0xa9b7bf55,
+    0xa9c1bf5c, 0xa9e1bf78, 0xaa41bf94, 0xaa61bfb0, 0xaa71bfc0, 0xaa77bfc5, 0xaa81bfcc, 0xaa82bfcd,
+    0xaa85bfd0, 0xaa89bfd4, 0xaa8abfd5, 0xaa91bfdc, 0xaa93bfdd, 0xaa95bfdf, 0xaa97bfe1, 0xaaa1bfe8,
+    0xaac1c004, 0xaae1c020, 0xab41c03c, 0xab57c051, 0xab61c058, 0xab62c059, 0xab65c05c, 0xab69c060,
+    0xab71c068, 0xab73c069, 0xab81c074, 0xaba1c090, 0xaba2c091, 0xaba5c094, 0xaba9c098, 0xabb1c0a0,
+    0xabb3c0a1, 0xabb5c0a3, 0xabb7c0a5, 0xac413145, 0xac61c0ac, 0xac62c0ad, 0xac63c0ae, 0xac64c0af,
+    0xac65c0b0, 0xac68c0b3, 0xac69c0b4, 0xac6ac0b5, 0xac6bc0b6, 0xac6cc0b7, 0xac70c0bb, 0xac71c0bc,
+    0xac73c0bd, 0xac75c0bf, 0xac76c0c0, 0xac77c0c1, 0xac79c0c3, 0xac7bc0c5, 0xac7dc0c7, 0xac81c0c8,
+    0xac82c0c9, 0xac85c0cc, 0xac88c0cf, 0xac89c0d0, 0xac91c0d8, 0xac93c0d9, 0xac95c0db, 0xac96c0dc,
+    0xac97c0dd, 0xaca1c0e4, 0xaca2c0e5, 0xaca5c0e8, 0xaca9c0ec, 0xacb1c0f4, 0xacb3c0f5, 0xacb5c0f7,
+    0xacb7c0f9, 0xacc1c100, 0xacc5c104, 0xacc9c108, 0xacd1c110, 0xacd7c115, 0xace1c11c, 0xace2c11d,
+    0xace3c11e, 0xace4c11f, 0xace5c120, 0xace8c123, 0xace9c124, 0xaceac125, 0xacebc126, 0xacecc127,
+    0xacf1c12c, 0xacf3c12d, 0xacf5c12f, 0xacf6c130, 0xacf7c131, 0xacfcc136, 0xad41c138, 0xad42c139,
+    0xad45c13c, 0xad48c13f, 0xad49c140, 0xad51c148, 0xad53c149, 0xad55c14b, 0xad56c14c, 0xad57c14d,
+    0xad5bc151, 0xad61c154, 0xad62c155, 0xad64c157, 0xad65c158, 0xad69c15c, 0xad71c164, 0xad73c165,
+    0xad75c167, 0xad76c168, 0xad77c169, 0xad81c170, 0xad85c174, 0xad89c178, 0xad97c185, 0xada1c18c,
+    0xada2c18d, 0xada3c18e, 0xada5c190, 0xada8c193, 0xada9c194, 0xadabc196, 0xadb1c19c, 0xadb3c19d,
+    0xadb5c19f, 0xadb7c1a1, 0xadbbc1a5, 0xadc1c1a8, 0xadc2c1a9, 0xadc5c1ac, 0xadc9c1b0, 0xadd5c1bb,
+    0xadd7c1bd, 0xade1c1c4, 0xade5c1c8, 0xade9c1cc, 0xadf1c1d4, 0xadf5c1d7, 0xadf6c1d8, 0xae41c1e0,
+    0xae45c1e4, 0xae49c1e8, 0xae51c1f0, 0xae53c1f1, 0xae55c1f3, 0xae61c1fc, 0xae62c1fd, 0xae65c200,
+    0xae69c204, 0xae71c20c, 0xae73c20d, 0xae75c20f, 0xae77c211, 0xae81c218, 0xae82c219, 0xae85c21c,
+    0xae88c21f, 0xae89c220, 0xae91c228, 0xae93c229, 0xae95c22b, 0xae97c22d, 0xae99c22f, 0xae9bc231,
+    0xae9cc232, 0xaea1c234, 0xaeb6c248, 0xaec1c250, 0xaec2c251, 0xaec5c254, 0xaec9c258, 0xaed1c260,
+    0xaed7c265, 0xaee1c26c, 0xaee2c26d, 0xaee5c270, 0xaee9c274, 0xaef1c27c, 0xaef3c27d, 0xaef5c27f,
+    0xaef7c281, 0xaf41c288, 0xaf42c289, 0xaf45c28c, 0xaf49c290, 0xaf51c298, 0xaf55c29b, 0xaf57c29d,
+    0xaf61c2a4, 0xaf62c2a5, 0xaf65c2a8, 0xaf69c2ac, 0xaf6ac2ad, 0xaf70c2b3, 0xaf71c2b4, 0xaf73c2b5,
+    0xaf75c2b7, 0xaf77c2b9, 0xaf81c2c0, 0xafa1c2dc, 0xafa2c2dd, 0xafa5c2e0, 0xafa8c2e3, 0xafa9c2e4,
+    0xafabc2e6, 0xafb0c2eb, 0xafb1c2ec, 0xafb3c2ed, 0xafb5c2ef, 0xafb7c2f1, 0xafbcc2f6, 0xb0413146,
+    0xb061c2f8, 0xb062c2f9, 0xb064c2fb, 0xb065c2fc, 0xb069c300, 0xb06bc302, 0xb071c308, 0xb073c309,
+    0xb075c30b, 0xb076c30c, 0xb077c30d, 0xb07dc313, 0xb081c314, 0xb082c315, 0xb085c318, 0xb089c31c,
+    0xb091c324, 0xb093c325, 0xb095c327, 0xb096c328, 0xb097c329, 0xb0a1c330, 0xb0b5c343, 0xb0b7c345,
+    0xb0c1c34c, 0xb0e1c368, 0xb0e2c369, 0xb0e5c36c, 0xb0e9c370, 0xb0ebc372, 0xb0f1c378, 0xb0f3c379,
+    0xb0f5c37b, 0xb0f6c37c, 0xb0f7c37d, 0xb141c384, 0xb142c385, 0xb145c388, 0xb149c38c, 0xb157c399,
+    0xb161c3a0, 0xb181c3bc, 0xb185c3c0, 0xb1a1c3d8, 0xb1a2c3d9, 0xb1a5c3dc, 0xb1a8c3df, 0xb1a9c3e0,
+    0xb1abc3e2, 0xb1b1c3e8, 0xb1b3c3e9, 0xb1b7c3ed, 0xb1c1c3f4, 0xb1c2c3f5, 0xb1c5c3f8, 0xb1c9c3fc,
+    0xb1d6c408, 0xb1e1c410, 0xb1f6c424, 0xb241c42c, 0xb245c430, 0xb249c434, 0xb251c43c, 0xb253c43d,
+    0xb255c43f, 0xb261c448, 0xb281c464, 0xb282c465, 0xb285c468, 0xb289c46c, 0xb291c474, 0xb293c475,
+    0xb295c477, 0xb297c479, 0xb2a1c480, 0xb2b5c493, 0xb2b6c494, 0xb2c1c49c, 0xb2e1c4b8, 0xb2e5c4bc,
+    0xb2fdc4d3, 0xb341c4d4, 0xb357c4e9, 0xb361c4f0, 0xb362c4f1, 0xb365c4f4, 0xb369c4f8, 0xb36bc4fa,
+    0xb370c4ff, 0xb371c500, 0xb373c501, 0xb381c50c, 0xb385c510, 0xb389c514, 0xb391c51c, 0xb3a1c528,
+    0xb3a2c529, 0xb3a5c52c, 0xb3a9c530, 0xb3b1c538, 0xb3b3c539, 0xb3b5c53b, 0xb3b6c53c, 0xb3b7c53d,
+    0xb3b9c53f, 0xb4413147, 0xb461c544, 0xb462c545, 0xb465c548, 0xb466c549, 0xb467c54a, 0xb469c54c,
+    0xb46ac54d, 0xb46bc54e, 0xb46cc54f, 0xb470c553, 0xb471c554, 0xb473c555, 0xb475c557, 0xb476c558,
+    0xb477c559, 0xb47bc55d, 0xb47cc55e, 0xb47dc55f, 0xb481c560, 0xb482c561, 0xb485c564, 0xb489c568,
+    0xb491c570, 0xb493c571, 0xb495c573, 0xb496c574, 0xb497c575, 0xb4a1c57c, 0xb4a2c57d, 0xb4a5c580,
+    0xb4a9c584, 0xb4acc587, 0xb4b1c58c, 0xb4b3c58d, 0xb4b5c58f, 0xb4b6c590, 0xb4b7c591, 0xb4bbc595,
+    0xb4bdc597, 0xb4c1c598, 0xb4c5c59c, 0xb4c9c5a0, 0xb4d3c5a9, 0xb4d5c5ab, 0xb4e1c5b4, 0xb4e2c5b5,
+    0xb4e3c5b6, 0xb4e5c5b8, 0xb4e6c5b9, 0xb4e8c5bb, 0xb4e9c5bc, 0xb4eac5bd, 0xb4ebc5be, 0xb4f1c5c4,
+    0xb4f3c5c5, 0xb4f4c5c6, 0xb4f5c5c7, 0xb4f6c5c8, 0xb4f7c5c9, 0xb4f8c5ca, 0xb4fac5cc, 0xb4fcc5ce,
+    0xb541c5d0, 0xb542c5d1, 0xb545c5d4, 0xb549c5d8, 0xb551c5e0, 0xb553c5e1, 0xb555c5e3, 0xb557c5e5,
+    0xb561c5ec, 0xb562c5ed, 0xb563c5ee, 0xb565c5f0, 0xb566c5f1, 0xb568c5f3, 0xb569c5f4, 0xb56bc5f6,
+    0xb56cc5f7, 0xb571c5fc, 0xb573c5fd, 0xb574c5fe, 0xb575c5ff, 0xb576c600, 0xb577c601, 0xb57bc605,
+    0xb57cc606, 0xb57dc607, 0xb581c608, 0xb585c60c, 0xb589c610, 0xb591c618, 0xb593c619, 0xb595c61b,
+    0xb596c61c, 0xb597c61d, 0xb5a1c624, 0xb5a2c625, 0xb5a5c628, 0xb5a8c62b, 0xb5a9c62c, 0xb5aac62d,
+    0xb5abc62e, 0xb5adc630, 0xb5b0c633, 0xb5b1c634, 0xb5b3c635, 0xb5b5c637, 0xb5b7c639, 0xb5b8c63a,
+    0xb5b9c63b, 0xb5c1c640, 0xb5c2c641, 0xb5c5c644, 0xb5c9c648, 0xb5d1c650, 0xb5d3c651, 0xb5d5c653,
+    0xb5d6c654, 0xb5d7c655, 0xb5e1c65c, 0xb5e2c65d, 0xb5e5c660, 0xb5f1c66c, 0xb5f5c66f, 0xb5f7c671,
+    0xb641c678, 0xb642c679, 0xb645c67c, 0xb649c680, 0xb651c688, 0xb653c689, 0xb655c68b, 0xb657c68d,
+    0xb661c694, 0xb662c695, 0xb665c698, 0xb669c69c, 0xb671c6a4, 0xb673c6a5, 0xb675c6a7, 0xb677c6a9,
+    0xb681c6b0, 0xb682c6b1, 0xb685c6b4, 0xb688c6b7, 0xb689c6b8, 0xb68ac6b9, 0xb68bc6ba, 0xb691c6c0,
+    0xb693c6c1, 0xb695c6c3, 0xb697c6c5, 0xb6a1c6cc, 0xb6a2c6cd, 0xb6a5c6d0, 0xb6a9c6d4, 0xb6b1c6dc,
+    0xb6b3c6dd, 0xb6b5c6df, 0xb6b6c6e0, 0xb6b7c6e1, 0xb6c1c6e8, 0xb6c2c6e9, 0xb6c5c6ec, 0xb6c9c6f0,
+    0xb6d1c6f8, 0xb6d3c6f9, 0xb6d7c6fd, 0xb6e1c704, 0xb6e2c705, 0xb6e5c708, 0xb6e8c70b, 0xb6e9c70c,
+    0xb6f1c714, 0xb6f3c715, 0xb6f5c717, 0xb6f7c719, 0xb741c720, 0xb742c721, 0xb745c724, 0xb749c728,
+    0xb751c730, 0xb753c731, 0xb755c733, 0xb757c735, 0xb758c736, 0xb759c737, 0xb761c73c, 0xb762c73d,
+    0xb765c740, 0xb769c744, 0xb76fc74a, 0xb771c74c, 0xb773c74d, 0xb775c74f, 0xb777c751, 0xb778c752,
+    0xb779c753, 0xb77ac754, 0xb77bc755, 0xb77cc756, 0xb77dc757, 0xb781c758, 0xb785c75c, 0xb789c760,
+    0xb791c768, 0xb795c76b, 0xb7a1c774, 0xb7a2c775, 0xb7a5c778, 0xb7a8c77b, 0xb7a9c77c, 0xb7aac77d,
+    0xb7abc77e, 0xb7b0c783, 0xb7b1c784, 0xb7b3c785, 0xb7b5c787, 0xb7b6c788, 0xb7b7c789, 0xb7b8c78a,
+    0xb7bcc78e, 0xb8413148, 0xb861c790, 0xb862c791, 0xb865c794, 0xb867c796, 0xb868c797, 0xb869c798,
+    0xb86bc79a, 0xb871c7a0, 0xb873c7a1, 0xb875c7a3, 0xb876c7a4, 0xb877c7a5, 0xb878c7a6, 0xb879c7a7,
+    0xb87cc7aa, 0xb881c7ac, 0xb882c7ad, 0xb885c7b0, 0xb889c7b4, 0xb891c7bc, 0xb893c7bd, 0xb895c7bf,
+    0xb896c7c0, 0xb897c7c1, 0xb8a1c7c8, 0xb8a2c7c9, 0xb8a5c7cc, 0xb8a7c7ce, 0xb8a9c7d0, 0xb8b1c7d8,
+    0xb8b7c7dd, 0xb8c1c7e4, 0xb8c5c7e8, 0xb8c9c7ec, 0xb8e1c800, 0xb8e2c801, 0xb8e5c804, 0xb8e8c807,
+    0xb8e9c808, 0xb8ebc80a, 0xb8f1c810, 0xb8f3c811, 0xb8f5c813, 0xb8f6c814, 0xb8f7c815, 0xb8f8c816,
+    0xb8fdc81b, 0xb941c81c, 0xb942c81d, 0xb945c820, 0xb949c824, 0xb951c82c, 0xb953c82d, 0xb955c82f,
+    0xb957c831, 0xb961c838, 0xb962c839, 0xb965c83c, 0xb969c840, 0xb971c848, 0xb973c849, 0xb975c84b,
+    0xb976c84c, 0xb977c84d, 0xb981c854, 0xb9a1c870, 0xb9a2c871, 0xb9a5c874, 0xb9a9c878, 0xb9abc87a,
+    0xb9b1c880, 0xb9b3c881, 0xb9b5c883, 0xb9b7c885, 0xb9b8c886, 0xb9b9c887, 0xb9bdc88b, 0xb9c1c88c,
+    0xb9c2c88d, 0xb9c5c890, 0xb9c9c894, 0xb9d1c89c, 0xb9d3c89d, 0xb9d5c89f, 0xb9d6c8a0, 0xb9d7c8a1,
+    0xb9e1c8a8, 0xb9e5c8ac, 0xb9e9c8b0, 0xb9f1c8b8, 0xb9f6c8bc, 0xb9f7c8bd, 0xba41c8c4, 0xba45c8c8,
+    0xba49c8cc, 0xba51c8d4, 0xba53c8d5, 0xba55c8d7, 0xba57c8d9, 0xba61c8e0, 0xba62c8e1, 0xba65c8e4,
+    0xba69c8e8, 0xba71c8f0, 0xba73c8f1, 0xba77c8f5, 0xba81c8fc, 0xba82c8fd, 0xba85c900, 0xba89c904,
+    0xba8ac905, 0xba8bc906, 0xba91c90c, 0xba93c90d, 0xba95c90f, 0xba97c911, 0xbaa1c918, 0xbab5c92b,
+    0xbab6c92c, 0xbac1c934, 0xbae1c950, 0xbae2c951, 0xbae5c954, 0xbae9c958, 0xbaf1c960, 0xbaf3c961,
+    0xbaf5c963, 0xbb41c96c, 0xbb42c96d, 0xbb45c970, 0xbb49c974, 0xbb51c97c, 0xbb61c988, 0xbb62c989,
+    0xbb65c98c, 0xbb69c990, 0xbb71c998, 0xbb73c999, 0xbb75c99b, 0xbb77c99d, 0xbb81c9a4, 0xbba1c9c0,
+    0xbba2c9c1, 0xbba5c9c4, 0xbba8c9c7, 0xbba9c9c8, 0xbbabc9ca, 0xbbb1c9d0, 0xbbb3c9d1, 0xbbb5c9d3,
+    0xbbb6c9d4, 0xbbb7c9d5, 0xbbb8c9d6, 0xbbbbc9d9, 0xbbbcc9da, 0xbc413149, 0xbc61c9dc, 0xbc62c9dd,
+    0xbc65c9e0, 0xbc67c9e2, 0xbc69c9e4, 0xbc6cc9e7, 0xbc71c9ec, 0xbc73c9ed, 0xbc75c9ef, 0xbc76c9f0,
+    0xbc77c9f1, 0xbc81c9f8, 0xbc82c9f9, 0xbc85c9fc, 0xbc89ca00, 0xbc91ca08, 0xbc93ca09, 0xbc95ca0b,
+    0xbc96ca0c, 0xbc97ca0d, 0xbca1ca14, 0xbca5ca18, 0xbcb7ca29, 0xbcc1ca30, 0xbce1ca4c, 0xbce2ca4d,
+    0xbce5ca50, 0xbce9ca54, 0xbcecca57, 0xbcf1ca5c, 0xbcf3ca5d, 0xbcf5ca5f, 0xbcf6ca60, 0xbcf7ca61,
+    0xbd41ca68, 0xbd49ca70, 0xbd57ca7d, 0xbd61ca84, 0xbd75ca97, 0xbd76ca98, 0xbd81caa0, 0xbda1cabc,
+    0xbda2cabd, 0xbda5cac0, 0xbda9cac4, 0xbdb1cacc, 0xbdb3cacd, 0xbdb5cacf, 0xbdb7cad1, 0xbdb8cad2,
+    0xbdb9cad3, 0xbdc1cad8, 0xbdc2cad9, 0xbdc9cae0, 0xbdd6caec, 0xbde1caf4, 0xbdf6cb08, 0xbe41cb10,
+    0xbe45cb14, 0xbe49cb18, 0xbe51cb20, 0xbe53cb21, 0xbe61cb2c, 0xbe77cb41, 0xbe81cb48, 0xbe82cb49,
+    0xbe85cb4c, 0xbe89cb50, 0xbe91cb58, 0xbe93cb59, 0xbe97cb5d, 0xbea1cb64, 0xbeb6cb78, 0xbeb7cb79,
+    0xbec1cb80, 0xbee1cb9c, 0xbf41cbb8, 0xbf61cbd4, 0xbf71cbe4, 0xbf73cbe5, 0xbf75cbe7, 0xbf77cbe9,
+    0xbf81cbf0, 0xbfa1cc0c, 0xbfa2cc0d, 0xbfa5cc10, 0xbfa9cc14, 0xbfb1cc1c, 0xbfb3cc1d, 0xbfb5cc1f,
+    0xbfb7cc21, 0xbfb8cc22, 0xbfbccc26, 0xbfbdcc27, 0xc041314a, 0xc061cc28, 0xc062cc29, 0xc065cc2c,
+    0xc067cc2e, 0xc068cc2f, 0xc069cc30, 0xc071cc38, 0xc073cc39, 0xc075cc3b, 0xc076cc3c, 0xc077cc3d,
+    0xc078cc3e, 0xc079cc3f, 0xc07ccc42, 0xc081cc44, 0xc082cc45, 0xc085cc48, 0xc089cc4c, 0xc091cc54,
+    0xc093cc55, 0xc095cc57, 0xc096cc58, 0xc097cc59, 0xc0a1cc60, 0xc0a5cc64, 0xc0a7cc66, 0xc0a9cc68,
+    0xc0b1cc70, 0xc0b3cc71, 0xc0b7cc75, 0xc0c1cc7c, 0xc0e1cc98, 0xc0e2cc99, 0xc0e5cc9c, 0xc0e9cca0,
+    0xc0f1cca8, 0xc0f3cca9, 0xc0f5ccab, 0xc0f6ccac, 0xc0f7ccad, 0xc141ccb4, 0xc142ccb5, 0xc145ccb8,
+    0xc149ccbc, 0xc151ccc4, 0xc153ccc5, 0xc155ccc7, 0xc157ccc9, 0xc161ccd0, 0xc165ccd4, 0xc175cce3,
+    0xc176cce4, 0xc177cce5, 0xc181ccec, 0xc185ccf0, 0xc197cd01, 0xc1a1cd08, 0xc1a2cd09, 0xc1a5cd0c,
+    0xc1a9cd10, 0xc1b1cd18, 0xc1b3cd19, 0xc1b5cd1b, 0xc1b7cd1d, 0xc1c1cd24, 0xc1c5cd28, 0xc1c9cd2c,
+    0xc1d7cd39, 0xc1e1cd40, 0xc241cd5c, 0xc245cd60, 0xc249cd64, 0xc251cd6c, 0xc253cd6d, 0xc255cd6f,
+    0xc257cd71, 0xc261cd78, 0xc271cd88, 0xc281cd94, 0xc282cd95, 0xc285cd98, 0xc289cd9c, 0xc291cda4,
+    0xc293cda5, 0xc295cda7, 0xc297cda9, 0xc2a1cdb0, 0xc2b5cdc3, 0xc2b6cdc4, 0xc2c1cdcc, 0xc2c5cdd0,
+    0xc2e1cde8, 0xc2e5cdec, 0xc2e9cdf0, 0xc2f1cdf8, 0xc2f3cdf9, 0xc2f5cdfb, 0xc2f7cdfd, 0xc341ce04,
+    0xc345ce08, 0xc349ce0c, 0xc351ce14, 0xc357ce19, 0xc361ce20, 0xc362ce21, 0xc365ce24, 0xc369ce28,
+    0xc371ce30, 0xc373ce31, 0xc375ce33, 0xc377ce35, 0xc381ce3c, 0xc3a1ce58, 0xc3a2ce59, 0xc3a5ce5c,
+    0xc3a8ce5f, 0xc3a9ce60, 0xc3aace61, 0xc3b1ce68, 0xc3b3ce69, 0xc3b5ce6b, 0xc3b7ce6d, 0xc441314b,
+    0xc461ce74, 0xc462ce75, 0xc465ce78, 0xc468ce7b, 0xc469ce7c, 0xc471ce84, 0xc473ce85, 0xc475ce87,
+    0xc477ce89, 0xc481ce90, 0xc482ce91, 0xc485ce94, 0xc488ce97, 0xc489ce98, 0xc491cea0, 0xc493cea1,
+    0xc495cea3, 0xc496cea4, 0xc497cea5, 0xc49bcea9, 0xc4a1ceac, 0xc4a2cead, 0xc4b7cec1, 0xc4c1cec8,
+    0xc4e1cee4, 0xc4e2cee5, 0xc4e5cee8, 0xc4e8ceeb, 0xc4e9ceec, 0xc4f1cef4, 0xc4f3cef5, 0xc4f5cef7,
+    0xc4f6cef8, 0xc4f7cef9, 0xc4fbcefd, 0xc541cf00, 0xc542cf01, 0xc545cf04, 0xc549cf08, 0xc551cf10,
+    0xc553cf11, 0xc555cf13, 0xc557cf15, 0xc55bcf19, 0xc561cf1c, 0xc565cf20, 0xc569cf24, 0xc571cf2c,
+    0xc573cf2d, 0xc575cf2f, 0xc576cf30, 0xc577cf31, 0xc581cf38, 0xc5a1cf54, 0xc5a2cf55, 0xc5a5cf58,
+    0xc5a9cf5c, 0xc5b1cf64, 0xc5b3cf65, 0xc5b5cf67, 0xc5b7cf69, 0xc5c1cf70, 0xc5c2cf71, 0xc5c5cf74,
+    0xc5c9cf78, 0xc5d1cf80, 0xc5d7cf85, 0xc5e1cf8c, 0xc5e2cf8d, 0xc5e5cf90, 0xc5f5cf9f, 0xc5f7cfa1,
+    0xc641cfa8, 0xc645cfac, 0xc649cfb0, 0xc657cfbd, 0xc661cfc4, 0xc681cfe0, 0xc682cfe1, 0xc685cfe4,
+    0xc689cfe8, 0xc691cff0, 0xc693cff1, 0xc695cff3, 0xc697cff5, 0xc6a1cffc, 0xc6a5d000, 0xc6a9d004,
+    0xc6b7d011, 0xc6c1d018, 0xc6d7d02d, 0xc6e1d034, 0xc6e2d035, 0xc6e5d038, 0xc6e9d03c, 0xc6f1d044,
+    0xc6f3d045, 0xc6f5d047, 0xc6f7d049, 0xc741d050, 0xc745d054, 0xc749d058, 0xc751d060, 0xc761d06c,
+    0xc762d06d, 0xc765d070, 0xc769d074, 0xc771d07c, 0xc773d07d, 0xc777d081, 0xc781d088, 0xc7a1d0a4,
+    0xc7a2d0a5, 0xc7a5d0a8, 0xc7a9d0ac, 0xc7b1d0b4, 0xc7b3d0b5, 0xc7b5d0b7, 0xc7b7d0b9, 0xc841314c,
+    0xc861d0c0, 0xc862d0c1, 0xc865d0c4, 0xc869d0c8, 0xc86ad0c9, 0xc871d0d0, 0xc873d0d1, 0xc875d0d3,
+    0xc876d0d4, 0xc877d0d5, 0xc881d0dc, 0xc882d0dd, 0xc885d0e0, 0xc889d0e4, 0xc891d0ec, 0xc893d0ed,
+    0xc895d0ef, 0xc896d0f0, 0xc897d0f1, 0xc8a1d0f8, 0xc8b7d10d, 0xc8c1d114, 0xc8e1d130, 0xc8e2d131,
+    0xc8e5d134, 0xc8e9d138, 0xc8ebd13a, 0xc8f1d140, 0xc8f3d141, 0xc8f5d143, 0xc8f6d144, 0xc8f7d145,
+    0xc941d14c, 0xc942d14d, 0xc945d150, 0xc949d154, 0xc951d15c, 0xc953d15d, 0xc955d15f, 0xc956d160,
+    0xc957d161, 0xc961d168, 0xc962d169, 0xc965d16c, 0xc976d17c, 0xc981d184, 0xc985d188, 0xc9a1d1a0,
+    0xc9a2d1a1, 0xc9a5d1a4, 0xc9a9d1a8, 0xc9b1d1b0, 0xc9b3d1b1, 0xc9b5d1b3, 0xc9b7d1b5, 0xc9bcd1ba,
+    0xc9c1d1bc, 0xc9c5d1c0, 0xc9e1d1d8, 0xca41d1f4, 0xca45d1f8, 0xca55d207, 0xca57d209, 0xca61d210,
+    0xca81d22c, 0xca82d22d, 0xca85d230, 0xca89d234, 0xca91d23c, 0xca93d23d, 0xca95d23f, 0xca97d241,
+    0xcaa1d248, 0xcab6d25c, 0xcac1d264, 0xcad5d277, 0xcae1d280, 0xcae2d281, 0xcae5d284, 0xcae9d288,
+    0xcaf1d290, 0xcaf3d291, 0xcaf5d293, 0xcaf7d295, 0xcb41d29c, 0xcb45d2a0, 0xcb49d2a4, 0xcb51d2ac,
+    0xcb57d2b1, 0xcb61d2b8, 0xcb62d2b9, 0xcb65d2bc, 0xcb68d2bf, 0xcb69d2c0, 0xcb6bd2c2, 0xcb71d2c8,
+    0xcb73d2c9, 0xcb75d2cb, 0xcb77d2cd, 0xcb81d2d4, 0xcb85d2d8, 0xcb89d2dc, 0xcb91d2e4, 0xcb93d2e5,
+    0xcb95d2e7, 0xcba1d2f0, 0xcba2d2f1, 0xcba5d2f4, 0xcba9d2f8, 0xcbb1d300, 0xcbb3d301, 0xcbb5d303,
+    0xcbb7d305, 0xcbbcd30a, 0xcc41314d, 0xcc61d30c, 0xcc62d30d, 0xcc63d30e, 0xcc65d310, 0xcc69d314,
+    0xcc6bd316, 0xcc71d31c, 0xcc73d31d, 0xcc75d31f, 0xcc76d320, 0xcc77d321, 0xcc7bd325, 0xcc7cd326,
+    0xcc81d328, 0xcc82d329, 0xcc85d32c, 0xcc89d330, 0xcc91d338, 0xcc93d339, 0xcc95d33b, 0xcc96d33c,
+    0xcc97d33d, 0xcca1d344, 0xcca2d345, 0xccb7d359, 0xccc1d360, 0xcce1d37c, 0xcce2d37d, 0xcce5d380,
+    0xcce9d384, 0xccf1d38c, 0xccf3d38d, 0xccf5d38f, 0xccf6d390, 0xccf7d391, 0xcd41d398, 0xcd42d399,
+    0xcd45d39c, 0xcd49d3a0, 0xcd51d3a8, 0xcd53d3a9, 0xcd55d3ab, 0xcd57d3ad, 0xcd5cd3b2, 0xcd61d3b4,
+    0xcd62d3b5, 0xcd65d3b8, 0xcd69d3bc, 0xcd71d3c4, 0xcd73d3c5, 0xcd75d3c7, 0xcd76d3c8, 0xcd77d3c9,
+    0xcd81d3d0, 0xcd89d3d8, 0xcd93d3e1, 0xcd95d3e3, 0xcda1d3ec, 0xcda2d3ed, 0xcda5d3f0, 0xcda9d3f4,
+    0xcdb1d3fc, 0xcdb3d3fd, 0xcdb5d3ff, 0xcdb7d401, 0xcdc1d408, 0xcdd7d41d, 0xcde1d424, 0xce41d440,
+    0xce45d444, 0xce61d45c, 0xce65d460, 0xce69d464, 0xce73d46d, 0xce75d46f, 0xce81d478, 0xce82d479,
+    0xce85d47c, 0xce88d47f, 0xce89d480, 0xce8bd482, 0xce91d488, 0xce93d489, 0xce95d48b, 0xce97d48d,
+    0xcea1d494, 0xceb7d4a9, 0xcec1d4b0, 0xcee1d4cc, 0xcee5d4d0, 0xcee9d4d4, 0xcef1d4dc, 0xcef5d4df,
+    0xcf41d4e8, 0xcf42d4e9, 0xcf45d4ec, 0xcf49d4f0, 0xcf51d4f8, 0xcf55d4fb, 0xcf57d4fd, 0xcf61d504,
+    0xcf62d505, 0xcf65d508, 0xcf69d50c, 0xcf6dd510, 0xcf71d514, 0xcf73d515, 0xcf75d517, 0xcf77d519,
+    0xcf81d520, 0xcf85d524, 0xcfa1d53c, 0xcfa2d53d, 0xcfa5d540, 0xcfa9d544, 0xcfb1d54c, 0xcfb3d54d,
+    0xcfb5d54f, 0xcfb7d551, 0xd041314e, 0xd061d558, 0xd062d559, 0xd065d55c, 0xd068d55f, 0xd069d560,
+    0xd06ad561, 0xd06ed565, 0xd071d568, 0xd073d569, 0xd075d56b, 0xd076d56c, 0xd077d56d, 0xd07bd571,
+    0xd081d574, 0xd082d575, 0xd085d578, 0xd089d57c, 0xd091d584, 0xd093d585, 0xd095d587, 0xd096d588,
+    0xd097d589, 0xd0a1d590, 0xd0b7d5a5, 0xd0c1d5ac, 0xd0e1d5c8, 0xd0e2d5c9, 0xd0e5d5cc, 0xd0e8d5cf,
+    0xd0e9d5d0, 0xd0ebd5d2, 0xd0f1d5d8, 0xd0f3d5d9, 0xd0f5d5db, 0xd0f7d5dd, 0xd141d5e4, 0xd142d5e5,
+    0xd145d5e8, 0xd149d5ec, 0xd151d5f4, 0xd153d5f5, 0xd155d5f7, 0xd157d5f9, 0xd161d600, 0xd162d601,
+    0xd165d604, 0xd169d608, 0xd171d610, 0xd173d611, 0xd175d613, 0xd176d614, 0xd177d615, 0xd181d61c,
+    0xd185d620, 0xd189d624, 0xd191d62c, 0xd193d62d, 0xd1a1d638, 0xd1a2d639, 0xd1a5d63c, 0xd1a9d640,
+    0xd1aed645, 0xd1b1d648, 0xd1b3d649, 0xd1b5d64b, 0xd1b7d64d, 0xd1bbd651, 0xd1c1d654, 0xd1c2d655,
+    0xd1c5d658, 0xd1c9d65c, 0xd1d5d667, 0xd1d7d669, 0xd1e1d670, 0xd1e2d671, 0xd1e5d674, 0xd1f5d683,
+    0xd1f7d685, 0xd241d68c, 0xd242d68d, 0xd245d690, 0xd249d694, 0xd253d69d, 0xd255d69f, 0xd257d6a1,
+    0xd261d6a8, 0xd262d6a9, 0xd265d6ac, 0xd269d6b0, 0xd271d6b8, 0xd273d6b9, 0xd275d6bb, 0xd281d6c4,
+    0xd282d6c5, 0xd285d6c8, 0xd289d6cc, 0xd28ed6d1, 0xd291d6d4, 0xd293d6d5, 0xd295d6d7, 0xd297d6d9,
+    0xd2a1d6e0, 0xd2a5d6e4, 0xd2a9d6e8, 0xd2b1d6f0, 0xd2b7d6f5, 0xd2c1d6fc, 0xd2c2d6fd, 0xd2c5d700,
+    0xd2c9d704, 0xd2d1d70c, 0xd2d7d711, 0xd2e1d718, 0xd2e2d719, 0xd2e5d71c, 0xd2e9d720, 0xd2f1d728,
+    0xd2f3d729, 0xd2f5d72b, 0xd2f7d72d, 0xd341d734, 0xd342d735, 0xd345d738, 0xd349d73c, 0xd351d744,
+    0xd355d747, 0xd357d749, 0xd361d750, 0xd362d751, 0xd365d754, 0xd367d756, 0xd368d757, 0xd369d758,
+    0xd36ad759, 0xd371d760, 0xd373d761, 0xd375d763, 0xd377d765, 0xd37bd769, 0xd381d76c, 0xd382d76d,
+    0xd385d770, 0xd389d774, 0xd391d77c, 0xd393d77d, 0xd397d781, 0xd3a1d788, 0xd3a2d789, 0xd3a5d78c,
+    0xd3a9d790, 0xd3b1d798, 0xd3b3d799, 0xd3b5d79b, 0xd3b7d79d, 0xd441e000, 0xd442e001, 0xd443e002,
+    0xd444e003, 0xd445e004, 0xd446e005, 0xd447e006, 0xd448e007, 0xd449e008, 0xd44ae009, 0xd44be00a,
+    0xd44ce00b, 0xd44de00c, 0xd44ee00d, 0xd44fe00e, 0xd450e00f, 0xd451e010, 0xd452e011, 0xd453e012,
+    0xd454e013, 0xd455e014, 0xd456e015, 0xd457e016, 0xd458e017, 0xd459e018, 0xd45ae019, 0xd45be01a,
+    0xd45ce01b, 0xd45de01c, 0xd45ee01d, 0xd45fe01e, 0xd460e01f, 0xd461e020, 0xd462e021, 0xd463e022,
+    0xd464e023, 0xd465e024, 0xd466e025, 0xd467e026, 0xd468e027, 0xd469e028, 0xd46ae029, 0xd46be02a,
+    0xd46ce02b, 0xd46de02c, 0xd46ee02d, 0xd46fe02e, 0xd470e02f, 0xd471e030, 0xd472e031, 0xd473e032,
+    0xd474e033, 0xd475e034, 0xd476e035, 0xd477e036, 0xd478e037, 0xd479e038, 0xd47ae039, 0xd47be03a,
+    0xd47ce03b, 0xd47de03c, 0xd47ee03d, 0xd47fe03e, 0xd481e03f, 0xd482e040, 0xd483e041, 0xd484e042,
+    0xd485e043, 0xd486e044, 0xd487e045, 0xd488e046, 0xd489e047, 0xd48ae048, 0xd48be049, 0xd48ce04a,
+    0xd48de04b, 0xd48ee04c, 0xd48fe04d, 0xd490e04e, 0xd491e04f, 0xd492e050, 0xd493e051, 0xd494e052,
+    0xd495e053, 0xd496e054, 0xd497e055, 0xd498e056, 0xd499e057, 0xd49ae058, 0xd49be059, 0xd49ce05a,
+    0xd49de05b, 0xd49ee05c, 0xd49fe05d, 0xd4a0e05e, 0xd4a1e05f, 0xd4a2e060, 0xd4a3e061, 0xd4a4e062,
+    0xd4a5e063, 0xd4a6e064, 0xd4a7e065, 0xd4a8e066, 0xd4a9e067, 0xd4aae068, 0xd4abe069, 0xd4ace06a,
+    0xd4ade06b, 0xd4aee06c, 0xd4afe06d, 0xd4b0e06e, 0xd4b1e06f, 0xd4b2e070, 0xd4b3e071, 0xd4b4e072,
+    0xd4b5e073, 0xd4b6e074, 0xd4b7e075, 0xd4b8e076, 0xd4b9e077, 0xd4bae078, 0xd4bbe079, 0xd4bce07a,
+    0xd4bde07b, 0xd4bee07c, 0xd4bfe07d, 0xd4c0e07e, 0xd4c1e07f, 0xd4c2e080, 0xd4c3e081, 0xd4c4e082,
+    0xd4c5e083, 0xd4c6e084, 0xd4c7e085, 0xd4c8e086, 0xd4c9e087, 0xd4cae088, 0xd4cbe089, 0xd4cce08a,
+    0xd4cde08b, 0xd4cee08c, 0xd4cfe08d, 0xd4d0e08e, 0xd4d1e08f, 0xd4d2e090, 0xd4d3e091, 0xd4d4e092,
+    0xd4d5e093, 0xd4d6e094, 0xd4d7e095, 0xd4d8e096, 0xd4d9e097, 0xd4dae098, 0xd4dbe099, 0xd4dce09a,
+    0xd4dde09b, 0xd4dee09c, 0xd4dfe09d, 0xd4e0e09e, 0xd4e1e09f, 0xd4e2e0a0, 0xd4e3e0a1, 0xd4e4e0a2,
+    0xd4e5e0a3, 0xd4e6e0a4, 0xd4e7e0a5, 0xd4e8e0a6, 0xd4e9e0a7, 0xd4eae0a8, 0xd4ebe0a9, 0xd4ece0aa,
+    0xd4ede0ab, 0xd4eee0ac, 0xd4efe0ad, 0xd4f0e0ae, 0xd4f1e0af, 0xd4f2e0b0, 0xd4f3e0b1, 0xd4f4e0b2,
+    0xd4f5e0b3, 0xd4f6e0b4, 0xd4f7e0b5, 0xd4f8e0b6, 0xd4f9e0b7, 0xd4fae0b8, 0xd4fbe0b9, 0xd4fce0ba,
+    0xd4fde0bb, 0xd541e0bc, 0xd542e0bd, 0xd543e0be, 0xd544e0bf, 0xd545e0c0, 0xd546e0c1, 0xd547e0c2,
+    0xd548e0c3, 0xd549e0c4, 0xd54ae0c5, 0xd54be0c6, 0xd54ce0c7, 0xd54de0c8, 0xd54ee0c9, 0xd54fe0ca,
+    0xd550e0cb, 0xd551e0cc, 0xd552e0cd, 0xd553e0ce, 0xd554e0cf, 0xd555e0d0, 0xd556e0d1, 0xd557e0d2,
+    0xd558e0d3, 0xd559e0d4, 0xd55ae0d5, 0xd55be0d6, 0xd55ce0d7, 0xd55de0d8, 0xd55ee0d9, 0xd55fe0da,
+    0xd560e0db, 0xd561e0dc, 0xd562e0dd, 0xd563e0de, 0xd564e0df, 0xd565e0e0, 0xd566e0e1, 0xd567e0e2,
+    0xd568e0e3, 0xd569e0e4, 0xd56ae0e5, 0xd56be0e6, 0xd56ce0e7, 0xd56de0e8, 0xd56ee0e9, 0xd56fe0ea,
+    0xd570e0eb, 0xd571e0ec, 0xd572e0ed, 0xd573e0ee, 0xd574e0ef, 0xd575e0f0, 0xd576e0f1, 0xd577e0f2,
+    0xd578e0f3, 0xd579e0f4, 0xd57ae0f5, 0xd57be0f6, 0xd57ce0f7, 0xd57de0f8, 0xd57ee0f9, 0xd57fe0fa,
+    0xd581e0fb, 0xd582e0fc, 0xd583e0fd, 0xd584e0fe, 0xd585e0ff, 0xd586e100, 0xd587e101, 0xd588e102,
+    0xd589e103, 0xd58ae104, 0xd58be105, 0xd58ce106, 0xd58de107, 0xd58ee108, 0xd58fe109, 0xd590e10a,
+    0xd591e10b, 0xd592e10c, 0xd593e10d, 0xd594e10e, 0xd595e10f, 0xd596e110, 0xd597e111, 0xd598e112,
+    0xd599e113, 0xd59ae114, 0xd59be115, 0xd59ce116, 0xd59de117, 0xd59ee118, 0xd59fe119, 0xd5a0e11a,
+    0xd5a1e11b, 0xd5a2e11c, 0xd5a3e11d, 0xd5a4e11e, 0xd5a5e11f, 0xd5a6e120, 0xd5a7e121, 0xd5a8e122,
+    0xd5a9e123, 0xd5aae124, 0xd5abe125, 0xd5ace126, 0xd5ade127, 0xd5aee128, 0xd5afe129, 0xd5b0e12a,
+    0xd5b1e12b, 0xd5b2e12c, 0xd5b3e12d, 0xd5b4e12e, 0xd5b5e12f, 0xd5b6e130, 0xd5b7e131, 0xd5b8e132,
+    0xd5b9e133, 0xd5bae134, 0xd5bbe135, 0xd5bce136, 0xd5bde137, 0xd5bee138, 0xd5bfe139, 0xd5c0e13a,
+    0xd5c1e13b, 0xd5c2e13c, 0xd5c3e13d, 0xd5c4e13e, 0xd5c5e13f, 0xd5c6e140, 0xd5c7e141, 0xd5c8e142,
+    0xd5c9e143, 0xd5cae144, 0xd5cbe145, 0xd5cce146, 0xd5cde147, 0xd5cee148, 0xd5cfe149, 0xd5d0e14a,
+    0xd5d1e14b, 0xd5d2e14c, 0xd5d3e14d, 0xd5d4e14e, 0xd5d5e14f, 0xd5d6e150, 0xd5d7e151, 0xd5d8e152,
+    0xd5d9e153, 0xd5dae154, 0xd5dbe155, 0xd5dce156, 0xd5dde157, 0xd5dee158, 0xd5dfe159, 0xd5e0e15a,
+    0xd5e1e15b, 0xd5e2e15c, 0xd5e3e15d, 0xd5e4e15e, 0xd5e5e15f, 0xd5e6e160, 0xd5e7e161, 0xd5e8e162,
+    0xd5e9e163, 0xd5eae164, 0xd5ebe165, 0xd5ece166, 0xd5ede167, 0xd5eee168, 0xd5efe169, 0xd5f0e16a,
+    0xd5f1e16b, 0xd5f2e16c, 0xd5f3e16d, 0xd5f4e16e, 0xd5f5e16f, 0xd5f6e170, 0xd5f7e171, 0xd5f8e172,
+    0xd5f9e173, 0xd5fae174, 0xd5fbe175, 0xd5fce176, 0xd5fde177, 0xd641e178, 0xd642e179, 0xd643e17a,
+    0xd644e17b, 0xd645e17c, 0xd646e17d, 0xd647e17e, 0xd648e17f, 0xd649e180, 0xd64ae181, 0xd64be182,
+    0xd64ce183, 0xd64de184, 0xd64ee185, 0xd64fe186, 0xd650e187, 0xd651e188, 0xd652e189, 0xd653e18a,
+    0xd654e18b, 0xd655e18c, 0xd656e18d, 0xd657e18e, 0xd658e18f, 0xd659e190, 0xd65ae191, 0xd65be192,
+    0xd65ce193, 0xd65de194, 0xd65ee195, 0xd65fe196, 0xd660e197, 0xd661e198, 0xd662e199, 0xd663e19a,
+    0xd664e19b, 0xd665e19c, 0xd666e19d, 0xd667e19e, 0xd668e19f, 0xd669e1a0, 0xd66ae1a1, 0xd66be1a2,
+    0xd66ce1a3, 0xd66de1a4, 0xd66ee1a5, 0xd66fe1a6, 0xd670e1a7, 0xd671e1a8, 0xd672e1a9, 0xd673e1aa,
+    0xd674e1ab, 0xd675e1ac, 0xd676e1ad, 0xd677e1ae, 0xd678e1af, 0xd679e1b0, 0xd67ae1b1, 0xd67be1b2,
+    0xd67ce1b3, 0xd67de1b4, 0xd67ee1b5, 0xd67fe1b6, 0xd681e1b7, 0xd682e1b8, 0xd683e1b9, 0xd684e1ba,
+    0xd685e1bb, 0xd686e1bc, 0xd687e1bd, 0xd688e1be, 0xd689e1bf, 0xd68ae1c0, 0xd68be1c1, 0xd68ce1c2,
+    0xd68de1c3, 0xd68ee1c4, 0xd68fe1c5, 0xd690e1c6, 0xd691e1c7, 0xd692e1c8, 0xd693e1c9, 0xd694e1ca,
+    0xd695e1cb, 0xd696e1cc, 0xd697e1cd, 0xd698e1ce, 0xd699e1cf, 0xd69ae1d0, 0xd69be1d1, 0xd69ce1d2,
+    0xd69de1d3, 0xd69ee1d4, 0xd69fe1d5, 0xd6a0e1d6, 0xd6a1e1d7, 0xd6a2e1d8, 0xd6a3e1d9, 0xd6a4e1da,
+    0xd6a5e1db, 0xd6a6e1dc, 0xd6a7e1dd, 0xd6a8e1de, 0xd6a9e1df, 0xd6aae1e0, 0xd6abe1e1, 0xd6ace1e2,
+    0xd6ade1e3, 0xd6aee1e4, 0xd6afe1e5, 0xd6b0e1e6, 0xd6b1e1e7, 0xd6b2e1e8, 0xd6b3e1e9, 0xd6b4e1ea,
+    0xd6b5e1eb, 0xd6b6e1ec, 0xd6b7e1ed, 0xd6b8e1ee, 0xd6b9e1ef, 0xd6bae1f0, 0xd6bbe1f1, 0xd6bce1f2,
+    0xd6bde1f3, 0xd6bee1f4, 0xd6bfe1f5, 0xd6c0e1f6, 0xd6c1e1f7, 0xd6c2e1f8, 0xd6c3e1f9, 0xd6c4e1fa,
+    0xd6c5e1fb, 0xd6c6e1fc, 0xd6c7e1fd, 0xd6c8e1fe, 0xd6c9e1ff, 0xd6cae200, 0xd6cbe201, 0xd6cce202,
+    0xd6cde203, 0xd6cee204, 0xd6cfe205, 0xd6d0e206, 0xd6d1e207, 0xd6d2e208, 0xd6d3e209, 0xd6d4e20a,
+    0xd6d5e20b, 0xd6d6e20c, 0xd6d7e20d, 0xd6d8e20e, 0xd6d9e20f, 0xd6dae210, 0xd6dbe211, 0xd6dce212,
+    0xd6dde213, 0xd6dee214, 0xd6dfe215, 0xd6e0e216, 0xd6e1e217, 0xd6e2e218, 0xd6e3e219, 0xd6e4e21a,
+    0xd6e5e21b, 0xd6e6e21c, 0xd6e7e21d, 0xd6e8e21e, 0xd6e9e21f, 0xd6eae220, 0xd6ebe221, 0xd6ece222,
+    0xd6ede223, 0xd6eee224, 0xd6efe225, 0xd6f0e226, 0xd6f1e227, 0xd6f2e228, 0xd6f3e229, 0xd6f4e22a,
+    0xd6f5e22b, 0xd6f6e22c, 0xd6f7e22d, 0xd6f8e22e, 0xd6f9e22f, 0xd6fae230, 0xd6fbe231, 0xd6fce232,
+    0xd6fde233, 0xd741e234, 0xd742e235, 0xd743e236, 0xd744e237, 0xd745e238, 0xd746e239, 0xd747e23a,
+    0xd748e23b, 0xd749e23c, 0xd74ae23d, 0xd74be23e, 0xd74ce23f, 0xd74de240, 0xd74ee241, 0xd74fe242,
+    0xd750e243, 0xd751e244, 0xd752e245, 0xd753e246, 0xd754e247, 0xd755e248, 0xd756e249, 0xd757e24a,
+    0xd758e24b, 0xd759e24c, 0xd75ae24d, 0xd75be24e, 0xd75ce24f, 0xd75de250, 0xd75ee251, 0xd75fe252,
+    0xd760e253, 0xd761e254, 0xd762e255, 0xd763e256, 0xd764e257, 0xd765e258, 0xd766e259, 0xd767e25a,
+    0xd768e25b, 0xd769e25c, 0xd76ae25d, 0xd76be25e, 0xd76ce25f, 0xd76de260, 0xd76ee261, 0xd76fe262,
+    0xd770e263, 0xd771e264, 0xd772e265, 0xd773e266, 0xd774e267, 0xd775e268, 0xd776e269, 0xd777e26a,
+    0xd778e26b, 0xd779e26c, 0xd77ae26d, 0xd77be26e, 0xd77ce26f, 0xd77de270, 0xd77ee271, 0xd77fe272,
+    0xd781e273, 0xd782e274, 0xd783e275, 0xd784e276, 0xd785e277, 0xd786e278, 0xd787e279, 0xd788e27a,
+    0xd789e27b, 0xd78ae27c, 0xd78be27d, 0xd78ce27e, 0xd78de27f, 0xd78ee280, 0xd78fe281, 0xd790e282,
+    0xd791e283, 0xd792e284, 0xd793e285, 0xd794e286, 0xd795e287, 0xd796e288, 0xd797e289, 0xd798e28a,
+    0xd799e28b, 0xd79ae28c, 0xd79be28d, 0xd79ce28e, 0xd79de28f, 0xd79ee290, 0xd79fe291, 0xd7a0e292,
+    0xd7a1e293, 0xd7a2e294, 0xd7a3e295, 0xd7a4e296, 0xd7a5e297, 0xd7a6e298, 0xd7a7e299, 0xd7a8e29a,
+    0xd7a9e29b, 0xd7aae29c, 0xd7abe29d, 0xd7ace29e, 0xd7ade29f, 0xd7aee2a0, 0xd7afe2a1, 0xd7b0e2a2,
+    0xd7b1e2a3, 0xd7b2e2a4, 0xd7b3e2a5, 0xd7b4e2a6, 0xd7b5e2a7, 0xd7b6e2a8, 0xd7b7e2a9, 0xd7b8e2aa,
+    0xd7b9e2ab, 0xd7bae2ac, 0xd7bbe2ad, 0xd7bce2ae, 0xd7bde2af, 0xd7bee2b0, 0xd7bfe2b1, 0xd7c0e2b2,
+    0xd7c1e2b3, 0xd7c2e2b4, 0xd7c3e2b5, 0xd7c4e2b6, 0xd7c5e2b7, 0xd7c6e2b8, 0xd7c7e2b9, 0xd7c8e2ba,
+    0xd7c9e2bb, 0xd7cae2bc, 0xd7cbe2bd, 0xd7cce2be, 0xd7cde2bf, 0xd7cee2c0, 0xd7cfe2c1, 0xd7d0e2c2,
+    0xd7d1e2c3, 0xd7d2e2c4, 0xd7d3e2c5, 0xd7d4e2c6, 0xd7d5e2c7, 0xd7d6e2c8, 0xd7d7e2c9, 0xd7d8e2ca,
+    0xd7d9e2cb, 0xd7dae2cc, 0xd7dbe2cd, 0xd7dce2ce, 0xd7dde2cf, 0xd7dee2d0, 0xd7dfe2d1, 0xd7e0e2d2,
+    0xd7e1e2d3, 0xd7e2e2d4, 0xd7e3e2d5, 0xd7e4e2d6, 0xd7e5e2d7, 0xd7e6e2d8, 0xd7e7e2d9, 0xd7e8e2da,
+    0xd7e9e2db, 0xd7eae2dc, 0xd7ebe2dd, 0xd7ece2de, 0xd7ede2df, 0xd7eee2e0, 0xd7efe2e1, 0xd7f0e2e2,
+    0xd7f1e2e3, 0xd7f2e2e4, 0xd7f3e2e5, 0xd7f4e2e6, 0xd7f5e2e7, 0xd7f6e2e8, 0xd7f7e2e9, 0xd7f8e2ea,
+    0xd7f9e2eb, 0xd7fae2ec, 0xd7fbe2ed, 0xd7fce2ee, 0xd7fde2ef, 0xd841e2f0, 0xd842e2f1, 0xd843e2f2,
+    0xd844e2f3, 0xd845e2f4, 0xd846e2f5, 0xd847e2f6, 0xd848e2f7, 0xd849e2f8, 0xd84ae2f9, 0xd84be2fa,
+    0xd84ce2fb, 0xd84de2fc, 0xd84ee2fd, 0xd84fe2fe, 0xd850e2ff, 0xd851e300, 0xd852e301, 0xd853e302,
+    0xd854e303, 0xd855e304, 0xd856e305, 0xd857e306, 0xd858e307, 0xd859e308, 0xd85ae309, 0xd85be30a,
+    0xd85ce30b, 0xd85de30c, 0xd85ee30d, 0xd85fe30e, 0xd860e30f, 0xd861e310, 0xd862e311, 0xd863e312,
+    0xd864e313, 0xd865e314, 0xd866e315, 0xd867e316, 0xd868e317, 0xd869e318, 0xd86ae319, 0xd86be31a,
+    0xd86ce31b, 0xd86de31c, 0xd86ee31d, 0xd86fe31e, 0xd870e31f, 0xd871e320, 0xd872e321, 0xd873e322,
+    0xd874e323, 0xd875e324, 0xd876e325, 0xd877e326, 0xd878e327, 0xd879e328, 0xd87ae329, 0xd87be32a,
+    0xd87ce32b, 0xd87de32c, 0xd87ee32d, 0xd87fe32e, 0xd881e32f, 0xd882e330, 0xd883e331, 0xd884e332,
+    0xd885e333, 0xd886e334, 0xd887e335, 0xd888e336, 0xd889e337, 0xd88ae338, 0xd88be339, 0xd88ce33a,
+    0xd88de33b, 0xd88ee33c, 0xd88fe33d, 0xd890e33e, 0xd891e33f, 0xd892e340, 0xd893e341, 0xd894e342,
+    0xd895e343, 0xd896e344, 0xd897e345, 0xd898e346, 0xd899e347, 0xd89ae348, 0xd89be349, 0xd89ce34a,
+    0xd89de34b, 0xd89ee34c, 0xd89fe34d, 0xd8a0e34e, 0xd8a1e34f, 0xd8a2e350, 0xd8a3e351, 0xd8a4e352,
+    0xd8a5e353, 0xd8a6e354, 0xd8a7e355, 0xd8a8e356, 0xd8a9e357, 0xd8aae358, 0xd8abe359, 0xd8ace35a,
+    0xd8ade35b, 0xd8aee35c, 0xd8afe35d, 0xd8b0e35e, 0xd8b1e35f, 0xd8b2e360, 0xd8b3e361, 0xd8b4e362,
+    0xd8b5e363, 0xd8b6e364, 0xd8b7e365, 0xd8b8e366, 0xd8b9e367, 0xd8bae368, 0xd8bbe369, 0xd8bce36a,
+    0xd8bde36b, 0xd8bee36c, 0xd8bfe36d, 0xd8c0e36e, 0xd8c1e36f, 0xd8c2e370, 0xd8c3e371, 0xd8c4e372,
+    0xd8c5e373, 0xd8c6e374, 0xd8c7e375, 0xd8c8e376, 0xd8c9e377, 0xd8cae378, 0xd8cbe379, 0xd8cce37a,
+    0xd8cde37b, 0xd8cee37c, 0xd8cfe37d, 0xd8d0e37e, 0xd8d1e37f, 0xd8d2e380, 0xd8d3e381, 0xd8d4e382,
+    0xd8d5e383, 0xd8d6e384, 0xd8d7e385, 0xd8d8e386, 0xd8d9e387, 0xd8dae388, 0xd8dbe389, 0xd8dce38a,
+    0xd8dde38b, 0xd8dee38c, 0xd8dfe38d, 0xd8e0e38e, 0xd8e1e38f, 0xd8e2e390, 0xd8e3e391, 0xd8e4e392,
+    0xd8e5e393, 0xd8e6e394, 0xd8e7e395, 0xd8e8e396, 0xd8e9e397, 0xd8eae398, 0xd8ebe399, 0xd8ece39a,
+    0xd8ede39b, 0xd8eee39c, 0xd8efe39d, 0xd8f0e39e, 0xd8f1e39f, 0xd8f2e3a0, 0xd8f3e3a1, 0xd8f4e3a2,
+    0xd8f5e3a3, 0xd8f6e3a4, 0xd8f7e3a5, 0xd8f8e3a6, 0xd8f9e3a7, 0xd8fae3a8, 0xd8fbe3a9, 0xd8fce3aa,
+    0xd8fde3ab, 0xd941e3ac, 0xd942e3ad, 0xd943e3ae, 0xd944e3af, 0xd945e3b0, 0xd946e3b1, 0xd947e3b2,
+    0xd948e3b3, 0xd949e3b4, 0xd94ae3b5, 0xd94be3b6, 0xd94ce3b7, 0xd94de3b8, 0xd94ee3b9, 0xd94fe3ba,
+    0xd950e3bb, 0xd951e3bc, 0xd952e3bd, 0xd953e3be, 0xd954e3bf, 0xd955e3c0, 0xd956e3c1, 0xd957e3c2,
+    0xd958e3c3, 0xd959e3c4, 0xd95ae3c5, 0xd95be3c6, 0xd95ce3c7, 0xd95de3c8, 0xd95ee3c9, 0xd95fe3ca,
+    0xd960e3cb, 0xd961e3cc, 0xd962e3cd, 0xd963e3ce, 0xd964e3cf, 0xd965e3d0, 0xd966e3d1, 0xd967e3d2,
+    0xd968e3d3, 0xd969e3d4, 0xd96ae3d5, 0xd96be3d6, 0xd96ce3d7, 0xd96de3d8, 0xd96ee3d9, 0xd96fe3da,
+    0xd970e3db, 0xd971e3dc, 0xd972e3dd, 0xd973e3de, 0xd974e3df, 0xd975e3e0, 0xd976e3e1, 0xd977e3e2,
+    0xd978e3e3, 0xd979e3e4, 0xd97ae3e5, 0xd97be3e6, 0xd97ce3e7, 0xd97de3e8, 0xd97ee3e9, 0xd97fe3ea,
+    0xd981e3eb, 0xd982e3ec, 0xd983e3ed, 0xd984e3ee, 0xd985e3ef, 0xd986e3f0, 0xd987e3f1, 0xd988e3f2,
+    0xd989e3f3, 0xd98ae3f4, 0xd98be3f5, 0xd98ce3f6, 0xd98de3f7, 0xd98ee3f8, 0xd98fe3f9, 0xd990e3fa,
+    0xd991e3fb, 0xd992e3fc, 0xd993e3fd, 0xd994e3fe, 0xd995e3ff, 0xd996e400, 0xd997e401, 0xd998e402,
+    0xd999e403, 0xd99ae404, 0xd99be405, 0xd99ce406, 0xd99de407, 0xd99ee408, 0xd99fe409, 0xd9a0e40a,
+    0xd9a1e40b, 0xd9a2e40c, 0xd9a3e40d, 0xd9a4e40e, 0xd9a5e40f, 0xd9a6e410, 0xd9a7e411, 0xd9a8e412,
+    0xd9a9e413, 0xd9aae414, 0xd9abe415, 0xd9ace416, 0xd9ade417, 0xd9aee418, 0xd9afe419, 0xd9b0e41a,
+    0xd9b1e41b, 0xd9b2e41c, 0xd9b3e41d, 0xd9b4e41e, 0xd9b5e41f, 0xd9b6e420, 0xd9b7e421, 0xd9b8e422,
+    0xd9b9e423, 0xd9bae424, 0xd9bbe425, 0xd9bce426, 0xd9bde427, 0xd9bee428, 0xd9bfe429, 0xd9c0e42a,
+    0xd9c1e42b, 0xd9c2e42c, 0xd9c3e42d, 0xd9c4e42e, 0xd9c5e42f, 0xd9c6e430, 0xd9c7e431, 0xd9c8e432,
+    0xd9c9e433, 0xd9cae434, 0xd9cbe435, 0xd9cce436, 0xd9cde437, 0xd9cee438, 0xd9cfe439, 0xd9d0e43a,
+    0xd9d1e43b, 0xd9d2e43c, 0xd9d3e43d, 0xd9d4e43e, 0xd9d5e43f, 0xd9d6e440, 0xd9d7e441, 0xd9d8e442,
+    0xd9d9e443, 0xd9dae444, 0xd9dbe445, 0xd9dce446, 0xd9dde447, 0xd9dee448, 0xd9dfe449, 0xd9e0e44a,
+    0xd9e1e44b, 0xd9e2e44c, 0xd9e3e44d, 0xd9e4e44e, 0xd9e5e44f, 0xd9e6e450, 0xd9e7e451, 0xd9e8e452,
+    0xd9e9e453, 0xd9eae454, 0xd9ebe455, 0xd9ece456, 0xd9ede457, 0xd9eee458, 0xd9efe459, 0xd9f0e45a,
+    0xd9f1e45b, 0xd9f2e45c, 0xd9f3e45d, 0xd9f4e45e, 0xd9f5e45f, 0xd9f6e460, 0xd9f7e461, 0xd9f8e462,
+    0xd9f9e463, 0xd9fae464, 0xd9fbe465, 0xd9fce466, 0xd9fde467, 0xda41e468, 0xda42e469, 0xda43e46a,
+    0xda44e46b, 0xda45e46c, 0xda46e46d, 0xda47e46e, 0xda48e46f, 0xda49e470, 0xda4ae471, 0xda4be472,
+    0xda4ce473, 0xda4de474, 0xda4ee475, 0xda4fe476, 0xda50e477, 0xda51e478, 0xda52e479, 0xda53e47a,
+    0xda54e47b, 0xda55e47c, 0xda56e47d, 0xda57e47e, 0xda58e47f, 0xda59e480, 0xda5ae481, 0xda5be482,
+    0xda5ce483, 0xda5de484, 0xda5ee485, 0xda5fe486, 0xda60e487, 0xda61e488, 0xda62e489, 0xda63e48a,
+    0xda64e48b, 0xda65e48c, 0xda66e48d, 0xda67e48e, 0xda68e48f, 0xda69e490, 0xda6ae491, 0xda6be492,
+    0xda6ce493, 0xda6de494, 0xda6ee495, 0xda6fe496, 0xda70e497, 0xda71e498, 0xda72e499, 0xda73e49a,
+    0xda74e49b, 0xda75e49c, 0xda76e49d, 0xda77e49e, 0xda78e49f, 0xda79e4a0, 0xda7ae4a1, 0xda7be4a2,
+    0xda7ce4a3, 0xda7de4a4, 0xda7ee4a5, 0xda7fe4a6, 0xda81e4a7, 0xda82e4a8, 0xda83e4a9, 0xda84e4aa,
+    0xda85e4ab, 0xda86e4ac, 0xda87e4ad, 0xda88e4ae, 0xda89e4af, 0xda8ae4b0, 0xda8be4b1, 0xda8ce4b2,
+    0xda8de4b3, 0xda8ee4b4, 0xda8fe4b5, 0xda90e4b6, 0xda91e4b7, 0xda92e4b8, 0xda93e4b9, 0xda94e4ba,
+    0xda95e4bb, 0xda96e4bc, 0xda97e4bd, 0xda98e4be, 0xda99e4bf, 0xda9ae4c0, 0xda9be4c1, 0xda9ce4c2,
+    0xda9de4c3, 0xda9ee4c4, 0xda9fe4c5, 0xdaa0e4c6, 0xdaa1e4c7, 0xdaa2e4c8, 0xdaa3e4c9, 0xdaa4e4ca,
+    0xdaa5e4cb, 0xdaa6e4cc, 0xdaa7e4cd, 0xdaa8e4ce, 0xdaa9e4cf, 0xdaaae4d0, 0xdaabe4d1, 0xdaace4d2,
+    0xdaade4d3, 0xdaaee4d4, 0xdaafe4d5, 0xdab0e4d6, 0xdab1e4d7, 0xdab2e4d8, 0xdab3e4d9, 0xdab4e4da,
+    0xdab5e4db, 0xdab6e4dc, 0xdab7e4dd, 0xdab8e4de, 0xdab9e4df, 0xdabae4e0, 0xdabbe4e1, 0xdabce4e2,
+    0xdabde4e3, 0xdabee4e4, 0xdabfe4e5, 0xdac0e4e6, 0xdac1e4e7, 0xdac2e4e8, 0xdac3e4e9, 0xdac4e4ea,
+    0xdac5e4eb, 0xdac6e4ec, 0xdac7e4ed, 0xdac8e4ee, 0xdac9e4ef, 0xdacae4f0, 0xdacbe4f1, 0xdacce4f2,
+    0xdacde4f3, 0xdacee4f4, 0xdacfe4f5, 0xdad0e4f6, 0xdad1e4f7, 0xdad2e4f8, 0xdad3e4f9, 0xdad4e4fa,
+    0xdad5e4fb, 0xdad6e4fc, 0xdad7e4fd, 0xdad8e4fe, 0xdad9e4ff, 0xdadae500, 0xdadbe501, 0xdadce502,
+    0xdadde503, 0xdadee504, 0xdadfe505, 0xdae0e506, 0xdae1e507, 0xdae2e508, 0xdae3e509, 0xdae4e50a,
+    0xdae5e50b, 0xdae6e50c, 0xdae7e50d, 0xdae8e50e, 0xdae9e50f, 0xdaeae510, 0xdaebe511, 0xdaece512,
+    0xdaede513, 0xdaeee514, 0xdaefe515, 0xdaf0e516, 0xdaf1e517, 0xdaf2e518, 0xdaf3e519, 0xdaf4e51a,
+    0xdaf5e51b, 0xdaf6e51c, 0xdaf7e51d, 0xdaf8e51e, 0xdaf9e51f, 0xdafae520, 0xdafbe521, 0xdafce522,
+    0xdafde523, 0xdb41e524, 0xdb42e525, 0xdb43e526, 0xdb44e527, 0xdb45e528, 0xdb46e529, 0xdb47e52a,
+    0xdb48e52b, 0xdb49e52c, 0xdb4ae52d, 0xdb4be52e, 0xdb4ce52f, 0xdb4de530, 0xdb4ee531, 0xdb4fe532,
+    0xdb50e533, 0xdb51e534, 0xdb52e535, 0xdb53e536, 0xdb54e537, 0xdb55e538, 0xdb56e539, 0xdb57e53a,
+    0xdb58e53b, 0xdb59e53c, 0xdb5ae53d, 0xdb5be53e, 0xdb5ce53f, 0xdb5de540, 0xdb5ee541, 0xdb5fe542,
+    0xdb60e543, 0xdb61e544, 0xdb62e545, 0xdb63e546, 0xdb64e547, 0xdb65e548, 0xdb66e549, 0xdb67e54a,
+    0xdb68e54b, 0xdb69e54c, 0xdb6ae54d, 0xdb6be54e, 0xdb6ce54f, 0xdb6de550, 0xdb6ee551, 0xdb6fe552,
+    0xdb70e553, 0xdb71e554, 0xdb72e555, 0xdb73e556, 0xdb74e557, 0xdb75e558, 0xdb76e559, 0xdb77e55a,
+    0xdb78e55b, 0xdb79e55c, 0xdb7ae55d, 0xdb7be55e, 0xdb7ce55f, 0xdb7de560, 0xdb7ee561, 0xdb7fe562,
+    0xdb81e563, 0xdb82e564, 0xdb83e565, 0xdb84e566, 0xdb85e567, 0xdb86e568, 0xdb87e569, 0xdb88e56a,
+    0xdb89e56b, 0xdb8ae56c, 0xdb8be56d, 0xdb8ce56e, 0xdb8de56f, 0xdb8ee570, 0xdb8fe571, 0xdb90e572,
+    0xdb91e573, 0xdb92e574, 0xdb93e575, 0xdb94e576, 0xdb95e577, 0xdb96e578, 0xdb97e579, 0xdb98e57a,
+    0xdb99e57b, 0xdb9ae57c, 0xdb9be57d, 0xdb9ce57e, 0xdb9de57f, 0xdb9ee580, 0xdb9fe581, 0xdba0e582,
+    0xdba1e583, 0xdba2e584, 0xdba3e585, 0xdba4e586, 0xdba5e587, 0xdba6e588, 0xdba7e589, 0xdba8e58a,
+    0xdba9e58b, 0xdbaae58c, 0xdbabe58d, 0xdbace58e, 0xdbade58f, 0xdbaee590, 0xdbafe591, 0xdbb0e592,
+    0xdbb1e593, 0xdbb2e594, 0xdbb3e595, 0xdbb4e596, 0xdbb5e597, 0xdbb6e598, 0xdbb7e599, 0xdbb8e59a,
+    0xdbb9e59b, 0xdbbae59c, 0xdbbbe59d, 0xdbbce59e, 0xdbbde59f, 0xdbbee5a0, 0xdbbfe5a1, 0xdbc0e5a2,
+    0xdbc1e5a3, 0xdbc2e5a4, 0xdbc3e5a5, 0xdbc4e5a6, 0xdbc5e5a7, 0xdbc6e5a8, 0xdbc7e5a9, 0xdbc8e5aa,
+    0xdbc9e5ab, 0xdbcae5ac, 0xdbcbe5ad, 0xdbcce5ae, 0xdbcde5af, 0xdbcee5b0, 0xdbcfe5b1, 0xdbd0e5b2,
+    0xdbd1e5b3, 0xdbd2e5b4, 0xdbd3e5b5, 0xdbd4e5b6, 0xdbd5e5b7, 0xdbd6e5b8, 0xdbd7e5b9, 0xdbd8e5ba,
+    0xdbd9e5bb, 0xdbdae5bc, 0xdbdbe5bd, 0xdbdce5be, 0xdbdde5bf, 0xdbdee5c0, 0xdbdfe5c1, 0xdbe0e5c2,
+    0xdbe1e5c3, 0xdbe2e5c4, 0xdbe3e5c5, 0xdbe4e5c6, 0xdbe5e5c7, 0xdbe6e5c8, 0xdbe7e5c9, 0xdbe8e5ca,
+    0xdbe9e5cb, 0xdbeae5cc, 0xdbebe5cd, 0xdbece5ce, 0xdbede5cf, 0xdbeee5d0, 0xdbefe5d1, 0xdbf0e5d2,
+    0xdbf1e5d3, 0xdbf2e5d4, 0xdbf3e5d5, 0xdbf4e5d6, 0xdbf5e5d7, 0xdbf6e5d8, 0xdbf7e5d9, 0xdbf8e5da,
+    0xdbf9e5db, 0xdbfae5dc, 0xdbfbe5dd, 0xdbfce5de, 0xdbfde5df, 0xdc41e5e0, 0xdc42e5e1, 0xdc43e5e2,
+    0xdc44e5e3, 0xdc45e5e4, 0xdc46e5e5, 0xdc47e5e6, 0xdc48e5e7, 0xdc49e5e8, 0xdc4ae5e9, 0xdc4be5ea,
+    0xdc4ce5eb, 0xdc4de5ec, 0xdc4ee5ed, 0xdc4fe5ee, 0xdc50e5ef, 0xdc51e5f0, 0xdc52e5f1, 0xdc53e5f2,
+    0xdc54e5f3, 0xdc55e5f4, 0xdc56e5f5, 0xdc57e5f6, 0xdc58e5f7, 0xdc59e5f8, 0xdc5ae5f9, 0xdc5be5fa,
+    0xdc5ce5fb, 0xdc5de5fc, 0xdc5ee5fd, 0xdc5fe5fe, 0xdc60e5ff, 0xdc61e600, 0xdc62e601, 0xdc63e602,
+    0xdc64e603, 0xdc65e604, 0xdc66e605, 0xdc67e606, 0xdc68e607, 0xdc69e608, 0xdc6ae609, 0xdc6be60a,
+    0xdc6ce60b, 0xdc6de60c, 0xdc6ee60d, 0xdc6fe60e, 0xdc70e60f, 0xdc71e610, 0xdc72e611, 0xdc73e612,
+    0xdc74e613, 0xdc75e614, 0xdc76e615, 0xdc77e616, 0xdc78e617, 0xdc79e618, 0xdc7ae619, 0xdc7be61a,
+    0xdc7ce61b, 0xdc7de61c, 0xdc7ee61d, 0xdc7fe61e, 0xdc81e61f, 0xdc82e620, 0xdc83e621, 0xdc84e622,
+    0xdc85e623, 0xdc86e624, 0xdc87e625, 0xdc88e626, 0xdc89e627, 0xdc8ae628, 0xdc8be629, 0xdc8ce62a,
+    0xdc8de62b, 0xdc8ee62c, 0xdc8fe62d, 0xdc90e62e, 0xdc91e62f, 0xdc92e630, 0xdc93e631, 0xdc94e632,
+    0xdc95e633, 0xdc96e634, 0xdc97e635, 0xdc98e636, 0xdc99e637, 0xdc9ae638, 0xdc9be639, 0xdc9ce63a,
+    0xdc9de63b, 0xdc9ee63c, 0xdc9fe63d, 0xdca0e63e, 0xdca1e63f, 0xdca2e640, 0xdca3e641, 0xdca4e642,
+    0xdca5e643, 0xdca6e644, 0xdca7e645, 0xdca8e646, 0xdca9e647, 0xdcaae648, 0xdcabe649, 0xdcace64a,
+    0xdcade64b, 0xdcaee64c, 0xdcafe64d, 0xdcb0e64e, 0xdcb1e64f, 0xdcb2e650, 0xdcb3e651, 0xdcb4e652,
+    0xdcb5e653, 0xdcb6e654, 0xdcb7e655, 0xdcb8e656, 0xdcb9e657, 0xdcbae658, 0xdcbbe659, 0xdcbce65a,
+    0xdcbde65b, 0xdcbee65c, 0xdcbfe65d, 0xdcc0e65e, 0xdcc1e65f, 0xdcc2e660, 0xdcc3e661, 0xdcc4e662,
+    0xdcc5e663, 0xdcc6e664, 0xdcc7e665, 0xdcc8e666, 0xdcc9e667, 0xdccae668, 0xdccbe669, 0xdccce66a,
+    0xdccde66b, 0xdccee66c, 0xdccfe66d, 0xdcd0e66e, 0xdcd1e66f, 0xdcd2e670, 0xdcd3e671, 0xdcd4e672,
+    0xdcd5e673, 0xdcd6e674, 0xdcd7e675, 0xdcd8e676, 0xdcd9e677, 0xdcdae678, 0xdcdbe679, 0xdcdce67a,
+    0xdcdde67b, 0xdcdee67c, 0xdcdfe67d, 0xdce0e67e, 0xdce1e67f, 0xdce2e680, 0xdce3e681, 0xdce4e682,
+    0xdce5e683, 0xdce6e684, 0xdce7e685, 0xdce8e686, 0xdce9e687, 0xdceae688, 0xdcebe689, 0xdcece68a,
+    0xdcede68b, 0xdceee68c, 0xdcefe68d, 0xdcf0e68e, 0xdcf1e68f, 0xdcf2e690, 0xdcf3e691, 0xdcf4e692,
+    0xdcf5e693, 0xdcf6e694, 0xdcf7e695, 0xdcf8e696, 0xdcf9e697, 0xdcfae698, 0xdcfbe699, 0xdcfce69a,
+    0xdcfde69b, 0xdd41e69c, 0xdd42e69d, 0xdd43e69e, 0xdd44e69f, 0xdd45e6a0, 0xdd46e6a1, 0xdd47e6a2,
+    0xdd48e6a3, 0xdd49e6a4, 0xdd4ae6a5, 0xdd4be6a6, 0xdd4ce6a7, 0xdd4de6a8, 0xdd4ee6a9, 0xdd4fe6aa,
+    0xdd50e6ab, 0xdd51e6ac, 0xdd52e6ad, 0xdd53e6ae, 0xdd54e6af, 0xdd55e6b0, 0xdd56e6b1, 0xdd57e6b2,
+    0xdd58e6b3, 0xdd59e6b4, 0xdd5ae6b5, 0xdd5be6b6, 0xdd5ce6b7, 0xdd5de6b8, 0xdd5ee6b9, 0xdd5fe6ba,
+    0xdd60e6bb, 0xdd61e6bc, 0xdd62e6bd, 0xdd63e6be, 0xdd64e6bf, 0xdd65e6c0, 0xdd66e6c1, 0xdd67e6c2,
+    0xdd68e6c3, 0xdd69e6c4, 0xdd6ae6c5, 0xdd6be6c6, 0xdd6ce6c7, 0xdd6de6c8, 0xdd6ee6c9, 0xdd6fe6ca,
+    0xdd70e6cb, 0xdd71e6cc, 0xdd72e6cd, 0xdd73e6ce, 0xdd74e6cf, 0xdd75e6d0, 0xdd76e6d1, 0xdd77e6d2,
+    0xdd78e6d3, 0xdd79e6d4, 0xdd7ae6d5, 0xdd7be6d6, 0xdd7ce6d7, 0xdd7de6d8, 0xdd7ee6d9, 0xdd7fe6da,
+    0xdd81e6db, 0xdd82e6dc, 0xdd83e6dd, 0xdd84e6de, 0xdd85e6df, 0xdd86e6e0, 0xdd87e6e1, 0xdd88e6e2,
+    0xdd89e6e3, 0xdd8ae6e4, 0xdd8be6e5, 0xdd8ce6e6, 0xdd8de6e7, 0xdd8ee6e8, 0xdd8fe6e9, 0xdd90e6ea,
+    0xdd91e6eb, 0xdd92e6ec, 0xdd93e6ed, 0xdd94e6ee, 0xdd95e6ef, 0xdd96e6f0, 0xdd97e6f1, 0xdd98e6f2,
+    0xdd99e6f3, 0xdd9ae6f4, 0xdd9be6f5, 0xdd9ce6f6, 0xdd9de6f7, 0xdd9ee6f8, 0xdd9fe6f9, 0xdda0e6fa,
+    0xdda1e6fb, 0xdda2e6fc, 0xdda3e6fd, 0xdda4e6fe, 0xdda5e6ff, 0xdda6e700, 0xdda7e701, 0xdda8e702,
+    0xdda9e703, 0xddaae704, 0xddabe705, 0xddace706, 0xddade707, 0xddaee708, 0xddafe709, 0xddb0e70a,
+    0xddb1e70b, 0xddb2e70c, 0xddb3e70d, 0xddb4e70e, 0xddb5e70f, 0xddb6e710, 0xddb7e711, 0xddb8e712,
+    0xddb9e713, 0xddbae714, 0xddbbe715, 0xddbce716, 0xddbde717, 0xddbee718, 0xddbfe719, 0xddc0e71a,
+    0xddc1e71b, 0xddc2e71c, 0xddc3e71d, 0xddc4e71e, 0xddc5e71f, 0xddc6e720, 0xddc7e721, 0xddc8e722,
+    0xddc9e723, 0xddcae724, 0xddcbe725, 0xddcce726, 0xddcde727, 0xddcee728, 0xddcfe729, 0xddd0e72a,
+    0xddd1e72b, 0xddd2e72c, 0xddd3e72d, 0xddd4e72e, 0xddd5e72f, 0xddd6e730, 0xddd7e731, 0xddd8e732,
+    0xddd9e733, 0xdddae734, 0xdddbe735, 0xdddce736, 0xdddde737, 0xdddee738, 0xdddfe739, 0xdde0e73a,
+    0xdde1e73b, 0xdde2e73c, 0xdde3e73d, 0xdde4e73e, 0xdde5e73f, 0xdde6e740, 0xdde7e741, 0xdde8e742,
+    0xdde9e743, 0xddeae744, 0xddebe745, 0xddece746, 0xddede747, 0xddeee748, 0xddefe749, 0xddf0e74a,
+    0xddf1e74b, 0xddf2e74c, 0xddf3e74d, 0xddf4e74e, 0xddf5e74f, 0xddf6e750, 0xddf7e751, 0xddf8e752,
+    0xddf9e753, 0xddfae754, 0xddfbe755, 0xddfce756, 0xddfde757,
+];
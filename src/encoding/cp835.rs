@@ -0,0 +1,2556 @@
+#[allow(unused)]
+pub static DBCS_TBL: [u32; 20264] = [
+    0x40403000, 0x414103b1, 0x414203b2, 0x414303b3, 0x414403b4, 0x414503b5, 0x414603b6, 0x414703b7,
+    0x414803b8, 0x414903b9, 0x414a03ba, 0x414b03bb, 0x414c03bc, 0x414d03bd, 0x414e03be, 0x414f03bf,
+    0x415003c0, 0x415103c1, 0x415203c3, 0x415303c4, 0x415403c5, 0x415503c6, 0x415603c7, 0x415703c8,
+    0x415803c9, 0x41610391, 0x41620392, 0x41630393, 0x41640394, 0x41650395, 0x41660396, 0x41670397,
+    0x41680398, 0x41690399, 0x416a039a, 0x416b039b, 0x416c039c, 0x416d039d, 0x416e039e, 0x416f039f,
+    0x417003a0, 0x417103a1, 0x417203a3, 0x417303a4, 0x417403a5, 0x417503a6, 0x417603a7, 0x417703a8,
+    0x417803a9, 0x41800430, 0x41810431, 0x41820432, 0x41830433, 0x41840434, 0x41850435, 0x41860451,
+    0x41870436, 0x41880437, 0x41890438, 0x418a0439, 0x418b043a, 0x418c043b, 0x418d043c, 0x418e043d,
+    0x418f043e, 0x4190043f, 0x41910440, 0x41920441, 0x41930442, 0x41940443, 0x41950444, 0x41960445,
+    0x41970446, 0x41980447, 0x41990448, 0x419a0449, 0x419b044a, 0x419c044b, 0x419d044c, 0x419e044d,
+    0x419f044e, 0x41a0044f, 0x41b12170, 0x41b22171, 0x41b32172, 0x41b42173, 0x41b52174, 0x41b62175,
+    0x41b72176, 0x41b82177, 0x41b92178, 0x41ba2179, 0x41c00410, 0x41c10411, 0x41c20412, 0x41c30413,
+    0x41c40414, 0x41c50415, 0x41c60401, 0x41c70416, 0x41c80417, 0x41c90418, 0x41ca0419, 0x41cb041a,
+    0x41cc041b, 0x41cd041c, 0x41ce041d, 0x41cf041e, 0x41d0041f, 0x41d10420, 0x41d20421, 0x41d30422,
+    0x41d40423, 0x41d50424, 0x41d60425, 0x41d70426, 0x41d80427, 0x41d90428, 0x41da0429, 0x41db042a,
+    0x41dc042b, 0x41dd042c, 0x41de042d, 0x41df042e, 0x41e0042f, 0x41f12160, 0x41f22161, 0x41f32162,
+    0x41f42163, 0x41f52164, 0x41f62165, 0x41f72166, 0x41f82167, 0x41f92168, 0x41fa2169, 0x424affe1,
+    0x424bff0e, 0x424cff1c, 0x424dff08, 0x424eff0b, 0x424fff5c, 0x4250ff06, 0x425aff01, 0x425bffe5,
+    0x425c273d, 0x425dff09, 0x425eff1b, 0x425fffe2, 0x4260ff0d, 0x4261ff0f, 0x426affe4, 0x426bff0c,
+    0x426cff05, 0x426dff3f, 0x426eff1e, 0x426fff1f, 0x42792035, 0x427aff1a, 0x427bff03, 0x427cff20,
+    0x427dff07, 0x427eff1d, 0x427fff02, 0x4281ff41, 0x4282ff42, 0x4283ff43, 0x4284ff44, 0x4285ff45,
+    0x4286ff46, 0x4287ff47, 0x4288ff48, 0x4289ff49, 0x4291ff4a, 0x4292ff4b, 0x4293ff4c, 0x4294ff4d,
+    0x4295ff4e, 0x4296ff4f, 0x4297ff50, 0x4298ff51, 0x4299ff52, 0x42a1203e, 0x42a2ff53, 0x42a3ff54,
+    0x42a4ff55, 0x42a5ff56, 0x42a6ff57, 0x42a7ff58, 0x42a8ff59, 0x42a9ff5a, 0x42c0ff5b, 0x42c1ff21,
+    0x42c2ff22, 0x42c3ff23, 0x42c4ff24, 0x42c5ff25, 0x42c6ff26, 0x42c7ff27, 0x42c8ff28, 0x42c9ff29,
+    0x42d0ff5d, 0x42d1ff2a, 0x42d2ff2b, 0x42d3ff2c, 0x42d4ff2d, 0x42d5ff2e, 0x42d6ff2f, 0x42d7ff30,
+    0x42d8ff31, 0x42d9ff32, 0x42e0ff04, 0x42e2ff33, 0x42e3ff34, 0x42e4ff35, 0x42e5ff36, 0x42e6ff37,
+    0x42e7ff38, 0x42e8ff39, 0x42e9ff3a, 0x42f0ff10, 0x42f1ff11, 0x42f2ff12, 0x42f3ff13, 0x42f4ff14,
+    0x42f5ff15, 0x42f6ff16, 0x42f7ff17, 0x42f8ff18, 0x42f9ff19, 0x43413002, 0x4342300c, 0x4343300d,
+    0x43443001, 0x43452027, 0x434630f2, 0x434730a1, 0x434830a3, 0x434930a5, 0x434affe0, 0x434d2312,
+    0x434e2202, 0x434f2207, 0x435130a7, 0x435230a9, 0x435330e3, 0x435430e5, 0x435530e7, 0x435630c3,
+    0x435730ee, 0x435830fc, 0x435930f5, 0x435a30f6, 0x435d226a, 0x435e226b, 0x4360223d, 0x4361221d,
+    0x4363222c, 0x43642208, 0x4365220b, 0x43662286, 0x43672287, 0x43682282, 0x43692283, 0x436c2227,
+    0x436d2228, 0x436e21d2, 0x436f21d4, 0x43702200, 0x43712203, 0x4372212b, 0x43732030, 0x4374266f,
+    0x4375266d, 0x4376266a, 0x43772020, 0x43782021, 0x437900b6, 0x437a25ef, 0x438130a2, 0x438230a4,
+    0x438330a6, 0x438430a8, 0x438530aa, 0x438630ab, 0x438730ad, 0x438830af, 0x438930b1, 0x438a30b3,
+    0x438c30b5, 0x438d30b7, 0x438e30b9, 0x438f30bb, 0x439030bd, 0x439130bf, 0x439230c1, 0x439330c4,
+    0x439430c6, 0x439530c8, 0x439630ca, 0x439730cb, 0x439830cc, 0x439930cd, 0x439a30ce, 0x439d30cf,
+    0x439e30d2, 0x439f30d5, 0x43a1223c, 0x43a230d8, 0x43a330db, 0x43a430de, 0x43a530df, 0x43a630e0,
+    0x43a730e1, 0x43a830e2, 0x43a930e4, 0x43aa30e6, 0x43ac30e8, 0x43ad30e9, 0x43ae30ea, 0x43af30eb,
+    0x43b72501, 0x43b82503, 0x43b9250f, 0x43ba30ec, 0x43bb30ed, 0x43bc30ef, 0x43bd30f3, 0x43be309b,
+    0x43bf309c, 0x43c030ac, 0x43c130ae, 0x43c230b0, 0x43c330b2, 0x43c430b4, 0x43c530b6, 0x43c630b8,
+    0x43c730ba, 0x43c830bc, 0x43c930be, 0x43ca30c0, 0x43cb30c2, 0x43cc30c5, 0x43cd30c7, 0x43ce30c9,
+    0x43cf30d0, 0x43d030d3, 0x43d130d6, 0x43d230d9, 0x43d330dc, 0x43d430f4, 0x43d530d1, 0x43d630d4,
+    0x43d730d7, 0x43d830da, 0x43d930dd, 0x43da30f0, 0x43db30f1, 0x43dc30fd, 0x43dd30fe, 0x43e0ff3c,
+    0x43e12513, 0x43e2251b, 0x43e32517, 0x43e42523, 0x43e52533, 0x43e6252b, 0x43e7253b, 0x43e8254b,
+    0x43e92520, 0x43ea252f, 0x43eb2528, 0x43ec2537, 0x43ed253f, 0x43ee251d, 0x43ef2530, 0x43f02525,
+    0x43f12538, 0x43f22542, 0x4442300e, 0x4443300f, 0x4444ff3b, 0x4445ff3d, 0x44463092, 0x44473041,
+    0x44483043, 0x44493045, 0x444a2014, 0x444b00b1, 0x444c2260, 0x444d221e, 0x444e2103, 0x44502032,
+    0x44513047, 0x44523049, 0x44533083, 0x44543085, 0x44553087, 0x44563063, 0x4457308e, 0x445a2010,
+    0x445b02ba, 0x445cf83e, 0x445d3005, 0x445e3006, 0x445f3007, 0x446000a8, 0x44612018, 0x4462201c,
+    0x44633014, 0x44643008, 0x4465300a, 0x44663010, 0x44672266, 0x44682234, 0x44692642, 0x446a00a7,
+    0x446b203b, 0x446c3012, 0x446d3231, 0x446e2116, 0x446f2121, 0x4470ff3e, 0x44712019, 0x4472201d,
+    0x44733015, 0x44743009, 0x4475300b, 0x44763011, 0x44772267, 0x44782235, 0x44792640, 0x447a00d7,
+    0x447b00f7, 0x447c2225, 0x447d3013, 0x447e2025, 0x447f2026, 0x44813042, 0x44823044, 0x44833046,
+    0x44843048, 0x4485304a, 0x4486304b, 0x4487304d, 0x4488304f, 0x44893051, 0x448a3053, 0x448c3055,
+    0x448d3057, 0x448e3059, 0x448f305b, 0x4490305d, 0x4491305f, 0x44923061, 0x44933064, 0x44943066,
+    0x44953068, 0x4496306a, 0x4497306b, 0x4498306c, 0x4499306d, 0x449a306e, 0x449d306f, 0x449e3072,
+    0x449f3075, 0x44a23078, 0x44a3307b, 0x44a4307e, 0x44a5307f, 0x44a63080, 0x44a73081, 0x44a83082,
+    0x44a93084, 0x44aa3086, 0x44ac3088, 0x44ad3089, 0x44ae308a, 0x44af308b, 0x44ba308c, 0x44bb308d,
+    0x44bc308f, 0x44bd3093, 0x44c0304c, 0x44c1304e, 0x44c23050, 0x44c33052, 0x44c43054, 0x44c53056,
+    0x44c63058, 0x44c7305a, 0x44c8305c, 0x44c9305e, 0x44ca3060, 0x44cb3062, 0x44cc3065, 0x44cd3067,
+    0x44ce3069, 0x44cf3070, 0x44d03073, 0x44d13076, 0x44d23079, 0x44d3307c, 0x44d53071, 0x44d63074,
+    0x44d73077, 0x44d8307a, 0x44d9307d, 0x44da3090, 0x44db3091, 0x44dc309d, 0x44dd309e, 0x44e025cb,
+    0x44e125cf, 0x44e225b3, 0x44e325b2, 0x44e425ce, 0x44e52606, 0x44e62605, 0x44e725c7, 0x44e825c6,
+    0x44e925a1, 0x44ea25a0, 0x44eb25bd, 0x44ec25bc, 0x44ed00b0, 0x44ee00b4, 0x44ef2033, 0x44f02192,
+    0x44f12190, 0x44f22191, 0x44f32193, 0x45413021, 0x45423022, 0x45433023, 0x45443024, 0x45453025,
+    0x45463026, 0x45473027, 0x45483028, 0x45493029, 0x454a5341, 0x454b5344, 0x454c5345, 0x455102c9,
+    0x455202ca, 0x455302c7, 0x455402cb, 0x455502d9, 0x45563105, 0x45573106, 0x45583107, 0x45593108,
+    0x455a3109, 0x455b310a, 0x455c310b, 0x455d310c, 0x455e310d, 0x455f310e, 0x4560310f, 0x45613110,
+    0x45623111, 0x45633112, 0x45643113, 0x45653114, 0x45663115, 0x45673116, 0x45683117, 0x45693118,
+    0x456a3119, 0x456b311a, 0x456c311b, 0x456d311c, 0x456e311d, 0x456f311e, 0x4570311f, 0x45713120,
+    0x45723121, 0x45733122, 0x45743123, 0x45753124, 0x45763125, 0x45773126, 0x45783127, 0x45793128,
+    0x457a3129, 0x46412460, 0x46422461, 0x46432462, 0x46442463, 0x46452464, 0x46462465, 0x46472466,
+    0x46482467, 0x46492468, 0x464a2469, 0x46512474, 0x46522475, 0x46532476, 0x46542477, 0x46552478,
+    0x46562479, 0x4657247a, 0x4658247b, 0x4659247c, 0x465a247d, 0x46602581, 0x46612582, 0x46622583,
+    0x46632584, 0x46642585, 0x46652586, 0x46662587, 0x46672588, 0x4668258f, 0x4669258e, 0x466a258d,
+    0x466b258c, 0x466c258b, 0x466d258a, 0x466e2589, 0x466f253c, 0x46702534, 0x4671252c, 0x46722524,
+    0x4673251c, 0x46742594, 0x46752500, 0x46762502, 0x46772595, 0x4678250c, 0x46792510, 0x467a2514,
+    0x467b2518, 0x467c256d, 0x467d256e, 0x467e2570, 0x467f256f, 0x46812550, 0x4682255e, 0x4683256a,
+    0x46842561, 0x468525e2, 0x468625e3, 0x468725e5, 0x468825e4, 0x46892571, 0x468a2572, 0x468b2573,
+    0x46a0fe41, 0x46a1fe42, 0x46a2fe43, 0x46a3fe44, 0x46a4fe3b, 0x46a5fe3c, 0x46a7fe31, 0x46a8f83f,
+    0x46a9f840, 0x46aaf841, 0x46abf842, 0x46acfe35, 0x46adfe36, 0x46aefe37, 0x46affe38, 0x46b0fe39,
+    0x46b1fe3a, 0x46b2fe3d, 0x46b3fe3e, 0x46b4fe3f, 0x46b5fe40, 0x46b6fe33, 0x46b72574, 0x46b8ff0a,
+    0x46b93003, 0x46ba32a3, 0x46bb2105, 0x46bcfe34, 0x46bdfe4f, 0x46befe49, 0x46bffe4a, 0x46c0fe4d,
+    0x46c1fe4e, 0x46c2fe4b, 0x46c3fe4c, 0x46c4fe61, 0x46c5221a, 0x46c62252, 0x46c72261, 0x46c82229,
+    0x46c9222a, 0x46ca22a5, 0x46cb2220, 0x46cc221f, 0x46cd22bf, 0x46ce33d2, 0x46cf33d1, 0x46d0222b,
+    0x46d1222e, 0x46d22295, 0x46d32299, 0x46d42196, 0x46d52197, 0x46d62199, 0x46d72198, 0x46da2215,
+    0x46db2109, 0x46dc33d5, 0x46dd339c, 0x46de339d, 0x46df339e, 0x46e033ce, 0x46e133a1, 0x46e2338e,
+    0x46e3338f, 0x46e433c4, 0x46e500b7, 0x46e9301d, 0x46ea301e, 0x46ee21e7, 0x46ef21b8, 0x46f021b9,
+    0x46f15159, 0x46f2515b, 0x46f3515e, 0x46f4515d, 0x46f55161, 0x46f65163, 0x46f755e7, 0x46f874e9,
+    0x46f97cce, 0x4741fe30, 0x4742fe50, 0x4743fe51, 0x4744fe52, 0x4745fe54, 0x4746fe55, 0x4747fe56,
+    0x4748fe57, 0x4749fe32, 0x474afe58, 0x474bfe59, 0x474cfe5a, 0x474dfe5b, 0x474efe5c, 0x474ffe5d,
+    0x4750fe5e, 0x4751ffe3, 0x475202cd, 0x4753fe5f, 0x4754fe60, 0x4755fe62, 0x4756fe63, 0x4757fe64,
+    0x4758fe65, 0x4759fe66, 0x475afe68, 0x475bfe69, 0x475cfe6a, 0x475dfe6b, 0x47812400, 0x47822401,
+    0x47832402, 0x47842403, 0x47852404, 0x47862405, 0x47872406, 0x47882407, 0x47892408, 0x478a2409,
+    0x478b240a, 0x478c240b, 0x478d240c, 0x478e240d, 0x478f240e, 0x4790240f, 0x47912410, 0x47922411,
+    0x47932412, 0x47942413, 0x47952414, 0x47962415, 0x47972416, 0x47982417, 0x47992418, 0x479a2419,
+    0x479b241a, 0x479c241b, 0x479d241c, 0x479e241d, 0x479f241e, 0x47a0241f, 0x47a12421, 0x48414e00,
+    0x48424e28, 0x48434e36, 0x48444e3f, 0x48454e59, 0x48464e85, 0x48474e8c, 0x48484ea0, 0x48494eba,
+    0x484a513f, 0x484b5165, 0x484c516b, 0x484d5182, 0x484e5196, 0x484f51ab, 0x485051e0, 0x485151f5,
+    0x48525200, 0x4853529b, 0x485452f9, 0x48555315, 0x4856531a, 0x48575338, 0x48585341, 0x4859535c,
+    0x485a5369, 0x485b5382, 0x485c53b6, 0x485d53c8, 0x485e53e3, 0x485f56d7, 0x4860571f, 0x486158eb,
+    0x4862590a, 0x48635915, 0x48645927, 0x48655973, 0x48665b50, 0x48675b80, 0x48685bf8, 0x48695c0f,
+    0x486a5c22, 0x486b5c38, 0x486c5c6e, 0x486d5c71, 0x486e5ddb, 0x486f5de5, 0x48705df1, 0x48715dfe,
+    0x48725e72, 0x48735e7a, 0x48745e7f, 0x48755ef4, 0x48765efe, 0x48775f0b, 0x48785f13, 0x48795f50,
+    0x487a5f61, 0x487b5f73, 0x487c5fc3, 0x487d6208, 0x487e6236, 0x487f624b, 0x4881652f, 0x48826534,
+    0x48836587, 0x48846597, 0x488565a4, 0x488665b9, 0x488765e0, 0x488865e5, 0x488966f0, 0x488a6708,
+    0x488b6728, 0x488c6b20, 0x488d6b62, 0x488e6b79, 0x488f6bb3, 0x48906bcb, 0x48916bd4, 0x48926bdb,
+    0x48936c0f, 0x48946c14, 0x48956c34, 0x4896706b, 0x4897722a, 0x48987236, 0x4899723b, 0x489a723f,
+    0x489b7247, 0x489c7259, 0x489d725b, 0x489e72ac, 0x489f7384, 0x48a07389, 0x48a174dc, 0x48a274e6,
+    0x48a37518, 0x48a4751f, 0x48a57528, 0x48a67530, 0x48a7758b, 0x48a87592, 0x48a97676, 0x48aa767d,
+    0x48ab76ae, 0x48ac76bf, 0x48ad76ee, 0x48ae77db, 0x48af77e2, 0x48b077f3, 0x48b1793a, 0x48b279b8,
+    0x48b379be, 0x48b47a74, 0x48b57acb, 0x48b67af9, 0x48b77c73, 0x48b87cf8, 0x48b97f36, 0x48ba7f51,
+    0x48bb7f8a, 0x48bc7fbd, 0x48bd8001, 0x48be800c, 0x48bf8012, 0x48c08033, 0x48c1807f, 0x48c28089,
+    0x48c381e3, 0x48c481ea, 0x48c581f3, 0x48c681fc, 0x48c7820c, 0x48c8821b, 0x48c9821f, 0x48ca826e,
+    0x48cb8272, 0x48cc8278, 0x48cd864d, 0x48ce866b, 0x48cf8840, 0x48d0884c, 0x48d18863, 0x48d2897e,
+    0x48d3898b, 0x48d489d2, 0x48d58a00, 0x48d68c37, 0x48d78c46, 0x48d88c55, 0x48d98c78, 0x48da8c9d,
+    0x48db8d64, 0x48dc8d70, 0x48dd8db3, 0x48de8eab, 0x48df8eca, 0x48e08f9b, 0x48e18fb0, 0x48e28fb5,
+    0x48e39091, 0x48e49149, 0x48e591c6, 0x48e691cc, 0x48e791d1, 0x48e89577, 0x48e99580, 0x48ea961c,
+    0x48eb96b6, 0x48ec96b9, 0x48ed96e8, 0x48ee9752, 0x48ef975e, 0x48f09762, 0x48f19769, 0x48f297cb,
+    0x48f397ed, 0x48f497f3, 0x48f59801, 0x48f698a8, 0x48f798db, 0x48f898df, 0x48f99996, 0x48fa9999,
+    0x48fb99ac, 0x48fc9aa8, 0x48fd9ad8, 0x49419adf, 0x49429b25, 0x49439b2f, 0x49449b32, 0x49459b3c,
+    0x49469b5a, 0x49479ce5, 0x49489e75, 0x49499e7f, 0x494a9ea5, 0x494b9ebb, 0x494c9ec3, 0x494d9ecd,
+    0x494e9ed1, 0x494f9ef9, 0x49509efd, 0x49519f0e, 0x49529f13, 0x49539f20, 0x49549f3b, 0x49559f4a,
+    0x49569f52, 0x49579f8d, 0x49589f9c, 0x49599fa0, 0x4c414e00, 0x4c424e59, 0x4c434e01, 0x4c444e03,
+    0x4c454e43, 0x4c464e5d, 0x4c474e86, 0x4c484e8c, 0x4c494eba, 0x4c4a513f, 0x4c4b5165, 0x4c4c516b,
+    0x4c4d51e0, 0x4c4e5200, 0x4c4f5201, 0x4c50529b, 0x4c515315, 0x4c525341, 0x4c53535c, 0x4c5453c8,
+    0x4c554e09, 0x4c564e0b, 0x4c574e08, 0x4c584e0a, 0x4c594e2b, 0x4c5a4e38, 0x4c5b51e1, 0x4c5c4e45,
+    0x4c5d4e48, 0x4c5e4e5f, 0x4c5f4e5e, 0x4c604e8e, 0x4c614ea1, 0x4c625140, 0x4c635203, 0x4c6452fa,
+    0x4c655343, 0x4c6653c9, 0x4c6753e3, 0x4c68571f, 0x4c6958eb, 0x4c6a5915, 0x4c6b5927, 0x4c6c5973,
+    0x4c6d5b50, 0x4c6e5b51, 0x4c6f5b53, 0x4c705bf8, 0x4c715c0f, 0x4c725c22, 0x4c735c38, 0x4c745c71,
+    0x4c755ddd, 0x4c765de5, 0x4c775df1, 0x4c785df2, 0x4c795df3, 0x4c7a5dfe, 0x4c7b5e72, 0x4c7c5efe,
+    0x4c7d5f0b, 0x4c7e5f13, 0x4c7f624d, 0x4c814e11, 0x4c824e10, 0x4c834e0d, 0x4c844e2d, 0x4c854e30,
+    0x4c864e39, 0x4c874e4b, 0x4c885c39, 0x4c894e88, 0x4c8a4e91, 0x4c8b4e95, 0x4c8c4e92, 0x4c8d4e94,
+    0x4c8e4ea2, 0x4c8f4ec1, 0x4c904ec0, 0x4c914ec3, 0x4c924ec6, 0x4c934ec7, 0x4c944ecd, 0x4c954eca,
+    0x4c964ecb, 0x4c974ec4, 0x4c985143, 0x4c995141, 0x4c9a5167, 0x4c9b516d, 0x4c9c516e, 0x4c9d516c,
+    0x4c9e5197, 0x4c9f51f6, 0x4ca05206, 0x4ca15207, 0x4ca25208, 0x4ca352fb, 0x4ca452fe, 0x4ca552ff,
+    0x4ca65316, 0x4ca75339, 0x4ca85348, 0x4ca95347, 0x4caa5345, 0x4cab535e, 0x4cac5384, 0x4cad53cb,
+    0x4cae53ca, 0x4caf53cd, 0x4cb058ec, 0x4cb15929, 0x4cb2592b, 0x4cb3592a, 0x4cb4592d, 0x4cb55b54,
+    0x4cb65c11, 0x4cb75c24, 0x4cb85c3a, 0x4cb95c6f, 0x4cba5df4, 0x4cbb5e7b, 0x4cbc5eff, 0x4cbd5f14,
+    0x4cbe5f15, 0x4cbf5fc3, 0x4cc06208, 0x4cc16236, 0x4cc2624b, 0x4cc3624e, 0x4cc4652f, 0x4cc56587,
+    0x4cc66597, 0x4cc765a4, 0x4cc865b9, 0x4cc965e5, 0x4cca66f0, 0x4ccb6708, 0x4ccc6728, 0x4ccd6b20,
+    0x4cce6b62, 0x4ccf6b79, 0x4cd06bcb, 0x4cd16bd4, 0x4cd26bdb, 0x4cd36c0f, 0x4cd46c34, 0x4cd5706b,
+    0x4cd6722a, 0x4cd77236, 0x4cd8723b, 0x4cd97247, 0x4cda7259, 0x4cdb725b, 0x4cdc72ac, 0x4cdd738b,
+    0x4cde4e19, 0x4cdf4e16, 0x4ce04e15, 0x4ce14e14, 0x4ce24e18, 0x4ce34e3b, 0x4ce44e4d, 0x4ce54e4f,
+    0x4ce64e4e, 0x4ce74ee5, 0x4ce84ed8, 0x4ce94ed4, 0x4cea4ed5, 0x4ceb4ed6, 0x4cec4ed7, 0x4ced4ee3,
+    0x4cee4ee4, 0x4cef4ed9, 0x4cf04ede, 0x4cf15145, 0x4cf25144, 0x4cf35189, 0x4cf4518a, 0x4cf551ac,
+    0x4cf651f9, 0x4cf751fa, 0x4cf851f8, 0x4cf9520a, 0x4cfa52a0, 0x4cfb529f, 0x4cfc5305, 0x4cfd5306,
+    0x4d415317, 0x4d42531d, 0x4d434edf, 0x4d44534a, 0x4d455349, 0x4d465361, 0x4d475360, 0x4d48536f,
+    0x4d49536e, 0x4d4a53bb, 0x4d4b53ef, 0x4d4c53e4, 0x4d4d53f3, 0x4d4e53ec, 0x4d4f53ee, 0x4d5053e9,
+    0x4d5153e8, 0x4d5253fc, 0x4d5353f8, 0x4d5453f5, 0x4d5553eb, 0x4d5653e6, 0x4d5753ea, 0x4d5853f2,
+    0x4d5953f1, 0x4d5a53f0, 0x4d5b53e5, 0x4d5c53ed, 0x4d5d53fb, 0x4d5e56db, 0x4d5f56da, 0x4d605916,
+    0x4d61592e, 0x4d625931, 0x4d635974, 0x4d645976, 0x4d655b55, 0x4d665b83, 0x4d675c3c, 0x4d685de8,
+    0x4d695de7, 0x4d6a5de6, 0x4d6b5e02, 0x4d6c5e03, 0x4d6d5e73, 0x4d6e5e7c, 0x4d6f5f01, 0x4d705f18,
+    0x4d715f17, 0x4d725fc5, 0x4d73620a, 0x4d746253, 0x4d756254, 0x4d766252, 0x4d776251, 0x4d7865a5,
+    0x4d7965e6, 0x4d7a672e, 0x4d7b672c, 0x4d7c672a, 0x4d7d672b, 0x4d7e672d, 0x4d7f6b63, 0x4d816bcd,
+    0x4d826c11, 0x4d836c10, 0x4d846c38, 0x4d856c41, 0x4d866c40, 0x4d876c3e, 0x4d8872af, 0x4d897384,
+    0x4d8a7389, 0x4d8b74dc, 0x4d8c74e6, 0x4d8d7518, 0x4d8e751f, 0x4d8f7528, 0x4d907529, 0x4d917530,
+    0x4d927531, 0x4d937532, 0x4d947533, 0x4d95758b, 0x4d96767d, 0x4d9776ae, 0x4d9876bf, 0x4d9976ee,
+    0x4d9a77db, 0x4d9b77e2, 0x4d9c77f3, 0x4d9d793a, 0x4d9e79be, 0x4d9f7a74, 0x4da07acb, 0x4da14e1e,
+    0x4da24e1f, 0x4da34e52, 0x4da44e53, 0x4da54e69, 0x4da64e99, 0x4da74ea4, 0x4da84ea6, 0x4da94ea5,
+    0x4daa4eff, 0x4dab4f09, 0x4dac4f19, 0x4dad4f0a, 0x4dae4f15, 0x4daf4f0d, 0x4db04f10, 0x4db14f11,
+    0x4db24f0f, 0x4db34ef2, 0x4db44ef6, 0x4db54efb, 0x4db64ef0, 0x4db74ef3, 0x4db84efd, 0x4db94f01,
+    0x4dba4f0b, 0x4dbb5149, 0x4dbc5147, 0x4dbd5146, 0x4dbe5148, 0x4dbf5168, 0x4dc05171, 0x4dc1518d,
+    0x4dc251b0, 0x4dc35217, 0x4dc45211, 0x4dc55212, 0x4dc6520e, 0x4dc75216, 0x4dc852a3, 0x4dc95308,
+    0x4dca5321, 0x4dcb5320, 0x4dcc5370, 0x4dcd5371, 0x4dce5409, 0x4dcf540f, 0x4dd0540c, 0x4dd1540a,
+    0x4dd25410, 0x4dd35401, 0x4dd4540b, 0x4dd55404, 0x4dd65411, 0x4dd7540d, 0x4dd85408, 0x4dd95403,
+    0x4dda540e, 0x4ddb5406, 0x4ddc5412, 0x4ddd56e0, 0x4dde56de, 0x4ddf56dd, 0x4de05733, 0x4de15730,
+    0x4de25728, 0x4de3572d, 0x4de4572c, 0x4de5572f, 0x4de65729, 0x4de75919, 0x4de8591a, 0x4de95937,
+    0x4dea5938, 0x4deb5984, 0x4dec5978, 0x4ded5983, 0x4dee597d, 0x4def5979, 0x4df05982, 0x4df15981,
+    0x4df25b57, 0x4df35b58, 0x4df45b87, 0x4df55b88, 0x4df65b85, 0x4df75b89, 0x4df85bfa, 0x4df95c16,
+    0x4dfa5c79, 0x4dfb5dde, 0x4dfc5e06, 0x4dfd5e76, 0x4e415e74, 0x4e425f0f, 0x4e435f1b, 0x4e445fd9,
+    0x4e455fd6, 0x4e46620e, 0x4e47620c, 0x4e48620d, 0x4e496210, 0x4e4a6263, 0x4e4b625b, 0x4e4c6258,
+    0x4e4d6536, 0x4e4e65e9, 0x4e4f65e8, 0x4e5065ec, 0x4e5165ed, 0x4e5266f2, 0x4e5366f3, 0x4e546709,
+    0x4e55673d, 0x4e566734, 0x4e576731, 0x4e586735, 0x4e596b21, 0x4e5a6b64, 0x4e5b6b7b, 0x4e5c6c16,
+    0x4e5d6c5d, 0x4e5e6c57, 0x4e5f6c59, 0x4e606c5f, 0x4e616c60, 0x4e626c50, 0x4e636c55, 0x4e646c61,
+    0x4e656c5b, 0x4e666c4d, 0x4e676c4e, 0x4e687070, 0x4e69725f, 0x4e6a725d, 0x4e6b767e, 0x4e6c7af9,
+    0x4e6d7c73, 0x4e6e7cf8, 0x4e6f7f36, 0x4e707f8a, 0x4e717fbd, 0x4e728001, 0x4e738003, 0x4e74800c,
+    0x4e758012, 0x4e768033, 0x4e77807f, 0x4e788089, 0x4e79808b, 0x4e7a808c, 0x4e7b81e3, 0x4e7c81ea,
+    0x4e7d81f3, 0x4e7e81fc, 0x4e7f820c, 0x4e81821b, 0x4e82821f, 0x4e83826e, 0x4e848272, 0x4e85827e,
+    0x4e86866b, 0x4e878840, 0x4e88884c, 0x4e898863, 0x4e8a897f, 0x4e8b9621, 0x4e8c4e32, 0x4e8d4ea8,
+    0x4e8e4f4d, 0x4e8f4f4f, 0x4e904f47, 0x4e914f57, 0x4e924f5e, 0x4e934f34, 0x4e944f5b, 0x4e954f55,
+    0x4e964f30, 0x4e974f50, 0x4e984f51, 0x4e994f3d, 0x4e9a4f3a, 0x4e9b4f38, 0x4e9c4f43, 0x4e9d4f54,
+    0x4e9e4f3c, 0x4e9f4f46, 0x4ea04f63, 0x4ea14f5c, 0x4ea24f60, 0x4ea34f2f, 0x4ea44f4e, 0x4ea54f36,
+    0x4ea64f59, 0x4ea74f5d, 0x4ea84f48, 0x4ea94f5a, 0x4eaa514c, 0x4eab514b, 0x4eac514d, 0x4ead5175,
+    0x4eae51b6, 0x4eaf51b7, 0x4eb05225, 0x4eb15224, 0x4eb25229, 0x4eb3522a, 0x4eb45228, 0x4eb552ab,
+    0x4eb652a9, 0x4eb752aa, 0x4eb852ac, 0x4eb95323, 0x4eba5373, 0x4ebb5375, 0x4ebc541d, 0x4ebd542d,
+    0x4ebe541e, 0x4ebf543e, 0x4ec05426, 0x4ec1544e, 0x4ec25427, 0x4ec35446, 0x4ec45443, 0x4ec55433,
+    0x4ec65448, 0x4ec75442, 0x4ec8541b, 0x4ec95429, 0x4eca544a, 0x4ecb5439, 0x4ecc543b, 0x4ecd5438,
+    0x4ece542e, 0x4ecf5435, 0x4ed05436, 0x4ed15420, 0x4ed2543c, 0x4ed35440, 0x4ed45431, 0x4ed5542b,
+    0x4ed6541f, 0x4ed7542c, 0x4ed856ea, 0x4ed956f0, 0x4eda56e4, 0x4edb56eb, 0x4edc574a, 0x4edd5751,
+    0x4ede5740, 0x4edf574d, 0x4ee05747, 0x4ee1574e, 0x4ee2573e, 0x4ee35750, 0x4ee4574f, 0x4ee5573b,
+    0x4ee658ef, 0x4ee7593e, 0x4ee8599d, 0x4ee95992, 0x4eea59a8, 0x4eeb599e, 0x4eec59a3, 0x4eed5999,
+    0x4eee5996, 0x4eef598d, 0x4ef059a4, 0x4ef15993, 0x4ef2598a, 0x4ef359a5, 0x4ef45b5d, 0x4ef55b5c,
+    0x4ef65b5a, 0x4ef75b5b, 0x4ef85b8c, 0x4ef95b8b, 0x4efa5b8f, 0x4efb5c2c, 0x4efc5c40, 0x4efd5c41,
+    0x4f415c3f, 0x4f425c3e, 0x4f435c90, 0x4f445c91, 0x4f455c94, 0x4f465c8c, 0x4f475deb, 0x4f485e0c,
+    0x4f495e8f, 0x4f4a5e87, 0x4f4b5e8a, 0x4f4c5ef7, 0x4f4d5f04, 0x4f4e5f1f, 0x4f4f5f64, 0x4f505f62,
+    0x4f515f77, 0x4f525f79, 0x4f535fd8, 0x4f545fcc, 0x4f555fd7, 0x4f565fcd, 0x4f575ff1, 0x4f585feb,
+    0x4f595ff8, 0x4f5a5fea, 0x4f5b6212, 0x4f5c6211, 0x4f5d6284, 0x4f5e6297, 0x4f5f6296, 0x4f606280,
+    0x4f616276, 0x4f626289, 0x4f63626d, 0x4f64628a, 0x4f65627c, 0x4f66627e, 0x4f676279, 0x4f686273,
+    0x4f696292, 0x4f6a626f, 0x4f6b6298, 0x4f6c626e, 0x4f6d6295, 0x4f6e6293, 0x4f6f6291, 0x4f706286,
+    0x4f716539, 0x4f72653b, 0x4f736538, 0x4f7465f1, 0x4f7566f4, 0x4f76675f, 0x4f77674e, 0x4f78674f,
+    0x4f796750, 0x4f7a6751, 0x4f7b675c, 0x4f7c6756, 0x4f7d675e, 0x4f7e6749, 0x4f7f6746, 0x4f816760,
+    0x4f826753, 0x4f836757, 0x4f846b65, 0x4f856bcf, 0x4f866c42, 0x4f876c5e, 0x4f886c99, 0x4f896c81,
+    0x4f8a6c88, 0x4f8b6c89, 0x4f8c6c85, 0x4f8d6c9b, 0x4f8e6c6a, 0x4f8f6c7a, 0x4f906c90, 0x4f916c70,
+    0x4f926c8c, 0x4f936c68, 0x4f946c96, 0x4f956c92, 0x4f966c7d, 0x4f976c83, 0x4f986c72, 0x4f996c7e,
+    0x4f9a6c74, 0x4f9b6c86, 0x4f9c6c76, 0x4f9d6c8d, 0x4f9e6c94, 0x4f9f6c98, 0x4fa06c82, 0x4fa17076,
+    0x4fa2707c, 0x4fa3707d, 0x4fa47078, 0x4fa57262, 0x4fa67261, 0x4fa77260, 0x4fa872c4, 0x4fa972c2,
+    0x4faa7396, 0x4fab752c, 0x4fac752b, 0x4fad7537, 0x4fae7538, 0x4faf7682, 0x4fb076ef, 0x4fb177e3,
+    0x4fb279c1, 0x4fb379c0, 0x4fb479bf, 0x4fb57a76, 0x4fb67cfb, 0x4fb77f55, 0x4fb88096, 0x4fb98093,
+    0x4fba809d, 0x4fbb8098, 0x4fbc809b, 0x4fbd809a, 0x4fbe80b2, 0x4fbf826f, 0x4fc08292, 0x4fc1828b,
+    0x4fc2828d, 0x4fc3898b, 0x4fc489d2, 0x4fc58a00, 0x4fc68c37, 0x4fc78c46, 0x4fc88c55, 0x4fc98c9d,
+    0x4fca8d64, 0x4fcb8d70, 0x4fcc8db3, 0x4fcd8eab, 0x4fce8eca, 0x4fcf8f9b, 0x4fd08fb0, 0x4fd18fc2,
+    0x4fd28fc6, 0x4fd38fc5, 0x4fd48fc4, 0x4fd55de1, 0x4fd69091, 0x4fd790a2, 0x4fd890aa, 0x4fd990a6,
+    0x4fda90a3, 0x4fdb9149, 0x4fdc91c6, 0x4fdd91cc, 0x4fde9632, 0x4fdf962e, 0x4fe09631, 0x4fe1962a,
+    0x4fe2962c, 0x4fe34e26, 0x4fe44e56, 0x4fe54e73, 0x4fe64e8b, 0x4fe74e9b, 0x4fe84e9e, 0x4fe94eab,
+    0x4fea4eac, 0x4feb4f6f, 0x4fec4f9d, 0x4fed4f8d, 0x4fee4f73, 0x4fef4f7f, 0x4ff04f6c, 0x4ff14f9b,
+    0x4ff24f8b, 0x4ff34f86, 0x4ff44f83, 0x4ff54f70, 0x4ff64f75, 0x4ff74f88, 0x4ff84f69, 0x4ff94f7b,
+    0x4ffa4f96, 0x4ffb4f7e, 0x4ffc4f8f, 0x4ffd4f91, 0x50414f7a, 0x50425154, 0x50435152, 0x50445155,
+    0x50455169, 0x50465177, 0x50475176, 0x50485178, 0x504951bd, 0x504a51fd, 0x504b523b, 0x504c5238,
+    0x504d5237, 0x504e523a, 0x504f5230, 0x5050522e, 0x50515236, 0x50525241, 0x505352be, 0x505452bb,
+    0x50555352, 0x50565354, 0x50575353, 0x50585351, 0x50595366, 0x505a5377, 0x505b5378, 0x505c5379,
+    0x505d53d6, 0x505e53d4, 0x505f53d7, 0x50605473, 0x50615475, 0x50625496, 0x50635478, 0x50645495,
+    0x50655480, 0x5066547b, 0x50675477, 0x50685484, 0x50695492, 0x506a5486, 0x506b547c, 0x506c5490,
+    0x506d5471, 0x506e5476, 0x506f548c, 0x5070549a, 0x50715462, 0x50725468, 0x5073548b, 0x5074547d,
+    0x5075548e, 0x507656fa, 0x50775783, 0x50785777, 0x5079576a, 0x507a5769, 0x507b5761, 0x507c5766,
+    0x507d5764, 0x507e577c, 0x507f591c, 0x50815949, 0x50825947, 0x50835948, 0x50845944, 0x50855954,
+    0x508659be, 0x508759bb, 0x508859d4, 0x508959b9, 0x508a59ae, 0x508b59d1, 0x508c59c6, 0x508d59d0,
+    0x508e59cd, 0x508f59cb, 0x509059d3, 0x509159ca, 0x509259af, 0x509359b3, 0x509459d2, 0x509559c5,
+    0x50965b5f, 0x50975b64, 0x50985b63, 0x50995b97, 0x509a5b9a, 0x509b5b98, 0x509c5b9c, 0x509d5b99,
+    0x509e5b9b, 0x509f5c1a, 0x50a05c48, 0x50a15c45, 0x50a25c46, 0x50a35cb7, 0x50a45ca1, 0x50a55cb8,
+    0x50a65ca9, 0x50a75cab, 0x50a85cb1, 0x50a95cb3, 0x50aa5e18, 0x50ab5e1a, 0x50ac5e16, 0x50ad5e15,
+    0x50ae5e1b, 0x50af5e11, 0x50b05e78, 0x50b15e9a, 0x50b25e97, 0x50b35e9c, 0x50b45e95, 0x50b55e96,
+    0x50b65ef6, 0x50b75f26, 0x50b85f27, 0x50b95f29, 0x50ba5f80, 0x50bb5f81, 0x50bc5f7f, 0x50bd5f7c,
+    0x50be5fdd, 0x50bf5fe0, 0x50c05ffd, 0x50c15ff5, 0x50c25fff, 0x50c3600f, 0x50c46014, 0x50c5602f,
+    0x50c66035, 0x50c76016, 0x50c8602a, 0x50c96015, 0x50ca6021, 0x50cb6027, 0x50cc6029, 0x50cd602b,
+    0x50ce601b, 0x50cf6216, 0x50d06215, 0x50d1623f, 0x50d2623e, 0x50d36240, 0x50d4627f, 0x50d562c9,
+    0x50d662cc, 0x50d762c4, 0x50d862bf, 0x50d962c2, 0x50da62b9, 0x50db62d2, 0x50dc62db, 0x50dd62ab,
+    0x50de62d3, 0x50df62d4, 0x50e062cb, 0x50e162c8, 0x50e262a8, 0x50e362bd, 0x50e462bc, 0x50e562d0,
+    0x50e662d9, 0x50e762c7, 0x50e862cd, 0x50e962b5, 0x50ea62da, 0x50eb62b1, 0x50ec62d8, 0x50ed62d6,
+    0x50ee62d7, 0x50ef62c6, 0x50f062ac, 0x50f162ce, 0x50f2653e, 0x50f365a7, 0x50f465bc, 0x50f565fa,
+    0x50f66614, 0x50f76613, 0x50f8660c, 0x50f96606, 0x50fa6602, 0x50fb660e, 0x50fc6600, 0x50fd660f,
+    0x51416615, 0x5142660a, 0x51436607, 0x5144670d, 0x5145670b, 0x5146676d, 0x5147678b, 0x51486795,
+    0x51496771, 0x514a679c, 0x514b6773, 0x514c6777, 0x514d6787, 0x514e679d, 0x514f6797, 0x5150676f,
+    0x51516770, 0x5152677f, 0x51536789, 0x5154677e, 0x51556790, 0x51566775, 0x5157679a, 0x51586793,
+    0x5159677c, 0x515a676a, 0x515b6772, 0x515c6b23, 0x515d6b66, 0x515e6b67, 0x515f6b7f, 0x51606c13,
+    0x51616c1b, 0x51626ce3, 0x51636ce8, 0x51646cf3, 0x51656cb1, 0x51666ccc, 0x51676ce5, 0x51686cb3,
+    0x51696cbd, 0x516a6cbe, 0x516b6cbc, 0x516c6ce2, 0x516d6cab, 0x516e6cd5, 0x516f6cd3, 0x51706cb8,
+    0x51716cc4, 0x51726cb9, 0x51736cc1, 0x51746cae, 0x51756cd7, 0x51766cc5, 0x51776cf1, 0x51786cbf,
+    0x51796cbb, 0x517a6ce1, 0x517b6cdb, 0x517c6cca, 0x517d6cac, 0x517e6cef, 0x517f6cdc, 0x51816cd6,
+    0x51826ce0, 0x51837095, 0x5184708e, 0x51857092, 0x5186708a, 0x51877099, 0x5188722c, 0x5189722d,
+    0x518a7238, 0x518b7248, 0x518c7267, 0x518d7269, 0x518e72c0, 0x518f72ce, 0x519072d9, 0x519172d7,
+    0x519272d0, 0x519373a9, 0x519473a8, 0x5195739f, 0x519673ab, 0x519773a5, 0x5198753d, 0x5199759d,
+    0x519a7599, 0x519b759a, 0x519c7684, 0x519d76c2, 0x519e76f2, 0x519f76f4, 0x51a077e5, 0x51a177fd,
+    0x51a2793e, 0x51a37940, 0x51a47941, 0x51a579c9, 0x51a679c8, 0x51a77a7a, 0x51a87a79, 0x51a97afa,
+    0x51aa7cfe, 0x51ab7f54, 0x51ac7f8c, 0x51ad7f8b, 0x51ae8005, 0x51af80ba, 0x51b080a5, 0x51b180a2,
+    0x51b280b1, 0x51b380a1, 0x51b480ab, 0x51b580a9, 0x51b680b4, 0x51b780aa, 0x51b880af, 0x51b981e5,
+    0x51ba81fe, 0x51bb820d, 0x51bc82b3, 0x51bd829d, 0x51be8299, 0x51bf82ad, 0x51c082bd, 0x51c1829f,
+    0x51c282b9, 0x51c382b1, 0x51c482ac, 0x51c582a5, 0x51c682af, 0x51c782b8, 0x51c882a3, 0x51c982b0,
+    0x51ca82be, 0x51cb82b7, 0x51cc864e, 0x51cd8671, 0x51ce521d, 0x51cf8868, 0x51d08ecb, 0x51d18fce,
+    0x51d28fd4, 0x51d38fd1, 0x51d490b5, 0x51d590b8, 0x51d690b1, 0x51d790b6, 0x51d891c7, 0x51d991d1,
+    0x51da9577, 0x51db9580, 0x51dc961c, 0x51dd9640, 0x51de963f, 0x51df963b, 0x51e09644, 0x51e19642,
+    0x51e296b9, 0x51e396e8, 0x51e49752, 0x51e5975e, 0x51e64e9f, 0x51e74ead, 0x51e84eae, 0x51e94fe1,
+    0x51ea4fb5, 0x51eb4faf, 0x51ec4fbf, 0x51ed4fe0, 0x51ee4fd1, 0x51ef4fcf, 0x51f04fdd, 0x51f14fc3,
+    0x51f24fb6, 0x51f34fd8, 0x51f44fdf, 0x51f54fca, 0x51f64fd7, 0x51f74fae, 0x51f84fd0, 0x51f94fc4,
+    0x51fa4fc2, 0x51fb4fda, 0x51fc4fce, 0x51fd4fde, 0x52414fb7, 0x52425157, 0x52435192, 0x52445191,
+    0x524551a0, 0x5246524e, 0x52475243, 0x5248524a, 0x5249524d, 0x524a524c, 0x524b524b, 0x524c5247,
+    0x524d52c7, 0x524e52c9, 0x524f52c3, 0x525052c1, 0x5251530d, 0x52525357, 0x5253537b, 0x5254539a,
+    0x525553db, 0x525654ac, 0x525754c0, 0x525854a8, 0x525954ce, 0x525a54c9, 0x525b54b8, 0x525c54a6,
+    0x525d54b3, 0x525e54c7, 0x525f54c2, 0x526054bd, 0x526154aa, 0x526254c1, 0x526354c4, 0x526454c8,
+    0x526554af, 0x526654ab, 0x526754b1, 0x526854bb, 0x526954a9, 0x526a54a7, 0x526b54bf, 0x526c56ff,
+    0x526d5782, 0x526e578b, 0x526f57a0, 0x527057a3, 0x527157a2, 0x527257ce, 0x527357ae, 0x52745793,
+    0x52755955, 0x52765951, 0x5277594f, 0x5278594e, 0x52795950, 0x527a59dc, 0x527b59d8, 0x527c59ff,
+    0x527d59e3, 0x527e59e8, 0x527f5a03, 0x528159e5, 0x528259ea, 0x528359da, 0x528459e6, 0x52855a01,
+    0x528659fb, 0x52875b69, 0x52885ba3, 0x52895ba6, 0x528a5ba4, 0x528b5ba2, 0x528c5ba5, 0x528d5c01,
+    0x528e5c4e, 0x528f5c4f, 0x52905c4d, 0x52915c4b, 0x52925cd9, 0x52935cd2, 0x52945df7, 0x52955e1d,
+    0x52965e25, 0x52975e1f, 0x52985e7d, 0x52995ea0, 0x529a5ea6, 0x529b5efa, 0x529c5f08, 0x529d5f2d,
+    0x529e5f65, 0x529f5f88, 0x52a05f85, 0x52a15f8a, 0x52a25f8b, 0x52a35f87, 0x52a45f8c, 0x52a55f89,
+    0x52a66012, 0x52a7601d, 0x52a86020, 0x52a96025, 0x52aa600e, 0x52ab6028, 0x52ac604d, 0x52ad6070,
+    0x52ae6068, 0x52af6062, 0x52b06046, 0x52b16043, 0x52b2606c, 0x52b3606b, 0x52b4606a, 0x52b56064,
+    0x52b66241, 0x52b762dc, 0x52b86316, 0x52b96309, 0x52ba62fc, 0x52bb62ed, 0x52bc6301, 0x52bd62ee,
+    0x52be62fd, 0x52bf6307, 0x52c062f1, 0x52c162f7, 0x52c262ef, 0x52c362ec, 0x52c462fe, 0x52c562f4,
+    0x52c66311, 0x52c76302, 0x52c8653f, 0x52c96545, 0x52ca65ab, 0x52cb65bd, 0x52cc65e2, 0x52cd6625,
+    0x52ce662d, 0x52cf6620, 0x52d06627, 0x52d1662f, 0x52d2661f, 0x52d36628, 0x52d46631, 0x52d56624,
+    0x52d666f7, 0x52d767ff, 0x52d867d3, 0x52d967f1, 0x52da67d4, 0x52db67d0, 0x52dc67ec, 0x52dd67b6,
+    0x52de67af, 0x52df67f5, 0x52e067e9, 0x52e167ef, 0x52e267c4, 0x52e367d1, 0x52e467b4, 0x52e567da,
+    0x52e667e5, 0x52e767b8, 0x52e867cf, 0x52e967de, 0x52ea67f3, 0x52eb67b0, 0x52ec67d9, 0x52ed67e2,
+    0x52ee67dd, 0x52ef67d2, 0x52f06b6a, 0x52f16b83, 0x52f26b86, 0x52f36bb5, 0x52f46bd2, 0x52f56bd7,
+    0x52f66c1f, 0x52f76cc9, 0x52f86d0b, 0x52f96d32, 0x52fa6d2a, 0x52fb6d41, 0x52fc6d25, 0x52fd6d0c,
+    0x53416d31, 0x53426d1e, 0x53436d17, 0x53446d3b, 0x53456d3d, 0x53466d3e, 0x53476d36, 0x53486d1b,
+    0x53496cf5, 0x534a6d39, 0x534b6d27, 0x534c6d38, 0x534d6d29, 0x534e6d2e, 0x534f6d35, 0x53506d0e,
+    0x53516d2b, 0x535270ab, 0x535370ba, 0x535470b3, 0x535570ac, 0x535670af, 0x535770ad, 0x535870b8,
+    0x535970ae, 0x535a70a4, 0x535b7230, 0x535c7272, 0x535d726f, 0x535e7274, 0x535f72e9, 0x536072e0,
+    0x536172e1, 0x536273b7, 0x536373ca, 0x536473bb, 0x536573b2, 0x536673cd, 0x536773c0, 0x536873b3,
+    0x5369751a, 0x536a752d, 0x536b754f, 0x536c754c, 0x536d754e, 0x536e754b, 0x536f75ab, 0x537075a4,
+    0x537175a5, 0x537275a2, 0x537375a3, 0x53747678, 0x53757686, 0x53767687, 0x53777688, 0x537876c8,
+    0x537976c6, 0x537a76c3, 0x537b76c5, 0x537c7701, 0x537d76f9, 0x537e76f8, 0x537f7709, 0x5381770b,
+    0x538276fe, 0x538376fc, 0x53847707, 0x538577dc, 0x53867802, 0x53877814, 0x5388780c, 0x5389780d,
+    0x538a7946, 0x538b7949, 0x538c7948, 0x538d7947, 0x538e79b9, 0x538f79ba, 0x539079d1, 0x539179d2,
+    0x539279cb, 0x53937a7f, 0x53947a81, 0x53957aff, 0x53967afd, 0x53977c7d, 0x53987d02, 0x53997d05,
+    0x539a7d00, 0x539b7d09, 0x539c7d07, 0x539d7d04, 0x539e7d06, 0x539f7f38, 0x53a07f8e, 0x53a17fbf,
+    0x53a28004, 0x53a38010, 0x53a4800d, 0x53a58011, 0x53a68036, 0x53a780d6, 0x53a880e5, 0x53a980da,
+    0x53aa80c3, 0x53ab80c4, 0x53ac80cc, 0x53ad80e1, 0x53ae80db, 0x53af80ce, 0x53b080de, 0x53b180e4,
+    0x53b280dd, 0x53b381f4, 0x53b48222, 0x53b582e7, 0x53b68303, 0x53b78305, 0x53b882e3, 0x53b982db,
+    0x53ba82e6, 0x53bb8304, 0x53bc82e5, 0x53bd8302, 0x53be8309, 0x53bf82d2, 0x53c082d7, 0x53c182f1,
+    0x53c28301, 0x53c382dc, 0x53c482d4, 0x53c582d1, 0x53c682de, 0x53c782d3, 0x53c882df, 0x53c982ef,
+    0x53ca8306, 0x53cb8650, 0x53cc8679, 0x53cd867b, 0x53ce867a, 0x53cf884d, 0x53d0886b, 0x53d18981,
+    0x53d289d4, 0x53d38a08, 0x53d48a02, 0x53d58a03, 0x53d68c9e, 0x53d78ca0, 0x53d88d74, 0x53d98d73,
+    0x53da8db4, 0x53db8ecd, 0x53dc8ecc, 0x53dd8ff0, 0x53de8fe6, 0x53df8fe2, 0x53e08fea, 0x53e18fe5,
+    0x53e28fed, 0x53e38feb, 0x53e48fe4, 0x53e58fe8, 0x53e690ca, 0x53e790ce, 0x53e890c1, 0x53e990c3,
+    0x53ea914b, 0x53eb914a, 0x53ec91cd, 0x53ed9582, 0x53ee9650, 0x53ef964b, 0x53f0964c, 0x53f1964d,
+    0x53f29762, 0x53f39769, 0x53f497cb, 0x53f597ed, 0x53f697f3, 0x53f79801, 0x53f898a8, 0x53f998db,
+    0x53fa98df, 0x53fb9996, 0x53fc9999, 0x53fd4e58, 0x54414eb3, 0x5442500c, 0x5443500d, 0x54445023,
+    0x54454fef, 0x54465026, 0x54475025, 0x54484ff8, 0x54495029, 0x544a5016, 0x544b5006, 0x544c503c,
+    0x544d501f, 0x544e501a, 0x544f5012, 0x54505011, 0x54514ffa, 0x54525000, 0x54535014, 0x54545028,
+    0x54554ff1, 0x54565021, 0x5457500b, 0x54585019, 0x54595018, 0x545a4ff3, 0x545b4fee, 0x545c502d,
+    0x545d502a, 0x545e4ffe, 0x545f502b, 0x54605009, 0x5461517c, 0x546251a4, 0x546351a5, 0x546451a2,
+    0x546551cd, 0x546651cc, 0x546751c6, 0x546851cb, 0x54695256, 0x546a525c, 0x546b5254, 0x546c525b,
+    0x546d525d, 0x546e532a, 0x546f537f, 0x5470539f, 0x5471539d, 0x547253df, 0x547354e8, 0x54745510,
+    0x54755501, 0x54765537, 0x547754fc, 0x547854e5, 0x547954f2, 0x547a5506, 0x547b54fa, 0x547c5514,
+    0x547d54e9, 0x547e54ed, 0x547f54e1, 0x54815509, 0x548254ee, 0x548354ea, 0x548454e6, 0x54855527,
+    0x54865507, 0x548754fd, 0x5488550f, 0x54895703, 0x548a5704, 0x548b57c2, 0x548c57d4, 0x548d57cb,
+    0x548e57c3, 0x548f5809, 0x5490590f, 0x54915957, 0x54925958, 0x5493595a, 0x54945a11, 0x54955a18,
+    0x54965a1c, 0x54975a1f, 0x54985a1b, 0x54995a13, 0x549a59ec, 0x549b5a20, 0x549c5a23, 0x549d5a29,
+    0x549e5a25, 0x549f5a0c, 0x54a05a09, 0x54a15b6b, 0x54a25c58, 0x54a35bb0, 0x54a45bb3, 0x54a55bb6,
+    0x54a65bb4, 0x54a75bae, 0x54a85bb5, 0x54a95bb9, 0x54aa5bb8, 0x54ab5c04, 0x54ac5c51, 0x54ad5c55,
+    0x54ae5c50, 0x54af5ced, 0x54b05cfd, 0x54b15cfb, 0x54b25cea, 0x54b35ce8, 0x54b45cf0, 0x54b55cf6,
+    0x54b65d01, 0x54b75cf4, 0x54b85dee, 0x54b95e2d, 0x54ba5e2b, 0x54bb5eab, 0x54bc5ead, 0x54bd5ea7,
+    0x54be5f31, 0x54bf5f92, 0x54c05f91, 0x54c15f90, 0x54c26059, 0x54c36063, 0x54c46065, 0x54c56050,
+    0x54c66055, 0x54c7606d, 0x54c86069, 0x54c9606f, 0x54ca6084, 0x54cb609f, 0x54cc609a, 0x54cd608d,
+    0x54ce6094, 0x54cf608c, 0x54d06085, 0x54d16096, 0x54d26247, 0x54d362f3, 0x54d46308, 0x54d562ff,
+    0x54d6634e, 0x54d7633e, 0x54d8632f, 0x54d96355, 0x54da6342, 0x54db6346, 0x54dc634f, 0x54dd6349,
+    0x54de633a, 0x54df6350, 0x54e0633d, 0x54e1632a, 0x54e2632b, 0x54e36328, 0x54e4634d, 0x54e5634c,
+    0x54e66548, 0x54e76549, 0x54e86599, 0x54e965c1, 0x54ea65c5, 0x54eb6642, 0x54ec6649, 0x54ed664f,
+    0x54ee6643, 0x54ef6652, 0x54f0664c, 0x54f16645, 0x54f26641, 0x54f366f8, 0x54f46714, 0x54f56715,
+    0x54f66717, 0x54f76821, 0x54f86838, 0x54f96848, 0x54fa6846, 0x54fb6853, 0x54fc6839, 0x54fd6842,
+    0x55416854, 0x55426829, 0x554368b3, 0x55446817, 0x5545684c, 0x55466851, 0x5547683d, 0x554867f4,
+    0x55496850, 0x554a6840, 0x554b683c, 0x554c6843, 0x554d682a, 0x554e6845, 0x554f6813, 0x55506818,
+    0x55516841, 0x55526b8a, 0x55536b89, 0x55546bb7, 0x55556c23, 0x55566c27, 0x55576c28, 0x55586c26,
+    0x55596c24, 0x555a6cf0, 0x555b6d6a, 0x555c6d95, 0x555d6d88, 0x555e6d87, 0x555f6d66, 0x55606d78,
+    0x55616d77, 0x55626d59, 0x55636d93, 0x55646d6c, 0x55656d89, 0x55666d6e, 0x55676d5a, 0x55686d74,
+    0x55696d69, 0x556a6d8c, 0x556b6d8a, 0x556c6d79, 0x556d6d85, 0x556e6d65, 0x556f6d94, 0x557070ca,
+    0x557170d8, 0x557270e4, 0x557370d9, 0x557470c8, 0x557570cf, 0x55767239, 0x55777279, 0x557872fc,
+    0x557972f9, 0x557a72fd, 0x557b72f8, 0x557c72f7, 0x557d7386, 0x557e73ed, 0x557f7409, 0x558173ee,
+    0x558273e0, 0x558373ea, 0x558473de, 0x55857554, 0x5586755d, 0x5587755c, 0x5588755a, 0x55897559,
+    0x558a75be, 0x558b75c5, 0x558c75c7, 0x558d75b2, 0x558e75b3, 0x558f75bd, 0x559075bc, 0x559175b9,
+    0x559275c2, 0x559375b8, 0x5594768b, 0x559576b0, 0x559676ca, 0x559776cd, 0x559876ce, 0x55997729,
+    0x559a771f, 0x559b7720, 0x559c7728, 0x559d77e9, 0x559e7830, 0x559f7827, 0x55a07838, 0x55a1781d,
+    0x55a27834, 0x55a37837, 0x55a47825, 0x55a5782d, 0x55a67820, 0x55a7781f, 0x55a87832, 0x55a97955,
+    0x55aa7950, 0x55ab7960, 0x55ac795f, 0x55ad7956, 0x55ae795e, 0x55af795d, 0x55b07957, 0x55b1795a,
+    0x55b279e4, 0x55b379e3, 0x55b479e7, 0x55b579df, 0x55b679e6, 0x55b779e9, 0x55b879d8, 0x55b97a84,
+    0x55ba7a88, 0x55bb7ad9, 0x55bc7b06, 0x55bd7b11, 0x55be7c89, 0x55bf7d21, 0x55c07d17, 0x55c17d0b,
+    0x55c27d0a, 0x55c37d20, 0x55c47d22, 0x55c57d14, 0x55c67d10, 0x55c77d15, 0x55c87d1a, 0x55c97d1c,
+    0x55ca7d0d, 0x55cb7d19, 0x55cc7d1b, 0x55cd7f3a, 0x55ce7f5f, 0x55cf7f94, 0x55d07fc5, 0x55d17fc1,
+    0x55d28006, 0x55d38018, 0x55d48015, 0x55d58019, 0x55d68017, 0x55d7803d, 0x55d8803f, 0x55d980f1,
+    0x55da8102, 0x55db80f0, 0x55dc8105, 0x55dd80ed, 0x55de80f4, 0x55df8106, 0x55e080f8, 0x55e180f3,
+    0x55e28108, 0x55e380fd, 0x55e4810a, 0x55e580fc, 0x55e680ef, 0x55e781ed, 0x55e881ec, 0x55e98200,
+    0x55ea8210, 0x55eb822a, 0x55ec822b, 0x55ed8228, 0x55ee822c, 0x55ef82bb, 0x55f0832b, 0x55f18352,
+    0x55f28354, 0x55f3834a, 0x55f48338, 0x55f58350, 0x55f68349, 0x55f78335, 0x55f88334, 0x55f9834f,
+    0x55fa8332, 0x55fb8339, 0x55fc8336, 0x55fd8317, 0x56418340, 0x56428331, 0x56438328, 0x56448343,
+    0x56458654, 0x5646868a, 0x564786aa, 0x56488693, 0x564986a4, 0x564a86a9, 0x564b868c, 0x564c86a3,
+    0x564d869c, 0x564e8870, 0x564f8877, 0x56508881, 0x56518882, 0x5652887d, 0x56538879, 0x56548a18,
+    0x56558a10, 0x56568a0e, 0x56578a0c, 0x56588a15, 0x56598a0a, 0x565a8a17, 0x565b8a13, 0x565c8a16,
+    0x565d8a0f, 0x565e8a11, 0x565f8c48, 0x56608c7a, 0x56618c79, 0x56628ca1, 0x56638ca2, 0x56648d77,
+    0x56658eac, 0x56668ed2, 0x56678ed4, 0x56688ecf, 0x56698fb1, 0x566a9001, 0x566b9006, 0x566c8ff7,
+    0x566d9000, 0x566e8ffa, 0x566f8ff4, 0x56709003, 0x56718ffd, 0x56729005, 0x56738ff8, 0x56749095,
+    0x567590e1, 0x567690dd, 0x567790e2, 0x56789152, 0x5679914d, 0x567a914c, 0x567b91d8, 0x567c91dd,
+    0x567d91d7, 0x567e91dc, 0x567f91d9, 0x56819583, 0x56829662, 0x56839663, 0x56849661, 0x5685965b,
+    0x5686965d, 0x56879664, 0x56889658, 0x5689965e, 0x568a96bb, 0x568b98e2, 0x568c99ac, 0x568d9aa8,
+    0x568e9ad8, 0x568f9b25, 0x56909b32, 0x56919b3c, 0x56924e7e, 0x5693507a, 0x5694507d, 0x5695505c,
+    0x56965047, 0x56975043, 0x5698504c, 0x5699505a, 0x569a5049, 0x569b5065, 0x569c5076, 0x569d504e,
+    0x569e5055, 0x569f5075, 0x56a05074, 0x56a15077, 0x56a2504f, 0x56a3500f, 0x56a4506f, 0x56a5506d,
+    0x56a6515c, 0x56a75195, 0x56a851f0, 0x56a9526a, 0x56aa526f, 0x56ab52d2, 0x56ac52d9, 0x56ad52d8,
+    0x56ae52d5, 0x56af5310, 0x56b0530f, 0x56b15319, 0x56b2533f, 0x56b35340, 0x56b4533e, 0x56b553c3,
+    0x56b666fc, 0x56b75546, 0x56b8556a, 0x56b95566, 0x56ba5544, 0x56bb555e, 0x56bc5561, 0x56bd5543,
+    0x56be554a, 0x56bf5531, 0x56c05556, 0x56c1554f, 0x56c25555, 0x56c3552f, 0x56c45564, 0x56c55538,
+    0x56c6552e, 0x56c7555c, 0x56c8552c, 0x56c95563, 0x56ca5533, 0x56cb5541, 0x56cc5557, 0x56cd5708,
+    0x56ce570b, 0x56cf5709, 0x56d057df, 0x56d15805, 0x56d2580a, 0x56d35806, 0x56d457e0, 0x56d557e4,
+    0x56d657fa, 0x56d75802, 0x56d85835, 0x56d957f7, 0x56da57f9, 0x56db5920, 0x56dc5962, 0x56dd5a36,
+    0x56de5a41, 0x56df5a49, 0x56e05a66, 0x56e15a6a, 0x56e25a40, 0x56e35a3c, 0x56e45a62, 0x56e55a5a,
+    0x56e65a46, 0x56e75a4a, 0x56e85b70, 0x56e95bc7, 0x56ea5bc5, 0x56eb5bc4, 0x56ec5bc2, 0x56ed5bbf,
+    0x56ee5bc6, 0x56ef5c09, 0x56f05c08, 0x56f15c07, 0x56f25c60, 0x56f35c5c, 0x56f45c5d, 0x56f55d07,
+    0x56f65d06, 0x56f75d0e, 0x56f85d1b, 0x56f95d16, 0x56fa5d22, 0x56fb5d11, 0x56fc5d29, 0x56fd5d14,
+    0x57415d19, 0x57425d24, 0x57435d27, 0x57445d17, 0x57455de2, 0x57465e38, 0x57475e36, 0x57485e33,
+    0x57495e37, 0x574a5eb7, 0x574b5eb8, 0x574c5eb6, 0x574d5eb5, 0x574e5ebe, 0x574f5f35, 0x57505f37,
+    0x57515f57, 0x57525f6c, 0x57535f69, 0x57545f6b, 0x57555f97, 0x57565f99, 0x57575f9e, 0x57585f98,
+    0x57595fa1, 0x575a5fa0, 0x575b5f9c, 0x575c607f, 0x575d60a3, 0x575e6089, 0x575f60a0, 0x576060a8,
+    0x576160cb, 0x576260b4, 0x576360e6, 0x576460bd, 0x576560c5, 0x576660bb, 0x576760b5, 0x576860dc,
+    0x576960bc, 0x576a60d8, 0x576b60d5, 0x576c60c6, 0x576d60df, 0x576e60b8, 0x576f60da, 0x577060c7,
+    0x5771621a, 0x5772621b, 0x57736248, 0x577463a0, 0x577563a7, 0x57766372, 0x57776396, 0x577863a2,
+    0x577963a5, 0x577a6377, 0x577b6367, 0x577c6398, 0x577d63aa, 0x577e6371, 0x577f63a9, 0x57816389,
+    0x57826383, 0x5783639b, 0x5784636b, 0x578563a8, 0x57866384, 0x57876388, 0x57886399, 0x578963a1,
+    0x578a63ac, 0x578b6392, 0x578c638f, 0x578d6380, 0x578e637b, 0x578f6369, 0x57906368, 0x5791637a,
+    0x5792655d, 0x57936556, 0x57946551, 0x57956559, 0x57966557, 0x5797555f, 0x5798654f, 0x57996558,
+    0x579a6555, 0x579b6554, 0x579c659c, 0x579d659b, 0x579e65ac, 0x579f65cf, 0x57a065cb, 0x57a165cc,
+    0x57a265ce, 0x57a3665d, 0x57a4665a, 0x57a56664, 0x57a66668, 0x57a76666, 0x57a8665e, 0x57a966f9,
+    0x57aa52d7, 0x57ab671b, 0x57ac6881, 0x57ad68af, 0x57ae68a2, 0x57af6893, 0x57b068b5, 0x57b1687f,
+    0x57b26876, 0x57b368b1, 0x57b468a7, 0x57b56897, 0x57b668b0, 0x57b76883, 0x57b868c4, 0x57b968ad,
+    0x57ba6886, 0x57bb6885, 0x57bc6894, 0x57bd689d, 0x57be68a8, 0x57bf689f, 0x57c068a1, 0x57c16882,
+    0x57c26b32, 0x57c36bba, 0x57c46beb, 0x57c56bec, 0x57c66c2b, 0x57c76d8e, 0x57c86dbc, 0x57c96df3,
+    0x57ca6dd9, 0x57cb6db2, 0x57cc6de1, 0x57cd6dcc, 0x57ce6de4, 0x57cf6dfb, 0x57d06dfa, 0x57d16e05,
+    0x57d26dc7, 0x57d36dcb, 0x57d46daf, 0x57d56dd1, 0x57d66dae, 0x57d76dde, 0x57d86df9, 0x57d96db8,
+    0x57da6df7, 0x57db6df5, 0x57dc6dc5, 0x57dd6dd2, 0x57de6e1a, 0x57df6db5, 0x57e06dda, 0x57e16deb,
+    0x57e26dd8, 0x57e36dea, 0x57e46df1, 0x57e56dee, 0x57e66de8, 0x57e76dc6, 0x57e86dc4, 0x57e96daa,
+    0x57ea6dec, 0x57eb6dbf, 0x57ec6de6, 0x57ed70f9, 0x57ee7109, 0x57ef710a, 0x57f070fd, 0x57f170ef,
+    0x57f2723d, 0x57f3727d, 0x57f47281, 0x57f5731c, 0x57f6731b, 0x57f77316, 0x57f87313, 0x57f97319,
+    0x57fa7387, 0x57fb7405, 0x57fc740a, 0x57fd7403, 0x58417406, 0x584273fe, 0x5843740d, 0x584474e0,
+    0x584574f6, 0x584674f7, 0x5847751c, 0x58487522, 0x58497565, 0x584a7566, 0x584b7562, 0x584c7570,
+    0x584d758f, 0x584e75d4, 0x584f75d5, 0x585075b5, 0x585175ca, 0x585275cd, 0x5853768e, 0x585476d4,
+    0x585576d2, 0x585676db, 0x58577737, 0x5858773e, 0x5859773c, 0x585a7736, 0x585b7738, 0x585c773a,
+    0x585d786b, 0x585e7843, 0x585f784e, 0x58607965, 0x58617968, 0x5862796d, 0x586379fb, 0x58647a92,
+    0x58657a95, 0x58667b20, 0x58677b28, 0x58687b1b, 0x58697b2c, 0x586a7b26, 0x586b7b19, 0x586c7b1e,
+    0x586d7b2e, 0x586e7c92, 0x586f7c97, 0x58707c95, 0x58717d46, 0x58727d43, 0x58737d71, 0x58747d2e,
+    0x58757d39, 0x58767d3c, 0x58777d40, 0x58787d30, 0x58797d33, 0x587a7d44, 0x587b7d2f, 0x587c7d42,
+    0x587d7d32, 0x587e7d31, 0x587f7f3d, 0x58817f9e, 0x58827f9a, 0x58837fcc, 0x58847fce, 0x58857fd2,
+    0x5886801c, 0x5887804a, 0x58888046, 0x5889812f, 0x588a8116, 0x588b8123, 0x588c812b, 0x588d8129,
+    0x588e8130, 0x588f8124, 0x58908202, 0x58918235, 0x58928237, 0x58938236, 0x58948239, 0x5895838e,
+    0x5896839e, 0x58978398, 0x58988378, 0x589983a2, 0x589a8396, 0x589b83bd, 0x589c83ab, 0x589d8392,
+    0x589e838a, 0x589f8393, 0x58a08389, 0x58a183a0, 0x58a28377, 0x58a3837b, 0x58a4837c, 0x58a58386,
+    0x58a683a7, 0x58a78655, 0x58a85f6a, 0x58a986c7, 0x58aa86c0, 0x58ab86b6, 0x58ac86c4, 0x58ad86b5,
+    0x58ae86c6, 0x58af86cb, 0x58b086b1, 0x58b186af, 0x58b286c9, 0x58b38853, 0x58b4889e, 0x58b58888,
+    0x58b688ab, 0x58b78892, 0x58b88896, 0x58b9888d, 0x58ba888b, 0x58bb8993, 0x58bc898f, 0x58bd8a2a,
+    0x58be8a1d, 0x58bf8a23, 0x58c08a25, 0x58c18a31, 0x58c28a2d, 0x58c38a1f, 0x58c48a1b, 0x58c58a22,
+    0x58c68c49, 0x58c78c5a, 0x58c88ca9, 0x58c98cac, 0x58ca8cab, 0x58cb8ca8, 0x58cc8caa, 0x58cd8ca7,
+    0x58ce8d67, 0x58cf8d66, 0x58d08dbe, 0x58d18dba, 0x58d28edb, 0x58d38edf, 0x58d49019, 0x58d5900d,
+    0x58d6901a, 0x58d79017, 0x58d89023, 0x58d9901f, 0x58da901d, 0x58db9010, 0x58dc9015, 0x58dd901e,
+    0x58de9020, 0x58df900f, 0x58e09022, 0x58e19016, 0x58e2901b, 0x58e39014, 0x58e490e8, 0x58e590ed,
+    0x58e690fd, 0x58e79157, 0x58e891ce, 0x58e991f5, 0x58ea91e6, 0x58eb91e3, 0x58ec91e7, 0x58ed91ed,
+    0x58ee91e9, 0x58ef9589, 0x58f0966a, 0x58f19675, 0x58f29673, 0x58f39678, 0x58f49670, 0x58f59674,
+    0x58f69676, 0x58f79677, 0x58f8966c, 0x58f996c0, 0x58fa96ea, 0x58fb96e9, 0x58fc7ae0, 0x58fd7adf,
+    0x59419802, 0x59429803, 0x59439b5a, 0x59449ce5, 0x59459e75, 0x59469e7f, 0x59479ea5, 0x59489ebb,
+    0x594950a2, 0x594a508d, 0x594b5085, 0x594c5099, 0x594d5091, 0x594e5080, 0x594f5096, 0x59505098,
+    0x5951509a, 0x59526700, 0x595351f1, 0x59545272, 0x59555274, 0x59565275, 0x59575269, 0x595852de,
+    0x595952dd, 0x595a52db, 0x595b535a, 0x595c53a5, 0x595d557b, 0x595e5580, 0x595f55a7, 0x5960557c,
+    0x5961558a, 0x5962559d, 0x59635598, 0x59645582, 0x5965559c, 0x596655aa, 0x59675594, 0x59685587,
+    0x5969558b, 0x596a5583, 0x596b55b3, 0x596c55ae, 0x596d559f, 0x596e553e, 0x596f55b2, 0x5970559a,
+    0x597155bb, 0x597255ac, 0x597355b1, 0x5974557e, 0x59755589, 0x597655ab, 0x59775599, 0x5978570d,
+    0x5979582f, 0x597a582a, 0x597b5834, 0x597c5824, 0x597d5830, 0x597e5831, 0x597f5821, 0x5981581d,
+    0x59825820, 0x598358f9, 0x598458fa, 0x59855960, 0x59865a77, 0x59875a9a, 0x59885a7f, 0x59895a92,
+    0x598a5a9b, 0x598b5aa7, 0x598c5b73, 0x598d5b71, 0x598e5bd2, 0x598f5bcc, 0x59905bd3, 0x59915bd0,
+    0x59925c0a, 0x59935c0b, 0x59945c31, 0x59955d4c, 0x59965d50, 0x59975d34, 0x59985d47, 0x59995dfd,
+    0x599a5e45, 0x599b5e3d, 0x599c5e40, 0x599d5e43, 0x599e5e7e, 0x599f5eca, 0x59a05ec1, 0x59a15ec2,
+    0x59a25ec4, 0x59a35f3c, 0x59a45f6d, 0x59a55fa9, 0x59a65faa, 0x59a75fa8, 0x59a860d1, 0x59a960e1,
+    0x59aa60b2, 0x59ab60b6, 0x59ac60e0, 0x59ad611c, 0x59ae6123, 0x59af60fa, 0x59b06115, 0x59b160f0,
+    0x59b260fb, 0x59b360f4, 0x59b46168, 0x59b560f1, 0x59b6610e, 0x59b760f6, 0x59b86109, 0x59b96100,
+    0x59ba6112, 0x59bb621f, 0x59bc6249, 0x59bd63a3, 0x59be638c, 0x59bf63cf, 0x59c063c0, 0x59c163e9,
+    0x59c263c9, 0x59c363c6, 0x59c463cd, 0x59c563d2, 0x59c663e3, 0x59c763d0, 0x59c863e1, 0x59c963d6,
+    0x59ca63ed, 0x59cb63ee, 0x59cc6376, 0x59cd63f4, 0x59ce63ea, 0x59cf63db, 0x59d06452, 0x59d163da,
+    0x59d263f9, 0x59d3655e, 0x59d46566, 0x59d56562, 0x59d66563, 0x59d76591, 0x59d86590, 0x59d965af,
+    0x59da666e, 0x59db6670, 0x59dc6674, 0x59dd6676, 0x59de666f, 0x59df6691, 0x59e0667a, 0x59e1667e,
+    0x59e26677, 0x59e366fe, 0x59e466ff, 0x59e5671f, 0x59e6671d, 0x59e768fa, 0x59e868d5, 0x59e968e0,
+    0x59ea68d8, 0x59eb68d7, 0x59ec6905, 0x59ed68df, 0x59ee68f5, 0x59ef68ee, 0x59f068e7, 0x59f168f9,
+    0x59f268d2, 0x59f368f2, 0x59f468e3, 0x59f568cb, 0x59f668cd, 0x59f7690d, 0x59f86912, 0x59f9690e,
+    0x59fa68c9, 0x59fb68da, 0x59fc696e, 0x59fd68fb, 0x5a416b3e, 0x5a426b3a, 0x5a436b3d, 0x5a446b98,
+    0x5a456b96, 0x5a466bbc, 0x5a476bef, 0x5a486c2e, 0x5a496c2f, 0x5a4a6c2c, 0x5a4b6e2f, 0x5a4c6e38,
+    0x5a4d6e54, 0x5a4e6e21, 0x5a4f6e32, 0x5a506e67, 0x5a516e4a, 0x5a526e20, 0x5a536e25, 0x5a546e23,
+    0x5a556e1b, 0x5a566e5b, 0x5a576e58, 0x5a586e24, 0x5a596e56, 0x5a5a6e6e, 0x5a5b6e2d, 0x5a5c6e26,
+    0x5a5d6e6f, 0x5a5e6e34, 0x5a5f6e4d, 0x5a606e3a, 0x5a616e2c, 0x5a626e43, 0x5a636e1d, 0x5a646e3e,
+    0x5a656ecb, 0x5a666e89, 0x5a676e19, 0x5a686e4e, 0x5a696e63, 0x5a6a6e44, 0x5a6b6e72, 0x5a6c6e69,
+    0x5a6d6e5f, 0x5a6e7119, 0x5a6f711a, 0x5a707126, 0x5a717130, 0x5a727121, 0x5a737136, 0x5a74716e,
+    0x5a75711c, 0x5a76724c, 0x5a777284, 0x5a787280, 0x5a797336, 0x5a7a7325, 0x5a7b7334, 0x5a7c7329,
+    0x5a7d743a, 0x5a7e742a, 0x5a7f7433, 0x5a817422, 0x5a827425, 0x5a837435, 0x5a847436, 0x5a857434,
+    0x5a86742f, 0x5a87741b, 0x5a887426, 0x5a897428, 0x5a8a7525, 0x5a8b7526, 0x5a8c756b, 0x5a8d756a,
+    0x5a8e75e2, 0x5a8f75db, 0x5a9075e3, 0x5a9175d9, 0x5a9275d8, 0x5a9375de, 0x5a9475e0, 0x5a95767b,
+    0x5a96767c, 0x5a977696, 0x5a987693, 0x5a9976b4, 0x5a9a76dc, 0x5a9b774f, 0x5a9c77ed, 0x5a9d785d,
+    0x5a9e786c, 0x5a9f786f, 0x5aa07a0d, 0x5aa17a08, 0x5aa27a0b, 0x5aa37a05, 0x5aa47a00, 0x5aa57a98,
+    0x5aa67a97, 0x5aa77a96, 0x5aa87ae5, 0x5aa97ae3, 0x5aaa7b49, 0x5aab7b56, 0x5aac7b46, 0x5aad7b50,
+    0x5aae7b52, 0x5aaf7b54, 0x5ab07b4d, 0x5ab17b4b, 0x5ab27b4f, 0x5ab37b51, 0x5ab47c9f, 0x5ab57ca5,
+    0x5ab67d5e, 0x5ab77d50, 0x5ab87d68, 0x5ab97d55, 0x5aba7d2b, 0x5abb7d6e, 0x5abc7d72, 0x5abd7d61,
+    0x5abe7d66, 0x5abf7d62, 0x5ac07d70, 0x5ac17d73, 0x5ac25584, 0x5ac37fd4, 0x5ac47fd5, 0x5ac5800b,
+    0x5ac68052, 0x5ac78085, 0x5ac88155, 0x5ac98154, 0x5aca814b, 0x5acb8151, 0x5acc814e, 0x5acd8139,
+    0x5ace8146, 0x5acf813e, 0x5ad0814c, 0x5ad18153, 0x5ad28174, 0x5ad38212, 0x5ad4821c, 0x5ad583e9,
+    0x5ad68403, 0x5ad783f8, 0x5ad8840d, 0x5ad983e0, 0x5ada83c5, 0x5adb840b, 0x5adc83c1, 0x5add83ef,
+    0x5ade83f1, 0x5adf83f4, 0x5ae08457, 0x5ae1840a, 0x5ae283f0, 0x5ae3840c, 0x5ae483cc, 0x5ae583fd,
+    0x5ae683f2, 0x5ae783ca, 0x5ae88438, 0x5ae9840e, 0x5aea8404, 0x5aeb83dc, 0x5aec8407, 0x5aed83d4,
+    0x5aee83df, 0x5aef865b, 0x5af086df, 0x5af186d9, 0x5af286ed, 0x5af386d4, 0x5af486db, 0x5af586e4,
+    0x5af686d0, 0x5af786de, 0x5af88857, 0x5af988c1, 0x5afa88c2, 0x5afb88b1, 0x5afc8983, 0x5afd8996,
+    0x5b418a3b, 0x5b428a60, 0x5b438a55, 0x5b448a5e, 0x5b458a3c, 0x5b468a41, 0x5b478a54, 0x5b488a5b,
+    0x5b498a50, 0x5b4a8a46, 0x5b4b8a34, 0x5b4c8a3a, 0x5b4d8a36, 0x5b4e8a56, 0x5b4f8c61, 0x5b508c82,
+    0x5b518caf, 0x5b528cbc, 0x5b538cb3, 0x5b548cbd, 0x5b558cc1, 0x5b568cbb, 0x5b578cc0, 0x5b588cb4,
+    0x5b598cb7, 0x5b5a8cb6, 0x5b5b8cbf, 0x5b5c8cb8, 0x5b5d8d8a, 0x5b5e8d85, 0x5b5f8d81, 0x5b608dce,
+    0x5b618ddd, 0x5b628dcb, 0x5b638dda, 0x5b648dd1, 0x5b658dcc, 0x5b668ddb, 0x5b678dc6, 0x5b688efb,
+    0x5b698ef8, 0x5b6a8efc, 0x5b6b8f9c, 0x5b6c902e, 0x5b6d9035, 0x5b6e9031, 0x5b6f9038, 0x5b709032,
+    0x5b719036, 0x5b729102, 0x5b7390f5, 0x5b749109, 0x5b7590fe, 0x5b769163, 0x5b779165, 0x5b7891cf,
+    0x5b799214, 0x5b7a9215, 0x5b7b9223, 0x5b7c9209, 0x5b7d921e, 0x5b7e920d, 0x5b7f9210, 0x5b819207,
+    0x5b829211, 0x5b839594, 0x5b84958f, 0x5b85958b, 0x5b869591, 0x5b879593, 0x5b889592, 0x5b89958e,
+    0x5b8a968a, 0x5b8b968e, 0x5b8c968b, 0x5b8d967d, 0x5b8e9685, 0x5b8f9686, 0x5b90968d, 0x5b919672,
+    0x5b929684, 0x5b9396c1, 0x5b9496c5, 0x5b9596c4, 0x5b9696c6, 0x5b9796c7, 0x5b9896ef, 0x5b9996f2,
+    0x5b9a97cc, 0x5b9b9805, 0x5b9c9806, 0x5b9d9808, 0x5b9e98e7, 0x5b9f98ea, 0x5ba098ef, 0x5ba198e9,
+    0x5ba298f2, 0x5ba398ed, 0x5ba499ae, 0x5ba599ad, 0x5ba69ec3, 0x5ba79ecd, 0x5ba89ed1, 0x5ba94e82,
+    0x5baa50ad, 0x5bab50b5, 0x5bac50b2, 0x5bad50b3, 0x5bae50c5, 0x5baf50be, 0x5bb050ac, 0x5bb150b7,
+    0x5bb250bb, 0x5bb350af, 0x5bb450c7, 0x5bb5527f, 0x5bb65277, 0x5bb7527d, 0x5bb852df, 0x5bb952e6,
+    0x5bba52e4, 0x5bbb52e2, 0x5bbc52e3, 0x5bbd532f, 0x5bbe55df, 0x5bbf55e8, 0x5bc055d3, 0x5bc155e6,
+    0x5bc255ce, 0x5bc355dc, 0x5bc455c7, 0x5bc555d1, 0x5bc655e3, 0x5bc755e4, 0x5bc855ef, 0x5bc955da,
+    0x5bca55e1, 0x5bcb55c5, 0x5bcc55c6, 0x5bcd55e5, 0x5bce55c9, 0x5bcf5712, 0x5bd05713, 0x5bd1585e,
+    0x5bd25851, 0x5bd35858, 0x5bd45857, 0x5bd5585a, 0x5bd65854, 0x5bd7586b, 0x5bd8584c, 0x5bd9586d,
+    0x5bda584a, 0x5bdb5862, 0x5bdc5852, 0x5bdd584b, 0x5bde5967, 0x5bdf5ac1, 0x5be05ac9, 0x5be15acc,
+    0x5be25abe, 0x5be35abd, 0x5be45abc, 0x5be55ab3, 0x5be65ac2, 0x5be75ab2, 0x5be85d69, 0x5be95d6f,
+    0x5bea5e4c, 0x5beb5e79, 0x5bec5ec9, 0x5bed5ec8, 0x5bee5f12, 0x5bef5f59, 0x5bf05fac, 0x5bf15fae,
+    0x5bf2611a, 0x5bf3610f, 0x5bf46148, 0x5bf5611f, 0x5bf660f3, 0x5bf7611b, 0x5bf860f9, 0x5bf96101,
+    0x5bfa6108, 0x5bfb614e, 0x5bfc614c, 0x5bfd6144, 0x5c41614d, 0x5c42613e, 0x5c436134, 0x5c446127,
+    0x5c45610d, 0x5c466106, 0x5c476137, 0x5c486221, 0x5c496222, 0x5c4a6413, 0x5c4b643e, 0x5c4c641e,
+    0x5c4d642a, 0x5c4e642d, 0x5c4f643d, 0x5c50642c, 0x5c51640f, 0x5c52641c, 0x5c536414, 0x5c54640d,
+    0x5c556436, 0x5c566416, 0x5c576417, 0x5c586406, 0x5c59656c, 0x5c5a659f, 0x5c5b65b0, 0x5c5c6697,
+    0x5c5d6689, 0x5c5e6687, 0x5c5f6688, 0x5c606696, 0x5c616684, 0x5c626698, 0x5c63668d, 0x5c646703,
+    0x5c656994, 0x5c66696d, 0x5c67695a, 0x5c686977, 0x5c696960, 0x5c6a6954, 0x5c6b6975, 0x5c6c6930,
+    0x5c6d6982, 0x5c6e694a, 0x5c6f6968, 0x5c70696b, 0x5c71695e, 0x5c726953, 0x5c736979, 0x5c746986,
+    0x5c75695d, 0x5c766963, 0x5c77695b, 0x5c786b47, 0x5c796b72, 0x5c7a6bc0, 0x5c7b6bbf, 0x5c7c6bd3,
+    0x5c7d6bfd, 0x5c7e6ea2, 0x5c7f6eaf, 0x5c816ed3, 0x5c826eb6, 0x5c836ec2, 0x5c846e90, 0x5c856e9d,
+    0x5c866ec7, 0x5c876ec5, 0x5c886ea5, 0x5c896e98, 0x5c8a6ebc, 0x5c8b6eba, 0x5c8c6eab, 0x5c8d6ed1,
+    0x5c8e6e96, 0x5c8f6e9c, 0x5c906ec4, 0x5c916ed4, 0x5c926eaa, 0x5c936ea7, 0x5c946eb4, 0x5c95714e,
+    0x5c967159, 0x5c977169, 0x5c987164, 0x5c997149, 0x5c9a7167, 0x5c9b715c, 0x5c9c716c, 0x5c9d7166,
+    0x5c9e714c, 0x5c9f7165, 0x5ca0715e, 0x5ca17146, 0x5ca27168, 0x5ca37156, 0x5ca4723a, 0x5ca57252,
+    0x5ca67337, 0x5ca77345, 0x5ca8733f, 0x5ca9733e, 0x5caa746f, 0x5cab745a, 0x5cac7455, 0x5cad745f,
+    0x5cae745e, 0x5caf7441, 0x5cb0743f, 0x5cb17459, 0x5cb2745b, 0x5cb3745c, 0x5cb47576, 0x5cb57578,
+    0x5cb67600, 0x5cb775f0, 0x5cb87601, 0x5cb975f2, 0x5cba75f1, 0x5cbb75fa, 0x5cbc75ff, 0x5cbd75f4,
+    0x5cbe75f3, 0x5cbf76de, 0x5cc076df, 0x5cc1775b, 0x5cc2776b, 0x5cc37766, 0x5cc4775e, 0x5cc57763,
+    0x5cc67779, 0x5cc7776a, 0x5cc8776c, 0x5cc9775c, 0x5cca7765, 0x5ccb7768, 0x5ccc7762, 0x5ccd77ee,
+    0x5cce788e, 0x5ccf78b0, 0x5cd07897, 0x5cd17898, 0x5cd2788c, 0x5cd37889, 0x5cd4787c, 0x5cd57891,
+    0x5cd67893, 0x5cd7787f, 0x5cd8797a, 0x5cd9797f, 0x5cda7981, 0x5cdb842c, 0x5cdc79bd, 0x5cdd7a1c,
+    0x5cde7a1a, 0x5cdf7a20, 0x5ce07a14, 0x5ce17a1f, 0x5ce27a1e, 0x5ce37a9f, 0x5ce47aa0, 0x5ce57b77,
+    0x5ce67bc0, 0x5ce77b60, 0x5ce87b6e, 0x5ce97b67, 0x5cea7cb1, 0x5ceb7cb3, 0x5cec7cb5, 0x5ced7d93,
+    0x5cee7d79, 0x5cef7d91, 0x5cf07d81, 0x5cf17d8f, 0x5cf27d5b, 0x5cf37f6e, 0x5cf47f69, 0x5cf57f6a,
+    0x5cf67f72, 0x5cf77fa9, 0x5cf87fa8, 0x5cf97fa4, 0x5cfa8056, 0x5cfb8058, 0x5cfc8086, 0x5cfd8084,
+    0x5d418171, 0x5d428170, 0x5d438178, 0x5d448165, 0x5d45816e, 0x5d468173, 0x5d47816b, 0x5d488179,
+    0x5d49817a, 0x5d4a8166, 0x5d4b8205, 0x5d4c8247, 0x5d4d8482, 0x5d4e8477, 0x5d4f843d, 0x5d508431,
+    0x5d518475, 0x5d528466, 0x5d53846b, 0x5d548449, 0x5d55846c, 0x5d56845b, 0x5d57843c, 0x5d588435,
+    0x5d598461, 0x5d5a8463, 0x5d5b8469, 0x5d5c846d, 0x5d5d8446, 0x5d5e865e, 0x5d5f865c, 0x5d60865f,
+    0x5d6186f9, 0x5d628713, 0x5d638708, 0x5d648707, 0x5d658700, 0x5d6686fe, 0x5d6786fb, 0x5d688702,
+    0x5d698703, 0x5d6a8706, 0x5d6b870a, 0x5d6c8859, 0x5d6d88df, 0x5d6e88d4, 0x5d6f88d9, 0x5d7088dc,
+    0x5d7188d8, 0x5d7288dd, 0x5d7388e1, 0x5d7488ca, 0x5d7588d5, 0x5d7688d2, 0x5d77899c, 0x5d7889e3,
+    0x5d798a6b, 0x5d7a8a72, 0x5d7b8a73, 0x5d7c8a66, 0x5d7d8a69, 0x5d7e8a70, 0x5d7f8a87, 0x5d818a7c,
+    0x5d828a63, 0x5d838aa0, 0x5d848a71, 0x5d858a85, 0x5d868a6d, 0x5d878a62, 0x5d888a6e, 0x5d898a6c,
+    0x5d8a8a79, 0x5d8b8a7b, 0x5d8c8a3e, 0x5d8d8a68, 0x5d8e8c62, 0x5d8f8c8a, 0x5d908c89, 0x5d918cca,
+    0x5d928cc7, 0x5d938cc8, 0x5d948cc4, 0x5d958cb2, 0x5d968cc3, 0x5d978cc2, 0x5d988cc5, 0x5d998de1,
+    0x5d9a8ddf, 0x5d9b8de8, 0x5d9c8def, 0x5d9d8df3, 0x5d9e8dfa, 0x5d9f8dea, 0x5da08de4, 0x5da18de6,
+    0x5da28eb2, 0x5da38f03, 0x5da48f09, 0x5da58efe, 0x5da68f0a, 0x5da78f9f, 0x5da88fb2, 0x5da9904b,
+    0x5daa904a, 0x5dab9053, 0x5dac9042, 0x5dad9054, 0x5dae903c, 0x5daf9055, 0x5db09050, 0x5db19047,
+    0x5db2904f, 0x5db3904e, 0x5db4904d, 0x5db59051, 0x5db6903e, 0x5db79041, 0x5db89112, 0x5db99117,
+    0x5dba916c, 0x5dbb916a, 0x5dbc9169, 0x5dbd91c9, 0x5dbe9237, 0x5dbf9257, 0x5dc09238, 0x5dc1923d,
+    0x5dc29240, 0x5dc3923e, 0x5dc4925b, 0x5dc5924b, 0x5dc69264, 0x5dc79251, 0x5dc89234, 0x5dc99249,
+    0x5dca924d, 0x5dcb9245, 0x5dcc9239, 0x5dcd923f, 0x5dce925a, 0x5dcf9598, 0x5dd09698, 0x5dd19694,
+    0x5dd29695, 0x5dd396cd, 0x5dd496cb, 0x5dd596c9, 0x5dd696ca, 0x5dd796f7, 0x5dd896fb, 0x5dd996f9,
+    0x5dda96f6, 0x5ddb9756, 0x5ddc9774, 0x5ddd9776, 0x5dde9810, 0x5ddf9811, 0x5de09813, 0x5de1980a,
+    0x5de29812, 0x5de3980c, 0x5de498fc, 0x5de598f4, 0x5de698fd, 0x5de798fe, 0x5de899b3, 0x5de999b1,
+    0x5dea99b4, 0x5deb9ae1, 0x5dec9ce9, 0x5ded9e82, 0x5dee9f0e, 0x5def9f13, 0x5df09f20, 0x5df150e7,
+    0x5df250ee, 0x5df350e5, 0x5df450d6, 0x5df550ed, 0x5df650da, 0x5df750d5, 0x5df850cf, 0x5df950d1,
+    0x5dfa50f1, 0x5dfb50ce, 0x5dfc50e9, 0x5dfd5162, 0x5e4151f3, 0x5e425283, 0x5e435282, 0x5e445331,
+    0x5e4553ad, 0x5e4655fe, 0x5e475600, 0x5e48561b, 0x5e495617, 0x5e4a55fd, 0x5e4b5614, 0x5e4c5606,
+    0x5e4d5609, 0x5e4e560d, 0x5e4f560e, 0x5e5055f7, 0x5e515616, 0x5e52561f, 0x5e535608, 0x5e545610,
+    0x5e5555f6, 0x5e565718, 0x5e575716, 0x5e585875, 0x5e59587e, 0x5e5a5883, 0x5e5b5893, 0x5e5c588a,
+    0x5e5d5879, 0x5e5e5885, 0x5e5f587d, 0x5e6058fd, 0x5e615925, 0x5e625922, 0x5e635924, 0x5e64596a,
+    0x5e655969, 0x5e665ae1, 0x5e675ae6, 0x5e685ae9, 0x5e695ad7, 0x5e6a5ad6, 0x5e6b5ad8, 0x5e6c5ae3,
+    0x5e6d5b75, 0x5e6e5bde, 0x5e6f5be7, 0x5e705be1, 0x5e715be5, 0x5e725be6, 0x5e735be8, 0x5e745be2,
+    0x5e755be4, 0x5e765bdf, 0x5e775c0d, 0x5e785c62, 0x5e795d84, 0x5e7a5d87, 0x5e7b5e5b, 0x5e7c5e63,
+    0x5e7d5e55, 0x5e7e5e57, 0x5e7f5e54, 0x5e815ed3, 0x5e825ed6, 0x5e835f0a, 0x5e845f46, 0x5e855f70,
+    0x5e865fb9, 0x5e876147, 0x5e88613f, 0x5e89614b, 0x5e8a6177, 0x5e8b6162, 0x5e8c6163, 0x5e8d615f,
+    0x5e8e615a, 0x5e8f6158, 0x5e906175, 0x5e91622a, 0x5e926487, 0x5e936458, 0x5e946454, 0x5e9564a4,
+    0x5e966478, 0x5e97645f, 0x5e98647a, 0x5e996451, 0x5e9a6467, 0x5e9b6434, 0x5e9c646d, 0x5e9d647b,
+    0x5e9e6572, 0x5e9f65a1, 0x5ea065d7, 0x5ea165d6, 0x5ea266a2, 0x5ea366a8, 0x5ea4669d, 0x5ea5699c,
+    0x5ea669a8, 0x5ea76995, 0x5ea869c1, 0x5ea969ae, 0x5eaa69d3, 0x5eab69cb, 0x5eac699b, 0x5ead69b7,
+    0x5eae69bb, 0x5eaf69ab, 0x5eb069b4, 0x5eb169d0, 0x5eb269cd, 0x5eb369ad, 0x5eb469cc, 0x5eb569a6,
+    0x5eb669c3, 0x5eb769a3, 0x5eb86b49, 0x5eb96b4c, 0x5eba6c33, 0x5ebb6f33, 0x5ebc6f14, 0x5ebd6efe,
+    0x5ebe6f13, 0x5ebf6ef4, 0x5ec06f29, 0x5ec16f3e, 0x5ec26f20, 0x5ec36f2c, 0x5ec46f0f, 0x5ec56f02,
+    0x5ec66f22, 0x5ec76eff, 0x5ec86eef, 0x5ec96f06, 0x5eca6f31, 0x5ecb6f38, 0x5ecc6f32, 0x5ecd6f23,
+    0x5ece6f15, 0x5ecf6f2b, 0x5ed06f2f, 0x5ed16f88, 0x5ed26f2a, 0x5ed36eec, 0x5ed46f01, 0x5ed56ef2,
+    0x5ed66ecc, 0x5ed76ef7, 0x5ed87194, 0x5ed97199, 0x5eda717d, 0x5edb718a, 0x5edc7184, 0x5edd7192,
+    0x5ede723e, 0x5edf7292, 0x5ee07296, 0x5ee17344, 0x5ee27350, 0x5ee37464, 0x5ee47463, 0x5ee5746a,
+    0x5ee67470, 0x5ee7746d, 0x5ee87504, 0x5ee97591, 0x5eea7627, 0x5eeb760d, 0x5eec760b, 0x5eed7609,
+    0x5eee7613, 0x5eef76e1, 0x5ef076e3, 0x5ef17784, 0x5ef2777d, 0x5ef3777f, 0x5ef47761, 0x5ef578c1,
+    0x5ef6789f, 0x5ef778a7, 0x5ef878b3, 0x5ef978a9, 0x5efa78a3, 0x5efb798e, 0x5efc798f, 0x5efd798d,
+    0x5f417a2e, 0x5f427a31, 0x5f437aaa, 0x5f447aa9, 0x5f457aed, 0x5f467aef, 0x5f477ba1, 0x5f487b95,
+    0x5f497b8b, 0x5f4a7b75, 0x5f4b7b97, 0x5f4c7b9d, 0x5f4d7b94, 0x5f4e7b8f, 0x5f4f7bb8, 0x5f507b87,
+    0x5f517b84, 0x5f527cb9, 0x5f537cbd, 0x5f547cbe, 0x5f557dbb, 0x5f567db0, 0x5f577d9c, 0x5f587dbd,
+    0x5f597dbe, 0x5f5a7da0, 0x5f5b7dca, 0x5f5c7db4, 0x5f5d7db2, 0x5f5e7db1, 0x5f5f7dba, 0x5f607da2,
+    0x5f617dbf, 0x5f627db5, 0x5f637db8, 0x5f647dad, 0x5f657dd2, 0x5f667dc7, 0x5f677dac, 0x5f687f70,
+    0x5f697fe0, 0x5f6a7fe1, 0x5f6b7fdf, 0x5f6c805e, 0x5f6d805a, 0x5f6e8087, 0x5f6f8150, 0x5f708180,
+    0x5f71818f, 0x5f728188, 0x5f73818a, 0x5f74817f, 0x5f758182, 0x5f7681e7, 0x5f7781fa, 0x5f788207,
+    0x5f798214, 0x5f7a821e, 0x5f7b824b, 0x5f7c84c9, 0x5f7d84bf, 0x5f7e84c6, 0x5f7f84c4, 0x5f818499,
+    0x5f82849e, 0x5f8384b2, 0x5f84849c, 0x5f8584cb, 0x5f8684b8, 0x5f8784c0, 0x5f8884d3, 0x5f898490,
+    0x5f8a84bc, 0x5f8b84d1, 0x5f8c84ca, 0x5f8d873f, 0x5f8e871c, 0x5f8f873b, 0x5f908722, 0x5f918725,
+    0x5f928734, 0x5f938718, 0x5f948755, 0x5f958737, 0x5f968729, 0x5f9788f3, 0x5f988902, 0x5f9988f4,
+    0x5f9a88f9, 0x5f9b88f8, 0x5f9c88fd, 0x5f9d88e8, 0x5f9e891a, 0x5f9f88ef, 0x5fa08aa6, 0x5fa18a8c,
+    0x5fa28a9e, 0x5fa38aa3, 0x5fa48a8d, 0x5fa58aa1, 0x5fa68a93, 0x5fa78aa4, 0x5fa88aaa, 0x5fa98aa5,
+    0x5faa8aa8, 0x5fab8a98, 0x5fac8a91, 0x5fad8a9a, 0x5fae8aa7, 0x5faf8c6a, 0x5fb08c8d, 0x5fb18c8c,
+    0x5fb28cd3, 0x5fb38cd1, 0x5fb48cd2, 0x5fb58d6b, 0x5fb68d99, 0x5fb78d95, 0x5fb88dfc, 0x5fb98f14,
+    0x5fba8f12, 0x5fbb8f15, 0x5fbc8f13, 0x5fbd8fa3, 0x5fbe9060, 0x5fbf9058, 0x5fc0905c, 0x5fc19063,
+    0x5fc29059, 0x5fc3905e, 0x5fc49062, 0x5fc5905d, 0x5fc6905b, 0x5fc79119, 0x5fc89118, 0x5fc9911e,
+    0x5fca9175, 0x5fcb9178, 0x5fcc9177, 0x5fcd9174, 0x5fce9278, 0x5fcf9280, 0x5fd09285, 0x5fd19298,
+    0x5fd29296, 0x5fd3927b, 0x5fd49293, 0x5fd5929c, 0x5fd692a8, 0x5fd7927c, 0x5fd89291, 0x5fd995a1,
+    0x5fda95a8, 0x5fdb95a9, 0x5fdc95a3, 0x5fdd95a5, 0x5fde95a4, 0x5fdf9699, 0x5fe0969c, 0x5fe1969b,
+    0x5fe296cc, 0x5fe396d2, 0x5fe49700, 0x5fe5977c, 0x5fe69785, 0x5fe797f6, 0x5fe89817, 0x5fe99818,
+    0x5fea98af, 0x5feb98b1, 0x5fec9903, 0x5fed9905, 0x5fee990c, 0x5fef9909, 0x5ff099c1, 0x5ff19aaf,
+    0x5ff29ab0, 0x5ff39ae6, 0x5ff49b41, 0x5ff59b42, 0x5ff69cf4, 0x5ff79cf6, 0x5ff89cf3, 0x5ff99ebc,
+    0x5ffa9f3b, 0x5ffb9f4a, 0x5ffc5104, 0x5ffd5100, 0x604150fb, 0x604250f5, 0x604350f9, 0x60445102,
+    0x60455108, 0x60465109, 0x60475105, 0x604851dc, 0x60495287, 0x604a5288, 0x604b5289, 0x604c528d,
+    0x604d528a, 0x604e52f0, 0x604f53b2, 0x6050562e, 0x6051563b, 0x60525639, 0x60535632, 0x6054563f,
+    0x60555634, 0x60565629, 0x60575653, 0x6058564e, 0x60595657, 0x605a5674, 0x605b5636, 0x605c562f,
+    0x605d5630, 0x605e5880, 0x605f589f, 0x6060589e, 0x606158b3, 0x6062589c, 0x606358ae, 0x606458a9,
+    0x606558a6, 0x6066596d, 0x60675b09, 0x60685afb, 0x60695b0b, 0x606a5af5, 0x606b5b0c, 0x606c5b08,
+    0x606d5bee, 0x606e5bec, 0x606f5be9, 0x60705beb, 0x60715c64, 0x60725c65, 0x60735d9d, 0x60745d94,
+    0x60755e62, 0x60765e5f, 0x60775e61, 0x60785ee2, 0x60795eda, 0x607a5edf, 0x607b5edd, 0x607c5ee3,
+    0x607d5ee0, 0x607e5f48, 0x607f5f71, 0x60815fb7, 0x60825fb5, 0x60836176, 0x60846167, 0x6085616e,
+    0x6086615d, 0x60876155, 0x60886182, 0x6089617c, 0x608a6170, 0x608b616b, 0x608c617e, 0x608d61a7,
+    0x608e6190, 0x608f61ab, 0x6090618e, 0x609161ac, 0x6092619a, 0x609361a4, 0x60946194, 0x609561ae,
+    0x6096622e, 0x60976469, 0x6098646f, 0x60996479, 0x609a649e, 0x609b64b2, 0x609c6488, 0x609d6490,
+    0x609e64b0, 0x609f64a5, 0x60a06493, 0x60a16495, 0x60a264a9, 0x60a36492, 0x60a464ae, 0x60a564ad,
+    0x60a664ab, 0x60a7649a, 0x60a864ac, 0x60a96499, 0x60aa64a2, 0x60ab64b3, 0x60ac6575, 0x60ad6577,
+    0x60ae6578, 0x60af66ae, 0x60b066ab, 0x60b166b4, 0x60b266b1, 0x60b36a23, 0x60b46a1f, 0x60b569e8,
+    0x60b66a01, 0x60b76a1e, 0x60b86a19, 0x60b969fd, 0x60ba6a21, 0x60bb6a13, 0x60bc6a0a, 0x60bd69f3,
+    0x60be6a02, 0x60bf6a05, 0x60c069ed, 0x60c16a11, 0x60c26b50, 0x60c36b4e, 0x60c46ba4, 0x60c56bc5,
+    0x60c66bc6, 0x60c76f3f, 0x60c86f7c, 0x60c96f84, 0x60ca6f51, 0x60cb6f66, 0x60cc6f54, 0x60cd6f86,
+    0x60ce6f6d, 0x60cf6f5b, 0x60d06f78, 0x60d16f6e, 0x60d26f8e, 0x60d36f7a, 0x60d46f70, 0x60d56f64,
+    0x60d66f97, 0x60d76f58, 0x60d86ed5, 0x60d96f6f, 0x60da6f60, 0x60db6f5f, 0x60dc719f, 0x60dd71ac,
+    0x60de71b1, 0x60df71a8, 0x60e07256, 0x60e1729b, 0x60e2734e, 0x60e37357, 0x60e47469, 0x60e5748b,
+    0x60e67483, 0x60e7747e, 0x60e87480, 0x60e9757f, 0x60ea7620, 0x60eb7629, 0x60ec761f, 0x60ed7624,
+    0x60ee7626, 0x60ef7621, 0x60f07622, 0x60f1769a, 0x60f276ba, 0x60f376e4, 0x60f4778e, 0x60f57787,
+    0x60f6778c, 0x60f77791, 0x60f8778b, 0x60f978cb, 0x60fa78c5, 0x60fb78ba, 0x60fc78ca, 0x60fd78be,
+    0x614178d5, 0x614278bc, 0x614378d0, 0x61447a3f, 0x61457a3c, 0x61467a40, 0x61477a3d, 0x61487a37,
+    0x61497a3b, 0x614a7aaf, 0x614b7aae, 0x614c7bad, 0x614d7bb1, 0x614e7bc4, 0x614f7bb4, 0x61507bc6,
+    0x61517bc7, 0x61527bc1, 0x61537ba0, 0x61547bcc, 0x61557cca, 0x61567de0, 0x61577df4, 0x61587def,
+    0x61597dfb, 0x615a7dd8, 0x615b7dec, 0x615c7ddd, 0x615d7de8, 0x615e7de3, 0x615f7dda, 0x61607dde,
+    0x61617de9, 0x61627d9e, 0x61637dd9, 0x61647df2, 0x61657df9, 0x61667f75, 0x61677f77, 0x61687faf,
+    0x61697fe9, 0x616a8026, 0x616b819b, 0x616c819c, 0x616d819d, 0x616e81a0, 0x616f819a, 0x61708198,
+    0x61718517, 0x6172853d, 0x6173851a, 0x617484ee, 0x6175852c, 0x6176852d, 0x61778513, 0x61788511,
+    0x61798523, 0x617a8521, 0x617b8514, 0x617c84ec, 0x617d8525, 0x617e84ff, 0x617f8506, 0x61818782,
+    0x61828774, 0x61838776, 0x61848760, 0x61858766, 0x61868778, 0x61878768, 0x61888759, 0x61898757,
+    0x618a874c, 0x618b8753, 0x618c885b, 0x618d885d, 0x618e8910, 0x618f8907, 0x61908912, 0x61918913,
+    0x61928915, 0x6193890a, 0x61948abc, 0x61958ad2, 0x61968ac7, 0x61978ac4, 0x61988a95, 0x61998acb,
+    0x619a8af8, 0x619b8ab2, 0x619c8ac9, 0x619d8ac2, 0x619e8abf, 0x619f8ab0, 0x61a08ad6, 0x61a18acd,
+    0x61a28ab6, 0x61a38ab9, 0x61a48adb, 0x61a58c4c, 0x61a68c4e, 0x61a78c6c, 0x61a88ce0, 0x61a98cde,
+    0x61aa8ce6, 0x61ab8ce4, 0x61ac8cec, 0x61ad8ced, 0x61ae8ce2, 0x61af8ce3, 0x61b08cdc, 0x61b18cea,
+    0x61b28ce1, 0x61b38d6d, 0x61b48d9f, 0x61b58da3, 0x61b68e2b, 0x61b78e10, 0x61b88e1d, 0x61b98e22,
+    0x61ba8e0f, 0x61bb8e29, 0x61bc8e1f, 0x61bd8e21, 0x61be8e1e, 0x61bf8eba, 0x61c08f1d, 0x61c18f1b,
+    0x61c28f1f, 0x61c38f29, 0x61c48f26, 0x61c58f2a, 0x61c68f1c, 0x61c78f1e, 0x61c88f25, 0x61c99069,
+    0x61ca906e, 0x61cb9068, 0x61cc906d, 0x61cd9077, 0x61ce9130, 0x61cf912d, 0x61d09127, 0x61d19131,
+    0x61d29187, 0x61d39189, 0x61d4918b, 0x61d59183, 0x61d692c5, 0x61d792bb, 0x61d892b7, 0x61d992ea,
+    0x61da92ac, 0x61db92e4, 0x61dc92c1, 0x61dd92b3, 0x61de92bc, 0x61df92d2, 0x61e092c7, 0x61e192f0,
+    0x61e292b2, 0x61e395ad, 0x61e495b1, 0x61e59704, 0x61e69706, 0x61e79707, 0x61e89709, 0x61e99760,
+    0x61ea978d, 0x61eb978b, 0x61ec978f, 0x61ed9821, 0x61ee982b, 0x61ef981c, 0x61f098b3, 0x61f1990a,
+    0x61f29913, 0x61f39912, 0x61f49918, 0x61f599dd, 0x61f699d0, 0x61f799df, 0x61f899db, 0x61f999d1,
+    0x61fa99d5, 0x61fb99d2, 0x61fc99d9, 0x61fd9ab7, 0x62419aee, 0x62429aef, 0x62439b27, 0x62449b45,
+    0x62459b44, 0x62469b77, 0x62479b6f, 0x62489d06, 0x62499d09, 0x624a9d03, 0x624b9ea9, 0x624c9ebe,
+    0x624d9ece, 0x624e58a8, 0x624f9f52, 0x62505112, 0x62515118, 0x62525114, 0x62535110, 0x62545115,
+    0x62555180, 0x625651aa, 0x625751dd, 0x62585291, 0x62595293, 0x625a52f3, 0x625b5659, 0x625c566b,
+    0x625d5679, 0x625e5669, 0x625f5664, 0x62605678, 0x6261566a, 0x62625668, 0x62635665, 0x62645671,
+    0x6265566f, 0x6266566c, 0x62675662, 0x62685676, 0x626958c1, 0x626a58be, 0x626b58c7, 0x626c58c5,
+    0x626d596e, 0x626e5b1d, 0x626f5b34, 0x62705b78, 0x62715bf0, 0x62725c0e, 0x62735f4a, 0x627461b2,
+    0x62756191, 0x627661a9, 0x6277618a, 0x627861cd, 0x627961b6, 0x627a61be, 0x627b61ca, 0x627c61c8,
+    0x627d6230, 0x627e64c5, 0x627f64c1, 0x628164cb, 0x628264bb, 0x628364bc, 0x628464da, 0x628564c4,
+    0x628664c7, 0x628764c2, 0x628864cd, 0x628964bf, 0x628a64d2, 0x628b64d4, 0x628c64be, 0x628d6574,
+    0x628e66c6, 0x628f66c9, 0x629066b9, 0x629166c4, 0x629266c7, 0x629366b8, 0x62946a3d, 0x62956a38,
+    0x62966a3a, 0x62976a59, 0x62986a6b, 0x62996a58, 0x629a6a39, 0x629b6a44, 0x629c6a62, 0x629d6a61,
+    0x629e6a4b, 0x629f6a47, 0x62a06a35, 0x62a16a5f, 0x62a26a48, 0x62a36b59, 0x62a46b77, 0x62a56c05,
+    0x62a66fc2, 0x62a76fb1, 0x62a86fa1, 0x62a96fc3, 0x62aa6fa4, 0x62ab6fc1, 0x62ac6fa7, 0x62ad6fb3,
+    0x62ae6fc0, 0x62af6fb9, 0x62b06fb6, 0x62b16fa6, 0x62b26fa0, 0x62b36fb4, 0x62b471be, 0x62b571c9,
+    0x62b671d0, 0x62b771d2, 0x62b871c8, 0x62b971d5, 0x62ba71b9, 0x62bb71ce, 0x62bc71d9, 0x62bd71dc,
+    0x62be71c3, 0x62bf71c4, 0x62c07368, 0x62c1749c, 0x62c274a3, 0x62c37498, 0x62c4749f, 0x62c5749e,
+    0x62c674e2, 0x62c7750c, 0x62c8750d, 0x62c97634, 0x62ca7638, 0x62cb763a, 0x62cc76e7, 0x62cd76e5,
+    0x62ce77a0, 0x62cf779e, 0x62d0779f, 0x62d177a5, 0x62d278e8, 0x62d378da, 0x62d478ec, 0x62d578e7,
+    0x62d679a6, 0x62d77a4d, 0x62d87a4e, 0x62d97a46, 0x62da7a4c, 0x62db7a4b, 0x62dc7aba, 0x62dd7bd9,
+    0x62de7c11, 0x62df7bc9, 0x62e07be4, 0x62e17bdb, 0x62e27be1, 0x62e37be9, 0x62e47be6, 0x62e57cd5,
+    0x62e67cd6, 0x62e77e0a, 0x62e87e11, 0x62e97e08, 0x62ea7e1b, 0x62eb7e23, 0x62ec7e1e, 0x62ed7e1d,
+    0x62ee7e09, 0x62ef7e10, 0x62f07f79, 0x62f17fb2, 0x62f27ff0, 0x62f37ff1, 0x62f47fee, 0x62f58028,
+    0x62f681b3, 0x62f781a9, 0x62f881a8, 0x62f981fb, 0x62fa8208, 0x62fb8258, 0x62fc8259, 0x62fd854a,
+    0x63418559, 0x63428548, 0x63438568, 0x63448569, 0x63458543, 0x63468549, 0x6347856d, 0x6348856a,
+    0x6349855e, 0x634a8783, 0x634b879f, 0x634c879e, 0x634d87a2, 0x634e878d, 0x634f8861, 0x6350892a,
+    0x63518932, 0x63528925, 0x6353892b, 0x63548921, 0x635589aa, 0x635689a6, 0x63578ae6, 0x63588afa,
+    0x63598aeb, 0x635a8af1, 0x635b8b00, 0x635c8adc, 0x635d8ae7, 0x635e8aee, 0x635f8afe, 0x63608b01,
+    0x63618b02, 0x63628af7, 0x63638aed, 0x63648af3, 0x63658af6, 0x63668afc, 0x63678c6b, 0x63688c6d,
+    0x63698c93, 0x636a8cf4, 0x636b8e44, 0x636c8e31, 0x636d8e34, 0x636e8e42, 0x636f8e39, 0x63708e35,
+    0x63718f3b, 0x63728f2f, 0x63738f38, 0x63748f33, 0x63758fa8, 0x63768fa6, 0x63779075, 0x63789074,
+    0x63799078, 0x637a9072, 0x637b907c, 0x637c907a, 0x637d9134, 0x637e9192, 0x637f9320, 0x63819336,
+    0x638292f8, 0x63839333, 0x6384932f, 0x63859322, 0x638692fc, 0x6387932b, 0x63889304, 0x6389931a,
+    0x638a9310, 0x638b9326, 0x638c9321, 0x638d9315, 0x638e932e, 0x638f9319, 0x639095bb, 0x639196a7,
+    0x639296a8, 0x639396aa, 0x639496d5, 0x6395970e, 0x63969711, 0x63979716, 0x6398970d, 0x63999713,
+    0x639a970f, 0x639b975b, 0x639c975c, 0x639d9766, 0x639e9798, 0x639f9830, 0x63a09838, 0x63a1983b,
+    0x63a29837, 0x63a3982d, 0x63a49839, 0x63a59824, 0x63a69910, 0x63a79928, 0x63a8991e, 0x63a9991b,
+    0x63aa9921, 0x63ab991a, 0x63ac99ed, 0x63ad99e2, 0x63ae99f1, 0x63af9ab8, 0x63b09abc, 0x63b19afb,
+    0x63b29aed, 0x63b39b28, 0x63b49b91, 0x63b59d15, 0x63b69d23, 0x63b79d26, 0x63b89d28, 0x63b99d12,
+    0x63ba9d1b, 0x63bb9ed8, 0x63bc9ed4, 0x63bd9f8d, 0x63be9f9c, 0x63bf512a, 0x63c0511f, 0x63c15121,
+    0x63c25132, 0x63c352f5, 0x63c4568e, 0x63c55680, 0x63c65690, 0x63c75685, 0x63c85687, 0x63c9568f,
+    0x63ca58d5, 0x63cb58d3, 0x63cc58d1, 0x63cd58ce, 0x63ce5b30, 0x63cf5b2a, 0x63d05b24, 0x63d15b7a,
+    0x63d25c37, 0x63d35c68, 0x63d45dbc, 0x63d55dba, 0x63d65dbd, 0x63d75db8, 0x63d85e6b, 0x63d95f4c,
+    0x63da5fbd, 0x63db61c9, 0x63dc61c2, 0x63dd61c7, 0x63de61e6, 0x63df61cb, 0x63e06232, 0x63e16234,
+    0x63e264ce, 0x63e364ca, 0x63e464d8, 0x63e564e0, 0x63e664f0, 0x63e764e6, 0x63e864ec, 0x63e964f1,
+    0x63ea64e2, 0x63eb64ed, 0x63ec6582, 0x63ed6583, 0x63ee66d9, 0x63ef66d6, 0x63f06a80, 0x63f16a94,
+    0x63f26a84, 0x63f36aa2, 0x63f46a9c, 0x63f56adb, 0x63f66aa3, 0x63f76a7e, 0x63f86a97, 0x63f96a90,
+    0x63fa6aa0, 0x63fb6b5c, 0x63fc6bae, 0x63fd6bda, 0x64416c08, 0x64426fd8, 0x64436ff1, 0x64446fdf,
+    0x64456fe0, 0x64466fdb, 0x64476fe4, 0x64486feb, 0x64496fef, 0x644a6f80, 0x644b6fec, 0x644c6fe1,
+    0x644d6fe9, 0x644e6fd5, 0x644f6fee, 0x64506ff0, 0x645171e7, 0x645271df, 0x645371ee, 0x645471e6,
+    0x645571e5, 0x645671ed, 0x645771ec, 0x645871f4, 0x645971e0, 0x645a7235, 0x645b7246, 0x645c7370,
+    0x645d7372, 0x645e74a9, 0x645f74b0, 0x646074a6, 0x646174a8, 0x64627646, 0x64637642, 0x6464764c,
+    0x646576ea, 0x646677b3, 0x646777aa, 0x646877b0, 0x646977ac, 0x646a77a7, 0x646b77ad, 0x646c77ef,
+    0x646d78f7, 0x646e78fa, 0x646f78f4, 0x647078ef, 0x64717901, 0x647279a7, 0x647379aa, 0x64747a57,
+    0x64757abf, 0x64767c07, 0x64777c0d, 0x64787bfe, 0x64797bf7, 0x647a7c0c, 0x647b7be0, 0x647c7ce0,
+    0x647d7cdc, 0x647e7cde, 0x647f7ce2, 0x64817cdf, 0x64827cd9, 0x64837cdd, 0x64847e2e, 0x64857e3e,
+    0x64867e46, 0x64877e37, 0x64887e32, 0x64897e43, 0x648a7e2b, 0x648b7e3d, 0x648c7e31, 0x648d7e45,
+    0x648e7e41, 0x648f7e34, 0x64907e39, 0x64917e48, 0x64927e35, 0x64937e3f, 0x64947e2f, 0x64957f44,
+    0x64967ff3, 0x64977ffc, 0x64988071, 0x64998072, 0x649a8070, 0x649b806f, 0x649c8073, 0x649d81c6,
+    0x649e81c3, 0x649f81ba, 0x64a081c2, 0x64a181c0, 0x64a281bf, 0x64a381bd, 0x64a481c9, 0x64a581be,
+    0x64a681e8, 0x64a78209, 0x64a88271, 0x64a985aa, 0x64aa8584, 0x64ab857e, 0x64ac859c, 0x64ad8591,
+    0x64ae8594, 0x64af85af, 0x64b0859b, 0x64b18587, 0x64b285a8, 0x64b3858a, 0x64b48667, 0x64b587c0,
+    0x64b687d1, 0x64b787b3, 0x64b887d2, 0x64b987c6, 0x64ba87ab, 0x64bb87bb, 0x64bc87ba, 0x64bd87c8,
+    0x64be87cb, 0x64bf893b, 0x64c08936, 0x64c18944, 0x64c28938, 0x64c3893d, 0x64c489ac, 0x64c58b0e,
+    0x64c68b17, 0x64c78b19, 0x64c88b1b, 0x64c98b0a, 0x64ca8b20, 0x64cb8b1d, 0x64cc8b04, 0x64cd8b10,
+    0x64ce8c41, 0x64cf8c3f, 0x64d08c73, 0x64d18cfa, 0x64d28cfd, 0x64d38cfc, 0x64d48cf8, 0x64d58cfb,
+    0x64d68da8, 0x64d78e49, 0x64d88e4b, 0x64d98e48, 0x64da8e4a, 0x64db8f44, 0x64dc8f3e, 0x64dd8f42,
+    0x64de8f45, 0x64df8f3f, 0x64e0907f, 0x64e1907d, 0x64e29084, 0x64e39081, 0x64e49082, 0x64e59080,
+    0x64e69139, 0x64e791a3, 0x64e8919e, 0x64e9919c, 0x64ea934d, 0x64eb9382, 0x64ec9328, 0x64ed9375,
+    0x64ee934a, 0x64ef9365, 0x64f0934b, 0x64f19318, 0x64f2937e, 0x64f3936c, 0x64f4935b, 0x64f59370,
+    0x64f6935a, 0x64f79354, 0x64f895ca, 0x64f995cb, 0x64fa95cc, 0x64fb95c8, 0x64fc95c6, 0x64fd96b1,
+    0x654196b8, 0x654296d6, 0x6543971c, 0x6544971e, 0x654597a0, 0x654697d3, 0x65479846, 0x654898b6,
+    0x65499935, 0x654a9a01, 0x654b99ff, 0x654c9bae, 0x654d9bab, 0x654e9baa, 0x654f9bad, 0x65509d3b,
+    0x65519d3f, 0x65529e8b, 0x65539ecf, 0x65549ede, 0x65559edc, 0x65569edd, 0x65579edb, 0x65589f3e,
+    0x65599f4b, 0x655a53e2, 0x655b5695, 0x655c56ae, 0x655d58d9, 0x655e58d8, 0x655f5b38, 0x65605f5e,
+    0x656161e3, 0x65626233, 0x656364f4, 0x656464f2, 0x656564fe, 0x65666506, 0x656764fa, 0x656864fb,
+    0x656964f7, 0x656a65b7, 0x656b66dc, 0x656c6726, 0x656d6ab3, 0x656e6aac, 0x656f6ac3, 0x65706abb,
+    0x65716ab8, 0x65726ac2, 0x65736aae, 0x65746aaf, 0x65756b5f, 0x65766b78, 0x65776baf, 0x65787009,
+    0x6579700b, 0x657a6ffe, 0x657b7006, 0x657c6ffa, 0x657d7011, 0x657e700f, 0x657f71fb, 0x658171fc,
+    0x658271fe, 0x658371f8, 0x65847377, 0x65857375, 0x658674a7, 0x658774bf, 0x65887515, 0x65897656,
+    0x658a7658, 0x658b7652, 0x658c77bd, 0x658d77bf, 0x658e77bb, 0x658f77bc, 0x6590790e, 0x659179ae,
+    0x65927a61, 0x65937a62, 0x65947a60, 0x65957ac4, 0x65967ac5, 0x65977c2b, 0x65987c27, 0x65997c2a,
+    0x659a7c1e, 0x659b7c23, 0x659c7c21, 0x659d7ce7, 0x659e7e54, 0x659f7e55, 0x65a07e5e, 0x65a17e5a,
+    0x65a27e61, 0x65a37e52, 0x65a47e59, 0x65a57f48, 0x65a67ff9, 0x65a77ffb, 0x65a88077, 0x65a98076,
+    0x65aa81cd, 0x65ab81cf, 0x65ac820a, 0x65ad85cf, 0x65ae85a9, 0x65af85cd, 0x65b085d0, 0x65b185c9,
+    0x65b285b0, 0x65b385ba, 0x65b485b9, 0x65b585a6, 0x65b687ef, 0x65b787ec, 0x65b887f2, 0x65b987e0,
+    0x65ba8986, 0x65bb89b2, 0x65bc89f4, 0x65bd8b28, 0x65be8b39, 0x65bf8b2c, 0x65c08b2b, 0x65c18c50,
+    0x65c28d05, 0x65c38e59, 0x65c48e63, 0x65c58e66, 0x65c68e64, 0x65c78e5f, 0x65c88e55, 0x65c98ec0,
+    0x65ca8f49, 0x65cb8f4d, 0x65cc9087, 0x65cd9083, 0x65ce9088, 0x65cf91ab, 0x65d091ac, 0x65d191d0,
+    0x65d29394, 0x65d3938a, 0x65d49396, 0x65d593a2, 0x65d693b3, 0x65d793ae, 0x65d893ac, 0x65d993b0,
+    0x65da9398, 0x65db939a, 0x65dc9397, 0x65dd95d4, 0x65de95d6, 0x65df95d0, 0x65e095d5, 0x65e196e2,
+    0x65e296dc, 0x65e396d9, 0x65e496db, 0x65e596de, 0x65e69724, 0x65e797a3, 0x65e897a6, 0x65e997ad,
+    0x65ea97f9, 0x65eb984d, 0x65ec984f, 0x65ed984c, 0x65ee984e, 0x65ef9853, 0x65f098ba, 0x65f1993e,
+    0x65f2993f, 0x65f3993d, 0x65f4992e, 0x65f599a5, 0x65f69a0e, 0x65f79ac1, 0x65f89b03, 0x65f99b06,
+    0x65fa9b4f, 0x65fb9b4e, 0x65fc9b4d, 0x65fd9bca, 0x66419bc9, 0x66429bfd, 0x66439bc8, 0x66449bc0,
+    0x66459d51, 0x66469d5d, 0x66479d60, 0x66489ee0, 0x66499f15, 0x664a9f2c, 0x664b5133, 0x664c56a5,
+    0x664d58de, 0x664e58df, 0x664f58e2, 0x66505bf5, 0x66519f90, 0x66525eec, 0x665361f2, 0x665461f7,
+    0x665561f6, 0x665661f5, 0x66576500, 0x6658650f, 0x665966e0, 0x665a66dd, 0x665b6ae5, 0x665c6add,
+    0x665d6ada, 0x665e6ad3, 0x665f701b, 0x6660701f, 0x66617028, 0x6662701a, 0x6663701d, 0x66647015,
+    0x66657018, 0x66667206, 0x6667720d, 0x66687258, 0x666972a2, 0x666a7378, 0x666b737a, 0x666c74bd,
+    0x666d74ca, 0x666e74e3, 0x666f7587, 0x66707586, 0x6671765f, 0x66727661, 0x667377c7, 0x66747919,
+    0x667579b1, 0x66767a6b, 0x66777a69, 0x66787c3e, 0x66797c3f, 0x667a7c38, 0x667b7c3d, 0x667c7c37,
+    0x667d7c40, 0x667e7e6b, 0x667f7e6d, 0x66817e79, 0x66827e69, 0x66837e6a, 0x66847f85, 0x66857e73,
+    0x66867fb6, 0x66877fb9, 0x66887fb8, 0x668981d8, 0x668a85e9, 0x668b85dd, 0x668c85ea, 0x668d85d5,
+    0x668e85e4, 0x668f85e5, 0x669085f7, 0x669187fb, 0x66928805, 0x6693880d, 0x669487f9, 0x669587fe,
+    0x66968960, 0x6697895f, 0x66988956, 0x6699895e, 0x669a8b41, 0x669b8b5c, 0x669c8b58, 0x669d8b49,
+    0x669e8b5a, 0x669f8b4e, 0x66a08b4f, 0x66a18b46, 0x66a28b59, 0x66a38d08, 0x66a48d0a, 0x66a58e7c,
+    0x66a68e72, 0x66a78e87, 0x66a88e76, 0x66a98e6c, 0x66aa8e7a, 0x66ab8e74, 0x66ac8f54, 0x66ad8f4e,
+    0x66ae8fad, 0x66af908a, 0x66b0908b, 0x66b191b1, 0x66b291ae, 0x66b393e1, 0x66b493d1, 0x66b593df,
+    0x66b693c3, 0x66b793c8, 0x66b893dc, 0x66b993dd, 0x66ba93d6, 0x66bb93e2, 0x66bc93cd, 0x66bd93d8,
+    0x66be93e4, 0x66bf93d7, 0x66c093e8, 0x66c195dc, 0x66c296b4, 0x66c396e3, 0x66c4972a, 0x66c59727,
+    0x66c69761, 0x66c797dc, 0x66c897fb, 0x66c9985e, 0x66ca9858, 0x66cb985b, 0x66cc98bc, 0x66cd9945,
+    0x66ce9949, 0x66cf9a16, 0x66d09a19, 0x66d19b0d, 0x66d29be8, 0x66d39be7, 0x66d49bd6, 0x66d59bdb,
+    0x66d69d89, 0x66d79d61, 0x66d89d72, 0x66d99d6a, 0x66da9d6c, 0x66db9e92, 0x66dc9e97, 0x66dd9e93,
+    0x66de9eb4, 0x66df52f8, 0x66e056a8, 0x66e156b7, 0x66e256b6, 0x66e356b4, 0x66e456bc, 0x66e558e4,
+    0x66e65b40, 0x66e75b43, 0x66e85b7d, 0x66e95bf6, 0x66ea5dc9, 0x66eb61f8, 0x66ec61fa, 0x66ed6518,
+    0x66ee6514, 0x66ef6519, 0x66f066e6, 0x66f16727, 0x66f26aec, 0x66f3703e, 0x66f47030, 0x66f57032,
+    0x66f67210, 0x66f7737b, 0x66f874cf, 0x66f97662, 0x66fa7665, 0x66fb7926, 0x66fc792a, 0x66fd792c,
+    0x6741792b, 0x67427ac7, 0x67437af6, 0x67447c4c, 0x67457c43, 0x67467c4d, 0x67477cef, 0x67487cf0,
+    0x67498fae, 0x674a7e7d, 0x674b7e7c, 0x674c7e82, 0x674d7f4c, 0x674e8000, 0x674f81da, 0x67508266,
+    0x675185fb, 0x675285f9, 0x67538611, 0x675485fa, 0x67558606, 0x6756860b, 0x67578607, 0x6758860a,
+    0x67598814, 0x675a8815, 0x675b8964, 0x675c89ba, 0x675d89f8, 0x675e8b70, 0x675f8b6c, 0x67608b66,
+    0x67618b6f, 0x67628b5f, 0x67638b6b, 0x67648d0f, 0x67658d0d, 0x67668e89, 0x67678e81, 0x67688e85,
+    0x67698e82, 0x676a91b4, 0x676b91cb, 0x676c9418, 0x676d9403, 0x676e93fd, 0x676f95e1, 0x67709730,
+    0x677198c4, 0x67729952, 0x67739951, 0x677499a8, 0x67759a2b, 0x67769a30, 0x67779a37, 0x67789a35,
+    0x67799c13, 0x677a9c0d, 0x677b9e79, 0x677c9eb5, 0x677d9ee8, 0x677e9f2f, 0x677f9f5f, 0x67819f63,
+    0x67829f61, 0x67835137, 0x67845138, 0x678556c1, 0x678656c0, 0x678756c2, 0x67885914, 0x67895c6c,
+    0x678a5dcd, 0x678b61fc, 0x678c61fe, 0x678d651d, 0x678e651c, 0x678f6595, 0x679066e9, 0x67916afb,
+    0x67926b04, 0x67936afa, 0x67946bb2, 0x6795704c, 0x6796721b, 0x679772a7, 0x679874d6, 0x679974d4,
+    0x679a7669, 0x679b77d3, 0x679c7c50, 0x679d7e8f, 0x679e7e8c, 0x679f7fbc, 0x67a08617, 0x67a1862d,
+    0x67a2861a, 0x67a38823, 0x67a48822, 0x67a58821, 0x67a6881f, 0x67a7896a, 0x67a8896c, 0x67a989bd,
+    0x67aa8b74, 0x67ab8b77, 0x67ac8b7d, 0x67ad8d13, 0x67ae8e8a, 0x67af8e8d, 0x67b08e8b, 0x67b18f5f,
+    0x67b28faf, 0x67b391ba, 0x67b4942e, 0x67b59433, 0x67b69435, 0x67b7943a, 0x67b89438, 0x67b99432,
+    0x67ba942b, 0x67bb95e2, 0x67bc9738, 0x67bd9739, 0x67be9732, 0x67bf97ff, 0x67c09867, 0x67c19865,
+    0x67c29957, 0x67c39a45, 0x67c49a43, 0x67c59a40, 0x67c69a3e, 0x67c79acf, 0x67c89b54, 0x67c99b51,
+    0x67ca9c2d, 0x67cb9c25, 0x67cc9daf, 0x67cd9db4, 0x67ce9dc2, 0x67cf9db8, 0x67d09e9d, 0x67d19eef,
+    0x67d29f19, 0x67d39f5c, 0x67d49f66, 0x67d59f67, 0x67d6513c, 0x67d7513b, 0x67d856c8, 0x67d956ca,
+    0x67da56c9, 0x67db5b7f, 0x67dc5dd4, 0x67dd5dd2, 0x67de5f4e, 0x67df61ff, 0x67e06524, 0x67e16b0a,
+    0x67e26b61, 0x67e37051, 0x67e47058, 0x67e57380, 0x67e674e4, 0x67e7758a, 0x67e8766e, 0x67e9766c,
+    0x67ea79b3, 0x67eb7c60, 0x67ec7c5f, 0x67ed807e, 0x67ee807d, 0x67ef81df, 0x67f08972, 0x67f1896f,
+    0x67f289fc, 0x67f38b80, 0x67f48d16, 0x67f58d17, 0x67f68e91, 0x67f78e93, 0x67f88f61, 0x67f99148,
+    0x67fa9444, 0x67fb9451, 0x67fc9452, 0x67fd973d, 0x6841973e, 0x684297c3, 0x684397c1, 0x6844986b,
+    0x68459955, 0x68469a55, 0x68479a4d, 0x68489ad2, 0x68499b1a, 0x684a9c49, 0x684b9c31, 0x684c9c3e,
+    0x684d9c3b, 0x684e9dd3, 0x684f9dd7, 0x68509f34, 0x68519f6c, 0x68529f6a, 0x68539f94, 0x685456cc,
+    0x68555dd6, 0x68566200, 0x68576523, 0x6858652b, 0x6859652a, 0x685a66ec, 0x685b6b10, 0x685c74da,
+    0x685d7aca, 0x685e7c64, 0x685f7c63, 0x68607c65, 0x68617e93, 0x68627e96, 0x68637e94, 0x686481e2,
+    0x68658638, 0x6866863f, 0x68678831, 0x68688b8a, 0x68699090, 0x686a908f, 0x686b9463, 0x686c9460,
+    0x686d9464, 0x686e9768, 0x686f986f, 0x6870995c, 0x68719a5a, 0x68729a5b, 0x68739a57, 0x68749ad3,
+    0x68759ad4, 0x68769ad1, 0x68779c54, 0x68789c57, 0x68799c56, 0x687a9de5, 0x687b9e9f, 0x687c9ef4,
+    0x687d56d1, 0x687e58e9, 0x687f652c, 0x6881705e, 0x68827671, 0x68837672, 0x688477d7, 0x68857f50,
+    0x68867f88, 0x68878836, 0x68888839, 0x68898862, 0x688a8b93, 0x688b8b92, 0x688c8b96, 0x688d8277,
+    0x688e8d1b, 0x688f91c0, 0x6890946a, 0x68919742, 0x68929748, 0x68939744, 0x689497c6, 0x68959870,
+    0x68969a5f, 0x68979b22, 0x68989b58, 0x68999c5f, 0x689a9df9, 0x689b9dfa, 0x689c9e7c, 0x689d9e7d,
+    0x689e9f07, 0x689f9f77, 0x68a09f72, 0x68a15ef3, 0x68a26b16, 0x68a37063, 0x68a47c6c, 0x68a57c6e,
+    0x68a6883b, 0x68a789c0, 0x68a88ea1, 0x68a991c1, 0x68aa9472, 0x68ab9470, 0x68ac9871, 0x68ad995e,
+    0x68ae9ad6, 0x68af9b23, 0x68b09ecc, 0x68b17064, 0x68b277da, 0x68b38b9a, 0x68b49477, 0x68b597c9,
+    0x68b69a62, 0x68b79a65, 0x68b87e9c, 0x68b98b9c, 0x68ba8eaa, 0x68bb91c5, 0x68bc947d, 0x68bd947e,
+    0x68be947c, 0x68bf9c77, 0x68c09c78, 0x68c19ef7, 0x68c28c54, 0x68c3947f, 0x68c49e1a, 0x68c57228,
+    0x68c69a6a, 0x68c79b31, 0x68c89e1b, 0x68c99e1e, 0x68ca7c72, 0x68cb6491, 0x69414e42, 0x69424e5c,
+    0x694351f5, 0x6944531a, 0x69455382, 0x69464e07, 0x69474e0c, 0x69484e47, 0x69494e8d, 0x694a56d7,
+    0x694bfa0c, 0x694c5c6e, 0x694d5f73, 0x694e4e0f, 0x694f5187, 0x69504e0e, 0x69514e2e, 0x69524e93,
+    0x69534ec2, 0x69544ec9, 0x69554ec8, 0x69565198, 0x695752fc, 0x6958536c, 0x695953b9, 0x695a5720,
+    0x695b5903, 0x695c592c, 0x695d5c10, 0x695e5dff, 0x695f65e1, 0x69606bb3, 0x69616bcc, 0x69626c14,
+    0x6963723f, 0x69644e31, 0x69654e3c, 0x69664ee8, 0x69674edc, 0x69684ee9, 0x69694ee1, 0x696a4edd,
+    0x696b4eda, 0x696c520c, 0x696d531c, 0x696e534c, 0x696f5722, 0x69705723, 0x69715917, 0x6972592f,
+    0x69735b81, 0x69745b84, 0x69755c12, 0x69765c3b, 0x69775c74, 0x69785c73, 0x69795e04, 0x697a5e80,
+    0x697b5e82, 0x697c5fc9, 0x697d6209, 0x697e6250, 0x697f6c15, 0x69816c36, 0x69826c43, 0x69836c3f,
+    0x69846c3b, 0x698572ae, 0x698672b0, 0x6987738a, 0x698879b8, 0x6989808a, 0x698a961e, 0x698b4f0e,
+    0x698c4f18, 0x698d4f2c, 0x698e4ef5, 0x698f4f14, 0x69904ef1, 0x69914f00, 0x69924ef7, 0x69934f08,
+    0x69944f1d, 0x69954f02, 0x69964f05, 0x69974f22, 0x69984f13, 0x69994f04, 0x699a4ef4, 0x699b4f12,
+    0x699c51b1, 0x699d5213, 0x699e5209, 0x699f5210, 0x69a052a6, 0x69a15322, 0x69a2531f, 0x69a3534d,
+    0x69a4538a, 0x69a55407, 0x69a656e1, 0x69a756df, 0x69a8572e, 0x69a9572a, 0x69aa5734, 0x69ab593c,
+    0x69ac5980, 0x69ad597c, 0x69ae5985, 0x69af597b, 0x69b0597e, 0x69b15977, 0x69b2597f, 0x69b35b56,
+    0x69b45c15, 0x69b55c25, 0x69b65c7c, 0x69b75c7a, 0x69b85c7b, 0x69b95c7e, 0x69ba5ddf, 0x69bb5e75,
+    0x69bc5e84, 0x69bd5f02, 0x69be5f1a, 0x69bf5f74, 0x69c05fd5, 0x69c15fd4, 0x69c25fcf, 0x69c36265,
+    0x69c4625c, 0x69c5625e, 0x69c66264, 0x69c76261, 0x69c86266, 0x69c96262, 0x69ca6259, 0x69cb6260,
+    0x69cc625a, 0x69cd65ef, 0x69ce65ee, 0x69cf673e, 0x69d06739, 0x69d16738, 0x69d2673b, 0x69d3673a,
+    0x69d4673f, 0x69d5673c, 0x69d66733, 0x69d76c18, 0x69d86c46, 0x69d96c52, 0x69da6c5c, 0x69db6c4f,
+    0x69dc6c4a, 0x69dd6c54, 0x69de6c4b, 0x69df6c4c, 0x69e07071, 0x69e1725e, 0x69e272b4, 0x69e372b5,
+    0x69e4738e, 0x69e5752a, 0x69e6767f, 0x69e77a75, 0x69e87f51, 0x69e98278, 0x69ea827c, 0x69eb8280,
+    0x69ec827d, 0x69ed827f, 0x69ee864d, 0x69ef897e, 0x69f09099, 0x69f19097, 0x69f29098, 0x69f3909b,
+    0x69f49094, 0x69f59622, 0x69f69624, 0x69f79620, 0x69f89623, 0x69f94f56, 0x69fa4f3b, 0x69fb4f62,
+    0x69fc4f49, 0x69fd4f53, 0x6a414f64, 0x6a424f3e, 0x6a434f67, 0x6a444f52, 0x6a454f5f, 0x6a464f41,
+    0x6a474f58, 0x6a484f2d, 0x6a494f33, 0x6a4a4f3f, 0x6a4b4f61, 0x6a4c518f, 0x6a4d51b9, 0x6a4e521c,
+    0x6a4f521e, 0x6a505221, 0x6a5152ad, 0x6a5252ae, 0x6a535309, 0x6a545363, 0x6a555372, 0x6a56538e,
+    0x6a57538f, 0x6a585430, 0x6a595437, 0x6a5a542a, 0x6a5b5454, 0x6a5c5445, 0x6a5d5419, 0x6a5e541c,
+    0x6a5f5425, 0x6a605418, 0x6a61543d, 0x6a62544f, 0x6a635441, 0x6a645428, 0x6a655424, 0x6a665447,
+    0x6a6756ee, 0x6a6856e7, 0x6a6956e5, 0x6a6a5741, 0x6a6b5745, 0x6a6c574c, 0x6a6d5749, 0x6a6e574b,
+    0x6a6f5752, 0x6a705906, 0x6a715940, 0x6a7259a6, 0x6a735998, 0x6a7459a0, 0x6a755997, 0x6a76598e,
+    0x6a7759a2, 0x6a785990, 0x6a79598f, 0x6a7a59a7, 0x6a7b59a1, 0x6a7c5b8e, 0x6a7d5b92, 0x6a7e5c28,
+    0x6a7f5c2a, 0x6a815c8d, 0x6a825c8f, 0x6a835c88, 0x6a845c8b, 0x6a855c89, 0x6a865c92, 0x6a875c8a,
+    0x6a885c86, 0x6a895c93, 0x6a8a5c95, 0x6a8b5de0, 0x6a8c5e0a, 0x6a8d5e0e, 0x6a8e5e8b, 0x6a8f5e89,
+    0x6a905e8c, 0x6a915e88, 0x6a925e8d, 0x6a935f05, 0x6a945f1d, 0x6a955f78, 0x6a965f76, 0x6a975fd2,
+    0x6a985fd1, 0x6a995fd0, 0x6a9a5fed, 0x6a9b5fe8, 0x6a9c5fee, 0x6a9d5ff3, 0x6a9e5fe1, 0x6a9f5fe4,
+    0x6aa05fe3, 0x6aa15ffa, 0x6aa25fef, 0x6aa35ff7, 0x6aa45ffb, 0x6aa56000, 0x6aa65ff4, 0x6aa7623a,
+    0x6aa86283, 0x6aa9628c, 0x6aaa628e, 0x6aab628f, 0x6aac6294, 0x6aad6287, 0x6aae6271, 0x6aaf627b,
+    0x6ab0627a, 0x6ab16270, 0x6ab26281, 0x6ab36288, 0x6ab46277, 0x6ab5627d, 0x6ab66272, 0x6ab76274,
+    0x6ab86537, 0x6ab965f0, 0x6aba65f4, 0x6abb65f3, 0x6abc65f2, 0x6abd65f5, 0x6abe6745, 0x6abf6747,
+    0x6ac06759, 0x6ac16755, 0x6ac2674c, 0x6ac36748, 0x6ac4675d, 0x6ac5674d, 0x6ac6675a, 0x6ac7674b,
+    0x6ac86bd0, 0x6ac96c19, 0x6aca6c1a, 0x6acb6c78, 0x6acc6c67, 0x6acd6c6b, 0x6ace6c84, 0x6acf6c8b,
+    0x6ad06c8f, 0x6ad16c71, 0x6ad26c6f, 0x6ad36c69, 0x6ad46c9a, 0x6ad56c6d, 0x6ad66c87, 0x6ad76c95,
+    0x6ad86c9c, 0x6ad96c66, 0x6ada6c73, 0x6adb6c65, 0x6adc6c7b, 0x6add6c8e, 0x6ade7074, 0x6adf707a,
+    0x6ae07263, 0x6ae172bf, 0x6ae272bd, 0x6ae372c3, 0x6ae472c6, 0x6ae572c1, 0x6ae672ba, 0x6ae772c5,
+    0x6ae87395, 0x6ae97397, 0x6aea7393, 0x6aeb7394, 0x6aec7392, 0x6aed753a, 0x6aee7539, 0x6aef7594,
+    0x6af07595, 0x6af17681, 0x6af2793d, 0x6af38034, 0x6af48095, 0x6af58099, 0x6af68090, 0x6af78092,
+    0x6af8809c, 0x6af98290, 0x6afa828f, 0x6afb8285, 0x6afc828e, 0x6afd8291, 0x6b418293, 0x6b42828a,
+    0x6b438283, 0x6b448284, 0x6b458c78, 0x6b468fc9, 0x6b478fbf, 0x6b48909f, 0x6b4990a1, 0x6b4a90a5,
+    0x6b4b909e, 0x6b4c90a7, 0x6b4d90a0, 0x6b4e9630, 0x6b4f9628, 0x6b50962f, 0x6b51962d, 0x6b524e33,
+    0x6b534f98, 0x6b544f7c, 0x6b554f85, 0x6b564f7d, 0x6b574f80, 0x6b584f87, 0x6b594f76, 0x6b5a4f74,
+    0x6b5b4f89, 0x6b5c4f84, 0x6b5d4f77, 0x6b5e4f4c, 0x6b5f4f97, 0x6b604f6a, 0x6b614f9a, 0x6b624f79,
+    0x6b634f81, 0x6b644f78, 0x6b654f90, 0x6b664f9c, 0x6b674f94, 0x6b684f9e, 0x6b694f92, 0x6b6a4f82,
+    0x6b6b4f95, 0x6b6c4f6b, 0x6b6d4f6e, 0x6b6e519e, 0x6b6f51bc, 0x6b7051be, 0x6b715235, 0x6b725232,
+    0x6b735233, 0x6b745246, 0x6b755231, 0x6b7652bc, 0x6b77530a, 0x6b78530b, 0x6b79533c, 0x6b7a5392,
+    0x6b7b5394, 0x6b7c5487, 0x6b7d547f, 0x6b7e5481, 0x6b7f5491, 0x6b815482, 0x6b825488, 0x6b83546b,
+    0x6b84547a, 0x6b85547e, 0x6b865465, 0x6b87546c, 0x6b885474, 0x6b895466, 0x6b8a548d, 0x6b8b546f,
+    0x6b8c5461, 0x6b8d5460, 0x6b8e5498, 0x6b8f5463, 0x6b905467, 0x6b915464, 0x6b9256f7, 0x6b9356f9,
+    0x6b94576f, 0x6b955772, 0x6b96576d, 0x6b97576b, 0x6b985771, 0x6b995770, 0x6b9a5776, 0x6b9b5780,
+    0x6b9c5775, 0x6b9d577b, 0x6b9e5773, 0x6b9f5774, 0x6ba05762, 0x6ba15768, 0x6ba2577d, 0x6ba3590c,
+    0x6ba45945, 0x6ba559b5, 0x6ba659ba, 0x6ba759cf, 0x6ba859ce, 0x6ba959b2, 0x6baa59cc, 0x6bab59c1,
+    0x6bac59b6, 0x6bad59bc, 0x6bae59c3, 0x6baf59d6, 0x6bb059b1, 0x6bb159bd, 0x6bb259c0, 0x6bb359c8,
+    0x6bb459b4, 0x6bb559c7, 0x6bb65b62, 0x6bb75b65, 0x6bb85b93, 0x6bb95b95, 0x6bba5c44, 0x6bbb5c47,
+    0x6bbc5cae, 0x6bbd5ca4, 0x6bbe5ca0, 0x6bbf5cb5, 0x6bc05caf, 0x6bc15ca8, 0x6bc25cac, 0x6bc35c9f,
+    0x6bc45ca3, 0x6bc55cad, 0x6bc65ca2, 0x6bc75caa, 0x6bc85ca7, 0x6bc95c9d, 0x6bca5ca5, 0x6bcb5cb6,
+    0x6bcc5cb0, 0x6bcd5ca6, 0x6bce5e17, 0x6bcf5e14, 0x6bd05e19, 0x6bd15f28, 0x6bd25f22, 0x6bd35f23,
+    0x6bd45f24, 0x6bd55f54, 0x6bd65f82, 0x6bd75f7e, 0x6bd85f7d, 0x6bd95fde, 0x6bda5fe5, 0x6bdb602d,
+    0x6bdc6026, 0x6bdd6019, 0x6bde6032, 0x6bdf600b, 0x6be06034, 0x6be1600a, 0x6be26017, 0x6be36033,
+    0x6be4601a, 0x6be5601e, 0x6be6602c, 0x6be76022, 0x6be8600d, 0x6be96010, 0x6bea602e, 0x6beb6013,
+    0x6bec6011, 0x6bed600c, 0x6bee6009, 0x6bef601c, 0x6bf06214, 0x6bf1623d, 0x6bf262ad, 0x6bf362b4,
+    0x6bf462d1, 0x6bf562be, 0x6bf662aa, 0x6bf762b6, 0x6bf862ca, 0x6bf962ae, 0x6bfa62b3, 0x6bfb62af,
+    0x6bfc62bb, 0x6bfd62a9, 0x6c4162b0, 0x6c4262b8, 0x6c43653d, 0x6c4465a8, 0x6c4565bb, 0x6c466609,
+    0x6c4765fc, 0x6c486604, 0x6c496612, 0x6c4a6608, 0x6c4b65fb, 0x6c4c6603, 0x6c4d660b, 0x6c4e660d,
+    0x6c4f6605, 0x6c5065fd, 0x6c516611, 0x6c526610, 0x6c5366f6, 0x6c54670a, 0x6c556785, 0x6c56676c,
+    0x6c57678e, 0x6c586792, 0x6c596776, 0x6c5a677b, 0x6c5b6798, 0x6c5c6786, 0x6c5d6784, 0x6c5e6774,
+    0x6c5f678d, 0x6c60678c, 0x6c61677a, 0x6c62679f, 0x6c636791, 0x6c646799, 0x6c656783, 0x6c66677d,
+    0x6c676781, 0x6c686778, 0x6c696779, 0x6c6a6794, 0x6c6b6b25, 0x6c6c6b80, 0x6c6d6b7e, 0x6c6e6bde,
+    0x6c6f6c1d, 0x6c706c93, 0x6c716cec, 0x6c726ceb, 0x6c736cee, 0x6c746cd9, 0x6c756cb6, 0x6c766cd4,
+    0x6c776cad, 0x6c786ce7, 0x6c796cb7, 0x6c7a6cd0, 0x6c7b6cc2, 0x6c7c6cba, 0x6c7d6cc3, 0x6c7e6cc6,
+    0x6c7f6ced, 0x6c816cf2, 0x6c826cd2, 0x6c836cdd, 0x6c846cb4, 0x6c856c8a, 0x6c866c9d, 0x6c876c80,
+    0x6c886cde, 0x6c896cc0, 0x6c8a6d30, 0x6c8b6ccd, 0x6c8c6cc7, 0x6c8d6cb0, 0x6c8e6cf9, 0x6c8f6ccf,
+    0x6c906ce9, 0x6c916cd1, 0x6c927094, 0x6c937098, 0x6c947085, 0x6c957093, 0x6c967086, 0x6c977084,
+    0x6c987091, 0x6c997096, 0x6c9a7082, 0x6c9b709a, 0x6c9c7083, 0x6c9d726a, 0x6c9e72d6, 0x6c9f72cb,
+    0x6ca072d8, 0x6ca172c9, 0x6ca272dc, 0x6ca372d2, 0x6ca472d4, 0x6ca572da, 0x6ca672cc, 0x6ca772d1,
+    0x6ca873a4, 0x6ca973a1, 0x6caa73ad, 0x6cab73a6, 0x6cac73a2, 0x6cad73a0, 0x6cae73ac, 0x6caf739d,
+    0x6cb074dd, 0x6cb174e8, 0x6cb2753f, 0x6cb37540, 0x6cb4753e, 0x6cb5758c, 0x6cb67598, 0x6cb776af,
+    0x6cb876f3, 0x6cb976f1, 0x6cba76f0, 0x6cbb76f5, 0x6cbc77f8, 0x6cbd77fc, 0x6cbe77f9, 0x6cbf77fb,
+    0x6cc077fa, 0x6cc177f7, 0x6cc27942, 0x6cc3793f, 0x6cc479c5, 0x6cc57a78, 0x6cc67a7b, 0x6cc77afb,
+    0x6cc87c75, 0x6cc97cfd, 0x6cca8035, 0x6ccb808f, 0x6ccc80ae, 0x6ccd80a3, 0x6cce80b8, 0x6ccf80b5,
+    0x6cd080ad, 0x6cd18220, 0x6cd282a0, 0x6cd382c0, 0x6cd482ab, 0x6cd5829a, 0x6cd68298, 0x6cd7829b,
+    0x6cd882b5, 0x6cd982a7, 0x6cda82ae, 0x6cdb82bc, 0x6cdc829e, 0x6cdd82ba, 0x6cde82b4, 0x6cdf82a8,
+    0x6ce082a1, 0x6ce182a9, 0x6ce282c2, 0x6ce382a4, 0x6ce482c3, 0x6ce582b6, 0x6ce682a2, 0x6ce78670,
+    0x6ce8866f, 0x6ce9866d, 0x6cea866e, 0x6ceb8c56, 0x6cec8fd2, 0x6ced8fcb, 0x6cee8fd3, 0x6cef8fcd,
+    0x6cf08fd6, 0x6cf18fd5, 0x6cf28fd7, 0x6cf390b2, 0x6cf490b4, 0x6cf590af, 0x6cf690b3, 0x6cf790b0,
+    0x6cf89639, 0x6cf9963d, 0x6cfa963c, 0x6cfb963a, 0x6cfc9643, 0x6cfd4fcd, 0x6d414fc5, 0x6d424fd3,
+    0x6d434fb2, 0x6d444fc9, 0x6d454fcb, 0x6d464fc1, 0x6d474fd4, 0x6d484fdc, 0x6d494fd9, 0x6d4a4fbb,
+    0x6d4b4fb3, 0x6d4c4fdb, 0x6d4d4fc7, 0x6d4e4fd6, 0x6d4f4fba, 0x6d504fc0, 0x6d514fb9, 0x6d524fec,
+    0x6d535244, 0x6d545249, 0x6d5552c0, 0x6d5652c2, 0x6d57533d, 0x6d58537c, 0x6d595397, 0x6d5a5396,
+    0x6d5b5399, 0x6d5c5398, 0x6d5d54ba, 0x6d5e54a1, 0x6d5f54ad, 0x6d6054a5, 0x6d6154cf, 0x6d6254c3,
+    0x6d63830d, 0x6d6454b7, 0x6d6554ae, 0x6d6654d6, 0x6d6754b6, 0x6d6854c5, 0x6d6954c6, 0x6d6a54a0,
+    0x6d6b5470, 0x6d6c54bc, 0x6d6d54a2, 0x6d6e54be, 0x6d6f5472, 0x6d7054de, 0x6d7154b0, 0x6d7257b5,
+    0x6d73579e, 0x6d74579f, 0x6d7557a4, 0x6d76578c, 0x6d775797, 0x6d78579d, 0x6d79579b, 0x6d7a5794,
+    0x6d7b5798, 0x6d7c578f, 0x6d7d5799, 0x6d7e57a5, 0x6d7f579a, 0x6d815795, 0x6d8258f4, 0x6d83590d,
+    0x6d845953, 0x6d8559e1, 0x6d8659de, 0x6d8759ee, 0x6d885a00, 0x6d8959f1, 0x6d8a59dd, 0x6d8b59fa,
+    0x6d8c59fd, 0x6d8d59fc, 0x6d8e59f6, 0x6d8f59e4, 0x6d9059f2, 0x6d9159f7, 0x6d9259db, 0x6d9359e9,
+    0x6d9459f3, 0x6d9559f5, 0x6d9659e0, 0x6d9759fe, 0x6d9859f4, 0x6d9959ed, 0x6d9a5ba8, 0x6d9b5c4c,
+    0x6d9c5cd0, 0x6d9d5cd8, 0x6d9e5ccc, 0x6d9f5cd7, 0x6da05ccb, 0x6da15cdb, 0x6da25cde, 0x6da35cda,
+    0x6da45cc9, 0x6da55cc7, 0x6da65cca, 0x6da75cd6, 0x6da85cd3, 0x6da95cd4, 0x6daa5ccf, 0x6dab5cc8,
+    0x6dac5cc6, 0x6dad5cce, 0x6dae5cdf, 0x6daf5cf8, 0x6db05df9, 0x6db15e21, 0x6db25e22, 0x6db35e23,
+    0x6db45e20, 0x6db55e24, 0x6db65eb0, 0x6db75ea4, 0x6db85ea2, 0x6db95e9b, 0x6dba5ea3, 0x6dbb5ea5,
+    0x6dbc5f07, 0x6dbd5f2e, 0x6dbe5f56, 0x6dbf5f86, 0x6dc06037, 0x6dc16039, 0x6dc26054, 0x6dc36072,
+    0x6dc4605e, 0x6dc56045, 0x6dc66053, 0x6dc76047, 0x6dc86049, 0x6dc9605b, 0x6dca604c, 0x6dcb6040,
+    0x6dcc6042, 0x6dcd605f, 0x6dce6024, 0x6dcf6044, 0x6dd06058, 0x6dd16066, 0x6dd2606e, 0x6dd36242,
+    0x6dd46243, 0x6dd562cf, 0x6dd6630d, 0x6dd7630b, 0x6dd862f5, 0x6dd9630e, 0x6dda6303, 0x6ddb62eb,
+    0x6ddc62f9, 0x6ddd630f, 0x6dde630c, 0x6ddf62f8, 0x6de062f6, 0x6de16300, 0x6de26313, 0x6de36314,
+    0x6de462fa, 0x6de56315, 0x6de662fb, 0x6de762f0, 0x6de86541, 0x6de96543, 0x6dea65aa, 0x6deb65bf,
+    0x6dec6636, 0x6ded6621, 0x6dee6632, 0x6def6635, 0x6df0661c, 0x6df16626, 0x6df26622, 0x6df36633,
+    0x6df4662b, 0x6df5663a, 0x6df6661d, 0x6df76634, 0x6df86639, 0x6df9662e, 0x6dfa670f, 0x6dfb6710,
+    0x6dfc67c1, 0x6dfd67f2, 0x6e4167c8, 0x6e4267ba, 0x6e4367dc, 0x6e4467bb, 0x6e4567f8, 0x6e4667d8,
+    0x6e4767c0, 0x6e4867b7, 0x6e4967c5, 0x6e4a67eb, 0x6e4b67e4, 0x6e4c67df, 0x6e4d67b5, 0x6e4e67cd,
+    0x6e4f67b3, 0x6e5067f7, 0x6e5167f6, 0x6e5267ee, 0x6e5367e3, 0x6e5467c2, 0x6e5567b9, 0x6e5667ce,
+    0x6e5767e7, 0x6e5867f0, 0x6e5967b2, 0x6e5a67fc, 0x6e5b67c6, 0x6e5c67ed, 0x6e5d67cc, 0x6e5e67ae,
+    0x6e5f67e6, 0x6e6067db, 0x6e6167fa, 0x6e6267c9, 0x6e6367ca, 0x6e6467c3, 0x6e6567ea, 0x6e6667cb,
+    0x6e676b28, 0x6e686b82, 0x6e696b84, 0x6e6a6bb6, 0x6e6b6bd6, 0x6e6c6bd8, 0x6e6d6be0, 0x6e6e6c20,
+    0x6e6f6c21, 0x6e706d28, 0x6e716d34, 0x6e726d2d, 0x6e736d1f, 0x6e746d3c, 0x6e756d3f, 0x6e766d12,
+    0x6e776d0a, 0x6e786cda, 0x6e796d33, 0x6e7a6d04, 0x6e7b6d19, 0x6e7c6d3a, 0x6e7d6d1a, 0x6e7e6d11,
+    0x6e7f6d00, 0x6e816d1d, 0x6e826d42, 0x6e836d01, 0x6e846d18, 0x6e856d37, 0x6e866d03, 0x6e876d0f,
+    0x6e886d40, 0x6e896d07, 0x6e8a6d20, 0x6e8b6d2c, 0x6e8c6d08, 0x6e8d6d22, 0x6e8e6d09, 0x6e8f6d10,
+    0x6e9070b7, 0x6e91709f, 0x6e9270be, 0x6e9370b1, 0x6e9470b0, 0x6e9570a1, 0x6e9670b4, 0x6e9770b5,
+    0x6e9870a9, 0x6e997241, 0x6e9a7249, 0x6e9b724a, 0x6e9c726c, 0x6e9d7270, 0x6e9e7273, 0x6e9f726e,
+    0x6ea072ca, 0x6ea172e4, 0x6ea272e8, 0x6ea372eb, 0x6ea472df, 0x6ea572ea, 0x6ea672e6, 0x6ea772e3,
+    0x6ea87385, 0x6ea973cc, 0x6eaa73c2, 0x6eab73c8, 0x6eac73c5, 0x6ead73b9, 0x6eae73b6, 0x6eaf73b5,
+    0x6eb073b4, 0x6eb173eb, 0x6eb273bf, 0x6eb373c7, 0x6eb473be, 0x6eb573c3, 0x6eb673c6, 0x6eb773b8,
+    0x6eb873cb, 0x6eb974ec, 0x6eba74ee, 0x6ebb752e, 0x6ebc7547, 0x6ebd7548, 0x6ebe75a7, 0x6ebf75aa,
+    0x6ec07679, 0x6ec176c4, 0x6ec27708, 0x6ec37703, 0x6ec47704, 0x6ec57705, 0x6ec6770a, 0x6ec776f7,
+    0x6ec876fb, 0x6ec976fa, 0x6eca77e7, 0x6ecb77e8, 0x6ecc7806, 0x6ecd7811, 0x6ece7812, 0x6ecf7805,
+    0x6ed07810, 0x6ed1780f, 0x6ed2780e, 0x6ed37809, 0x6ed47803, 0x6ed57813, 0x6ed6794a, 0x6ed7794c,
+    0x6ed8794b, 0x6ed97945, 0x6eda7944, 0x6edb79d5, 0x6edc79cd, 0x6edd79cf, 0x6ede79d6, 0x6edf79ce,
+    0x6ee07a80, 0x6ee17a7e, 0x6ee27ad1, 0x6ee37b00, 0x6ee47b01, 0x6ee57c7a, 0x6ee67c78, 0x6ee77c79,
+    0x6ee87c7f, 0x6ee97c80, 0x6eea7c81, 0x6eeb7d03, 0x6eec7d08, 0x6eed7d01, 0x6eee7f58, 0x6eef7f91,
+    0x6ef07f8d, 0x6ef17fbe, 0x6ef28007, 0x6ef3800e, 0x6ef4800f, 0x6ef58014, 0x6ef68037, 0x6ef780d8,
+    0x6ef880c7, 0x6ef980e0, 0x6efa80d1, 0x6efb80c8, 0x6efc80c2, 0x6efd80d0, 0x6f4180c5, 0x6f4280e3,
+    0x6f4380d9, 0x6f4480dc, 0x6f4580ca, 0x6f4680d5, 0x6f4780c9, 0x6f4880cf, 0x6f4980d7, 0x6f4a80e6,
+    0x6f4b80cd, 0x6f4c81ff, 0x6f4d8221, 0x6f4e8294, 0x6f4f82d9, 0x6f5082fe, 0x6f5182f9, 0x6f528307,
+    0x6f5382e8, 0x6f548300, 0x6f5582d5, 0x6f56833a, 0x6f5782eb, 0x6f5882d6, 0x6f5982f4, 0x6f5a82ec,
+    0x6f5b82e1, 0x6f5c82f2, 0x6f5d82f5, 0x6f5e830c, 0x6f5f82fb, 0x6f6082f6, 0x6f6182f0, 0x6f6282ea,
+    0x6f6382e4, 0x6f6482e0, 0x6f6582fa, 0x6f6682f3, 0x6f6782ed, 0x6f688677, 0x6f698674, 0x6f6a867c,
+    0x6f6b8673, 0x6f6c8841, 0x6f6d884e, 0x6f6e8867, 0x6f6f886a, 0x6f708869, 0x6f7189d3, 0x6f728a04,
+    0x6f738a07, 0x6f748d72, 0x6f758fe3, 0x6f768fe1, 0x6f778fee, 0x6f788fe0, 0x6f7990f1, 0x6f7a90bd,
+    0x6f7b90bf, 0x6f7c90d5, 0x6f7d90c5, 0x6f7e90be, 0x6f7f90c7, 0x6f8190cb, 0x6f8290c8, 0x6f8391d4,
+    0x6f8491d3, 0x6f859654, 0x6f86964f, 0x6f879651, 0x6f889653, 0x6f89964a, 0x6f8a964e, 0x6f8b501e,
+    0x6f8c5005, 0x6f8d5007, 0x6f8e5013, 0x6f8f5022, 0x6f905030, 0x6f91501b, 0x6f924ff5, 0x6f934ff4,
+    0x6f945033, 0x6f955037, 0x6f96502c, 0x6f974ff6, 0x6f984ff7, 0x6f995017, 0x6f9a501c, 0x6f9b5020,
+    0x6f9c5027, 0x6f9d5035, 0x6f9e502f, 0x6f9f5031, 0x6fa0500e, 0x6fa1515a, 0x6fa25194, 0x6fa35193,
+    0x6fa451ca, 0x6fa551c4, 0x6fa651c5, 0x6fa751c8, 0x6fa851ce, 0x6fa95261, 0x6faa525a, 0x6fab5252,
+    0x6fac525e, 0x6fad525f, 0x6fae5255, 0x6faf5262, 0x6fb052cd, 0x6fb1530e, 0x6fb2539e, 0x6fb35526,
+    0x6fb454e2, 0x6fb55517, 0x6fb65512, 0x6fb754e7, 0x6fb854f3, 0x6fb954e4, 0x6fba551a, 0x6fbb54ff,
+    0x6fbc5504, 0x6fbd5508, 0x6fbe54eb, 0x6fbf5511, 0x6fc05505, 0x6fc154f1, 0x6fc2550a, 0x6fc354fb,
+    0x6fc454f7, 0x6fc554f8, 0x6fc654e0, 0x6fc7550e, 0x6fc85503, 0x6fc9550b, 0x6fca5701, 0x6fcb5702,
+    0x6fcc57cc, 0x6fcd5832, 0x6fce57d5, 0x6fcf57d2, 0x6fd057ba, 0x6fd157c6, 0x6fd257bd, 0x6fd357bc,
+    0x6fd457b8, 0x6fd557b6, 0x6fd657bf, 0x6fd757c7, 0x6fd857d0, 0x6fd957b9, 0x6fda57c1, 0x6fdb590e,
+    0x6fdc594a, 0x6fdd5a19, 0x6fde5a16, 0x6fdf5a2d, 0x6fe05a2e, 0x6fe15a15, 0x6fe25a0f, 0x6fe35a17,
+    0x6fe45a0a, 0x6fe55a1e, 0x6fe65a33, 0x6fe75b6c, 0x6fe85ba7, 0x6fe95bad, 0x6fea5bac, 0x6feb5c03,
+    0x6fec5c56, 0x6fed5c54, 0x6fee5cec, 0x6fef5cff, 0x6ff05cee, 0x6ff15cf1, 0x6ff25cf7, 0x6ff35d00,
+    0x6ff45cf9, 0x6ff55e29, 0x6ff65e28, 0x6ff75ea8, 0x6ff85eae, 0x6ff95eaa, 0x6ffa5eac, 0x6ffb5f33,
+    0x6ffc5f30, 0x6ffd5f67, 0x7041605d, 0x7042605a, 0x70436067, 0x70446041, 0x704560a2, 0x70466088,
+    0x70476080, 0x70486092, 0x70496081, 0x704a609d, 0x704b6083, 0x704c6095, 0x704d609b, 0x704e6097,
+    0x704f6087, 0x7050609c, 0x7051608e, 0x70526219, 0x70536246, 0x705462f2, 0x70556310, 0x70566356,
+    0x7057632c, 0x70586344, 0x70596345, 0x705a6336, 0x705b6343, 0x705c63e4, 0x705d6339, 0x705e634b,
+    0x705f634a, 0x7060633c, 0x70616329, 0x70626341, 0x70636334, 0x70646358, 0x70656354, 0x70666359,
+    0x7067632d, 0x70686347, 0x70696333, 0x706a635a, 0x706b6351, 0x706c6338, 0x706d6357, 0x706e6340,
+    0x706f6348, 0x7070654a, 0x70716546, 0x707265c6, 0x707365c3, 0x707465c4, 0x707565c2, 0x7076664a,
+    0x7077665f, 0x70786647, 0x70796651, 0x707a6712, 0x707b6713, 0x707c681f, 0x707d681a, 0x707e6849,
+    0x707f6832, 0x70816833, 0x7082683b, 0x7083684b, 0x7084684f, 0x70856816, 0x70866831, 0x7087681c,
+    0x70886835, 0x7089682b, 0x708a682d, 0x708b682f, 0x708c684e, 0x708d6844, 0x708e6834, 0x708f681d,
+    0x70906812, 0x70916814, 0x70926826, 0x70936828, 0x7094682e, 0x7095684d, 0x7096683a, 0x70976825,
+    0x70986820, 0x70996b2c, 0x709a6b2f, 0x709b6b2d, 0x709c6b31, 0x709d6b34, 0x709e6b6d, 0x709f8082,
+    0x70a06b88, 0x70a16be6, 0x70a26be4, 0x70a36be8, 0x70a46be3, 0x70a56be2, 0x70a66be7, 0x70a76c25,
+    0x70a86d7a, 0x70a96d63, 0x70aa6d64, 0x70ab6d76, 0x70ac6d0d, 0x70ad6d61, 0x70ae6d92, 0x70af6d58,
+    0x70b06d62, 0x70b16d6d, 0x70b26d6f, 0x70b36d91, 0x70b46d8d, 0x70b56def, 0x70b66d7f, 0x70b76d86,
+    0x70b86d5e, 0x70b96d67, 0x70ba6d60, 0x70bb6d97, 0x70bc6d70, 0x70bd6d7c, 0x70be6d5f, 0x70bf6d82,
+    0x70c06d98, 0x70c16d2f, 0x70c26d68, 0x70c36d8b, 0x70c46d7e, 0x70c56d80, 0x70c66d84, 0x70c76d16,
+    0x70c86d83, 0x70c96d7b, 0x70ca6d7d, 0x70cb6d75, 0x70cc6d90, 0x70cd70dc, 0x70ce70d3, 0x70cf70d1,
+    0x70d070dd, 0x70d170cb, 0x70d27f39, 0x70d370e2, 0x70d470d7, 0x70d570d2, 0x70d670de, 0x70d770e0,
+    0x70d870d4, 0x70d970cd, 0x70da70c5, 0x70db70c6, 0x70dc70c7, 0x70dd70da, 0x70de70ce, 0x70df70e1,
+    0x70e07242, 0x70e17278, 0x70e27277, 0x70e37276, 0x70e47300, 0x70e572fa, 0x70e672f4, 0x70e772fe,
+    0x70e872f6, 0x70e972f3, 0x70ea72fb, 0x70eb7301, 0x70ec73d3, 0x70ed73d9, 0x70ee73e5, 0x70ef73d6,
+    0x70f073bc, 0x70f173e7, 0x70f273e3, 0x70f373e9, 0x70f473dc, 0x70f573d2, 0x70f673db, 0x70f773d4,
+    0x70f873dd, 0x70f973da, 0x70fa73d7, 0x70fb73d8, 0x70fc73e8, 0x70fd74de, 0x714174df, 0x714274f4,
+    0x714374f5, 0x71447521, 0x7145755b, 0x7146755f, 0x714775b0, 0x714875c1, 0x714975bb, 0x714a75c4,
+    0x714b75c0, 0x714c75bf, 0x714d75b6, 0x714e75ba, 0x714f768a, 0x715076c9, 0x7151771d, 0x7152771b,
+    0x71537710, 0x71547713, 0x71557712, 0x71567723, 0x71577711, 0x71587715, 0x71597719, 0x715a771a,
+    0x715b7722, 0x715c7727, 0x715d7823, 0x715e782c, 0x715f7822, 0x71607835, 0x7161782f, 0x71627828,
+    0x7163782e, 0x7164782b, 0x71657821, 0x71667829, 0x71677833, 0x7168782a, 0x71697831, 0x716a7954,
+    0x716b795b, 0x716c794f, 0x716d795c, 0x716e7953, 0x716f7952, 0x71707951, 0x717179eb, 0x717279ec,
+    0x717379e0, 0x717479ee, 0x717579ed, 0x717679ea, 0x717779dc, 0x717879de, 0x717979dd, 0x717a7a86,
+    0x717b7a89, 0x717c7a85, 0x717d7a8b, 0x717e7a8c, 0x717f7a8a, 0x71817a87, 0x71827ad8, 0x71837b10,
+    0x71847b04, 0x71857b13, 0x71867b05, 0x71877b0f, 0x71887b08, 0x71897b0a, 0x718a7b0e, 0x718b7b09,
+    0x718c7b12, 0x718d7c84, 0x718e7c91, 0x718f7c8a, 0x71907c8c, 0x71917c88, 0x71927c8d, 0x71937c85,
+    0x71947d1e, 0x71957d1d, 0x71967d11, 0x71977d0e, 0x71987d18, 0x71997d16, 0x719a7d13, 0x719b7d1f,
+    0x719c7d12, 0x719d7d0f, 0x719e7d0c, 0x719f7f5c, 0x71a07f61, 0x71a17f5e, 0x71a27f60, 0x71a37f5d,
+    0x71a47f5b, 0x71a57f96, 0x71a67f92, 0x71a77fc3, 0x71a87fc2, 0x71a97fc0, 0x71aa8016, 0x71ab803e,
+    0x71ac8039, 0x71ad80fa, 0x71ae80f2, 0x71af80f9, 0x71b080f5, 0x71b18101, 0x71b280fb, 0x71b38100,
+    0x71b48201, 0x71b5822f, 0x71b68225, 0x71b78333, 0x71b8832d, 0x71b98344, 0x71ba8319, 0x71bb8351,
+    0x71bc8325, 0x71bd8356, 0x71be833f, 0x71bf8341, 0x71c08326, 0x71c1831c, 0x71c28322, 0x71c38342,
+    0x71c4834e, 0x71c5831b, 0x71c6832a, 0x71c78308, 0x71c8833c, 0x71c9834d, 0x71ca8316, 0x71cb8324,
+    0x71cc8320, 0x71cd8337, 0x71ce832f, 0x71cf8329, 0x71d08347, 0x71d18345, 0x71d2834c, 0x71d38353,
+    0x71d4831e, 0x71d5832c, 0x71d6834b, 0x71d78327, 0x71d88348, 0x71d98653, 0x71da8652, 0x71db86a2,
+    0x71dc86a8, 0x71dd8696, 0x71de868d, 0x71df8691, 0x71e0869e, 0x71e18687, 0x71e28697, 0x71e38686,
+    0x71e4868b, 0x71e5869a, 0x71e68685, 0x71e786a5, 0x71e88699, 0x71e986a1, 0x71ea86a7, 0x71eb8695,
+    0x71ec8698, 0x71ed868e, 0x71ee869d, 0x71ef8690, 0x71f08694, 0x71f18843, 0x71f28844, 0x71f3886d,
+    0x71f48875, 0x71f58876, 0x71f68872, 0x71f78880, 0x71f88871, 0x71f9887f, 0x71fa886f, 0x71fb8883,
+    0x71fc887e, 0x71fd8874, 0x7241887c, 0x72428a12, 0x72438c47, 0x72448c57, 0x72458c7b, 0x72468ca4,
+    0x72478ca3, 0x72488d76, 0x72498d78, 0x724a8db5, 0x724b8db7, 0x724c8db6, 0x724d8ed1, 0x724e8ed3,
+    0x724f8ffe, 0x72508ff5, 0x72519002, 0x72528fff, 0x72538ffb, 0x72549004, 0x72558ffc, 0x72568ff6,
+    0x725790d6, 0x725890e0, 0x725990d9, 0x725a90da, 0x725b90e3, 0x725c90df, 0x725d90e5, 0x725e90d8,
+    0x725f90db, 0x726090d7, 0x726190dc, 0x726290e4, 0x72639150, 0x7264914e, 0x7265914f, 0x726691d5,
+    0x726791e2, 0x726891da, 0x7269965c, 0x726a965f, 0x726b96bc, 0x726c98e3, 0x726d9adf, 0x726e9b2f,
+    0x726f4e7f, 0x72705070, 0x7271506a, 0x72725061, 0x7273505e, 0x72745060, 0x72755053, 0x7276504b,
+    0x7277505d, 0x72785072, 0x72795048, 0x727a504d, 0x727b5041, 0x727c505b, 0x727d504a, 0x727e5062,
+    0x727f5015, 0x72815045, 0x7282505f, 0x72835069, 0x7284506b, 0x72855063, 0x72865064, 0x72875046,
+    0x72885040, 0x7289506e, 0x728a5073, 0x728b5057, 0x728c5051, 0x728d51d0, 0x728e526b, 0x728f526d,
+    0x7290526c, 0x7291526e, 0x729252d6, 0x729352d3, 0x7294532d, 0x7295539c, 0x72965575, 0x72975576,
+    0x7298553c, 0x7299554d, 0x729a5550, 0x729b5534, 0x729c552a, 0x729d5551, 0x729e5562, 0x729f5536,
+    0x72a05535, 0x72a15530, 0x72a25552, 0x72a35545, 0x72a4550c, 0x72a55532, 0x72a65565, 0x72a7554e,
+    0x72a85539, 0x72a95548, 0x72aa552d, 0x72ab553b, 0x72ac5540, 0x72ad554b, 0x72ae570a, 0x72af5707,
+    0x72b057fb, 0x72b15814, 0x72b257e2, 0x72b357f6, 0x72b457dc, 0x72b557f4, 0x72b65800, 0x72b757ed,
+    0x72b857fd, 0x72b95808, 0x72ba57f8, 0x72bb580b, 0x72bc57f3, 0x72bd57cf, 0x72be5807, 0x72bf57ee,
+    0x72c057e3, 0x72c157f2, 0x72c257e5, 0x72c357ec, 0x72c457e1, 0x72c5580e, 0x72c657fc, 0x72c75810,
+    0x72c857e7, 0x72c95801, 0x72ca580c, 0x72cb57f1, 0x72cc57e9, 0x72cd57f0, 0x72ce580d, 0x72cf5804,
+    0x72d0595c, 0x72d15a60, 0x72d25a58, 0x72d35a55, 0x72d45a67, 0x72d55a5e, 0x72d65a38, 0x72d75a35,
+    0x72d85a6d, 0x72d95a50, 0x72da5a5f, 0x72db5a65, 0x72dc5a6c, 0x72dd5a53, 0x72de5a64, 0x72df5a57,
+    0x72e05a43, 0x72e15a5d, 0x72e25a52, 0x72e35a44, 0x72e45a5b, 0x72e55a48, 0x72e65a8e, 0x72e75a3e,
+    0x72e85a4d, 0x72e95a39, 0x72ea5a4c, 0x72eb5a70, 0x72ec5a69, 0x72ed5a47, 0x72ee5a51, 0x72ef5a56,
+    0x72f05a42, 0x72f15a5c, 0x72f25b72, 0x72f35b6e, 0x72f45bc1, 0x72f55bc0, 0x72f65c59, 0x72f75d1e,
+    0x72f85d0b, 0x72f95d1d, 0x72fa5d1a, 0x72fb5d20, 0x72fc5d0c, 0x72fd5d28, 0x73415d0d, 0x73425d26,
+    0x73435d25, 0x73445d0f, 0x73455d30, 0x73465d12, 0x73475d23, 0x73485d1f, 0x73495d2e, 0x734a5e3e,
+    0x734b5e34, 0x734c5eb1, 0x734d5eb4, 0x734e5eb9, 0x734f5eb2, 0x73505eb3, 0x73515f36, 0x73525f38,
+    0x73535f9b, 0x73545f96, 0x73555f9f, 0x7356608a, 0x73576090, 0x73586086, 0x735960be, 0x735a60b0,
+    0x735b60ba, 0x735c60d3, 0x735d60d4, 0x735e60cf, 0x735f60e4, 0x736060d9, 0x736160dd, 0x736260c8,
+    0x736360b1, 0x736460db, 0x736560b7, 0x736660ca, 0x736760bf, 0x736860c3, 0x736960cd, 0x736a60c0,
+    0x736b6332, 0x736c6365, 0x736d638a, 0x736e6382, 0x736f637d, 0x737063bd, 0x7371639e, 0x737263ad,
+    0x7373639d, 0x73746397, 0x737563ab, 0x7376638e, 0x7377636f, 0x73786387, 0x73796390, 0x737a636e,
+    0x737b63af, 0x737c6375, 0x737d639c, 0x737e636d, 0x737f63ae, 0x7381637c, 0x738263a4, 0x7383633b,
+    0x7384639f, 0x73856378, 0x73866385, 0x73876381, 0x73886391, 0x7389638d, 0x738a6370, 0x738b6553,
+    0x738c65cd, 0x738d6665, 0x738e6661, 0x738f665b, 0x73906659, 0x7391665c, 0x73926662, 0x73936718,
+    0x73946879, 0x73956887, 0x73966890, 0x7397689c, 0x7398686d, 0x7399686e, 0x739a68ae, 0x739b68ab,
+    0x739c6956, 0x739d686f, 0x739e68a3, 0x739f68ac, 0x73a068a9, 0x73a16875, 0x73a26874, 0x73a368b2,
+    0x73a4688f, 0x73a56877, 0x73a66892, 0x73a7687c, 0x73a8686b, 0x73a96872, 0x73aa68aa, 0x73ab6880,
+    0x73ac6871, 0x73ad687e, 0x73ae689b, 0x73af6896, 0x73b0688b, 0x73b168a0, 0x73b26889, 0x73b368a4,
+    0x73b46878, 0x73b5687b, 0x73b66891, 0x73b7688c, 0x73b8688a, 0x73b9687d, 0x73ba6b36, 0x73bb6b33,
+    0x73bc6b37, 0x73bd6b38, 0x73be6b91, 0x73bf6b8f, 0x73c06b8d, 0x73c16b8e, 0x73c26b8c, 0x73c36c2a,
+    0x73c46dc0, 0x73c56dab, 0x73c66db4, 0x73c76db3, 0x73c86e74, 0x73c96dac, 0x73ca6de9, 0x73cb6de2,
+    0x73cc6db7, 0x73cd6df6, 0x73ce6dd4, 0x73cf6e00, 0x73d06dc8, 0x73d16de0, 0x73d26ddf, 0x73d36dd6,
+    0x73d46dbe, 0x73d56de5, 0x73d66ddc, 0x73d76ddd, 0x73d86ddb, 0x73d96df4, 0x73da6dca, 0x73db6dbd,
+    0x73dc6ded, 0x73dd6df0, 0x73de6dba, 0x73df6dd5, 0x73e06dc2, 0x73e16dcf, 0x73e26dc9, 0x73e36dd0,
+    0x73e46df2, 0x73e56dd3, 0x73e66dfd, 0x73e76dd7, 0x73e86dcd, 0x73e96de3, 0x73ea6dbb, 0x73eb70fa,
+    0x73ec710d, 0x73ed70f7, 0x73ee7117, 0x73ef70f4, 0x73f0710c, 0x73f170f0, 0x73f27104, 0x73f370f3,
+    0x73f47110, 0x73f570fc, 0x73f670ff, 0x73f77106, 0x73f87113, 0x73f97100, 0x73fa70f8, 0x73fb70f6,
+    0x73fc710b, 0x73fd7102, 0x7441710e, 0x7442727e, 0x7443727b, 0x7444727c, 0x7445727f, 0x7446731d,
+    0x74477317, 0x74487307, 0x74497311, 0x744a7318, 0x744b730a, 0x744c7308, 0x744d72ff, 0x744e730f,
+    0x744f731e, 0x74507388, 0x745173f6, 0x745273f8, 0x745373f5, 0x74547404, 0x74557401, 0x745673fd,
+    0x74577407, 0x74587400, 0x745973fa, 0x745a73fc, 0x745b73ff, 0x745c740c, 0x745d740b, 0x745e73f4,
+    0x745f7408, 0x74607564, 0x74617563, 0x746275ce, 0x746375d2, 0x746475cf, 0x746575cb, 0x746675cc,
+    0x746775d1, 0x746875d0, 0x7469768f, 0x746a7689, 0x746b76d3, 0x746c7739, 0x746d772f, 0x746e772d,
+    0x746f7731, 0x74707732, 0x74717734, 0x74727733, 0x7473773d, 0x74747725, 0x7475773b, 0x74767735,
+    0x74777848, 0x74787852, 0x74797849, 0x747a784d, 0x747b784a, 0x747c784c, 0x747d7826, 0x747e7845,
+    0x747f7850, 0x74817964, 0x74827967, 0x74837969, 0x7484796a, 0x74857963, 0x7486796b, 0x74877961,
+    0x748879bb, 0x748979fa, 0x748a79f8, 0x748b79f6, 0x748c79f7, 0x748d7a8f, 0x748e7a94, 0x748f7a90,
+    0x74907b35, 0x74917b47, 0x74927b34, 0x74937b25, 0x74947b30, 0x74957b22, 0x74967b24, 0x74977b33,
+    0x74987b18, 0x74997b2a, 0x749a7b1d, 0x749b7b31, 0x749c7b2b, 0x749d7b2d, 0x749e7b2f, 0x749f7b32,
+    0x74a07b38, 0x74a17b1a, 0x74a27b23, 0x74a37c94, 0x74a47c98, 0x74a57c96, 0x74a67ca3, 0x74a77d35,
+    0x74a87d3d, 0x74a97d38, 0x74aa7d36, 0x74ab7d3a, 0x74ac7d45, 0x74ad7d2c, 0x74ae7d29, 0x74af7d41,
+    0x74b07d47, 0x74b17d3e, 0x74b27d3f, 0x74b37d4a, 0x74b47d3b, 0x74b57d28, 0x74b67f63, 0x74b77f95,
+    0x74b87f9c, 0x74b97f9d, 0x74ba7f9b, 0x74bb7fca, 0x74bc7fcb, 0x74bd7fcd, 0x74be7fd0, 0x74bf7fd1,
+    0x74c07fc7, 0x74c17fcf, 0x74c27fc9, 0x74c3801f, 0x74c4801e, 0x74c5801b, 0x74c68047, 0x74c78043,
+    0x74c88048, 0x74c98118, 0x74ca8125, 0x74cb8119, 0x74cc811b, 0x74cd812d, 0x74ce811f, 0x74cf812c,
+    0x74d0811e, 0x74d18121, 0x74d28115, 0x74d38127, 0x74d4811d, 0x74d58122, 0x74d68211, 0x74d78238,
+    0x74d88233, 0x74d9823a, 0x74da8234, 0x74db8232, 0x74dc8274, 0x74dd8390, 0x74de83a3, 0x74df83a8,
+    0x74e0838d, 0x74e1837a, 0x74e28373, 0x74e383a4, 0x74e48374, 0x74e5838f, 0x74e68381, 0x74e78395,
+    0x74e88399, 0x74e98375, 0x74ea8394, 0x74eb83a9, 0x74ec837d, 0x74ed8383, 0x74ee838c, 0x74ef839d,
+    0x74f0839b, 0x74f183aa, 0x74f2838b, 0x74f3837e, 0x74f483a5, 0x74f583af, 0x74f68388, 0x74f78397,
+    0x74f883b0, 0x74f9837f, 0x74fa83a6, 0x74fb8387, 0x74fc83ae, 0x74fd8376, 0x7541839a, 0x75428659,
+    0x75438656, 0x754486bf, 0x754586b7, 0x754686c2, 0x754786c1, 0x754886c5, 0x754986ba, 0x754a86b0,
+    0x754b86c8, 0x754c86b9, 0x754d86b3, 0x754e86b8, 0x754f86cc, 0x755086b4, 0x755186bb, 0x755286bc,
+    0x755386c3, 0x755486bd, 0x755586be, 0x75568852, 0x75578889, 0x75588895, 0x755988a8, 0x755a88a2,
+    0x755b88aa, 0x755c889a, 0x755d8891, 0x755e88a1, 0x755f889f, 0x75608898, 0x756188a7, 0x75628899,
+    0x7563889b, 0x75648897, 0x756588a4, 0x756688ac, 0x7567888c, 0x75688893, 0x7569888e, 0x756a8982,
+    0x756b89d6, 0x756c89d9, 0x756d89d5, 0x756e8a30, 0x756f8a27, 0x75708a2c, 0x75718a1e, 0x75728c39,
+    0x75738c3b, 0x75748c5c, 0x75758c5d, 0x75768c7d, 0x75778ca5, 0x75788d7d, 0x75798d7b, 0x757a8d79,
+    0x757b8dbc, 0x757c8dc2, 0x757d8db9, 0x757e8dbf, 0x757f8dc1, 0x75818ed8, 0x75828ede, 0x75838edd,
+    0x75848edc, 0x75858ed7, 0x75868ee0, 0x75878ee1, 0x75889024, 0x7589900b, 0x758a9011, 0x758b901c,
+    0x758c900c, 0x758d9021, 0x758e90ef, 0x758f90ea, 0x759090f0, 0x759190f4, 0x759290f2, 0x759390f3,
+    0x759490d4, 0x759590eb, 0x759690ec, 0x759790e9, 0x75989156, 0x75999158, 0x759a915a, 0x759b9153,
+    0x759c9155, 0x759d91ec, 0x759e91f4, 0x759f91f1, 0x75a091f3, 0x75a191f8, 0x75a291e4, 0x75a391f9,
+    0x75a491ea, 0x75a591eb, 0x75a691f7, 0x75a791e8, 0x75a891ee, 0x75a9957a, 0x75aa9586, 0x75ab9588,
+    0x75ac967c, 0x75ad966d, 0x75ae966b, 0x75af9671, 0x75b0966f, 0x75b196bf, 0x75b2976a, 0x75b39804,
+    0x75b498e5, 0x75b59997, 0x75b6509b, 0x75b75095, 0x75b85094, 0x75b9509e, 0x75ba508b, 0x75bb50a3,
+    0x75bc5083, 0x75bd508c, 0x75be508e, 0x75bf509d, 0x75c05068, 0x75c1509c, 0x75c25092, 0x75c35082,
+    0x75c45087, 0x75c5515f, 0x75c651d4, 0x75c75312, 0x75c85311, 0x75c953a4, 0x75ca53a7, 0x75cb5591,
+    0x75cc55a8, 0x75cd55a5, 0x75ce55ad, 0x75cf5577, 0x75d05645, 0x75d155a2, 0x75d25593, 0x75d35588,
+    0x75d4558f, 0x75d555b5, 0x75d65581, 0x75d755a3, 0x75d85592, 0x75d955a4, 0x75da557d, 0x75db558c,
+    0x75dc55a6, 0x75dd557f, 0x75de5595, 0x75df55a1, 0x75e0558e, 0x75e1570c, 0x75e25829, 0x75e35837,
+    0x75e45819, 0x75e5581e, 0x75e65827, 0x75e75823, 0x75e85828, 0x75e957f5, 0x75ea5848, 0x75eb5825,
+    0x75ec581c, 0x75ed581b, 0x75ee5833, 0x75ef583f, 0x75f05836, 0x75f1582e, 0x75f25839, 0x75f35838,
+    0x75f4582d, 0x75f5582c, 0x75f6583b, 0x75f75961, 0x75f85aaf, 0x75f95a94, 0x75fa5a9f, 0x75fb5a7a,
+    0x75fc5aa2, 0x75fd5a9e, 0x76415a78, 0x76425aa6, 0x76435a7c, 0x76445aa5, 0x76455aac, 0x76465a95,
+    0x76475aae, 0x76485a37, 0x76495a84, 0x764a5a8a, 0x764b5a97, 0x764c5a83, 0x764d5a8b, 0x764e5aa9,
+    0x764f5a7b, 0x76505a7d, 0x76515a8c, 0x76525a9c, 0x76535a8f, 0x76545a93, 0x76555a9d, 0x76565bea,
+    0x76575bcd, 0x76585bcb, 0x76595bd4, 0x765a5bd1, 0x765b5bca, 0x765c5bce, 0x765d5c0c, 0x765e5c30,
+    0x765f5d37, 0x76605d43, 0x76615d6b, 0x76625d41, 0x76635d4b, 0x76645d3f, 0x76655d35, 0x76665d51,
+    0x76675d4e, 0x76685d55, 0x76695d33, 0x766a5d3a, 0x766b5d52, 0x766c5d3d, 0x766d5d31, 0x766e5d59,
+    0x766f5d42, 0x76705d39, 0x76715d49, 0x76725d38, 0x76735d3c, 0x76745d32, 0x76755d36, 0x76765d40,
+    0x76775d45, 0x76785e44, 0x76795e41, 0x767a5f58, 0x767b5fa6, 0x767c5fa5, 0x767d5fab, 0x767e60c9,
+    0x767f60b9, 0x768160cc, 0x768260e2, 0x768360ce, 0x768460c4, 0x76856114, 0x768660f2, 0x7687610a,
+    0x76886116, 0x76896105, 0x768a60f5, 0x768b6113, 0x768c60f8, 0x768d60fc, 0x768e60fe, 0x768f60c1,
+    0x76906103, 0x76916118, 0x7692611d, 0x76936110, 0x769460ff, 0x76956104, 0x7696610b, 0x7697624a,
+    0x76986394, 0x769963b1, 0x769a63b0, 0x769b63ce, 0x769c63e5, 0x769d63e8, 0x769e63ef, 0x769f63c3,
+    0x76a0649d, 0x76a163f3, 0x76a263ca, 0x76a363e0, 0x76a463f6, 0x76a563d5, 0x76a663f2, 0x76a763f5,
+    0x76a86461, 0x76a963df, 0x76aa63be, 0x76ab63dd, 0x76ac63dc, 0x76ad63c4, 0x76ae63d8, 0x76af63d3,
+    0x76b063c2, 0x76b163c7, 0x76b263cc, 0x76b363cb, 0x76b463c8, 0x76b563f0, 0x76b663d7, 0x76b763d9,
+    0x76b86532, 0x76b96567, 0x76ba656a, 0x76bb6564, 0x76bc655c, 0x76bd6568, 0x76be6565, 0x76bf658c,
+    0x76c0659d, 0x76c1659e, 0x76c265ae, 0x76c365d0, 0x76c465d2, 0x76c5667c, 0x76c6666c, 0x76c7667b,
+    0x76c86680, 0x76c96671, 0x76ca6679, 0x76cb666a, 0x76cc6672, 0x76cd6701, 0x76ce690c, 0x76cf68d3,
+    0x76d06904, 0x76d168dc, 0x76d2692a, 0x76d368ec, 0x76d468ea, 0x76d568f1, 0x76d6690f, 0x76d768d6,
+    0x76d868f7, 0x76d968eb, 0x76da68e4, 0x76db68f6, 0x76dc6913, 0x76dd6910, 0x76de68f3, 0x76df68e1,
+    0x76e06907, 0x76e168cc, 0x76e26908, 0x76e36970, 0x76e468b4, 0x76e56911, 0x76e668ef, 0x76e768c6,
+    0x76e86914, 0x76e968f8, 0x76ea68d0, 0x76eb68fd, 0x76ec68fc, 0x76ed68e8, 0x76ee690b, 0x76ef690a,
+    0x76f06917, 0x76f168ce, 0x76f268c8, 0x76f368dd, 0x76f468de, 0x76f568e6, 0x76f668f4, 0x76f768d1,
+    0x76f86906, 0x76f968d4, 0x76fa68e9, 0x76fb6915, 0x76fc6925, 0x76fd68c7, 0x77416b39, 0x77426b3b,
+    0x77436b3f, 0x77446b3c, 0x77456b94, 0x77466b97, 0x77476b99, 0x77486b95, 0x77496bbd, 0x774a6bf0,
+    0x774b6bf2, 0x774c6bf3, 0x774d6c30, 0x774e6dfc, 0x774f6e46, 0x77506e47, 0x77516e1f, 0x77526e49,
+    0x77536e88, 0x77546e3c, 0x77556e3d, 0x77566e45, 0x77576e62, 0x77586e2b, 0x77596e3f, 0x775a6e41,
+    0x775b6e5d, 0x775c6e73, 0x775d6e1c, 0x775e6e33, 0x775f6e4b, 0x77606e40, 0x77616e51, 0x77626e3b,
+    0x77636e03, 0x77646e2e, 0x77656e5e, 0x77666e68, 0x77676e5c, 0x77686e61, 0x77696e31, 0x776a6e28,
+    0x776b6e60, 0x776c6e71, 0x776d6e6b, 0x776e6e39, 0x776f6e22, 0x77706e30, 0x77716e53, 0x77726e65,
+    0x77736e27, 0x77746e78, 0x77756e64, 0x77766e77, 0x77776e55, 0x77786e79, 0x77796e52, 0x777a6e66,
+    0x777b6e35, 0x777c6e36, 0x777d6e5a, 0x777e7120, 0x777f711e, 0x7781712f, 0x778270fb, 0x7783712e,
+    0x77847131, 0x77857123, 0x77867125, 0x77877122, 0x77887132, 0x7789711f, 0x778a7128, 0x778b713a,
+    0x778c711b, 0x778d724b, 0x778e725a, 0x778f7288, 0x77907289, 0x77917286, 0x77927285, 0x7793728b,
+    0x77947312, 0x7795730b, 0x77967330, 0x77977322, 0x77987331, 0x77997333, 0x779a7327, 0x779b7332,
+    0x779c732d, 0x779d7326, 0x779e7323, 0x779f7335, 0x77a0730c, 0x77a1742e, 0x77a2742c, 0x77a37430,
+    0x77a4742b, 0x77a57416, 0x77a6741a, 0x77a77421, 0x77a8742d, 0x77a97431, 0x77aa7424, 0x77ab7423,
+    0x77ac741d, 0x77ad7429, 0x77ae7420, 0x77af7432, 0x77b074fb, 0x77b1752f, 0x77b2756f, 0x77b3756c,
+    0x77b475e7, 0x77b575da, 0x77b675e1, 0x77b775e6, 0x77b875dd, 0x77b975df, 0x77ba75e4, 0x77bb75d7,
+    0x77bc7695, 0x77bd7692, 0x77be76da, 0x77bf7746, 0x77c07747, 0x77c17744, 0x77c2774d, 0x77c37745,
+    0x77c4774a, 0x77c5774e, 0x77c6774b, 0x77c7774c, 0x77c877de, 0x77c977ec, 0x77ca7860, 0x77cb7864,
+    0x77cc7865, 0x77cd785c, 0x77ce786d, 0x77cf7871, 0x77d0786a, 0x77d1786e, 0x77d27870, 0x77d37869,
+    0x77d47868, 0x77d5785e, 0x77d67862, 0x77d77974, 0x77d87973, 0x77d97972, 0x77da7970, 0x77db7a02,
+    0x77dc7a0a, 0x77dd7a03, 0x77de7a0c, 0x77df7a04, 0x77e07a99, 0x77e17ae6, 0x77e27ae4, 0x77e37b4a,
+    0x77e47b3b, 0x77e57b44, 0x77e67b48, 0x77e77b4c, 0x77e87b4e, 0x77e97b40, 0x77ea7b58, 0x77eb7b45,
+    0x77ec7ca2, 0x77ed7c9e, 0x77ee7ca8, 0x77ef7ca1, 0x77f07d58, 0x77f17d6f, 0x77f27d63, 0x77f37d53,
+    0x77f47d56, 0x77f57d67, 0x77f67d6a, 0x77f77d4f, 0x77f87d6d, 0x77f97d5c, 0x77fa7d6b, 0x77fb7d52,
+    0x77fc7d54, 0x77fd7d69, 0x78417d51, 0x78427d5f, 0x78437d4e, 0x78447f3e, 0x78457f3f, 0x78467f65,
+    0x78477f66, 0x78487fa2, 0x78497fa0, 0x784a7fa1, 0x784b7fd7, 0x784c8051, 0x784d804f, 0x784e8050,
+    0x784f80fe, 0x785080d4, 0x78518143, 0x7852814a, 0x78538152, 0x7854814f, 0x78558147, 0x7856813d,
+    0x7857814d, 0x7858813a, 0x785981e6, 0x785a81ee, 0x785b81f7, 0x785c81f8, 0x785d81f9, 0x785e8204,
+    0x785f823c, 0x7860823d, 0x7861823f, 0x78628275, 0x7863833b, 0x786483cf, 0x786583f9, 0x78668423,
+    0x786783c0, 0x786883e8, 0x78698412, 0x786a83e7, 0x786b83e4, 0x786c83fc, 0x786d83f6, 0x786e8410,
+    0x786f83c6, 0x787083c8, 0x787183eb, 0x787283e3, 0x787383bf, 0x78748401, 0x787583dd, 0x787683e5,
+    0x787783d8, 0x787883ff, 0x787983e1, 0x787a83cb, 0x787b83ce, 0x787c83d6, 0x787d83f5, 0x787e83c9,
+    0x787f8409, 0x7881840f, 0x788283de, 0x78838411, 0x78848406, 0x788583c2, 0x788683f3, 0x788783d5,
+    0x788883fa, 0x788983c7, 0x788a83d1, 0x788b83ea, 0x788c8413, 0x788d83c3, 0x788e83ec, 0x788f83ee,
+    0x789083c4, 0x789183fb, 0x789283d7, 0x789383e2, 0x7894841b, 0x789583db, 0x789683fe, 0x789786d8,
+    0x789886e2, 0x789986e6, 0x789a86d3, 0x789b86e3, 0x789c86da, 0x789d86ea, 0x789e86dd, 0x789f86eb,
+    0x78a086dc, 0x78a186ec, 0x78a286e9, 0x78a386d7, 0x78a486e8, 0x78a586d1, 0x78a68848, 0x78a78856,
+    0x78a88855, 0x78a988ba, 0x78aa88d7, 0x78ab88b9, 0x78ac88b8, 0x78ad88c0, 0x78ae88be, 0x78af88b6,
+    0x78b088bc, 0x78b188b7, 0x78b288bd, 0x78b388b2, 0x78b48901, 0x78b588c9, 0x78b68995, 0x78b78998,
+    0x78b88997, 0x78b989dd, 0x78ba89da, 0x78bb89db, 0x78bc8a4e, 0x78bd8a4d, 0x78be8a39, 0x78bf8a59,
+    0x78c08a40, 0x78c18a57, 0x78c28a58, 0x78c38a44, 0x78c48a45, 0x78c58a52, 0x78c68a48, 0x78c78a51,
+    0x78c88a4a, 0x78c98a4c, 0x78ca8a4f, 0x78cb8c5f, 0x78cc8c81, 0x78cd8c80, 0x78ce8cba, 0x78cf8cbe,
+    0x78d08cb0, 0x78d18cb9, 0x78d28cb5, 0x78d38d84, 0x78d48d80, 0x78d58d89, 0x78d68dd8, 0x78d78dd3,
+    0x78d88dcd, 0x78d98dc7, 0x78da8dd6, 0x78db8ddc, 0x78dc8dcf, 0x78dd8dd5, 0x78de8dd9, 0x78df8dc8,
+    0x78e08dd7, 0x78e18dc5, 0x78e28eef, 0x78e38ef7, 0x78e48efa, 0x78e58ef9, 0x78e68ee6, 0x78e78eee,
+    0x78e88ee5, 0x78e98ef5, 0x78ea8ee7, 0x78eb8ee8, 0x78ec8ef6, 0x78ed8eeb, 0x78ee8ef1, 0x78ef8eec,
+    0x78f08ef4, 0x78f18ee9, 0x78f2902d, 0x78f39034, 0x78f4902f, 0x78f59106, 0x78f6912c, 0x78f79104,
+    0x78f890ff, 0x78f990fc, 0x78fa9108, 0x78fb90f9, 0x78fc90fb, 0x78fd9101, 0x79419100, 0x79429107,
+    0x79439105, 0x79449103, 0x79459161, 0x79469164, 0x7947915f, 0x79489162, 0x79499160, 0x794a9201,
+    0x794b920a, 0x794c9225, 0x794d9203, 0x794e921a, 0x794f9226, 0x7950920f, 0x7951920c, 0x79529200,
+    0x79539212, 0x795491ff, 0x795591fd, 0x79569206, 0x79579204, 0x79589227, 0x79599202, 0x795a921c,
+    0x795b9224, 0x795c9219, 0x795d9217, 0x795e9205, 0x795f9216, 0x7960957b, 0x7961958d, 0x7962958c,
+    0x79639590, 0x79649687, 0x7965967e, 0x79669688, 0x79679689, 0x79689683, 0x79699680, 0x796a96c2,
+    0x796b96c8, 0x796c96c3, 0x796d96f1, 0x796e96f0, 0x796f976c, 0x79709770, 0x7971976e, 0x79729807,
+    0x797398a9, 0x797498eb, 0x79759ce6, 0x79769ef9, 0x79774e83, 0x79784e84, 0x79794eb6, 0x797a50bd,
+    0x797b50bf, 0x797c50c6, 0x797d50ae, 0x797e50c4, 0x797f50ca, 0x798150b4, 0x798250c8, 0x798350c2,
+    0x798450b0, 0x798550c1, 0x798650ba, 0x798750b1, 0x798850cb, 0x798950c9, 0x798a50b6, 0x798b50b8,
+    0x798c51d7, 0x798d527a, 0x798e5278, 0x798f527b, 0x7990527c, 0x799155c3, 0x799255db, 0x799355cc,
+    0x799455d0, 0x799555cb, 0x799655ca, 0x799755dd, 0x799855c0, 0x799955d4, 0x799a55c4, 0x799b55e9,
+    0x799c55bf, 0x799d55d2, 0x799e558d, 0x799f55cf, 0x79a055d5, 0x79a155e2, 0x79a255d6, 0x79a355c8,
+    0x79a455f2, 0x79a555cd, 0x79a655d9, 0x79a755c2, 0x79a85714, 0x79a95853, 0x79aa5868, 0x79ab5864,
+    0x79ac584f, 0x79ad584d, 0x79ae5849, 0x79af586f, 0x79b05855, 0x79b1584e, 0x79b2585d, 0x79b35859,
+    0x79b45865, 0x79b5585b, 0x79b6583d, 0x79b75863, 0x79b85871, 0x79b958fc, 0x79ba5ac7, 0x79bb5ac4,
+    0x79bc5acb, 0x79bd5aba, 0x79be5ab8, 0x79bf5ab1, 0x79c05ab5, 0x79c15ab0, 0x79c25abf, 0x79c35ac8,
+    0x79c45abb, 0x79c55ac6, 0x79c65ab7, 0x79c75ac0, 0x79c85aca, 0x79c95ab4, 0x79ca5ab6, 0x79cb5acd,
+    0x79cc5ab9, 0x79cd5a90, 0x79ce5bd6, 0x79cf5bd8, 0x79d05bd9, 0x79d15c1f, 0x79d25c33, 0x79d35d71,
+    0x79d45d63, 0x79d55d4a, 0x79d65d65, 0x79d75d72, 0x79d85d6c, 0x79d95d5e, 0x79da5d68, 0x79db5d67,
+    0x79dc5d62, 0x79dd5df0, 0x79de5e4f, 0x79df5e4e, 0x79e05e4a, 0x79e15e4d, 0x79e25e4b, 0x79e35ec5,
+    0x79e45ecc, 0x79e55ec6, 0x79e65ecb, 0x79e75ec7, 0x79e85f40, 0x79e95faf, 0x79ea5fad, 0x79eb60f7,
+    0x79ec6149, 0x79ed614a, 0x79ee612b, 0x79ef6145, 0x79f06136, 0x79f16132, 0x79f2612e, 0x79f36146,
+    0x79f4612f, 0x79f5614f, 0x79f66129, 0x79f76140, 0x79f86220, 0x79f99168, 0x79fa6223, 0x79fb6225,
+    0x79fc6224, 0x79fd63c5, 0x7a4163f1, 0x7a4263eb, 0x7a436410, 0x7a446412, 0x7a456409, 0x7a466420,
+    0x7a476424, 0x7a486433, 0x7a496443, 0x7a4a641f, 0x7a4b6415, 0x7a4c6418, 0x7a4d6439, 0x7a4e6437,
+    0x7a4f6422, 0x7a506423, 0x7a51640c, 0x7a526426, 0x7a536430, 0x7a546428, 0x7a556441, 0x7a566435,
+    0x7a57642f, 0x7a58640a, 0x7a59641a, 0x7a5a6440, 0x7a5b6425, 0x7a5c6427, 0x7a5d640b, 0x7a5e63e7,
+    0x7a5f641b, 0x7a60642e, 0x7a616421, 0x7a62640e, 0x7a63656f, 0x7a646592, 0x7a6565d3, 0x7a666686,
+    0x7a67668c, 0x7a686695, 0x7a696690, 0x7a6a668b, 0x7a6b668a, 0x7a6c6699, 0x7a6d6694, 0x7a6e6678,
+    0x7a6f6720, 0x7a706966, 0x7a71695f, 0x7a726938, 0x7a73694e, 0x7a746962, 0x7a756971, 0x7a76693f,
+    0x7a776945, 0x7a78696a, 0x7a796939, 0x7a7a6942, 0x7a7b6957, 0x7a7c6959, 0x7a7d697a, 0x7a7e6948,
+    0x7a7f6949, 0x7a816935, 0x7a82696c, 0x7a836933, 0x7a84693d, 0x7a856965, 0x7a8668f0, 0x7a876978,
+    0x7a886934, 0x7a896969, 0x7a8a6940, 0x7a8b696f, 0x7a8c6944, 0x7a8d6976, 0x7a8e6958, 0x7a8f6941,
+    0x7a906974, 0x7a91694c, 0x7a92693b, 0x7a93694b, 0x7a946937, 0x7a95695c, 0x7a96694f, 0x7a976951,
+    0x7a986932, 0x7a996952, 0x7a9a692f, 0x7a9b697b, 0x7a9c693c, 0x7a9d6b46, 0x7a9e6b45, 0x7a9f6b43,
+    0x7aa06b42, 0x7aa16b48, 0x7aa26b41, 0x7aa36b9b, 0x7aa4fa0d, 0x7aa56bfb, 0x7aa66bfc, 0x7aa76bf9,
+    0x7aa86bf7, 0x7aa96bf8, 0x7aaa6e9b, 0x7aab6ed6, 0x7aac6ec8, 0x7aad6e8f, 0x7aae6ec0, 0x7aaf6e9f,
+    0x7ab06e93, 0x7ab16e94, 0x7ab26ea0, 0x7ab36eb1, 0x7ab46eb9, 0x7ab56ec6, 0x7ab66ed2, 0x7ab76ebd,
+    0x7ab86ec1, 0x7ab96e9e, 0x7aba6ec9, 0x7abb6eb7, 0x7abc6eb0, 0x7abd6ecd, 0x7abe6ea6, 0x7abf6ecf,
+    0x7ac06eb2, 0x7ac16ebe, 0x7ac26ec3, 0x7ac36edc, 0x7ac46ed8, 0x7ac56e99, 0x7ac66e92, 0x7ac76e8e,
+    0x7ac86e8d, 0x7ac96ea4, 0x7aca6ea1, 0x7acb6ebf, 0x7acc6eb3, 0x7acd6ed0, 0x7ace6eca, 0x7acf6e97,
+    0x7ad06eae, 0x7ad16ea3, 0x7ad27147, 0x7ad37154, 0x7ad47152, 0x7ad57163, 0x7ad67160, 0x7ad77141,
+    0x7ad8715d, 0x7ad97162, 0x7ada7172, 0x7adb7178, 0x7adc716a, 0x7add7161, 0x7ade7142, 0x7adf7158,
+    0x7ae07143, 0x7ae1714b, 0x7ae27170, 0x7ae3715f, 0x7ae47150, 0x7ae57153, 0x7ae67144, 0x7ae7714d,
+    0x7ae8715a, 0x7ae9724f, 0x7aea728d, 0x7aeb728c, 0x7aec7291, 0x7aed7290, 0x7aee728e, 0x7aef733c,
+    0x7af07342, 0x7af1733b, 0x7af2733a, 0x7af37340, 0x7af4734a, 0x7af57349, 0x7af67444, 0x7af7744a,
+    0x7af8744b, 0x7af97452, 0x7afa7451, 0x7afb7457, 0x7afc7440, 0x7afd744f, 0x7b417450, 0x7b42744e,
+    0x7b437442, 0x7b447446, 0x7b45744d, 0x7b467454, 0x7b4774e1, 0x7b4874ff, 0x7b4974fe, 0x7b4a74fd,
+    0x7b4b751d, 0x7b4c7579, 0x7b4d7577, 0x7b4e6983, 0x7b4f75ef, 0x7b50760f, 0x7b517603, 0x7b5275f7,
+    0x7b5375fe, 0x7b5475fc, 0x7b5575f9, 0x7b5675f8, 0x7b577610, 0x7b5875fb, 0x7b5975f6, 0x7b5a75ed,
+    0x7b5b75f5, 0x7b5c75fd, 0x7b5d7699, 0x7b5e76b5, 0x7b5f76dd, 0x7b607755, 0x7b61775f, 0x7b627760,
+    0x7b637752, 0x7b647756, 0x7b65775a, 0x7b667769, 0x7b677767, 0x7b687754, 0x7b697759, 0x7b6a776d,
+    0x7b6b77e0, 0x7b6c7887, 0x7b6d789a, 0x7b6e7894, 0x7b6f788f, 0x7b707884, 0x7b717895, 0x7b727885,
+    0x7b737886, 0x7b7478a1, 0x7b757883, 0x7b767879, 0x7b777899, 0x7b787880, 0x7b797896, 0x7b7a787b,
+    0x7b7b797c, 0x7b7c7982, 0x7b7d797d, 0x7b7e7979, 0x7b7f7a11, 0x7b817a18, 0x7b827a19, 0x7b837a12,
+    0x7b847a17, 0x7b857a15, 0x7b867a22, 0x7b877a13, 0x7b887a1b, 0x7b897a10, 0x7b8a7aa3, 0x7b8b7aa2,
+    0x7b8c7a9e, 0x7b8d7aeb, 0x7b8e7b66, 0x7b8f7b64, 0x7b907b6d, 0x7b917b74, 0x7b927b69, 0x7b937b72,
+    0x7b947b65, 0x7b957b73, 0x7b967b71, 0x7b977b70, 0x7b987b61, 0x7b997b78, 0x7b9a7b76, 0x7b9b7b63,
+    0x7b9c7cb2, 0x7b9d7cb4, 0x7b9e7caf, 0x7b9f7d88, 0x7ba07d86, 0x7ba17d80, 0x7ba27d8d, 0x7ba37d7f,
+    0x7ba47d85, 0x7ba57d7a, 0x7ba67d8e, 0x7ba77d7b, 0x7ba87d83, 0x7ba97d7c, 0x7baa7d8c, 0x7bab7d94,
+    0x7bac7d84, 0x7bad7d7d, 0x7bae7d92, 0x7baf7f6d, 0x7bb07f6b, 0x7bb17f67, 0x7bb27f68, 0x7bb37f6c,
+    0x7bb47fa6, 0x7bb57fa5, 0x7bb67fa7, 0x7bb77fdb, 0x7bb87fdc, 0x7bb98021, 0x7bba8164, 0x7bbb8160,
+    0x7bbc8177, 0x7bbd815c, 0x7bbe8169, 0x7bbf815b, 0x7bc08162, 0x7bc18172, 0x7bc26721, 0x7bc3815e,
+    0x7bc48176, 0x7bc58167, 0x7bc6816f, 0x7bc78144, 0x7bc88161, 0x7bc9821d, 0x7bca8249, 0x7bcb8244,
+    0x7bcc8240, 0x7bcd8242, 0x7bce8245, 0x7bcf84f1, 0x7bd0843f, 0x7bd18456, 0x7bd28476, 0x7bd38479,
+    0x7bd4848f, 0x7bd5848d, 0x7bd68465, 0x7bd78451, 0x7bd88440, 0x7bd98486, 0x7bda8467, 0x7bdb8430,
+    0x7bdc844d, 0x7bdd847d, 0x7bde845a, 0x7bdf8459, 0x7be08474, 0x7be18473, 0x7be2845d, 0x7be38507,
+    0x7be4845e, 0x7be58437, 0x7be6843a, 0x7be78434, 0x7be8847a, 0x7be98443, 0x7bea8478, 0x7beb8432,
+    0x7bec8445, 0x7bed8429, 0x7bee83d9, 0x7bef844b, 0x7bf0842f, 0x7bf18442, 0x7bf2842d, 0x7bf3845f,
+    0x7bf48470, 0x7bf58439, 0x7bf6844e, 0x7bf7844c, 0x7bf88452, 0x7bf9846f, 0x7bfa84c5, 0x7bfb848e,
+    0x7bfc843b, 0x7bfd8447, 0x7c418436, 0x7c428433, 0x7c438468, 0x7c44847e, 0x7c458444, 0x7c46842b,
+    0x7c478460, 0x7c488454, 0x7c49846e, 0x7c4a8450, 0x7c4b870b, 0x7c4c8704, 0x7c4d86f7, 0x7c4e870c,
+    0x7c4f86fa, 0x7c5086d6, 0x7c5186f5, 0x7c52874d, 0x7c5386f8, 0x7c54870e, 0x7c558709, 0x7c568701,
+    0x7c5786f6, 0x7c58870d, 0x7c598705, 0x7c5a88d6, 0x7c5b88cb, 0x7c5c88cd, 0x7c5d88ce, 0x7c5e88de,
+    0x7c5f88db, 0x7c6088da, 0x7c6188cc, 0x7c6288d0, 0x7c638985, 0x7c64899b, 0x7c6589df, 0x7c6689e5,
+    0x7c6789e4, 0x7c6889e1, 0x7c6989e0, 0x7c6a89e2, 0x7c6b89dc, 0x7c6c89e6, 0x7c6d8a76, 0x7c6e8a86,
+    0x7c6f8a7f, 0x7c708a61, 0x7c718a3f, 0x7c728a77, 0x7c738a82, 0x7c748a84, 0x7c758a75, 0x7c768a83,
+    0x7c778a81, 0x7c788a74, 0x7c798a7a, 0x7c7a8c3c, 0x7c7b8c4b, 0x7c7c8c4a, 0x7c7d8c65, 0x7c7e8c64,
+    0x7c7f8c66, 0x7c818c86, 0x7c828c84, 0x7c838c85, 0x7c848ccc, 0x7c858d68, 0x7c868d69, 0x7c878d91,
+    0x7c888d8c, 0x7c898d8e, 0x7c8a8d8f, 0x7c8b8d8d, 0x7c8c8d93, 0x7c8d8d94, 0x7c8e8d90, 0x7c8f8d92,
+    0x7c908df0, 0x7c918de0, 0x7c928dec, 0x7c938df1, 0x7c948dee, 0x7c958dd0, 0x7c968de9, 0x7c978de3,
+    0x7c988de2, 0x7c998de7, 0x7c9a8df2, 0x7c9b8deb, 0x7c9c8df4, 0x7c9d8f06, 0x7c9e8eff, 0x7c9f8f01,
+    0x7ca08f00, 0x7ca18f05, 0x7ca28f07, 0x7ca38f08, 0x7ca48f02, 0x7ca58f0b, 0x7ca69052, 0x7ca7903f,
+    0x7ca89044, 0x7ca99049, 0x7caa903d, 0x7cab9110, 0x7cac910d, 0x7cad910f, 0x7cae9111, 0x7caf9116,
+    0x7cb09114, 0x7cb1910b, 0x7cb2910e, 0x7cb3916e, 0x7cb4916f, 0x7cb59248, 0x7cb69252, 0x7cb79230,
+    0x7cb8923a, 0x7cb99266, 0x7cba9233, 0x7cbb9265, 0x7cbc925e, 0x7cbd9283, 0x7cbe922e, 0x7cbf924a,
+    0x7cc09246, 0x7cc1926d, 0x7cc2926c, 0x7cc3924f, 0x7cc49260, 0x7cc59267, 0x7cc6926f, 0x7cc79236,
+    0x7cc89261, 0x7cc99270, 0x7cca9231, 0x7ccb9254, 0x7ccc9263, 0x7ccd9250, 0x7cce9272, 0x7ccf924e,
+    0x7cd09253, 0x7cd1924c, 0x7cd29256, 0x7cd39232, 0x7cd4959f, 0x7cd5959c, 0x7cd6959e, 0x7cd7959b,
+    0x7cd89692, 0x7cd99693, 0x7cda9691, 0x7cdb9697, 0x7cdc96ce, 0x7cdd96fa, 0x7cde96fd, 0x7cdf96f8,
+    0x7ce096f5, 0x7ce19773, 0x7ce29777, 0x7ce39778, 0x7ce49772, 0x7ce5980f, 0x7ce6980d, 0x7ce7980e,
+    0x7ce898ac, 0x7ce998f6, 0x7cea98f9, 0x7ceb99af, 0x7cec99b2, 0x7ced99b0, 0x7cee99b5, 0x7cef9aad,
+    0x7cf09aab, 0x7cf19b5b, 0x7cf29cea, 0x7cf39ced, 0x7cf49ce7, 0x7cf59e80, 0x7cf69efd, 0x7cf750e6,
+    0x7cf850d4, 0x7cf950d7, 0x7cfa50e8, 0x7cfb50f3, 0x7cfc50db, 0x7cfd50ea, 0x7d4150dd, 0x7d4250e4,
+    0x7d4350d3, 0x7d4450ec, 0x7d4550f0, 0x7d4650ef, 0x7d4750e3, 0x7d4850e0, 0x7d4951d8, 0x7d4a5280,
+    0x7d4b5281, 0x7d4c52e9, 0x7d4d52eb, 0x7d4e5330, 0x7d4f53ac, 0x7d505627, 0x7d515615, 0x7d52560c,
+    0x7d535612, 0x7d5455fc, 0x7d55560f, 0x7d56561c, 0x7d575601, 0x7d585613, 0x7d595602, 0x7d5a55fa,
+    0x7d5b561d, 0x7d5c5604, 0x7d5d55ff, 0x7d5e55f9, 0x7d5f5889, 0x7d60587c, 0x7d615890, 0x7d625898,
+    0x7d635886, 0x7d645881, 0x7d65587f, 0x7d665874, 0x7d67588b, 0x7d68587a, 0x7d695887, 0x7d6a5891,
+    0x7d6b588e, 0x7d6c5876, 0x7d6d5882, 0x7d6e5888, 0x7d6f587b, 0x7d705894, 0x7d71588f, 0x7d7258fe,
+    0x7d73596b, 0x7d745adc, 0x7d755aee, 0x7d765ae5, 0x7d775ad5, 0x7d785aea, 0x7d795ada, 0x7d7a5aed,
+    0x7d7b5aeb, 0x7d7c5af3, 0x7d7d5ae2, 0x7d7e5ae0, 0x7d7f5adb, 0x7d815aec, 0x7d825ade, 0x7d835add,
+    0x7d845ad9, 0x7d855ae8, 0x7d865adf, 0x7d875b77, 0x7d885be0, 0x7d895be3, 0x7d8a5c63, 0x7d8b5d82,
+    0x7d8c5d80, 0x7d8d5d7d, 0x7d8e5d86, 0x7d8f5d7a, 0x7d905d81, 0x7d915d77, 0x7d925d8a, 0x7d935d89,
+    0x7d945d88, 0x7d955d7e, 0x7d965d7c, 0x7d975d8d, 0x7d985d79, 0x7d995d7f, 0x7d9a5e58, 0x7d9b5e59,
+    0x7d9c5e53, 0x7d9d5ed8, 0x7d9e5ed1, 0x7d9f5ed7, 0x7da05ece, 0x7da15edc, 0x7da25ed5, 0x7da35ed9,
+    0x7da45ed2, 0x7da55ed4, 0x7da65f44, 0x7da75f43, 0x7da85f6f, 0x7da95fb6, 0x7daa612c, 0x7dab6128,
+    0x7dac6141, 0x7dad615e, 0x7dae6171, 0x7daf6173, 0x7db06152, 0x7db16153, 0x7db26172, 0x7db3616c,
+    0x7db46180, 0x7db56174, 0x7db66154, 0x7db7617a, 0x7db8615b, 0x7db96165, 0x7dba613b, 0x7dbb616a,
+    0x7dbc6161, 0x7dbd6156, 0x7dbe6229, 0x7dbf6227, 0x7dc0622b, 0x7dc1642b, 0x7dc2644d, 0x7dc3645b,
+    0x7dc4645d, 0x7dc56474, 0x7dc66476, 0x7dc76472, 0x7dc86473, 0x7dc9647d, 0x7dca6475, 0x7dcb6466,
+    0x7dcc64a6, 0x7dcd644e, 0x7dce6482, 0x7dcf645e, 0x7dd0645c, 0x7dd1644b, 0x7dd26453, 0x7dd36460,
+    0x7dd46450, 0x7dd5647f, 0x7dd6643f, 0x7dd7646c, 0x7dd8646b, 0x7dd96459, 0x7dda6465, 0x7ddb6477,
+    0x7ddc6573, 0x7ddd65a0, 0x7dde66a1, 0x7ddf66a0, 0x7de0669f, 0x7de16705, 0x7de26704, 0x7de36722,
+    0x7de469b1, 0x7de569b6, 0x7de669c9, 0x7de769a0, 0x7de869ce, 0x7de96996, 0x7dea69b0, 0x7deb69ac,
+    0x7dec69bc, 0x7ded6991, 0x7dee6999, 0x7def698e, 0x7df069a7, 0x7df1698d, 0x7df269a9, 0x7df369be,
+    0x7df469af, 0x7df569bf, 0x7df669c4, 0x7df769bd, 0x7df869a4, 0x7df969d4, 0x7dfa69b9, 0x7dfb69ca,
+    0x7dfc699a, 0x7dfd69cf, 0x7e4169b3, 0x7e426993, 0x7e4369aa, 0x7e4469a1, 0x7e45699e, 0x7e4669d9,
+    0x7e476997, 0x7e486990, 0x7e4969c2, 0x7e4a69b5, 0x7e4b69a5, 0x7e4c69c6, 0x7e4d6b4a, 0x7e4e6b4d,
+    0x7e4f6b4b, 0x7e506b9e, 0x7e516b9f, 0x7e526ba0, 0x7e536bc3, 0x7e546bc4, 0x7e556bfe, 0x7e566ece,
+    0x7e576ef5, 0x7e586ef1, 0x7e596f03, 0x7e5a6f25, 0x7e5b6ef8, 0x7e5c6f37, 0x7e5d6efb, 0x7e5e6f2e,
+    0x7e5f6f09, 0x7e606f4e, 0x7e616f19, 0x7e626f1a, 0x7e636f27, 0x7e646f18, 0x7e656f3b, 0x7e666f12,
+    0x7e676eed, 0x7e686f0a, 0x7e696f36, 0x7e6a6f73, 0x7e6b6ef9, 0x7e6c6eee, 0x7e6d6f2d, 0x7e6e6f40,
+    0x7e6f6f30, 0x7e706f3c, 0x7e716f35, 0x7e726eeb, 0x7e736f07, 0x7e746f0e, 0x7e756f43, 0x7e766f05,
+    0x7e776efd, 0x7e786ef6, 0x7e796f39, 0x7e7a6f1c, 0x7e7b6efc, 0x7e7c6f3a, 0x7e7d6f1f, 0x7e7e6f0d,
+    0x7e7f6f1e, 0x7e816f08, 0x7e826f21, 0x7e837187, 0x7e847190, 0x7e857189, 0x7e867180, 0x7e877185,
+    0x7e887182, 0x7e89718f, 0x7e8a717b, 0x7e8b7186, 0x7e8c7181, 0x7e8d7197, 0x7e8e7244, 0x7e8f7253,
+    0x7e907297, 0x7e917295, 0x7e927293, 0x7e937343, 0x7e94734d, 0x7e957351, 0x7e96734c, 0x7e977462,
+    0x7e987473, 0x7e997471, 0x7e9a7475, 0x7e9b7472, 0x7e9c7467, 0x7e9d746e, 0x7e9e7500, 0x7e9f7502,
+    0x7ea07503, 0x7ea1757d, 0x7ea27590, 0x7ea37616, 0x7ea47608, 0x7ea5760c, 0x7ea67615, 0x7ea77611,
+    0x7ea8760a, 0x7ea97614, 0x7eaa76b8, 0x7eab7781, 0x7eac777c, 0x7ead7785, 0x7eae7782, 0x7eaf776e,
+    0x7eb07780, 0x7eb1776f, 0x7eb2777e, 0x7eb37783, 0x7eb478b2, 0x7eb578aa, 0x7eb678b4, 0x7eb778ad,
+    0x7eb878a8, 0x7eb9787e, 0x7eba78ab, 0x7ebb789e, 0x7ebc78a5, 0x7ebd78a0, 0x7ebe78ac, 0x7ebf78a2,
+    0x7ec078a4, 0x7ec17998, 0x7ec2798a, 0x7ec3798b, 0x7ec47996, 0x7ec57995, 0x7ec67994, 0x7ec77993,
+    0x7ec87997, 0x7ec97988, 0x7eca7992, 0x7ecb7990, 0x7ecc7a2b, 0x7ecd7a4a, 0x7ece7a30, 0x7ecf7a2f,
+    0x7ed07a28, 0x7ed17a26, 0x7ed27aa8, 0x7ed37aab, 0x7ed47aac, 0x7ed57aee, 0x7ed67b88, 0x7ed77b9c,
+    0x7ed87b8a, 0x7ed97b91, 0x7eda7b90, 0x7edb7b96, 0x7edc7b8d, 0x7edd7b8c, 0x7ede7b9b, 0x7edf7b8e,
+    0x7ee07b85, 0x7ee17b98, 0x7ee25284, 0x7ee37b99, 0x7ee47ba4, 0x7ee57b82, 0x7ee67cbb, 0x7ee77cbf,
+    0x7ee87cbc, 0x7ee97cba, 0x7eea7da7, 0x7eeb7db7, 0x7eec7dc2, 0x7eed7da3, 0x7eee7daa, 0x7eef7dc1,
+    0x7ef07dc0, 0x7ef17dc5, 0x7ef27d9d, 0x7ef37dce, 0x7ef47dc4, 0x7ef57dc6, 0x7ef67dcb, 0x7ef77dcc,
+    0x7ef87daf, 0x7ef97db9, 0x7efa7d96, 0x7efb7dbc, 0x7efc7d9f, 0x7efd7da6, 0x7f417dae, 0x7f427da9,
+    0x7f437da1, 0x7f447dc9, 0x7f457f73, 0x7f467fe2, 0x7f477fe3, 0x7f487fe5, 0x7f497fde, 0x7f4a8024,
+    0x7f4b805d, 0x7f4c805c, 0x7f4d8189, 0x7f4e8186, 0x7f4f8183, 0x7f508187, 0x7f51818d, 0x7f52818c,
+    0x7f53818b, 0x7f548215, 0x7f558497, 0x7f5684a4, 0x7f5784a1, 0x7f58849f, 0x7f5984ba, 0x7f5a84ce,
+    0x7f5b84c2, 0x7f5c84ac, 0x7f5d84ae, 0x7f5e84ab, 0x7f5f84b9, 0x7f6084b4, 0x7f6184c1, 0x7f6284cd,
+    0x7f6384aa, 0x7f64849a, 0x7f6584b1, 0x7f6684d0, 0x7f67849d, 0x7f6884a7, 0x7f6984bb, 0x7f6a84a2,
+    0x7f6b8494, 0x7f6c84c7, 0x7f6d84cc, 0x7f6e849b, 0x7f6f84a9, 0x7f7084af, 0x7f7184a8, 0x7f7284d6,
+    0x7f738498, 0x7f7484b6, 0x7f7584cf, 0x7f7684a0, 0x7f7784d7, 0x7f7884d4, 0x7f7984d2, 0x7f7a84db,
+    0x7f7b84b0, 0x7f7c8491, 0x7f7d8661, 0x7f7e8733, 0x7f7f8723, 0x7f818728, 0x7f82876b, 0x7f838740,
+    0x7f84872e, 0x7f85871e, 0x7f868721, 0x7f878719, 0x7f88871b, 0x7f898743, 0x7f8a872c, 0x7f8b8741,
+    0x7f8c873e, 0x7f8d8746, 0x7f8e8720, 0x7f8f8732, 0x7f90872a, 0x7f91872d, 0x7f92873c, 0x7f938712,
+    0x7f94873a, 0x7f958731, 0x7f968735, 0x7f978742, 0x7f988726, 0x7f998727, 0x7f9a8738, 0x7f9b8724,
+    0x7f9c871a, 0x7f9d8730, 0x7f9e8711, 0x7f9f88f7, 0x7fa088e7, 0x7fa188f1, 0x7fa288f2, 0x7fa388fa,
+    0x7fa488fe, 0x7fa588ee, 0x7fa688fc, 0x7fa788f6, 0x7fa888fb, 0x7fa988f0, 0x7faa88ec, 0x7fab88eb,
+    0x7fac899d, 0x7fad89a1, 0x7fae899f, 0x7faf899e, 0x7fb089e9, 0x7fb189eb, 0x7fb289e8, 0x7fb38aab,
+    0x7fb48a99, 0x7fb58a8b, 0x7fb68a92, 0x7fb78a8f, 0x7fb88a96, 0x7fb98c3d, 0x7fba8c68, 0x7fbb8c69,
+    0x7fbc8cd5, 0x7fbd8ccf, 0x7fbe8cd7, 0x7fbf8d96, 0x7fc08e09, 0x7fc18e02, 0x7fc28dff, 0x7fc38e0d,
+    0x7fc48dfd, 0x7fc58e0a, 0x7fc68e03, 0x7fc78e07, 0x7fc88e06, 0x7fc98e05, 0x7fca8dfe, 0x7fcb8e00,
+    0x7fcc8e04, 0x7fcd8f10, 0x7fce8f11, 0x7fcf8f0e, 0x7fd08f0d, 0x7fd19123, 0x7fd2911c, 0x7fd39120,
+    0x7fd49122, 0x7fd5911f, 0x7fd6911d, 0x7fd7911a, 0x7fd89124, 0x7fd99121, 0x7fda911b, 0x7fdb917a,
+    0x7fdc9172, 0x7fdd9179, 0x7fde9173, 0x7fdf92a5, 0x7fe092a4, 0x7fe19276, 0x7fe2929b, 0x7fe3927a,
+    0x7fe492a0, 0x7fe59294, 0x7fe692aa, 0x7fe7928d, 0x7fe892a6, 0x7fe9929a, 0x7fea92ab, 0x7feb9279,
+    0x7fec9297, 0x7fed927f, 0x7fee92a3, 0x7fef92ee, 0x7ff0928e, 0x7ff19282, 0x7ff29295, 0x7ff392a2,
+    0x7ff4927d, 0x7ff59288, 0x7ff692a1, 0x7ff7928a, 0x7ff89286, 0x7ff9928c, 0x7ffa9299, 0x7ffb92a7,
+    0x7ffc927e, 0x7ffd9287, 0x804192a9, 0x8042929d, 0x8043928b, 0x8044922d, 0x8045969e, 0x804696a1,
+    0x804796ff, 0x80489758, 0x8049977d, 0x804a977a, 0x804b977e, 0x804c9783, 0x804d9780, 0x804e9782,
+    0x804f977b, 0x80509784, 0x80519781, 0x8052977f, 0x805397ce, 0x805497cd, 0x80559816, 0x805698ad,
+    0x805798ae, 0x80589902, 0x80599900, 0x805a9907, 0x805b999d, 0x805c999c, 0x805d99c3, 0x805e99b9,
+    0x805f99bb, 0x806099ba, 0x806199c2, 0x806299bd, 0x806399c7, 0x80649ab1, 0x80659ae3, 0x80669ae7,
+    0x80679b3e, 0x80689b3f, 0x80699b60, 0x806a9b61, 0x806b9b5f, 0x806c9cf1, 0x806d9cf2, 0x806e9cf5,
+    0x806f9ea7, 0x807050ff, 0x80715103, 0x80725130, 0x807350f8, 0x80745106, 0x80755107, 0x807650f6,
+    0x807750fe, 0x8078510b, 0x8079510c, 0x807a50fd, 0x807b510a, 0x807c528b, 0x807d528c, 0x807e52f1,
+    0x807f52ef, 0x80815648, 0x80825642, 0x8083564c, 0x80845635, 0x80855641, 0x8086564a, 0x80875649,
+    0x80885646, 0x80895658, 0x808a565a, 0x808b5640, 0x808c5633, 0x808d563d, 0x808e562c, 0x808f563e,
+    0x80905638, 0x8091562a, 0x8092563a, 0x8093571a, 0x809458ab, 0x8095589d, 0x809658b1, 0x809758a0,
+    0x809858a3, 0x809958af, 0x809a58ac, 0x809b58a5, 0x809c58a1, 0x809d58ff, 0x809e5aff, 0x809f5af4,
+    0x80a05afd, 0x80a15af7, 0x80a25af6, 0x80a35b03, 0x80a45af8, 0x80a55b02, 0x80a65af9, 0x80a75b01,
+    0x80a85b07, 0x80a95b05, 0x80aa5b0f, 0x80ab5c67, 0x80ac5d99, 0x80ad5d97, 0x80ae5d9f, 0x80af5d92,
+    0x80b05da2, 0x80b15d93, 0x80b25d95, 0x80b35da0, 0x80b45d9c, 0x80b55da1, 0x80b65d9a, 0x80b75d9e,
+    0x80b85e69, 0x80b95e5d, 0x80ba5e60, 0x80bb5e5c, 0x80bc7df3, 0x80bd5edb, 0x80be5ede, 0x80bf5ee1,
+    0x80c05f49, 0x80c15fb2, 0x80c2618b, 0x80c36183, 0x80c46179, 0x80c561b1, 0x80c661b0, 0x80c761a2,
+    0x80c86189, 0x80c9619b, 0x80ca6193, 0x80cb61af, 0x80cc61ad, 0x80cd619f, 0x80ce6192, 0x80cf61aa,
+    0x80d061a1, 0x80d1618d, 0x80d26166, 0x80d361b3, 0x80d4622d, 0x80d5646e, 0x80d66470, 0x80d76496,
+    0x80d864a0, 0x80d96485, 0x80da6497, 0x80db649c, 0x80dc648f, 0x80dd648b, 0x80de648a, 0x80df648c,
+    0x80e064a3, 0x80e1649f, 0x80e26468, 0x80e364b1, 0x80e46498, 0x80e56576, 0x80e6657a, 0x80e76579,
+    0x80e8657b, 0x80e965b2, 0x80ea65b3, 0x80eb66b5, 0x80ec66b0, 0x80ed66a9, 0x80ee66b2, 0x80ef66b7,
+    0x80f066aa, 0x80f166af, 0x80f26a00, 0x80f36a06, 0x80f46a17, 0x80f569e5, 0x80f669f8, 0x80f76a15,
+    0x80f869f1, 0x80f969e4, 0x80fa6a20, 0x80fb69ff, 0x80fc69ec, 0x80fd69e2, 0x81416a1b, 0x81426a1d,
+    0x814369fe, 0x81446a27, 0x814569f2, 0x814669ee, 0x81476a14, 0x814869f7, 0x814969e7, 0x814a6a40,
+    0x814b6a08, 0x814c69e6, 0x814d69fb, 0x814e6a0d, 0x814f69fc, 0x815069eb, 0x81516a09, 0x81526a04,
+    0x81536a18, 0x81546a25, 0x81556a0f, 0x815669f6, 0x81576a26, 0x81586a07, 0x815969f4, 0x815a6a16,
+    0x815b6b51, 0x815c6ba5, 0x815d6ba3, 0x815e6ba2, 0x815f6ba6, 0x81606c01, 0x81616c00, 0x81626bff,
+    0x81636c02, 0x81646f41, 0x81656f26, 0x81666f7e, 0x81676f87, 0x81686fc6, 0x81696f92, 0x816a6f8d,
+    0x816b6f89, 0x816c6f8c, 0x816d6f62, 0x816e6f4f, 0x816f6f85, 0x81706f5a, 0x81716f96, 0x81726f76,
+    0x81736f6c, 0x81746f82, 0x81756f55, 0x81766f72, 0x81776f52, 0x81786f50, 0x81796f57, 0x817a6f94,
+    0x817b6f93, 0x817c6f5d, 0x817d6f00, 0x817e6f61, 0x817f6f6b, 0x81816f7d, 0x81826f67, 0x81836f90,
+    0x81846f53, 0x81856f8b, 0x81866f69, 0x81876f7f, 0x81886f95, 0x81896f63, 0x818a6f77, 0x818b6f6a,
+    0x818c6f7b, 0x818d71b2, 0x818e71af, 0x818f719b, 0x819071b0, 0x819171a0, 0x8192719a, 0x819371a9,
+    0x819471b5, 0x8195719d, 0x819671a5, 0x8197719e, 0x819871a4, 0x819971a1, 0x819a71aa, 0x819b719c,
+    0x819c71a7, 0x819d71b3, 0x819e7298, 0x819f729a, 0x81a07358, 0x81a17352, 0x81a2735e, 0x81a3735f,
+    0x81a47360, 0x81a5735d, 0x81a6735b, 0x81a77361, 0x81a8735a, 0x81a97359, 0x81aa7362, 0x81ab7487,
+    0x81ac7489, 0x81ad748a, 0x81ae7486, 0x81af7481, 0x81b0747d, 0x81b17485, 0x81b27488, 0x81b3747c,
+    0x81b47479, 0x81b57508, 0x81b67507, 0x81b7757e, 0x81b87625, 0x81b9761e, 0x81ba7619, 0x81bb761d,
+    0x81bc761c, 0x81bd7623, 0x81be761a, 0x81bf7628, 0x81c0761b, 0x81c1769c, 0x81c2769d, 0x81c3769e,
+    0x81c4769b, 0x81c5778d, 0x81c6778f, 0x81c77789, 0x81c87788, 0x81c978cd, 0x81ca78bb, 0x81cb78cf,
+    0x81cc78cc, 0x81cd78d1, 0x81ce78ce, 0x81cf78d4, 0x81d078c8, 0x81d178c3, 0x81d278c4, 0x81d378c9,
+    0x81d4799a, 0x81d579a1, 0x81d679a0, 0x81d7799c, 0x81d879a2, 0x81d9799b, 0x81da6b76, 0x81db7a39,
+    0x81dc7ab2, 0x81dd7ab4, 0x81de7ab3, 0x81df7bb7, 0x81e07bcb, 0x81e17bbe, 0x81e27bac, 0x81e37bce,
+    0x81e47baf, 0x81e57bb9, 0x81e67bca, 0x81e77bb5, 0x81e87cc5, 0x81e97cc8, 0x81ea7ccc, 0x81eb7ccb,
+    0x81ec7df7, 0x81ed7ddb, 0x81ee7dea, 0x81ef7de7, 0x81f07dd7, 0x81f17de1, 0x81f27e03, 0x81f37dfa,
+    0x81f47de6, 0x81f57df6, 0x81f67df1, 0x81f77df0, 0x81f87dee, 0x81f97ddf, 0x81fa7f76, 0x81fb7fac,
+    0x81fc7fb0, 0x81fd7fad, 0x82417fed, 0x82427feb, 0x82437fea, 0x82447fec, 0x82457fe6, 0x82467fe8,
+    0x82478064, 0x82488067, 0x824981a3, 0x824a819f, 0x824b819e, 0x824c8195, 0x824d81a2, 0x824e8199,
+    0x824f8197, 0x82508216, 0x8251824f, 0x82528253, 0x82538252, 0x82548250, 0x8255824e, 0x82568251,
+    0x82578524, 0x8258853b, 0x8259850f, 0x825a8500, 0x825b8529, 0x825c850e, 0x825d8509, 0x825e850d,
+    0x825f851f, 0x8260850a, 0x82618527, 0x8262851c, 0x826384fb, 0x8264852b, 0x826584fa, 0x82668508,
+    0x8267850c, 0x826884f4, 0x8269852a, 0x826a84f2, 0x826b8515, 0x826c84f7, 0x826d84eb, 0x826e84f3,
+    0x826f84fc, 0x82708512, 0x827184ea, 0x827284e9, 0x82738516, 0x827484fe, 0x82758528, 0x8276851d,
+    0x8277852e, 0x82788502, 0x827984fd, 0x827a851e, 0x827b84f6, 0x827c8531, 0x827d8526, 0x827e84e7,
+    0x827f84e8, 0x828184f0, 0x828284ef, 0x828384f9, 0x82848518, 0x82858520, 0x82868530, 0x8287850b,
+    0x82888519, 0x8289852f, 0x828a8662, 0x828b8756, 0x828c8763, 0x828d8764, 0x828e8777, 0x828f87e1,
+    0x82908773, 0x82918758, 0x82928754, 0x8293875b, 0x82948752, 0x82958761, 0x8296875a, 0x82978751,
+    0x8298875e, 0x8299876d, 0x829a876a, 0x829b8750, 0x829c874e, 0x829d875f, 0x829e875d, 0x829f876f,
+    0x82a0876c, 0x82a1877a, 0x82a2876e, 0x82a3875c, 0x82a48765, 0x82a5874f, 0x82a6877b, 0x82a78775,
+    0x82a88762, 0x82a98767, 0x82aa8769, 0x82ab885a, 0x82ac8905, 0x82ad890c, 0x82ae8914, 0x82af890b,
+    0x82b08917, 0x82b18918, 0x82b28919, 0x82b38906, 0x82b48916, 0x82b58911, 0x82b6890e, 0x82b78909,
+    0x82b889a2, 0x82b989a4, 0x82ba89a3, 0x82bb89ed, 0x82bc89f0, 0x82bd89ec, 0x82be8acf, 0x82bf8ac6,
+    0x82c08ab8, 0x82c18ad3, 0x82c28ad1, 0x82c38ad4, 0x82c48ad5, 0x82c58abb, 0x82c68ad7, 0x82c78abe,
+    0x82c88ac0, 0x82c98ac5, 0x82ca8ad8, 0x82cb8ac3, 0x82cc8aba, 0x82cd8abd, 0x82ce8ad9, 0x82cf8c3e,
+    0x82d08c4d, 0x82d18c8f, 0x82d28ce5, 0x82d38cdf, 0x82d48cd9, 0x82d58ce8, 0x82d68cda, 0x82d78cdd,
+    0x82d88ce7, 0x82d98da0, 0x82da8d9c, 0x82db8da1, 0x82dc8d9b, 0x82dd8e20, 0x82de8e23, 0x82df8e25,
+    0x82e08e24, 0x82e18e2e, 0x82e28e15, 0x82e38e1b, 0x82e48e16, 0x82e58e11, 0x82e68e19, 0x82e78e26,
+    0x82e88e27, 0x82e98e14, 0x82ea8e12, 0x82eb8e18, 0x82ec8e13, 0x82ed8e1c, 0x82ee8e17, 0x82ef8e1a,
+    0x82f08f2c, 0x82f18f24, 0x82f28f18, 0x82f38f1a, 0x82f48f20, 0x82f58f23, 0x82f68f16, 0x82f78f17,
+    0x82f89073, 0x82f99070, 0x82fa906f, 0x82fb9067, 0x82fc906b, 0x82fd912f, 0x8341912b, 0x83429129,
+    0x8343912a, 0x83449132, 0x83459126, 0x8346912e, 0x83479185, 0x83489186, 0x8349918a, 0x834a9181,
+    0x834b9182, 0x834c9184, 0x834d9180, 0x834e92d0, 0x834f92c3, 0x835092c4, 0x835192c0, 0x835292d9,
+    0x835392b6, 0x835492cf, 0x835592f1, 0x835692df, 0x835792d8, 0x835892e9, 0x835992d7, 0x835a92dd,
+    0x835b92cc, 0x835c92ef, 0x835d92c2, 0x835e92e8, 0x835f92ca, 0x836092c8, 0x836192ce, 0x836292e6,
+    0x836392cd, 0x836492d5, 0x836592c9, 0x836692e0, 0x836792de, 0x836892e7, 0x836992d1, 0x836a92d3,
+    0x836b92b5, 0x836c92e1, 0x836d92c6, 0x836e92b4, 0x836f957c, 0x837095ac, 0x837195ab, 0x837295ae,
+    0x837395b0, 0x837496a4, 0x837596a2, 0x837696d3, 0x83779705, 0x83789708, 0x83799702, 0x837a975a,
+    0x837b978a, 0x837c978e, 0x837d9788, 0x837e97d0, 0x837f97cf, 0x8381981e, 0x8382981d, 0x83839826,
+    0x83849829, 0x83859828, 0x83869820, 0x8387981b, 0x83889827, 0x838998b2, 0x838a9908, 0x838b98fa,
+    0x838c9911, 0x838d9914, 0x838e9916, 0x838f9917, 0x83909915, 0x839199dc, 0x839299cd, 0x839399cf,
+    0x839499d3, 0x839599d4, 0x839699ce, 0x839799c9, 0x839899d6, 0x839999d8, 0x839a99cb, 0x839b99d7,
+    0x839c99cc, 0x839d9ab3, 0x839e9aec, 0x839f9aeb, 0x83a09af3, 0x83a19af2, 0x83a29af1, 0x83a39b46,
+    0x83a49b43, 0x83a59b67, 0x83a69b74, 0x83a79b71, 0x83a89b66, 0x83a99b76, 0x83aa9b75, 0x83ab9b70,
+    0x83ac9b68, 0x83ad9b64, 0x83ae9b6c, 0x83af9cfc, 0x83b09cfa, 0x83b19cfd, 0x83b29cff, 0x83b39cf7,
+    0x83b49d07, 0x83b59d00, 0x83b69cf9, 0x83b79cfb, 0x83b89d08, 0x83b99d05, 0x83ba9d04, 0x83bb9e83,
+    0x83bc9ed3, 0x83bd9f0f, 0x83be9f10, 0x83bf511c, 0x83c05113, 0x83c15117, 0x83c2511a, 0x83c35111,
+    0x83c451de, 0x83c55334, 0x83c653e1, 0x83c75670, 0x83c85660, 0x83c9566e, 0x83ca5673, 0x83cb5666,
+    0x83cc5663, 0x83cd566d, 0x83ce5672, 0x83cf565e, 0x83d05677, 0x83d1571c, 0x83d2571b, 0x83d358c8,
+    0x83d458bd, 0x83d558c9, 0x83d658bf, 0x83d758ba, 0x83d858c2, 0x83d958bc, 0x83da58c6, 0x83db5b17,
+    0x83dc5b19, 0x83dd5b1b, 0x83de5b21, 0x83df5b14, 0x83e05b13, 0x83e15b10, 0x83e25b16, 0x83e35b28,
+    0x83e45b1a, 0x83e55b20, 0x83e65b1e, 0x83e75bef, 0x83e85dac, 0x83e95db1, 0x83ea5da9, 0x83eb5da7,
+    0x83ec5db5, 0x83ed5db0, 0x83ee5dae, 0x83ef5daa, 0x83f05da8, 0x83f15db2, 0x83f25dad, 0x83f35daf,
+    0x83f45db4, 0x83f55e67, 0x83f65e68, 0x83f75e66, 0x83f85e6f, 0x83f95ee9, 0x83fa5ee7, 0x83fb5ee6,
+    0x83fc5ee8, 0x83fd5ee5, 0x84415f4b, 0x84425fbc, 0x8443619d, 0x844461a8, 0x84456196, 0x844661c5,
+    0x844761b4, 0x844861c6, 0x844961c1, 0x844a61cc, 0x844b61ba, 0x844c61bf, 0x844d61b8, 0x844e618c,
+    0x844f64d7, 0x845064d6, 0x845164d0, 0x845264cf, 0x845364c9, 0x845464bd, 0x84556489, 0x845664c3,
+    0x845764db, 0x845864f3, 0x845964d9, 0x845a6533, 0x845b657f, 0x845c657c, 0x845d65a2, 0x845e66c8,
+    0x845f66be, 0x846066c0, 0x846166ca, 0x846266cb, 0x846366cf, 0x846466bd, 0x846566bb, 0x846666ba,
+    0x846766cc, 0x84686723, 0x84696a34, 0x846a6a66, 0x846b6a49, 0x846c6a67, 0x846d6a32, 0x846e6a68,
+    0x846f6a3e, 0x84706a5d, 0x84716a6d, 0x84726a76, 0x84736a5b, 0x84746a51, 0x84756a28, 0x84766a5a,
+    0x84776a3b, 0x84786a3f, 0x84796a41, 0x847a6a6a, 0x847b6a64, 0x847c6a50, 0x847d6a4f, 0x847e6a54,
+    0x847f6a6f, 0x84816a69, 0x84826a60, 0x84836a3c, 0x84846a5e, 0x84856a56, 0x84866a55, 0x84876a4d,
+    0x84886a4e, 0x84896a46, 0x848a6b55, 0x848b6b54, 0x848c6b56, 0x848d6ba7, 0x848e6baa, 0x848f6bab,
+    0x84906bc8, 0x84916bc7, 0x84926c04, 0x84936c03, 0x84946c06, 0x84956fad, 0x84966fcb, 0x84976fa3,
+    0x84986fc7, 0x84996fbc, 0x849a6fce, 0x849b6fc8, 0x849c6f5e, 0x849d6fc4, 0x849e6fbd, 0x849f6f9e,
+    0x84a06fca, 0x84a16fa8, 0x84a27004, 0x84a36fa5, 0x84a46fae, 0x84a56fba, 0x84a66fac, 0x84a76faa,
+    0x84a86fcf, 0x84a96fbf, 0x84aa6fb8, 0x84ab6fa2, 0x84ac6fc9, 0x84ad6fab, 0x84ae6fcd, 0x84af6faf,
+    0x84b06fb2, 0x84b16fb0, 0x84b271c5, 0x84b371c2, 0x84b471bf, 0x84b571b8, 0x84b671d6, 0x84b771c0,
+    0x84b871c1, 0x84b971cb, 0x84ba71d4, 0x84bb71ca, 0x84bc71c7, 0x84bd71cf, 0x84be71bd, 0x84bf71d8,
+    0x84c071bc, 0x84c171c6, 0x84c271da, 0x84c371db, 0x84c4729d, 0x84c5729e, 0x84c67369, 0x84c77366,
+    0x84c87367, 0x84c9736c, 0x84ca7365, 0x84cb736b, 0x84cc736a, 0x84cd747f, 0x84ce749a, 0x84cf74a0,
+    0x84d07494, 0x84d17492, 0x84d27495, 0x84d374a1, 0x84d4750b, 0x84d57580, 0x84d6762f, 0x84d7762d,
+    0x84d87631, 0x84d9763d, 0x84da7633, 0x84db763c, 0x84dc7635, 0x84dd7632, 0x84de7630, 0x84df76bb,
+    0x84e076e6, 0x84e1779a, 0x84e2779d, 0x84e377a1, 0x84e4779c, 0x84e5779b, 0x84e677a2, 0x84e777a3,
+    0x84e87795, 0x84e97799, 0x84ea7797, 0x84eb78dd, 0x84ec78e9, 0x84ed78e5, 0x84ee78ea, 0x84ef78de,
+    0x84f078e3, 0x84f178db, 0x84f278e1, 0x84f378e2, 0x84f478ed, 0x84f578df, 0x84f678e0, 0x84f779a4,
+    0x84f87a44, 0x84f97a48, 0x84fa7a47, 0x84fb7ab6, 0x84fc7ab8, 0x84fd7ab5, 0x85417ab1, 0x85427ab7,
+    0x85437bde, 0x85447be3, 0x85457be7, 0x85467bdd, 0x85477bd5, 0x85487be5, 0x85497bda, 0x854a7be8,
+    0x854b7bf9, 0x854c7bd4, 0x854d7bea, 0x854e7be2, 0x854f7bdc, 0x85507beb, 0x85517bd8, 0x85527bdf,
+    0x85537cd2, 0x85547cd4, 0x85557cd7, 0x85567cd0, 0x85577cd1, 0x85587e12, 0x85597e21, 0x855a7e17,
+    0x855b7e0c, 0x855c7e1f, 0x855d7e20, 0x855e7e13, 0x855f7e0e, 0x85607e1c, 0x85617e15, 0x85627e1a,
+    0x85637e22, 0x85647e0b, 0x85657e0f, 0x85667e16, 0x85677e0d, 0x85687e14, 0x85697e25, 0x856a7e24,
+    0x856b7f43, 0x856c7f7b, 0x856d7f7c, 0x856e7f7a, 0x856f7fb1, 0x85707fef, 0x8571802a, 0x85728029,
+    0x8573806c, 0x857481b1, 0x857581a6, 0x857681ae, 0x857781b9, 0x857881b5, 0x857981ab, 0x857a81b0,
+    0x857b81ac, 0x857c81b4, 0x857d81b2, 0x857e81b7, 0x857f81a7, 0x858181f2, 0x85828255, 0x85838256,
+    0x85848257, 0x85858556, 0x85868545, 0x8587856b, 0x8588854d, 0x85898553, 0x858a8561, 0x858b8558,
+    0x858c8540, 0x858d8546, 0x858e8564, 0x858f8541, 0x85908562, 0x85918544, 0x85928551, 0x85938547,
+    0x85948563, 0x8595853e, 0x8596855b, 0x85978571, 0x8598854e, 0x8599856e, 0x859a8575, 0x859b8555,
+    0x859c8567, 0x859d8560, 0x859e858c, 0x859f8566, 0x85a0855d, 0x85a18554, 0x85a28565, 0x85a3856c,
+    0x85a48663, 0x85a58665, 0x85a68664, 0x85a7879b, 0x85a8878f, 0x85a98797, 0x85aa8793, 0x85ab8792,
+    0x85ac8788, 0x85ad8781, 0x85ae8796, 0x85af8798, 0x85b08779, 0x85b18787, 0x85b287a3, 0x85b38785,
+    0x85b48790, 0x85b58791, 0x85b6879d, 0x85b78784, 0x85b88794, 0x85b9879c, 0x85ba879a, 0x85bb8789,
+    0x85bc891e, 0x85bd8926, 0x85be8930, 0x85bf892d, 0x85c0892e, 0x85c18927, 0x85c28931, 0x85c38922,
+    0x85c48929, 0x85c58923, 0x85c6892f, 0x85c7892c, 0x85c8891f, 0x85c989f1, 0x85ca8ae0, 0x85cb8ae2,
+    0x85cc8af2, 0x85cd8af4, 0x85ce8af5, 0x85cf8add, 0x85d08b14, 0x85d18ae4, 0x85d28adf, 0x85d38af0,
+    0x85d48ac8, 0x85d58ade, 0x85d68ae1, 0x85d78ae8, 0x85d88aff, 0x85d98aef, 0x85da8afb, 0x85db8c91,
+    0x85dc8c92, 0x85dd8c90, 0x85de8cf5, 0x85df8cee, 0x85e08cf1, 0x85e18cf0, 0x85e28cf3, 0x85e38d6c,
+    0x85e48d6e, 0x85e58da5, 0x85e68da7, 0x85e78e33, 0x85e88e3e, 0x85e98e38, 0x85ea8e40, 0x85eb8e45,
+    0x85ec8e36, 0x85ed8e3c, 0x85ee8e3d, 0x85ef8e41, 0x85f08e30, 0x85f18e3f, 0x85f28ebd, 0x85f38f36,
+    0x85f48f2e, 0x85f58f35, 0x85f68f32, 0x85f78f39, 0x85f88f37, 0x85f98f34, 0x85fa9076, 0x85fb9079,
+    0x85fc907b, 0x85fd9086, 0x864190fa, 0x86429133, 0x86439135, 0x86449136, 0x86459193, 0x86469190,
+    0x86479191, 0x8648918d, 0x8649918f, 0x864a9327, 0x864b931e, 0x864c9308, 0x864d931f, 0x864e9306,
+    0x864f930f, 0x8650937a, 0x86519338, 0x8652933c, 0x8653931b, 0x86549323, 0x86559312, 0x86569301,
+    0x86579346, 0x8658932d, 0x8659930e, 0x865a930d, 0x865b92cb, 0x865c931d, 0x865d92fa, 0x865e9325,
+    0x865f9313, 0x866092f9, 0x866192f7, 0x86629334, 0x86639302, 0x86649324, 0x866592ff, 0x86669329,
+    0x86679339, 0x86689335, 0x8669932a, 0x866a9314, 0x866b930c, 0x866c930b, 0x866d92fe, 0x866e9309,
+    0x866f9300, 0x867092fb, 0x86719316, 0x867295bc, 0x867395cd, 0x867495be, 0x867595b9, 0x867695ba,
+    0x867795b6, 0x867895bf, 0x867995b5, 0x867a95bd, 0x867b96a9, 0x867c96d4, 0x867d970b, 0x867e9712,
+    0x867f9710, 0x86819799, 0x86829797, 0x86839794, 0x868497f0, 0x868597f8, 0x86869835, 0x8687982f,
+    0x86889832, 0x86899924, 0x868a991f, 0x868b9927, 0x868c9929, 0x868d999e, 0x868e99ee, 0x868f99ec,
+    0x869099e5, 0x869199e4, 0x869299f0, 0x869399e3, 0x869499ea, 0x869599e9, 0x869699e7, 0x86979ab9,
+    0x86989abf, 0x86999ab4, 0x869a9abb, 0x869b9af6, 0x869c9afa, 0x869d9af9, 0x869e9af7, 0x869f9b33,
+    0x86a09b80, 0x86a19b85, 0x86a29b87, 0x86a39b7c, 0x86a49b7e, 0x86a59b7b, 0x86a69b82, 0x86a79b93,
+    0x86a89b92, 0x86a99b90, 0x86aa9b7a, 0x86ab9b95, 0x86ac9b7d, 0x86ad9b88, 0x86ae9d25, 0x86af9d17,
+    0x86b09d20, 0x86b19d1e, 0x86b29d14, 0x86b39d29, 0x86b49d1d, 0x86b59d18, 0x86b69d22, 0x86b79d10,
+    0x86b89d19, 0x86b99d1f, 0x86ba9e88, 0x86bb9e86, 0x86bc9e87, 0x86bd9eae, 0x86be9ead, 0x86bf9ed5,
+    0x86c09ed6, 0x86c19efa, 0x86c29f12, 0x86c39f3d, 0x86c45126, 0x86c55125, 0x86c65122, 0x86c75124,
+    0x86c85120, 0x86c95129, 0x86ca52f4, 0x86cb5693, 0x86cc568c, 0x86cd568d, 0x86ce5686, 0x86cf5684,
+    0x86d05683, 0x86d1567e, 0x86d25682, 0x86d3567f, 0x86d45681, 0x86d558d6, 0x86d658d4, 0x86d758cf,
+    0x86d858d2, 0x86d95b2d, 0x86da5b25, 0x86db5b32, 0x86dc5b23, 0x86dd5b2c, 0x86de5b27, 0x86df5b26,
+    0x86e05b2f, 0x86e15b2e, 0x86e25b7b, 0x86e35bf1, 0x86e45bf2, 0x86e55db7, 0x86e65e6c, 0x86e75e6a,
+    0x86e85fbe, 0x86e95fbb, 0x86ea61c3, 0x86eb61b5, 0x86ec61bc, 0x86ed61e7, 0x86ee61e0, 0x86ef61e5,
+    0x86f061e4, 0x86f161e8, 0x86f261de, 0x86f364ef, 0x86f464e9, 0x86f564e3, 0x86f664eb, 0x86f764e4,
+    0x86f864e8, 0x86f96581, 0x86fa6580, 0x86fb65b6, 0x86fc65da, 0x86fd66d2, 0x87416a8d, 0x87426a96,
+    0x87436a81, 0x87446aa5, 0x87456a89, 0x87466a9f, 0x87476a9b, 0x87486aa1, 0x87496a9e, 0x874a6a87,
+    0x874b6a93, 0x874c6a8e, 0x874d6a95, 0x874e6a83, 0x874f6aa8, 0x87506aa4, 0x87516a91, 0x87526a7f,
+    0x87536aa6, 0x87546a9a, 0x87556a85, 0x87566a8c, 0x87576a92, 0x87586b5b, 0x87596bad, 0x875a6c09,
+    0x875b6fcc, 0x875c6fa9, 0x875d6ff4, 0x875e6fd4, 0x875f6fe3, 0x87606fdc, 0x87616fed, 0x87626fe7,
+    0x87636fe6, 0x87646fde, 0x87656ff2, 0x87666fdd, 0x87676fe2, 0x87686fe8, 0x876971e1, 0x876a71f1,
+    0x876b71e8, 0x876c71f2, 0x876d71e4, 0x876e71f0, 0x876f71e2, 0x87707373, 0x8771736e, 0x8772736f,
+    0x87737497, 0x877474b2, 0x877574ab, 0x87767490, 0x877774aa, 0x877874ad, 0x877974b1, 0x877a74a5,
+    0x877b74af, 0x877c7510, 0x877d7511, 0x877e7512, 0x877f750f, 0x87817584, 0x87827643, 0x87837648,
+    0x87847649, 0x87857647, 0x878676a4, 0x878776e9, 0x878877b5, 0x878977ab, 0x878a77b2, 0x878b77b7,
+    0x878c77b6, 0x878d77b4, 0x878e77b1, 0x878f77a8, 0x879077f0, 0x879178f3, 0x879278fd, 0x87937902,
+    0x879478fb, 0x879578fc, 0x879678f2, 0x87977905, 0x879878f9, 0x879978fe, 0x879a7904, 0x879b79ab,
+    0x879c79a8, 0x879d7a5c, 0x879e7a5b, 0x879f7a56, 0x87a07a58, 0x87a17a54, 0x87a27a5a, 0x87a37abe,
+    0x87a47ac0, 0x87a57ac1, 0x87a67c05, 0x87a77c0f, 0x87a87bf2, 0x87a97c00, 0x87aa7bff, 0x87ab7bfb,
+    0x87ac7c0e, 0x87ad7bf4, 0x87ae7c0b, 0x87af7bf3, 0x87b07c02, 0x87b17c09, 0x87b27c03, 0x87b37c01,
+    0x87b47bf8, 0x87b57bfd, 0x87b67c06, 0x87b77bf0, 0x87b87bf1, 0x87b97c10, 0x87ba7c0a, 0x87bb7ce8,
+    0x87bc7e2d, 0x87bd7e3c, 0x87be7e42, 0x87bf7e33, 0x87c09848, 0x87c17e38, 0x87c27e2a, 0x87c37e49,
+    0x87c47e40, 0x87c57e47, 0x87c67e29, 0x87c77e4c, 0x87c87e30, 0x87c97e3b, 0x87ca7e36, 0x87cb7e44,
+    0x87cc7e3a, 0x87cd7f45, 0x87ce7f7f, 0x87cf7f7e, 0x87d07f7d, 0x87d17ff4, 0x87d27ff2, 0x87d3802c,
+    0x87d481bb, 0x87d581c4, 0x87d681cc, 0x87d781ca, 0x87d881c5, 0x87d981c7, 0x87da81bc, 0x87db81e9,
+    0x87dc825b, 0x87dd825a, 0x87de825c, 0x87df8583, 0x87e08580, 0x87e1858f, 0x87e285a7, 0x87e38595,
+    0x87e485a0, 0x87e5858b, 0x87e685a3, 0x87e7857b, 0x87e885a4, 0x87e9859a, 0x87ea859e, 0x87eb8577,
+    0x87ec857c, 0x87ed8589, 0x87ee85a1, 0x87ef857a, 0x87f08578, 0x87f18557, 0x87f2858e, 0x87f38596,
+    0x87f48586, 0x87f5858d, 0x87f68599, 0x87f7859d, 0x87f88581, 0x87f985a2, 0x87fa8582, 0x87fb8588,
+    0x87fc8585, 0x87fd8579, 0x88418576, 0x88428598, 0x88438590, 0x8844859f, 0x88458668, 0x884687be,
+    0x884787aa, 0x884887ad, 0x884987c5, 0x884a87b0, 0x884b87ac, 0x884c87b9, 0x884d87b5, 0x884e87bc,
+    0x884f87ae, 0x885087c9, 0x885187c3, 0x885287c2, 0x885387cc, 0x885487b7, 0x885587af, 0x885687c4,
+    0x885787ca, 0x885887b4, 0x885987b6, 0x885a87bf, 0x885b87b8, 0x885c87bd, 0x885d87de, 0x885e87b2,
+    0x885f8935, 0x88608933, 0x8861893c, 0x8862893e, 0x88638941, 0x88648952, 0x88658937, 0x88668942,
+    0x886789ad, 0x886889af, 0x886989ae, 0x886a89f2, 0x886b89f3, 0x886c8b1e, 0x886d8b18, 0x886e8b16,
+    0x886f8b11, 0x88708b05, 0x88718b0b, 0x88728b22, 0x88738b0f, 0x88748b12, 0x88758b15, 0x88768b07,
+    0x88778b0d, 0x88788b08, 0x88798b06, 0x887a8b1c, 0x887b8b13, 0x887c8b1a, 0x887d8c4f, 0x887e8c70,
+    0x887f8c72, 0x88818c71, 0x88828c6f, 0x88838c95, 0x88848c94, 0x88858cf9, 0x88868d6f, 0x88878e4e,
+    0x88888e4d, 0x88898e53, 0x888a8e50, 0x888b8e4c, 0x888c8e47, 0x888d8f43, 0x888e8f40, 0x888f9085,
+    0x8890907e, 0x88919138, 0x8892919a, 0x889391a2, 0x8894919b, 0x88959199, 0x8896919f, 0x889791a1,
+    0x8898919d, 0x889991a0, 0x889a93a1, 0x889b9383, 0x889c93af, 0x889d9364, 0x889e9356, 0x889f9347,
+    0x88a0937c, 0x88a19358, 0x88a2935c, 0x88a39376, 0x88a49349, 0x88a59350, 0x88a69351, 0x88a79360,
+    0x88a8936d, 0x88a9938f, 0x88aa934c, 0x88ab936a, 0x88ac9379, 0x88ad9357, 0x88ae9355, 0x88af9352,
+    0x88b0934f, 0x88b19371, 0x88b29377, 0x88b3937b, 0x88b49361, 0x88b5935e, 0x88b69363, 0x88b79367,
+    0x88b89380, 0x88b9934e, 0x88ba9359, 0x88bb95c7, 0x88bc95c0, 0x88bd95c9, 0x88be95c3, 0x88bf95c5,
+    0x88c095b7, 0x88c196ae, 0x88c296b0, 0x88c396ac, 0x88c49720, 0x88c5971f, 0x88c69718, 0x88c7971d,
+    0x88c89719, 0x88c9979a, 0x88ca97a1, 0x88cb979c, 0x88cc979e, 0x88cd979d, 0x88ce97d5, 0x88cf97d4,
+    0x88d097f1, 0x88d19841, 0x88d29844, 0x88d3984a, 0x88d49849, 0x88d59845, 0x88d69843, 0x88d79925,
+    0x88d8992b, 0x88d9992c, 0x88da992a, 0x88db9933, 0x88dc9932, 0x88dd992f, 0x88de992d, 0x88df9931,
+    0x88e09930, 0x88e19998, 0x88e299a3, 0x88e399a1, 0x88e49a02, 0x88e599fa, 0x88e699f4, 0x88e799f7,
+    0x88e899f9, 0x88e999f8, 0x88ea99f6, 0x88eb99fb, 0x88ec99fd, 0x88ed99fe, 0x88ee99fc, 0x88ef9a03,
+    0x88f09abe, 0x88f19afe, 0x88f29afd, 0x88f39b01, 0x88f49afc, 0x88f59b48, 0x88f69b9a, 0x88f79ba8,
+    0x88f89b9e, 0x88f99b9b, 0x88fa9ba6, 0x88fb9ba1, 0x88fc9ba5, 0x88fd9ba4, 0x89419b86, 0x89429ba2,
+    0x89439ba0, 0x89449baf, 0x89459d33, 0x89469d41, 0x89479d67, 0x89489d36, 0x89499d2e, 0x894a9d2f,
+    0x894b9d31, 0x894c9d38, 0x894d9d30, 0x894e9d45, 0x894f9d42, 0x89509d43, 0x89519d3e, 0x89529d37,
+    0x89539d40, 0x89549d3d, 0x89557ff5, 0x89569d2d, 0x89579e8a, 0x89589e89, 0x89599e8d, 0x895a9eb0,
+    0x895b9ec8, 0x895c9eda, 0x895d9efb, 0x895e9eff, 0x895f9f24, 0x89609f23, 0x89619f22, 0x89629f54,
+    0x89639fa0, 0x89645131, 0x8965512d, 0x8966512e, 0x89675698, 0x8968569c, 0x89695697, 0x896a569a,
+    0x896b569d, 0x896c5699, 0x896d5970, 0x896e5b3c, 0x896f5c69, 0x89705c6a, 0x89715dc0, 0x89725e6d,
+    0x89735e6e, 0x897461d8, 0x897561df, 0x897661ed, 0x897761ee, 0x897861f1, 0x897961ea, 0x897a61f0,
+    0x897b61eb, 0x897c61d6, 0x897d61e9, 0x897e64ff, 0x897f6504, 0x898164fd, 0x898264f8, 0x89836501,
+    0x89846503, 0x898564fc, 0x89866594, 0x898765db, 0x898866da, 0x898966db, 0x898a66d8, 0x898b6ac5,
+    0x898c6ab9, 0x898d6abd, 0x898e6ae1, 0x898f6ac6, 0x89906aba, 0x89916ab6, 0x89926ab7, 0x89936ac7,
+    0x89946ab4, 0x89956aad, 0x89966b5e, 0x89976bc9, 0x89986c0b, 0x89997007, 0x899a700c, 0x899b700d,
+    0x899c7001, 0x899d7005, 0x899e7014, 0x899f700e, 0x89a06fff, 0x89a17000, 0x89a26ffb, 0x89a37026,
+    0x89a46ffc, 0x89a56ff7, 0x89a6700a, 0x89a77201, 0x89a871ff, 0x89a971f9, 0x89aa7203, 0x89ab71fd,
+    0x89ac7376, 0x89ad74b8, 0x89ae74c0, 0x89af74b5, 0x89b074c1, 0x89b174be, 0x89b274b6, 0x89b374bb,
+    0x89b474c2, 0x89b57514, 0x89b67513, 0x89b7765c, 0x89b87664, 0x89b97659, 0x89ba7650, 0x89bb7653,
+    0x89bc7657, 0x89bd765a, 0x89be76a6, 0x89bf76bd, 0x89c076ec, 0x89c177c2, 0x89c277ba, 0x89c378ff,
+    0x89c4790c, 0x89c57913, 0x89c67914, 0x89c77909, 0x89c87910, 0x89c97912, 0x89ca7911, 0x89cb79ad,
+    0x89cc79ac, 0x89cd7a5f, 0x89ce7c1c, 0x89cf7c29, 0x89d07c19, 0x89d17c20, 0x89d27c1f, 0x89d37c2d,
+    0x89d47c1d, 0x89d57c26, 0x89d67c28, 0x89d77c22, 0x89d87c25, 0x89d97c30, 0x89da7e5c, 0x89db7e50,
+    0x89dc7e56, 0x89dd7e63, 0x89de7e58, 0x89df7e62, 0x89e07e5f, 0x89e17e51, 0x89e27e60, 0x89e37e57,
+    0x89e47e53, 0x89e57fb5, 0x89e67fb3, 0x89e77ff7, 0x89e87ff8, 0x89e98075, 0x89ea81d1, 0x89eb81d2,
+    0x89ec81d0, 0x89ed825f, 0x89ee825e, 0x89ef85b4, 0x89f085c6, 0x89f185c0, 0x89f285c3, 0x89f385c2,
+    0x89f485b3, 0x89f585b5, 0x89f685bd, 0x89f785c7, 0x89f885c4, 0x89f985bf, 0x89fa85cb, 0x89fb85ce,
+    0x89fc85c8, 0x89fd85c5, 0x8a4185b1, 0x8a4285b6, 0x8a4385d2, 0x8a448624, 0x8a4585b8, 0x8a4685b7,
+    0x8a4785be, 0x8a488669, 0x8a4987e7, 0x8a4a87e6, 0x8a4b87e2, 0x8a4c87db, 0x8a4d87eb, 0x8a4e87ea,
+    0x8a4f87e5, 0x8a5087df, 0x8a5187f3, 0x8a5287e4, 0x8a5387d4, 0x8a5487dc, 0x8a5587d3, 0x8a5687ed,
+    0x8a5787d8, 0x8a5887e3, 0x8a5987a4, 0x8a5a87d7, 0x8a5b87d9, 0x8a5c8801, 0x8a5d87f4, 0x8a5e87e8,
+    0x8a5f87dd, 0x8a608953, 0x8a61894b, 0x8a62894f, 0x8a63894c, 0x8a648946, 0x8a658950, 0x8a668951,
+    0x8a678949, 0x8a688b2a, 0x8a698b27, 0x8a6a8b23, 0x8a6b8b33, 0x8a6c8b30, 0x8a6d8b35, 0x8a6e8b47,
+    0x8a6f8b2f, 0x8a708b3c, 0x8a718b3e, 0x8a728b31, 0x8a738b25, 0x8a748b37, 0x8a758b26, 0x8a768b36,
+    0x8a778b2e, 0x8a788b24, 0x8a798b3b, 0x8a7a8b3d, 0x8a7b8b3a, 0x8a7c8c42, 0x8a7d8c75, 0x8a7e8c99,
+    0x8a7f8c98, 0x8a818c97, 0x8a828cfe, 0x8a838d04, 0x8a848d02, 0x8a858d00, 0x8a868e5c, 0x8a878e62,
+    0x8a888e60, 0x8a898e57, 0x8a8a8e56, 0x8a8b8e5e, 0x8a8c8e65, 0x8a8d8e67, 0x8a8e8e5b, 0x8a8f8e5a,
+    0x8a908e61, 0x8a918e5d, 0x8a928e69, 0x8a938e54, 0x8a948f46, 0x8a958f47, 0x8a968f48, 0x8a978f4b,
+    0x8a989128, 0x8a99913a, 0x8a9a913b, 0x8a9b913e, 0x8a9c91a8, 0x8a9d91a5, 0x8a9e91a7, 0x8a9f91af,
+    0x8aa091aa, 0x8aa193b5, 0x8aa2938c, 0x8aa39392, 0x8aa493b7, 0x8aa5939b, 0x8aa6939d, 0x8aa79389,
+    0x8aa893a7, 0x8aa9938e, 0x8aaa93aa, 0x8aab939e, 0x8aac93a6, 0x8aad9395, 0x8aae9388, 0x8aaf9399,
+    0x8ab0939f, 0x8ab1938d, 0x8ab293b1, 0x8ab39391, 0x8ab493b2, 0x8ab593a4, 0x8ab693a8, 0x8ab793b4,
+    0x8ab893a3, 0x8ab993a5, 0x8aba95d2, 0x8abb95d3, 0x8abc95d1, 0x8abd96b3, 0x8abe96d7, 0x8abf96da,
+    0x8ac05dc2, 0x8ac196df, 0x8ac296d8, 0x8ac396dd, 0x8ac49723, 0x8ac59722, 0x8ac69725, 0x8ac797ac,
+    0x8ac897ae, 0x8ac997a8, 0x8aca97ab, 0x8acb97a4, 0x8acc97aa, 0x8acd97a2, 0x8ace97a5, 0x8acf97d7,
+    0x8ad097d9, 0x8ad197d6, 0x8ad297d8, 0x8ad397fa, 0x8ad49850, 0x8ad59851, 0x8ad69852, 0x8ad798b8,
+    0x8ad89941, 0x8ad9993c, 0x8ada993a, 0x8adb9a0f, 0x8adc9a0b, 0x8add9a09, 0x8ade9a0d, 0x8adf9a04,
+    0x8ae09a11, 0x8ae19a0a, 0x8ae29a05, 0x8ae39a07, 0x8ae49a06, 0x8ae59ac0, 0x8ae69adc, 0x8ae79b08,
+    0x8ae89b04, 0x8ae99b05, 0x8aea9b29, 0x8aeb9b35, 0x8aec9b4a, 0x8aed9b4c, 0x8aee9b4b, 0x8aef9bc7,
+    0x8af09bc6, 0x8af19bc3, 0x8af29bbf, 0x8af39bc1, 0x8af49bb5, 0x8af59bb8, 0x8af69bd3, 0x8af79bb6,
+    0x8af89bc4, 0x8af99bb9, 0x8afa9bbd, 0x8afb9d5c, 0x8afc9d53, 0x8afd9d4f, 0x8b419d4a, 0x8b429d5b,
+    0x8b439d4b, 0x8b449d59, 0x8b459d56, 0x8b469d4c, 0x8b479d57, 0x8b489d52, 0x8b499d54, 0x8b4a9d5f,
+    0x8b4b9d58, 0x8b4c9d5a, 0x8b4d9e8e, 0x8b4e9e8c, 0x8b4f9edf, 0x8b509f01, 0x8b519f00, 0x8b529f16,
+    0x8b539f25, 0x8b549f2b, 0x8b559f2a, 0x8b569f29, 0x8b579f28, 0x8b589f4c, 0x8b599f55, 0x8b5a5134,
+    0x8b5b5135, 0x8b5c5296, 0x8b5d52f7, 0x8b5e53b4, 0x8b5f56ab, 0x8b6056ad, 0x8b6156a6, 0x8b6256a7,
+    0x8b6356aa, 0x8b6456ac, 0x8b6558da, 0x8b6658dd, 0x8b6758db, 0x8b685912, 0x8b695b3d, 0x8b6a5b3e,
+    0x8b6b5b3f, 0x8b6c5dc3, 0x8b6d5e70, 0x8b6e5fbf, 0x8b6f61fb, 0x8b706507, 0x8b716510, 0x8b72650d,
+    0x8b736509, 0x8b74650c, 0x8b75650e, 0x8b766584, 0x8b7765de, 0x8b7865dd, 0x8b7966de, 0x8b7a6ae7,
+    0x8b7b6ae0, 0x8b7c6acc, 0x8b7d6ad1, 0x8b7e6ad9, 0x8b7f6acb, 0x8b816adf, 0x8b826adc, 0x8b836ad0,
+    0x8b846aeb, 0x8b856acf, 0x8b866acd, 0x8b876ade, 0x8b886b60, 0x8b896bb0, 0x8b8a6c0c, 0x8b8b7019,
+    0x8b8c7027, 0x8b8d7020, 0x8b8e7016, 0x8b8f702b, 0x8b907021, 0x8b917022, 0x8b927023, 0x8b937029,
+    0x8b947017, 0x8b957024, 0x8b96701c, 0x8b97702a, 0x8b98720c, 0x8b99720a, 0x8b9a7207, 0x8b9b7202,
+    0x8b9c7205, 0x8b9d72a5, 0x8b9e72a6, 0x8b9f72a4, 0x8ba072a3, 0x8ba172a1, 0x8ba274cb, 0x8ba374c5,
+    0x8ba474b7, 0x8ba574c3, 0x8ba67516, 0x8ba77660, 0x8ba877c9, 0x8ba977ca, 0x8baa77c4, 0x8bab77f1,
+    0x8bac791d, 0x8bad791b, 0x8bae7921, 0x8baf791c, 0x8bb07917, 0x8bb1791e, 0x8bb279b0, 0x8bb37a67,
+    0x8bb47a68, 0x8bb57c33, 0x8bb67c3c, 0x8bb77c39, 0x8bb87c2c, 0x8bb97c3b, 0x8bba7cec, 0x8bbb7cea,
+    0x8bbc7e76, 0x8bbd7e75, 0x8bbe7e78, 0x8bbf7e70, 0x8bc07e77, 0x8bc17e6f, 0x8bc27e7a, 0x8bc37e72,
+    0x8bc47e74, 0x8bc57e68, 0x8bc67f4b, 0x8bc77f4a, 0x8bc87f83, 0x8bc97f86, 0x8bca7fb7, 0x8bcb7ffd,
+    0x8bcc7ffe, 0x8bcd8078, 0x8bce81d7, 0x8bcf81d5, 0x8bd08264, 0x8bd18261, 0x8bd28263, 0x8bd385eb,
+    0x8bd485f1, 0x8bd585ed, 0x8bd685d9, 0x8bd785e1, 0x8bd885e8, 0x8bd985da, 0x8bda85d7, 0x8bdb85ec,
+    0x8bdc85f2, 0x8bdd85f8, 0x8bde85d8, 0x8bdf85df, 0x8be085e3, 0x8be185dc, 0x8be285d1, 0x8be385f0,
+    0x8be485e6, 0x8be585ef, 0x8be685de, 0x8be785e2, 0x8be88800, 0x8be987fa, 0x8bea8803, 0x8beb87f6,
+    0x8bec87f7, 0x8bed8809, 0x8bee880c, 0x8bef880b, 0x8bf08806, 0x8bf187fc, 0x8bf28808, 0x8bf387ff,
+    0x8bf4880a, 0x8bf58802, 0x8bf68962, 0x8bf7895a, 0x8bf8895b, 0x8bf98957, 0x8bfa8961, 0x8bfb895c,
+    0x8bfc8958, 0x8bfd895d, 0x8c418959, 0x8c428988, 0x8c4389b7, 0x8c4489b6, 0x8c4589f6, 0x8c468b50,
+    0x8c478b48, 0x8c488b4a, 0x8c498b40, 0x8c4a8b53, 0x8c4b8b56, 0x8c4c8b54, 0x8c4d8b4b, 0x8c4e8b55,
+    0x8c4f8b51, 0x8c508b42, 0x8c518b52, 0x8c528b57, 0x8c538c43, 0x8c548c77, 0x8c558c76, 0x8c568c9a,
+    0x8c578d06, 0x8c588d07, 0x8c598d09, 0x8c5a8dac, 0x8c5b8daa, 0x8c5c8dad, 0x8c5d8dab, 0x8c5e8e6d,
+    0x8c5f8e78, 0x8c608e73, 0x8c618e6a, 0x8c628e6f, 0x8c638e7b, 0x8c648ec2, 0x8c658f52, 0x8c668f51,
+    0x8c678f4f, 0x8c688f50, 0x8c698f53, 0x8c6a8fb4, 0x8c6b9140, 0x8c6c913f, 0x8c6d91b0, 0x8c6e91ad,
+    0x8c6f93de, 0x8c7093c7, 0x8c7193cf, 0x8c7293c2, 0x8c7393da, 0x8c7493d0, 0x8c7593f9, 0x8c7693ec,
+    0x8c7793cc, 0x8c7893d9, 0x8c7993a9, 0x8c7a93e6, 0x8c7b93ca, 0x8c7c93d4, 0x8c7d93ee, 0x8c7e93e3,
+    0x8c7f93d5, 0x8c8193c4, 0x8c8293ce, 0x8c8393c0, 0x8c8493d2, 0x8c8593e7, 0x8c86957d, 0x8c8795da,
+    0x8c8895db, 0x8c8996e1, 0x8c8a9729, 0x8c8b972b, 0x8c8c972c, 0x8c8d9728, 0x8c8e9726, 0x8c8f97b3,
+    0x8c9097b7, 0x8c9197b6, 0x8c9297dd, 0x8c9397de, 0x8c9497df, 0x8c95985c, 0x8c969859, 0x8c97985d,
+    0x8c989857, 0x8c9998bf, 0x8c9a98bd, 0x8c9b98bb, 0x8c9c98be, 0x8c9d9948, 0x8c9e9947, 0x8c9f9943,
+    0x8ca099a6, 0x8ca199a7, 0x8ca29a1a, 0x8ca39a15, 0x8ca49a25, 0x8ca59a1d, 0x8ca69a24, 0x8ca79a1b,
+    0x8ca89a22, 0x8ca99a20, 0x8caa9a27, 0x8cab9a23, 0x8cac9a1e, 0x8cad9a1c, 0x8cae9a14, 0x8caf9ac2,
+    0x8cb09b0b, 0x8cb19b0a, 0x8cb29b0e, 0x8cb39b0c, 0x8cb49b37, 0x8cb59bea, 0x8cb69beb, 0x8cb79be0,
+    0x8cb89bde, 0x8cb99be4, 0x8cba9be6, 0x8cbb9be2, 0x8cbc9bf0, 0x8cbd9bd4, 0x8cbe9bd7, 0x8cbf9bec,
+    0x8cc09bdc, 0x8cc19bd9, 0x8cc29be5, 0x8cc39bd5, 0x8cc49be1, 0x8cc59bda, 0x8cc69d77, 0x8cc79d81,
+    0x8cc89d8a, 0x8cc99d84, 0x8cca9d88, 0x8ccb9d71, 0x8ccc9d80, 0x8ccd9d78, 0x8cce9d86, 0x8ccf9d8b,
+    0x8cd09d8c, 0x8cd19d7d, 0x8cd29d6b, 0x8cd39d74, 0x8cd49d75, 0x8cd59d70, 0x8cd69d69, 0x8cd79d85,
+    0x8cd89d73, 0x8cd99d7b, 0x8cda9d82, 0x8cdb9d6f, 0x8cdc9d79, 0x8cdd9d7f, 0x8cde9d87, 0x8cdf9d68,
+    0x8ce09e94, 0x8ce19e91, 0x8ce29ec0, 0x8ce39efc, 0x8ce49f2d, 0x8ce59f40, 0x8ce69f41, 0x8ce79f4d,
+    0x8ce89f56, 0x8ce99f57, 0x8cea9f58, 0x8ceb5337, 0x8cec56b2, 0x8ced56b5, 0x8cee56b3, 0x8cef58e3,
+    0x8cf05b45, 0x8cf15dc6, 0x8cf25dc7, 0x8cf35eee, 0x8cf45eef, 0x8cf55fc0, 0x8cf65fc1, 0x8cf761f9,
+    0x8cf86517, 0x8cf96516, 0x8cfa6515, 0x8cfb6513, 0x8cfc65df, 0x8cfd66e8, 0x8d4166e3, 0x8d4266e4,
+    0x8d436af3, 0x8d446af0, 0x8d456aea, 0x8d466ae8, 0x8d476af9, 0x8d486af1, 0x8d496aee, 0x8d4a6aef,
+    0x8d4b703c, 0x8d4c7035, 0x8d4d702f, 0x8d4e7037, 0x8d4f7034, 0x8d507031, 0x8d517042, 0x8d527038,
+    0x8d53703f, 0x8d54703a, 0x8d557039, 0x8d567040, 0x8d57703b, 0x8d587033, 0x8d597041, 0x8d5a7213,
+    0x8d5b7214, 0x8d5c72a8, 0x8d5d737d, 0x8d5e737c, 0x8d5f74ba, 0x8d6076ab, 0x8d6176aa, 0x8d6276be,
+    0x8d6376ed, 0x8d6477cc, 0x8d6577ce, 0x8d6677cf, 0x8d6777cd, 0x8d6877f2, 0x8d697925, 0x8d6a7923,
+    0x8d6b7927, 0x8d6c7928, 0x8d6d7924, 0x8d6e7929, 0x8d6f79b2, 0x8d707a6e, 0x8d717a6c, 0x8d727a6d,
+    0x8d737af7, 0x8d747c49, 0x8d757c48, 0x8d767c4a, 0x8d777c47, 0x8d787c45, 0x8d797cee, 0x8d7a7e7b,
+    0x8d7b7e7e, 0x8d7c7e81, 0x8d7d7e80, 0x8d7e7fba, 0x8d7f7fff, 0x8d818079, 0x8d8281db, 0x8d8381d9,
+    0x8d84820b, 0x8d858268, 0x8d868269, 0x8d878622, 0x8d8885ff, 0x8d898601, 0x8d8a85fe, 0x8d8b861b,
+    0x8d8c8600, 0x8d8d85f6, 0x8d8e8604, 0x8d8f8609, 0x8d908605, 0x8d91860c, 0x8d9285fd, 0x8d938819,
+    0x8d948810, 0x8d958811, 0x8d968817, 0x8d978813, 0x8d988816, 0x8d998963, 0x8d9a8966, 0x8d9b89b9,
+    0x8d9c89f7, 0x8d9d8b60, 0x8d9e8b6a, 0x8d9f8b5d, 0x8da08b68, 0x8da18b63, 0x8da28b65, 0x8da38b67,
+    0x8da48b6d, 0x8da58dae, 0x8da68e86, 0x8da78e88, 0x8da88e84, 0x8da98f59, 0x8daa8f56, 0x8dab8f57,
+    0x8dac8f55, 0x8dad8f58, 0x8dae8f5a, 0x8daf908d, 0x8db09143, 0x8db19141, 0x8db291b7, 0x8db391b5,
+    0x8db491b2, 0x8db591b3, 0x8db6940b, 0x8db79413, 0x8db893fb, 0x8db99420, 0x8dba940f, 0x8dbb9414,
+    0x8dbc93fe, 0x8dbd9415, 0x8dbe9410, 0x8dbf9428, 0x8dc09419, 0x8dc1940d, 0x8dc293f5, 0x8dc39400,
+    0x8dc493f7, 0x8dc59407, 0x8dc6940e, 0x8dc79416, 0x8dc89412, 0x8dc993fa, 0x8dca9409, 0x8dcb93f8,
+    0x8dcc940a, 0x8dcd93ff, 0x8dce93fc, 0x8dcf940c, 0x8dd093f6, 0x8dd19411, 0x8dd29406, 0x8dd395de,
+    0x8dd495e0, 0x8dd595df, 0x8dd6972e, 0x8dd7972f, 0x8dd897b9, 0x8dd997bb, 0x8dda97fd, 0x8ddb97fe,
+    0x8ddc9860, 0x8ddd9862, 0x8dde9863, 0x8ddf985f, 0x8de098c1, 0x8de198c2, 0x8de29950, 0x8de3994e,
+    0x8de49959, 0x8de5994c, 0x8de6994b, 0x8de79953, 0x8de89a32, 0x8de99a34, 0x8dea9a31, 0x8deb9a2c,
+    0x8dec9a2a, 0x8ded9a36, 0x8dee9a29, 0x8def9a2e, 0x8df09a38, 0x8df19a2d, 0x8df29ac7, 0x8df39aca,
+    0x8df49ac6, 0x8df59b10, 0x8df69b12, 0x8df79b11, 0x8df89c0b, 0x8df99c08, 0x8dfa9bf7, 0x8dfb9c05,
+    0x8dfc9c12, 0x8dfd9bf8, 0x8e419c40, 0x8e429c07, 0x8e439c0e, 0x8e449c06, 0x8e459c17, 0x8e469c14,
+    0x8e479c09, 0x8e489d9f, 0x8e499d99, 0x8e4a9da4, 0x8e4b9d9d, 0x8e4c9d92, 0x8e4d9d98, 0x8e4e9d90,
+    0x8e4f9d9b, 0x8e509da0, 0x8e519d94, 0x8e529d9c, 0x8e539daa, 0x8e549d97, 0x8e559da1, 0x8e569d9a,
+    0x8e579da2, 0x8e589da8, 0x8e599d9e, 0x8e5a9da3, 0x8e5b9dbf, 0x8e5c9da9, 0x8e5d9d96, 0x8e5e9da6,
+    0x8e5f9da7, 0x8e609e99, 0x8e619e9b, 0x8e629e9a, 0x8e639ee5, 0x8e649ee4, 0x8e659ee7, 0x8e669ee6,
+    0x8e679f30, 0x8e689f2e, 0x8e699f5b, 0x8e6a9f60, 0x8e6b9f5e, 0x8e6c9f5d, 0x8e6d9f59, 0x8e6e9f91,
+    0x8e6f513a, 0x8e705139, 0x8e715298, 0x8e725297, 0x8e7356c3, 0x8e7456bd, 0x8e7556be, 0x8e765b48,
+    0x8e775b47, 0x8e785dcb, 0x8e795dcf, 0x8e7a5ef1, 0x8e7b61fd, 0x8e7c651b, 0x8e7d6b02, 0x8e7e6afc,
+    0x8e7f6b03, 0x8e816af8, 0x8e826b00, 0x8e837043, 0x8e847044, 0x8e85704a, 0x8e867048, 0x8e877049,
+    0x8e887045, 0x8e897046, 0x8e8a721d, 0x8e8b721a, 0x8e8c7219, 0x8e8d737e, 0x8e8e7517, 0x8e8f766a,
+    0x8e9077d0, 0x8e91792d, 0x8e927931, 0x8e93792f, 0x8e947c54, 0x8e957c53, 0x8e967cf2, 0x8e977e8a,
+    0x8e987e87, 0x8e997e88, 0x8e9a7e8b, 0x8e9b7e86, 0x8e9c7e8d, 0x8e9d7f4d, 0x8e9e7fbb, 0x8e9f8030,
+    0x8ea081dd, 0x8ea18618, 0x8ea2862a, 0x8ea38626, 0x8ea4861f, 0x8ea58623, 0x8ea6861c, 0x8ea78619,
+    0x8ea88627, 0x8ea9862e, 0x8eaa8621, 0x8eab8620, 0x8eac8629, 0x8ead861e, 0x8eae8625, 0x8eaf8829,
+    0x8eb0881d, 0x8eb1881b, 0x8eb28820, 0x8eb38824, 0x8eb4881c, 0x8eb5882b, 0x8eb6884a, 0x8eb7896d,
+    0x8eb88969, 0x8eb9896e, 0x8eba896b, 0x8ebb89fa, 0x8ebc8b79, 0x8ebd8b78, 0x8ebe8b45, 0x8ebf8b7a,
+    0x8ec08b7b, 0x8ec18d10, 0x8ec28d14, 0x8ec38daf, 0x8ec48e8e, 0x8ec58e8c, 0x8ec68f5e, 0x8ec78f5b,
+    0x8ec88f5d, 0x8ec99146, 0x8eca9144, 0x8ecb9145, 0x8ecc91b9, 0x8ecd943f, 0x8ece943b, 0x8ecf9436,
+    0x8ed09429, 0x8ed1943d, 0x8ed2943c, 0x8ed39430, 0x8ed49439, 0x8ed5942a, 0x8ed69437, 0x8ed7942c,
+    0x8ed89440, 0x8ed99431, 0x8eda95e5, 0x8edb95e4, 0x8edc95e3, 0x8edd9735, 0x8ede973a, 0x8edf97bf,
+    0x8ee097e1, 0x8ee19864, 0x8ee298c9, 0x8ee398c6, 0x8ee498c0, 0x8ee59958, 0x8ee69956, 0x8ee79a39,
+    0x8ee89a3d, 0x8ee99a46, 0x8eea9a44, 0x8eeb9a42, 0x8eec9a41, 0x8eed9a3a, 0x8eee9a3f, 0x8eef9acd,
+    0x8ef09b15, 0x8ef19b17, 0x8ef29b18, 0x8ef39b16, 0x8ef49b3a, 0x8ef59b52, 0x8ef69c2b, 0x8ef79c1d,
+    0x8ef89c1c, 0x8ef99c2c, 0x8efa9c23, 0x8efb9c28, 0x8efc9c29, 0x8efd9c24, 0x8f419c21, 0x8f429db7,
+    0x8f439db6, 0x8f449dbc, 0x8f459dc1, 0x8f469dc7, 0x8f479dca, 0x8f489dcf, 0x8f499dbe, 0x8f4a9dc5,
+    0x8f4b9dc3, 0x8f4c9dbb, 0x8f4d9db5, 0x8f4e9dce, 0x8f4f9db9, 0x8f509dba, 0x8f519dac, 0x8f529dc8,
+    0x8f539db1, 0x8f549dad, 0x8f559dcc, 0x8f569db3, 0x8f579dcd, 0x8f589db2, 0x8f599e7a, 0x8f5a9e9c,
+    0x8f5b9eeb, 0x8f5c9eee, 0x8f5d9eed, 0x8f5e9f1b, 0x8f5f9f18, 0x8f609f1a, 0x8f619f31, 0x8f629f4e,
+    0x8f639f65, 0x8f649f64, 0x8f659f92, 0x8f664eb9, 0x8f6756c6, 0x8f6856c5, 0x8f6956cb, 0x8f6a5971,
+    0x8f6b5b4b, 0x8f6c5b4c, 0x8f6d5dd5, 0x8f6e5dd1, 0x8f6f5ef2, 0x8f706521, 0x8f716520, 0x8f726526,
+    0x8f736522, 0x8f746b0b, 0x8f756b08, 0x8f766b09, 0x8f776c0d, 0x8f787055, 0x8f797056, 0x8f7a7057,
+    0x8f7b7052, 0x8f7c721e, 0x8f7d721f, 0x8f7e72a9, 0x8f7f737f, 0x8f8174d8, 0x8f8274d5, 0x8f8374d9,
+    0x8f8474d7, 0x8f85766d, 0x8f8676ad, 0x8f877935, 0x8f8879b4, 0x8f897a70, 0x8f8a7a71, 0x8f8b7c57,
+    0x8f8c7c5c, 0x8f8d7c59, 0x8f8e7c5b, 0x8f8f7c5a, 0x8f907cf4, 0x8f917cf1, 0x8f927e91, 0x8f937f4f,
+    0x8f947f87, 0x8f9581de, 0x8f96826b, 0x8f978634, 0x8f988635, 0x8f998633, 0x8f9a862c, 0x8f9b8632,
+    0x8f9c8636, 0x8f9d882c, 0x8f9e8828, 0x8f9f8826, 0x8fa0882a, 0x8fa18825, 0x8fa28971, 0x8fa389bf,
+    0x8fa489be, 0x8fa589fb, 0x8fa68b7e, 0x8fa78b84, 0x8fa88b82, 0x8fa98b86, 0x8faa8b85, 0x8fab8b7f,
+    0x8fac8d15, 0x8fad8e95, 0x8fae8e94, 0x8faf8e9a, 0x8fb08e92, 0x8fb18e90, 0x8fb28e96, 0x8fb38e97,
+    0x8fb48f60, 0x8fb58f62, 0x8fb69147, 0x8fb7944c, 0x8fb89450, 0x8fb9944a, 0x8fba944b, 0x8fbb944f,
+    0x8fbc9447, 0x8fbd9445, 0x8fbe9448, 0x8fbf9449, 0x8fc09446, 0x8fc1973f, 0x8fc297e3, 0x8fc3986a,
+    0x8fc49869, 0x8fc598cb, 0x8fc69954, 0x8fc7995b, 0x8fc89a4e, 0x8fc99a53, 0x8fca9a54, 0x8fcb9a4c,
+    0x8fcc9a4f, 0x8fcd9a48, 0x8fce9a4a, 0x8fcf9a49, 0x8fd09a52, 0x8fd19a50, 0x8fd29ad0, 0x8fd39b19,
+    0x8fd49b2b, 0x8fd59b3b, 0x8fd69b56, 0x8fd79b55, 0x8fd89c46, 0x8fd99c48, 0x8fda9c3f, 0x8fdb9c44,
+    0x8fdc9c39, 0x8fdd9c33, 0x8fde9c41, 0x8fdf9c3c, 0x8fe09c37, 0x8fe19c34, 0x8fe29c32, 0x8fe39c3d,
+    0x8fe49c36, 0x8fe59ddb, 0x8fe69dd2, 0x8fe79dde, 0x8fe89dda, 0x8fe99dcb, 0x8fea9dd0, 0x8feb9ddc,
+    0x8fec9dd1, 0x8fed9ddf, 0x8fee9de9, 0x8fef9dd9, 0x8ff09dd8, 0x8ff19dd6, 0x8ff29df5, 0x8ff39dd5,
+    0x8ff49ddd, 0x8ff59eb6, 0x8ff69ef0, 0x8ff79f35, 0x8ff89f33, 0x8ff99f32, 0x8ffa9f42, 0x8ffb9f6b,
+    0x8ffc9f95, 0x8ffd9fa2, 0x9041513d, 0x90425299, 0x904358e8, 0x904458e7, 0x90455972, 0x90465b4d,
+    0x90475dd8, 0x9048882f, 0x90495f4f, 0x904a6201, 0x904b6203, 0x904c6204, 0x904d6529, 0x904e6525,
+    0x904f6596, 0x905066eb, 0x90516b11, 0x90526b12, 0x90536b0f, 0x90546bca, 0x9055705b, 0x9056705a,
+    0x90577222, 0x90587382, 0x90597381, 0x905a7383, 0x905b7670, 0x905c77d4, 0x905d7c67, 0x905e7c66,
+    0x905f7e95, 0x9060826c, 0x9061863a, 0x90628640, 0x90638639, 0x9064863c, 0x90658631, 0x9066863b,
+    0x9067863e, 0x90688830, 0x90698832, 0x906a882e, 0x906b8833, 0x906c8976, 0x906d8974, 0x906e8973,
+    0x906f89fe, 0x90708b8c, 0x90718b8e, 0x90728b8b, 0x90738b88, 0x90748c45, 0x90758d19, 0x90768e98,
+    0x90778f64, 0x90788f63, 0x907991bc, 0x907a9462, 0x907b9455, 0x907c945d, 0x907d9457, 0x907e945e,
+    0x907f97c4, 0x908197c5, 0x90829800, 0x90839a56, 0x90849a59, 0x90859b1e, 0x90869b1f, 0x90879b20,
+    0x90889c52, 0x90899c58, 0x908a9c50, 0x908b9c4a, 0x908c9c4d, 0x908d9c4b, 0x908e9c55, 0x908f9c59,
+    0x90909c4c, 0x90919c4e, 0x90929dfb, 0x90939df7, 0x90949def, 0x90959de3, 0x90969deb, 0x90979df8,
+    0x90989de4, 0x90999df6, 0x909a9de1, 0x909b9dee, 0x909c9de6, 0x909d9df2, 0x909e9df0, 0x909f9de2,
+    0x90a09dec, 0x90a19df4, 0x90a29df3, 0x90a39de8, 0x90a49ded, 0x90a59ec2, 0x90a69ed0, 0x90a79ef2,
+    0x90a89ef3, 0x90a99f06, 0x90aa9f1c, 0x90ab9f38, 0x90ac9f37, 0x90ad9f36, 0x90ae9f43, 0x90af9f4f,
+    0x90b09f71, 0x90b19f70, 0x90b29f6e, 0x90b39f6f, 0x90b456d3, 0x90b556cd, 0x90b65b4e, 0x90b75c6d,
+    0x90b8652d, 0x90b966ed, 0x90ba66ee, 0x90bb6b13, 0x90bc705f, 0x90bd7061, 0x90be705d, 0x90bf7060,
+    0x90c07223, 0x90c174db, 0x90c274e5, 0x90c377d5, 0x90c47938, 0x90c579b7, 0x90c679b6, 0x90c77c6a,
+    0x90c87e97, 0x90c97f89, 0x90ca826d, 0x90cb8643, 0x90cc8838, 0x90cd8837, 0x90ce8835, 0x90cf884b,
+    0x90d08b94, 0x90d18b95, 0x90d28e9e, 0x90d38e9f, 0x90d48ea0, 0x90d58e9d, 0x90d691be, 0x90d791bd,
+    0x90d891c2, 0x90d9946b, 0x90da9468, 0x90db9469, 0x90dc96e5, 0x90dd9746, 0x90de9743, 0x90df9747,
+    0x90e097c7, 0x90e197e5, 0x90e29a5e, 0x90e39ad5, 0x90e49b59, 0x90e59c63, 0x90e69c67, 0x90e79c66,
+    0x90e89c62, 0x90e99c5e, 0x90ea9c60, 0x90eb9e02, 0x90ec9dfe, 0x90ed9e07, 0x90ee9e03, 0x90ef9e06,
+    0x90f09e05, 0x90f19e00, 0x90f29e01, 0x90f39e09, 0x90f49dff, 0x90f59dfd, 0x90f69e04, 0x90f79ea0,
+    0x90f89f1e, 0x90f99f46, 0x90fa9f74, 0x90fb9f75, 0x90fc9f76, 0x90fd56d4, 0x9141652e, 0x914265b8,
+    0x91436b18, 0x91446b19, 0x91456b17, 0x91466b1a, 0x91477062, 0x91487226, 0x914972aa, 0x914a77d8,
+    0x914b77d9, 0x914c7939, 0x914d7c69, 0x914e7c6b, 0x914f7cf6, 0x91507e9a, 0x91517e98, 0x91527e9b,
+    0x91537e99, 0x915481e0, 0x915581e1, 0x91568646, 0x91578647, 0x91588648, 0x91598979, 0x915a897a,
+    0x915b897c, 0x915c897b, 0x915d89ff, 0x915e8b98, 0x915f8b99, 0x91608ea5, 0x91618ea4, 0x91628ea3,
+    0x9163946e, 0x9164946d, 0x9165946f, 0x91669471, 0x91679473, 0x91689749, 0x91699872, 0x916a995f,
+    0x916b9c68, 0x916c9c6e, 0x916d9c6d, 0x916e9e0b, 0x916f9e0d, 0x91709e10, 0x91719e0f, 0x91729e12,
+    0x91739e11, 0x91749ea1, 0x91759ef5, 0x91769f09, 0x91779f47, 0x91789f78, 0x91799f7b, 0x917a9f7a,
+    0x917b9f79, 0x917c571e, 0x917d7066, 0x917e7c6f, 0x917f883c, 0x91818db2, 0x91828ea6, 0x918391c3,
+    0x91849474, 0x91859478, 0x91869476, 0x91879475, 0x91889a60, 0x91899c74, 0x918a9c73, 0x918b9c71,
+    0x918c9c75, 0x918d9e14, 0x918e9e13, 0x918f9ef6, 0x91909f0a, 0x91919fa4, 0x91927068, 0x91937065,
+    0x91947cf7, 0x9195866a, 0x9196883e, 0x9197883d, 0x9198883f, 0x91998b9e, 0x919a8c9c, 0x919b8ea9,
+    0x919c8ec9, 0x919d974b, 0x919e9873, 0x919f9874, 0x91a098cc, 0x91a19961, 0x91a299ab, 0x91a39a64,
+    0x91a49a66, 0x91a59a67, 0x91a69b24, 0x91a79e15, 0x91a89e17, 0x91a99f48, 0x91aa6207, 0x91ab6b1e,
+    0x91ac7227, 0x91ad864c, 0x91ae8ea8, 0x91af9482, 0x91b09480, 0x91b19481, 0x91b29a69, 0x91b39a68,
+    0x91b49b2e, 0x91b59e19, 0x91b67229, 0x91b7864b, 0x91b88b9f, 0x91b99483, 0x91ba9c79, 0x91bb9eb7,
+    0x91bc7675, 0x91bd9a6b, 0x91be9c7a, 0x91bf9e1d, 0x91c07069, 0x91c1706a, 0x91c29ea4, 0x91c39f7e,
+    0x91c49f49, 0x91c59f98, 0x91c6691e, 0x91c76eb8, 0xc241e000, 0xc242e001, 0xc243e002, 0xc244e003,
+    0xc245e004, 0xc246e005, 0xc247e006, 0xc248e007, 0xc249e008, 0xc24ae009, 0xc24be00a, 0xc24ce00b,
+    0xc24de00c, 0xc24ee00d, 0xc24fe00e, 0xc250e00f, 0xc251e010, 0xc252e011, 0xc253e012, 0xc254e013,
+    0xc255e014, 0xc256e015, 0xc257e016, 0xc258e017, 0xc259e018, 0xc25ae019, 0xc25be01a, 0xc25ce01b,
+    0xc25de01c, 0xc25ee01d, 0xc25fe01e, 0xc260e01f, 0xc261e020, 0xc262e021, 0xc263e022, 0xc264e023,
+    0xc265e024, 0xc266e025, 0xc267e026, 0xc268e027, 0xc269e028, 0xc26ae029, 0xc26be02a, 0xc26ce02b,
+    0xc26de02c, 0xc26ee02d, 0xc26fe02e, 0xc270e02f, 0xc271e030, 0xc272e031, 0xc273e032, 0xc274e033,
+    0xc275e034, 0xc276e035, 0xc277e036, 0xc278e037, 0xc279e038, 0xc27ae039, 0xc27be03a, 0xc27ce03b,
+    0xc27de03c, 0xc27ee03d, 0xc27fe03e, 0xc281e03f, 0xc282e040, 0xc283e041, 0xc284e042, 0xc285e043,
+    0xc286e044, 0xc287e045, 0xc288e046, 0xc289e047, 0xc28ae048, 0xc28be049, 0xc28ce04a, 0xc28de04b,
+    0xc28ee04c, 0xc28fe04d, 0xc290e04e, 0xc291e04f, 0xc292e050, 0xc293e051, 0xc294e052, 0xc295e053,
+    0xc296e054, 0xc297e055, 0xc298e056, 0xc299e057, 0xc29ae058, 0xc29be059, 0xc29ce05a, 0xc29de05b,
+    0xc29ee05c, 0xc29fe05d, 0xc2a0e05e, 0xc2a1e05f, 0xc2a2e060, 0xc2a3e061, 0xc2a4e062, 0xc2a5e063,
+    0xc2a6e064, 0xc2a7e065, 0xc2a8e066, 0xc2a9e067, 0xc2aae068, 0xc2abe069, 0xc2ace06a, 0xc2ade06b,
+    0xc2aee06c, 0xc2afe06d, 0xc2b0e06e, 0xc2b1e06f, 0xc2b2e070, 0xc2b3e071, 0xc2b4e072, 0xc2b5e073,
+    0xc2b6e074, 0xc2b7e075, 0xc2b8e076, 0xc2b9e077, 0xc2bae078, 0xc2bbe079, 0xc2bce07a, 0xc2bde07b,
+    0xc2bee07c, 0xc2bfe07d, 0xc2c0e07e, 0xc2c1e07f, 0xc2c2e080, 0xc2c3e081, 0xc2c4e082, 0xc2c5e083,
+    0xc2c6e084, 0xc2c7e085, 0xc2c8e086, 0xc2c9e087, 0xc2cae088, 0xc2cbe089, 0xc2cce08a, 0xc2cde08b,
+    0xc2cee08c, 0xc2cfe08d, 0xc2d0e08e, 0xc2d1e08f, 0xc2d2e090, 0xc2d3e091, 0xc2d4e092, 0xc2d5e093,
+    0xc2d6e094, 0xc2d7e095, 0xc2d8e096, 0xc2d9e097, 0xc2dae098, 0xc2dbe099, 0xc2dce09a, 0xc2dde09b,
+    0xc2dee09c, 0xc2dfe09d, 0xc2e0e09e, 0xc2e1e09f, 0xc2e2e0a0, 0xc2e3e0a1, 0xc2e4e0a2, 0xc2e5e0a3,
+    0xc2e6e0a4, 0xc2e7e0a5, 0xc2e8e0a6, 0xc2e9e0a7, 0xc2eae0a8, 0xc2ebe0a9, 0xc2ece0aa, 0xc2ede0ab,
+    0xc2eee0ac, 0xc2efe0ad, 0xc2f0e0ae, 0xc2f1e0af, 0xc2f2e0b0, 0xc2f3e0b1, 0xc2f4e0b2, 0xc2f5e0b3,
+    0xc2f6e0b4, 0xc2f7e0b5, 0xc2f8e0b6, 0xc2f9e0b7, 0xc2fae0b8, 0xc2fbe0b9, 0xc2fce0ba, 0xc2fde0bb,
+    0xc341e0bc, 0xc342e0bd, 0xc343e0be, 0xc344e0bf, 0xc345e0c0, 0xc346e0c1, 0xc347e0c2, 0xc348e0c3,
+    0xc349e0c4, 0xc34ae0c5, 0xc34be0c6, 0xc34ce0c7, 0xc34de0c8, 0xc34ee0c9, 0xc34fe0ca, 0xc350e0cb,
+    0xc351e0cc, 0xc352e0cd, 0xc353e0ce, 0xc354e0cf, 0xc355e0d0, 0xc356e0d1, 0xc357e0d2, 0xc358e0d3,
+    0xc359e0d4, 0xc35ae0d5, 0xc35be0d6, 0xc35ce0d7, 0xc35de0d8, 0xc35ee0d9, 0xc35fe0da, 0xc360e0db,
+    0xc361e0dc, 0xc362e0dd, 0xc363e0de, 0xc364e0df, 0xc365e0e0, 0xc366e0e1, 0xc367e0e2, 0xc368e0e3,
+    0xc369e0e4, 0xc36ae0e5, 0xc36be0e6, 0xc36ce0e7, 0xc36de0e8, 0xc36ee0e9, 0xc36fe0ea, 0xc370e0eb,
+    0xc371e0ec, 0xc372e0ed, 0xc373e0ee, 0xc374e0ef, 0xc375e0f0, 0xc376e0f1, 0xc377e0f2, 0xc378e0f3,
+    0xc379e0f4, 0xc37ae0f5, 0xc37be0f6, 0xc37ce0f7, 0xc37de0f8, 0xc37ee0f9, 0xc37fe0fa, 0xc381e0fb,
+    0xc382e0fc, 0xc383e0fd, 0xc384e0fe, 0xc385e0ff, 0xc386e100, 0xc387e101, 0xc388e102, 0xc389e103,
+    0xc38ae104, 0xc38be105, 0xc38ce106, 0xc38de107, 0xc38ee108, 0xc38fe109, 0xc390e10a, 0xc391e10b,
+    0xc392e10c, 0xc393e10d, 0xc394e10e, 0xc395e10f, 0xc396e110, 0xc397e111, 0xc398e112, 0xc399e113,
+    0xc39ae114, 0xc39be115, 0xc39ce116, 0xc39de117, 0xc39ee118, 0xc39fe119, 0xc3a0e11a, 0xc3a1e11b,
+    0xc3a2e11c, 0xc3a3e11d, 0xc3a4e11e, 0xc3a5e11f, 0xc3a6e120, 0xc3a7e121, 0xc3a8e122, 0xc3a9e123,
+    0xc3aae124, 0xc3abe125, 0xc3ace126, 0xc3ade127, 0xc3aee128, 0xc3afe129, 0xc3b0e12a, 0xc3b1e12b,
+    0xc3b2e12c, 0xc3b3e12d, 0xc3b4e12e, 0xc3b5e12f, 0xc3b6e130, 0xc3b7e131, 0xc3b8e132, 0xc3b9e133,
+    0xc3bae134, 0xc3bbe135, 0xc3bce136, 0xc3bde137, 0xc3bee138, 0xc3bfe139, 0xc3c0e13a, 0xc3c1e13b,
+    0xc3c2e13c, 0xc3c3e13d, 0xc3c4e13e, 0xc3c5e13f, 0xc3c6e140, 0xc3c7e141, 0xc3c8e142, 0xc3c9e143,
+    0xc3cae144, 0xc3cbe145, 0xc3cce146, 0xc3cde147, 0xc3cee148, 0xc3cfe149, 0xc3d0e14a, 0xc3d1e14b,
+    0xc3d2e14c, 0xc3d3e14d, 0xc3d4e14e, 0xc3d5e14f, 0xc3d6e150, 0xc3d7e151, 0xc3d8e152, 0xc3d9e153,
+    0xc3dae154, 0xc3dbe155, 0xc3dce156, 0xc3dde157, 0xc3dee158, 0xc3dfe159, 0xc3e0e15a, 0xc3e1e15b,
+    0xc3e2e15c, 0xc3e3e15d, 0xc3e4e15e, 0xc3e5e15f, 0xc3e6e160, 0xc3e7e161, 0xc3e8e162, 0xc3e9e163,
+    0xc3eae164, 0xc3ebe165, 0xc3ece166, 0xc3ede167, 0xc3eee168, 0xc3efe169, 0xc3f0e16a, 0xc3f1e16b,
+    0xc3f2e16c, 0xc3f3e16d, 0xc3f4e16e, 0xc3f5e16f, 0xc3f6e170, 0xc3f7e171, 0xc3f8e172, 0xc3f9e173,
+    0xc3fae174, 0xc3fbe175, 0xc3fce176, 0xc3fde177, 0xc441e178, 0xc442e179, 0xc443e17a, 0xc444e17b,
+    0xc445e17c, 0xc446e17d, 0xc447e17e, 0xc448e17f, 0xc449e180, 0xc44ae181, 0xc44be182, 0xc44ce183,
+    0xc44de184, 0xc44ee185, 0xc44fe186, 0xc450e187, 0xc451e188, 0xc452e189, 0xc453e18a, 0xc454e18b,
+    0xc455e18c, 0xc456e18d, 0xc457e18e, 0xc458e18f, 0xc459e190, 0xc45ae191, 0xc45be192, 0xc45ce193,
+    0xc45de194, 0xc45ee195, 0xc45fe196, 0xc460e197, 0xc461e198, 0xc462e199, 0xc463e19a, 0xc464e19b,
+    0xc465e19c, 0xc466e19d, 0xc467e19e, 0xc468e19f, 0xc469e1a0, 0xc46ae1a1, 0xc46be1a2, 0xc46ce1a3,
+    0xc46de1a4, 0xc46ee1a5, 0xc46fe1a6, 0xc470e1a7, 0xc471e1a8, 0xc472e1a9, 0xc473e1aa, 0xc474e1ab,
+    0xc475e1ac, 0xc476e1ad, 0xc477e1ae, 0xc478e1af, 0xc479e1b0, 0xc47ae1b1, 0xc47be1b2, 0xc47ce1b3,
+    0xc47de1b4, 0xc47ee1b5, 0xc47fe1b6, 0xc481e1b7, 0xc482e1b8, 0xc483e1b9, 0xc484e1ba, 0xc485e1bb,
+    0xc486e1bc, 0xc487e1bd, 0xc488e1be, 0xc489e1bf, 0xc48ae1c0, 0xc48be1c1, 0xc48ce1c2, 0xc48de1c3,
+    0xc48ee1c4, 0xc48fe1c5, 0xc490e1c6, 0xc491e1c7, 0xc492e1c8, 0xc493e1c9, 0xc494e1ca, 0xc495e1cb,
+    0xc496e1cc, 0xc497e1cd, 0xc498e1ce, 0xc499e1cf, 0xc49ae1d0, 0xc49be1d1, 0xc49ce1d2, 0xc49de1d3,
+    0xc49ee1d4, 0xc49fe1d5, 0xc4a0e1d6, 0xc4a1e1d7, 0xc4a2e1d8, 0xc4a3e1d9, 0xc4a4e1da, 0xc4a5e1db,
+    0xc4a6e1dc, 0xc4a7e1dd, 0xc4a8e1de, 0xc4a9e1df, 0xc4aae1e0, 0xc4abe1e1, 0xc4ace1e2, 0xc4ade1e3,
+    0xc4aee1e4, 0xc4afe1e5, 0xc4b0e1e6, 0xc4b1e1e7, 0xc4b2e1e8, 0xc4b3e1e9, 0xc4b4e1ea, 0xc4b5e1eb,
+    0xc4b6e1ec, 0xc4b7e1ed, 0xc4b8e1ee, 0xc4b9e1ef, 0xc4bae1f0, 0xc4bbe1f1, 0xc4bce1f2, 0xc4bde1f3,
+    0xc4bee1f4, 0xc4bfe1f5, 0xc4c0e1f6, 0xc4c1e1f7, 0xc4c2e1f8, 0xc4c3e1f9, 0xc4c4e1fa, 0xc4c5e1fb,
+    0xc4c6e1fc, 0xc4c7e1fd, 0xc4c8e1fe, 0xc4c9e1ff, 0xc4cae200, 0xc4cbe201, 0xc4cce202, 0xc4cde203,
+    0xc4cee204, 0xc4cfe205, 0xc4d0e206, 0xc4d1e207, 0xc4d2e208, 0xc4d3e209, 0xc4d4e20a, 0xc4d5e20b,
+    0xc4d6e20c, 0xc4d7e20d, 0xc4d8e20e, 0xc4d9e20f, 0xc4dae210, 0xc4dbe211, 0xc4dce212, 0xc4dde213,
+    0xc4dee214, 0xc4dfe215, 0xc4e0e216, 0xc4e1e217, 0xc4e2e218, 0xc4e3e219, 0xc4e4e21a, 0xc4e5e21b,
+    0xc4e6e21c, 0xc4e7e21d, 0xc4e8e21e, 0xc4e9e21f, 0xc4eae220, 0xc4ebe221, 0xc4ece222, 0xc4ede223,
+    0xc4eee224, 0xc4efe225, 0xc4f0e226, 0xc4f1e227, 0xc4f2e228, 0xc4f3e229, 0xc4f4e22a, 0xc4f5e22b,
+    0xc4f6e22c, 0xc4f7e22d, 0xc4f8e22e, 0xc4f9e22f, 0xc4fae230, 0xc4fbe231, 0xc4fce232, 0xc4fde233,
+    0xc541e234, 0xc542e235, 0xc543e236, 0xc544e237, 0xc545e238, 0xc546e239, 0xc547e23a, 0xc548e23b,
+    0xc549e23c, 0xc54ae23d, 0xc54be23e, 0xc54ce23f, 0xc54de240, 0xc54ee241, 0xc54fe242, 0xc550e243,
+    0xc551e244, 0xc552e245, 0xc553e246, 0xc554e247, 0xc555e248, 0xc556e249, 0xc557e24a, 0xc558e24b,
+    0xc559e24c, 0xc55ae24d, 0xc55be24e, 0xc55ce24f, 0xc55de250, 0xc55ee251, 0xc55fe252, 0xc560e253,
+    0xc561e254, 0xc562e255, 0xc563e256, 0xc564e257, 0xc565e258, 0xc566e259, 0xc567e25a, 0xc568e25b,
+    0xc569e25c, 0xc56ae25d, 0xc56be25e, 0xc56ce25f, 0xc56de260, 0xc56ee261, 0xc56fe262, 0xc570e263,
+    0xc571e264, 0xc572e265, 0xc573e266, 0xc574e267, 0xc575e268, 0xc576e269, 0xc577e26a, 0xc578e26b,
+    0xc579e26c, 0xc57ae26d, 0xc57be26e, 0xc57ce26f, 0xc57de270, 0xc57ee271, 0xc57fe272, 0xc581e273,
+    0xc582e274, 0xc583e275, 0xc584e276, 0xc585e277, 0xc586e278, 0xc587e279, 0xc588e27a, 0xc589e27b,
+    0xc58ae27c, 0xc58be27d, 0xc58ce27e, 0xc58de27f, 0xc58ee280, 0xc58fe281, 0xc590e282, 0xc591e283,
+    0xc592e284, 0xc593e285, 0xc594e286, 0xc595e287, 0xc596e288, 0xc597e289, 0xc598e28a, 0xc599e28b,
+    0xc59ae28c, 0xc59be28d, 0xc59ce28e, 0xc59de28f, 0xc59ee290, 0xc59fe291, 0xc5a0e292, 0xc5a1e293,
+    0xc5a2e294, 0xc5a3e295, 0xc5a4e296, 0xc5a5e297, 0xc5a6e298, 0xc5a7e299, 0xc5a8e29a, 0xc5a9e29b,
+    0xc5aae29c, 0xc5abe29d, 0xc5ace29e, 0xc5ade29f, 0xc5aee2a0, 0xc5afe2a1, 0xc5b0e2a2, 0xc5b1e2a3,
+    0xc5b2e2a4, 0xc5b3e2a5, 0xc5b4e2a6, 0xc5b5e2a7, 0xc5b6e2a8, 0xc5b7e2a9, 0xc5b8e2aa, 0xc5b9e2ab,
+    0xc5bae2ac, 0xc5bbe2ad, 0xc5bce2ae, 0xc5bde2af, 0xc5bee2b0, 0xc5bfe2b1, 0xc5c0e2b2, 0xc5c1e2b3,
+    0xc5c2e2b4, 0xc5c3e2b5, 0xc5c4e2b6, 0xc5c5e2b7, 0xc5c6e2b8, 0xc5c7e2b9, 0xc5c8e2ba, 0xc5c9e2bb,
+    0xc5cae2bc, 0xc5cbe2bd, 0xc5cce2be, 0xc5cde2bf, 0xc5cee2c0, 0xc5cfe2c1, 0xc5d0e2c2, 0xc5d1e2c3,
+    0xc5d2e2c4, 0xc5d3e2c5, 0xc5d4e2c6, 0xc5d5e2c7, 0xc5d6e2c8, 0xc5d7e2c9, 0xc5d8e2ca, 0xc5d9e2cb,
+    0xc5dae2cc, 0xc5dbe2cd, 0xc5dce2ce, 0xc5dde2cf, 0xc5dee2d0, 0xc5dfe2d1, 0xc5e0e2d2, 0xc5e1e2d3,
+    0xc5e2e2d4, 0xc5e3e2d5, 0xc5e4e2d6, 0xc5e5e2d7, 0xc5e6e2d8, 0xc5e7e2d9, 0xc5e8e2da, 0xc5e9e2db,
+    0xc5eae2dc, 0xc5ebe2dd, 0xc5ece2de, 0xc5ede2df, 0xc5eee2e0, 0xc5efe2e1, 0xc5f0e2e2, 0xc5f1e2e3,
+    0xc5f2e2e4, 0xc5f3e2e5, 0xc5f4e2e6, 0xc5f5e2e7, 0xc5f6e2e8, 0xc5f7e2e9, 0xc5f8e2ea, 0xc5f9e2eb,
+    0xc5fae2ec, 0xc5fbe2ed, 0xc5fce2ee, 0xc5fde2ef, 0xc641e2f0, 0xc642e2f1, 0xc643e2f2, 0xc644e2f3,
+    0xc645e2f4, 0xc646e2f5, 0xc647e2f6, 0xc648e2f7, 0xc649e2f8, 0xc64ae2f9, 0xc64be2fa, 0xc64ce2fb,
+    0xc64de2fc, 0xc64ee2fd, 0xc64fe2fe, 0xc650e2ff, 0xc651e300, 0xc652e301, 0xc653e302, 0xc654e303,
+    0xc655e304, 0xc656e305, 0xc657e306, 0xc658e307, 0xc659e308, 0xc65ae309, 0xc65be30a, 0xc65ce30b,
+    0xc65de30c, 0xc65ee30d, 0xc65fe30e, 0xc660e30f, 0xc661e310, 0xc662e311, 0xc663e312, 0xc664e313,
+    0xc665e314, 0xc666e315, 0xc667e316, 0xc668e317, 0xc669e318, 0xc66ae319, 0xc66be31a, 0xc66ce31b,
+    0xc66de31c, 0xc66ee31d, 0xc66fe31e, 0xc670e31f, 0xc671e320, 0xc672e321, 0xc673e322, 0xc674e323,
+    0xc675e324, 0xc676e325, 0xc677e326, 0xc678e327, 0xc679e328, 0xc67ae329, 0xc67be32a, 0xc67ce32b,
+    0xc67de32c, 0xc67ee32d, 0xc67fe32e, 0xc681e32f, 0xc682e330, 0xc683e331, 0xc684e332, 0xc685e333,
+    0xc686e334, 0xc687e335, 0xc688e336, 0xc689e337, 0xc68ae338, 0xc68be339, 0xc68ce33a, 0xc68de33b,
+    0xc68ee33c, 0xc68fe33d, 0xc690e33e, 0xc691e33f, 0xc692e340, 0xc693e341, 0xc694e342, 0xc695e343,
+    0xc696e344, 0xc697e345, 0xc698e346, 0xc699e347, 0xc69ae348, 0xc69be349, 0xc69ce34a, 0xc69de34b,
+    0xc69ee34c, 0xc69fe34d, 0xc6a0e34e, 0xc6a1e34f, 0xc6a2e350, 0xc6a3e351, 0xc6a4e352, 0xc6a5e353,
+    0xc6a6e354, 0xc6a7e355, 0xc6a8e356, 0xc6a9e357, 0xc6aae358, 0xc6abe359, 0xc6ace35a, 0xc6ade35b,
+    0xc6aee35c, 0xc6afe35d, 0xc6b0e35e, 0xc6b1e35f, 0xc6b2e360, 0xc6b3e361, 0xc6b4e362, 0xc6b5e363,
+    0xc6b6e364, 0xc6b7e365, 0xc6b8e366, 0xc6b9e367, 0xc6bae368, 0xc6bbe369, 0xc6bce36a, 0xc6bde36b,
+    0xc6bee36c, 0xc6bfe36d, 0xc6c0e36e, 0xc6c1e36f, 0xc6c2e370, 0xc6c3e371, 0xc6c4e372, 0xc6c5e373,
+    0xc6c6e374, 0xc6c7e375, 0xc6c8e376, 0xc6c9e377, 0xc6cae378, 0xc6cbe379, 0xc6cce37a, 0xc6cde37b,
+    0xc6cee37c, 0xc6cfe37d, 0xc6d0e37e, 0xc6d1e37f, 0xc6d2e380, 0xc6d3e381, 0xc6d4e382, 0xc6d5e383,
+    0xc6d6e384, 0xc6d7e385, 0xc6d8e386, 0xc6d9e387, 0xc6dae388, 0xc6dbe389, 0xc6dce38a, 0xc6dde38b,
+    0xc6dee38c, 0xc6dfe38d, 0xc6e0e38e, 0xc6e1e38f, 0xc6e2e390, 0xc6e3e391, 0xc6e4e392, 0xc6e5e393,
+    0xc6e6e394, 0xc6e7e395, 0xc6e8e396, 0xc6e9e397, 0xc6eae398, 0xc6ebe399, 0xc6ece39a, 0xc6ede39b,
+    0xc6eee39c, 0xc6efe39d, 0xc6f0e39e, 0xc6f1e39f, 0xc6f2e3a0, 0xc6f3e3a1, 0xc6f4e3a2, 0xc6f5e3a3,
+    0xc6f6e3a4, 0xc6f7e3a5, 0xc6f8e3a6, 0xc6f9e3a7, 0xc6fae3a8, 0xc6fbe3a9, 0xc6fce3aa, 0xc6fde3ab,
+    0xc741e3ac, 0xc742e3ad, 0xc743e3ae, 0xc744e3af, 0xc745e3b0, 0xc746e3b1, 0xc747e3b2, 0xc748e3b3,
+    0xc749e3b4, 0xc74ae3b5, 0xc74be3b6, 0xc74ce3b7, 0xc74de3b8, 0xc74ee3b9, 0xc74fe3ba, 0xc750e3bb,
+    0xc751e3bc, 0xc752e3bd, 0xc753e3be, 0xc754e3bf, 0xc755e3c0, 0xc756e3c1, 0xc757e3c2, 0xc758e3c3,
+    0xc759e3c4, 0xc75ae3c5, 0xc75be3c6, 0xc75ce3c7, 0xc75de3c8, 0xc75ee3c9, 0xc75fe3ca, 0xc760e3cb,
+    0xc761e3cc, 0xc762e3cd, 0xc763e3ce, 0xc764e3cf, 0xc765e3d0, 0xc766e3d1, 0xc767e3d2, 0xc768e3d3,
+    0xc769e3d4, 0xc76ae3d5, 0xc76be3d6, 0xc76ce3d7, 0xc76de3d8, 0xc76ee3d9, 0xc76fe3da, 0xc770e3db,
+    0xc771e3dc, 0xc772e3dd, 0xc773e3de, 0xc774e3df, 0xc775e3e0, 0xc776e3e1, 0xc777e3e2, 0xc778e3e3,
+    0xc779e3e4, 0xc77ae3e5, 0xc77be3e6, 0xc77ce3e7, 0xc77de3e8, 0xc77ee3e9, 0xc77fe3ea, 0xc781e3eb,
+    0xc782e3ec, 0xc783e3ed, 0xc784e3ee, 0xc785e3ef, 0xc786e3f0, 0xc787e3f1, 0xc788e3f2, 0xc789e3f3,
+    0xc78ae3f4, 0xc78be3f5, 0xc78ce3f6, 0xc78de3f7, 0xc78ee3f8, 0xc78fe3f9, 0xc790e3fa, 0xc791e3fb,
+    0xc792e3fc, 0xc793e3fd, 0xc794e3fe, 0xc795e3ff, 0xc796e400, 0xc797e401, 0xc798e402, 0xc799e403,
+    0xc79ae404, 0xc79be405, 0xc79ce406, 0xc79de407, 0xc79ee408, 0xc79fe409, 0xc7a0e40a, 0xc7a1e40b,
+    0xc7a2e40c, 0xc7a3e40d, 0xc7a4e40e, 0xc7a5e40f, 0xc7a6e410, 0xc7a7e411, 0xc7a8e412, 0xc7a9e413,
+    0xc7aae414, 0xc7abe415, 0xc7ace416, 0xc7ade417, 0xc7aee418, 0xc7afe419, 0xc7b0e41a, 0xc7b1e41b,
+    0xc7b2e41c, 0xc7b3e41d, 0xc7b4e41e, 0xc7b5e41f, 0xc7b6e420, 0xc7b7e421, 0xc7b8e422, 0xc7b9e423,
+    0xc7bae424, 0xc7bbe425, 0xc7bce426, 0xc7bde427, 0xc7bee428, 0xc7bfe429, 0xc7c0e42a, 0xc7c1e42b,
+    0xc7c2e42c, 0xc7c3e42d, 0xc7c4e42e, 0xc7c5e42f, 0xc7c6e430, 0xc7c7e431, 0xc7c8e432, 0xc7c9e433,
+    0xc7cae434, 0xc7cbe435, 0xc7cce436, 0xc7cde437, 0xc7cee438, 0xc7cfe439, 0xc7d0e43a, 0xc7d1e43b,
+    0xc7d2e43c, 0xc7d3e43d, 0xc7d4e43e, 0xc7d5e43f, 0xc7d6e440, 0xc7d7e441, 0xc7d8e442, 0xc7d9e443,
+    0xc7dae444, 0xc7dbe445, 0xc7dce446, 0xc7dde447, 0xc7dee448, 0xc7dfe449, 0xc7e0e44a, 0xc7e1e44b,
+    0xc7e2e44c, 0xc7e3e44d, 0xc7e4e44e, 0xc7e5e44f, 0xc7e6e450, 0xc7e7e451, 0xc7e8e452, 0xc7e9e453,
+    0xc7eae454, 0xc7ebe455, 0xc7ece456, 0xc7ede457, 0xc7eee458, 0xc7efe459, 0xc7f0e45a, 0xc7f1e45b,
+    0xc7f2e45c, 0xc7f3e45d, 0xc7f4e45e, 0xc7f5e45f, 0xc7f6e460, 0xc7f7e461, 0xc7f8e462, 0xc7f9e463,
+    0xc7fae464, 0xc7fbe465, 0xc7fce466, 0xc7fde467, 0xc841e468, 0xc842e469, 0xc843e46a, 0xc844e46b,
+    0xc845e46c, 0xc846e46d, 0xc847e46e, 0xc848e46f, 0xc849e470, 0xc84ae471, 0xc84be472, 0xc84ce473,
+    0xc84de474, 0xc84ee475, 0xc84fe476, 0xc850e477, 0xc851e478, 0xc852e479, 0xc853e47a, 0xc854e47b,
+    0xc855e47c, 0xc856e47d, 0xc857e47e, 0xc858e47f, 0xc859e480, 0xc85ae481, 0xc85be482, 0xc85ce483,
+    0xc85de484, 0xc85ee485, 0xc85fe486, 0xc860e487, 0xc861e488, 0xc862e489, 0xc863e48a, 0xc864e48b,
+    0xc865e48c, 0xc866e48d, 0xc867e48e, 0xc868e48f, 0xc869e490, 0xc86ae491, 0xc86be492, 0xc86ce493,
+    0xc86de494, 0xc86ee495, 0xc86fe496, 0xc870e497, 0xc871e498, 0xc872e499, 0xc873e49a, 0xc874e49b,
+    0xc875e49c, 0xc876e49d, 0xc877e49e, 0xc878e49f, 0xc879e4a0, 0xc87ae4a1, 0xc87be4a2, 0xc87ce4a3,
+    0xc87de4a4, 0xc87ee4a5, 0xc87fe4a6, 0xc881e4a7, 0xc882e4a8, 0xc883e4a9, 0xc884e4aa, 0xc885e4ab,
+    0xc886e4ac, 0xc887e4ad, 0xc888e4ae, 0xc889e4af, 0xc88ae4b0, 0xc88be4b1, 0xc88ce4b2, 0xc88de4b3,
+    0xc88ee4b4, 0xc88fe4b5, 0xc890e4b6, 0xc891e4b7, 0xc892e4b8, 0xc893e4b9, 0xc894e4ba, 0xc895e4bb,
+    0xc896e4bc, 0xc897e4bd, 0xc898e4be, 0xc899e4bf, 0xc89ae4c0, 0xc89be4c1, 0xc89ce4c2, 0xc89de4c3,
+    0xc89ee4c4, 0xc89fe4c5, 0xc8a0e4c6, 0xc8a1e4c7, 0xc8a2e4c8, 0xc8a3e4c9, 0xc8a4e4ca, 0xc8a5e4cb,
+    0xc8a6e4cc, 0xc8a7e4cd, 0xc8a8e4ce, 0xc8a9e4cf, 0xc8aae4d0, 0xc8abe4d1, 0xc8ace4d2, 0xc8ade4d3,
+    0xc8aee4d4, 0xc8afe4d5, 0xc8b0e4d6, 0xc8b1e4d7, 0xc8b2e4d8, 0xc8b3e4d9, 0xc8b4e4da, 0xc8b5e4db,
+    0xc8b6e4dc, 0xc8b7e4dd, 0xc8b8e4de, 0xc8b9e4df, 0xc8bae4e0, 0xc8bbe4e1, 0xc8bce4e2, 0xc8bde4e3,
+    0xc8bee4e4, 0xc8bfe4e5, 0xc8c0e4e6, 0xc8c1e4e7, 0xc8c2e4e8, 0xc8c3e4e9, 0xc8c4e4ea, 0xc8c5e4eb,
+    0xc8c6e4ec, 0xc8c7e4ed, 0xc8c8e4ee, 0xc8c9e4ef, 0xc8cae4f0, 0xc8cbe4f1, 0xc8cce4f2, 0xc8cde4f3,
+    0xc8cee4f4, 0xc8cfe4f5, 0xc8d0e4f6, 0xc8d1e4f7, 0xc8d2e4f8, 0xc8d3e4f9, 0xc8d4e4fa, 0xc8d5e4fb,
+    0xc8d6e4fc, 0xc8d7e4fd, 0xc8d8e4fe, 0xc8d9e4ff, 0xc8dae500, 0xc8dbe501, 0xc8dce502, 0xc8dde503,
+    0xc8dee504, 0xc8dfe505, 0xc8e0e506, 0xc8e1e507, 0xc8e2e508, 0xc8e3e509, 0xc8e4e50a, 0xc8e5e50b,
+    0xc8e6e50c, 0xc8e7e50d, 0xc8e8e50e, 0xc8e9e50f, 0xc8eae510, 0xc8ebe511, 0xc8ece512, 0xc8ede513,
+    0xc8eee514, 0xc8efe515, 0xc8f0e516, 0xc8f1e517, 0xc8f2e518, 0xc8f3e519, 0xc8f4e51a, 0xc8f5e51b,
+    0xc8f6e51c, 0xc8f7e51d, 0xc8f8e51e, 0xc8f9e51f, 0xc8fae520, 0xc8fbe521, 0xc8fce522, 0xc8fde523,
+    0xc941e524, 0xc942e525, 0xc943e526, 0xc944e527, 0xc945e528, 0xc946e529, 0xc947e52a, 0xc948e52b,
+    0xc949e52c, 0xc94ae52d, 0xc94be52e, 0xc94ce52f, 0xc94de530, 0xc94ee531, 0xc94fe532, 0xc950e533,
+    0xc951e534, 0xc952e535, 0xc953e536, 0xc954e537, 0xc955e538, 0xc956e539, 0xc957e53a, 0xc958e53b,
+    0xc959e53c, 0xc95ae53d, 0xc95be53e, 0xc95ce53f, 0xc95de540, 0xc95ee541, 0xc95fe542, 0xc960e543,
+    0xc961e544, 0xc962e545, 0xc963e546, 0xc964e547, 0xc965e548, 0xc966e549, 0xc967e54a, 0xc968e54b,
+    0xc969e54c, 0xc96ae54d, 0xc96be54e, 0xc96ce54f, 0xc96de550, 0xc96ee551, 0xc96fe552, 0xc970e553,
+    0xc971e554, 0xc972e555, 0xc973e556, 0xc974e557, 0xc975e558, 0xc976e559, 0xc977e55a, 0xc978e55b,
+    0xc979e55c, 0xc97ae55d, 0xc97be55e, 0xc97ce55f, 0xc97de560, 0xc97ee561, 0xc97fe562, 0xc981e563,
+    0xc982e564, 0xc983e565, 0xc984e566, 0xc985e567, 0xc986e568, 0xc987e569, 0xc988e56a, 0xc989e56b,
+    0xc98ae56c, 0xc98be56d, 0xc98ce56e, 0xc98de56f, 0xc98ee570, 0xc98fe571, 0xc990e572, 0xc991e573,
+    0xc992e574, 0xc993e575, 0xc994e576, 0xc995e577, 0xc996e578, 0xc997e579, 0xc998e57a, 0xc999e57b,
+    0xc99ae57c, 0xc99be57d, 0xc99ce57e, 0xc99de57f, 0xc99ee580, 0xc99fe581, 0xc9a0e582, 0xc9a1e583,
+    0xc9a2e584, 0xc9a3e585, 0xc9a4e586, 0xc9a5e587, 0xc9a6e588, 0xc9a7e589, 0xc9a8e58a, 0xc9a9e58b,
+    0xc9aae58c, 0xc9abe58d, 0xc9ace58e, 0xc9ade58f, 0xc9aee590, 0xc9afe591, 0xc9b0e592, 0xc9b1e593,
+    0xc9b2e594, 0xc9b3e595, 0xc9b4e596, 0xc9b5e597, 0xc9b6e598, 0xc9b7e599, 0xc9b8e59a, 0xc9b9e59b,
+    0xc9bae59c, 0xc9bbe59d, 0xc9bce59e, 0xc9bde59f, 0xc9bee5a0, 0xc9bfe5a1, 0xc9c0e5a2, 0xc9c1e5a3,
+    0xc9c2e5a4, 0xc9c3e5a5, 0xc9c4e5a6, 0xc9c5e5a7, 0xc9c6e5a8, 0xc9c7e5a9, 0xc9c8e5aa, 0xc9c9e5ab,
+    0xc9cae5ac, 0xc9cbe5ad, 0xc9cce5ae, 0xc9cde5af, 0xc9cee5b0, 0xc9cfe5b1, 0xc9d0e5b2, 0xc9d1e5b3,
+    0xc9d2e5b4, 0xc9d3e5b5, 0xc9d4e5b6, 0xc9d5e5b7, 0xc9d6e5b8, 0xc9d7e5b9, 0xc9d8e5ba, 0xc9d9e5bb,
+    0xc9dae5bc, 0xc9dbe5bd, 0xc9dce5be, 0xc9dde5bf, 0xc9dee5c0, 0xc9dfe5c1, 0xc9e0e5c2, 0xc9e1e5c3,
+    0xc9e2e5c4, 0xc9e3e5c5, 0xc9e4e5c6, 0xc9e5e5c7, 0xc9e6e5c8, 0xc9e7e5c9, 0xc9e8e5ca, 0xc9e9e5cb,
+    0xc9eae5cc, 0xc9ebe5cd, 0xc9ece5ce, 0xc9ede5cf, 0xc9eee5d0, 0xc9efe5d1, 0xc9f0e5d2, 0xc9f1e5d3,
+    0xc9f2e5d4, 0xc9f3e5d5, 0xc9f4e5d6, 0xc9f5e5d7, 0xc9f6e5d8, 0xc9f7e5d9, 0xc9f8e5da, 0xc9f9e5db,
+    0xc9fae5dc, 0xc9fbe5dd, 0xc9fce5de, 0xc9fde5df, 0xca41e5e0, 0xca42e5e1, 0xca43e5e2, 0xca44e5e3,
+    0xca45e5e4, 0xca46e5e5, 0xca47e5e6, 0xca48e5e7, 0xca49e5e8, 0xca4ae5e9, 0xca4be5ea, 0xca4ce5eb,
+    0xca4de5ec, 0xca4ee5ed, 0xca4fe5ee, 0xca50e5ef, 0xca51e5f0, 0xca52e5f1, 0xca53e5f2, 0xca54e5f3,
+    0xca55e5f4, 0xca56e5f5, 0xca57e5f6, 0xca58e5f7, 0xca59e5f8, 0xca5ae5f9, 0xca5be5fa, 0xca5ce5fb,
+    0xca5de5fc, 0xca5ee5fd, 0xca5fe5fe, 0xca60e5ff, 0xca61e600, 0xca62e601, 0xca63e602, 0xca64e603,
+    0xca65e604, 0xca66e605, 0xca67e606, 0xca68e607, 0xca69e608, 0xca6ae609, 0xca6be60a, 0xca6ce60b,
+    0xca6de60c, 0xca6ee60d, 0xca6fe60e, 0xca70e60f, 0xca71e610, 0xca72e611, 0xca73e612, 0xca74e613,
+    0xca75e614, 0xca76e615, 0xca77e616, 0xca78e617, 0xca79e618, 0xca7ae619, 0xca7be61a, 0xca7ce61b,
+    0xca7de61c, 0xca7ee61d, 0xca7fe61e, 0xca81e61f, 0xca82e620, 0xca83e621, 0xca84e622, 0xca85e623,
+    0xca86e624, 0xca87e625, 0xca88e626, 0xca89e627, 0xca8ae628, 0xca8be629, 0xca8ce62a, 0xca8de62b,
+    0xca8ee62c, 0xca8fe62d, 0xca90e62e, 0xca91e62f, 0xca92e630, 0xca93e631, 0xca94e632, 0xca95e633,
+    0xca96e634, 0xca97e635, 0xca98e636, 0xca99e637, 0xca9ae638, 0xca9be639, 0xca9ce63a, 0xca9de63b,
+    0xca9ee63c, 0xca9fe63d, 0xcaa0e63e, 0xcaa1e63f, 0xcaa2e640, 0xcaa3e641, 0xcaa4e642, 0xcaa5e643,
+    0xcaa6e644, 0xcaa7e645, 0xcaa8e646, 0xcaa9e647, 0xcaaae648, 0xcaabe649, 0xcaace64a, 0xcaade64b,
+    0xcaaee64c, 0xcaafe64d, 0xcab0e64e, 0xcab1e64f, 0xcab2e650, 0xcab3e651, 0xcab4e652, 0xcab5e653,
+    0xcab6e654, 0xcab7e655, 0xcab8e656, 0xcab9e657, 0xcabae658, 0xcabbe659, 0xcabce65a, 0xcabde65b,
+    0xcabee65c, 0xcabfe65d, 0xcac0e65e, 0xcac1e65f, 0xcac2e660, 0xcac3e661, 0xcac4e662, 0xcac5e663,
+    0xcac6e664, 0xcac7e665, 0xcac8e666, 0xcac9e667, 0xcacae668, 0xcacbe669, 0xcacce66a, 0xcacde66b,
+    0xcacee66c, 0xcacfe66d, 0xcad0e66e, 0xcad1e66f, 0xcad2e670, 0xcad3e671, 0xcad4e672, 0xcad5e673,
+    0xcad6e674, 0xcad7e675, 0xcad8e676, 0xcad9e677, 0xcadae678, 0xcadbe679, 0xcadce67a, 0xcadde67b,
+    0xcadee67c, 0xcadfe67d, 0xcae0e67e, 0xcae1e67f, 0xcae2e680, 0xcae3e681, 0xcae4e682, 0xcae5e683,
+    0xcae6e684, 0xcae7e685, 0xcae8e686, 0xcae9e687, 0xcaeae688, 0xcaebe689, 0xcaece68a, 0xcaede68b,
+    0xcaeee68c, 0xcaefe68d, 0xcaf0e68e, 0xcaf1e68f, 0xcaf2e690, 0xcaf3e691, 0xcaf4e692, 0xcaf5e693,
+    0xcaf6e694, 0xcaf7e695, 0xcaf8e696, 0xcaf9e697, 0xcafae698, 0xcafbe699, 0xcafce69a, 0xcafde69b,
+    0xcb41e69c, 0xcb42e69d, 0xcb43e69e, 0xcb44e69f, 0xcb45e6a0, 0xcb46e6a1, 0xcb47e6a2, 0xcb48e6a3,
+    0xcb49e6a4, 0xcb4ae6a5, 0xcb4be6a6, 0xcb4ce6a7, 0xcb4de6a8, 0xcb4ee6a9, 0xcb4fe6aa, 0xcb50e6ab,
+    0xcb51e6ac, 0xcb52e6ad, 0xcb53e6ae, 0xcb54e6af, 0xcb55e6b0, 0xcb56e6b1, 0xcb57e6b2, 0xcb58e6b3,
+    0xcb59e6b4, 0xcb5ae6b5, 0xcb5be6b6, 0xcb5ce6b7, 0xcb5de6b8, 0xcb5ee6b9, 0xcb5fe6ba, 0xcb60e6bb,
+    0xcb61e6bc, 0xcb62e6bd, 0xcb63e6be, 0xcb64e6bf, 0xcb65e6c0, 0xcb66e6c1, 0xcb67e6c2, 0xcb68e6c3,
+    0xcb69e6c4, 0xcb6ae6c5, 0xcb6be6c6, 0xcb6ce6c7, 0xcb6de6c8, 0xcb6ee6c9, 0xcb6fe6ca, 0xcb70e6cb,
+    0xcb71e6cc, 0xcb72e6cd, 0xcb73e6ce, 0xcb74e6cf, 0xcb75e6d0, 0xcb76e6d1, 0xcb77e6d2, 0xcb78e6d3,
+    0xcb79e6d4, 0xcb7ae6d5, 0xcb7be6d6, 0xcb7ce6d7, 0xcb7de6d8, 0xcb7ee6d9, 0xcb7fe6da, 0xcb81e6db,
+    0xcb82e6dc, 0xcb83e6dd, 0xcb84e6de, 0xcb85e6df, 0xcb86e6e0, 0xcb87e6e1, 0xcb88e6e2, 0xcb89e6e3,
+    0xcb8ae6e4, 0xcb8be6e5, 0xcb8ce6e6, 0xcb8de6e7, 0xcb8ee6e8, 0xcb8fe6e9, 0xcb90e6ea, 0xcb91e6eb,
+    0xcb92e6ec, 0xcb93e6ed, 0xcb94e6ee, 0xcb95e6ef, 0xcb96e6f0, 0xcb97e6f1, 0xcb98e6f2, 0xcb99e6f3,
+    0xcb9ae6f4, 0xcb9be6f5, 0xcb9ce6f6, 0xcb9de6f7, 0xcb9ee6f8, 0xcb9fe6f9, 0xcba0e6fa, 0xcba1e6fb,
+    0xcba2e6fc, 0xcba3e6fd, 0xcba4e6fe, 0xcba5e6ff, 0xcba6e700, 0xcba7e701, 0xcba8e702, 0xcba9e703,
+    0xcbaae704, 0xcbabe705, 0xcbace706, 0xcbade707, 0xcbaee708, 0xcbafe709, 0xcbb0e70a, 0xcbb1e70b,
+    0xcbb2e70c, 0xcbb3e70d, 0xcbb4e70e, 0xcbb5e70f, 0xcbb6e710, 0xcbb7e711, 0xcbb8e712, 0xcbb9e713,
+    0xcbbae714, 0xcbbbe715, 0xcbbce716, 0xcbbde717, 0xcbbee718, 0xcbbfe719, 0xcbc0e71a, 0xcbc1e71b,
+    0xcbc2e71c, 0xcbc3e71d, 0xcbc4e71e, 0xcbc5e71f, 0xcbc6e720, 0xcbc7e721, 0xcbc8e722, 0xcbc9e723,
+    0xcbcae724, 0xcbcbe725, 0xcbcce726, 0xcbcde727, 0xcbcee728, 0xcbcfe729, 0xcbd0e72a, 0xcbd1e72b,
+    0xcbd2e72c, 0xcbd3e72d, 0xcbd4e72e, 0xcbd5e72f, 0xcbd6e730, 0xcbd7e731, 0xcbd8e732, 0xcbd9e733,
+    0xcbdae734, 0xcbdbe735, 0xcbdce736, 0xcbdde737, 0xcbdee738, 0xcbdfe739, 0xcbe0e73a, 0xcbe1e73b,
+    0xcbe2e73c, 0xcbe3e73d, 0xcbe4e73e, 0xcbe5e73f, 0xcbe6e740, 0xcbe7e741, 0xcbe8e742, 0xcbe9e743,
+    0xcbeae744, 0xcbebe745, 0xcbece746, 0xcbede747, 0xcbeee748, 0xcbefe749, 0xcbf0e74a, 0xcbf1e74b,
+    0xcbf2e74c, 0xcbf3e74d, 0xcbf4e74e, 0xcbf5e74f, 0xcbf6e750, 0xcbf7e751, 0xcbf8e752, 0xcbf9e753,
+    0xcbfae754, 0xcbfbe755, 0xcbfce756, 0xcbfde757, 0xcc41e758, 0xcc42e759, 0xcc43e75a, 0xcc44e75b,
+    0xcc45e75c, 0xcc46e75d, 0xcc47e75e, 0xcc48e75f, 0xcc49e760, 0xcc4ae761, 0xcc4be762, 0xcc4ce763,
+    0xcc4de764, 0xcc4ee765, 0xcc4fe766, 0xcc50e767, 0xcc51e768, 0xcc52e769, 0xcc53e76a, 0xcc54e76b,
+    0xcc55e76c, 0xcc56e76d, 0xcc57e76e, 0xcc58e76f, 0xcc59e770, 0xcc5ae771, 0xcc5be772, 0xcc5ce773,
+    0xcc5de774, 0xcc5ee775, 0xcc5fe776, 0xcc60e777, 0xcc61e778, 0xcc62e779, 0xcc63e77a, 0xcc64e77b,
+    0xcc65e77c, 0xcc66e77d, 0xcc67e77e, 0xcc68e77f, 0xcc69e780, 0xcc6ae781, 0xcc6be782, 0xcc6ce783,
+    0xcc6de784, 0xcc6ee785, 0xcc6fe786, 0xcc70e787, 0xcc71e788, 0xcc72e789, 0xcc73e78a, 0xcc74e78b,
+    0xcc75e78c, 0xcc76e78d, 0xcc77e78e, 0xcc78e78f, 0xcc79e790, 0xcc7ae791, 0xcc7be792, 0xcc7ce793,
+    0xcc7de794, 0xcc7ee795, 0xcc7fe796, 0xcc81e797, 0xcc82e798, 0xcc83e799, 0xcc84e79a, 0xcc85e79b,
+    0xcc86e79c, 0xcc87e79d, 0xcc88e79e, 0xcc89e79f, 0xcc8ae7a0, 0xcc8be7a1, 0xcc8ce7a2, 0xcc8de7a3,
+    0xcc8ee7a4, 0xcc8fe7a5, 0xcc90e7a6, 0xcc91e7a7, 0xcc92e7a8, 0xcc93e7a9, 0xcc94e7aa, 0xcc95e7ab,
+    0xcc96e7ac, 0xcc97e7ad, 0xcc98e7ae, 0xcc99e7af, 0xcc9ae7b0, 0xcc9be7b1, 0xcc9ce7b2, 0xcc9de7b3,
+    0xcc9ee7b4, 0xcc9fe7b5, 0xcca0e7b6, 0xcca1e7b7, 0xcca2e7b8, 0xcca3e7b9, 0xcca4e7ba, 0xcca5e7bb,
+    0xcca6e7bc, 0xcca7e7bd, 0xcca8e7be, 0xcca9e7bf, 0xccaae7c0, 0xccabe7c1, 0xccace7c2, 0xccade7c3,
+    0xccaee7c4, 0xccafe7c5, 0xccb0e7c6, 0xccb1e7c7, 0xccb2e7c8, 0xccb3e7c9, 0xccb4e7ca, 0xccb5e7cb,
+    0xccb6e7cc, 0xccb7e7cd, 0xccb8e7ce, 0xccb9e7cf, 0xccbae7d0, 0xccbbe7d1, 0xccbce7d2, 0xccbde7d3,
+    0xccbee7d4, 0xccbfe7d5, 0xccc0e7d6, 0xccc1e7d7, 0xccc2e7d8, 0xccc3e7d9, 0xccc4e7da, 0xccc5e7db,
+    0xccc6e7dc, 0xccc7e7dd, 0xccc8e7de, 0xccc9e7df, 0xcccae7e0, 0xcccbe7e1, 0xcccce7e2, 0xcccde7e3,
+    0xcccee7e4, 0xcccfe7e5, 0xccd0e7e6, 0xccd1e7e7, 0xccd2e7e8, 0xccd3e7e9, 0xccd4e7ea, 0xccd5e7eb,
+    0xccd6e7ec, 0xccd7e7ed, 0xccd8e7ee, 0xccd9e7ef, 0xccdae7f0, 0xccdbe7f1, 0xccdce7f2, 0xccdde7f3,
+    0xccdee7f4, 0xccdfe7f5, 0xcce0e7f6, 0xcce1e7f7, 0xcce2e7f8, 0xcce3e7f9, 0xcce4e7fa, 0xcce5e7fb,
+    0xcce6e7fc, 0xcce7e7fd, 0xcce8e7fe, 0xcce9e7ff, 0xcceae800, 0xccebe801, 0xccece802, 0xccede803,
+    0xcceee804, 0xccefe805, 0xccf0e806, 0xccf1e807, 0xccf2e808, 0xccf3e809, 0xccf4e80a, 0xccf5e80b,
+    0xccf6e80c, 0xccf7e80d, 0xccf8e80e, 0xccf9e80f, 0xccfae810, 0xccfbe811, 0xccfce812, 0xccfde813,
+    0xcd41e814, 0xcd42e815, 0xcd43e816, 0xcd44e817, 0xcd45e818, 0xcd46e819, 0xcd47e81a, 0xcd48e81b,
+    0xcd49e81c, 0xcd4ae81d, 0xcd4be81e, 0xcd4ce81f, 0xcd4de820, 0xcd4ee821, 0xcd4fe822, 0xcd50e823,
+    0xcd51e824, 0xcd52e825, 0xcd53e826, 0xcd54e827, 0xcd55e828, 0xcd56e829, 0xcd57e82a, 0xcd58e82b,
+    0xcd59e82c, 0xcd5ae82d, 0xcd5be82e, 0xcd5ce82f, 0xcd5de830, 0xcd5ee831, 0xcd5fe832, 0xcd60e833,
+    0xcd61e834, 0xcd62e835, 0xcd63e836, 0xcd64e837, 0xcd65e838, 0xcd66e839, 0xcd67e83a, 0xcd68e83b,
+    0xcd69e83c, 0xcd6ae83d, 0xcd6be83e, 0xcd6ce83f, 0xcd6de840, 0xcd6ee841, 0xcd6fe842, 0xcd70e843,
+    0xcd71e844, 0xcd72e845, 0xcd73e846, 0xcd74e847, 0xcd75e848, 0xcd76e849, 0xcd77e84a, 0xcd78e84b,
+    0xcd79e84c, 0xcd7ae84d, 0xcd7be84e, 0xcd7ce84f, 0xcd7de850, 0xcd7ee851, 0xcd7fe852, 0xcd81e853,
+    0xcd82e854, 0xcd83e855, 0xcd84e856, 0xcd85e857, 0xcd86e858, 0xcd87e859, 0xcd88e85a, 0xcd89e85b,
+    0xcd8ae85c, 0xcd8be85d, 0xcd8ce85e, 0xcd8de85f, 0xcd8ee860, 0xcd8fe861, 0xcd90e862, 0xcd91e863,
+    0xcd92e864, 0xcd93e865, 0xcd94e866, 0xcd95e867, 0xcd96e868, 0xcd97e869, 0xcd98e86a, 0xcd99e86b,
+    0xcd9ae86c, 0xcd9be86d, 0xcd9ce86e, 0xcd9de86f, 0xcd9ee870, 0xcd9fe871, 0xcda0e872, 0xcda1e873,
+    0xcda2e874, 0xcda3e875, 0xcda4e876, 0xcda5e877, 0xcda6e878, 0xcda7e879, 0xcda8e87a, 0xcda9e87b,
+    0xcdaae87c, 0xcdabe87d, 0xcdace87e, 0xcdade87f, 0xcdaee880, 0xcdafe881, 0xcdb0e882, 0xcdb1e883,
+    0xcdb2e884, 0xcdb3e885, 0xcdb4e886, 0xcdb5e887, 0xcdb6e888, 0xcdb7e889, 0xcdb8e88a, 0xcdb9e88b,
+    0xcdbae88c, 0xcdbbe88d, 0xcdbce88e, 0xcdbde88f, 0xcdbee890, 0xcdbfe891, 0xcdc0e892, 0xcdc1e893,
+    0xcdc2e894, 0xcdc3e895, 0xcdc4e896, 0xcdc5e897, 0xcdc6e898, 0xcdc7e899, 0xcdc8e89a, 0xcdc9e89b,
+    0xcdcae89c, 0xcdcbe89d, 0xcdcce89e, 0xcdcde89f, 0xcdcee8a0, 0xcdcfe8a1, 0xcdd0e8a2, 0xcdd1e8a3,
+    0xcdd2e8a4, 0xcdd3e8a5, 0xcdd4e8a6, 0xcdd5e8a7, 0xcdd6e8a8, 0xcdd7e8a9, 0xcdd8e8aa, 0xcdd9e8ab,
+    0xcddae8ac, 0xcddbe8ad, 0xcddce8ae, 0xcddde8af, 0xcddee8b0, 0xcddfe8b1, 0xcde0e8b2, 0xcde1e8b3,
+    0xcde2e8b4, 0xcde3e8b5, 0xcde4e8b6, 0xcde5e8b7, 0xcde6e8b8, 0xcde7e8b9, 0xcde8e8ba, 0xcde9e8bb,
+    0xcdeae8bc, 0xcdebe8bd, 0xcdece8be, 0xcdede8bf, 0xcdeee8c0, 0xcdefe8c1, 0xcdf0e8c2, 0xcdf1e8c3,
+    0xcdf2e8c4, 0xcdf3e8c5, 0xcdf4e8c6, 0xcdf5e8c7, 0xcdf6e8c8, 0xcdf7e8c9, 0xcdf8e8ca, 0xcdf9e8cb,
+    0xcdfae8cc, 0xcdfbe8cd, 0xcdfce8ce, 0xcdfde8cf, 0xce41e8d0, 0xce42e8d1, 0xce43e8d2, 0xce44e8d3,
+    0xce45e8d4, 0xce46e8d5, 0xce47e8d6, 0xce48e8d7, 0xce49e8d8, 0xce4ae8d9, 0xce4be8da, 0xce4ce8db,
+    0xce4de8dc, 0xce4ee8dd, 0xce4fe8de, 0xce50e8df, 0xce51e8e0, 0xce52e8e1, 0xce53e8e2, 0xce54e8e3,
+    0xce55e8e4, 0xce56e8e5, 0xce57e8e6, 0xce58e8e7, 0xce59e8e8, 0xce5ae8e9, 0xce5be8ea, 0xce5ce8eb,
+    0xce5de8ec, 0xce5ee8ed, 0xce5fe8ee, 0xce60e8ef, 0xce61e8f0, 0xce62e8f1, 0xce63e8f2, 0xce64e8f3,
+    0xce65e8f4, 0xce66e8f5, 0xce67e8f6, 0xce68e8f7, 0xce69e8f8, 0xce6ae8f9, 0xce6be8fa, 0xce6ce8fb,
+    0xce6de8fc, 0xce6ee8fd, 0xce6fe8fe, 0xce70e8ff, 0xce71e900, 0xce72e901, 0xce73e902, 0xce74e903,
+    0xce75e904, 0xce76e905, 0xce77e906, 0xce78e907, 0xce79e908, 0xce7ae909, 0xce7be90a, 0xce7ce90b,
+    0xce7de90c, 0xce7ee90d, 0xce7fe90e, 0xce81e90f, 0xce82e910, 0xce83e911, 0xce84e912, 0xce85e913,
+    0xce86e914, 0xce87e915, 0xce88e916, 0xce89e917, 0xce8ae918, 0xce8be919, 0xce8ce91a, 0xce8de91b,
+    0xce8ee91c, 0xce8fe91d, 0xce90e91e, 0xce91e91f, 0xce92e920, 0xce93e921, 0xce94e922, 0xce95e923,
+    0xce96e924, 0xce97e925, 0xce98e926, 0xce99e927, 0xce9ae928, 0xce9be929, 0xce9ce92a, 0xce9de92b,
+    0xce9ee92c, 0xce9fe92d, 0xcea0e92e, 0xcea1e92f, 0xcea2e930, 0xcea3e931, 0xcea4e932, 0xcea5e933,
+    0xcea6e934, 0xcea7e935, 0xcea8e936, 0xcea9e937, 0xceaae938, 0xceabe939, 0xceace93a, 0xceade93b,
+    0xceaee93c, 0xceafe93d, 0xceb0e93e, 0xceb1e93f, 0xceb2e940, 0xceb3e941, 0xceb4e942, 0xceb5e943,
+    0xceb6e944, 0xceb7e945, 0xceb8e946, 0xceb9e947, 0xcebae948, 0xcebbe949, 0xcebce94a, 0xcebde94b,
+    0xcebee94c, 0xcebfe94d, 0xcec0e94e, 0xcec1e94f, 0xcec2e950, 0xcec3e951, 0xcec4e952, 0xcec5e953,
+    0xcec6e954, 0xcec7e955, 0xcec8e956, 0xcec9e957, 0xcecae958, 0xcecbe959, 0xcecce95a, 0xcecde95b,
+    0xcecee95c, 0xcecfe95d, 0xced0e95e, 0xced1e95f, 0xced2e960, 0xced3e961, 0xced4e962, 0xced5e963,
+    0xced6e964, 0xced7e965, 0xced8e966, 0xced9e967, 0xcedae968, 0xcedbe969, 0xcedce96a, 0xcedde96b,
+    0xcedee96c, 0xcedfe96d, 0xcee0e96e, 0xcee1e96f, 0xcee2e970, 0xcee3e971, 0xcee4e972, 0xcee5e973,
+    0xcee6e974, 0xcee7e975, 0xcee8e976, 0xcee9e977, 0xceeae978, 0xceebe979, 0xceece97a, 0xceede97b,
+    0xceeee97c, 0xceefe97d, 0xcef0e97e, 0xcef1e97f, 0xcef2e980, 0xcef3e981, 0xcef4e982, 0xcef5e983,
+    0xcef6e984, 0xcef7e985, 0xcef8e986, 0xcef9e987, 0xcefae988, 0xcefbe989, 0xcefce98a, 0xcefde98b,
+    0xcf41e98c, 0xcf42e98d, 0xcf43e98e, 0xcf44e98f, 0xcf45e990, 0xcf46e991, 0xcf47e992, 0xcf48e993,
+    0xcf49e994, 0xcf4ae995, 0xcf4be996, 0xcf4ce997, 0xcf4de998, 0xcf4ee999, 0xcf4fe99a, 0xcf50e99b,
+    0xcf51e99c, 0xcf52e99d, 0xcf53e99e, 0xcf54e99f, 0xcf55e9a0, 0xcf56e9a1, 0xcf57e9a2, 0xcf58e9a3,
+    0xcf59e9a4, 0xcf5ae9a5, 0xcf5be9a6, 0xcf5ce9a7, 0xcf5de9a8, 0xcf5ee9a9, 0xcf5fe9aa, 0xcf60e9ab,
+    0xcf61e9ac, 0xcf62e9ad, 0xcf63e9ae, 0xcf64e9af, 0xcf65e9b0, 0xcf66e9b1, 0xcf67e9b2, 0xcf68e9b3,
+    0xcf69e9b4, 0xcf6ae9b5, 0xcf6be9b6, 0xcf6ce9b7, 0xcf6de9b8, 0xcf6ee9b9, 0xcf6fe9ba, 0xcf70e9bb,
+    0xcf71e9bc, 0xcf72e9bd, 0xcf73e9be, 0xcf74e9bf, 0xcf75e9c0, 0xcf76e9c1, 0xcf77e9c2, 0xcf78e9c3,
+    0xcf79e9c4, 0xcf7ae9c5, 0xcf7be9c6, 0xcf7ce9c7, 0xcf7de9c8, 0xcf7ee9c9, 0xcf7fe9ca, 0xcf81e9cb,
+    0xcf82e9cc, 0xcf83e9cd, 0xcf84e9ce, 0xcf85e9cf, 0xcf86e9d0, 0xcf87e9d1, 0xcf88e9d2, 0xcf89e9d3,
+    0xcf8ae9d4, 0xcf8be9d5, 0xcf8ce9d6, 0xcf8de9d7, 0xcf8ee9d8, 0xcf8fe9d9, 0xcf90e9da, 0xcf91e9db,
+    0xcf92e9dc, 0xcf93e9dd, 0xcf94e9de, 0xcf95e9df, 0xcf96e9e0, 0xcf97e9e1, 0xcf98e9e2, 0xcf99e9e3,
+    0xcf9ae9e4, 0xcf9be9e5, 0xcf9ce9e6, 0xcf9de9e7, 0xcf9ee9e8, 0xcf9fe9e9, 0xcfa0e9ea, 0xcfa1e9eb,
+    0xcfa2e9ec, 0xcfa3e9ed, 0xcfa4e9ee, 0xcfa5e9ef, 0xcfa6e9f0, 0xcfa7e9f1, 0xcfa8e9f2, 0xcfa9e9f3,
+    0xcfaae9f4, 0xcfabe9f5, 0xcface9f6, 0xcfade9f7, 0xcfaee9f8, 0xcfafe9f9, 0xcfb0e9fa, 0xcfb1e9fb,
+    0xcfb2e9fc, 0xcfb3e9fd, 0xcfb4e9fe, 0xcfb5e9ff, 0xcfb6ea00, 0xcfb7ea01, 0xcfb8ea02, 0xcfb9ea03,
+    0xcfbaea04, 0xcfbbea05, 0xcfbcea06, 0xcfbdea07, 0xcfbeea08, 0xcfbfea09, 0xcfc0ea0a, 0xcfc1ea0b,
+    0xcfc2ea0c, 0xcfc3ea0d, 0xcfc4ea0e, 0xcfc5ea0f, 0xcfc6ea10, 0xcfc7ea11, 0xcfc8ea12, 0xcfc9ea13,
+    0xcfcaea14, 0xcfcbea15, 0xcfccea16, 0xcfcdea17, 0xcfceea18, 0xcfcfea19, 0xcfd0ea1a, 0xcfd1ea1b,
+    0xcfd2ea1c, 0xcfd3ea1d, 0xcfd4ea1e, 0xcfd5ea1f, 0xcfd6ea20, 0xcfd7ea21, 0xcfd8ea22, 0xcfd9ea23,
+    0xcfdaea24, 0xcfdbea25, 0xcfdcea26, 0xcfddea27, 0xcfdeea28, 0xcfdfea29, 0xcfe0ea2a, 0xcfe1ea2b,
+    0xcfe2ea2c, 0xcfe3ea2d, 0xcfe4ea2e, 0xcfe5ea2f, 0xcfe6ea30, 0xcfe7ea31, 0xcfe8ea32, 0xcfe9ea33,
+    0xcfeaea34, 0xcfebea35, 0xcfecea36, 0xcfedea37, 0xcfeeea38, 0xcfefea39, 0xcff0ea3a, 0xcff1ea3b,
+    0xcff2ea3c, 0xcff3ea3d, 0xcff4ea3e, 0xcff5ea3f, 0xcff6ea40, 0xcff7ea41, 0xcff8ea42, 0xcff9ea43,
+    0xcffaea44, 0xcffbea45, 0xcffcea46, 0xcffdea47, 0xd041ea48, 0xd042ea49, 0xd043ea4a, 0xd044ea4b,
+    0xd045ea4c, 0xd046ea4d, 0xd047ea4e, 0xd048ea4f, 0xd049ea50, 0xd04aea51, 0xd04bea52, 0xd04cea53,
+    0xd04dea54, 0xd04eea55, 0xd04fea56, 0xd050ea57, 0xd051ea58, 0xd052ea59, 0xd053ea5a, 0xd054ea5b,
+    0xd055ea5c, 0xd056ea5d, 0xd057ea5e, 0xd058ea5f, 0xd059ea60, 0xd05aea61, 0xd05bea62, 0xd05cea63,
+    0xd05dea64, 0xd05eea65, 0xd05fea66, 0xd060ea67, 0xd061ea68, 0xd062ea69, 0xd063ea6a, 0xd064ea6b,
+    0xd065ea6c, 0xd066ea6d, 0xd067ea6e, 0xd068ea6f, 0xd069ea70, 0xd06aea71, 0xd06bea72, 0xd06cea73,
+    0xd06dea74, 0xd06eea75, 0xd06fea76, 0xd070ea77, 0xd071ea78, 0xd072ea79, 0xd073ea7a, 0xd074ea7b,
+    0xd075ea7c, 0xd076ea7d, 0xd077ea7e, 0xd078ea7f, 0xd079ea80, 0xd07aea81, 0xd07bea82, 0xd07cea83,
+    0xd07dea84, 0xd07eea85, 0xd07fea86, 0xd081ea87, 0xd082ea88, 0xd083ea89, 0xd084ea8a, 0xd085ea8b,
+    0xd086ea8c, 0xd087ea8d, 0xd088ea8e, 0xd089ea8f, 0xd08aea90, 0xd08bea91, 0xd08cea92, 0xd08dea93,
+    0xd08eea94, 0xd08fea95, 0xd090ea96, 0xd091ea97, 0xd092ea98, 0xd093ea99, 0xd094ea9a, 0xd095ea9b,
+    0xd096ea9c, 0xd097ea9d, 0xd098ea9e, 0xd099ea9f, 0xd09aeaa0, 0xd09beaa1, 0xd09ceaa2, 0xd09deaa3,
+    0xd09eeaa4, 0xd09feaa5, 0xd0a0eaa6, 0xd0a1eaa7, 0xd0a2eaa8, 0xd0a3eaa9, 0xd0a4eaaa, 0xd0a5eaab,
+    0xd0a6eaac, 0xd0a7eaad, 0xd0a8eaae, 0xd0a9eaaf, 0xd0aaeab0, 0xd0abeab1, 0xd0aceab2, 0xd0adeab3,
+    0xd0aeeab4, 0xd0afeab5, 0xd0b0eab6, 0xd0b1eab7, 0xd0b2eab8, 0xd0b3eab9, 0xd0b4eaba, 0xd0b5eabb,
+    0xd0b6eabc, 0xd0b7eabd, 0xd0b8eabe, 0xd0b9eabf, 0xd0baeac0, 0xd0bbeac1, 0xd0bceac2, 0xd0bdeac3,
+    0xd0beeac4, 0xd0bfeac5, 0xd0c0eac6, 0xd0c1eac7, 0xd0c2eac8, 0xd0c3eac9, 0xd0c4eaca, 0xd0c5eacb,
+    0xd0c6eacc, 0xd0c7eacd, 0xd0c8eace, 0xd0c9eacf, 0xd0caead0, 0xd0cbead1, 0xd0ccead2, 0xd0cdead3,
+    0xd0ceead4, 0xd0cfead5, 0xd0d0ead6, 0xd0d1ead7, 0xd0d2ead8, 0xd0d3ead9, 0xd0d4eada, 0xd0d5eadb,
+    0xd0d6eadc, 0xd0d7eadd, 0xd0d8eade, 0xd0d9eadf, 0xd0daeae0, 0xd0dbeae1, 0xd0dceae2, 0xd0ddeae3,
+    0xd0deeae4, 0xd0dfeae5, 0xd0e0eae6, 0xd0e1eae7, 0xd0e2eae8, 0xd0e3eae9, 0xd0e4eaea, 0xd0e5eaeb,
+    0xd0e6eaec, 0xd0e7eaed, 0xd0e8eaee, 0xd0e9eaef, 0xd0eaeaf0, 0xd0ebeaf1, 0xd0eceaf2, 0xd0edeaf3,
+    0xd0eeeaf4, 0xd0efeaf5, 0xd0f0eaf6, 0xd0f1eaf7, 0xd0f2eaf8, 0xd0f3eaf9, 0xd0f4eafa, 0xd0f5eafb,
+    0xd0f6eafc, 0xd0f7eafd, 0xd0f8eafe, 0xd0f9eaff, 0xd0faeb00, 0xd0fbeb01, 0xd0fceb02, 0xd0fdeb03,
+    0xd141eb04, 0xd142eb05, 0xd143eb06, 0xd144eb07, 0xd145eb08, 0xd146eb09, 0xd147eb0a, 0xd148eb0b,
+    0xd149eb0c, 0xd14aeb0d, 0xd14beb0e, 0xd14ceb0f, 0xd14deb10, 0xd14eeb11, 0xd14feb12, 0xd150eb13,
+    0xd151eb14, 0xd152eb15, 0xd153eb16, 0xd154eb17, 0xd155eb18, 0xd156eb19, 0xd157eb1a, 0xd158eb1b,
+    0xd159eb1c, 0xd15aeb1d, 0xd15beb1e, 0xd15ceb1f, 0xd15deb20, 0xd15eeb21, 0xd15feb22, 0xd160eb23,
+    0xd161eb24, 0xd162eb25, 0xd163eb26, 0xd164eb27, 0xd165eb28, 0xd166eb29, 0xd167eb2a, 0xd168eb2b,
+    0xd169eb2c, 0xd16aeb2d, 0xd16beb2e, 0xd16ceb2f, 0xd16deb30, 0xd16eeb31, 0xd16feb32, 0xd170eb33,
+    0xd171eb34, 0xd172eb35, 0xd173eb36, 0xd174eb37, 0xd175eb38, 0xd176eb39, 0xd177eb3a, 0xd178eb3b,
+    0xd179eb3c, 0xd17aeb3d, 0xd17beb3e, 0xd17ceb3f, 0xd17deb40, 0xd17eeb41, 0xd17feb42, 0xd181eb43,
+    0xd182eb44, 0xd183eb45, 0xd184eb46, 0xd185eb47, 0xd186eb48, 0xd187eb49, 0xd188eb4a, 0xd189eb4b,
+    0xd18aeb4c, 0xd18beb4d, 0xd18ceb4e, 0xd18deb4f, 0xd18eeb50, 0xd18feb51, 0xd190eb52, 0xd191eb53,
+    0xd192eb54, 0xd193eb55, 0xd194eb56, 0xd195eb57, 0xd196eb58, 0xd197eb59, 0xd198eb5a, 0xd199eb5b,
+    0xd19aeb5c, 0xd19beb5d, 0xd19ceb5e, 0xd19deb5f, 0xd19eeb60, 0xd19feb61, 0xd1a0eb62, 0xd1a1eb63,
+    0xd1a2eb64, 0xd1a3eb65, 0xd1a4eb66, 0xd1a5eb67, 0xd1a6eb68, 0xd1a7eb69, 0xd1a8eb6a, 0xd1a9eb6b,
+    0xd1aaeb6c, 0xd1abeb6d, 0xd1aceb6e, 0xd1adeb6f, 0xd1aeeb70, 0xd1afeb71, 0xd1b0eb72, 0xd1b1eb73,
+    0xd1b2eb74, 0xd1b3eb75, 0xd1b4eb76, 0xd1b5eb77, 0xd1b6eb78, 0xd1b7eb79, 0xd1b8eb7a, 0xd1b9eb7b,
+    0xd1baeb7c, 0xd1bbeb7d, 0xd1bceb7e, 0xd1bdeb7f, 0xd1beeb80, 0xd1bfeb81, 0xd1c0eb82, 0xd1c1eb83,
+    0xd1c2eb84, 0xd1c3eb85, 0xd1c4eb86, 0xd1c5eb87, 0xd1c6eb88, 0xd1c7eb89, 0xd1c8eb8a, 0xd1c9eb8b,
+    0xd1caeb8c, 0xd1cbeb8d, 0xd1cceb8e, 0xd1cdeb8f, 0xd1ceeb90, 0xd1cfeb91, 0xd1d0eb92, 0xd1d1eb93,
+    0xd1d2eb94, 0xd1d3eb95, 0xd1d4eb96, 0xd1d5eb97, 0xd1d6eb98, 0xd1d7eb99, 0xd1d8eb9a, 0xd1d9eb9b,
+    0xd1daeb9c, 0xd1dbeb9d, 0xd1dceb9e, 0xd1ddeb9f, 0xd1deeba0, 0xd1dfeba1, 0xd1e0eba2, 0xd1e1eba3,
+    0xd1e2eba4, 0xd1e3eba5, 0xd1e4eba6, 0xd1e5eba7, 0xd1e6eba8, 0xd1e7eba9, 0xd1e8ebaa, 0xd1e9ebab,
+    0xd1eaebac, 0xd1ebebad, 0xd1ecebae, 0xd1edebaf, 0xd1eeebb0, 0xd1efebb1, 0xd1f0ebb2, 0xd1f1ebb3,
+    0xd1f2ebb4, 0xd1f3ebb5, 0xd1f4ebb6, 0xd1f5ebb7, 0xd1f6ebb8, 0xd1f7ebb9, 0xd1f8ebba, 0xd1f9ebbb,
+    0xd1faebbc, 0xd1fbebbd, 0xd1fcebbe, 0xd1fdebbf, 0xd241ebc0, 0xd242ebc1, 0xd243ebc2, 0xd244ebc3,
+    0xd245ebc4, 0xd246ebc5, 0xd247ebc6, 0xd248ebc7, 0xd249ebc8, 0xd24aebc9, 0xd24bebca, 0xd24cebcb,
+    0xd24debcc, 0xd24eebcd, 0xd24febce, 0xd250ebcf, 0xd251ebd0, 0xd252ebd1, 0xd253ebd2, 0xd254ebd3,
+    0xd255ebd4, 0xd256ebd5, 0xd257ebd6, 0xd258ebd7, 0xd259ebd8, 0xd25aebd9, 0xd25bebda, 0xd25cebdb,
+    0xd25debdc, 0xd25eebdd, 0xd25febde, 0xd260ebdf, 0xd261ebe0, 0xd262ebe1, 0xd263ebe2, 0xd264ebe3,
+    0xd265ebe4, 0xd266ebe5, 0xd267ebe6, 0xd268ebe7, 0xd269ebe8, 0xd26aebe9, 0xd26bebea, 0xd26cebeb,
+    0xd26debec, 0xd26eebed, 0xd26febee, 0xd270ebef, 0xd271ebf0, 0xd272ebf1, 0xd273ebf2, 0xd274ebf3,
+    0xd275ebf4, 0xd276ebf5, 0xd277ebf6, 0xd278ebf7, 0xd279ebf8, 0xd27aebf9, 0xd27bebfa, 0xd27cebfb,
+    0xd27debfc, 0xd27eebfd, 0xd27febfe, 0xd281ebff, 0xd282ec00, 0xd283ec01, 0xd284ec02, 0xd285ec03,
+    0xd286ec04, 0xd287ec05, 0xd288ec06, 0xd289ec07, 0xd28aec08, 0xd28bec09, 0xd28cec0a, 0xd28dec0b,
+    0xd28eec0c, 0xd28fec0d, 0xd290ec0e, 0xd291ec0f, 0xd292ec10, 0xd293ec11, 0xd294ec12, 0xd295ec13,
+    0xd296ec14, 0xd297ec15, 0xd298ec16, 0xd299ec17, 0xd29aec18, 0xd29bec19, 0xd29cec1a, 0xd29dec1b,
+    0xd29eec1c, 0xd29fec1d, 0xd2a0ec1e, 0xd2a1ec1f, 0xd2a2ec20, 0xd2a3ec21, 0xd2a4ec22, 0xd2a5ec23,
+    0xd2a6ec24, 0xd2a7ec25, 0xd2a8ec26, 0xd2a9ec27, 0xd2aaec28, 0xd2abec29, 0xd2acec2a, 0xd2adec2b,
+    0xd2aeec2c, 0xd2afec2d, 0xd2b0ec2e, 0xd2b1ec2f, 0xd2b2ec30, 0xd2b3ec31, 0xd2b4ec32, 0xd2b5ec33,
+    0xd2b6ec34, 0xd2b7ec35, 0xd2b8ec36, 0xd2b9ec37, 0xd2baec38, 0xd2bbec39, 0xd2bcec3a, 0xd2bdec3b,
+    0xd2beec3c, 0xd2bfec3d, 0xd2c0ec3e, 0xd2c1ec3f, 0xd2c2ec40, 0xd2c3ec41, 0xd2c4ec42, 0xd2c5ec43,
+    0xd2c6ec44, 0xd2c7ec45, 0xd2c8ec46, 0xd2c9ec47, 0xd2caec48, 0xd2cbec49, 0xd2ccec4a, 0xd2cdec4b,
+    0xd2ceec4c, 0xd2cfec4d, 0xd2d0ec4e, 0xd2d1ec4f, 0xd2d2ec50, 0xd2d3ec51, 0xd2d4ec52, 0xd2d5ec53,
+    0xd2d6ec54, 0xd2d7ec55, 0xd2d8ec56, 0xd2d9ec57, 0xd2daec58, 0xd2dbec59, 0xd2dcec5a, 0xd2ddec5b,
+    0xd2deec5c, 0xd2dfec5d, 0xd2e0ec5e, 0xd2e1ec5f, 0xd2e2ec60, 0xd2e3ec61, 0xd2e4ec62, 0xd2e5ec63,
+    0xd2e6ec64, 0xd2e7ec65, 0xd2e8ec66, 0xd2e9ec67, 0xd2eaec68, 0xd2ebec69, 0xd2ecec6a, 0xd2edec6b,
+    0xd2eeec6c, 0xd2efec6d, 0xd2f0ec6e, 0xd2f1ec6f, 0xd2f2ec70, 0xd2f3ec71, 0xd2f4ec72, 0xd2f5ec73,
+    0xd2f6ec74, 0xd2f7ec75, 0xd2f8ec76, 0xd2f9ec77, 0xd2faec78, 0xd2fbec79, 0xd2fcec7a, 0xd2fdec7b,
+    0xd341ec7c, 0xd342ec7d, 0xd343ec7e, 0xd344ec7f, 0xd345ec80, 0xd346ec81, 0xd347ec82, 0xd348ec83,
+    0xd349ec84, 0xd34aec85, 0xd34bec86, 0xd34cec87, 0xd34dec88, 0xd34eec89, 0xd34fec8a, 0xd350ec8b,
+    0xd351ec8c, 0xd352ec8d, 0xd353ec8e, 0xd354ec8f, 0xd355ec90, 0xd356ec91, 0xd357ec92, 0xd358ec93,
+    0xd359ec94, 0xd35aec95, 0xd35bec96, 0xd35cec97, 0xd35dec98, 0xd35eec99, 0xd35fec9a, 0xd360ec9b,
+    0xd361ec9c, 0xd362ec9d, 0xd363ec9e, 0xd364ec9f, 0xd365eca0, 0xd366eca1, 0xd367eca2, 0xd368eca3,
+    0xd369eca4, 0xd36aeca5, 0xd36beca6, 0xd36ceca7, 0xd36deca8, 0xd36eeca9, 0xd36fecaa, 0xd370ecab,
+    0xd371ecac, 0xd372ecad, 0xd373ecae, 0xd374ecaf, 0xd375ecb0, 0xd376ecb1, 0xd377ecb2, 0xd378ecb3,
+    0xd379ecb4, 0xd37aecb5, 0xd37becb6, 0xd37cecb7, 0xd37decb8, 0xd37eecb9, 0xd37fecba, 0xd381ecbb,
+    0xd382ecbc, 0xd383ecbd, 0xd384ecbe, 0xd385ecbf, 0xd386ecc0, 0xd387ecc1, 0xd388ecc2, 0xd389ecc3,
+    0xd38aecc4, 0xd38becc5, 0xd38cecc6, 0xd38decc7, 0xd38eecc8, 0xd38fecc9, 0xd390ecca, 0xd391eccb,
+    0xd392eccc, 0xd393eccd, 0xd394ecce, 0xd395eccf, 0xd396ecd0, 0xd397ecd1, 0xd398ecd2, 0xd399ecd3,
+    0xd39aecd4, 0xd39becd5, 0xd39cecd6, 0xd39decd7, 0xd39eecd8, 0xd39fecd9, 0xd3a0ecda, 0xd3a1ecdb,
+    0xd3a2ecdc, 0xd3a3ecdd, 0xd3a4ecde, 0xd3a5ecdf, 0xd3a6ece0, 0xd3a7ece1, 0xd3a8ece2, 0xd3a9ece3,
+    0xd3aaece4, 0xd3abece5, 0xd3acece6, 0xd3adece7, 0xd3aeece8, 0xd3afece9, 0xd3b0ecea, 0xd3b1eceb,
+    0xd3b2ecec, 0xd3b3eced, 0xd3b4ecee, 0xd3b5ecef, 0xd3b6ecf0, 0xd3b7ecf1, 0xd3b8ecf2, 0xd3b9ecf3,
+    0xd3baecf4, 0xd3bbecf5, 0xd3bcecf6, 0xd3bdecf7, 0xd3beecf8, 0xd3bfecf9, 0xd3c0ecfa, 0xd3c1ecfb,
+    0xd3c2ecfc, 0xd3c3ecfd, 0xd3c4ecfe, 0xd3c5ecff, 0xd3c6ed00, 0xd3c7ed01, 0xd3c8ed02, 0xd3c9ed03,
+    0xd3caed04, 0xd3cbed05, 0xd3cced06, 0xd3cded07, 0xd3ceed08, 0xd3cfed09, 0xd3d0ed0a, 0xd3d1ed0b,
+    0xd3d2ed0c, 0xd3d3ed0d, 0xd3d4ed0e, 0xd3d5ed0f, 0xd3d6ed10, 0xd3d7ed11, 0xd3d8ed12, 0xd3d9ed13,
+    0xd3daed14, 0xd3dbed15, 0xd3dced16, 0xd3dded17, 0xd3deed18, 0xd3dfed19, 0xd3e0ed1a, 0xd3e1ed1b,
+    0xd3e2ed1c, 0xd3e3ed1d, 0xd3e4ed1e, 0xd3e5ed1f, 0xd3e6ed20, 0xd3e7ed21, 0xd3e8ed22, 0xd3e9ed23,
+    0xd3eaed24, 0xd3ebed25, 0xd3eced26, 0xd3eded27, 0xd3eeed28, 0xd3efed29, 0xd3f0ed2a, 0xd3f1ed2b,
+    0xd3f2ed2c, 0xd3f3ed2d, 0xd3f4ed2e, 0xd3f5ed2f, 0xd3f6ed30, 0xd3f7ed31, 0xd3f8ed32, 0xd3f9ed33,
+    0xd3faed34, 0xd3fbed35, 0xd3fced36, 0xd3fded37, 0xd441ed38, 0xd442ed39, 0xd443ed3a, 0xd444ed3b,
+    0xd445ed3c, 0xd446ed3d, 0xd447ed3e, 0xd448ed3f, 0xd449ed40, 0xd44aed41, 0xd44bed42, 0xd44ced43,
+    0xd44ded44, 0xd44eed45, 0xd44fed46, 0xd450ed47, 0xd451ed48, 0xd452ed49, 0xd453ed4a, 0xd454ed4b,
+    0xd455ed4c, 0xd456ed4d, 0xd457ed4e, 0xd458ed4f, 0xd459ed50, 0xd45aed51, 0xd45bed52, 0xd45ced53,
+    0xd45ded54, 0xd45eed55, 0xd45fed56, 0xd460ed57, 0xd461ed58, 0xd462ed59, 0xd463ed5a, 0xd464ed5b,
+    0xd465ed5c, 0xd466ed5d, 0xd467ed5e, 0xd468ed5f, 0xd469ed60, 0xd46aed61, 0xd46bed62, 0xd46ced63,
+    0xd46ded64, 0xd46eed65, 0xd46fed66, 0xd470ed67, 0xd471ed68, 0xd472ed69, 0xd473ed6a, 0xd474ed6b,
+    0xd475ed6c, 0xd476ed6d, 0xd477ed6e, 0xd478ed6f, 0xd479ed70, 0xd47aed71, 0xd47bed72, 0xd47ced73,
+    0xd47ded74, 0xd47eed75, 0xd47fed76, 0xd481ed77, 0xd482ed78, 0xd483ed79, 0xd484ed7a, 0xd485ed7b,
+    0xd486ed7c, 0xd487ed7d, 0xd488ed7e, 0xd489ed7f, 0xd48aed80, 0xd48bed81, 0xd48ced82, 0xd48ded83,
+    0xd48eed84, 0xd48fed85, 0xd490ed86, 0xd491ed87, 0xd492ed88, 0xd493ed89, 0xd494ed8a, 0xd495ed8b,
+    0xd496ed8c, 0xd497ed8d, 0xd498ed8e, 0xd499ed8f, 0xd49aed90, 0xd49bed91, 0xd49ced92, 0xd49ded93,
+    0xd49eed94, 0xd49fed95, 0xd4a0ed96, 0xd4a1ed97, 0xd4a2ed98, 0xd4a3ed99, 0xd4a4ed9a, 0xd4a5ed9b,
+    0xd4a6ed9c, 0xd4a7ed9d, 0xd4a8ed9e, 0xd4a9ed9f, 0xd4aaeda0, 0xd4abeda1, 0xd4aceda2, 0xd4adeda3,
+    0xd4aeeda4, 0xd4afeda5, 0xd4b0eda6, 0xd4b1eda7, 0xd4b2eda8, 0xd4b3eda9, 0xd4b4edaa, 0xd4b5edab,
+    0xd4b6edac, 0xd4b7edad, 0xd4b8edae, 0xd4b9edaf, 0xd4baedb0, 0xd4bbedb1, 0xd4bcedb2, 0xd4bdedb3,
+    0xd4beedb4, 0xd4bfedb5, 0xd4c0edb6, 0xd4c1edb7, 0xd4c2edb8, 0xd4c3edb9, 0xd4c4edba, 0xd4c5edbb,
+    0xd4c6edbc, 0xd4c7edbd, 0xd4c8edbe, 0xd4c9edbf, 0xd4caedc0, 0xd4cbedc1, 0xd4ccedc2, 0xd4cdedc3,
+    0xd4ceedc4, 0xd4cfedc5, 0xd4d0edc6, 0xd4d1edc7, 0xd4d2edc8, 0xd4d3edc9, 0xd4d4edca, 0xd4d5edcb,
+    0xd4d6edcc, 0xd4d7edcd, 0xd4d8edce, 0xd4d9edcf, 0xd4daedd0, 0xd4dbedd1, 0xd4dcedd2, 0xd4ddedd3,
+    0xd4deedd4, 0xd4dfedd5, 0xd4e0edd6, 0xd4e1edd7, 0xd4e2edd8, 0xd4e3edd9, 0xd4e4edda, 0xd4e5eddb,
+    0xd4e6eddc, 0xd4e7eddd, 0xd4e8edde, 0xd4e9eddf, 0xd4eaede0, 0xd4ebede1, 0xd4ecede2, 0xd4edede3,
+    0xd4eeede4, 0xd4efede5, 0xd4f0ede6, 0xd4f1ede7, 0xd4f2ede8, 0xd4f3ede9, 0xd4f4edea, 0xd4f5edeb,
+    0xd4f6edec, 0xd4f7eded, 0xd4f8edee, 0xd4f9edef, 0xd4faedf0, 0xd4fbedf1, 0xd4fcedf2, 0xd4fdedf3,
+    0xd541edf4, 0xd542edf5, 0xd543edf6, 0xd544edf7, 0xd545edf8, 0xd546edf9, 0xd547edfa, 0xd548edfb,
+    0xd549edfc, 0xd54aedfd, 0xd54bedfe, 0xd54cedff, 0xd54dee00, 0xd54eee01, 0xd54fee02, 0xd550ee03,
+    0xd551ee04, 0xd552ee05, 0xd553ee06, 0xd554ee07, 0xd555ee08, 0xd556ee09, 0xd557ee0a, 0xd558ee0b,
+    0xd559ee0c, 0xd55aee0d, 0xd55bee0e, 0xd55cee0f, 0xd55dee10, 0xd55eee11, 0xd55fee12, 0xd560ee13,
+    0xd561ee14, 0xd562ee15, 0xd563ee16, 0xd564ee17, 0xd565ee18, 0xd566ee19, 0xd567ee1a, 0xd568ee1b,
+    0xd569ee1c, 0xd56aee1d, 0xd56bee1e, 0xd56cee1f, 0xd56dee20, 0xd56eee21, 0xd56fee22, 0xd570ee23,
+    0xd571ee24, 0xd572ee25, 0xd573ee26, 0xd574ee27, 0xd575ee28, 0xd576ee29, 0xd577ee2a, 0xd578ee2b,
+    0xd579ee2c, 0xd57aee2d, 0xd57bee2e, 0xd57cee2f, 0xd57dee30, 0xd57eee31, 0xd57fee32, 0xd581ee33,
+    0xd582ee34, 0xd583ee35, 0xd584ee36, 0xd585ee37, 0xd586ee38, 0xd587ee39, 0xd588ee3a, 0xd589ee3b,
+    0xd58aee3c, 0xd58bee3d, 0xd58cee3e, 0xd58dee3f, 0xd58eee40, 0xd58fee41, 0xd590ee42, 0xd591ee43,
+    0xd592ee44, 0xd593ee45, 0xd594ee46, 0xd595ee47, 0xd596ee48, 0xd597ee49, 0xd598ee4a, 0xd599ee4b,
+    0xd59aee4c, 0xd59bee4d, 0xd59cee4e, 0xd59dee4f, 0xd59eee50, 0xd59fee51, 0xd5a0ee52, 0xd5a1ee53,
+    0xd5a2ee54, 0xd5a3ee55, 0xd5a4ee56, 0xd5a5ee57, 0xd5a6ee58, 0xd5a7ee59, 0xd5a8ee5a, 0xd5a9ee5b,
+    0xd5aaee5c, 0xd5abee5d, 0xd5acee5e, 0xd5adee5f, 0xd5aeee60, 0xd5afee61, 0xd5b0ee62, 0xd5b1ee63,
+    0xd5b2ee64, 0xd5b3ee65, 0xd5b4ee66, 0xd5b5ee67, 0xd5b6ee68, 0xd5b7ee69, 0xd5b8ee6a, 0xd5b9ee6b,
+    0xd5baee6c, 0xd5bbee6d, 0xd5bcee6e, 0xd5bdee6f, 0xd5beee70, 0xd5bfee71, 0xd5c0ee72, 0xd5c1ee73,
+    0xd5c2ee74, 0xd5c3ee75, 0xd5c4ee76, 0xd5c5ee77, 0xd5c6ee78, 0xd5c7ee79, 0xd5c8ee7a, 0xd5c9ee7b,
+    0xd5caee7c, 0xd5cbee7d, 0xd5ccee7e, 0xd5cdee7f, 0xd5ceee80, 0xd5cfee81, 0xd5d0ee82, 0xd5d1ee83,
+    0xd5d2ee84, 0xd5d3ee85, 0xd5d4ee86, 0xd5d5ee87, 0xd5d6ee88, 0xd5d7ee89, 0xd5d8ee8a, 0xd5d9ee8b,
+    0xd5daee8c, 0xd5dbee8d, 0xd5dcee8e, 0xd5ddee8f, 0xd5deee90, 0xd5dfee91, 0xd5e0ee92, 0xd5e1ee93,
+    0xd5e2ee94, 0xd5e3ee95, 0xd5e4ee96, 0xd5e5ee97, 0xd5e6ee98, 0xd5e7ee99, 0xd5e8ee9a, 0xd5e9ee9b,
+    0xd5eaee9c, 0xd5ebee9d, 0xd5ecee9e, 0xd5edee9f, 0xd5eeeea0, 0xd5efeea1, 0xd5f0eea2, 0xd5f1eea3,
+    0xd5f2eea4, 0xd5f3eea5, 0xd5f4eea6, 0xd5f5eea7, 0xd5f6eea8, 0xd5f7eea9, 0xd5f8eeaa, 0xd5f9eeab,
+    0xd5faeeac, 0xd5fbeead, 0xd5fceeae, 0xd5fdeeaf, 0xd641eeb0, 0xd642eeb1, 0xd643eeb2, 0xd644eeb3,
+    0xd645eeb4, 0xd646eeb5, 0xd647eeb6, 0xd648eeb7, 0xd649eeb8, 0xd64aeeb9, 0xd64beeba, 0xd64ceebb,
+    0xd64deebc, 0xd64eeebd, 0xd64feebe, 0xd650eebf, 0xd651eec0, 0xd652eec1, 0xd653eec2, 0xd654eec3,
+    0xd655eec4, 0xd656eec5, 0xd657eec6, 0xd658eec7, 0xd659eec8, 0xd65aeec9, 0xd65beeca, 0xd65ceecb,
+    0xd65deecc, 0xd65eeecd, 0xd65feece, 0xd660eecf, 0xd661eed0, 0xd662eed1, 0xd663eed2, 0xd664eed3,
+    0xd665eed4, 0xd666eed5, 0xd667eed6, 0xd668eed7, 0xd669eed8, 0xd66aeed9, 0xd66beeda, 0xd66ceedb,
+    0xd66deedc, 0xd66eeedd, 0xd66feede, 0xd670eedf, 0xd671eee0, 0xd672eee1, 0xd673eee2, 0xd674eee3,
+    0xd675eee4, 0xd676eee5, 0xd677eee6, 0xd678eee7, 0xd679eee8, 0xd67aeee9, 0xd67beeea, 0xd67ceeeb,
+    0xd67deeec, 0xd67eeeed, 0xd67feeee, 0xd681eeef, 0xd682eef0, 0xd683eef1, 0xd684eef2, 0xd685eef3,
+    0xd686eef4, 0xd687eef5, 0xd688eef6, 0xd689eef7, 0xd68aeef8, 0xd68beef9, 0xd68ceefa, 0xd68deefb,
+    0xd68eeefc, 0xd68feefd, 0xd690eefe, 0xd691eeff, 0xd692ef00, 0xd693ef01, 0xd694ef02, 0xd695ef03,
+    0xd696ef04, 0xd697ef05, 0xd698ef06, 0xd699ef07, 0xd69aef08, 0xd69bef09, 0xd69cef0a, 0xd69def0b,
+    0xd69eef0c, 0xd69fef0d, 0xd6a0ef0e, 0xd6a1ef0f, 0xd6a2ef10, 0xd6a3ef11, 0xd6a4ef12, 0xd6a5ef13,
+    0xd6a6ef14, 0xd6a7ef15, 0xd6a8ef16, 0xd6a9ef17, 0xd6aaef18, 0xd6abef19, 0xd6acef1a, 0xd6adef1b,
+    0xd6aeef1c, 0xd6afef1d, 0xd6b0ef1e, 0xd6b1ef1f, 0xd6b2ef20, 0xd6b3ef21, 0xd6b4ef22, 0xd6b5ef23,
+    0xd6b6ef24, 0xd6b7ef25, 0xd6b8ef26, 0xd6b9ef27, 0xd6baef28, 0xd6bbef29, 0xd6bcef2a, 0xd6bdef2b,
+    0xd6beef2c, 0xd6bfef2d, 0xd6c0ef2e, 0xd6c1ef2f, 0xd6c2ef30, 0xd6c3ef31, 0xd6c4ef32, 0xd6c5ef33,
+    0xd6c6ef34, 0xd6c7ef35, 0xd6c8ef36, 0xd6c9ef37, 0xd6caef38, 0xd6cbef39, 0xd6ccef3a, 0xd6cdef3b,
+    0xd6ceef3c, 0xd6cfef3d, 0xd6d0ef3e, 0xd6d1ef3f, 0xd6d2ef40, 0xd6d3ef41, 0xd6d4ef42, 0xd6d5ef43,
+    0xd6d6ef44, 0xd6d7ef45, 0xd6d8ef46, 0xd6d9ef47, 0xd6daef48, 0xd6dbef49, 0xd6dcef4a, 0xd6ddef4b,
+    0xd6deef4c, 0xd6dfef4d, 0xd6e0ef4e, 0xd6e1ef4f, 0xd6e2ef50, 0xd6e3ef51, 0xd6e4ef52, 0xd6e5ef53,
+    0xd6e6ef54, 0xd6e7ef55, 0xd6e8ef56, 0xd6e9ef57, 0xd6eaef58, 0xd6ebef59, 0xd6ecef5a, 0xd6edef5b,
+    0xd6eeef5c, 0xd6efef5d, 0xd6f0ef5e, 0xd6f1ef5f, 0xd6f2ef60, 0xd6f3ef61, 0xd6f4ef62, 0xd6f5ef63,
+    0xd6f6ef64, 0xd6f7ef65, 0xd6f8ef66, 0xd6f9ef67, 0xd6faef68, 0xd6fbef69, 0xd6fcef6a, 0xd6fdef6b,
+    0xd741ef6c, 0xd742ef6d, 0xd743ef6e, 0xd744ef6f, 0xd745ef70, 0xd746ef71, 0xd747ef72, 0xd748ef73,
+    0xd749ef74, 0xd74aef75, 0xd74bef76, 0xd74cef77, 0xd74def78, 0xd74eef79, 0xd74fef7a, 0xd750ef7b,
+    0xd751ef7c, 0xd752ef7d, 0xd753ef7e, 0xd754ef7f, 0xd755ef80, 0xd756ef81, 0xd757ef82, 0xd758ef83,
+    0xd759ef84, 0xd75aef85, 0xd75bef86, 0xd75cef87, 0xd75def88, 0xd75eef89, 0xd75fef8a, 0xd760ef8b,
+    0xd761ef8c, 0xd762ef8d, 0xd763ef8e, 0xd764ef8f, 0xd765ef90, 0xd766ef91, 0xd767ef92, 0xd768ef93,
+    0xd769ef94, 0xd76aef95, 0xd76bef96, 0xd76cef97, 0xd76def98, 0xd76eef99, 0xd76fef9a, 0xd770ef9b,
+    0xd771ef9c, 0xd772ef9d, 0xd773ef9e, 0xd774ef9f, 0xd775efa0, 0xd776efa1, 0xd777efa2, 0xd778efa3,
+    0xd779efa4, 0xd77aefa5, 0xd77befa6, 0xd77cefa7, 0xd77defa8, 0xd77eefa9, 0xd77fefaa, 0xd781efab,
+    0xd782efac, 0xd783efad, 0xd784efae, 0xd785efaf, 0xd786efb0, 0xd787efb1, 0xd788efb2, 0xd789efb3,
+    0xd78aefb4, 0xd78befb5, 0xd78cefb6, 0xd78defb7, 0xd78eefb8, 0xd78fefb9, 0xd790efba, 0xd791efbb,
+    0xd792efbc, 0xd793efbd, 0xd794efbe, 0xd795efbf, 0xd796efc0, 0xd797efc1, 0xd798efc2, 0xd799efc3,
+    0xd79aefc4, 0xd79befc5, 0xd79cefc6, 0xd79defc7, 0xd79eefc8, 0xd79fefc9, 0xd7a0efca, 0xd7a1efcb,
+    0xd7a2efcc, 0xd7a3efcd, 0xd7a4efce, 0xd7a5efcf, 0xd7a6efd0, 0xd7a7efd1, 0xd7a8efd2, 0xd7a9efd3,
+    0xd7aaefd4, 0xd7abefd5, 0xd7acefd6, 0xd7adefd7, 0xd7aeefd8, 0xd7afefd9, 0xd7b0efda, 0xd7b1efdb,
+    0xd7b2efdc, 0xd7b3efdd, 0xd7b4efde, 0xd7b5efdf, 0xd7b6efe0, 0xd7b7efe1, 0xd7b8efe2, 0xd7b9efe3,
+    0xd7baefe4, 0xd7bbefe5, 0xd7bcefe6, 0xd7bdefe7, 0xd7beefe8, 0xd7bfefe9, 0xd7c0efea, 0xd7c1efeb,
+    0xd7c2efec, 0xd7c3efed, 0xd7c4efee, 0xd7c5efef, 0xd7c6eff0, 0xd7c7eff1, 0xd7c8eff2, 0xd7c9eff3,
+    0xd7caeff4, 0xd7cbeff5, 0xd7cceff6, 0xd7cdeff7, 0xd7ceeff8, 0xd7cfeff9, 0xd7d0effa, 0xd7d1effb,
+    0xd7d2effc, 0xd7d3effd, 0xd7d4effe, 0xd7d5efff, 0xd7d6f000, 0xd7d7f001, 0xd7d8f002, 0xd7d9f003,
+    0xd7daf004, 0xd7dbf005, 0xd7dcf006, 0xd7ddf007, 0xd7def008, 0xd7dff009, 0xd7e0f00a, 0xd7e1f00b,
+    0xd7e2f00c, 0xd7e3f00d, 0xd7e4f00e, 0xd7e5f00f, 0xd7e6f010, 0xd7e7f011, 0xd7e8f012, 0xd7e9f013,
+    0xd7eaf014, 0xd7ebf015, 0xd7ecf016, 0xd7edf017, 0xd7eef018, 0xd7eff019, 0xd7f0f01a, 0xd7f1f01b,
+    0xd7f2f01c, 0xd7f3f01d, 0xd7f4f01e, 0xd7f5f01f, 0xd7f6f020, 0xd7f7f021, 0xd7f8f022, 0xd7f9f023,
+    0xd7faf024, 0xd7fbf025, 0xd7fcf026, 0xd7fdf027, 0xd841f028, 0xd842f029, 0xd843f02a, 0xd844f02b,
+    0xd845f02c, 0xd846f02d, 0xd847f02e, 0xd848f02f, 0xd849f030, 0xd84af031, 0xd84bf032, 0xd84cf033,
+    0xd84df034, 0xd84ef035, 0xd84ff036, 0xd850f037, 0xd851f038, 0xd852f039, 0xd853f03a, 0xd854f03b,
+    0xd855f03c, 0xd856f03d, 0xd857f03e, 0xd858f03f, 0xd859f040, 0xd85af041, 0xd85bf042, 0xd85cf043,
+    0xd85df044, 0xd85ef045, 0xd85ff046, 0xd860f047, 0xd861f048, 0xd862f049, 0xd863f04a, 0xd864f04b,
+    0xd865f04c, 0xd866f04d, 0xd867f04e, 0xd868f04f, 0xd869f050, 0xd86af051, 0xd86bf052, 0xd86cf053,
+    0xd86df054, 0xd86ef055, 0xd86ff056, 0xd870f057, 0xd871f058, 0xd872f059, 0xd873f05a, 0xd874f05b,
+    0xd875f05c, 0xd876f05d, 0xd877f05e, 0xd878f05f, 0xd879f060, 0xd87af061, 0xd87bf062, 0xd87cf063,
+    0xd87df064, 0xd87ef065, 0xd87ff066, 0xd881f067, 0xd882f068, 0xd883f069, 0xd884f06a, 0xd885f06b,
+    0xd886f06c, 0xd887f06d, 0xd888f06e, 0xd889f06f, 0xd88af070, 0xd88bf071, 0xd88cf072, 0xd88df073,
+    0xd88ef074, 0xd88ff075, 0xd890f076, 0xd891f077, 0xd892f078, 0xd893f079, 0xd894f07a, 0xd895f07b,
+    0xd896f07c, 0xd897f07d, 0xd898f07e, 0xd899f07f, 0xd89af080, 0xd89bf081, 0xd89cf082, 0xd89df083,
+    0xd89ef084, 0xd89ff085, 0xd8a0f086, 0xd8a1f087, 0xd8a2f088, 0xd8a3f089, 0xd8a4f08a, 0xd8a5f08b,
+    0xd8a6f08c, 0xd8a7f08d, 0xd8a8f08e, 0xd8a9f08f, 0xd8aaf090, 0xd8abf091, 0xd8acf092, 0xd8adf093,
+    0xd8aef094, 0xd8aff095, 0xd8b0f096, 0xd8b1f097, 0xd8b2f098, 0xd8b3f099, 0xd8b4f09a, 0xd8b5f09b,
+    0xd8b6f09c, 0xd8b7f09d, 0xd8b8f09e, 0xd8b9f09f, 0xd8baf0a0, 0xd8bbf0a1, 0xd8bcf0a2, 0xd8bdf0a3,
+    0xd8bef0a4, 0xd8bff0a5, 0xd8c0f0a6, 0xd8c1f0a7, 0xd8c2f0a8, 0xd8c3f0a9, 0xd8c4f0aa, 0xd8c5f0ab,
+    0xd8c6f0ac, 0xd8c7f0ad, 0xd8c8f0ae, 0xd8c9f0af, 0xd8caf0b0, 0xd8cbf0b1, 0xd8ccf0b2, 0xd8cdf0b3,
+    0xd8cef0b4, 0xd8cff0b5, 0xd8d0f0b6, 0xd8d1f0b7, 0xd8d2f0b8, 0xd8d3f0b9, 0xd8d4f0ba, 0xd8d5f0bb,
+    0xd8d6f0bc, 0xd8d7f0bd, 0xd8d8f0be, 0xd8d9f0bf, 0xd8daf0c0, 0xd8dbf0c1, 0xd8dcf0c2, 0xd8ddf0c3,
+    0xd8def0c4, 0xd8dff0c5, 0xd8e0f0c6, 0xd8e1f0c7, 0xd8e2f0c8, 0xd8e3f0c9, 0xd8e4f0ca, 0xd8e5f0cb,
+    0xd8e6f0cc, 0xd8e7f0cd, 0xd8e8f0ce, 0xd8e9f0cf, 0xd8eaf0d0, 0xd8ebf0d1, 0xd8ecf0d2, 0xd8edf0d3,
+    0xd8eef0d4, 0xd8eff0d5, 0xd8f0f0d6, 0xd8f1f0d7, 0xd8f2f0d8, 0xd8f3f0d9, 0xd8f4f0da, 0xd8f5f0db,
+    0xd8f6f0dc, 0xd8f7f0dd, 0xd8f8f0de, 0xd8f9f0df, 0xd8faf0e0, 0xd8fbf0e1, 0xd8fcf0e2, 0xd8fdf0e3,
+    0xd941f0e4, 0xd942f0e5, 0xd943f0e6, 0xd944f0e7, 0xd945f0e8, 0xd946f0e9, 0xd947f0ea, 0xd948f0eb,
+    0xd949f0ec, 0xd94af0ed, 0xd94bf0ee, 0xd94cf0ef, 0xd94df0f0, 0xd94ef0f1, 0xd94ff0f2, 0xd950f0f3,
+    0xd951f0f4, 0xd952f0f5, 0xd953f0f6, 0xd954f0f7, 0xd955f0f8, 0xd956f0f9, 0xd957f0fa, 0xd958f0fb,
+    0xd959f0fc, 0xd95af0fd, 0xd95bf0fe, 0xd95cf0ff, 0xd95df100, 0xd95ef101, 0xd95ff102, 0xd960f103,
+    0xd961f104, 0xd962f105, 0xd963f106, 0xd964f107, 0xd965f108, 0xd966f109, 0xd967f10a, 0xd968f10b,
+    0xd969f10c, 0xd96af10d, 0xd96bf10e, 0xd96cf10f, 0xd96df110, 0xd96ef111, 0xd96ff112, 0xd970f113,
+    0xd971f114, 0xd972f115, 0xd973f116, 0xd974f117, 0xd975f118, 0xd976f119, 0xd977f11a, 0xd978f11b,
+    0xd979f11c, 0xd97af11d, 0xd97bf11e, 0xd97cf11f, 0xd97df120, 0xd97ef121, 0xd97ff122, 0xd981f123,
+    0xd982f124, 0xd983f125, 0xd984f126, 0xd985f127, 0xd986f128, 0xd987f129, 0xd988f12a, 0xd989f12b,
+    0xd98af12c, 0xd98bf12d, 0xd98cf12e, 0xd98df12f, 0xd98ef130, 0xd98ff131, 0xd990f132, 0xd991f133,
+    0xd992f134, 0xd993f135, 0xd994f136, 0xd995f137, 0xd996f138, 0xd997f139, 0xd998f13a, 0xd999f13b,
+    0xd99af13c, 0xd99bf13d, 0xd99cf13e, 0xd99df13f, 0xd99ef140, 0xd99ff141, 0xd9a0f142, 0xd9a1f143,
+    0xd9a2f144, 0xd9a3f145, 0xd9a4f146, 0xd9a5f147, 0xd9a6f148, 0xd9a7f149, 0xd9a8f14a, 0xd9a9f14b,
+    0xd9aaf14c, 0xd9abf14d, 0xd9acf14e, 0xd9adf14f, 0xd9aef150, 0xd9aff151, 0xd9b0f152, 0xd9b1f153,
+    0xd9b2f154, 0xd9b3f155, 0xd9b4f156, 0xd9b5f157, 0xd9b6f158, 0xd9b7f159, 0xd9b8f15a, 0xd9b9f15b,
+    0xd9baf15c, 0xd9bbf15d, 0xd9bcf15e, 0xd9bdf15f, 0xd9bef160, 0xd9bff161, 0xd9c0f162, 0xd9c1f163,
+    0xd9c2f164, 0xd9c3f165, 0xd9c4f166, 0xd9c5f167, 0xd9c6f168, 0xd9c7f169, 0xd9c8f16a, 0xd9c9f16b,
+    0xd9caf16c, 0xd9cbf16d, 0xd9ccf16e, 0xd9cdf16f, 0xd9cef170, 0xd9cff171, 0xd9d0f172, 0xd9d1f173,
+    0xd9d2f174, 0xd9d3f175, 0xd9d4f176, 0xd9d5f177, 0xd9d6f178, 0xd9d7f179, 0xd9d8f17a, 0xd9d9f17b,
+    0xd9daf17c, 0xd9dbf17d, 0xd9dcf17e, 0xd9ddf17f, 0xd9def180, 0xd9dff181, 0xd9e0f182, 0xd9e1f183,
+    0xd9e2f184, 0xd9e3f185, 0xd9e4f186, 0xd9e5f187, 0xd9e6f188, 0xd9e7f189, 0xd9e8f18a, 0xd9e9f18b,
+    0xd9eaf18c, 0xd9ebf18d, 0xd9ecf18e, 0xd9edf18f, 0xd9eef190, 0xd9eff191, 0xd9f0f192, 0xd9f1f193,
+    0xd9f2f194, 0xd9f3f195, 0xd9f4f196, 0xd9f5f197, 0xd9f6f198, 0xd9f7f199, 0xd9f8f19a, 0xd9f9f19b,
+    0xd9faf19c, 0xd9fbf19d, 0xd9fcf19e, 0xd9fdf19f, 0xda41f1a0, 0xda42f1a1, 0xda43f1a2, 0xda44f1a3,
+    0xda45f1a4, 0xda46f1a5, 0xda47f1a6, 0xda48f1a7, 0xda49f1a8, 0xda4af1a9, 0xda4bf1aa, 0xda4cf1ab,
+    0xda4df1ac, 0xda4ef1ad, 0xda4ff1ae, 0xda50f1af, 0xda51f1b0, 0xda52f1b1, 0xda53f1b2, 0xda54f1b3,
+    0xda55f1b4, 0xda56f1b5, 0xda57f1b6, 0xda58f1b7, 0xda59f1b8, 0xda5af1b9, 0xda5bf1ba, 0xda5cf1bb,
+    0xda5df1bc, 0xda5ef1bd, 0xda5ff1be, 0xda60f1bf, 0xda61f1c0, 0xda62f1c1, 0xda63f1c2, 0xda64f1c3,
+    0xda65f1c4, 0xda66f1c5, 0xda67f1c6, 0xda68f1c7, 0xda69f1c8, 0xda6af1c9, 0xda6bf1ca, 0xda6cf1cb,
+    0xda6df1cc, 0xda6ef1cd, 0xda6ff1ce, 0xda70f1cf, 0xda71f1d0, 0xda72f1d1, 0xda73f1d2, 0xda74f1d3,
+    0xda75f1d4, 0xda76f1d5, 0xda77f1d6, 0xda78f1d7, 0xda79f1d8, 0xda7af1d9, 0xda7bf1da, 0xda7cf1db,
+    0xda7df1dc, 0xda7ef1dd, 0xda7ff1de, 0xda81f1df, 0xda82f1e0, 0xda83f1e1, 0xda84f1e2, 0xda85f1e3,
+    0xda86f1e4, 0xda87f1e5, 0xda88f1e6, 0xda89f1e7, 0xda8af1e8, 0xda8bf1e9, 0xda8cf1ea, 0xda8df1eb,
+    0xda8ef1ec, 0xda8ff1ed, 0xda90f1ee, 0xda91f1ef, 0xda92f1f0, 0xda93f1f1, 0xda94f1f2, 0xda95f1f3,
+    0xda96f1f4, 0xda97f1f5, 0xda98f1f6, 0xda99f1f7, 0xda9af1f8, 0xda9bf1f9, 0xda9cf1fa, 0xda9df1fb,
+    0xda9ef1fc, 0xda9ff1fd, 0xdaa0f1fe, 0xdaa1f1ff, 0xdaa2f200, 0xdaa3f201, 0xdaa4f202, 0xdaa5f203,
+    0xdaa6f204, 0xdaa7f205, 0xdaa8f206, 0xdaa9f207, 0xdaaaf208, 0xdaabf209, 0xdaacf20a, 0xdaadf20b,
+    0xdaaef20c, 0xdaaff20d, 0xdab0f20e, 0xdab1f20f, 0xdab2f210, 0xdab3f211, 0xdab4f212, 0xdab5f213,
+    0xdab6f214, 0xdab7f215, 0xdab8f216, 0xdab9f217, 0xdabaf218, 0xdabbf219, 0xdabcf21a, 0xdabdf21b,
+    0xdabef21c, 0xdabff21d, 0xdac0f21e, 0xdac1f21f, 0xdac2f220, 0xdac3f221, 0xdac4f222, 0xdac5f223,
+    0xdac6f224, 0xdac7f225, 0xdac8f226, 0xdac9f227, 0xdacaf228, 0xdacbf229, 0xdaccf22a, 0xdacdf22b,
+    0xdacef22c, 0xdacff22d, 0xdad0f22e, 0xdad1f22f, 0xdad2f230, 0xdad3f231, 0xdad4f232, 0xdad5f233,
+    0xdad6f234, 0xdad7f235, 0xdad8f236, 0xdad9f237, 0xdadaf238, 0xdadbf239, 0xdadcf23a, 0xdaddf23b,
+    0xdadef23c, 0xdadff23d, 0xdae0f23e, 0xdae1f23f, 0xdae2f240, 0xdae3f241, 0xdae4f242, 0xdae5f243,
+    0xdae6f244, 0xdae7f245, 0xdae8f246, 0xdae9f247, 0xdaeaf248, 0xdaebf249, 0xdaecf24a, 0xdaedf24b,
+    0xdaeef24c, 0xdaeff24d, 0xdaf0f24e, 0xdaf1f24f, 0xdaf2f250, 0xdaf3f251, 0xdaf4f252, 0xdaf5f253,
+    0xdaf6f254, 0xdaf7f255, 0xdaf8f256, 0xdaf9f257, 0xdafaf258, 0xdafbf259, 0xdafcf25a, 0xdafdf25b,
+    0xdb41f25c, 0xdb42f25d, 0xdb43f25e, 0xdb44f25f, 0xdb45f260, 0xdb46f261, 0xdb47f262, 0xdb48f263,
+    0xdb49f264, 0xdb4af265, 0xdb4bf266, 0xdb4cf267, 0xdb4df268, 0xdb4ef269, 0xdb4ff26a, 0xdb50f26b,
+    0xdb51f26c, 0xdb52f26d, 0xdb53f26e, 0xdb54f26f, 0xdb55f270, 0xdb56f271, 0xdb57f272, 0xdb58f273,
+    0xdb59f274, 0xdb5af275, 0xdb5bf276, 0xdb5cf277, 0xdb5df278, 0xdb5ef279, 0xdb5ff27a, 0xdb60f27b,
+    0xdb61f27c, 0xdb62f27d, 0xdb63f27e, 0xdb64f27f, 0xdb65f280, 0xdb66f281, 0xdb67f282, 0xdb68f283,
+    0xdb69f284, 0xdb6af285, 0xdb6bf286, 0xdb6cf287, 0xdb6df288, 0xdb6ef289, 0xdb6ff28a, 0xdb70f28b,
+    0xdb71f28c, 0xdb72f28d, 0xdb73f28e, 0xdb74f28f, 0xdb75f290, 0xdb76f291, 0xdb77f292, 0xdb78f293,
+    0xdb79f294, 0xdb7af295, 0xdb7bf296, 0xdb7cf297, 0xdb7df298, 0xdb7ef299, 0xdb7ff29a, 0xdb81f29b,
+    0xdb82f29c, 0xdb83f29d, 0xdb84f29e, 0xdb85f29f, 0xdb86f2a0, 0xdb87f2a1, 0xdb88f2a2, 0xdb89f2a3,
+    0xdb8af2a4, 0xdb8bf2a5, 0xdb8cf2a6, 0xdb8df2a7, 0xdb8ef2a8, 0xdb8ff2a9, 0xdb90f2aa, 0xdb91f2ab,
+    0xdb92f2ac, 0xdb93f2ad, 0xdb94f2ae, 0xdb95f2af, 0xdb96f2b0, 0xdb97f2b1, 0xdb98f2b2, 0xdb99f2b3,
+    0xdb9af2b4, 0xdb9bf2b5, 0xdb9cf2b6, 0xdb9df2b7, 0xdb9ef2b8, 0xdb9ff2b9, 0xdba0f2ba, 0xdba1f2bb,
+    0xdba2f2bc, 0xdba3f2bd, 0xdba4f2be, 0xdba5f2bf, 0xdba6f2c0, 0xdba7f2c1, 0xdba8f2c2, 0xdba9f2c3,
+    0xdbaaf2c4, 0xdbabf2c5, 0xdbacf2c6, 0xdbadf2c7, 0xdbaef2c8, 0xdbaff2c9, 0xdbb0f2ca, 0xdbb1f2cb,
+    0xdbb2f2cc, 0xdbb3f2cd, 0xdbb4f2ce, 0xdbb5f2cf, 0xdbb6f2d0, 0xdbb7f2d1, 0xdbb8f2d2, 0xdbb9f2d3,
+    0xdbbaf2d4, 0xdbbbf2d5, 0xdbbcf2d6, 0xdbbdf2d7, 0xdbbef2d8, 0xdbbff2d9, 0xdbc0f2da, 0xdbc1f2db,
+    0xdbc2f2dc, 0xdbc3f2dd, 0xdbc4f2de, 0xdbc5f2df, 0xdbc6f2e0, 0xdbc7f2e1, 0xdbc8f2e2, 0xdbc9f2e3,
+    0xdbcaf2e4, 0xdbcbf2e5, 0xdbccf2e6, 0xdbcdf2e7, 0xdbcef2e8, 0xdbcff2e9, 0xdbd0f2ea, 0xdbd1f2eb,
+    0xdbd2f2ec, 0xdbd3f2ed, 0xdbd4f2ee, 0xdbd5f2ef, 0xdbd6f2f0, 0xdbd7f2f1, 0xdbd8f2f2, 0xdbd9f2f3,
+    0xdbdaf2f4, 0xdbdbf2f5, 0xdbdcf2f6, 0xdbddf2f7, 0xdbdef2f8, 0xdbdff2f9, 0xdbe0f2fa, 0xdbe1f2fb,
+    0xdbe2f2fc, 0xdbe3f2fd, 0xdbe4f2fe, 0xdbe5f2ff, 0xdbe6f300, 0xdbe7f301, 0xdbe8f302, 0xdbe9f303,
+    0xdbeaf304, 0xdbebf305, 0xdbecf306, 0xdbedf307, 0xdbeef308, 0xdbeff309, 0xdbf0f30a, 0xdbf1f30b,
+    0xdbf2f30c, 0xdbf3f30d, 0xdbf4f30e, 0xdbf5f30f, 0xdbf6f310, 0xdbf7f311, 0xdbf8f312, 0xdbf9f313,
+    0xdbfaf314, 0xdbfbf315, 0xdbfcf316, 0xdbfdf317, 0xdc41f318, 0xdc42f319, 0xdc43f31a, 0xdc44f31b,
+    0xdc45f31c, 0xdc46f31d, 0xdc47f31e, 0xdc48f31f, 0xdc49f320, 0xdc4af321, 0xdc4bf322, 0xdc4cf323,
+    0xdc4df324, 0xdc4ef325, 0xdc4ff326, 0xdc50f327, 0xdc51f328, 0xdc52f329, 0xdc53f32a, 0xdc54f32b,
+    0xdc55f32c, 0xdc56f32d, 0xdc57f32e, 0xdc58f32f, 0xdc59f330, 0xdc5af331, 0xdc5bf332, 0xdc5cf333,
+    0xdc5df334, 0xdc5ef335, 0xdc5ff336, 0xdc60f337, 0xdc61f338, 0xdc62f339, 0xdc63f33a, 0xdc64f33b,
+    0xdc65f33c, 0xdc66f33d, 0xdc67f33e, 0xdc68f33f, 0xdc69f340, 0xdc6af341, 0xdc6bf342, 0xdc6cf343,
+    0xdc6df344, 0xdc6ef345, 0xdc6ff346, 0xdc70f347, 0xdc71f348, 0xdc72f349, 0xdc73f34a, 0xdc74f34b,
+    0xdc75f34c, 0xdc76f34d, 0xdc77f34e, 0xdc78f34f, 0xdc79f350, 0xdc7af351, 0xdc7bf352, 0xdc7cf353,
+    0xdc7df354, 0xdc7ef355, 0xdc7ff356, 0xdc81f357, 0xdc82f358, 0xdc83f359, 0xdc84f35a, 0xdc85f35b,
+    0xdc86f35c, 0xdc87f35d, 0xdc88f35e, 0xdc89f35f, 0xdc8af360, 0xdc8bf361, 0xdc8cf362, 0xdc8df363,
+    0xdc8ef364, 0xdc8ff365, 0xdc90f366, 0xdc91f367, 0xdc92f368, 0xdc93f369, 0xdc94f36a, 0xdc95f36b,
+    0xdc96f36c, 0xdc97f36d, 0xdc98f36e, 0xdc99f36f, 0xdc9af370, 0xdc9bf371, 0xdc9cf372, 0xdc9df373,
+    0xdc9ef374, 0xdc9ff375, 0xdca0f376, 0xdca1f377, 0xdca2f378, 0xdca3f379, 0xdca4f37a, 0xdca5f37b,
+    0xdca6f37c, 0xdca7f37d, 0xdca8f37e, 0xdca9f37f, 0xdcaaf380, 0xdcabf381, 0xdcacf382, 0xdcadf383,
+    0xdcaef384, 0xdcaff385, 0xdcb0f386, 0xdcb1f387, 0xdcb2f388, 0xdcb3f389, 0xdcb4f38a, 0xdcb5f38b,
+    0xdcb6f38c, 0xdcb7f38d, 0xdcb8f38e, 0xdcb9f38f, 0xdcbaf390, 0xdcbbf391, 0xdcbcf392, 0xdcbdf393,
+    0xdcbef394, 0xdcbff395, 0xdcc0f396, 0xdcc1f397, 0xdcc2f398, 0xdcc3f399, 0xdcc4f39a, 0xdcc5f39b,
+    0xdcc6f39c, 0xdcc7f39d, 0xdcc8f39e, 0xdcc9f39f, 0xdccaf3a0, 0xdccbf3a1, 0xdcccf3a2, 0xdccdf3a3,
+    0xdccef3a4, 0xdccff3a5, 0xdcd0f3a6, 0xdcd1f3a7, 0xdcd2f3a8, 0xdcd3f3a9, 0xdcd4f3aa, 0xdcd5f3ab,
+    0xdcd6f3ac, 0xdcd7f3ad, 0xdcd8f3ae, 0xdcd9f3af, 0xdcdaf3b0, 0xdcdbf3b1, 0xdcdcf3b2, 0xdcddf3b3,
+    0xdcdef3b4, 0xdcdff3b5, 0xdce0f3b6, 0xdce1f3b7, 0xdce2f3b8, 0xdce3f3b9, 0xdce4f3ba, 0xdce5f3bb,
+    0xdce6f3bc, 0xdce7f3bd, 0xdce8f3be, 0xdce9f3bf, 0xdceaf3c0, 0xdcebf3c1, 0xdcecf3c2, 0xdcedf3c3,
+    0xdceef3c4, 0xdceff3c5, 0xdcf0f3c6, 0xdcf1f3c7, 0xdcf2f3c8, 0xdcf3f3c9, 0xdcf4f3ca, 0xdcf5f3cb,
+    0xdcf6f3cc, 0xdcf7f3cd, 0xdcf8f3ce, 0xdcf9f3cf, 0xdcfaf3d0, 0xdcfbf3d1, 0xdcfcf3d2, 0xdcfdf3d3,
+    0xdd41f3d4, 0xdd42f3d5, 0xdd43f3d6, 0xdd44f3d7, 0xdd45f3d8, 0xdd46f3d9, 0xdd47f3da, 0xdd48f3db,
+    0xdd49f3dc, 0xdd4af3dd, 0xdd4bf3de, 0xdd4cf3df, 0xdd4df3e0, 0xdd4ef3e1, 0xdd4ff3e2, 0xdd50f3e3,
+    0xdd51f3e4, 0xdd52f3e5, 0xdd53f3e6, 0xdd54f3e7, 0xdd55f3e8, 0xdd56f3e9, 0xdd57f3ea, 0xdd58f3eb,
+    0xdd59f3ec, 0xdd5af3ed, 0xdd5bf3ee, 0xdd5cf3ef, 0xdd5df3f0, 0xdd5ef3f1, 0xdd5ff3f2, 0xdd60f3f3,
+    0xdd61f3f4, 0xdd62f3f5, 0xdd63f3f6, 0xdd64f3f7, 0xdd65f3f8, 0xdd66f3f9, 0xdd67f3fa, 0xdd68f3fb,
+    0xdd69f3fc, 0xdd6af3fd, 0xdd6bf3fe, 0xdd6cf3ff, 0xdd6df400, 0xdd6ef401, 0xdd6ff402, 0xdd70f403,
+    0xdd71f404, 0xdd72f405, 0xdd73f406, 0xdd74f407, 0xdd75f408, 0xdd76f409, 0xdd77f40a, 0xdd78f40b,
+    0xdd79f40c, 0xdd7af40d, 0xdd7bf40e, 0xdd7cf40f, 0xdd7df410, 0xdd7ef411, 0xdd7ff412, 0xdd81f413,
+    0xdd82f414, 0xdd83f415, 0xdd84f416, 0xdd85f417, 0xdd86f418, 0xdd87f419, 0xdd88f41a, 0xdd89f41b,
+    0xdd8af41c, 0xdd8bf41d, 0xdd8cf41e, 0xdd8df41f, 0xdd8ef420, 0xdd8ff421, 0xdd90f422, 0xdd91f423,
+    0xdd92f424, 0xdd93f425, 0xdd94f426, 0xdd95f427, 0xdd96f428, 0xdd97f429, 0xdd98f42a, 0xdd99f42b,
+    0xdd9af42c, 0xdd9bf42d, 0xdd9cf42e, 0xdd9df42f, 0xdd9ef430, 0xdd9ff431, 0xdda0f432, 0xdda1f433,
+    0xdda2f434, 0xdda3f435, 0xdda4f436, 0xdda5f437, 0xdda6f438, 0xdda7f439, 0xdda8f43a, 0xdda9f43b,
+    0xddaaf43c, 0xddabf43d, 0xddacf43e, 0xddadf43f, 0xddaef440, 0xddaff441, 0xddb0f442, 0xddb1f443,
+    0xddb2f444, 0xddb3f445, 0xddb4f446, 0xddb5f447, 0xddb6f448, 0xddb7f449, 0xddb8f44a, 0xddb9f44b,
+    0xddbaf44c, 0xddbbf44d, 0xddbcf44e, 0xddbdf44f, 0xddbef450, 0xddbff451, 0xddc0f452, 0xddc1f453,
+    0xddc2f454, 0xddc3f455, 0xddc4f456, 0xddc5f457, 0xddc6f458, 0xddc7f459, 0xddc8f45a, 0xddc9f45b,
+    0xddcaf45c, 0xddcbf45d, 0xddccf45e, 0xddcdf45f, 0xddcef460, 0xddcff461, 0xddd0f462, 0xddd1f463,
+    0xddd2f464, 0xddd3f465, 0xddd4f466, 0xddd5f467, 0xddd6f468, 0xddd7f469, 0xddd8f46a, 0xddd9f46b,
+    0xdddaf46c, 0xdddbf46d, 0xdddcf46e, 0xddddf46f, 0xdddef470, 0xdddff471, 0xdde0f472, 0xdde1f473,
+    0xdde2f474, 0xdde3f475, 0xdde4f476, 0xdde5f477, 0xdde6f478, 0xdde7f479, 0xdde8f47a, 0xdde9f47b,
+    0xddeaf47c, 0xddebf47d, 0xddecf47e, 0xddedf47f, 0xddeef480, 0xddeff481, 0xddf0f482, 0xddf1f483,
+    0xddf2f484, 0xddf3f485, 0xddf4f486, 0xddf5f487, 0xddf6f488, 0xddf7f489, 0xddf8f48a, 0xddf9f48b,
+    0xddfaf48c, 0xddfbf48d, 0xddfcf48e, 0xddfdf48f, 0xde41f490, 0xde42f491, 0xde43f492, 0xde44f493,
+    0xde45f494, 0xde46f495, 0xde47f496, 0xde48f497, 0xde49f498, 0xde4af499, 0xde4bf49a, 0xde4cf49b,
+    0xde4df49c, 0xde4ef49d, 0xde4ff49e, 0xde50f49f, 0xde51f4a0, 0xde52f4a1, 0xde53f4a2, 0xde54f4a3,
+    0xde55f4a4, 0xde56f4a5, 0xde57f4a6, 0xde58f4a7, 0xde59f4a8, 0xde5af4a9, 0xde5bf4aa, 0xde5cf4ab,
+    0xde5df4ac, 0xde5ef4ad, 0xde5ff4ae, 0xde60f4af, 0xde61f4b0, 0xde62f4b1, 0xde63f4b2, 0xde64f4b3,
+    0xde65f4b4, 0xde66f4b5, 0xde67f4b6, 0xde68f4b7, 0xde69f4b8, 0xde6af4b9, 0xde6bf4ba, 0xde6cf4bb,
+    0xde6df4bc, 0xde6ef4bd, 0xde6ff4be, 0xde70f4bf, 0xde71f4c0, 0xde72f4c1, 0xde73f4c2, 0xde74f4c3,
+    0xde75f4c4, 0xde76f4c5, 0xde77f4c6, 0xde78f4c7, 0xde79f4c8, 0xde7af4c9, 0xde7bf4ca, 0xde7cf4cb,
+    0xde7df4cc, 0xde7ef4cd, 0xde7ff4ce, 0xde81f4cf, 0xde82f4d0, 0xde83f4d1, 0xde84f4d2, 0xde85f4d3,
+    0xde86f4d4, 0xde87f4d5, 0xde88f4d6, 0xde89f4d7, 0xde8af4d8, 0xde8bf4d9, 0xde8cf4da, 0xde8df4db,
+    0xde8ef4dc, 0xde8ff4dd, 0xde90f4de, 0xde91f4df, 0xde92f4e0, 0xde93f4e1, 0xde94f4e2, 0xde95f4e3,
+    0xde96f4e4, 0xde97f4e5, 0xde98f4e6, 0xde99f4e7, 0xde9af4e8, 0xde9bf4e9, 0xde9cf4ea, 0xde9df4eb,
+    0xde9ef4ec, 0xde9ff4ed, 0xdea0f4ee, 0xdea1f4ef, 0xdea2f4f0, 0xdea3f4f1, 0xdea4f4f2, 0xdea5f4f3,
+    0xdea6f4f4, 0xdea7f4f5, 0xdea8f4f6, 0xdea9f4f7, 0xdeaaf4f8, 0xdeabf4f9, 0xdeacf4fa, 0xdeadf4fb,
+    0xdeaef4fc, 0xdeaff4fd, 0xdeb0f4fe, 0xdeb1f4ff, 0xdeb2f500, 0xdeb3f501, 0xdeb4f502, 0xdeb5f503,
+    0xdeb6f504, 0xdeb7f505, 0xdeb8f506, 0xdeb9f507, 0xdebaf508, 0xdebbf509, 0xdebcf50a, 0xdebdf50b,
+    0xdebef50c, 0xdebff50d, 0xdec0f50e, 0xdec1f50f, 0xdec2f510, 0xdec3f511, 0xdec4f512, 0xdec5f513,
+    0xdec6f514, 0xdec7f515, 0xdec8f516, 0xdec9f517, 0xdecaf518, 0xdecbf519, 0xdeccf51a, 0xdecdf51b,
+    0xdecef51c, 0xdecff51d, 0xded0f51e, 0xded1f51f, 0xded2f520, 0xded3f521, 0xded4f522, 0xded5f523,
+    0xded6f524, 0xded7f525, 0xded8f526, 0xded9f527, 0xdedaf528, 0xdedbf529, 0xdedcf52a, 0xdeddf52b,
+    0xdedef52c, 0xdedff52d, 0xdee0f52e, 0xdee1f52f, 0xdee2f530, 0xdee3f531, 0xdee4f532, 0xdee5f533,
+    0xdee6f534, 0xdee7f535, 0xdee8f536, 0xdee9f537, 0xdeeaf538, 0xdeebf539, 0xdeecf53a, 0xdeedf53b,
+    0xdeeef53c, 0xdeeff53d, 0xdef0f53e, 0xdef1f53f, 0xdef2f540, 0xdef3f541, 0xdef4f542, 0xdef5f543,
+    0xdef6f544, 0xdef7f545, 0xdef8f546, 0xdef9f547, 0xdefaf548, 0xdefbf549, 0xdefcf54a, 0xdefdf54b,
+    0xdf41f54c, 0xdf42f54d, 0xdf43f54e, 0xdf44f54f, 0xdf45f550, 0xdf46f551, 0xdf47f552, 0xdf48f553,
+    0xdf49f554, 0xdf4af555, 0xdf4bf556, 0xdf4cf557, 0xdf4df558, 0xdf4ef559, 0xdf4ff55a, 0xdf50f55b,
+    0xdf51f55c, 0xdf52f55d, 0xdf53f55e, 0xdf54f55f, 0xdf55f560, 0xdf56f561, 0xdf57f562, 0xdf58f563,
+    0xdf59f564, 0xdf5af565, 0xdf5bf566, 0xdf5cf567, 0xdf5df568, 0xdf5ef569, 0xdf5ff56a, 0xdf60f56b,
+    0xdf61f56c, 0xdf62f56d, 0xdf63f56e, 0xdf64f56f, 0xdf65f570, 0xdf66f571, 0xdf67f572, 0xdf68f573,
+    0xdf69f574, 0xdf6af575, 0xdf6bf576, 0xdf6cf577, 0xdf6df578, 0xdf6ef579, 0xdf6ff57a, 0xdf70f57b,
+    0xdf71f57c, 0xdf72f57d, 0xdf73f57e, 0xdf74f57f, 0xdf75f580, 0xdf76f581, 0xdf77f582, 0xdf78f583,
+    0xdf79f584, 0xdf7af585, 0xdf7bf586, 0xdf7cf587, 0xdf7df588, 0xdf7ef589, 0xdf7ff58a, 0xdf81f58b,
+    0xdf82f58c, 0xdf83f58d, 0xdf84f58e, 0xdf85f58f, 0xdf86f590, 0xdf87f591, 0xdf88f592, 0xdf89f593,
+    0xdf8af594, 0xdf8bf595, 0xdf8cf596, 0xdf8df597, 0xdf8ef598, 0xdf8ff599, 0xdf90f59a, 0xdf91f59b,
+    0xdf92f59c, 0xdf93f59d, 0xdf94f59e, 0xdf95f59f, 0xdf96f5a0, 0xdf97f5a1, 0xdf98f5a2, 0xdf99f5a3,
+    0xdf9af5a4, 0xdf9bf5a5, 0xdf9cf5a6, 0xdf9df5a7, 0xdf9ef5a8, 0xdf9ff5a9, 0xdfa0f5aa, 0xdfa1f5ab,
+    0xdfa2f5ac, 0xdfa3f5ad, 0xdfa4f5ae, 0xdfa5f5af, 0xdfa6f5b0, 0xdfa7f5b1, 0xdfa8f5b2, 0xdfa9f5b3,
+    0xdfaaf5b4, 0xdfabf5b5, 0xdfacf5b6, 0xdfadf5b7, 0xdfaef5b8, 0xdfaff5b9, 0xdfb0f5ba, 0xdfb1f5bb,
+    0xdfb2f5bc, 0xdfb3f5bd, 0xdfb4f5be, 0xdfb5f5bf, 0xdfb6f5c0, 0xdfb7f5c1, 0xdfb8f5c2, 0xdfb9f5c3,
+    0xdfbaf5c4, 0xdfbbf5c5, 0xdfbcf5c6, 0xdfbdf5c7, 0xdfbef5c8, 0xdfbff5c9, 0xdfc0f5ca, 0xdfc1f5cb,
+    0xdfc2f5cc, 0xdfc3f5cd, 0xdfc4f5ce, 0xdfc5f5cf, 0xdfc6f5d0, 0xdfc7f5d1, 0xdfc8f5d2, 0xdfc9f5d3,
+    0xdfcaf5d4, 0xdfcbf5d5, 0xdfccf5d6, 0xdfcdf5d7, 0xdfcef5d8, 0xdfcff5d9, 0xdfd0f5da, 0xdfd1f5db,
+    0xdfd2f5dc, 0xdfd3f5dd, 0xdfd4f5de, 0xdfd5f5df, 0xdfd6f5e0, 0xdfd7f5e1, 0xdfd8f5e2, 0xdfd9f5e3,
+    0xdfdaf5e4, 0xdfdbf5e5, 0xdfdcf5e6, 0xdfddf5e7, 0xdfdef5e8, 0xdfdff5e9, 0xdfe0f5ea, 0xdfe1f5eb,
+    0xdfe2f5ec, 0xdfe3f5ed, 0xdfe4f5ee, 0xdfe5f5ef, 0xdfe6f5f0, 0xdfe7f5f1, 0xdfe8f5f2, 0xdfe9f5f3,
+    0xdfeaf5f4, 0xdfebf5f5, 0xdfecf5f6, 0xdfedf5f7, 0xdfeef5f8, 0xdfeff5f9, 0xdff0f5fa, 0xdff1f5fb,
+    0xdff2f5fc, 0xdff3f5fd, 0xdff4f5fe, 0xdff5f5ff, 0xdff6f600, 0xdff7f601, 0xdff8f602, 0xdff9f603,
+    0xdffaf604, 0xdffbf605, 0xdffcf606, 0xdffdf607, 0xe041f608, 0xe042f609, 0xe043f60a, 0xe044f60b,
+    0xe045f60c, 0xe046f60d, 0xe047f60e, 0xe048f60f, 0xe049f610, 0xe04af611, 0xe04bf612, 0xe04cf613,
+    0xe04df614, 0xe04ef615, 0xe04ff616, 0xe050f617, 0xe051f618, 0xe052f619, 0xe053f61a, 0xe054f61b,
+    0xe055f61c, 0xe056f61d, 0xe057f61e, 0xe058f61f, 0xe059f620, 0xe05af621, 0xe05bf622, 0xe05cf623,
+    0xe05df624, 0xe05ef625, 0xe05ff626, 0xe060f627, 0xe061f628, 0xe062f629, 0xe063f62a, 0xe064f62b,
+    0xe065f62c, 0xe066f62d, 0xe067f62e, 0xe068f62f, 0xe069f630, 0xe06af631, 0xe06bf632, 0xe06cf633,
+    0xe06df634, 0xe06ef635, 0xe06ff636, 0xe070f637, 0xe071f638, 0xe072f639, 0xe073f63a, 0xe074f63b,
+    0xe075f63c, 0xe076f63d, 0xe077f63e, 0xe078f63f, 0xe079f640, 0xe07af641, 0xe07bf642, 0xe07cf643,
+    0xe07df644, 0xe07ef645, 0xe07ff646, 0xe081f647, 0xe082f648, 0xe083f649, 0xe084f64a, 0xe085f64b,
+    0xe086f64c, 0xe087f64d, 0xe088f64e, 0xe089f64f, 0xe08af650, 0xe08bf651, 0xe08cf652, 0xe08df653,
+    0xe08ef654, 0xe08ff655, 0xe090f656, 0xe091f657, 0xe092f658, 0xe093f659, 0xe094f65a, 0xe095f65b,
+    0xe096f65c, 0xe097f65d, 0xe098f65e, 0xe099f65f, 0xe09af660, 0xe09bf661, 0xe09cf662, 0xe09df663,
+    0xe09ef664, 0xe09ff665, 0xe0a0f666, 0xe0a1f667, 0xe0a2f668, 0xe0a3f669, 0xe0a4f66a, 0xe0a5f66b,
+    0xe0a6f66c, 0xe0a7f66d, 0xe0a8f66e, 0xe0a9f66f, 0xe0aaf670, 0xe0abf671, 0xe0acf672, 0xe0adf673,
+    0xe0aef674, 0xe0aff675, 0xe0b0f676, 0xe0b1f677, 0xe0b2f678, 0xe0b3f679, 0xe0b4f67a, 0xe0b5f67b,
+    0xe0b6f67c, 0xe0b7f67d, 0xe0b8f67e, 0xe0b9f67f, 0xe0baf680, 0xe0bbf681, 0xe0bcf682, 0xe0bdf683,
+    0xe0bef684, 0xe0bff685, 0xe0c0f686, 0xe0c1f687, 0xe0c2f688, 0xe0c3f689, 0xe0c4f68a, 0xe0c5f68b,
+    0xe0c6f68c, 0xe0c7f68d, 0xe0c8f68e, 0xe0c9f68f, 0xe0caf690, 0xe0cbf691, 0xe0ccf692, 0xe0cdf693,
+    0xe0cef694, 0xe0cff695, 0xe0d0f696, 0xe0d1f697, 0xe0d2f698, 0xe0d3f699, 0xe0d4f69a, 0xe0d5f69b,
+    0xe0d6f69c, 0xe0d7f69d, 0xe0d8f69e, 0xe0d9f69f, 0xe0daf6a0, 0xe0dbf6a1, 0xe0dcf6a2, 0xe0ddf6a3,
+    0xe0def6a4, 0xe0dff6a5, 0xe0e0f6a6, 0xe0e1f6a7, 0xe0e2f6a8, 0xe0e3f6a9, 0xe0e4f6aa, 0xe0e5f6ab,
+    0xe0e6f6ac, 0xe0e7f6ad, 0xe0e8f6ae, 0xe0e9f6af, 0xe0eaf6b0, 0xe0ebf6b1, 0xe0ecf6b2, 0xe0edf6b3,
+    0xe0eef6b4, 0xe0eff6b5, 0xe0f0f6b6, 0xe0f1f6b7, 0xe0f2f6b8, 0xe0f3f6b9, 0xe0f4f6ba, 0xe0f5f6bb,
+    0xe0f6f6bc, 0xe0f7f6bd, 0xe0f8f6be, 0xe0f9f6bf, 0xe0faf6c0, 0xe0fbf6c1, 0xe0fcf6c2, 0xe0fdf6c3,
+    0xe141f6c4, 0xe142f6c5, 0xe143f6c6, 0xe144f6c7, 0xe145f6c8, 0xe146f6c9, 0xe147f6ca, 0xe148f6cb,
+    0xe149f6cc, 0xe14af6cd, 0xe14bf6ce, 0xe14cf6cf, 0xe14df6d0, 0xe14ef6d1, 0xe14ff6d2, 0xe150f6d3,
+    0xe151f6d4, 0xe152f6d5, 0xe153f6d6, 0xe154f6d7, 0xe155f6d8, 0xe156f6d9, 0xe157f6da, 0xe158f6db,
+    0xe159f6dc, 0xe15af6dd, 0xe15bf6de, 0xe15cf6df, 0xe15df6e0, 0xe15ef6e1, 0xe15ff6e2, 0xe160f6e3,
+    0xe161f6e4, 0xe162f6e5, 0xe163f6e6, 0xe164f6e7, 0xe165f6e8, 0xe166f6e9, 0xe167f6ea, 0xe168f6eb,
+    0xe169f6ec, 0xe16af6ed, 0xe16bf6ee, 0xe16cf6ef, 0xe16df6f0, 0xe16ef6f1, 0xe16ff6f2, 0xe170f6f3,
+    0xe171f6f4, 0xe172f6f5, 0xe173f6f6, 0xe174f6f7, 0xe175f6f8, 0xe176f6f9, 0xe177f6fa, 0xe178f6fb,
+    0xe179f6fc, 0xe17af6fd, 0xe17bf6fe, 0xe17cf6ff, 0xe17df700, 0xe17ef701, 0xe17ff702, 0xe181f703,
+    0xe182f704, 0xe183f705, 0xe184f706, 0xe185f707, 0xe186f708, 0xe187f709, 0xe188f70a, 0xe189f70b,
+    0xe18af70c, 0xe18bf70d, 0xe18cf70e, 0xe18df70f, 0xe18ef710, 0xe18ff711, 0xe190f712, 0xe191f713,
+    0xe192f714, 0xe193f715, 0xe194f716, 0xe195f717, 0xe196f718, 0xe197f719, 0xe198f71a, 0xe199f71b,
+    0xe19af71c, 0xe19bf71d, 0xe19cf71e, 0xe19df71f, 0xe19ef720, 0xe19ff721, 0xe1a0f722, 0xe1a1f723,
+    0xe1a2f724, 0xe1a3f725, 0xe1a4f726, 0xe1a5f727, 0xe1a6f728, 0xe1a7f729, 0xe1a8f72a, 0xe1a9f72b,
+    0xe1aaf72c, 0xe1abf72d, 0xe1acf72e, 0xe1adf72f, 0xe1aef730, 0xe1aff731, 0xe1b0f732, 0xe1b1f733,
+    0xe1b2f734, 0xe1b3f735, 0xe1b4f736, 0xe1b5f737, 0xe1b6f738, 0xe1b7f739, 0xe1b8f73a, 0xe1b9f73b,
+    0xe1baf73c, 0xe1bbf73d, 0xe1bcf73e, 0xe1bdf73f, 0xe1bef740, 0xe1bff741, 0xe1c0f742, 0xe1c1f743,
+    0xe1c2f744, 0xe1c3f745, 0xe1c4f746, 0xe1c5f747, 0xe1c6f748, 0xe1c7f749, 0xe1c8f74a, 0xe1c9f74b,
+    0xe1caf74c, 0xe1cbf74d, 0xe1ccf74e, 0xe1cdf74f, 0xe1cef750, 0xe1cff751, 0xe1d0f752, 0xe1d1f753,
+    0xe1d2f754, 0xe1d3f755, 0xe1d4f756, 0xe1d5f757, 0xe1d6f758, 0xe1d7f759, 0xe1d8f75a, 0xe1d9f75b,
+    0xe1daf75c, 0xe1dbf75d, 0xe1dcf75e, 0xe1ddf75f, 0xe1def760, 0xe1dff761, 0xe1e0f762, 0xe1e1f763,
+    0xe1e2f764, 0xe1e3f765, 0xe1e4f766, 0xe1e5f767, 0xe1e6f768, 0xe1e7f769, 0xe1e8f76a, 0xe1e9f76b,
+    0xe1eaf76c, 0xe1ebf76d, 0xe1ecf76e, 0xe1edf76f, 0xe1eef770, 0xe1eff771, 0xe1f0f772, 0xe1f1f773,
+    0xe1f2f774, 0xe1f3f775, 0xe1f4f776, 0xe1f5f777, 0xe1f6f778, 0xe1f7f779, 0xe1f8f77a, 0xe1f9f77b,
+    0xe1faf77c, 0xe1fbf77d, 0xe1fcf77e, 0xe1fdf77f, 0xe241f780, 0xe242f781, 0xe243f782, 0xe244f783,
+    0xe245f784, 0xe246f785, 0xe247f786, 0xe248f787, 0xe249f788, 0xe24af789, 0xe24bf78a, 0xe24cf78b,
+    0xe24df78c, 0xe24ef78d, 0xe24ff78e, 0xe250f78f, 0xe251f790, 0xe252f791, 0xe253f792, 0xe254f793,
+    0xe255f794, 0xe256f795, 0xe257f796, 0xe258f797, 0xe259f798, 0xe25af799, 0xe25bf79a, 0xe25cf79b,
+    0xe25df79c, 0xe25ef79d, 0xe25ff79e, 0xe260f79f, 0xe261f7a0, 0xe262f7a1, 0xe263f7a2, 0xe264f7a3,
+    0xe265f7a4, 0xe266f7a5, 0xe267f7a6, 0xe268f7a7, 0xe269f7a8, 0xe26af7a9, 0xe26bf7aa, 0xe26cf7ab,
+    0xe26df7ac, 0xe26ef7ad, 0xe26ff7ae, 0xe270f7af, 0xe271f7b0, 0xe272f7b1, 0xe273f7b2, 0xe274f7b3,
+    0xe275f7b4, 0xe276f7b5, 0xe277f7b6, 0xe278f7b7, 0xe279f7b8, 0xe27af7b9, 0xe27bf7ba, 0xe27cf7bb,
+    0xe27df7bc, 0xe27ef7bd, 0xe27ff7be, 0xe281f7bf, 0xe282f7c0, 0xe283f7c1, 0xe284f7c2, 0xe285f7c3,
+    0xe286f7c4, 0xe287f7c5, 0xe288f7c6, 0xe289f7c7, 0xe28af7c8, 0xe28bf7c9, 0xe28cf7ca, 0xe28df7cb,
+    0xe28ef7cc, 0xe28ff7cd, 0xe290f7ce, 0xe291f7cf, 0xe292f7d0, 0xe293f7d1, 0xe294f7d2, 0xe295f7d3,
+    0xe296f7d4, 0xe297f7d5, 0xe298f7d6, 0xe299f7d7, 0xe29af7d8, 0xe29bf7d9, 0xe29cf7da, 0xe29df7db,
+    0xe29ef7dc, 0xe29ff7dd, 0xe2a0f7de, 0xe2a1f7df, 0xe2a2f7e0, 0xe2a3f7e1, 0xe2a4f7e2, 0xe2a5f7e3,
+    0xe2a6f7e4, 0xe2a7f7e5, 0xe2a8f7e6, 0xe2a9f7e7, 0xe2aaf7e8, 0xe2abf7e9, 0xe2acf7ea, 0xe2adf7eb,
+    0xe2aef7ec, 0xe2aff7ed, 0xe2b0f7ee, 0xe2b1f7ef, 0xe2b2f7f0, 0xe2b3f7f1, 0xe2b4f7f2, 0xe2b5f7f3,
+    0xe2b6f7f4, 0xe2b7f7f5, 0xe2b8f7f6, 0xe2b9f7f7, 0xe2baf7f8, 0xe2bbf7f9, 0xe2bcf7fa, 0xe2bdf7fb,
+    0xe2bef7fc, 0xe2bff7fd, 0xe2c0f7fe, 0xe2c1f7ff, 0xe2c2f800, 0xe2c3f801, 0xe2c4f802, 0xe2c5f803,
+    0xe2c6f804, 0xe2c7f805, 0xe2c8f806, 0xe2c9f807, 0xe2caf808, 0xe2cbf809, 0xe2ccf80a, 0xe2cdf80b,
+    0xe2cef80c, 0xe2cff80d, 0xe2d0f80e, 0xe2d1f80f, 0xe2d2f810, 0xe2d3f811, 0xe2d4f812, 0xe2d5f813,
+    0xe2d6f814, 0xe2d7f815, 0xe2d8f816, 0xe2d9f817, 0xe2daf818, 0xe2dbf819, 0xe2dcf81a, 0xe2ddf81b,
+    0xe2def81c, 0xe2dff81d, 0xe2e0f81e, 0xe2e1f81f, 0xe2e2f820, 0xe2e3f821, 0xe2e4f822, 0xe2e5f823,
+    0xe2e6f824, 0xe2e7f825, 0xe2e8f826, 0xe2e9f827, 0xe2eaf828, 0xe2ebf829, 0xe2ecf82a, 0xe2edf82b,
+    0xe2eef82c, 0xe2eff82d, 0xe2f0f82e, 0xe2f1f82f, 0xe2f2f830, 0xe2f3f831, 0xe2f4f832, 0xe2f5f833,
+    0xe2f6f834, 0xe2f7f835, 0xe2f8f836, 0xe2f9f837, 0xe2faf838, 0xe2fbf839, 0xe2fcf83a, 0xe2fdf83b,
+];
+
+#[allow(unused)]
+pub static DECODE_ONLY: [u16; 189] = [
+    0x454a, 0x454c, 0x4841, 0x4845, 0x4847, 0x4849, 0x484a, 0x484b, 0x484c, 0x4850, 0x4851, 0x4852,
+    0x4853, 0x4855, 0x4856, 0x4858, 0x4859, 0x485b, 0x485d, 0x485e, 0x485f, 0x4860, 0x4861, 0x4863,
+    0x4864, 0x4865, 0x4866, 0x4868, 0x4869, 0x486a, 0x486b, 0x486c, 0x486d, 0x486f, 0x4870, 0x4871,
+    0x4872, 0x4876, 0x4877, 0x4878, 0x487b, 0x487c, 0x487d, 0x487e, 0x487f, 0x4881, 0x4883, 0x4884,
+    0x4885, 0x4886, 0x4888, 0x4889, 0x488a, 0x488b, 0x488c, 0x488d, 0x488e, 0x488f, 0x4890, 0x4891,
+    0x4892, 0x4893, 0x4894, 0x4895, 0x4896, 0x4897, 0x4898, 0x4899, 0x489a, 0x489b, 0x489c, 0x489d,
+    0x489e, 0x489f, 0x48a0, 0x48a1, 0x48a2, 0x48a3, 0x48a4, 0x48a5, 0x48a6, 0x48a7, 0x48aa, 0x48ab,
+    0x48ac, 0x48ad, 0x48ae, 0x48af, 0x48b0, 0x48b1, 0x48b2, 0x48b3, 0x48b4, 0x48b5, 0x48b6, 0x48b7,
+    0x48b8, 0x48b9, 0x48ba, 0x48bb, 0x48bc, 0x48bd, 0x48be, 0x48bf, 0x48c0, 0x48c1, 0x48c2, 0x48c3,
+    0x48c4, 0x48c5, 0x48c6, 0x48c7, 0x48c8, 0x48c9, 0x48ca, 0x48cb, 0x48cc, 0x48cd, 0x48ce, 0x48cf,
+    0x48d0, 0x48d1, 0x48d2, 0x48d3, 0x48d4, 0x48d5, 0x48d6, 0x48d7, 0x48d8, 0x48d9, 0x48da, 0x48db,
+    0x48dc, 0x48dd, 0x48de, 0x48df, 0x48e0, 0x48e1, 0x48e3, 0x48e4, 0x48e5, 0x48e6, 0x48e7, 0x48e8,
+    0x48e9, 0x48ea, 0x48ec, 0x48ed, 0x48ee, 0x48ef, 0x48f0, 0x48f1, 0x48f2, 0x48f3, 0x48f4, 0x48f5,
+    0x48f6, 0x48f7, 0x48f8, 0x48f9, 0x48fa, 0x48fb, 0x48fc, 0x48fd, 0x4941, 0x4942, 0x4943, 0x4944,
+    0x4945, 0x4946, 0x4947, 0x4948, 0x4949, 0x494a, 0x494b, 0x494c, 0x494d, 0x494e, 0x494f, 0x4950,
+    0x4951, 0x4952, 0x4953, 0x4954, 0x4955, 0x4956, 0x4957, 0x4958, 0x4959,
+];
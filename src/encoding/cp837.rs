@@ -0,0 +1,1173 @@
+#[allow(unused)]
+pub static DBCS_TBL: [u32; 9356] = [
+    0x40403000, 0x414103b1, 0x414203b2, 0x414303b3, 0x414403b4, 0x414503b5, 0x414603b6, 0x414703b7,
+    0x414803b8, 0x414903b9, 0x414a03ba, 0x414b03bb, 0x414c03bc, 0x414d03bd, 0x414e03be, 0x414f03bf,
+    0x415003c0, 0x415103c1, 0x415203c3, 0x415303c4, 0x415403c5, 0x415503c6, 0x415603c7, 0x415703c8,
+    0x415803c9, 0x41610391, 0x41620392, 0x41630393, 0x41640394, 0x41650395, 0x41660396, 0x41670397,
+    0x41680398, 0x41690399, 0x416a039a, 0x416b039b, 0x416c039c, 0x416d039d, 0x416e039e, 0x416f039f,
+    0x417003a0, 0x417103a1, 0x417203a3, 0x417303a4, 0x417403a5, 0x417503a6, 0x417603a7, 0x417703a8,
+    0x417803a9, 0x41800430, 0x41810431, 0x41820432, 0x41830433, 0x41840434, 0x41850435, 0x41860451,
+    0x41870436, 0x41880437, 0x41890438, 0x418a0439, 0x418b043a, 0x418c043b, 0x418d043c, 0x418e043d,
+    0x418f043e, 0x4190043f, 0x41910440, 0x41920441, 0x41930442, 0x41940443, 0x41950444, 0x41960445,
+    0x41970446, 0x41980447, 0x41990448, 0x419a0449, 0x419b044a, 0x419c044b, 0x419d044c, 0x419e044d,
+    0x419f044e, 0x41a0044f, 0x41b12170, 0x41b22171, 0x41b32172, 0x41b42173, 0x41b52174, 0x41b62175,
+    0x41b72176, 0x41b82177, 0x41b92178, 0x41ba2179, 0x41c00410, 0x41c10411, 0x41c20412, 0x41c30413,
+    0x41c40414, 0x41c50415, 0x41c60401, 0x41c70416, 0x41c80417, 0x41c90418, 0x41ca0419, 0x41cb041a,
+    0x41cc041b, 0x41cd041c, 0x41ce041d, 0x41cf041e, 0x41d0041f, 0x41d10420, 0x41d20421, 0x41d30422,
+    0x41d40423, 0x41d50424, 0x41d60425, 0x41d70426, 0x41d80427, 0x41d90428, 0x41da0429, 0x41db042a,
+    0x41dc042b, 0x41dd042c, 0x41de042d, 0x41df042e, 0x41e0042f, 0x41f12160, 0x41f22161, 0x41f32162,
+    0x41f42163, 0x41f52164, 0x41f62165, 0x41f72166, 0x41f82167, 0x41f92168, 0x41fa2169, 0x41fb216a,
+    0x41fc216b, 0x424affe1, 0x424bff0e, 0x424cff1c, 0x424dff08, 0x424eff0b, 0x424fff5c, 0x4250ff06,
+    0x425aff01, 0x425bffe5, 0x425cff0a, 0x425dff09, 0x425eff1b, 0x425fffe2, 0x4260ff0d, 0x4261ff0f,
+    0x426affe4, 0x426bff0c, 0x426cff05, 0x426dff3f, 0x426eff1e, 0x426fff1f, 0x4279ff40, 0x427aff1a,
+    0x427bff03, 0x427cff20, 0x427dff07, 0x427eff1d, 0x427fff02, 0x4281ff41, 0x4282ff42, 0x4283ff43,
+    0x4284ff44, 0x4285ff45, 0x4286ff46, 0x4287ff47, 0x4288ff48, 0x4289ff49, 0x4291ff4a, 0x4292ff4b,
+    0x4293ff4c, 0x4294ff4d, 0x4295ff4e, 0x4296ff4f, 0x4297ff50, 0x4298ff51, 0x4299ff52, 0x42a1ffe3,
+    0x42a2ff53, 0x42a3ff54, 0x42a4ff55, 0x42a5ff56, 0x42a6ff57, 0x42a7ff58, 0x42a8ff59, 0x42a9ff5a,
+    0x42c0ff5b, 0x42c1ff21, 0x42c2ff22, 0x42c3ff23, 0x42c4ff24, 0x42c5ff25, 0x42c6ff26, 0x42c7ff27,
+    0x42c8ff28, 0x42c9ff29, 0x42d0ff5d, 0x42d1ff2a, 0x42d2ff2b, 0x42d3ff2c, 0x42d4ff2d, 0x42d5ff2e,
+    0x42d6ff2f, 0x42d7ff30, 0x42d8ff31, 0x42d9ff32, 0x42e0ff04, 0x42e2ff33, 0x42e3ff34, 0x42e4ff35,
+    0x42e5ff36, 0x42e6ff37, 0x42e7ff38, 0x42e8ff39, 0x42e9ff3a, 0x42f0ff10, 0x42f1ff11, 0x42f2ff12,
+    0x42f3ff13, 0x42f4ff14, 0x42f5ff15, 0x42f6ff16, 0x42f7ff17, 0x42f8ff18, 0x42f9ff19, 0x43413002,
+    0x4342300c, 0x4343300d, 0x43443001, 0x434530fb, 0x434630f2, 0x434730a1, 0x434830a3, 0x434930a5,
+    0x434affe0, 0x435130a7, 0x435230a9, 0x435330e3, 0x435430e5, 0x435530e7, 0x435630c3, 0x435730ee,
+    0x435830fc, 0x435930f5, 0x435a30f6, 0x435bf83d, 0x438130a2, 0x438230a4, 0x438330a6, 0x438430a8,
+    0x438530aa, 0x438630ab, 0x438730ad, 0x438830af, 0x438930b1, 0x438a30b3, 0x438c30b5, 0x438d30b7,
+    0x438e30b9, 0x438f30bb, 0x439030bd, 0x439130bf, 0x439230c1, 0x439330c4, 0x439430c6, 0x439530c8,
+    0x439630ca, 0x439730cb, 0x439830cc, 0x439930cd, 0x439a30ce, 0x439d30cf, 0x439e30d2, 0x439f30d5,
+    0x43a1ff5e, 0x43a230d8, 0x43a330db, 0x43a430de, 0x43a530df, 0x43a630e0, 0x43a730e1, 0x43a830e2,
+    0x43a930e4, 0x43aa30e6, 0x43ac30e8, 0x43ad30e9, 0x43ae30ea, 0x43af30eb, 0x43ba30ec, 0x43bb30ed,
+    0x43bc30ef, 0x43bd30f3, 0x43be309b, 0x43bf309c, 0x43c030ac, 0x43c130ae, 0x43c230b0, 0x43c330b2,
+    0x43c430b4, 0x43c530b6, 0x43c630b8, 0x43c730ba, 0x43c830bc, 0x43c930be, 0x43ca30c0, 0x43cb30c2,
+    0x43cc30c5, 0x43cd30c7, 0x43ce30c9, 0x43cf30d0, 0x43d030d3, 0x43d130d6, 0x43d230d9, 0x43d330dc,
+    0x43d430f4, 0x43d530d1, 0x43d630d4, 0x43d730d7, 0x43d830da, 0x43d930dd, 0x43da30f0, 0x43db30f1,
+    0x43dc30fd, 0x43dd30fe, 0x43e0ff3c, 0x4442300e, 0x4443300f, 0x4444ff3b, 0x4445ff3d, 0x44463092,
+    0x44473041, 0x44483043, 0x44493045, 0x444a2015, 0x444b00b1, 0x444c2260, 0x444d221e, 0x444e2103,
+    0x445000b4, 0x44513047, 0x44523049, 0x44533083, 0x44543085, 0x44553087, 0x44563063, 0x4457308e,
+    0x445a2010, 0x445b3003, 0x445cf83e, 0x445d3005, 0x445e3006, 0x445f3007, 0x446000a8, 0x44612018,
+    0x4462201c, 0x44633014, 0x44643008, 0x4465300a, 0x44663010, 0x44672264, 0x44682234, 0x44692642,
+    0x446a00a7, 0x446b203b, 0x446c3012, 0x446d3231, 0x446e2116, 0x446f2121, 0x4470ff3e, 0x44712019,
+    0x4472201d, 0x44733015, 0x44743009, 0x4475300b, 0x44763011, 0x44772265, 0x44782235, 0x44792640,
+    0x447a00d7, 0x447b00f7, 0x447c2016, 0x447d3013, 0x447e2025, 0x447f2026, 0x44813042, 0x44823044,
+    0x44833046, 0x44843048, 0x4485304a, 0x4486304b, 0x4487304d, 0x4488304f, 0x44893051, 0x448a3053,
+    0x448c3055, 0x448d3057, 0x448e3059, 0x448f305b, 0x4490305d, 0x4491305f, 0x44923061, 0x44933064,
+    0x44943066, 0x44953068, 0x4496306a, 0x4497306b, 0x4498306c, 0x4499306d, 0x449a306e, 0x449d306f,
+    0x449e3072, 0x449f3075, 0x44a23078, 0x44a3307b, 0x44a4307e, 0x44a5307f, 0x44a63080, 0x44a73081,
+    0x44a83082, 0x44a93084, 0x44aa3086, 0x44ac3088, 0x44ad3089, 0x44ae308a, 0x44af308b, 0x44ba308c,
+    0x44bb308d, 0x44bc308f, 0x44bd3093, 0x44c0304c, 0x44c1304e, 0x44c23050, 0x44c33052, 0x44c43054,
+    0x44c53056, 0x44c63058, 0x44c7305a, 0x44c8305c, 0x44c9305e, 0x44ca3060, 0x44cb3062, 0x44cc3065,
+    0x44cd3067, 0x44ce3069, 0x44cf3070, 0x44d03073, 0x44d13076, 0x44d23079, 0x44d3307c, 0x44d53071,
+    0x44d63074, 0x44d73077, 0x44d8307a, 0x44d9307d, 0x44da3090, 0x44db3091, 0x44dc309d, 0x44dd309e,
+    0x44e025cb, 0x44e125cf, 0x44e225b3, 0x44e325b2, 0x44e425ce, 0x44e52606, 0x44e62605, 0x44e725c7,
+    0x44e825c6, 0x44e925a1, 0x44ea25a0, 0x44eb25bd, 0x44ec25bc, 0x44ed00b0, 0x44ee2032, 0x44ef2033,
+    0x44f02192, 0x44f12190, 0x44f22191, 0x44f32193, 0x454502c9, 0x454602c7, 0x455b3016, 0x455c3017,
+    0x45622236, 0x45632227, 0x45642228, 0x45652211, 0x4566220f, 0x4567222a, 0x45682229, 0x45692208,
+    0x456a2237, 0x456b221a, 0x456c22a5, 0x456d2225, 0x456e2220, 0x456f2312, 0x45702299, 0x4571222b,
+    0x4572222e, 0x45732261, 0x4574224c, 0x45752248, 0x4576223d, 0x4577221d, 0x4579226e, 0x457a226f,
+    0x458800a4, 0x458b2030, 0x45b12488, 0x45b22489, 0x45b3248a, 0x45b4248b, 0x45b5248c, 0x45b6248d,
+    0x45b7248e, 0x45b8248f, 0x45b92490, 0x45ba2491, 0x45bb2492, 0x45bc2493, 0x45bd2494, 0x45be2495,
+    0x45bf2496, 0x45c02497, 0x45c12498, 0x45c22499, 0x45c3249a, 0x45c4249b, 0x45c52474, 0x45c62475,
+    0x45c72476, 0x45c82477, 0x45c92478, 0x45ca2479, 0x45cb247a, 0x45cc247b, 0x45cd247c, 0x45ce247d,
+    0x45cf247e, 0x45d0247f, 0x45d12480, 0x45d22481, 0x45d32482, 0x45d42483, 0x45d52484, 0x45d62485,
+    0x45d72486, 0x45d82487, 0x45e12460, 0x45e22461, 0x45e32462, 0x45e42463, 0x45e52464, 0x45e62465,
+    0x45e72466, 0x45e82467, 0x45e92468, 0x45ea2469, 0x45f13220, 0x45f23221, 0x45f33222, 0x45f43223,
+    0x45f53224, 0x45f63225, 0x45f73226, 0x45f83227, 0x45f93228, 0x45fa3229, 0x46410101, 0x464200e1,
+    0x464301ce, 0x464400e0, 0x46450113, 0x464600e9, 0x4647011b, 0x464800e8, 0x4649012b, 0x464a00ed,
+    0x464b01d0, 0x464c00ec, 0x464d014d, 0x464e00f3, 0x464f01d2, 0x465000f2, 0x4651016b, 0x465200fa,
+    0x465301d4, 0x465400f9, 0x465501d6, 0x465601d8, 0x465701da, 0x465801dc, 0x465900fc, 0x465a00ea,
+    0x46653105, 0x46663106, 0x46673107, 0x46683108, 0x46693109, 0x466a310a, 0x466b310b, 0x466c310c,
+    0x466d310d, 0x466e310e, 0x466f310f, 0x46703110, 0x46713111, 0x46723112, 0x46733113, 0x46743114,
+    0x46753115, 0x46763116, 0x46773117, 0x46783118, 0x46793119, 0x467a311a, 0x467b311b, 0x467c311c,
+    0x467d311d, 0x467e311e, 0x467f311f, 0x46803120, 0x46813121, 0x46823122, 0x46833123, 0x46843124,
+    0x46853125, 0x46863126, 0x46873127, 0x46883128, 0x46893129, 0x46a42500, 0x46a52501, 0x46a62502,
+    0x46a72503, 0x46a82504, 0x46a92505, 0x46aa2506, 0x46ab2507, 0x46ac2508, 0x46ad2509, 0x46ae250a,
+    0x46af250b, 0x46b0250c, 0x46b1250d, 0x46b2250e, 0x46b3250f, 0x46b42510, 0x46b52511, 0x46b62512,
+    0x46b72513, 0x46b82514, 0x46b92515, 0x46ba2516, 0x46bb2517, 0x46bc2518, 0x46bd2519, 0x46be251a,
+    0x46bf251b, 0x46c0251c, 0x46c1251d, 0x46c2251e, 0x46c3251f, 0x46c42520, 0x46c52521, 0x46c62522,
+    0x46c72523, 0x46c82524, 0x46c92525, 0x46ca2526, 0x46cb2527, 0x46cc2528, 0x46cd2529, 0x46ce252a,
+    0x46cf252b, 0x46d0252c, 0x46d1252d, 0x46d2252e, 0x46d3252f, 0x46d42530, 0x46d52531, 0x46d62532,
+    0x46d72533, 0x46d82534, 0x46d92535, 0x46da2536, 0x46db2537, 0x46dc2538, 0x46dd2539, 0x46de253a,
+    0x46df253b, 0x46e0253c, 0x46e1253d, 0x46e2253e, 0x46e3253f, 0x46e42540, 0x46e52541, 0x46e62542,
+    0x46e72543, 0x46e82544, 0x46e92545, 0x46ea2546, 0x46eb2547, 0x46ec2548, 0x46ed2549, 0x46ee254a,
+    0x46ef254b, 0x48a0554a, 0x48a1963f, 0x48a257c3, 0x48a36328, 0x48a454ce, 0x48a55509, 0x48a654c0,
+    0x48a77691, 0x48a8764c, 0x48a9853c, 0x48aa77ee, 0x48ab827e, 0x48ac788d, 0x48ad7231, 0x48ae9698,
+    0x48af978d, 0x48b06c28, 0x48b15b89, 0x48b24ffa, 0x48b36309, 0x48b46697, 0x48b55cb8, 0x48b680fa,
+    0x48b76848, 0x48b880ae, 0x48b96602, 0x48ba76ce, 0x48bb51f9, 0x48bc6556, 0x48bd71ac, 0x48be7ff1,
+    0x48bf8884, 0x48c050b2, 0x48c15965, 0x48c261ca, 0x48c36fb3, 0x48c482ad, 0x48c5634c, 0x48c66252,
+    0x48c753ed, 0x48c85427, 0x48c97b06, 0x48ca516b, 0x48cb75a4, 0x48cc5df4, 0x48cd62d4, 0x48ce8dcb,
+    0x48cf9776, 0x48d0628a, 0x48d18019, 0x48d2575d, 0x48d39738, 0x48d47f62, 0x48d57238, 0x48d6767d,
+    0x48d767cf, 0x48d8767e, 0x48d96446, 0x48da4f70, 0x48db8d25, 0x48dc62dc, 0x48dd7a17, 0x48de6591,
+    0x48df73ed, 0x48e0642c, 0x48e16273, 0x48e2822c, 0x48e39881, 0x48e4677f, 0x48e57248, 0x48e6626e,
+    0x48e762cc, 0x48e84f34, 0x48e974e3, 0x48ea534a, 0x48eb529e, 0x48ec7eca, 0x48ed90a6, 0x48ee5e2e,
+    0x48ef6886, 0x48f0699c, 0x48f18180, 0x48f27ed1, 0x48f368d2, 0x48f478c5, 0x48f5868c, 0x48f69551,
+    0x48f7508d, 0x48f88c24, 0x48f982de, 0x48fa80de, 0x48fb5305, 0x48fc8912, 0x48fd5265, 0x49418584,
+    0x494296f9, 0x49434fdd, 0x49445821, 0x49459971, 0x49465b9d, 0x494762b1, 0x494862a5, 0x494966b4,
+    0x494a8c79, 0x494b9c8d, 0x494c7206, 0x494d676f, 0x494e7891, 0x494f60b2, 0x49505351, 0x49515317,
+    0x49528f88, 0x495380cc, 0x49548d1d, 0x495594a1, 0x4956500d, 0x495772c8, 0x49585907, 0x495960eb,
+    0x495a7119, 0x495b88ab, 0x495c5954, 0x495d82ef, 0x495e672c, 0x495f7b28, 0x49605d29, 0x49617ef7,
+    0x4962752d, 0x49636cf5, 0x49648e66, 0x49658ff8, 0x4966903c, 0x49679f3b, 0x49686bd4, 0x49699119,
+    0x496a7b14, 0x496b5f7c, 0x496c78a7, 0x496d84d6, 0x496e853d, 0x496f6bd5, 0x49706bd9, 0x49716bd6,
+    0x49725e01, 0x49735e87, 0x497475f9, 0x497595ed, 0x4976655d, 0x49775f0a, 0x49785fc5, 0x49798f9f,
+    0x497a58c1, 0x497b81c2, 0x497c907f, 0x497d965b, 0x497e97ad, 0x497f8fb9, 0x49817f16, 0x49828d2c,
+    0x49836241, 0x49844fbf, 0x498553d8, 0x4986535e, 0x49878fa8, 0x49888fa9, 0x49898fab, 0x498a904d,
+    0x498b6807, 0x498c5f6a, 0x498d8198, 0x498e8868, 0x498f9cd6, 0x4990618b, 0x4991522b, 0x4992762a,
+    0x49935f6c, 0x4994658c, 0x49956fd2, 0x49966ee8, 0x49975bbe, 0x49986448, 0x49995175, 0x499a51b0,
+    0x499b67c4, 0x499c4e19, 0x499d79c9, 0x499e997c, 0x499f70b3, 0x49a075c5, 0x49a15e76, 0x49a273bb,
+    0x49a383e0, 0x49a464ad, 0x49a562e8, 0x49a694b5, 0x49a76ce2, 0x49a8535a, 0x49a952c3, 0x49aa640f,
+    0x49ab94c2, 0x49ac7b94, 0x49ad4f2f, 0x49ae5e1b, 0x49af8236, 0x49b08116, 0x49b1818a, 0x49b26e24,
+    0x49b36cca, 0x49b49a73, 0x49b56355, 0x49b6535c, 0x49b754fa, 0x49b88865, 0x49b957e0, 0x49ba4e0d,
+    0x49bb5e03, 0x49bc6b65, 0x49bd7c3f, 0x49be90e8, 0x49bf6016, 0x49c064e6, 0x49c1731c, 0x49c288c1,
+    0x49c36750, 0x49c4624d, 0x49c58d22, 0x49c6776c, 0x49c78e29, 0x49c891c7, 0x49c95f69, 0x49ca83dc,
+    0x49cb8521, 0x49cc9910, 0x49cd53c2, 0x49ce8695, 0x49cf6b8b, 0x49d060ed, 0x49d160e8, 0x49d2707f,
+    0x49d382cd, 0x49d48231, 0x49d54ed3, 0x49d66ca7, 0x49d785cf, 0x49d864cd, 0x49d97cd9, 0x49da69fd,
+    0x49db66f9, 0x49dc8349, 0x49dd5395, 0x49de7b56, 0x49df4fa7, 0x49e0518c, 0x49e16d4b, 0x49e25c42,
+    0x49e38e6d, 0x49e463d2, 0x49e553c9, 0x49e6832c, 0x49e78336, 0x49e867e5, 0x49e978b4, 0x49ea643d,
+    0x49eb5bdf, 0x49ec5c94, 0x49ed5dee, 0x49ee8be7, 0x49ef62c6, 0x49f067f4, 0x49f18c7a, 0x49f26400,
+    0x49f363ba, 0x49f48749, 0x49f5998b, 0x49f68c17, 0x49f77f20, 0x49f894f2, 0x49f94ea7, 0x49fa9610,
+    0x49fb98a4, 0x49fc660c, 0x49fd7316, 0x4a41573a, 0x4a425c1d, 0x4a435e38, 0x4a44957f, 0x4a45507f,
+    0x4a4680a0, 0x4a475382, 0x4a48655e, 0x4a497545, 0x4a4a5531, 0x4a4b5021, 0x4a4c8d85, 0x4a4d6284,
+    0x4a4e949e, 0x4a4f671d, 0x4a505632, 0x4a516f6e, 0x4a525de2, 0x4a535435, 0x4a547092, 0x4a558f66,
+    0x4a56626f, 0x4a5764a4, 0x4a5863a3, 0x4a595f7b, 0x4a5a6f88, 0x4a5b90f4, 0x4a5c81e3, 0x4a5d8fb0,
+    0x4a5e5c18, 0x4a5f6668, 0x4a605ff1, 0x4a616c89, 0x4a629648, 0x4a638d81, 0x4a64886c, 0x4a656491,
+    0x4a6679f0, 0x4a6757ce, 0x4a686a59, 0x4a696210, 0x4a6a5448, 0x4a6b4e58, 0x4a6c7a0b, 0x4a6d60e9,
+    0x4a6e6f84, 0x4a6f8bda, 0x4a70627f, 0x4a71901e, 0x4a729a8b, 0x4a7379e4, 0x4a745403, 0x4a7575f4,
+    0x4a766301, 0x4a775319, 0x4a786c60, 0x4a798fdf, 0x4a7a5f1b, 0x4a7b9a70, 0x4a7c803b, 0x4a7d9f7f,
+    0x4a7e4f88, 0x4a7f5c3a, 0x4a818d64, 0x4a827fc5, 0x4a8365a5, 0x4a8470bd, 0x4a855145, 0x4a8651b2,
+    0x4a87866b, 0x4a885d07, 0x4a895ba0, 0x4a8a62bd, 0x4a8b916c, 0x4a8c7574, 0x4a8d8e0c, 0x4a8e7a20,
+    0x4a8f6101, 0x4a907b79, 0x4a914ec7, 0x4a927ef8, 0x4a937785, 0x4a944e11, 0x4a9581ed, 0x4a96521d,
+    0x4a9751fa, 0x4a986a71, 0x4a9953a8, 0x4a9a8e87, 0x4a9b9504, 0x4a9c96cf, 0x4a9d6ec1, 0x4a9e9664,
+    0x4a9f695a, 0x4aa07840, 0x4aa150a8, 0x4aa277d7, 0x4aa36410, 0x4aa489e6, 0x4aa55904, 0x4aa663e3,
+    0x4aa75ddd, 0x4aa87a7f, 0x4aa9693d, 0x4aaa4f20, 0x4aab8239, 0x4aac5598, 0x4aad4e32, 0x4aae75ae,
+    0x4aaf7a97, 0x4ab05e62, 0x4ab15e8a, 0x4ab295ef, 0x4ab3521b, 0x4ab45439, 0x4ab5708a, 0x4ab66376,
+    0x4ab79524, 0x4ab85782, 0x4ab96625, 0x4aba693f, 0x4abb9187, 0x4abc5507, 0x4abd6df3, 0x4abe7eaf,
+    0x4abf8822, 0x4ac06233, 0x4ac17ef0, 0x4ac275b5, 0x4ac38328, 0x4ac478c1, 0x4ac596cc, 0x4ac68f9e,
+    0x4ac76148, 0x4ac874f7, 0x4ac98bcd, 0x4aca6b64, 0x4acb523a, 0x4acc8d50, 0x4acd6b21, 0x4ace806a,
+    0x4acf8471, 0x4ad056f1, 0x4ad15306, 0x4ad24ece, 0x4ad34e1b, 0x4ad451d1, 0x4ad57c97, 0x4ad6918b,
+    0x4ad77c07, 0x4ad84fc3, 0x4ad98e7f, 0x4ada7be1, 0x4adb7a9c, 0x4adc6467, 0x4add5d14, 0x4ade50ac,
+    0x4adf8106, 0x4ae07601, 0x4ae17cb9, 0x4ae26dec, 0x4ae37fe0, 0x4ae46751, 0x4ae55b58, 0x4ae65bf8,
+    0x4ae778cb, 0x4ae864ae, 0x4ae96413, 0x4aea63aa, 0x4aeb632b, 0x4aec9519, 0x4aed642d, 0x4aee8fbe,
+    0x4aef7b54, 0x4af07629, 0x4af16253, 0x4af25927, 0x4af35446, 0x4af46b79, 0x4af550a3, 0x4af66234,
+    0x4af75e26, 0x4af86b86, 0x4af94ee3, 0x4afa8d37, 0x4afb888b, 0x4afc5f85, 0x4afd902e, 0x4b416020,
+    0x4b42803d, 0x4b4362c5, 0x4b444e39, 0x4b455355, 0x4b4690f8, 0x4b4763b8, 0x4b4880c6, 0x4b4965e6,
+    0x4b4a6c2e, 0x4b4b4f46, 0x4b4c60ee, 0x4b4d6de1, 0x4b4e8bde, 0x4b4f5f39, 0x4b5086cb, 0x4b515f53,
+    0x4b526321, 0x4b53515a, 0x4b548361, 0x4b556863, 0x4b565200, 0x4b576363, 0x4b588e48, 0x4b595012,
+    0x4b5a5c9b, 0x4b5b7977, 0x4b5c5bfc, 0x4b5d5230, 0x4b5e7a3b, 0x4b5f60bc, 0x4b609053, 0x4b6176d7,
+    0x4b625fb7, 0x4b635f97, 0x4b647684, 0x4b658e6c, 0x4b66706f, 0x4b67767b, 0x4b687b49, 0x4b6977aa,
+    0x4b6a51f3, 0x4b6b9093, 0x4b6c5824, 0x4b6d4f4e, 0x4b6e6ef4, 0x4b6f8fea, 0x4b70654c, 0x4b717b1b,
+    0x4b7272c4, 0x4b736da4, 0x4b747fdf, 0x4b755ae1, 0x4b7662b5, 0x4b775e95, 0x4b785730, 0x4b798482,
+    0x4b7a7b2c, 0x4b7b5e1d, 0x4b7c5f1f, 0x4b7d9012, 0x4b7e7f14, 0x4b7f98a0, 0x4b816382, 0x4b826ec7,
+    0x4b837898, 0x4b8470b9, 0x4b855178, 0x4b86975b, 0x4b8757ab, 0x4b887535, 0x4b894f43, 0x4b8a7538,
+    0x4b8b5e97, 0x4b8c60e6, 0x4b8d5960, 0x4b8e6dc0, 0x4b8f6bbf, 0x4b907889, 0x4b9153fc, 0x4b9296d5,
+    0x4b9351cb, 0x4b945201, 0x4b956389, 0x4b96540a, 0x4b979493, 0x4b988c03, 0x4b998dcc, 0x4b9a7239,
+    0x4b9b789f, 0x4b9c8776, 0x4b9d8fed, 0x4b9e8c0d, 0x4b9f53e0, 0x4ba04e01, 0x4ba176ef, 0x4ba253ee,
+    0x4ba39489, 0x4ba49876, 0x4ba59f0e, 0x4ba6952d, 0x4ba75b9a, 0x4ba88ba2, 0x4ba94e22, 0x4baa4e1c,
+    0x4bab51ac, 0x4bac8463, 0x4bad61c2, 0x4bae52a8, 0x4baf680b, 0x4bb04f97, 0x4bb1606b, 0x4bb251bb,
+    0x4bb36d1e, 0x4bb4515c, 0x4bb56296, 0x4bb66597, 0x4bb79661, 0x4bb88c46, 0x4bb99017, 0x4bba75d8,
+    0x4bbb90fd, 0x4bbc7763, 0x4bbd6bd2, 0x4bbe728a, 0x4bbf72ec, 0x4bc08bfb, 0x4bc15835, 0x4bc27779,
+    0x4bc38d4c, 0x4bc4675c, 0x4bc59540, 0x4bc6809a, 0x4bc75ea6, 0x4bc86e21, 0x4bc95992, 0x4bca7aef,
+    0x4bcb77ed, 0x4bcc953b, 0x4bcd6bb5, 0x4bce65ad, 0x4bcf7f0e, 0x4bd05806, 0x4bd15151, 0x4bd2961f,
+    0x4bd35bf9, 0x4bd458a9, 0x4bd55428, 0x4bd68e72, 0x4bd76566, 0x4bd8987f, 0x4bd956e4, 0x4bda949d,
+    0x4bdb76fe, 0x4bdc9041, 0x4bdd6387, 0x4bde54c6, 0x4bdf591a, 0x4be0593a, 0x4be1579b, 0x4be28eb2,
+    0x4be36735, 0x4be48dfa, 0x4be58235, 0x4be65241, 0x4be760f0, 0x4be85815, 0x4be986fe, 0x4bea5ce8,
+    0x4beb9e45, 0x4bec4fc4, 0x4bed989d, 0x4bee8bb9, 0x4bef5a25, 0x4bf06076, 0x4bf15384, 0x4bf2627c,
+    0x4bf3904f, 0x4bf49102, 0x4bf5997f, 0x4bf66069, 0x4bf7800c, 0x4bf8513f, 0x4bf98033, 0x4bfa5c14,
+    0x4bfb9975, 0x4bfc6d31, 0x4bfd4e8c, 0x4c418d30, 0x4c4253d1, 0x4c437f5a, 0x4c447b4f, 0x4c454f10,
+    0x4c464e4f, 0x4c479600, 0x4c486cd5, 0x4c4973d0, 0x4c4a85e9, 0x4c4b5e06, 0x4c4c756a, 0x4c4d7ffb,
+    0x4c4e6a0a, 0x4c4f77fe, 0x4c509492, 0x4c517e41, 0x4c5251e1, 0x4c5370e6, 0x4c5453cd, 0x4c558fd4,
+    0x4c568303, 0x4c578d29, 0x4c5872af, 0x4c59996d, 0x4c5a6cdb, 0x4c5b574a, 0x4c5c82b3, 0x4c5d65b9,
+    0x4c5e80aa, 0x4c5f623f, 0x4c609632, 0x4c6159a8, 0x4c624eff, 0x4c638bbf, 0x4c647eba, 0x4c65653e,
+    0x4c6683f2, 0x4c67975e, 0x4c685561, 0x4c6998de, 0x4c6a80a5, 0x4c6b532a, 0x4c6c8bfd, 0x4c6d5420,
+    0x4c6e80ba, 0x4c6f5e9f, 0x4c706cb8, 0x4c718d39, 0x4c7282ac, 0x4c73915a, 0x4c745429, 0x4c756c1b,
+    0x4c765206, 0x4c777eb7, 0x4c78575f, 0x4c79711a, 0x4c7a6c7e, 0x4c7b7c89, 0x4c7c594b, 0x4c7d4efd,
+    0x4c7e5fff, 0x4c7f6124, 0x4c817caa, 0x4c824e30, 0x4c835c01, 0x4c8467ab, 0x4c858702, 0x4c865cf0,
+    0x4c87950b, 0x4c8898ce, 0x4c8975af, 0x4c8a70fd, 0x4c8b9022, 0x4c8c51af, 0x4c8d7f1d, 0x4c8e8bbd,
+    0x4c8f5949, 0x4c9051e4, 0x4c914f5b, 0x4c925426, 0x4c93592b, 0x4c946577, 0x4c9580a4, 0x4c965b75,
+    0x4c976276, 0x4c9862c2, 0x4c998f90, 0x4c9a5e45, 0x4c9b6c1f, 0x4c9c7b26, 0x4c9d4f0f, 0x4c9e4fd8,
+    0x4c9f670d, 0x4ca06d6e, 0x4ca16daa, 0x4ca2798f, 0x4ca388b1, 0x4ca45f17, 0x4ca5752b, 0x4ca6629a,
+    0x4ca78f85, 0x4ca84fef, 0x4ca991dc, 0x4caa65a7, 0x4cab812f, 0x4cac8151, 0x4cad5e9c, 0x4cae8150,
+    0x4caf8d74, 0x4cb0526f, 0x4cb18986, 0x4cb28d4b, 0x4cb3590d, 0x4cb45085, 0x4cb54ed8, 0x4cb6961c,
+    0x4cb77236, 0x4cb88179, 0x4cb98d1f, 0x4cba5bcc, 0x4cbb8ba3, 0x4cbc9644, 0x4cbd5987, 0x4cbe7f1a,
+    0x4cbf5490, 0x4cc05676, 0x4cc1560e, 0x4cc28be5, 0x4cc36539, 0x4cc46982, 0x4cc59499, 0x4cc676d6,
+    0x4cc76e89, 0x4cc85e72, 0x4cc97518, 0x4cca6746, 0x4ccb67d1, 0x4ccc7aff, 0x4ccd809d, 0x4cce8d76,
+    0x4ccf611f, 0x4cd079c6, 0x4cd16562, 0x4cd28d63, 0x4cd35188, 0x4cd4521a, 0x4cd594a2, 0x4cd67f38,
+    0x4cd7809b, 0x4cd87eb2, 0x4cd95c97, 0x4cda6e2f, 0x4cdb6760, 0x4cdc7bd9, 0x4cdd768b, 0x4cde9ad8,
+    0x4cdf818f, 0x4ce07f94, 0x4ce17cd5, 0x4ce2641e, 0x4ce39550, 0x4ce47a3f, 0x4ce5544a, 0x4ce654e5,
+    0x4ce76b4c, 0x4ce86401, 0x4ce96208, 0x4cea9e3d, 0x4ceb80f3, 0x4cec7599, 0x4ced5272, 0x4cee9769,
+    0x4cef845b, 0x4cf0683c, 0x4cf186e4, 0x4cf29601, 0x4cf39694, 0x4cf494ec, 0x4cf54e2a, 0x4cf65404,
+    0x4cf77ed9, 0x4cf86839, 0x4cf98ddf, 0x4cfa8015, 0x4cfb66f4, 0x4cfc5e9a, 0x4cfd7fb9, 0x4d4157c2,
+    0x4d42803f, 0x4d436897, 0x4d445de5, 0x4d45653b, 0x4d46529f, 0x4d47606d, 0x4d489f9a, 0x4d494f9b,
+    0x4d4a8eac, 0x4d4b516c, 0x4d4c5bab, 0x4d4d5f13, 0x4d4e5de9, 0x4d4f6c5e, 0x4d5062f1, 0x4d518d21,
+    0x4d525171, 0x4d5394a9, 0x4d5452fe, 0x4d556c9f, 0x4d5682df, 0x4d5772d7, 0x4d5857a2, 0x4d596784,
+    0x4d5a8d2d, 0x4d5b591f, 0x4d5c8f9c, 0x4d5d83c7, 0x4d5e5495, 0x4d5f7b8d, 0x4d604f30, 0x4d616cbd,
+    0x4d625b64, 0x4d6359d1, 0x4d649f13, 0x4d6553e4, 0x4d6686ca, 0x4d679aa8, 0x4d688c37, 0x4d6980a1,
+    0x4d6a6545, 0x4d6b987e, 0x4d6c56fa, 0x4d6d96c7, 0x4d6e522e, 0x4d6f74dc, 0x4d705250, 0x4d715be1,
+    0x4d726302, 0x4d738902, 0x4d744e56, 0x4d7562d0, 0x4d76602a, 0x4d7768fa, 0x4d785173, 0x4d795b98,
+    0x4d7a51a0, 0x4d7b89c2, 0x4d7c7ba1, 0x4d7d9986, 0x4d7e7f50, 0x4d7f60ef, 0x4d81704c, 0x4d828d2f,
+    0x4d835149, 0x4d845e7f, 0x4d85901b, 0x4d867470, 0x4d8789c4, 0x4d88572d, 0x4d897845, 0x4d8a5f52,
+    0x4d8b9f9f, 0x4d8c95fa, 0x4d8d8f68, 0x4d8e9b3c, 0x4d8f8be1, 0x4d907678, 0x4d916842, 0x4d9267dc,
+    0x4d938dea, 0x4d948d35, 0x4d95523d, 0x4d968f8a, 0x4d976eda, 0x4d9868cd, 0x4d999505, 0x4d9a90ed,
+    0x4d9b56fd, 0x4d9c679c, 0x4d9d88f9, 0x4d9e8fc7, 0x4d9f54c8, 0x4da09ab8, 0x4da15b69, 0x4da26d77,
+    0x4da36c26, 0x4da44ea5, 0x4da55bb3, 0x4da69a87, 0x4da79163, 0x4da861a8, 0x4da990af, 0x4daa97e9,
+    0x4dab542b, 0x4dac6db5, 0x4dad5bd2, 0x4dae51fd, 0x4daf558a, 0x4db07f55, 0x4db17ff0, 0x4db264bc,
+    0x4db3634d, 0x4db465f1, 0x4db561be, 0x4db6608d, 0x4db7710a, 0x4db86c57, 0x4db96c49, 0x4dba592f,
+    0x4dbb676d, 0x4dbc822a, 0x4dbd58d5, 0x4dbe568e, 0x4dbf8c6a, 0x4dc06beb, 0x4dc190dd, 0x4dc2597d,
+    0x4dc38017, 0x4dc453f7, 0x4dc56d69, 0x4dc65475, 0x4dc7559d, 0x4dc88377, 0x4dc983cf, 0x4dca6838,
+    0x4dcb79be, 0x4dcc548c, 0x4dcd4f55, 0x4dce5408, 0x4dcf76d2, 0x4dd08c89, 0x4dd19602, 0x4dd26cb3,
+    0x4dd36db8, 0x4dd48d6b, 0x4dd58910, 0x4dd69e64, 0x4dd78d3a, 0x4dd8563f, 0x4dd99ed1, 0x4dda75d5,
+    0x4ddb5f88, 0x4ddc72e0, 0x4ddd6068, 0x4dde54fc, 0x4ddf4ea8, 0x4de06a2a, 0x4de18861, 0x4de26052,
+    0x4de38f70, 0x4de454c4, 0x4de570d8, 0x4de68679, 0x4de79e3f, 0x4de86d2a, 0x4de95b8f, 0x4dea5f18,
+    0x4deb7ea2, 0x4dec5589, 0x4ded4faf, 0x4dee7334, 0x4def543c, 0x4df0539a, 0x4df15019, 0x4df2540e,
+    0x4df3547c, 0x4df44e4e, 0x4df55ffd, 0x4df6745a, 0x4df758f6, 0x4df8846b, 0x4df980e1, 0x4dfa8774,
+    0x4dfb72d0, 0x4dfc7cca, 0x4dfd6e56, 0x4e415f27, 0x4e42864e, 0x4e43552c, 0x4e4462a4, 0x4e454e92,
+    0x4e466caa, 0x4e476237, 0x4e4882b1, 0x4e4954d7, 0x4e4a534e, 0x4e4b733e, 0x4e4c6ed1, 0x4e4d753b,
+    0x4e4e5212, 0x4e4f5316, 0x4e508bdd, 0x4e5169d0, 0x4e525f8a, 0x4e536000, 0x4e546dee, 0x4e55574f,
+    0x4e566b22, 0x4e5773af, 0x4e586853, 0x4e598fd8, 0x4e5a7f13, 0x4e5b6362, 0x4e5c60a3, 0x4e5d5524,
+    0x4e5e75ea, 0x4e5f8c62, 0x4e607115, 0x4e616da3, 0x4e625ba6, 0x4e635e7b, 0x4e648352, 0x4e65614c,
+    0x4e669ec4, 0x4e6778fa, 0x4e688757, 0x4e697c27, 0x4e6a7687, 0x4e6b51f0, 0x4e6c60f6, 0x4e6d714c,
+    0x4e6e6643, 0x4e6f5e4c, 0x4e70604d, 0x4e718c0e, 0x4e727070, 0x4e736325, 0x4e748f89, 0x4e755fbd,
+    0x4e766062, 0x4e7786d4, 0x4e7856de, 0x4e796bc1, 0x4e7a6094, 0x4e7b6167, 0x4e7c5349, 0x4e7d60e0,
+    0x4e7e6666, 0x4e7f8d3f, 0x4e8179fd, 0x4e824f1a, 0x4e8370e9, 0x4e846c47, 0x4e858bb3, 0x4e868bf2,
+    0x4e877ed8, 0x4e888364, 0x4e89660f, 0x4e8a5a5a, 0x4e8b9b42, 0x4e8c6d51, 0x4e8d6df7, 0x4e8e8c41,
+    0x4e8f6d3b, 0x4e904f19, 0x4e91706b, 0x4e9283b7, 0x4e936216, 0x4e9460d1, 0x4e95970d, 0x4e968d27,
+    0x4e977978, 0x4e9851fb, 0x4e99573e, 0x4e9a57fa, 0x4e9b673a, 0x4e9c7578, 0x4e9d7a3d, 0x4e9e79ef,
+    0x4e9f7b95, 0x4ea0808c, 0x4ea19965, 0x4ea28ff9, 0x4ea36fc0, 0x4ea48ba5, 0x4ea59e21, 0x4ea659ec,
+    0x4ea77ee9, 0x4ea87f09, 0x4ea95409, 0x4eaa6781, 0x4eab68d8, 0x4eac8f91, 0x4ead7c4d, 0x4eae96c6,
+    0x4eaf53ca, 0x4eb06025, 0x4eb175be, 0x4eb26c72, 0x4eb35373, 0x4eb45ac9, 0x4eb57ea7, 0x4eb66324,
+    0x4eb751e0, 0x4eb8810a, 0x4eb95df1, 0x4eba84df, 0x4ebb6280, 0x4ebc5180, 0x4ebd5b63, 0x4ebe4f0e,
+    0x4ebf796d, 0x4ec05242, 0x4ec160b8, 0x4ec26d4e, 0x4ec35bc4, 0x4ec45bc2, 0x4ec58ba1, 0x4ec68bb0,
+    0x4ec765e2, 0x4ec85fcc, 0x4ec99645, 0x4eca5993, 0x4ecb7ee7, 0x4ecc7eaa, 0x4ecd5609, 0x4ece67b7,
+    0x4ecf5939, 0x4ed04f73, 0x4ed15bb6, 0x4ed252a0, 0x4ed3835a, 0x4ed4988a, 0x4ed58d3e, 0x4ed67532,
+    0x4ed794be, 0x4ed85047, 0x4ed97a3c, 0x4eda4ef7, 0x4edb67b6, 0x4edc9a7e, 0x4edd5ac1, 0x4ede6b7c,
+    0x4edf76d1, 0x4ee0575a, 0x4ee15c16, 0x4ee27b3a, 0x4ee395f4, 0x4ee4714e, 0x4ee5517c, 0x4ee680a9,
+    0x4ee78270, 0x4ee85978, 0x4ee97f04, 0x4eea8327, 0x4eeb68c0, 0x4eec67ec, 0x4eed78b1, 0x4eee7877,
+    0x4eef62e3, 0x4ef06361, 0x4ef17b80, 0x4ef24fed, 0x4ef3526a, 0x4ef451cf, 0x4ef58350, 0x4ef669db,
+    0x4ef79274, 0x4ef88df5, 0x4ef98d31, 0x4efa89c1, 0x4efb952e, 0x4efc7bad, 0x4efd4ef6, 0x4f415065,
+    0x4f428230, 0x4f435251, 0x4f44996f, 0x4f456e10, 0x4f466e85, 0x4f476da7, 0x4f485efa, 0x4f4950f5,
+    0x4f4a59dc, 0x4f4b5c06, 0x4f4c6d46, 0x4f4d6c5f, 0x4f4e7586, 0x4f4f848b, 0x4f506868, 0x4f515956,
+    0x4f528bb2, 0x4f535320, 0x4f549171, 0x4f55964d, 0x4f568549, 0x4f576912, 0x4f587901, 0x4f597126,
+    0x4f5a80f6, 0x4f5b4ea4, 0x4f5c90ca, 0x4f5d6d47, 0x4f5e9a84, 0x4f5f5a07, 0x4f6056bc, 0x4f616405,
+    0x4f6294f0, 0x4f6377eb, 0x4f644fa5, 0x4f65811a, 0x4f6672e1, 0x4f6789d2, 0x4f68997a, 0x4f697f34,
+    0x4f6a7ede, 0x4f6b527f, 0x4f6c6559, 0x4f6d9175, 0x4f6e8f7f, 0x4f6f8f83, 0x4f7053eb, 0x4f717a96,
+    0x4f7263ed, 0x4f7363a5, 0x4f747686, 0x4f7579f8, 0x4f768857, 0x4f779636, 0x4f78622a, 0x4f7952ab,
+    0x4f7a8282, 0x4f7b6854, 0x4f7c6770, 0x4f7d6377, 0x4f7e776b, 0x4f7f7aed, 0x4f816d01, 0x4f827ed3,
+    0x4f8389e3, 0x4f8459d0, 0x4f856212, 0x4f8685c9, 0x4f8782a5, 0x4f88754c, 0x4f89501f, 0x4f8a4ecb,
+    0x4f8b75a5, 0x4f8c8beb, 0x4f8d5c4a, 0x4f8e5dfe, 0x4f8f7b4b, 0x4f9065a4, 0x4f9191d1, 0x4f924eca,
+    0x4f936d25, 0x4f94895f, 0x4f957d27, 0x4f969526, 0x4f974ec5, 0x4f988c28, 0x4f998fdb, 0x4f9a9773,
+    0x4f9b664b, 0x4f9c7981, 0x4f9d8fd1, 0x4f9e70ec, 0x4f9f6d78, 0x4fa05c3d, 0x4fa152b2, 0x4fa28346,
+    0x4fa35162, 0x4fa4830e, 0x4fa5775b, 0x4fa66676, 0x4fa79cb8, 0x4fa84eac, 0x4fa960ca, 0x4faa7cbe,
+    0x4fab7cb3, 0x4fac7ecf, 0x4fad4e95, 0x4fae8b66, 0x4faf666f, 0x4fb09888, 0x4fb19759, 0x4fb25883,
+    0x4fb3656c, 0x4fb4955c, 0x4fb55f84, 0x4fb675c9, 0x4fb79756, 0x4fb87adf, 0x4fb97ade, 0x4fba51c0,
+    0x4fbb70af, 0x4fbc7a98, 0x4fbd63ea, 0x4fbe7a76, 0x4fbf7ea0, 0x4fc07396, 0x4fc197ed, 0x4fc24e45,
+    0x4fc37078, 0x4fc44e5d, 0x4fc59152, 0x4fc653a9, 0x4fc76551, 0x4fc865e7, 0x4fc981fc, 0x4fca8205,
+    0x4fcb548e, 0x4fcc5c31, 0x4fcd759a, 0x4fce97a0, 0x4fcf62d8, 0x4fd072d9, 0x4fd175bd, 0x4fd25c45,
+    0x4fd39a79, 0x4fd483ca, 0x4fd55c40, 0x4fd65480, 0x4fd777e9, 0x4fd84e3e, 0x4fd96cae, 0x4fda805a,
+    0x4fdb62d2, 0x4fdc636e, 0x4fdd5de8, 0x4fde5177, 0x4fdf8ddd, 0x4fe08e1e, 0x4fe1952f, 0x4fe24ff1,
+    0x4fe353e5, 0x4fe460e7, 0x4fe570ac, 0x4fe65267, 0x4fe76350, 0x4fe89e43, 0x4fe95a1f, 0x4fea5026,
+    0x4feb7737, 0x4fec5377, 0x4fed7ee2, 0x4fee6485, 0x4fef652b, 0x4ff06289, 0x4ff16398, 0x4ff25014,
+    0x4ff37235, 0x4ff489c9, 0x4ff551b3, 0x4ff68bc0, 0x4ff77edd, 0x4ff85747, 0x4ff983cc, 0x4ffa94a7,
+    0x4ffb519b, 0x4ffc541b, 0x4ffd5cfb, 0x50414fca, 0x50427ae3, 0x50436d5a, 0x504490e1, 0x50459a8f,
+    0x50465580, 0x50475496, 0x50485361, 0x504954af, 0x504a5f00, 0x504b63e9, 0x504c6977, 0x504d51ef,
+    0x504e6168, 0x504f520a, 0x5050582a, 0x505152d8, 0x5052574e, 0x5053780d, 0x5054770b, 0x50555eb7,
+    0x50566177, 0x50577ce0, 0x5058625b, 0x50596297, 0x505a4ea2, 0x505b7095, 0x505c8003, 0x505d62f7,
+    0x505e70e4, 0x505f9760, 0x50605777, 0x506182db, 0x506267ef, 0x506368f5, 0x506478d5, 0x50659897,
+    0x506679d1, 0x506758f3, 0x506854b3, 0x506953ef, 0x506a6e34, 0x506b514b, 0x506c523b, 0x506d5ba2,
+    0x506e8bfe, 0x506f80af, 0x50705543, 0x507157a6, 0x50726073, 0x50735751, 0x5074542d, 0x50757a7a,
+    0x50766050, 0x50775b54, 0x507863a7, 0x507962a0, 0x507a53e3, 0x507b6263, 0x507c5bc7, 0x507d67af,
+    0x507e54ed, 0x507f7a9f, 0x508182e6, 0x50829177, 0x50835e93, 0x508488e4, 0x50855938, 0x508657ae,
+    0x5087630e, 0x50888de8, 0x508980ef, 0x508a5757, 0x508b7b77, 0x508c4fa9, 0x508d5feb, 0x508e5bbd,
+    0x508f6b3e, 0x50905321, 0x50917b50, 0x509272c2, 0x50936846, 0x509477ff, 0x50957736, 0x509665f7,
+    0x509751b5, 0x50984e8f, 0x509976d4, 0x509a5cbf, 0x509b7aa5, 0x509c8475, 0x509d594e, 0x509e9b41,
+    0x509f5080, 0x50a09988, 0x50a16127, 0x50a26e83, 0x50a35764, 0x50a46606, 0x50a56346, 0x50a656f0,
+    0x50a762ec, 0x50a86269, 0x50a95ed3, 0x50aa9614, 0x50ab5783, 0x50ac62c9, 0x50ad5587, 0x50ae8721,
+    0x50af814a, 0x50b08fa3, 0x50b15566, 0x50b283b1, 0x50b36765, 0x50b48d56, 0x50b584dd, 0x50b65a6a,
+    0x50b7680f, 0x50b862e6, 0x50b97bee, 0x50ba9611, 0x50bb5170, 0x50bc6f9c, 0x50bd8c30, 0x50be63fd,
+    0x50bf89c8, 0x50c061d2, 0x50c17f06, 0x50c270c2, 0x50c36ee5, 0x50c47405, 0x50c56994, 0x50c672fc,
+    0x50c75eca, 0x50c890ce, 0x50c96717, 0x50ca6d6a, 0x50cb635e, 0x50cc52b3, 0x50cd7262, 0x50ce8001,
+    0x50cf4f6c, 0x50d059e5, 0x50d1916a, 0x50d270d9, 0x50d36d9d, 0x50d452d2, 0x50d54e50, 0x50d696f7,
+    0x50d7956d, 0x50d8857e, 0x50d978ca, 0x50da7d2f, 0x50db5121, 0x50dc5792, 0x50dd64c2, 0x50de808b,
+    0x50df7c7b, 0x50e06cea, 0x50e168f1, 0x50e2695e, 0x50e351b7, 0x50e45398, 0x50e568a8, 0x50e67281,
+    0x50e79ece, 0x50e87bf1, 0x50e972f8, 0x50ea79bb, 0x50eb6f13, 0x50ec7406, 0x50ed674e, 0x50ee91cc,
+    0x50ef9ca4, 0x50f0793c, 0x50f18389, 0x50f28354, 0x50f3540f, 0x50f46817, 0x50f54e3d, 0x50f65389,
+    0x50f752b1, 0x50f8783e, 0x50f95386, 0x50fa5229, 0x50fb5088, 0x50fc4f8b, 0x50fd4fd0, 0x514175e2,
+    0x51427acb, 0x51437c92, 0x51446ca5, 0x514596b6, 0x5146529b, 0x51477483, 0x514854e9, 0x51494fe9,
+    0x514a8054, 0x514b83b2, 0x514c8fde, 0x514d9570, 0x514e5ec9, 0x514f601c, 0x51506d9f, 0x51515e18,
+    0x5152655b, 0x51538138, 0x515494fe, 0x5155604b, 0x515670bc, 0x51577ec3, 0x51587cae, 0x515951c9,
+    0x515a6881, 0x515b7cb1, 0x515c826f, 0x515d4e24, 0x515e8f86, 0x515f91cf, 0x5160667e, 0x51614eae,
+    0x51628c05, 0x516364a9, 0x5164804a, 0x516550da, 0x51667597, 0x516771ce, 0x51685be5, 0x51698fbd,
+    0x516a6f66, 0x516b4e86, 0x516c6482, 0x516d9563, 0x516e5ed6, 0x516f6599, 0x51705217, 0x517188c2,
+    0x517270c8, 0x517352a3, 0x5174730e, 0x51757433, 0x51766797, 0x517778f7, 0x51789716, 0x51794e34,
+    0x517a90bb, 0x517b9cde, 0x517c6dcb, 0x517d51db, 0x517e8d41, 0x517f541d, 0x518162ce, 0x518273b2,
+    0x518383f1, 0x518496f6, 0x51859f84, 0x518694c3, 0x51874f36, 0x51887f9a, 0x518951cc, 0x518a7075,
+    0x518b9675, 0x518c5cad, 0x518d9886, 0x518e53e6, 0x518f4ee4, 0x51906e9c, 0x51917409, 0x519269b4,
+    0x5193786b, 0x5194998f, 0x51957559, 0x51965218, 0x51977624, 0x51986d41, 0x519967f3, 0x519a516d,
+    0x519b9f99, 0x519c804b, 0x519d5499, 0x519e7b3c, 0x519f7abf, 0x51a09686, 0x51a15784, 0x51a262e2,
+    0x51a39647, 0x51a4697c, 0x51a55a04, 0x51a66402, 0x51a77bd3, 0x51a86f0f, 0x51a9964b, 0x51aa82a6,
+    0x51ab5362, 0x51ac9885, 0x51ad5e90, 0x51ae7089, 0x51af63b3, 0x51b05364, 0x51b1864f, 0x51b29c81,
+    0x51b39e93, 0x51b4788c, 0x51b59732, 0x51b68def, 0x51b78d42, 0x51b89e7f, 0x51b96f5e, 0x51ba7984,
+    0x51bb5f55, 0x51bc9646, 0x51bd622e, 0x51be9a74, 0x51bf5415, 0x51c094dd, 0x51c14fa3, 0x51c265c5,
+    0x51c35c65, 0x51c45c61, 0x51c57f15, 0x51c68651, 0x51c76c2f, 0x51c85f8b, 0x51c97387, 0x51ca6ee4,
+    0x51cb7eff, 0x51cc5ce6, 0x51cd631b, 0x51ce5b6a, 0x51cf6ee6, 0x51d05375, 0x51d14e71, 0x51d263a0,
+    0x51d37565, 0x51d462a1, 0x51d58f6e, 0x51d64f26, 0x51d74ed1, 0x51d86ca6, 0x51d97eb6, 0x51da8bba,
+    0x51db841d, 0x51dc87ba, 0x51dd7f57, 0x51de903b, 0x51df9523, 0x51e07ba9, 0x51e19aa1, 0x51e288f8,
+    0x51e3843d, 0x51e46d1b, 0x51e59a86, 0x51e67edc, 0x51e75988, 0x51e89ebb, 0x51e9739b, 0x51ea7801,
+    0x51eb8682, 0x51ec9a6c, 0x51ed9a82, 0x51ee561b, 0x51ef5417, 0x51f057cb, 0x51f14e70, 0x51f29ea6,
+    0x51f35356, 0x51f48fc8, 0x51f58109, 0x51f67792, 0x51f79992, 0x51f886ee, 0x51f96ee1, 0x51fa8513,
+    0x51fb66fc, 0x51fc6162, 0x51fd6f2b, 0x52418c29, 0x52428292, 0x5243832b, 0x524476f2, 0x52456c13,
+    0x52465fd9, 0x524783bd, 0x5248732b, 0x52498305, 0x524a951a, 0x524b6bdb, 0x524c77db, 0x524d94c6,
+    0x524e536f, 0x524f8302, 0x52505192, 0x52515e3d, 0x52528c8c, 0x52538d38, 0x52544e48, 0x525573ab,
+    0x5256679a, 0x52576885, 0x52589176, 0x52599709, 0x525a7164, 0x525b6ca1, 0x525c7709, 0x525d5a92,
+    0x525e9541, 0x525f6bcf, 0x52607f8e, 0x52616627, 0x52625bd0, 0x526359b9, 0x52645a9a, 0x526595e8,
+    0x526695f7, 0x52674eec, 0x5268840c, 0x52698499, 0x526a6aac, 0x526b76df, 0x526c9530, 0x526d731b,
+    0x526e68a6, 0x526f5b5f, 0x5270772f, 0x5271919a, 0x52729761, 0x52737cdc, 0x52748ff7, 0x52758c1c,
+    0x52765f25, 0x52777c73, 0x527879d8, 0x527989c5, 0x527a6ccc, 0x527b871c, 0x527c5bc6, 0x527d5e42,
+    0x527e68c9, 0x527f7720, 0x52817ef5, 0x52825195, 0x5283514d, 0x528452c9, 0x52855a29, 0x52867f05,
+    0x52879762, 0x528882d7, 0x528963cf, 0x528a7784, 0x528b85d0, 0x528c79d2, 0x528d6e3a, 0x528e5e99,
+    0x528f5999, 0x52908511, 0x5291706d, 0x52926c11, 0x529362bf, 0x529476bf, 0x5295654f, 0x529660af,
+    0x529795fd, 0x5298660e, 0x5299879f, 0x529a9e23, 0x529b94ed, 0x529c540d, 0x529d547d, 0x529e8c2c,
+    0x529f6478, 0x52a06479, 0x52a18611, 0x52a26a21, 0x52a3819c, 0x52a478e8, 0x52a56469, 0x52a69b54,
+    0x52a762b9, 0x52a8672b, 0x52a983ab, 0x52aa58a8, 0x52ab9ed8, 0x52ac6cab, 0x52ad6f20, 0x52ae5bde,
+    0x52af964c, 0x52b08c0b, 0x52b1725f, 0x52b267d0, 0x52b362c7, 0x52b47261, 0x52b54ea9, 0x52b659c6,
+    0x52b76bcd, 0x52b85893, 0x52b966ae, 0x52ba5e55, 0x52bb52df, 0x52bc6155, 0x52bd6728, 0x52be76ee,
+    0x52bf7766, 0x52c07267, 0x52c17a46, 0x52c262ff, 0x52c354ea, 0x52c45450, 0x52c594a0, 0x52c690a3,
+    0x52c75a1c, 0x52c87eb3, 0x52c96c16, 0x52ca4e43, 0x52cb5976, 0x52cc8010, 0x52cd5948, 0x52ce5357,
+    0x52cf7537, 0x52d096be, 0x52d156ca, 0x52d26320, 0x52d38111, 0x52d4607c, 0x52d595f9, 0x52d66dd6,
+    0x52d75462, 0x52d89981, 0x52d95185, 0x52da5ae9, 0x52db80fd, 0x52dc59ae, 0x52dd9713, 0x52de502a,
+    0x52df6ce5, 0x52e05c3c, 0x52e162df, 0x52e24f60, 0x52e3533f, 0x52e4817b, 0x52e59006, 0x52e66eba,
+    0x52e7852b, 0x52e862c8, 0x52e95e74, 0x52ea78be, 0x52eb64b5, 0x52ec637b, 0x52ed5ff5, 0x52ee5a18,
+    0x52ef917f, 0x52f09e1f, 0x52f15c3f, 0x52f2634f, 0x52f38042, 0x52f45b7d, 0x52f5556e, 0x52f6954a,
+    0x52f7954d, 0x52f86d85, 0x52f960a8, 0x52fa67e0, 0x52fb72de, 0x52fc51dd, 0x52fd5b81, 0x534162e7,
+    0x53426cde, 0x5343725b, 0x5344626d, 0x534594ae, 0x53467ebd, 0x53478113, 0x53486d53, 0x5349519c,
+    0x534a5f04, 0x534b5974, 0x534c52aa, 0x534d6012, 0x534e5973, 0x534f6696, 0x53508650, 0x5351759f,
+    0x5352632a, 0x535361e6, 0x53547cef, 0x53558bfa, 0x535654e6, 0x53576b27, 0x53589e25, 0x53596bb4,
+    0x535a85d5, 0x535b5455, 0x535c5076, 0x535d6ca4, 0x535e556a, 0x535f8db4, 0x5360722c, 0x53615e15,
+    0x53626015, 0x53637436, 0x536462cd, 0x53656392, 0x5366724c, 0x53675f98, 0x53686e43, 0x53696d3e,
+    0x536a6500, 0x536b6f58, 0x536c76d8, 0x536d78d0, 0x536e76fc, 0x536f7554, 0x53705224, 0x537153db,
+    0x53724e53, 0x53735e9e, 0x537465c1, 0x5375802a, 0x537680d6, 0x5377629b, 0x53785486, 0x53795228,
+    0x537a70ae, 0x537b888d, 0x537c8dd1, 0x537d6ce1, 0x537e5478, 0x537f80da, 0x538157f9, 0x538288f4,
+    0x53838d54, 0x5384966a, 0x5385914d, 0x53864f69, 0x53876c9b, 0x538855b7, 0x538976c6, 0x538a7830,
+    0x538b62a8, 0x538c70f9, 0x538d6f8e, 0x538e5f6d, 0x538f84ec, 0x539068da, 0x5391787c, 0x53927bf7,
+    0x539381a8, 0x5394670b, 0x53959e4f, 0x53966367, 0x539778b0, 0x5398576f, 0x53997812, 0x539a9739,
+    0x539b6279, 0x539c62ab, 0x539d5288, 0x539e7435, 0x539f6bd7, 0x53a05564, 0x53a1813e, 0x53a275b2,
+    0x53a376ae, 0x53a45339, 0x53a575de, 0x53a650fb, 0x53a75c41, 0x53a88b6c, 0x53a97bc7, 0x53aa504f,
+    0x53ab7247, 0x53ac9a97, 0x53ad98d8, 0x53ae6f02, 0x53af74e2, 0x53b07968, 0x53b16487, 0x53b277a5,
+    0x53b362fc, 0x53b49891, 0x53b58d2b, 0x53b654c1, 0x53b78058, 0x53b84e52, 0x53b9576a, 0x53ba82f9,
+    0x53bb840d, 0x53bc5e73, 0x53bd51ed, 0x53be74f6, 0x53bf8bc4, 0x53c05c4f, 0x53c15761, 0x53c26cfc,
+    0x53c39887, 0x53c45a46, 0x53c57834, 0x53c69b44, 0x53c78feb, 0x53c87c95, 0x53c95256, 0x53ca6251,
+    0x53cb94fa, 0x53cc4ec6, 0x53cd8386, 0x53ce8461, 0x53cf83e9, 0x53d084b2, 0x53d157d4, 0x53d26734,
+    0x53d35703, 0x53d4666e, 0x53d56d66, 0x53d68c31, 0x53d766dd, 0x53d87011, 0x53d9671f, 0x53da6b3a,
+    0x53db6816, 0x53dc621a, 0x53dd59bb, 0x53de4e03, 0x53df51c4, 0x53e06f06, 0x53e167d2, 0x53e26c8f,
+    0x53e35176, 0x53e468cb, 0x53e55947, 0x53e66b67, 0x53e77566, 0x53e85d0e, 0x53e98110, 0x53ea9f50,
+    0x53eb65d7, 0x53ec7948, 0x53ed7941, 0x53ee9a91, 0x53ef8d77, 0x53f05c82, 0x53f14e5e, 0x53f24f01,
+    0x53f3542f, 0x53f45951, 0x53f5780c, 0x53f65668, 0x53f76c14, 0x53f88fc4, 0x53f95f03, 0x53fa6c7d,
+    0x53fb6ce3, 0x53fc8bab, 0x53fd6390, 0x54416070, 0x54426d3d, 0x54437275, 0x54446266, 0x5445948e,
+    0x544694c5, 0x54475343, 0x54488fc1, 0x54497b7e, 0x544a4edf, 0x544b8c26, 0x544c4e7e, 0x544d9ed4,
+    0x544e94b1, 0x544f94b3, 0x5450524d, 0x54516f5c, 0x54529063, 0x54536d45, 0x54548c34, 0x54555811,
+    0x54565d4c, 0x54576b20, 0x54586b49, 0x545967aa, 0x545a545b, 0x545b8154, 0x545c7f8c, 0x545d5899,
+    0x545e8537, 0x545f5f3a, 0x546062a2, 0x54616a47, 0x54629539, 0x54636572, 0x54646084, 0x54656865,
+    0x546677a7, 0x54674e54, 0x54684fa8, 0x54695de7, 0x546a9798, 0x546b64ac, 0x546c7fd8, 0x546d5ced,
+    0x546e4fcf, 0x546f7a8d, 0x54705207, 0x54718304, 0x54724e14, 0x5473602f, 0x54747a83, 0x547594a6,
+    0x54764fb5, 0x54774eb2, 0x547879e6, 0x54797434, 0x547a52e4, 0x547b82b9, 0x547c64d2, 0x547d79bd,
+    0x547e5bdd, 0x547f6c81, 0x54819752, 0x54828f7b, 0x54836c22, 0x5484503e, 0x5485537f, 0x54866e05,
+    0x548764ce, 0x54886674, 0x54896c30, 0x548a60c5, 0x548b9877, 0x548c8bf7, 0x548d5e86, 0x548e743c,
+    0x548f7a77, 0x549079cb, 0x54914e18, 0x549290b1, 0x54937403, 0x54946c42, 0x549556da, 0x5496914b,
+    0x54976cc5, 0x54988d8b, 0x5499533a, 0x549a86c6, 0x549b66f2, 0x549c8eaf, 0x549d5c48, 0x549e9a71,
+    0x549f6e20, 0x54a053d6, 0x54a15a36, 0x54a29f8b, 0x54a38da3, 0x54a453bb, 0x54a55708, 0x54a698a7,
+    0x54a76743, 0x54a8919b, 0x54a96cc9, 0x54aa5168, 0x54ab75ca, 0x54ac62f3, 0x54ad72ac, 0x54ae5238,
+    0x54af529d, 0x54b07f3a, 0x54b17094, 0x54b27638, 0x54b35374, 0x54b49e4a, 0x54b569b7, 0x54b6786e,
+    0x54b796c0, 0x54b888d9, 0x54b97fa4, 0x54ba7136, 0x54bb71c3, 0x54bc5189, 0x54bd67d3, 0x54be74e4,
+    0x54bf58e4, 0x54c06518, 0x54c156b7, 0x54c28ba9, 0x54c39976, 0x54c46270, 0x54c57ed5, 0x54c660f9,
+    0x54c770ed, 0x54c858ec, 0x54c94ec1, 0x54ca4eba, 0x54cb5fcd, 0x54cc97e7, 0x54cd4efb, 0x54ce8ba4,
+    0x54cf5203, 0x54d0598a, 0x54d17eab, 0x54d26254, 0x54d34ecd, 0x54d465e5, 0x54d5620e, 0x54d68338,
+    0x54d784c9, 0x54d88363, 0x54d9878d, 0x54da7194, 0x54db6eb6, 0x54dc5bb9, 0x54dd7ed2, 0x54de5197,
+    0x54df63c9, 0x54e067d4, 0x54e18089, 0x54e28339, 0x54e38815, 0x54e45112, 0x54e55b7a, 0x54e65982,
+    0x54e78fb1, 0x54e84e73, 0x54e96c5d, 0x54ea5165, 0x54eb8925, 0x54ec8f6f, 0x54ed962e, 0x54ee854a,
+    0x54ef745e, 0x54f09510, 0x54f195f0, 0x54f26da6, 0x54f382e5, 0x54f45f31, 0x54f56492, 0x54f66d12,
+    0x54f78428, 0x54f8816e, 0x54f99cc3, 0x54fa585e, 0x54fb8d5b, 0x54fc4e09, 0x54fd53c1, 0x55414f1e,
+    0x55426563, 0x55436851, 0x554455d3, 0x55454e27, 0x55466414, 0x55479a9a, 0x5548626b, 0x55495ac2,
+    0x554a745f, 0x554b8272, 0x554c6da9, 0x554d68ee, 0x554e50e7, 0x554f838e, 0x55507802, 0x55516740,
+    0x55525239, 0x55536c99, 0x55547eb1, 0x555550bb, 0x55565565, 0x5557715e, 0x55587b5b, 0x55596652,
+    0x555a73ca, 0x555b82eb, 0x555c6749, 0x555d5c71, 0x555e5220, 0x555f717d, 0x5560886b, 0x556195ea,
+    0x55629655, 0x556364c5, 0x55648d61, 0x556581b3, 0x55665584, 0x55676c55, 0x55686247, 0x55697f2e,
+    0x556a5892, 0x556b4f24, 0x556c5546, 0x556d8d4f, 0x556e664c, 0x556f4e0a, 0x55705c1a, 0x557188f3,
+    0x557268a2, 0x5573634e, 0x55747a0d, 0x557570e7, 0x5576828d, 0x557752fa, 0x557897f6, 0x55795c11,
+    0x557a54e8, 0x557b90b5, 0x557c7ecd, 0x557d5962, 0x557e8d4a, 0x557f86c7, 0x5581820c, 0x5582820d,
+    0x55838d66, 0x55846444, 0x55855c04, 0x55866151, 0x55876d89, 0x5588793e, 0x55898bbe, 0x558a7837,
+    0x558b7533, 0x558c547b, 0x558d4f38, 0x558e8eab, 0x558f6df1, 0x55905a20, 0x55917ec5, 0x5592795e,
+    0x55936c88, 0x55945ba1, 0x55955a76, 0x5596751a, 0x559780be, 0x5598614e, 0x55996e17, 0x559a58f0,
+    0x559b751f, 0x559c7525, 0x559d7272, 0x559e5347, 0x559f7ef3, 0x55a07701, 0x55a176db, 0x55a25269,
+    0x55a380dc, 0x55a45723, 0x55a55e08, 0x55a65931, 0x55a772ee, 0x55a865bd, 0x55a96e7f, 0x55aa8bd7,
+    0x55ab5c38, 0x55ac8671, 0x55ad5341, 0x55ae77f3, 0x55af62fe, 0x55b065f6, 0x55b14ec0, 0x55b298df,
+    0x55b38680, 0x55b45b9e, 0x55b58bc6, 0x55b653f2, 0x55b777e2, 0x55b84f7f, 0x55b95c4e, 0x55ba9a76,
+    0x55bb59cb, 0x55bc5f0f, 0x55bd793a, 0x55be58eb, 0x55bf4e16, 0x55c067ff, 0x55c14e8b, 0x55c262ed,
+    0x55c38a93, 0x55c4901d, 0x55c552bf, 0x55c6662f, 0x55c755dc, 0x55c8566c, 0x55c99002, 0x55ca4ed5,
+    0x55cb4f8d, 0x55cc91ca, 0x55cd9970, 0x55ce6c0f, 0x55cf5e02, 0x55d06043, 0x55d15ba4, 0x55d289c6,
+    0x55d38bd5, 0x55d46536, 0x55d5624b, 0x55d69996, 0x55d75b88, 0x55d85bff, 0x55d96388, 0x55da552e,
+    0x55db53d7, 0x55dc7626, 0x55dd517d, 0x55de852c, 0x55df67a2, 0x55e068b3, 0x55e16b8a, 0x55e26292,
+    0x55e38f93, 0x55e453d4, 0x55e58212, 0x55e66dd1, 0x55e7758f, 0x55e84e66, 0x55e98d4e, 0x55ea5b70,
+    0x55eb719f, 0x55ec85af, 0x55ed6691, 0x55ee66d9, 0x55ef7f72, 0x55f08700, 0x55f19ecd, 0x55f29f20,
+    0x55f35c5e, 0x55f4672f, 0x55f58ff0, 0x55f66811, 0x55f7675f, 0x55f8620d, 0x55f97ad6, 0x55fa5885,
+    0x55fb5eb6, 0x55fc6570, 0x55fd6f31, 0x56416055, 0x56425237, 0x5643800d, 0x56446454, 0x56458870,
+    0x56467529, 0x56475e05, 0x56486813, 0x564962f4, 0x564a971c, 0x564b53cc, 0x564c723d, 0x564d8c01,
+    0x564e6c34, 0x564f7761, 0x56507a0e, 0x5651542e, 0x565277ac, 0x5653987a, 0x5654821c, 0x56558bf4,
+    0x56567855, 0x56576714, 0x565870c1, 0x565965af, 0x565a6495, 0x565b5636, 0x565c601d, 0x565d79c1,
+    0x565e53f8, 0x565f4e1d, 0x56606b7b, 0x56618086, 0x56625bfa, 0x566355e3, 0x566456db, 0x56654f3a,
+    0x56664f3c, 0x56679972, 0x56685df3, 0x5669677e, 0x566a8038, 0x566b6002, 0x566c9882, 0x566d9001,
+    0x566e5b8b, 0x566f8bbc, 0x56708bf5, 0x5671641c, 0x56728258, 0x567364de, 0x567455fd, 0x567582cf,
+    0x56769165, 0x56774fd7, 0x56787d20, 0x5679901f, 0x567a7c9f, 0x567b50f3, 0x567c5851, 0x567d6eaf,
+    0x567e5bbf, 0x567f8bc9, 0x56818083, 0x56829178, 0x5683849c, 0x56847b97, 0x5685867d, 0x5686968b,
+    0x5687968f, 0x56887ee5, 0x56899ad3, 0x568a788e, 0x568b5c81, 0x568c7a57, 0x568d9042, 0x568e96a7,
+    0x568f795f, 0x56905b59, 0x5691635f, 0x56927b0b, 0x569384d1, 0x569468ad, 0x56955506, 0x56967f29,
+    0x56977410, 0x56987d22, 0x56999501, 0x569a6240, 0x569b584c, 0x569c4ed6, 0x569d5b83, 0x569e5979,
+    0x569f5854, 0x56a0736d, 0x56a1631e, 0x56a28e4b, 0x56a38e0f, 0x56a480ce, 0x56a582d4, 0x56a662ac,
+    0x56a753f0, 0x56a86cf0, 0x56a9915e, 0x56aa592a, 0x56ab6001, 0x56ac6c70, 0x56ad574d, 0x56ae644a,
+    0x56af8d2a, 0x56b0762b, 0x56b16ee9, 0x56b2575b, 0x56b36a80, 0x56b475f0, 0x56b56f6d, 0x56b68c2d,
+    0x56b78c08, 0x56b85766, 0x56b96bef, 0x56ba8892, 0x56bb78b3, 0x56bc63a2, 0x56bd53f9, 0x56be70ad,
+    0x56bf6c64, 0x56c05858, 0x56c1642a, 0x56c25802, 0x56c368e0, 0x56c4819b, 0x56c55510, 0x56c67cd6,
+    0x56c75018, 0x56c88eba, 0x56c96dcc, 0x56ca8d9f, 0x56cb70eb, 0x56cc638f, 0x56cd6d9b, 0x56ce6ed4,
+    0x56cf7ee6, 0x56d08404, 0x56d16843, 0x56d29003, 0x56d36dd8, 0x56d49676, 0x56d58ba8, 0x56d65957,
+    0x56d77279, 0x56d885e4, 0x56d9817e, 0x56da75bc, 0x56db8a8a, 0x56dc68af, 0x56dd5254, 0x56de8e22,
+    0x56df9511, 0x56e063d0, 0x56e19898, 0x56e28e44, 0x56e3557c, 0x56e44f53, 0x56e566ff, 0x56e6568f,
+    0x56e760d5, 0x56e86d95, 0x56e95243, 0x56ea5c49, 0x56eb5929, 0x56ec6dfb, 0x56ed586b, 0x56ee7530,
+    0x56ef751c, 0x56f0606c, 0x56f18214, 0x56f28146, 0x56f36311, 0x56f46761, 0x56f58fe2, 0x56f6773a,
+    0x56f78df3, 0x56f88d34, 0x56f994c1, 0x56fa5e16, 0x56fb5385, 0x56fc542c, 0x56fd70c3, 0x57416c40,
+    0x57425ef7, 0x5743505c, 0x57444ead, 0x57455ead, 0x5746633a, 0x57478247, 0x5748901a, 0x57496850,
+    0x574a916e, 0x574b77b3, 0x574c540c, 0x574d94dc, 0x574e5f64, 0x574f7ae5, 0x57506876, 0x57516345,
+    0x57527b52, 0x57537edf, 0x575475db, 0x57555077, 0x57566295, 0x57575934, 0x5758900f, 0x575951f8,
+    0x575a79c3, 0x575b7a81, 0x575c56fe, 0x575d5f92, 0x575e9014, 0x575f6d82, 0x57605c60, 0x5761571f,
+    0x57625410, 0x57635154, 0x57646e4d, 0x576556e2, 0x576663a8, 0x57679893, 0x5768817f, 0x57698715,
+    0x576a892a, 0x576b9000, 0x576c541e, 0x576d5c6f, 0x576e81c0, 0x576f62d6, 0x57706258, 0x57718131,
+    0x57729e35, 0x57739640, 0x57749a6e, 0x57759a7c, 0x5776692d, 0x577759a5, 0x577862d3, 0x5779553e,
+    0x577a6316, 0x577b54c7, 0x577c86d9, 0x577d6d3c, 0x577e5a03, 0x577f74e6, 0x5781889c, 0x57826b6a,
+    0x57835916, 0x57848c4c, 0x57855f2f, 0x57866e7e, 0x578773a9, 0x5788987d, 0x57894e38, 0x578a70f7,
+    0x578b5b8c, 0x578c7897, 0x578d633d, 0x578e665a, 0x578f7696, 0x579060cb, 0x57915b9b, 0x57925a49,
+    0x57934e07, 0x57948155, 0x57956c6a, 0x5796738b, 0x57974ea1, 0x57986789, 0x57997f51, 0x579a5f80,
+    0x579b65fa, 0x579c671b, 0x579d5fd8, 0x579e5984, 0x579f5a01, 0x57a05dcd, 0x57a15fae, 0x57a25371,
+    0x57a397e6, 0x57a48fdd, 0x57a56845, 0x57a656f4, 0x57a7552f, 0x57a860df, 0x57a94e3a, 0x57aa6f4d,
+    0x57ab7ef4, 0x57ac82c7, 0x57ad840e, 0x57ae59d4, 0x57af4f1f, 0x57b04f2a, 0x57b15c3e, 0x57b27eac,
+    0x57b3672a, 0x57b4851a, 0x57b55473, 0x57b6754f, 0x57b780c3, 0x57b85582, 0x57b99b4f, 0x57ba4f4d,
+    0x57bb6e2d, 0x57bc8c13, 0x57bd5c09, 0x57be6170, 0x57bf536b, 0x57c0761f, 0x57c16e29, 0x57c2868a,
+    0x57c36587, 0x57c495fb, 0x57c57eb9, 0x57c6543b, 0x57c77a33, 0x57c87d0a, 0x57c995ee, 0x57ca55e1,
+    0x57cb7fc1, 0x57cc74ee, 0x57cd631d, 0x57ce8717, 0x57cf6da1, 0x57d07a9d, 0x57d16211, 0x57d265a1,
+    0x57d35367, 0x57d463e1, 0x57d56c83, 0x57d65deb, 0x57d7545c, 0x57d894a8, 0x57d94e4c, 0x57da6c61,
+    0x57db8bec, 0x57dc5c4b, 0x57dd65e0, 0x57de829c, 0x57df68a7, 0x57e0543e, 0x57e15434, 0x57e26bcb,
+    0x57e36b66, 0x57e44e94, 0x57e56342, 0x57e65348, 0x57e7821e, 0x57e84f0d, 0x57e94fae, 0x57ea575e,
+    0x57eb620a, 0x57ec96fe, 0x57ed6664, 0x57ee7269, 0x57ef52ff, 0x57f052a1, 0x57f1609f, 0x57f28bef,
+    0x57f36614, 0x57f47199, 0x57f56790, 0x57f6897f, 0x57f77852, 0x57f877fd, 0x57f96670, 0x57fa563b,
+    0x57fb5438, 0x57fc9521, 0x57fd727a, 0x58417a00, 0x5842606f, 0x58435e0c, 0x58446089, 0x5845819d,
+    0x58465915, 0x584760dc, 0x58487184, 0x584970ef, 0x584a6eaa, 0x584b6c50, 0x584c7280, 0x584d6a84,
+    0x584e88ad, 0x584f5e2d, 0x58504e60, 0x58515ab3, 0x5852559c, 0x585394e3, 0x58546d17, 0x58557cfb,
+    0x58569699, 0x5857620f, 0x58587ec6, 0x5859778e, 0x585a867e, 0x585b5323, 0x585c971e, 0x585d8f96,
+    0x585e6687, 0x585f5ce1, 0x58604fa0, 0x586172ed, 0x58624e0b, 0x586353a6, 0x5864590f, 0x58655413,
+    0x58666380, 0x58679528, 0x58685148, 0x58694ed9, 0x586a9c9c, 0x586b7ea4, 0x586c54b8, 0x586d8d24,
+    0x586e8854, 0x586f8237, 0x587095f2, 0x58716d8e, 0x58725f26, 0x58735acc, 0x5874663e, 0x58759669,
+    0x587673b0, 0x5877732e, 0x587853bf, 0x5879817a, 0x587a9985, 0x587b7fa1, 0x587c5baa, 0x587d9677,
+    0x587e9650, 0x587f7ebf, 0x588176f8, 0x588253a2, 0x58839576, 0x58849999, 0x58857bb1, 0x58868944,
+    0x58876e58, 0x58884e61, 0x58897fd4, 0x588a7965, 0x588b8be6, 0x588c60f3, 0x588d54cd, 0x588e4eab,
+    0x588f9879, 0x58905df7, 0x58916a61, 0x589250cf, 0x58935411, 0x58948c61, 0x58958427, 0x5896785d,
+    0x58979704, 0x5898524a, 0x589954ee, 0x589a56a3, 0x589b9500, 0x589c6d88, 0x589d5bb5, 0x589e6dc6,
+    0x589f6653, 0x58a05c0f, 0x58a15b5d, 0x58a26821, 0x58a38096, 0x58a45578, 0x58a57b11, 0x58a66548,
+    0x58a76954, 0x58a84e9b, 0x58a96b47, 0x58aa874e, 0x58ab978b, 0x58ac534f, 0x58ad631f, 0x58ae643a,
+    0x58af90aa, 0x58b0659c, 0x58b180c1, 0x58b28c10, 0x58b35199, 0x58b468b0, 0x58b55378, 0x58b687f9,
+    0x58b761c8, 0x58b86cc4, 0x58b96cfb, 0x58ba8c22, 0x58bb5c51, 0x58bc85aa, 0x58bd82af, 0x58be950c,
+    0x58bf6b23, 0x58c08f9b, 0x58c165b0, 0x58c25ffb, 0x58c35fc3, 0x58c44fe1, 0x58c58845, 0x58c6661f,
+    0x58c78165, 0x58c87329, 0x58c960fa, 0x58ca5174, 0x58cb5211, 0x58cc578b, 0x58cd5f62, 0x58ce90a2,
+    0x58cf884c, 0x58d09192, 0x58d15e78, 0x58d2674f, 0x58d36027, 0x58d459d3, 0x58d55144, 0x58d651f6,
+    0x58d780f8, 0x58d85308, 0x58d96c79, 0x58da96c4, 0x58db718a, 0x58dc4f11, 0x58dd4fee, 0x58de7f9e,
+    0x58df673d, 0x58e055c5, 0x58e19508, 0x58e279c0, 0x58e38896, 0x58e47ee3, 0x58e5589f, 0x58e6620c,
+    0x58e79700, 0x58e8865a, 0x58e95618, 0x58ea987b, 0x58eb5f90, 0x58ec8bb8, 0x58ed84c4, 0x58ee9157,
+    0x58ef53d9, 0x58f065ed, 0x58f15e8f, 0x58f2755c, 0x58f36064, 0x58f47d6e, 0x58f55a7f, 0x58f67eea,
+    0x58f77eed, 0x58f88f69, 0x58f955a7, 0x58fa5ba3, 0x58fb60ac, 0x58fc65cb, 0x58fd7384, 0x59419009,
+    0x59427663, 0x59437729, 0x59447eda, 0x59459774, 0x5946859b, 0x59475b66, 0x59487a74, 0x594996ea,
+    0x594a8840, 0x594b52cb, 0x594c718f, 0x594d5faa, 0x594e65ec, 0x594f8be2, 0x59505bfb, 0x59519a6f,
+    0x59525de1, 0x59536b89, 0x59546c5b, 0x59558bad, 0x59568baf, 0x5957900a, 0x59588fc5, 0x5959538b,
+    0x595a62bc, 0x595b9e26, 0x595c9e2d, 0x595d5440, 0x595e4e2b, 0x595f82bd, 0x59607259, 0x5961869c,
+    0x59625d16, 0x59638859, 0x59646daf, 0x596596c5, 0x596654d1, 0x59674e9a, 0x59688bb6, 0x59697109,
+    0x596a54bd, 0x596b9609, 0x596c70df, 0x596d6df9, 0x596e76d0, 0x596f4e25, 0x59707814, 0x59718712,
+    0x59725ca9, 0x59735ef6, 0x59748a00, 0x5975989c, 0x5976960e, 0x5977708e, 0x59786cbf, 0x59795944,
+    0x597a63a9, 0x597b773c, 0x597c884d, 0x597d6f14, 0x597e8273, 0x597f5830, 0x598171d5, 0x5982538c,
+    0x5983781a, 0x598496c1, 0x59855501, 0x59865f66, 0x59877130, 0x59885bb4, 0x59898c1a, 0x598a9a8c,
+    0x598b6b83, 0x598c592e, 0x598d9e2f, 0x598e79e7, 0x598f6768, 0x5990626c, 0x59914f6f, 0x599275a1,
+    0x59937f8a, 0x59946d0b, 0x59959633, 0x59966c27, 0x59974ef0, 0x599875d2, 0x5999517b, 0x599a6837,
+    0x599b6f3e, 0x599c9080, 0x599d8170, 0x599e5996, 0x599f7476, 0x59a06447, 0x59a15c27, 0x59a29065,
+    0x59a37a91, 0x59a48c23, 0x59a559da, 0x59a654ac, 0x59a78200, 0x59a8836f, 0x59a98981, 0x59aa8000,
+    0x59ab6930, 0x59ac564e, 0x59ad8036, 0x59ae7237, 0x59af91ce, 0x59b051b6, 0x59b14e5f, 0x59b29875,
+    0x59b36396, 0x59b44e1a, 0x59b553f6, 0x59b666f3, 0x59b7814b, 0x59b8591c, 0x59b96db2, 0x59ba4e00,
+    0x59bb58f9, 0x59bc533b, 0x59bd63d6, 0x59be94f1, 0x59bf4f9d, 0x59c04f0a, 0x59c18863, 0x59c29890,
+    0x59c35937, 0x59c49057, 0x59c579fb, 0x59c64eea, 0x59c780f0, 0x59c87591, 0x59c96c82, 0x59ca5b9c,
+    0x59cb59e8, 0x59cc5f5d, 0x59cd6905, 0x59ce8681, 0x59cf501a, 0x59d05df2, 0x59d14e59, 0x59d277e3,
+    0x59d34ee5, 0x59d4827a, 0x59d56291, 0x59d66613, 0x59d79091, 0x59d85c79, 0x59d94ebf, 0x59da5f79,
+    0x59db81c6, 0x59dc9038, 0x59dd8084, 0x59de75ab, 0x59df4ea6, 0x59e088d4, 0x59e1610f, 0x59e26bc5,
+    0x59e35fc6, 0x59e44e49, 0x59e576ca, 0x59e66ea2, 0x59e78be3, 0x59e88bae, 0x59e98c0a, 0x59ea8bd1,
+    0x59eb5f02, 0x59ec7ffc, 0x59ed7fcc, 0x59ee7ece, 0x59ef8335, 0x59f0836b, 0x59f156e0, 0x59f26bb7,
+    0x59f397f3, 0x59f49634, 0x59f559fb, 0x59f6541f, 0x59f794f6, 0x59f86deb, 0x59f95bc5, 0x59fa996e,
+    0x59fb5c39, 0x59fc5f15, 0x59fd9690, 0x5a415370, 0x5a4282f1, 0x5a436a31, 0x5a445a74, 0x5a459e70,
+    0x5a465e94, 0x5a477f28, 0x5a4883b9, 0x5a498424, 0x5a4a8425, 0x5a4b8367, 0x5a4c8747, 0x5a4d8fce,
+    0x5a4e8d62, 0x5a4f76c8, 0x5a505f71, 0x5a519896, 0x5a52786c, 0x5a536620, 0x5a5454df, 0x5a5562e5,
+    0x5a564f63, 0x5a5781c3, 0x5a5875c8, 0x5a595eb8, 0x5a5a96cd, 0x5a5b8e0a, 0x5a5c86f9, 0x5a5d548f,
+    0x5a5e6cf3, 0x5a5f6d8c, 0x5a606c38, 0x5a61607f, 0x5a6252c7, 0x5a637528, 0x5a645e7d, 0x5a654f18,
+    0x5a6660a0, 0x5a675fe7, 0x5a685c24, 0x5a697531, 0x5a6a90ae, 0x5a6b94c0, 0x5a6c72b9, 0x5a6d6cb9,
+    0x5a6e6e38, 0x5a6f9149, 0x5a706709, 0x5a7153cb, 0x5a7253f3, 0x5a734f51, 0x5a7491c9, 0x5a758bf1,
+    0x5a7653c8, 0x5a775e7c, 0x5a788fc2, 0x5a796de4, 0x5a7a4e8e, 0x5a7b76c2, 0x5a7c6986, 0x5a7d865e,
+    0x5a7e611a, 0x5a7f8206, 0x5a814f59, 0x5a824fde, 0x5a83903e, 0x5a849c7c, 0x5a856109, 0x5a866e1d,
+    0x5a876e14, 0x5a889685, 0x5a894e88, 0x5a8a5a31, 0x5a8b96e8, 0x5a8c4e0e, 0x5a8d5c7f, 0x5a8e79b9,
+    0x5a8f5b87, 0x5a908bed, 0x5a917fbd, 0x5a927389, 0x5a9357df, 0x5a94828b, 0x5a9590c1, 0x5a965401,
+    0x5a979047, 0x5a9855bb, 0x5a995cea, 0x5a9a5fa1, 0x5a9b6108, 0x5a9c6b32, 0x5a9d72f1, 0x5a9e80b2,
+    0x5a9f8a89, 0x5aa06d74, 0x5aa15bd3, 0x5aa288d5, 0x5aa39884, 0x5aa48c6b, 0x5aa59a6d, 0x5aa69e33,
+    0x5aa76e0a, 0x5aa851a4, 0x5aa95143, 0x5aaa57a3, 0x5aab8881, 0x5aac539f, 0x5aad63f4, 0x5aae8f95,
+    0x5aaf56ed, 0x5ab05458, 0x5ab15706, 0x5ab2733f, 0x5ab36e90, 0x5ab47f18, 0x5ab58fdc, 0x5ab682d1,
+    0x5ab7613f, 0x5ab86028, 0x5ab99662, 0x5aba66f0, 0x5abb7ea6, 0x5abc8d8a, 0x5abd8dc3, 0x5abe94a5,
+    0x5abf5cb3, 0x5ac07ca4, 0x5ac16708, 0x5ac260a6, 0x5ac39605, 0x5ac48018, 0x5ac54e91, 0x5ac690e7,
+    0x5ac75300, 0x5ac89668, 0x5ac95141, 0x5aca8fd0, 0x5acb8574, 0x5acc915d, 0x5acd6655, 0x5ace97f5,
+    0x5acf5b55, 0x5ad0531d, 0x5ad17838, 0x5ad26742, 0x5ad3683d, 0x5ad454c9, 0x5ad5707e, 0x5ad65bb0,
+    0x5ad78f7d, 0x5ad8518d, 0x5ad95728, 0x5ada54b1, 0x5adb6512, 0x5adc6682, 0x5add8d5e, 0x5ade8d43,
+    0x5adf810f, 0x5ae0846c, 0x5ae1906d, 0x5ae27cdf, 0x5ae351ff, 0x5ae485fb, 0x5ae567a3, 0x5ae665e9,
+    0x5ae76fa1, 0x5ae886a4, 0x5ae98e81, 0x5aea566a, 0x5aeb9020, 0x5aec7682, 0x5aed7076, 0x5aee71e5,
+    0x5aef8d23, 0x5af062e9, 0x5af15219, 0x5af26cfd, 0x5af38d3c, 0x5af4600e, 0x5af5589e, 0x5af6618e,
+    0x5af766fe, 0x5af88d60, 0x5af9624e, 0x5afa55b3, 0x5afb6e23, 0x5afc672d, 0x5afd8f67, 0x5b4194e1,
+    0x5b4295f8, 0x5b437728, 0x5b446805, 0x5b4569a8, 0x5b46548b, 0x5b474e4d, 0x5b4870b8, 0x5b498bc8,
+    0x5b4a6458, 0x5b4b658b, 0x5b4c5b85, 0x5b4d7a84, 0x5b4e503a, 0x5b4f5be8, 0x5b5077bb, 0x5b516be1,
+    0x5b528a79, 0x5b537c98, 0x5b546cbe, 0x5b5576cf, 0x5b5665a9, 0x5b578f97, 0x5b585d2d, 0x5b595c55,
+    0x5b5a8638, 0x5b5b6808, 0x5b5c5360, 0x5b5d6218, 0x5b5e7ad9, 0x5b5f6e5b, 0x5b607efd, 0x5b616a1f,
+    0x5b627ae0, 0x5b635f70, 0x5b646f33, 0x5b655f20, 0x5b66638c, 0x5b676da8, 0x5b686756, 0x5b694e08,
+    0x5b6a5e10, 0x5b6b8d26, 0x5b6c4ed7, 0x5b6d80c0, 0x5b6e7634, 0x5b6f969c, 0x5b7062db, 0x5b71662d,
+    0x5b72627e, 0x5b736cbc, 0x5b748d75, 0x5b757167, 0x5b767f69, 0x5b775146, 0x5b788087, 0x5b7953ec,
+    0x5b7a906e, 0x5b7b6298, 0x5b7c54f2, 0x5b7d86f0, 0x5b7e8f99, 0x5b7f8005, 0x5b819517, 0x5b828517,
+    0x5b838fd9, 0x5b846d59, 0x5b8573cd, 0x5b86659f, 0x5b87771f, 0x5b887504, 0x5b897827, 0x5b8a81fb,
+    0x5b8b8d1e, 0x5b8c9488, 0x5b8d4fa6, 0x5b8e6795, 0x5b8f75b9, 0x5b908bca, 0x5b919707, 0x5b92632f,
+    0x5b939547, 0x5b949635, 0x5b9584b8, 0x5b966323, 0x5b977741, 0x5b985f81, 0x5b9972f0, 0x5b9a4e89,
+    0x5b9b6014, 0x5b9c6574, 0x5b9d62ef, 0x5b9e6b63, 0x5b9f653f, 0x5ba05e27, 0x5ba175c7, 0x5ba290d1,
+    0x5ba38bc1, 0x5ba4829d, 0x5ba5679d, 0x5ba6652f, 0x5ba75431, 0x5ba88718, 0x5ba977e5, 0x5baa80a2,
+    0x5bab8102, 0x5bac6c41, 0x5bad4e4b, 0x5bae7ec7, 0x5baf804c, 0x5bb076f4, 0x5bb1690d, 0x5bb26b96,
+    0x5bb36267, 0x5bb4503c, 0x5bb54f84, 0x5bb65740, 0x5bb76307, 0x5bb86b62, 0x5bb98dbe, 0x5bba53ea,
+    0x5bbb65e8, 0x5bbc7eb8, 0x5bbd5fd7, 0x5bbe631a, 0x5bbf63b7, 0x5bc081f3, 0x5bc181f4, 0x5bc27f6e,
+    0x5bc35e1c, 0x5bc45cd9, 0x5bc55236, 0x5bc6667a, 0x5bc779e9, 0x5bc87a1a, 0x5bc98d28, 0x5bca7099,
+    0x5bcb75d4, 0x5bcc6ede, 0x5bcd6cbb, 0x5bce7a92, 0x5bcf4e2d, 0x5bd076c5, 0x5bd15fe0, 0x5bd2949f,
+    0x5bd38877, 0x5bd47ec8, 0x5bd579cd, 0x5bd680bf, 0x5bd791cd, 0x5bd84ef2, 0x5bd94f17, 0x5bda821f,
+    0x5bdb5468, 0x5bdc5dde, 0x5bdd6d32, 0x5bde8bcc, 0x5bdf7ca5, 0x5be08f74, 0x5be18098, 0x5be25e1a,
+    0x5be35492, 0x5be476b1, 0x5be55b99, 0x5be6663c, 0x5be79aa4, 0x5be873e0, 0x5be9682a, 0x5bea86db,
+    0x5beb6731, 0x5bec732a, 0x5bed8bf8, 0x5bee8bdb, 0x5bef9010, 0x5bf07af9, 0x5bf170db, 0x5bf2716e,
+    0x5bf362c4, 0x5bf477a9, 0x5bf55631, 0x5bf64e3b, 0x5bf78457, 0x5bf867f1, 0x5bf952a9, 0x5bfa86c0,
+    0x5bfb8d2e, 0x5bfc94f8, 0x5bfd7b51, 0x5c414f4f, 0x5c426ce8, 0x5c43795d, 0x5c449a7b, 0x5c456293,
+    0x5c46722a, 0x5c4762fd, 0x5c484e13, 0x5c497816, 0x5c4a8f6c, 0x5c4b64b0, 0x5c4c8d5a, 0x5c4d7bc6,
+    0x5c4e6869, 0x5c4f5e84, 0x5c5088c5, 0x5c515986, 0x5c52649e, 0x5c5358ee, 0x5c5472b6, 0x5c55690e,
+    0x5c569525, 0x5c578ffd, 0x5c588d58, 0x5c595760, 0x5c5a7f00, 0x5c5b8c06, 0x5c5c51c6, 0x5c5d6349,
+    0x5c5e62d9, 0x5c5f5353, 0x5c60684c, 0x5c617422, 0x5c628301, 0x5c63914c, 0x5c645544, 0x5c657740,
+    0x5c66707c, 0x5c676d4a, 0x5c685179, 0x5c6954a8, 0x5c6a8d44, 0x5c6b59ff, 0x5c6c6ecb, 0x5c6d6dc4,
+    0x5c6e5b5c, 0x5c6f7d2b, 0x5c704ed4, 0x5c717c7d, 0x5c726ed3, 0x5c735b50, 0x5c7481ea, 0x5c756e0d,
+    0x5c765b57, 0x5c779b03, 0x5c7868d5, 0x5c798e2a, 0x5c7a5b97, 0x5c7b7efc, 0x5c7c603b, 0x5c7d7eb5,
+    0x5c7e90b9, 0x5c7f8d70, 0x5c81594f, 0x5c8263cd, 0x5c8379df, 0x5c848db3, 0x5c855352, 0x5c8665cf,
+    0x5c877956, 0x5c888bc5, 0x5c89963b, 0x5c8a7ec4, 0x5c8b94bb, 0x5c8c7e82, 0x5c8d5634, 0x5c8e9189,
+    0x5c8f6700, 0x5c907f6a, 0x5c915c0a, 0x5c929075, 0x5c936628, 0x5c945de6, 0x5c954f50, 0x5c9667de,
+    0x5c97505a, 0x5c984f5c, 0x5c995750, 0x5c9a5ea7, 0x5ca04e8d, 0x5ca14e0c, 0x5ca25140, 0x5ca34e10,
+    0x5ca45eff, 0x5ca55345, 0x5ca64e15, 0x5ca74e98, 0x5ca84e1e, 0x5ca99b32, 0x5caa5b6c, 0x5cab5669,
+    0x5cac4e28, 0x5cad79ba, 0x5cae4e3f, 0x5caf5315, 0x5cb04e47, 0x5cb1592d, 0x5cb2723b, 0x5cb3536e,
+    0x5cb46c10, 0x5cb556df, 0x5cb680e4, 0x5cb79997, 0x5cb86bd3, 0x5cb9777e, 0x5cba9f17, 0x5cbb4e36,
+    0x5cbc4e9f, 0x5cbd9f10, 0x5cbe4e5c, 0x5cbf4e69, 0x5cc04e93, 0x5cc18288, 0x5cc25b5b, 0x5cc3556c,
+    0x5cc4560f, 0x5cc54ec4, 0x5cc6538d, 0x5cc7539d, 0x5cc853a3, 0x5cc953a5, 0x5cca53ae, 0x5ccb9765,
+    0x5ccc8d5d, 0x5ccd531a, 0x5cce53f5, 0x5ccf5326, 0x5cd0532e, 0x5cd1533e, 0x5cd28d5c, 0x5cd35366,
+    0x5cd45363, 0x5cd55202, 0x5cd65208, 0x5cd7520e, 0x5cd8522d, 0x5cd95233, 0x5cda523f, 0x5cdb5240,
+    0x5cdc524c, 0x5cdd525e, 0x5cde5261, 0x5cdf525c, 0x5ce084af, 0x5ce1527d, 0x5ce25282, 0x5ce35281,
+    0x5ce45290, 0x5ce55293, 0x5ce65182, 0x5ce77f54, 0x5ce84ebb, 0x5ce94ec3, 0x5cea4ec9, 0x5ceb4ec2,
+    0x5cec4ee8, 0x5ced4ee1, 0x5cee4eeb, 0x5cef4ede, 0x5cf04f1b, 0x5cf14ef3, 0x5cf24f22, 0x5cf34f64,
+    0x5cf44ef5, 0x5cf54f25, 0x5cf64f27, 0x5cf74f09, 0x5cf84f2b, 0x5cf94f5e, 0x5cfa4f67, 0x5cfb6538,
+    0x5cfc4f5a, 0x5cfd4f5d, 0x5d414f5f, 0x5d424f57, 0x5d434f32, 0x5d444f3d, 0x5d454f76, 0x5d464f74,
+    0x5d474f91, 0x5d484f89, 0x5d494f83, 0x5d4a4f8f, 0x5d4b4f7e, 0x5d4c4f7b, 0x5d4d4faa, 0x5d4e4f7c,
+    0x5d4f4fac, 0x5d504f94, 0x5d514fe6, 0x5d524fe8, 0x5d534fea, 0x5d544fc5, 0x5d554fda, 0x5d564fe3,
+    0x5d574fdc, 0x5d584fd1, 0x5d594fdf, 0x5d5a4ff8, 0x5d5b5029, 0x5d5c504c, 0x5d5d4ff3, 0x5d5e502c,
+    0x5d5f500f, 0x5d60502e, 0x5d61502d, 0x5d624ffe, 0x5d63501c, 0x5d64500c, 0x5d655025, 0x5d665028,
+    0x5d67507e, 0x5d685043, 0x5d695055, 0x5d6a5048, 0x5d6b504e, 0x5d6c506c, 0x5d6d507b, 0x5d6e50a5,
+    0x5d6f50a7, 0x5d7050a9, 0x5d7150ba, 0x5d7250d6, 0x5d735106, 0x5d7450ed, 0x5d7550ec, 0x5d7650e6,
+    0x5d7750ee, 0x5d785107, 0x5d79510b, 0x5d7a4edd, 0x5d7b6c3d, 0x5d7c4f58, 0x5d7d4f65, 0x5d7e4fce,
+    0x5d7f9fa0, 0x5d816c46, 0x5d827c74, 0x5d83516e, 0x5d845dfd, 0x5d859ec9, 0x5d869998, 0x5d875181,
+    0x5d885914, 0x5d8952f9, 0x5d8a530d, 0x5d8b8a07, 0x5d8c5310, 0x5d8d51eb, 0x5d8e5919, 0x5d8f5155,
+    0x5d904ea0, 0x5d915156, 0x5d924eb3, 0x5d93886e, 0x5d9488a4, 0x5d954eb5, 0x5d968114, 0x5d9788d2,
+    0x5d987980, 0x5d995b34, 0x5d9a8803, 0x5d9b7fb8, 0x5d9c51ab, 0x5d9d51b1, 0x5d9e51bd, 0x5d9f51bc,
+    0x5da051c7, 0x5da15196, 0x5da251a2, 0x5da351a5, 0x5da48ba0, 0x5da58ba6, 0x5da68ba7, 0x5da78baa,
+    0x5da88bb4, 0x5da98bb5, 0x5daa8bb7, 0x5dab8bc2, 0x5dac8bc3, 0x5dad8bcb, 0x5dae8bcf, 0x5daf8bce,
+    0x5db08bd2, 0x5db18bd3, 0x5db28bd4, 0x5db38bd6, 0x5db48bd8, 0x5db58bd9, 0x5db68bdc, 0x5db78bdf,
+    0x5db88be0, 0x5db98be4, 0x5dba8be8, 0x5dbb8be9, 0x5dbc8bee, 0x5dbd8bf0, 0x5dbe8bf3, 0x5dbf8bf6,
+    0x5dc08bf9, 0x5dc18bfc, 0x5dc28bff, 0x5dc38c00, 0x5dc48c02, 0x5dc58c04, 0x5dc68c07, 0x5dc78c0c,
+    0x5dc88c0f, 0x5dc98c11, 0x5dca8c12, 0x5dcb8c14, 0x5dcc8c15, 0x5dcd8c16, 0x5dce8c19, 0x5dcf8c1b,
+    0x5dd08c18, 0x5dd18c1d, 0x5dd28c1f, 0x5dd38c20, 0x5dd48c21, 0x5dd58c25, 0x5dd68c27, 0x5dd78c2a,
+    0x5dd88c2b, 0x5dd98c2e, 0x5dda8c2f, 0x5ddb8c32, 0x5ddc8c33, 0x5ddd8c35, 0x5dde8c36, 0x5ddf5369,
+    0x5de0537a, 0x5de1961d, 0x5de29622, 0x5de39621, 0x5de49631, 0x5de5962a, 0x5de6963d, 0x5de7963c,
+    0x5de89642, 0x5de99649, 0x5dea9654, 0x5deb965f, 0x5dec9667, 0x5ded966c, 0x5dee9672, 0x5def9674,
+    0x5df09688, 0x5df1968d, 0x5df29697, 0x5df396b0, 0x5df49097, 0x5df5909b, 0x5df6909d, 0x5df79099,
+    0x5df890ac, 0x5df990a1, 0x5dfa90b4, 0x5dfb90b3, 0x5dfc90b6, 0x5dfd90ba, 0x5e4190b8, 0x5e4290b0,
+    0x5e4390cf, 0x5e4490c5, 0x5e4590be, 0x5e4690d0, 0x5e4790c4, 0x5e4890c7, 0x5e4990d3, 0x5e4a90e6,
+    0x5e4b90e2, 0x5e4c90dc, 0x5e4d90d7, 0x5e4e90db, 0x5e4f90eb, 0x5e5090ef, 0x5e5190fe, 0x5e529104,
+    0x5e539122, 0x5e54911e, 0x5e559123, 0x5e569131, 0x5e57912f, 0x5e589139, 0x5e599143, 0x5e5a9146,
+    0x5e5b520d, 0x5e5c5942, 0x5e5d52a2, 0x5e5e52ac, 0x5e5f52ad, 0x5e6052be, 0x5e6154ff, 0x5e6252d0,
+    0x5e6352d6, 0x5e6452f0, 0x5e6553df, 0x5e6671ee, 0x5e6777cd, 0x5e685ef4, 0x5e6951f5, 0x5e6a51fc,
+    0x5e6b9b2f, 0x5e6c53b6, 0x5e6d5f01, 0x5e6e755a, 0x5e6f5def, 0x5e70574c, 0x5e7157a9, 0x5e7257a1,
+    0x5e73587e, 0x5e7458bc, 0x5e7558c5, 0x5e7658d1, 0x5e775729, 0x5e78572c, 0x5e79572a, 0x5e7a5733,
+    0x5e7b5739, 0x5e7c572e, 0x5e7d572f, 0x5e7e575c, 0x5e7f573b, 0x5e815742, 0x5e825769, 0x5e835785,
+    0x5e84576b, 0x5e855786, 0x5e86577c, 0x5e87577b, 0x5e885768, 0x5e89576d, 0x5e8a5776, 0x5e8b5773,
+    0x5e8c57ad, 0x5e8d57a4, 0x5e8e578c, 0x5e8f57b2, 0x5e9057cf, 0x5e9157a7, 0x5e9257b4, 0x5e935793,
+    0x5e9457a0, 0x5e9557d5, 0x5e9657d8, 0x5e9757da, 0x5e9857d9, 0x5e9957d2, 0x5e9a57b8, 0x5e9b57f4,
+    0x5e9c57ef, 0x5e9d57f8, 0x5e9e57e4, 0x5e9f57dd, 0x5ea0580b, 0x5ea1580d, 0x5ea257fd, 0x5ea357ed,
+    0x5ea45800, 0x5ea5581e, 0x5ea65819, 0x5ea75844, 0x5ea85820, 0x5ea95865, 0x5eaa586c, 0x5eab5881,
+    0x5eac5889, 0x5ead589a, 0x5eae5880, 0x5eaf99a8, 0x5eb09f19, 0x5eb161ff, 0x5eb28279, 0x5eb3827d,
+    0x5eb4827f, 0x5eb5828f, 0x5eb6828a, 0x5eb782a8, 0x5eb88284, 0x5eb9828e, 0x5eba8291, 0x5ebb8297,
+    0x5ebc8299, 0x5ebd82ab, 0x5ebe82b8, 0x5ebf82be, 0x5ec082b0, 0x5ec182c8, 0x5ec282ca, 0x5ec382e3,
+    0x5ec48298, 0x5ec582b7, 0x5ec682ae, 0x5ec782cb, 0x5ec882cc, 0x5ec982c1, 0x5eca82a9, 0x5ecb82b4,
+    0x5ecc82a1, 0x5ecd82aa, 0x5ece829f, 0x5ecf82c4, 0x5ed082ce, 0x5ed182a4, 0x5ed282e1, 0x5ed38309,
+    0x5ed482f7, 0x5ed582e4, 0x5ed6830f, 0x5ed78307, 0x5ed882dc, 0x5ed982f4, 0x5eda82d2, 0x5edb82d8,
+    0x5edc830c, 0x5edd82fb, 0x5ede82d3, 0x5edf8311, 0x5ee0831a, 0x5ee18306, 0x5ee28314, 0x5ee38315,
+    0x5ee482e0, 0x5ee582d5, 0x5ee6831c, 0x5ee78351, 0x5ee8835b, 0x5ee9835c, 0x5eea8308, 0x5eeb8392,
+    0x5eec833c, 0x5eed8334, 0x5eee8331, 0x5eef839b, 0x5ef0835e, 0x5ef1832f, 0x5ef2834f, 0x5ef38347,
+    0x5ef48343, 0x5ef5835f, 0x5ef68340, 0x5ef78317, 0x5ef88360, 0x5ef9832d, 0x5efa833a, 0x5efb8333,
+    0x5efc8366, 0x5efd8365, 0x5f418368, 0x5f42831b, 0x5f438369, 0x5f44836c, 0x5f45836a, 0x5f46836d,
+    0x5f47836e, 0x5f4883b0, 0x5f498378, 0x5f4a83b3, 0x5f4b83b4, 0x5f4c83a0, 0x5f4d83aa, 0x5f4e8393,
+    0x5f4f839c, 0x5f508385, 0x5f51837c, 0x5f5283b6, 0x5f5383a9, 0x5f54837d, 0x5f5583b8, 0x5f56837b,
+    0x5f578398, 0x5f58839e, 0x5f5983a8, 0x5f5a83ba, 0x5f5b83bc, 0x5f5c83c1, 0x5f5d8401, 0x5f5e83e5,
+    0x5f5f83d8, 0x5f605807, 0x5f618418, 0x5f62840b, 0x5f6383dd, 0x5f6483fd, 0x5f6583d6, 0x5f66841c,
+    0x5f678438, 0x5f688411, 0x5f698406, 0x5f6a83d4, 0x5f6b83df, 0x5f6c840f, 0x5f6d8403, 0x5f6e83f8,
+    0x5f6f83f9, 0x5f7083ea, 0x5f7183c5, 0x5f7283c0, 0x5f738426, 0x5f7483f0, 0x5f7583e1, 0x5f76845c,
+    0x5f778451, 0x5f78845a, 0x5f798459, 0x5f7a8473, 0x5f7b8487, 0x5f7c8488, 0x5f7d847a, 0x5f7e8489,
+    0x5f7f8478, 0x5f81843c, 0x5f828446, 0x5f838469, 0x5f848476, 0x5f85848c, 0x5f86848e, 0x5f878431,
+    0x5f88846d, 0x5f8984c1, 0x5f8a84cd, 0x5f8b84d0, 0x5f8c84e6, 0x5f8d84bd, 0x5f8e84d3, 0x5f8f84ca,
+    0x5f9084bf, 0x5f9184ba, 0x5f9284e0, 0x5f9384a1, 0x5f9484b9, 0x5f9584b4, 0x5f968497, 0x5f9784e5,
+    0x5f9884e3, 0x5f99850c, 0x5f9a750d, 0x5f9b8538, 0x5f9c84f0, 0x5f9d8539, 0x5f9e851f, 0x5f9f853a,
+    0x5fa08556, 0x5fa1853b, 0x5fa284ff, 0x5fa384fc, 0x5fa48559, 0x5fa58548, 0x5fa68568, 0x5fa78564,
+    0x5fa8855e, 0x5fa9857a, 0x5faa77a2, 0x5fab8543, 0x5fac8572, 0x5fad857b, 0x5fae85a4, 0x5faf85a8,
+    0x5fb08587, 0x5fb1858f, 0x5fb28579, 0x5fb385ae, 0x5fb4859c, 0x5fb58585, 0x5fb685b9, 0x5fb785b7,
+    0x5fb885b0, 0x5fb985d3, 0x5fba85c1, 0x5fbb85dc, 0x5fbc85ff, 0x5fbd8627, 0x5fbe8605, 0x5fbf8629,
+    0x5fc08616, 0x5fc1863c, 0x5fc25efe, 0x5fc35f08, 0x5fc4593c, 0x5fc55941, 0x5fc68037, 0x5fc75955,
+    0x5fc8595a, 0x5fc95958, 0x5fca530f, 0x5fcb5c22, 0x5fcc5c25, 0x5fcd5c2c, 0x5fce5c34, 0x5fcf624c,
+    0x5fd0626a, 0x5fd1629f, 0x5fd262bb, 0x5fd362ca, 0x5fd462da, 0x5fd562d7, 0x5fd662ee, 0x5fd76322,
+    0x5fd862f6, 0x5fd96339, 0x5fda634b, 0x5fdb6343, 0x5fdc63ad, 0x5fdd63f6, 0x5fde6371, 0x5fdf637a,
+    0x5fe0638e, 0x5fe163b4, 0x5fe2636d, 0x5fe363ac, 0x5fe4638a, 0x5fe56369, 0x5fe663ae, 0x5fe763bc,
+    0x5fe863f2, 0x5fe963f8, 0x5fea63e0, 0x5feb63ff, 0x5fec63c4, 0x5fed63de, 0x5fee63ce, 0x5fef6452,
+    0x5ff063c6, 0x5ff163be, 0x5ff26445, 0x5ff36441, 0x5ff4640b, 0x5ff5641b, 0x5ff66420, 0x5ff7640c,
+    0x5ff86426, 0x5ff96421, 0x5ffa645e, 0x5ffb6484, 0x5ffc646d, 0x5ffd6496, 0x6041647a, 0x604264b7,
+    0x604364b8, 0x60446499, 0x604564ba, 0x604664c0, 0x604764d0, 0x604864d7, 0x604964e4, 0x604a64e2,
+    0x604b6509, 0x604c6525, 0x604d652e, 0x604e5f0b, 0x604f5fd2, 0x60507519, 0x60515f11, 0x6052535f,
+    0x605353f1, 0x605453fd, 0x605553e9, 0x605653e8, 0x605753fb, 0x60585412, 0x60595416, 0x605a5406,
+    0x605b544b, 0x605c5452, 0x605d5453, 0x605e5454, 0x605f5456, 0x60605443, 0x60615421, 0x60625457,
+    0x60635459, 0x60645423, 0x60655432, 0x60665482, 0x60675494, 0x60685477, 0x60695471, 0x606a5464,
+    0x606b549a, 0x606c549b, 0x606d5484, 0x606e5476, 0x606f5466, 0x6070549d, 0x607154d0, 0x607254ad,
+    0x607354c2, 0x607454b4, 0x607554d2, 0x607654a7, 0x607754a6, 0x607854d3, 0x607954d4, 0x607a5472,
+    0x607b54a3, 0x607c54d5, 0x607d54bb, 0x607e54bf, 0x607f54cc, 0x608154d9, 0x608254da, 0x608354dc,
+    0x608454a9, 0x608554aa, 0x608654a4, 0x608754dd, 0x608854cf, 0x608954de, 0x608a551b, 0x608b54e7,
+    0x608c5520, 0x608d54fd, 0x608e5514, 0x608f54f3, 0x60905522, 0x60915523, 0x6092550f, 0x60935511,
+    0x60945527, 0x6095552a, 0x60965567, 0x6097558f, 0x609855b5, 0x60995549, 0x609a556d, 0x609b5541,
+    0x609c5555, 0x609d553f, 0x609e5550, 0x609f553c, 0x60a05537, 0x60a15556, 0x60a25575, 0x60a35576,
+    0x60a45577, 0x60a55533, 0x60a65530, 0x60a7555c, 0x60a8558b, 0x60a955d2, 0x60aa5583, 0x60ab55b1,
+    0x60ac55b9, 0x60ad5588, 0x60ae5581, 0x60af559f, 0x60b0557e, 0x60b155d6, 0x60b25591, 0x60b3557b,
+    0x60b455df, 0x60b555bd, 0x60b655be, 0x60b75594, 0x60b85599, 0x60b955ea, 0x60ba55f7, 0x60bb55c9,
+    0x60bc561f, 0x60bd55d1, 0x60be55eb, 0x60bf55ec, 0x60c055d4, 0x60c155e6, 0x60c255dd, 0x60c355c4,
+    0x60c455ef, 0x60c555e5, 0x60c655f2, 0x60c755f3, 0x60c855cc, 0x60c955cd, 0x60ca55e8, 0x60cb55f5,
+    0x60cc55e4, 0x60cd8f94, 0x60ce561e, 0x60cf5608, 0x60d0560c, 0x60d15601, 0x60d25624, 0x60d35623,
+    0x60d455fe, 0x60d55600, 0x60d65627, 0x60d7562d, 0x60d85658, 0x60d95639, 0x60da5657, 0x60db562c,
+    0x60dc564d, 0x60dd5662, 0x60de5659, 0x60df565c, 0x60e0564c, 0x60e15654, 0x60e25686, 0x60e35664,
+    0x60e45671, 0x60e5566b, 0x60e6567b, 0x60e7567c, 0x60e85685, 0x60e95693, 0x60ea56af, 0x60eb56d4,
+    0x60ec56d7, 0x60ed56dd, 0x60ee56e1, 0x60ef56f5, 0x60f056eb, 0x60f156f9, 0x60f256ff, 0x60f35704,
+    0x60f4570a, 0x60f55709, 0x60f6571c, 0x60f75e0f, 0x60f85e19, 0x60f95e14, 0x60fa5e11, 0x60fb5e31,
+    0x60fc5e3b, 0x60fd5e3c, 0x61415e37, 0x61425e44, 0x61435e54, 0x61445e5b, 0x61455e5e, 0x61465e61,
+    0x61475c8c, 0x61485c7a, 0x61495c8d, 0x614a5c90, 0x614b5c96, 0x614c5c88, 0x614d5c98, 0x614e5c99,
+    0x614f5c91, 0x61505c9a, 0x61515c9c, 0x61525cb5, 0x61535ca2, 0x61545cbd, 0x61555cac, 0x61565cab,
+    0x61575cb1, 0x61585ca3, 0x61595cc1, 0x615a5cb7, 0x615b5cc4, 0x615c5cd2, 0x615d5ce4, 0x615e5ccb,
+    0x615f5ce5, 0x61605d02, 0x61615d03, 0x61625d27, 0x61635d26, 0x61645d2e, 0x61655d24, 0x61665d1e,
+    0x61675d06, 0x61685d1b, 0x61695d58, 0x616a5d3e, 0x616b5d34, 0x616c5d3d, 0x616d5d6c, 0x616e5d5b,
+    0x616f5d6f, 0x61705d5d, 0x61715d6b, 0x61725d4b, 0x61735d4a, 0x61745d69, 0x61755d74, 0x61765d82,
+    0x61775d99, 0x61785d9d, 0x61798c73, 0x617a5db7, 0x617b5dc5, 0x617c5f73, 0x617d5f77, 0x617e5f82,
+    0x617f5f87, 0x61815f89, 0x61825f8c, 0x61835f95, 0x61845f99, 0x61855f9c, 0x61865fa8, 0x61875fad,
+    0x61885fb5, 0x61895fbc, 0x618a8862, 0x618b5f61, 0x618c72ad, 0x618d72b0, 0x618e72b4, 0x618f72b7,
+    0x619072b8, 0x619172c3, 0x619272c1, 0x619372ce, 0x619472cd, 0x619572d2, 0x619672e8, 0x619772ef,
+    0x619872e9, 0x619972f2, 0x619a72f4, 0x619b72f7, 0x619c7301, 0x619d72f3, 0x619e7303, 0x619f72fa,
+    0x61a072fb, 0x61a17317, 0x61a27313, 0x61a37321, 0x61a4730a, 0x61a5731e, 0x61a6731d, 0x61a77315,
+    0x61a87322, 0x61a97339, 0x61aa7325, 0x61ab732c, 0x61ac7338, 0x61ad7331, 0x61ae7350, 0x61af734d,
+    0x61b07357, 0x61b17360, 0x61b2736c, 0x61b3736f, 0x61b4737e, 0x61b5821b, 0x61b65925, 0x61b798e7,
+    0x61b85924, 0x61b95902, 0x61ba9963, 0x61bb9967, 0x61bc9968, 0x61bd9969, 0x61be996a, 0x61bf996b,
+    0x61c0996c, 0x61c19974, 0x61c29977, 0x61c3997d, 0x61c49980, 0x61c59984, 0x61c69987, 0x61c7998a,
+    0x61c8998d, 0x61c99990, 0x61ca9991, 0x61cb9993, 0x61cc9994, 0x61cd9995, 0x61ce5e80, 0x61cf5e91,
+    0x61d05e8b, 0x61d15e96, 0x61d25ea5, 0x61d35ea0, 0x61d45eb9, 0x61d55eb5, 0x61d65ebe, 0x61d75eb3,
+    0x61d88d53, 0x61d95ed2, 0x61da5ed1, 0x61db5edb, 0x61dc5ee8, 0x61dd5eea, 0x61de81ba, 0x61df5fc4,
+    0x61e05fc9, 0x61e15fd6, 0x61e25fcf, 0x61e36003, 0x61e45fee, 0x61e56004, 0x61e65fe1, 0x61e75fe4,
+    0x61e85ffe, 0x61e96005, 0x61ea6006, 0x61eb5fea, 0x61ec5fed, 0x61ed5ff8, 0x61ee6019, 0x61ef6035,
+    0x61f06026, 0x61f1601b, 0x61f2600f, 0x61f3600d, 0x61f46029, 0x61f5602b, 0x61f6600a, 0x61f7603f,
+    0x61f86021, 0x61f96078, 0x61fa6079, 0x61fb607b, 0x61fc607a, 0x61fd6042, 0x6241606a, 0x6242607d,
+    0x62436096, 0x6244609a, 0x624560ad, 0x6246609d, 0x62476083, 0x62486092, 0x6249608c, 0x624a609b,
+    0x624b60ec, 0x624c60bb, 0x624d60b1, 0x624e60dd, 0x624f60d8, 0x625060c6, 0x625160da, 0x625260b4,
+    0x62536120, 0x62546126, 0x62556115, 0x62566123, 0x625760f4, 0x62586100, 0x6259610e, 0x625a612b,
+    0x625b614a, 0x625c6175, 0x625d61ac, 0x625e6194, 0x625f61a7, 0x626061b7, 0x626161d4, 0x626261f5,
+    0x62635fdd, 0x626496b3, 0x626595e9, 0x626695eb, 0x626795f1, 0x626895f3, 0x626995f5, 0x626a95f6,
+    0x626b95fc, 0x626c95fe, 0x626d9603, 0x626e9604, 0x626f9606, 0x62709608, 0x6271960a, 0x6272960b,
+    0x6273960c, 0x6274960d, 0x6275960f, 0x62769612, 0x62779615, 0x62789616, 0x62799617, 0x627a9619,
+    0x627b961a, 0x627c4e2c, 0x627d723f, 0x627e6215, 0x627f6c35, 0x62816c54, 0x62826c5c, 0x62836c4a,
+    0x62846ca3, 0x62856c85, 0x62866c90, 0x62876c94, 0x62886c8c, 0x62896c68, 0x628a6c69, 0x628b6c74,
+    0x628c6c76, 0x628d6c86, 0x628e6ca9, 0x628f6cd0, 0x62906cd4, 0x62916cad, 0x62926cf7, 0x62936cf8,
+    0x62946cf1, 0x62956cd7, 0x62966cb2, 0x62976ce0, 0x62986cd6, 0x62996cfa, 0x629a6ceb, 0x629b6cee,
+    0x629c6cb1, 0x629d6cd3, 0x629e6cef, 0x629f6cfe, 0x62a06d39, 0x62a16d27, 0x62a26d0c, 0x62a36d43,
+    0x62a46d48, 0x62a56d07, 0x62a66d04, 0x62a76d19, 0x62a86d0e, 0x62a96d2b, 0x62aa6d4d, 0x62ab6d2e,
+    0x62ac6d35, 0x62ad6d1a, 0x62ae6d4f, 0x62af6d52, 0x62b06d54, 0x62b16d33, 0x62b26d91, 0x62b36d6f,
+    0x62b46d9e, 0x62b56da0, 0x62b66d5e, 0x62b76d93, 0x62b86d94, 0x62b96d5c, 0x62ba6d60, 0x62bb6d7c,
+    0x62bc6d63, 0x62bd6e1a, 0x62be6dc7, 0x62bf6dc5, 0x62c06dde, 0x62c16e0e, 0x62c26dbf, 0x62c36de0,
+    0x62c46e11, 0x62c56de6, 0x62c66ddd, 0x62c76dd9, 0x62c86e16, 0x62c96dab, 0x62ca6e0c, 0x62cb6dae,
+    0x62cc6e2b, 0x62cd6e6e, 0x62ce6e4e, 0x62cf6e6b, 0x62d06eb2, 0x62d16e5f, 0x62d26e86, 0x62d36e53,
+    0x62d46e54, 0x62d56e32, 0x62d66e25, 0x62d76e44, 0x62d86edf, 0x62d96eb1, 0x62da6e98, 0x62db6ee0,
+    0x62dc6f2d, 0x62dd6ee2, 0x62de6ea5, 0x62df6ea7, 0x62e06ebd, 0x62e16ebb, 0x62e26eb7, 0x62e36ed7,
+    0x62e46eb4, 0x62e56ecf, 0x62e66e8f, 0x62e76ec2, 0x62e86e9f, 0x62e96f62, 0x62ea6f46, 0x62eb6f47,
+    0x62ec6f24, 0x62ed6f15, 0x62ee6ef9, 0x62ef6f2f, 0x62f06f36, 0x62f16f4b, 0x62f26f74, 0x62f36f2a,
+    0x62f46f09, 0x62f56f29, 0x62f66f89, 0x62f76f8d, 0x62f86f8c, 0x62f96f78, 0x62fa6f72, 0x62fb6f7c,
+    0x62fc6f7a, 0x62fd6fd1, 0x63416fc9, 0x63426fa7, 0x63436fb9, 0x63446fb6, 0x63456fc2, 0x63466fe1,
+    0x63476fee, 0x63486fde, 0x63496fe0, 0x634a6fef, 0x634b701a, 0x634c7023, 0x634d701b, 0x634e7039,
+    0x634f7035, 0x6350704f, 0x6351705e, 0x63525b80, 0x63535b84, 0x63545b95, 0x63555b93, 0x63565ba5,
+    0x63575bb8, 0x6358752f, 0x63599a9e, 0x635a6434, 0x635b5be4, 0x635c5bee, 0x635d8930, 0x635e5bf0,
+    0x635f8e47, 0x63608b07, 0x63618fb6, 0x63628fd3, 0x63638fd5, 0x63648fe5, 0x63658fee, 0x63668fe4,
+    0x63678fe9, 0x63688fe6, 0x63698ff3, 0x636a8fe8, 0x636b9005, 0x636c9004, 0x636d900b, 0x636e9026,
+    0x636f9011, 0x6370900d, 0x63719016, 0x63729021, 0x63739035, 0x63749036, 0x6375902d, 0x6376902f,
+    0x63779044, 0x63789051, 0x63799052, 0x637a9050, 0x637b9068, 0x637c9058, 0x637d9062, 0x637e905b,
+    0x637f66b9, 0x63819074, 0x6382907d, 0x63839082, 0x63849088, 0x63859083, 0x6386908b, 0x63875f50,
+    0x63885f57, 0x63895f56, 0x638a5f58, 0x638b5c3b, 0x638c54ab, 0x638d5c50, 0x638e5c59, 0x638f5b71,
+    0x63905c63, 0x63915c66, 0x63927fbc, 0x63935f2a, 0x63945f29, 0x63955f2d, 0x63968274, 0x63975f3c,
+    0x63989b3b, 0x63995c6e, 0x639a5981, 0x639b5983, 0x639c598d, 0x639d59a9, 0x639e59aa, 0x639f59a3,
+    0x63a05997, 0x63a159ca, 0x63a259ab, 0x63a3599e, 0x63a459a4, 0x63a559d2, 0x63a659b2, 0x63a759af,
+    0x63a859d7, 0x63a959be, 0x63aa5a05, 0x63ab5a06, 0x63ac59dd, 0x63ad5a08, 0x63ae59e3, 0x63af59d8,
+    0x63b059f9, 0x63b15a0c, 0x63b25a09, 0x63b35a32, 0x63b45a34, 0x63b55a11, 0x63b65a23, 0x63b75a13,
+    0x63b85a40, 0x63b95a67, 0x63ba5a4a, 0x63bb5a55, 0x63bc5a3c, 0x63bd5a62, 0x63be5a75, 0x63bf80ec,
+    0x63c05aaa, 0x63c15a9b, 0x63c25a77, 0x63c35a7a, 0x63c45abe, 0x63c55aeb, 0x63c65ab2, 0x63c75ad2,
+    0x63c85ad4, 0x63c95ab8, 0x63ca5ae0, 0x63cb5ae3, 0x63cc5af1, 0x63cd5ad6, 0x63ce5ae6, 0x63cf5ad8,
+    0x63d05adc, 0x63d15b09, 0x63d25b17, 0x63d35b16, 0x63d45b32, 0x63d55b37, 0x63d65b40, 0x63d75c15,
+    0x63d85c1c, 0x63d95b5a, 0x63da5b65, 0x63db5b73, 0x63dc5b51, 0x63dd5b53, 0x63de5b62, 0x63df9a75,
+    0x63e09a77, 0x63e19a78, 0x63e29a7a, 0x63e39a7f, 0x63e49a7d, 0x63e59a80, 0x63e69a81, 0x63e79a85,
+    0x63e89a88, 0x63e99a8a, 0x63ea9a90, 0x63eb9a92, 0x63ec9a93, 0x63ed9a96, 0x63ee9a98, 0x63ef9a9b,
+    0x63f09a9c, 0x63f19a9d, 0x63f29a9f, 0x63f39aa0, 0x63f49aa2, 0x63f59aa3, 0x63f69aa5, 0x63f79aa7,
+    0x63f87e9f, 0x63f97ea1, 0x63fa7ea3, 0x63fb7ea5, 0x63fc7ea8, 0x63fd7ea9, 0x64417ead, 0x64427eb0,
+    0x64437ebe, 0x64447ec0, 0x64457ec1, 0x64467ec2, 0x64477ec9, 0x64487ecb, 0x64497ecc, 0x644a7ed0,
+    0x644b7ed4, 0x644c7ed7, 0x644d7edb, 0x644e7ee0, 0x644f7ee1, 0x64507ee8, 0x64517eeb, 0x64527eee,
+    0x64537eef, 0x64547ef1, 0x64557ef2, 0x64567f0d, 0x64577ef6, 0x64587efa, 0x64597efb, 0x645a7efe,
+    0x645b7f01, 0x645c7f02, 0x645d7f03, 0x645e7f07, 0x645f7f08, 0x64607f0b, 0x64617f0c, 0x64627f0f,
+    0x64637f11, 0x64647f12, 0x64657f17, 0x64667f19, 0x64677f1c, 0x64687f1b, 0x64697f1f, 0x646a7f21,
+    0x646b7f22, 0x646c7f23, 0x646d7f24, 0x646e7f25, 0x646f7f26, 0x64707f27, 0x64717f2a, 0x64727f2b,
+    0x64737f2c, 0x64747f2d, 0x64757f2f, 0x64767f30, 0x64777f31, 0x64787f32, 0x64797f33, 0x647a7f35,
+    0x647b5e7a, 0x647c757f, 0x647d5ddb, 0x647e753e, 0x647f9095, 0x6481738e, 0x64827391, 0x648373ae,
+    0x648473a2, 0x6485739f, 0x648673cf, 0x648773c2, 0x648873d1, 0x648973b7, 0x648a73b3, 0x648b73c0,
+    0x648c73c9, 0x648d73c8, 0x648e73e5, 0x648f73d9, 0x6490987c, 0x6491740a, 0x649273e9, 0x649373e7,
+    0x649473de, 0x649573ba, 0x649673f2, 0x6497740f, 0x6498742a, 0x6499745b, 0x649a7426, 0x649b7425,
+    0x649c7428, 0x649d7430, 0x649e742e, 0x649f742c, 0x64a0741b, 0x64a1741a, 0x64a27441, 0x64a3745c,
+    0x64a47457, 0x64a57455, 0x64a67459, 0x64a77477, 0x64a8746d, 0x64a9747e, 0x64aa749c, 0x64ab748e,
+    0x64ac7480, 0x64ad7481, 0x64ae7487, 0x64af748b, 0x64b0749e, 0x64b174a8, 0x64b274a9, 0x64b37490,
+    0x64b474a7, 0x64b574d2, 0x64b674ba, 0x64b797ea, 0x64b897eb, 0x64b997ec, 0x64ba674c, 0x64bb6753,
+    0x64bc675e, 0x64bd6748, 0x64be6769, 0x64bf67a5, 0x64c06787, 0x64c1676a, 0x64c26773, 0x64c36798,
+    0x64c467a7, 0x64c56775, 0x64c667a8, 0x64c7679e, 0x64c867ad, 0x64c9678b, 0x64ca6777, 0x64cb677c,
+    0x64cc67f0, 0x64cd6809, 0x64ce67d8, 0x64cf680a, 0x64d067e9, 0x64d167b0, 0x64d2680c, 0x64d367d9,
+    0x64d467b5, 0x64d567da, 0x64d667b3, 0x64d767dd, 0x64d86800, 0x64d967c3, 0x64da67b8, 0x64db67e2,
+    0x64dc680e, 0x64dd67c1, 0x64de67fd, 0x64df6832, 0x64e06833, 0x64e16860, 0x64e26861, 0x64e3684e,
+    0x64e46862, 0x64e56844, 0x64e66864, 0x64e76883, 0x64e8681d, 0x64e96855, 0x64ea6866, 0x64eb6841,
+    0x64ec6867, 0x64ed6840, 0x64ee683e, 0x64ef684a, 0x64f06849, 0x64f16829, 0x64f268b5, 0x64f3688f,
+    0x64f46874, 0x64f56877, 0x64f66893, 0x64f7686b, 0x64f868c2, 0x64f9696e, 0x64fa68fc, 0x64fb691f,
+    0x64fc6920, 0x64fd68f9, 0x65416924, 0x654268f0, 0x6543690b, 0x65446901, 0x65456957, 0x654668e3,
+    0x65476910, 0x65486971, 0x65496939, 0x654a6960, 0x654b6942, 0x654c695d, 0x654d6984, 0x654e696b,
+    0x654f6980, 0x65506998, 0x65516978, 0x65526934, 0x655369cc, 0x65546987, 0x65556988, 0x655669ce,
+    0x65576989, 0x65586966, 0x65596963, 0x655a6979, 0x655b699b, 0x655c69a7, 0x655d69bb, 0x655e69ab,
+    0x655f69ad, 0x656069d4, 0x656169b1, 0x656269c1, 0x656369ca, 0x656469df, 0x65656995, 0x656669e0,
+    0x6567698d, 0x656869ff, 0x65696a2f, 0x656a69ed, 0x656b6a17, 0x656c6a18, 0x656d6a65, 0x656e69f2,
+    0x656f6a44, 0x65706a3e, 0x65716aa0, 0x65726a50, 0x65736a5b, 0x65746a35, 0x65756a8e, 0x65766a79,
+    0x65776a3d, 0x65786a28, 0x65796a58, 0x657a6a7c, 0x657b6a91, 0x657c6a90, 0x657d6aa9, 0x657e6a97,
+    0x657f6aab, 0x65817337, 0x65827352, 0x65836b81, 0x65846b82, 0x65856b87, 0x65866b84, 0x65876b92,
+    0x65886b93, 0x65896b8d, 0x658a6b9a, 0x658b6b9b, 0x658c6ba1, 0x658d6baa, 0x658e8f6b, 0x658f8f6d,
+    0x65908f71, 0x65918f72, 0x65928f73, 0x65938f75, 0x65948f76, 0x65958f78, 0x65968f77, 0x65978f79,
+    0x65988f7a, 0x65998f7c, 0x659a8f7e, 0x659b8f81, 0x659c8f82, 0x659d8f84, 0x659e8f87, 0x659f8f8b,
+    0x65a08f8d, 0x65a18f8e, 0x65a28f8f, 0x65a38f98, 0x65a48f9a, 0x65a58ece, 0x65a6620b, 0x65a76217,
+    0x65a8621b, 0x65a9621f, 0x65aa6222, 0x65ab6221, 0x65ac6225, 0x65ad6224, 0x65ae622c, 0x65af81e7,
+    0x65b074ef, 0x65b174f4, 0x65b274ff, 0x65b3750f, 0x65b47511, 0x65b57513, 0x65b66534, 0x65b765ee,
+    0x65b865ef, 0x65b965f0, 0x65ba660a, 0x65bb6619, 0x65bc6772, 0x65bd6603, 0x65be6615, 0x65bf6600,
+    0x65c07085, 0x65c166f7, 0x65c2661d, 0x65c36634, 0x65c46631, 0x65c56636, 0x65c66635, 0x65c78006,
+    0x65c8665f, 0x65c96654, 0x65ca6641, 0x65cb664f, 0x65cc6656, 0x65cd6661, 0x65ce6657, 0x65cf6677,
+    0x65d06684, 0x65d1668c, 0x65d266a7, 0x65d3669d, 0x65d466be, 0x65d566db, 0x65d666dc, 0x65d766e6,
+    0x65d866e9, 0x65d98d32, 0x65da8d33, 0x65db8d36, 0x65dc8d3b, 0x65dd8d3d, 0x65de8d40, 0x65df8d45,
+    0x65e08d46, 0x65e18d48, 0x65e28d49, 0x65e38d47, 0x65e48d4d, 0x65e58d55, 0x65e68d59, 0x65e789c7,
+    0x65e889ca, 0x65e989cb, 0x65ea89cc, 0x65eb89ce, 0x65ec89cf, 0x65ed89d0, 0x65ee89d1, 0x65ef726e,
+    0x65f0729f, 0x65f1725d, 0x65f27266, 0x65f3726f, 0x65f4727e, 0x65f5727f, 0x65f67284, 0x65f7728b,
+    0x65f8728d, 0x65f9728f, 0x65fa7292, 0x65fb6308, 0x65fc6332, 0x65fd63b0, 0x6641643f, 0x664264d8,
+    0x66438004, 0x66446bea, 0x66456bf3, 0x66466bfd, 0x66476bf5, 0x66486bf9, 0x66496c05, 0x664a6c07,
+    0x664b6c06, 0x664c6c0d, 0x664d6c15, 0x664e6c18, 0x664f6c19, 0x66506c1a, 0x66516c21, 0x66526c29,
+    0x66536c24, 0x66546c2a, 0x66556c32, 0x66566535, 0x66576555, 0x6658656b, 0x6659724d, 0x665a7252,
+    0x665b7256, 0x665c7230, 0x665d8662, 0x665e5216, 0x665f809f, 0x6660809c, 0x66618093, 0x666280bc,
+    0x6663670a, 0x666480bd, 0x666580b1, 0x666680ab, 0x666780ad, 0x666880b4, 0x666980b7, 0x666a80e7,
+    0x666b80e8, 0x666c80e9, 0x666d80ea, 0x666e80db, 0x666f80c2, 0x667080c4, 0x667180d9, 0x667280cd,
+    0x667380d7, 0x66746710, 0x667580dd, 0x667680eb, 0x667780f1, 0x667880f4, 0x667980ed, 0x667a810d,
+    0x667b810e, 0x667c80f2, 0x667d80fc, 0x667e6715, 0x667f8112, 0x66818c5a, 0x66828136, 0x6683811e,
+    0x6684812c, 0x66858118, 0x66868132, 0x66878148, 0x6688814c, 0x66898153, 0x668a8174, 0x668b8159,
+    0x668c815a, 0x668d8171, 0x668e8160, 0x668f8169, 0x6690817c, 0x6691817d, 0x6692816d, 0x66938167,
+    0x6694584d, 0x66955ab5, 0x66968188, 0x66978182, 0x66988191, 0x66996ed5, 0x669a81a3, 0x669b81aa,
+    0x669c81cc, 0x669d6726, 0x669e81ca, 0x669f81bb, 0x66a081c1, 0x66a181a6, 0x66a26b24, 0x66a36b37,
+    0x66a46b39, 0x66a56b43, 0x66a66b46, 0x66a76b59, 0x66a898d1, 0x66a998d2, 0x66aa98d3, 0x66ab98d5,
+    0x66ac98d9, 0x66ad98da, 0x66ae6bb3, 0x66af5f40, 0x66b06bc2, 0x66b189f3, 0x66b26590, 0x66b39f51,
+    0x66b46593, 0x66b565bc, 0x66b665c6, 0x66b765c4, 0x66b865c3, 0x66b965cc, 0x66ba65ce, 0x66bb65d2,
+    0x66bc65d6, 0x66bd7080, 0x66be709c, 0x66bf7096, 0x66c0709d, 0x66c170bb, 0x66c270c0, 0x66c370b7,
+    0x66c470ab, 0x66c570b1, 0x66c670e8, 0x66c770ca, 0x66c87110, 0x66c97113, 0x66ca7116, 0x66cb712f,
+    0x66cc7131, 0x66cd7173, 0x66ce715c, 0x66cf7168, 0x66d07145, 0x66d17172, 0x66d2714a, 0x66d37178,
+    0x66d4717a, 0x66d57198, 0x66d671b3, 0x66d771b5, 0x66d871a8, 0x66d971a0, 0x66da71e0, 0x66db71d4,
+    0x66dc71e7, 0x66dd71f9, 0x66de721d, 0x66df7228, 0x66e0706c, 0x66e17118, 0x66e27166, 0x66e371b9,
+    0x66e4623e, 0x66e5623d, 0x66e66243, 0x66e76248, 0x66e86249, 0x66e9793b, 0x66ea7940, 0x66eb7946,
+    0x66ec7949, 0x66ed795b, 0x66ee795c, 0x66ef7953, 0x66f0795a, 0x66f17962, 0x66f27957, 0x66f37960,
+    0x66f4796f, 0x66f57967, 0x66f6797a, 0x66f77985, 0x66f8798a, 0x66f9799a, 0x66fa79a7, 0x66fb79b3,
+    0x66fc5fd1, 0x66fd5fd0, 0x6741603c, 0x6742605d, 0x6743605a, 0x67446067, 0x67456041, 0x67466059,
+    0x67476063, 0x674860ab, 0x67496106, 0x674a610d, 0x674b615d, 0x674c61a9, 0x674d619d, 0x674e61cb,
+    0x674f61d1, 0x67506206, 0x67518080, 0x6752807f, 0x67536c93, 0x67546cf6, 0x67556dfc, 0x675677f6,
+    0x675777f8, 0x67587800, 0x67597809, 0x675a7817, 0x675b7818, 0x675c7811, 0x675d65ab, 0x675e782d,
+    0x675f781c, 0x6760781d, 0x67617839, 0x6762783a, 0x6763783b, 0x6764781f, 0x6765783c, 0x67667825,
+    0x6767782c, 0x67687823, 0x67697829, 0x676a784e, 0x676b786d, 0x676c7856, 0x676d7857, 0x676e7826,
+    0x676f7850, 0x67707847, 0x6771784c, 0x6772786a, 0x6773789b, 0x67747893, 0x6775789a, 0x67767887,
+    0x6777789c, 0x677878a1, 0x677978a3, 0x677a78b2, 0x677b78b9, 0x677c78a5, 0x677d78d4, 0x677e78d9,
+    0x677f78c9, 0x678178ec, 0x678278f2, 0x67837905, 0x678478f4, 0x67857913, 0x67867924, 0x6787791e,
+    0x67887934, 0x67899f9b, 0x678a9ef9, 0x678b9efb, 0x678c9efc, 0x678d76f1, 0x678e7704, 0x678f770d,
+    0x679076f9, 0x67917707, 0x67927708, 0x6793771a, 0x67947722, 0x67957719, 0x6796772d, 0x67977726,
+    0x67987735, 0x67997738, 0x679a7750, 0x679b7751, 0x679c7747, 0x679d7743, 0x679e775a, 0x679f7768,
+    0x67a07762, 0x67a17765, 0x67a2777f, 0x67a3778d, 0x67a4777d, 0x67a57780, 0x67a6778c, 0x67a77791,
+    0x67a8779f, 0x67a977a0, 0x67aa77b0, 0x67ab77b5, 0x67ac77bd, 0x67ad753a, 0x67ae7540, 0x67af754e,
+    0x67b0754b, 0x67b17548, 0x67b2755b, 0x67b37572, 0x67b47579, 0x67b57583, 0x67b67f58, 0x67b77f61,
+    0x67b87f5f, 0x67b98a48, 0x67ba7f68, 0x67bb7f74, 0x67bc7f71, 0x67bd7f79, 0x67be7f81, 0x67bf7f7e,
+    0x67c076cd, 0x67c176e5, 0x67c28832, 0x67c39485, 0x67c49486, 0x67c59487, 0x67c6948b, 0x67c7948a,
+    0x67c8948c, 0x67c9948d, 0x67ca948f, 0x67cb9490, 0x67cc9494, 0x67cd9497, 0x67ce9495, 0x67cf949a,
+    0x67d0949b, 0x67d1949c, 0x67d294a3, 0x67d394a4, 0x67d494ab, 0x67d594aa, 0x67d694ad, 0x67d794ac,
+    0x67d894af, 0x67d994b0, 0x67da94b2, 0x67db94b4, 0x67dc94b6, 0x67dd94b7, 0x67de94b8, 0x67df94b9,
+    0x67e094ba, 0x67e194bc, 0x67e294bd, 0x67e394bf, 0x67e494c4, 0x67e594c8, 0x67e694c9, 0x67e794ca,
+    0x67e894cb, 0x67e994cc, 0x67ea94cd, 0x67eb94ce, 0x67ec94d0, 0x67ed94d1, 0x67ee94d2, 0x67ef94d5,
+    0x67f094d6, 0x67f194d7, 0x67f294d9, 0x67f394d8, 0x67f494db, 0x67f594de, 0x67f694df, 0x67f794e0,
+    0x67f894e2, 0x67f994e4, 0x67fa94e5, 0x67fb94e7, 0x67fc94e8, 0x67fd94ea, 0x684194e9, 0x684294eb,
+    0x684394ee, 0x684494ef, 0x684594f3, 0x684694f4, 0x684794f5, 0x684894f7, 0x684994f9, 0x684a94fc,
+    0x684b94fd, 0x684c94ff, 0x684d9503, 0x684e9502, 0x684f9506, 0x68509507, 0x68519509, 0x6852950a,
+    0x6853950d, 0x6854950e, 0x6855950f, 0x68569512, 0x68579513, 0x68589514, 0x68599515, 0x685a9516,
+    0x685b9518, 0x685c951b, 0x685d951d, 0x685e951e, 0x685f951f, 0x68609522, 0x6861952a, 0x6862952b,
+    0x68639529, 0x6864952c, 0x68659531, 0x68669532, 0x68679534, 0x68689536, 0x68699537, 0x686a9538,
+    0x686b953c, 0x686c953e, 0x686d953f, 0x686e9542, 0x686f9535, 0x68709544, 0x68719545, 0x68729546,
+    0x68739549, 0x6874954c, 0x6875954e, 0x6876954f, 0x68779552, 0x68789553, 0x68799554, 0x687a9556,
+    0x687b9557, 0x687c9558, 0x687d9559, 0x687e955b, 0x687f955e, 0x6881955f, 0x6882955d, 0x68839561,
+    0x68849562, 0x68859564, 0x68869565, 0x68879566, 0x68889567, 0x68899568, 0x688a9569, 0x688b956a,
+    0x688c956b, 0x688d956c, 0x688e956f, 0x688f9571, 0x68909572, 0x68919573, 0x6892953a, 0x689377e7,
+    0x689477ec, 0x689596c9, 0x689679d5, 0x689779ed, 0x689879e3, 0x689979eb, 0x689a7a06, 0x689b5d47,
+    0x689c7a03, 0x689d7a02, 0x689e7a1e, 0x689f7a14, 0x68a07a39, 0x68a17a37, 0x68a27a51, 0x68a39ecf,
+    0x68a499a5, 0x68a57a70, 0x68a67688, 0x68a7768e, 0x68a87693, 0x68a97699, 0x68aa76a4, 0x68ab74de,
+    0x68ac74e0, 0x68ad752c, 0x68ae9e20, 0x68af9e22, 0x68b09e28, 0x68b19e29, 0x68b29e2a, 0x68b39e2b,
+    0x68b49e2c, 0x68b59e32, 0x68b69e31, 0x68b79e36, 0x68b89e38, 0x68b99e37, 0x68ba9e39, 0x68bb9e3a,
+    0x68bc9e3e, 0x68bd9e41, 0x68be9e42, 0x68bf9e44, 0x68c09e46, 0x68c19e47, 0x68c29e48, 0x68c39e49,
+    0x68c49e4b, 0x68c59e4c, 0x68c69e4e, 0x68c79e51, 0x68c89e55, 0x68c99e57, 0x68ca9e5a, 0x68cb9e5b,
+    0x68cc9e5c, 0x68cd9e5e, 0x68ce9e63, 0x68cf9e66, 0x68d09e67, 0x68d19e68, 0x68d29e69, 0x68d39e6a,
+    0x68d49e6b, 0x68d59e6c, 0x68d69e71, 0x68d79e6d, 0x68d89e73, 0x68d97592, 0x68da7594, 0x68db7596,
+    0x68dc75a0, 0x68dd759d, 0x68de75ac, 0x68df75a3, 0x68e075b3, 0x68e175b4, 0x68e275b8, 0x68e375c4,
+    0x68e475b1, 0x68e575b0, 0x68e675c3, 0x68e775c2, 0x68e875d6, 0x68e975cd, 0x68ea75e3, 0x68eb75e8,
+    0x68ec75e6, 0x68ed75e4, 0x68ee75eb, 0x68ef75e7, 0x68f07603, 0x68f175f1, 0x68f275fc, 0x68f375ff,
+    0x68f47610, 0x68f57600, 0x68f67605, 0x68f7760c, 0x68f87617, 0x68f9760a, 0x68fa7625, 0x68fb7618,
+    0x68fc7615, 0x68fd7619, 0x6941761b, 0x6942763c, 0x69437622, 0x69447620, 0x69457640, 0x6946762d,
+    0x69477630, 0x6948763f, 0x69497635, 0x694a7643, 0x694b763e, 0x694c7633, 0x694d764d, 0x694e765e,
+    0x694f7654, 0x6950765c, 0x69517656, 0x6952766b, 0x6953766f, 0x69547fca, 0x69557ae6, 0x69567a78,
+    0x69577a79, 0x69587a80, 0x69597a86, 0x695a7a88, 0x695b7a95, 0x695c7aa6, 0x695d7aa0, 0x695e7aac,
+    0x695f7aa8, 0x69607aad, 0x69617ab3, 0x69628864, 0x69638869, 0x69648872, 0x6965887d, 0x6966887f,
+    0x69678882, 0x696888a2, 0x696988c6, 0x696a88b7, 0x696b88bc, 0x696c88c9, 0x696d88e2, 0x696e88ce,
+    0x696f88e3, 0x697088e5, 0x697188f1, 0x6972891a, 0x697388fc, 0x697488e8, 0x697588fe, 0x697688f0,
+    0x69778921, 0x69788919, 0x69798913, 0x697a891b, 0x697b890a, 0x697c8934, 0x697d892b, 0x697e8936,
+    0x697f8941, 0x69818966, 0x6982897b, 0x6983758b, 0x698480e5, 0x698576b2, 0x698676b4, 0x698777dc,
+    0x69888012, 0x69898014, 0x698a8016, 0x698b801c, 0x698c8020, 0x698d8022, 0x698e8025, 0x698f8026,
+    0x69908027, 0x69918029, 0x69928028, 0x69938031, 0x6994800b, 0x69958035, 0x69968043, 0x69978046,
+    0x6998804d, 0x69998052, 0x699a8069, 0x699b8071, 0x699c8983, 0x699d9878, 0x699e9880, 0x699f9883,
+    0x69a09889, 0x69a1988c, 0x69a2988d, 0x69a3988f, 0x69a49894, 0x69a5989a, 0x69a6989b, 0x69a7989e,
+    0x69a8989f, 0x69a998a1, 0x69aa98a2, 0x69ab98a5, 0x69ac98a6, 0x69ad864d, 0x69ae8654, 0x69af866c,
+    0x69b0866e, 0x69b1867f, 0x69b2867a, 0x69b3867c, 0x69b4867b, 0x69b586a8, 0x69b6868d, 0x69b7868b,
+    0x69b886ac, 0x69b9869d, 0x69ba86a7, 0x69bb86a3, 0x69bc86aa, 0x69bd8693, 0x69be86a9, 0x69bf86b6,
+    0x69c086c4, 0x69c186b5, 0x69c286ce, 0x69c386b0, 0x69c486ba, 0x69c586b1, 0x69c686af, 0x69c786c9,
+    0x69c886cf, 0x69c986b4, 0x69ca86e9, 0x69cb86f1, 0x69cc86f2, 0x69cd86ed, 0x69ce86f3, 0x69cf86d0,
+    0x69d08713, 0x69d186de, 0x69d286f4, 0x69d386df, 0x69d486d8, 0x69d586d1, 0x69d68703, 0x69d78707,
+    0x69d886f8, 0x69d98708, 0x69da870a, 0x69db870d, 0x69dc8709, 0x69dd8723, 0x69de873b, 0x69df871e,
+    0x69e08725, 0x69e1872e, 0x69e2871a, 0x69e3873e, 0x69e48748, 0x69e58734, 0x69e68731, 0x69e78729,
+    0x69e88737, 0x69e9873f, 0x69ea8782, 0x69eb8722, 0x69ec877d, 0x69ed877e, 0x69ee877b, 0x69ef8760,
+    0x69f08770, 0x69f1874c, 0x69f2876e, 0x69f3878b, 0x69f48753, 0x69f58763, 0x69f6877c, 0x69f78764,
+    0x69f88759, 0x69f98765, 0x69fa8793, 0x69fb87af, 0x69fc87a8, 0x69fd87d2, 0x6a4187c6, 0x6a428788,
+    0x6a438785, 0x6a4487ad, 0x6a458797, 0x6a468783, 0x6a4787ab, 0x6a4887e5, 0x6a4987ac, 0x6a4a87b5,
+    0x6a4b87b3, 0x6a4c87cb, 0x6a4d87d3, 0x6a4e87bd, 0x6a4f87d1, 0x6a5087c0, 0x6a5187ca, 0x6a5287db,
+    0x6a5387ea, 0x6a5487e0, 0x6a5587ee, 0x6a568816, 0x6a578813, 0x6a5887fe, 0x6a59880a, 0x6a5a881b,
+    0x6a5b8821, 0x6a5c8839, 0x6a5d883c, 0x6a5e7f36, 0x6a5f7f42, 0x6a607f44, 0x6a617f45, 0x6a628210,
+    0x6a637afa, 0x6a647afd, 0x6a657b08, 0x6a667b03, 0x6a677b04, 0x6a687b15, 0x6a697b0a, 0x6a6a7b2b,
+    0x6a6b7b0f, 0x6a6c7b47, 0x6a6d7b38, 0x6a6e7b2a, 0x6a6f7b19, 0x6a707b2e, 0x6a717b31, 0x6a727b20,
+    0x6a737b25, 0x6a747b24, 0x6a757b33, 0x6a767b3e, 0x6a777b1e, 0x6a787b58, 0x6a797b5a, 0x6a7a7b45,
+    0x6a7b7b75, 0x6a7c7b4c, 0x6a7d7b5d, 0x6a7e7b60, 0x6a7f7b6e, 0x6a817b7b, 0x6a827b62, 0x6a837b72,
+    0x6a847b71, 0x6a857b90, 0x6a867ba6, 0x6a877ba7, 0x6a887bb8, 0x6a897bac, 0x6a8a7b9d, 0x6a8b7ba8,
+    0x6a8c7b85, 0x6a8d7baa, 0x6a8e7b9c, 0x6a8f7ba2, 0x6a907bab, 0x6a917bb4, 0x6a927bd1, 0x6a937bc1,
+    0x6a947bcc, 0x6a957bdd, 0x6a967bda, 0x6a977be5, 0x6a987be6, 0x6a997bea, 0x6a9a7c0c, 0x6a9b7bfe,
+    0x6a9c7bfc, 0x6a9d7c0f, 0x6a9e7c16, 0x6a9f7c0b, 0x6aa07c1f, 0x6aa17c2a, 0x6aa27c26, 0x6aa37c38,
+    0x6aa47c41, 0x6aa57c40, 0x6aa681fe, 0x6aa78201, 0x6aa88202, 0x6aa98204, 0x6aaa81ec, 0x6aab8844,
+    0x6aac8221, 0x6aad8222, 0x6aae8223, 0x6aaf822d, 0x6ab0822f, 0x6ab18228, 0x6ab2822b, 0x6ab38238,
+    0x6ab4823b, 0x6ab58233, 0x6ab68234, 0x6ab7823e, 0x6ab88244, 0x6ab98249, 0x6aba824b, 0x6abb824f,
+    0x6abc825a, 0x6abd825f, 0x6abe8268, 0x6abf887e, 0x6ac08885, 0x6ac18888, 0x6ac288d8, 0x6ac388df,
+    0x6ac4895e, 0x6ac57f9d, 0x6ac67f9f, 0x6ac77fa7, 0x6ac87faf, 0x6ac97fb0, 0x6aca7fb2, 0x6acb7c7c,
+    0x6acc6549, 0x6acd7c91, 0x6ace7c9d, 0x6acf7c9c, 0x6ad07c9e, 0x6ad17ca2, 0x6ad27cb2, 0x6ad37cbc,
+    0x6ad47cbd, 0x6ad57cc1, 0x6ad67cc7, 0x6ad77ccc, 0x6ad87ccd, 0x6ad97cc8, 0x6ada7cc5, 0x6adb7cd7,
+    0x6adc7ce8, 0x6add826e, 0x6ade66a8, 0x6adf7fbf, 0x6ae07fce, 0x6ae17fd5, 0x6ae27fe5, 0x6ae37fe1,
+    0x6ae47fe6, 0x6ae57fe9, 0x6ae67fee, 0x6ae77ff3, 0x6ae87cf8, 0x6ae97d77, 0x6aea7da6, 0x6aeb7dae,
+    0x6aec7e47, 0x6aed7e9b, 0x6aee9eb8, 0x6aef9eb4, 0x6af08d73, 0x6af18d84, 0x6af28d94, 0x6af38d91,
+    0x6af48db1, 0x6af58d67, 0x6af68d6d, 0x6af78c47, 0x6af88c49, 0x6af9914a, 0x6afa9150, 0x6afb914e,
+    0x6afc914f, 0x6afd9164, 0x6b419162, 0x6b429161, 0x6b439170, 0x6b449169, 0x6b45916f, 0x6b46917d,
+    0x6b47917e, 0x6b489172, 0x6b499174, 0x6b4a9179, 0x6b4b918c, 0x6b4c9185, 0x6b4d9190, 0x6b4e918d,
+    0x6b4f9191, 0x6b5091a2, 0x6b5191a3, 0x6b5291aa, 0x6b5391ad, 0x6b5491ae, 0x6b5591af, 0x6b5691b5,
+    0x6b5791b4, 0x6b5891ba, 0x6b598c55, 0x6b5a9e7e, 0x6b5b8db8, 0x6b5c8deb, 0x6b5d8e05, 0x6b5e8e59,
+    0x6b5f8e69, 0x6b608db5, 0x6b618dbf, 0x6b628dbc, 0x6b638dba, 0x6b648dc4, 0x6b658dd6, 0x6b668dd7,
+    0x6b678dda, 0x6b688dde, 0x6b698dce, 0x6b6a8dcf, 0x6b6b8ddb, 0x6b6c8dc6, 0x6b6d8dec, 0x6b6e8df7,
+    0x6b6f8df8, 0x6b708de3, 0x6b718df9, 0x6b728dfb, 0x6b738de4, 0x6b748e09, 0x6b758dfd, 0x6b768e14,
+    0x6b778e1d, 0x6b788e1f, 0x6b798e2c, 0x6b7a8e2e, 0x6b7b8e23, 0x6b7c8e2f, 0x6b7d8e3a, 0x6b7e8e40,
+    0x6b7f8e39, 0x6b818e35, 0x6b828e3d, 0x6b838e31, 0x6b848e49, 0x6b858e41, 0x6b868e42, 0x6b878e51,
+    0x6b888e52, 0x6b898e4a, 0x6b8a8e70, 0x6b8b8e76, 0x6b8c8e7c, 0x6b8d8e6f, 0x6b8e8e74, 0x6b8f8e85,
+    0x6b908e8f, 0x6b918e94, 0x6b928e90, 0x6b938e9c, 0x6b948e9e, 0x6b958c78, 0x6b968c82, 0x6b978c8a,
+    0x6b988c85, 0x6b998c98, 0x6b9a8c94, 0x6b9b659b, 0x6b9c89d6, 0x6b9d89de, 0x6b9e89da, 0x6b9f89dc,
+    0x6ba089e5, 0x6ba189eb, 0x6ba289ef, 0x6ba38a3e, 0x6ba48b26, 0x6ba59753, 0x6ba696e9, 0x6ba796f3,
+    0x6ba896ef, 0x6ba99706, 0x6baa9701, 0x6bab9708, 0x6bac970f, 0x6bad970e, 0x6bae972a, 0x6baf972d,
+    0x6bb09730, 0x6bb1973e, 0x6bb29f80, 0x6bb39f83, 0x6bb49f85, 0x6bb59f86, 0x6bb69f87, 0x6bb79f88,
+    0x6bb89f89, 0x6bb99f8a, 0x6bba9f8c, 0x6bbb9efe, 0x6bbc9f0b, 0x6bbd9f0d, 0x6bbe96b9, 0x6bbf96bc,
+    0x6bc096bd, 0x6bc196ce, 0x6bc296d2, 0x6bc377bf, 0x6bc496e0, 0x6bc5928e, 0x6bc692ae, 0x6bc792c8,
+    0x6bc8933e, 0x6bc9936a, 0x6bca93ca, 0x6bcb938f, 0x6bcc943e, 0x6bcd946b, 0x6bce9c7f, 0x6bcf9c82,
+    0x6bd09c85, 0x6bd19c86, 0x6bd29c87, 0x6bd39c88, 0x6bd47a23, 0x6bd59c8b, 0x6bd69c8e, 0x6bd79c90,
+    0x6bd89c91, 0x6bd99c92, 0x6bda9c94, 0x6bdb9c95, 0x6bdc9c9a, 0x6bdd9c9b, 0x6bde9c9e, 0x6bdf9c9f,
+    0x6be09ca0, 0x6be19ca1, 0x6be29ca2, 0x6be39ca3, 0x6be49ca5, 0x6be59ca6, 0x6be69ca7, 0x6be79ca8,
+    0x6be89ca9, 0x6be99cab, 0x6bea9cad, 0x6beb9cae, 0x6bec9cb0, 0x6bed9cb1, 0x6bee9cb2, 0x6bef9cb3,
+    0x6bf09cb4, 0x6bf19cb5, 0x6bf29cb6, 0x6bf39cb7, 0x6bf49cba, 0x6bf59cbb, 0x6bf69cbc, 0x6bf79cbd,
+    0x6bf89cc4, 0x6bf99cc5, 0x6bfa9cc6, 0x6bfb9cc7, 0x6bfc9cca, 0x6bfd9ccb, 0x6c419ccc, 0x6c429ccd,
+    0x6c439cce, 0x6c449ccf, 0x6c459cd0, 0x6c469cd3, 0x6c479cd4, 0x6c489cd5, 0x6c499cd7, 0x6c4a9cd8,
+    0x6c4b9cd9, 0x6c4c9cdc, 0x6c4d9cdd, 0x6c4e9cdf, 0x6c4f9ce2, 0x6c50977c, 0x6c519785, 0x6c529791,
+    0x6c539792, 0x6c549794, 0x6c5597af, 0x6c5697ab, 0x6c5797a3, 0x6c5897b2, 0x6c5997b4, 0x6c5a9ab1,
+    0x6c5b9ab0, 0x6c5c9ab7, 0x6c5d9e58, 0x6c5e9ab6, 0x6c5f9aba, 0x6c609abc, 0x6c619ac1, 0x6c629ac0,
+    0x6c639ac5, 0x6c649ac2, 0x6c659acb, 0x6c669acc, 0x6c679ad1, 0x6c689b45, 0x6c699b43, 0x6c6a9b47,
+    0x6c6b9b49, 0x6c6c9b48, 0x6c6d9b4d, 0x6c6e9b51, 0x6c6f98e8, 0x6c70990d, 0x6c71992e, 0x6c729955,
+    0x6c739954, 0x6c749adf, 0x6c759ae1, 0x6c769ae6, 0x6c779aef, 0x6c789aeb, 0x6c799afb, 0x6c7a9aed,
+    0x6c7b9af9, 0x6c7c9b08, 0x6c7d9b0f, 0x6c7e9b13, 0x6c7f9b1f, 0x6c819b23, 0x6c829ebd, 0x6c839ebe,
+    0x6c847e3b, 0x6c859e82, 0x6c869e87, 0x6c879e88, 0x6c889e8b, 0x6c899e92, 0x6c8a93d6, 0x6c8b9e9d,
+    0x6c8c9e9f, 0x6c8d9edb, 0x6c8e9edc, 0x6c8f9edd, 0x6c909ee0, 0x6c919edf, 0x6c929ee2, 0x6c939ee9,
+    0x6c949ee7, 0x6c959ee5, 0x6c969eea, 0x6c979eef, 0x6c989f22, 0x6c999f2c, 0x6c9a9f2f, 0x6c9b9f39,
+    0x6c9c9f37, 0x6c9d9f3d, 0x6c9e9f3e, 0x6c9f9f44, 0x7641e000, 0x7642e001, 0x7643e002, 0x7644e003,
+    0x7645e004, 0x7646e005, 0x7647e006, 0x7648e007, 0x7649e008, 0x764ae009, 0x764be00a, 0x764ce00b,
+    0x764de00c, 0x764ee00d, 0x764fe00e, 0x7650e00f, 0x7651e010, 0x7652e011, 0x7653e012, 0x7654e013,
+    0x7655e014, 0x7656e015, 0x7657e016, 0x7658e017, 0x7659e018, 0x765ae019, 0x765be01a, 0x765ce01b,
+    0x765de01c, 0x765ee01d, 0x765fe01e, 0x7660e01f, 0x7661e020, 0x7662e021, 0x7663e022, 0x7664e023,
+    0x7665e024, 0x7666e025, 0x7667e026, 0x7668e027, 0x7669e028, 0x766ae029, 0x766be02a, 0x766ce02b,
+    0x766de02c, 0x766ee02d, 0x766fe02e, 0x7670e02f, 0x7671e030, 0x7672e031, 0x7673e032, 0x7674e033,
+    0x7675e034, 0x7676e035, 0x7677e036, 0x7678e037, 0x7679e038, 0x767ae039, 0x767be03a, 0x767ce03b,
+    0x767de03c, 0x767ee03d, 0x767fe03e, 0x7681e03f, 0x7682e040, 0x7683e041, 0x7684e042, 0x7685e043,
+    0x7686e044, 0x7687e045, 0x7688e046, 0x7689e047, 0x768ae048, 0x768be049, 0x768ce04a, 0x768de04b,
+    0x768ee04c, 0x768fe04d, 0x7690e04e, 0x7691e04f, 0x7692e050, 0x7693e051, 0x7694e052, 0x7695e053,
+    0x7696e054, 0x7697e055, 0x7698e056, 0x7699e057, 0x769ae058, 0x769be059, 0x769ce05a, 0x769de05b,
+    0x769ee05c, 0x769fe05d, 0x76a0e05e, 0x76a1e05f, 0x76a2e060, 0x76a3e061, 0x76a4e062, 0x76a5e063,
+    0x76a6e064, 0x76a7e065, 0x76a8e066, 0x76a9e067, 0x76aae068, 0x76abe069, 0x76ace06a, 0x76ade06b,
+    0x76aee06c, 0x76afe06d, 0x76b0e06e, 0x76b1e06f, 0x76b2e070, 0x76b3e071, 0x76b4e072, 0x76b5e073,
+    0x76b6e074, 0x76b7e075, 0x76b8e076, 0x76b9e077, 0x76bae078, 0x76bbe079, 0x76bce07a, 0x76bde07b,
+    0x76bee07c, 0x76bfe07d, 0x76c0e07e, 0x76c1e07f, 0x76c2e080, 0x76c3e081, 0x76c4e082, 0x76c5e083,
+    0x76c6e084, 0x76c7e085, 0x76c8e086, 0x76c9e087, 0x76cae088, 0x76cbe089, 0x76cce08a, 0x76cde08b,
+    0x76cee08c, 0x76cfe08d, 0x76d0e08e, 0x76d1e08f, 0x76d2e090, 0x76d3e091, 0x76d4e092, 0x76d5e093,
+    0x76d6e094, 0x76d7e095, 0x76d8e096, 0x76d9e097, 0x76dae098, 0x76dbe099, 0x76dce09a, 0x76dde09b,
+    0x76dee09c, 0x76dfe09d, 0x76e0e09e, 0x76e1e09f, 0x76e2e0a0, 0x76e3e0a1, 0x76e4e0a2, 0x76e5e0a3,
+    0x76e6e0a4, 0x76e7e0a5, 0x76e8e0a6, 0x76e9e0a7, 0x76eae0a8, 0x76ebe0a9, 0x76ece0aa, 0x76ede0ab,
+    0x76eee0ac, 0x76efe0ad, 0x76f0e0ae, 0x76f1e0af, 0x76f2e0b0, 0x76f3e0b1, 0x76f4e0b2, 0x76f5e0b3,
+    0x76f6e0b4, 0x76f7e0b5, 0x76f8e0b6, 0x76f9e0b7, 0x76fae0b8, 0x76fbe0b9, 0x76fce0ba, 0x76fde0bb,
+    0x7741e0bc, 0x7742e0bd, 0x7743e0be, 0x7744e0bf, 0x7745e0c0, 0x7746e0c1, 0x7747e0c2, 0x7748e0c3,
+    0x7749e0c4, 0x774ae0c5, 0x774be0c6, 0x774ce0c7, 0x774de0c8, 0x774ee0c9, 0x774fe0ca, 0x7750e0cb,
+    0x7751e0cc, 0x7752e0cd, 0x7753e0ce, 0x7754e0cf, 0x7755e0d0, 0x7756e0d1, 0x7757e0d2, 0x7758e0d3,
+    0x7759e0d4, 0x775ae0d5, 0x775be0d6, 0x775ce0d7, 0x775de0d8, 0x775ee0d9, 0x775fe0da, 0x7760e0db,
+    0x7761e0dc, 0x7762e0dd, 0x7763e0de, 0x7764e0df, 0x7765e0e0, 0x7766e0e1, 0x7767e0e2, 0x7768e0e3,
+    0x7769e0e4, 0x776ae0e5, 0x776be0e6, 0x776ce0e7, 0x776de0e8, 0x776ee0e9, 0x776fe0ea, 0x7770e0eb,
+    0x7771e0ec, 0x7772e0ed, 0x7773e0ee, 0x7774e0ef, 0x7775e0f0, 0x7776e0f1, 0x7777e0f2, 0x7778e0f3,
+    0x7779e0f4, 0x777ae0f5, 0x777be0f6, 0x777ce0f7, 0x777de0f8, 0x777ee0f9, 0x777fe0fa, 0x7781e0fb,
+    0x7782e0fc, 0x7783e0fd, 0x7784e0fe, 0x7785e0ff, 0x7786e100, 0x7787e101, 0x7788e102, 0x7789e103,
+    0x778ae104, 0x778be105, 0x778ce106, 0x778de107, 0x778ee108, 0x778fe109, 0x7790e10a, 0x7791e10b,
+    0x7792e10c, 0x7793e10d, 0x7794e10e, 0x7795e10f, 0x7796e110, 0x7797e111, 0x7798e112, 0x7799e113,
+    0x779ae114, 0x779be115, 0x779ce116, 0x779de117, 0x779ee118, 0x779fe119, 0x77a0e11a, 0x77a1e11b,
+    0x77a2e11c, 0x77a3e11d, 0x77a4e11e, 0x77a5e11f, 0x77a6e120, 0x77a7e121, 0x77a8e122, 0x77a9e123,
+    0x77aae124, 0x77abe125, 0x77ace126, 0x77ade127, 0x77aee128, 0x77afe129, 0x77b0e12a, 0x77b1e12b,
+    0x77b2e12c, 0x77b3e12d, 0x77b4e12e, 0x77b5e12f, 0x77b6e130, 0x77b7e131, 0x77b8e132, 0x77b9e133,
+    0x77bae134, 0x77bbe135, 0x77bce136, 0x77bde137, 0x77bee138, 0x77bfe139, 0x77c0e13a, 0x77c1e13b,
+    0x77c2e13c, 0x77c3e13d, 0x77c4e13e, 0x77c5e13f, 0x77c6e140, 0x77c7e141, 0x77c8e142, 0x77c9e143,
+    0x77cae144, 0x77cbe145, 0x77cce146, 0x77cde147, 0x77cee148, 0x77cfe149, 0x77d0e14a, 0x77d1e14b,
+    0x77d2e14c, 0x77d3e14d, 0x77d4e14e, 0x77d5e14f, 0x77d6e150, 0x77d7e151, 0x77d8e152, 0x77d9e153,
+    0x77dae154, 0x77dbe155, 0x77dce156, 0x77dde157, 0x77dee158, 0x77dfe159, 0x77e0e15a, 0x77e1e15b,
+    0x77e2e15c, 0x77e3e15d, 0x77e4e15e, 0x77e5e15f, 0x77e6e160, 0x77e7e161, 0x77e8e162, 0x77e9e163,
+    0x77eae164, 0x77ebe165, 0x77ece166, 0x77ede167, 0x77eee168, 0x77efe169, 0x77f0e16a, 0x77f1e16b,
+    0x77f2e16c, 0x77f3e16d, 0x77f4e16e, 0x77f5e16f, 0x77f6e170, 0x77f7e171, 0x77f8e172, 0x77f9e173,
+    0x77fae174, 0x77fbe175, 0x77fce176, 0x77fde177, 0x7841e178, 0x7842e179, 0x7843e17a, 0x7844e17b,
+    0x7845e17c, 0x7846e17d, 0x7847e17e, 0x7848e17f, 0x7849e180, 0x784ae181, 0x784be182, 0x784ce183,
+    0x784de184, 0x784ee185, 0x784fe186, 0x7850e187, 0x7851e188, 0x7852e189, 0x7853e18a, 0x7854e18b,
+    0x7855e18c, 0x7856e18d, 0x7857e18e, 0x7858e18f, 0x7859e190, 0x785ae191, 0x785be192, 0x785ce193,
+    0x785de194, 0x785ee195, 0x785fe196, 0x7860e197, 0x7861e198, 0x7862e199, 0x7863e19a, 0x7864e19b,
+    0x7865e19c, 0x7866e19d, 0x7867e19e, 0x7868e19f, 0x7869e1a0, 0x786ae1a1, 0x786be1a2, 0x786ce1a3,
+    0x786de1a4, 0x786ee1a5, 0x786fe1a6, 0x7870e1a7, 0x7871e1a8, 0x7872e1a9, 0x7873e1aa, 0x7874e1ab,
+    0x7875e1ac, 0x7876e1ad, 0x7877e1ae, 0x7878e1af, 0x7879e1b0, 0x787ae1b1, 0x787be1b2, 0x787ce1b3,
+    0x787de1b4, 0x787ee1b5, 0x787fe1b6, 0x7881e1b7, 0x7882e1b8, 0x7883e1b9, 0x7884e1ba, 0x7885e1bb,
+    0x7886e1bc, 0x7887e1bd, 0x7888e1be, 0x7889e1bf, 0x788ae1c0, 0x788be1c1, 0x788ce1c2, 0x788de1c3,
+    0x788ee1c4, 0x788fe1c5, 0x7890e1c6, 0x7891e1c7, 0x7892e1c8, 0x7893e1c9, 0x7894e1ca, 0x7895e1cb,
+    0x7896e1cc, 0x7897e1cd, 0x7898e1ce, 0x7899e1cf, 0x789ae1d0, 0x789be1d1, 0x789ce1d2, 0x789de1d3,
+    0x789ee1d4, 0x789fe1d5, 0x78a0e1d6, 0x78a1e1d7, 0x78a2e1d8, 0x78a3e1d9, 0x78a4e1da, 0x78a5e1db,
+    0x78a6e1dc, 0x78a7e1dd, 0x78a8e1de, 0x78a9e1df, 0x78aae1e0, 0x78abe1e1, 0x78ace1e2, 0x78ade1e3,
+    0x78aee1e4, 0x78afe1e5, 0x78b0e1e6, 0x78b1e1e7, 0x78b2e1e8, 0x78b3e1e9, 0x78b4e1ea, 0x78b5e1eb,
+    0x78b6e1ec, 0x78b7e1ed, 0x78b8e1ee, 0x78b9e1ef, 0x78bae1f0, 0x78bbe1f1, 0x78bce1f2, 0x78bde1f3,
+    0x78bee1f4, 0x78bfe1f5, 0x78c0e1f6, 0x78c1e1f7, 0x78c2e1f8, 0x78c3e1f9, 0x78c4e1fa, 0x78c5e1fb,
+    0x78c6e1fc, 0x78c7e1fd, 0x78c8e1fe, 0x78c9e1ff, 0x78cae200, 0x78cbe201, 0x78cce202, 0x78cde203,
+    0x78cee204, 0x78cfe205, 0x78d0e206, 0x78d1e207, 0x78d2e208, 0x78d3e209, 0x78d4e20a, 0x78d5e20b,
+    0x78d6e20c, 0x78d7e20d, 0x78d8e20e, 0x78d9e20f, 0x78dae210, 0x78dbe211, 0x78dce212, 0x78dde213,
+    0x78dee214, 0x78dfe215, 0x78e0e216, 0x78e1e217, 0x78e2e218, 0x78e3e219, 0x78e4e21a, 0x78e5e21b,
+    0x78e6e21c, 0x78e7e21d, 0x78e8e21e, 0x78e9e21f, 0x78eae220, 0x78ebe221, 0x78ece222, 0x78ede223,
+    0x78eee224, 0x78efe225, 0x78f0e226, 0x78f1e227, 0x78f2e228, 0x78f3e229, 0x78f4e22a, 0x78f5e22b,
+    0x78f6e22c, 0x78f7e22d, 0x78f8e22e, 0x78f9e22f, 0x78fae230, 0x78fbe231, 0x78fce232, 0x78fde233,
+    0x7941e234, 0x7942e235, 0x7943e236, 0x7944e237, 0x7945e238, 0x7946e239, 0x7947e23a, 0x7948e23b,
+    0x7949e23c, 0x794ae23d, 0x794be23e, 0x794ce23f, 0x794de240, 0x794ee241, 0x794fe242, 0x7950e243,
+    0x7951e244, 0x7952e245, 0x7953e246, 0x7954e247, 0x7955e248, 0x7956e249, 0x7957e24a, 0x7958e24b,
+    0x7959e24c, 0x795ae24d, 0x795be24e, 0x795ce24f, 0x795de250, 0x795ee251, 0x795fe252, 0x7960e253,
+    0x7961e254, 0x7962e255, 0x7963e256, 0x7964e257, 0x7965e258, 0x7966e259, 0x7967e25a, 0x7968e25b,
+    0x7969e25c, 0x796ae25d, 0x796be25e, 0x796ce25f, 0x796de260, 0x796ee261, 0x796fe262, 0x7970e263,
+    0x7971e264, 0x7972e265, 0x7973e266, 0x7974e267, 0x7975e268, 0x7976e269, 0x7977e26a, 0x7978e26b,
+    0x7979e26c, 0x797ae26d, 0x797be26e, 0x797ce26f, 0x797de270, 0x797ee271, 0x797fe272, 0x7981e273,
+    0x7982e274, 0x7983e275, 0x7984e276, 0x7985e277, 0x7986e278, 0x7987e279, 0x7988e27a, 0x7989e27b,
+    0x798ae27c, 0x798be27d, 0x798ce27e, 0x798de27f, 0x798ee280, 0x798fe281, 0x7990e282, 0x7991e283,
+    0x7992e284, 0x7993e285, 0x7994e286, 0x7995e287, 0x7996e288, 0x7997e289, 0x7998e28a, 0x7999e28b,
+    0x799ae28c, 0x799be28d, 0x799ce28e, 0x799de28f, 0x799ee290, 0x799fe291, 0x79a0e292, 0x79a1e293,
+    0x79a2e294, 0x79a3e295, 0x79a4e296, 0x79a5e297, 0x79a6e298, 0x79a7e299, 0x79a8e29a, 0x79a9e29b,
+    0x79aae29c, 0x79abe29d, 0x79ace29e, 0x79ade29f, 0x79aee2a0, 0x79afe2a1, 0x79b0e2a2, 0x79b1e2a3,
+    0x79b2e2a4, 0x79b3e2a5, 0x79b4e2a6, 0x79b5e2a7, 0x79b6e2a8, 0x79b7e2a9, 0x79b8e2aa, 0x79b9e2ab,
+    0x79bae2ac, 0x79bbe2ad, 0x79bce2ae, 0x79bde2af, 0x79bee2b0, 0x79bfe2b1, 0x79c0e2b2, 0x79c1e2b3,
+    0x79c2e2b4, 0x79c3e2b5, 0x79c4e2b6, 0x79c5e2b7, 0x79c6e2b8, 0x79c7e2b9, 0x79c8e2ba, 0x79c9e2bb,
+    0x79cae2bc, 0x79cbe2bd, 0x79cce2be, 0x79cde2bf, 0x79cee2c0, 0x79cfe2c1, 0x79d0e2c2, 0x79d1e2c3,
+    0x79d2e2c4, 0x79d3e2c5, 0x79d4e2c6, 0x79d5e2c7, 0x79d6e2c8, 0x79d7e2c9, 0x79d8e2ca, 0x79d9e2cb,
+    0x79dae2cc, 0x79dbe2cd, 0x79dce2ce, 0x79dde2cf, 0x79dee2d0, 0x79dfe2d1, 0x79e0e2d2, 0x79e1e2d3,
+    0x79e2e2d4, 0x79e3e2d5, 0x79e4e2d6, 0x79e5e2d7, 0x79e6e2d8, 0x79e7e2d9, 0x79e8e2da, 0x79e9e2db,
+    0x79eae2dc, 0x79ebe2dd, 0x79ece2de, 0x79ede2df, 0x79eee2e0, 0x79efe2e1, 0x79f0e2e2, 0x79f1e2e3,
+    0x79f2e2e4, 0x79f3e2e5, 0x79f4e2e6, 0x79f5e2e7, 0x79f6e2e8, 0x79f7e2e9, 0x79f8e2ea, 0x79f9e2eb,
+    0x79fae2ec, 0x79fbe2ed, 0x79fce2ee, 0x79fde2ef, 0x7a41e2f0, 0x7a42e2f1, 0x7a43e2f2, 0x7a44e2f3,
+    0x7a45e2f4, 0x7a46e2f5, 0x7a47e2f6, 0x7a48e2f7, 0x7a49e2f8, 0x7a4ae2f9, 0x7a4be2fa, 0x7a4ce2fb,
+    0x7a4de2fc, 0x7a4ee2fd, 0x7a4fe2fe, 0x7a50e2ff, 0x7a51e300, 0x7a52e301, 0x7a53e302, 0x7a54e303,
+    0x7a55e304, 0x7a56e305, 0x7a57e306, 0x7a58e307, 0x7a59e308, 0x7a5ae309, 0x7a5be30a, 0x7a5ce30b,
+    0x7a5de30c, 0x7a5ee30d, 0x7a5fe30e, 0x7a60e30f, 0x7a61e310, 0x7a62e311, 0x7a63e312, 0x7a64e313,
+    0x7a65e314, 0x7a66e315, 0x7a67e316, 0x7a68e317, 0x7a69e318, 0x7a6ae319, 0x7a6be31a, 0x7a6ce31b,
+    0x7a6de31c, 0x7a6ee31d, 0x7a6fe31e, 0x7a70e31f, 0x7a71e320, 0x7a72e321, 0x7a73e322, 0x7a74e323,
+    0x7a75e324, 0x7a76e325, 0x7a77e326, 0x7a78e327, 0x7a79e328, 0x7a7ae329, 0x7a7be32a, 0x7a7ce32b,
+    0x7a7de32c, 0x7a7ee32d, 0x7a7fe32e, 0x7a81e32f, 0x7a82e330, 0x7a83e331, 0x7a84e332, 0x7a85e333,
+    0x7a86e334, 0x7a87e335, 0x7a88e336, 0x7a89e337, 0x7a8ae338, 0x7a8be339, 0x7a8ce33a, 0x7a8de33b,
+    0x7a8ee33c, 0x7a8fe33d, 0x7a90e33e, 0x7a91e33f, 0x7a92e340, 0x7a93e341, 0x7a94e342, 0x7a95e343,
+    0x7a96e344, 0x7a97e345, 0x7a98e346, 0x7a99e347, 0x7a9ae348, 0x7a9be349, 0x7a9ce34a, 0x7a9de34b,
+    0x7a9ee34c, 0x7a9fe34d, 0x7aa0e34e, 0x7aa1e34f, 0x7aa2e350, 0x7aa3e351, 0x7aa4e352, 0x7aa5e353,
+    0x7aa6e354, 0x7aa7e355, 0x7aa8e356, 0x7aa9e357, 0x7aaae358, 0x7aabe359, 0x7aace35a, 0x7aade35b,
+    0x7aaee35c, 0x7aafe35d, 0x7ab0e35e, 0x7ab1e35f, 0x7ab2e360, 0x7ab3e361, 0x7ab4e362, 0x7ab5e363,
+    0x7ab6e364, 0x7ab7e365, 0x7ab8e366, 0x7ab9e367, 0x7abae368, 0x7abbe369, 0x7abce36a, 0x7abde36b,
+    0x7abee36c, 0x7abfe36d, 0x7ac0e36e, 0x7ac1e36f, 0x7ac2e370, 0x7ac3e371, 0x7ac4e372, 0x7ac5e373,
+    0x7ac6e374, 0x7ac7e375, 0x7ac8e376, 0x7ac9e377, 0x7acae378, 0x7acbe379, 0x7acce37a, 0x7acde37b,
+    0x7acee37c, 0x7acfe37d, 0x7ad0e37e, 0x7ad1e37f, 0x7ad2e380, 0x7ad3e381, 0x7ad4e382, 0x7ad5e383,
+    0x7ad6e384, 0x7ad7e385, 0x7ad8e386, 0x7ad9e387, 0x7adae388, 0x7adbe389, 0x7adce38a, 0x7adde38b,
+    0x7adee38c, 0x7adfe38d, 0x7ae0e38e, 0x7ae1e38f, 0x7ae2e390, 0x7ae3e391, 0x7ae4e392, 0x7ae5e393,
+    0x7ae6e394, 0x7ae7e395, 0x7ae8e396, 0x7ae9e397, 0x7aeae398, 0x7aebe399, 0x7aece39a, 0x7aede39b,
+    0x7aeee39c, 0x7aefe39d, 0x7af0e39e, 0x7af1e39f, 0x7af2e3a0, 0x7af3e3a1, 0x7af4e3a2, 0x7af5e3a3,
+    0x7af6e3a4, 0x7af7e3a5, 0x7af8e3a6, 0x7af9e3a7, 0x7afae3a8, 0x7afbe3a9, 0x7afce3aa, 0x7afde3ab,
+    0x7b41e3ac, 0x7b42e3ad, 0x7b43e3ae, 0x7b44e3af, 0x7b45e3b0, 0x7b46e3b1, 0x7b47e3b2, 0x7b48e3b3,
+    0x7b49e3b4, 0x7b4ae3b5, 0x7b4be3b6, 0x7b4ce3b7, 0x7b4de3b8, 0x7b4ee3b9, 0x7b4fe3ba, 0x7b50e3bb,
+    0x7b51e3bc, 0x7b52e3bd, 0x7b53e3be, 0x7b54e3bf, 0x7b55e3c0, 0x7b56e3c1, 0x7b57e3c2, 0x7b58e3c3,
+    0x7b59e3c4, 0x7b5ae3c5, 0x7b5be3c6, 0x7b5ce3c7, 0x7b5de3c8, 0x7b5ee3c9, 0x7b5fe3ca, 0x7b60e3cb,
+    0x7b61e3cc, 0x7b62e3cd, 0x7b63e3ce, 0x7b64e3cf, 0x7b65e3d0, 0x7b66e3d1, 0x7b67e3d2, 0x7b68e3d3,
+    0x7b69e3d4, 0x7b6ae3d5, 0x7b6be3d6, 0x7b6ce3d7, 0x7b6de3d8, 0x7b6ee3d9, 0x7b6fe3da, 0x7b70e3db,
+    0x7b71e3dc, 0x7b72e3dd, 0x7b73e3de, 0x7b74e3df, 0x7b75e3e0, 0x7b76e3e1, 0x7b77e3e2, 0x7b78e3e3,
+    0x7b79e3e4, 0x7b7ae3e5, 0x7b7be3e6, 0x7b7ce3e7, 0x7b7de3e8, 0x7b7ee3e9, 0x7b7fe3ea, 0x7b81e3eb,
+    0x7b82e3ec, 0x7b83e3ed, 0x7b84e3ee, 0x7b85e3ef, 0x7b86e3f0, 0x7b87e3f1, 0x7b88e3f2, 0x7b89e3f3,
+    0x7b8ae3f4, 0x7b8be3f5, 0x7b8ce3f6, 0x7b8de3f7, 0x7b8ee3f8, 0x7b8fe3f9, 0x7b90e3fa, 0x7b91e3fb,
+    0x7b92e3fc, 0x7b93e3fd, 0x7b94e3fe, 0x7b95e3ff, 0x7b96e400, 0x7b97e401, 0x7b98e402, 0x7b99e403,
+    0x7b9ae404, 0x7b9be405, 0x7b9ce406, 0x7b9de407, 0x7b9ee408, 0x7b9fe409, 0x7ba0e40a, 0x7ba1e40b,
+    0x7ba2e40c, 0x7ba3e40d, 0x7ba4e40e, 0x7ba5e40f, 0x7ba6e410, 0x7ba7e411, 0x7ba8e412, 0x7ba9e413,
+    0x7baae414, 0x7babe415, 0x7bace416, 0x7bade417, 0x7baee418, 0x7bafe419, 0x7bb0e41a, 0x7bb1e41b,
+    0x7bb2e41c, 0x7bb3e41d, 0x7bb4e41e, 0x7bb5e41f, 0x7bb6e420, 0x7bb7e421, 0x7bb8e422, 0x7bb9e423,
+    0x7bbae424, 0x7bbbe425, 0x7bbce426, 0x7bbde427, 0x7bbee428, 0x7bbfe429, 0x7bc0e42a, 0x7bc1e42b,
+    0x7bc2e42c, 0x7bc3e42d, 0x7bc4e42e, 0x7bc5e42f, 0x7bc6e430, 0x7bc7e431, 0x7bc8e432, 0x7bc9e433,
+    0x7bcae434, 0x7bcbe435, 0x7bcce436, 0x7bcde437, 0x7bcee438, 0x7bcfe439, 0x7bd0e43a, 0x7bd1e43b,
+    0x7bd2e43c, 0x7bd3e43d, 0x7bd4e43e, 0x7bd5e43f, 0x7bd6e440, 0x7bd7e441, 0x7bd8e442, 0x7bd9e443,
+    0x7bdae444, 0x7bdbe445, 0x7bdce446, 0x7bdde447, 0x7bdee448, 0x7bdfe449, 0x7be0e44a, 0x7be1e44b,
+    0x7be2e44c, 0x7be3e44d, 0x7be4e44e, 0x7be5e44f, 0x7be6e450, 0x7be7e451, 0x7be8e452, 0x7be9e453,
+    0x7beae454, 0x7bebe455, 0x7bece456, 0x7bede457, 0x7beee458, 0x7befe459, 0x7bf0e45a, 0x7bf1e45b,
+    0x7bf2e45c, 0x7bf3e45d, 0x7bf4e45e, 0x7bf5e45f, 0x7bf6e460, 0x7bf7e461, 0x7bf8e462, 0x7bf9e463,
+    0x7bfae464, 0x7bfbe465, 0x7bfce466, 0x7bfde467, 0x7c41e468, 0x7c42e469, 0x7c43e46a, 0x7c44e46b,
+    0x7c45e46c, 0x7c46e46d, 0x7c47e46e, 0x7c48e46f, 0x7c49e470, 0x7c4ae471, 0x7c4be472, 0x7c4ce473,
+    0x7c4de474, 0x7c4ee475, 0x7c4fe476, 0x7c50e477, 0x7c51e478, 0x7c52e479, 0x7c53e47a, 0x7c54e47b,
+    0x7c55e47c, 0x7c56e47d, 0x7c57e47e, 0x7c58e47f, 0x7c59e480, 0x7c5ae481, 0x7c5be482, 0x7c5ce483,
+    0x7c5de484, 0x7c5ee485, 0x7c5fe486, 0x7c60e487, 0x7c61e488, 0x7c62e489, 0x7c63e48a, 0x7c64e48b,
+    0x7c65e48c, 0x7c66e48d, 0x7c67e48e, 0x7c68e48f, 0x7c69e490, 0x7c6ae491, 0x7c6be492, 0x7c6ce493,
+    0x7c6de494, 0x7c6ee495, 0x7c6fe496, 0x7c70e497, 0x7c71e498, 0x7c72e499, 0x7c73e49a, 0x7c74e49b,
+    0x7c75e49c, 0x7c76e49d, 0x7c77e49e, 0x7c78e49f, 0x7c79e4a0, 0x7c7ae4a1, 0x7c7be4a2, 0x7c7ce4a3,
+    0x7c7de4a4, 0x7c7ee4a5, 0x7c7fe4a6, 0x7c81e4a7, 0x7c82e4a8, 0x7c83e4a9, 0x7c84e4aa, 0x7c85e4ab,
+    0x7c86e4ac, 0x7c87e4ad, 0x7c88e4ae, 0x7c89e4af, 0x7c8ae4b0, 0x7c8be4b1, 0x7c8ce4b2, 0x7c8de4b3,
+    0x7c8ee4b4, 0x7c8fe4b5, 0x7c90e4b6, 0x7c91e4b7, 0x7c92e4b8, 0x7c93e4b9, 0x7c94e4ba, 0x7c95e4bb,
+    0x7c96e4bc, 0x7c97e4bd, 0x7c98e4be, 0x7c99e4bf, 0x7c9ae4c0, 0x7c9be4c1, 0x7c9ce4c2, 0x7c9de4c3,
+    0x7c9ee4c4, 0x7c9fe4c5, 0x7ca0e4c6, 0x7ca1e4c7, 0x7ca2e4c8, 0x7ca3e4c9, 0x7ca4e4ca, 0x7ca5e4cb,
+    0x7ca6e4cc, 0x7ca7e4cd, 0x7ca8e4ce, 0x7ca9e4cf, 0x7caae4d0, 0x7cabe4d1, 0x7cace4d2, 0x7cade4d3,
+    0x7caee4d4, 0x7cafe4d5, 0x7cb0e4d6, 0x7cb1e4d7, 0x7cb2e4d8, 0x7cb3e4d9, 0x7cb4e4da, 0x7cb5e4db,
+    0x7cb6e4dc, 0x7cb7e4dd, 0x7cb8e4de, 0x7cb9e4df, 0x7cbae4e0, 0x7cbbe4e1, 0x7cbce4e2, 0x7cbde4e3,
+    0x7cbee4e4, 0x7cbfe4e5, 0x7cc0e4e6, 0x7cc1e4e7, 0x7cc2e4e8, 0x7cc3e4e9, 0x7cc4e4ea, 0x7cc5e4eb,
+    0x7cc6e4ec, 0x7cc7e4ed, 0x7cc8e4ee, 0x7cc9e4ef, 0x7ccae4f0, 0x7ccbe4f1, 0x7ccce4f2, 0x7ccde4f3,
+    0x7ccee4f4, 0x7ccfe4f5, 0x7cd0e4f6, 0x7cd1e4f7, 0x7cd2e4f8, 0x7cd3e4f9, 0x7cd4e4fa, 0x7cd5e4fb,
+    0x7cd6e4fc, 0x7cd7e4fd, 0x7cd8e4fe, 0x7cd9e4ff, 0x7cdae500, 0x7cdbe501, 0x7cdce502, 0x7cdde503,
+    0x7cdee504, 0x7cdfe505, 0x7ce0e506, 0x7ce1e507, 0x7ce2e508, 0x7ce3e509, 0x7ce4e50a, 0x7ce5e50b,
+    0x7ce6e50c, 0x7ce7e50d, 0x7ce8e50e, 0x7ce9e50f, 0x7ceae510, 0x7cebe511, 0x7cece512, 0x7cede513,
+    0x7ceee514, 0x7cefe515, 0x7cf0e516, 0x7cf1e517, 0x7cf2e518, 0x7cf3e519, 0x7cf4e51a, 0x7cf5e51b,
+    0x7cf6e51c, 0x7cf7e51d, 0x7cf8e51e, 0x7cf9e51f, 0x7cfae520, 0x7cfbe521, 0x7cfce522, 0x7cfde523,
+    0x7d41e524, 0x7d42e525, 0x7d43e526, 0x7d44e527, 0x7d45e528, 0x7d46e529, 0x7d47e52a, 0x7d48e52b,
+    0x7d49e52c, 0x7d4ae52d, 0x7d4be52e, 0x7d4ce52f, 0x7d4de530, 0x7d4ee531, 0x7d4fe532, 0x7d50e533,
+    0x7d51e534, 0x7d52e535, 0x7d53e536, 0x7d54e537, 0x7d55e538, 0x7d56e539, 0x7d57e53a, 0x7d58e53b,
+    0x7d59e53c, 0x7d5ae53d, 0x7d5be53e, 0x7d5ce53f, 0x7d5de540, 0x7d5ee541, 0x7d5fe542, 0x7d60e543,
+    0x7d61e544, 0x7d62e545, 0x7d63e546, 0x7d64e547, 0x7d65e548, 0x7d66e549, 0x7d67e54a, 0x7d68e54b,
+    0x7d69e54c, 0x7d6ae54d, 0x7d6be54e, 0x7d6ce54f, 0x7d6de550, 0x7d6ee551, 0x7d6fe552, 0x7d70e553,
+    0x7d71e554, 0x7d72e555, 0x7d73e556, 0x7d74e557, 0x7d75e558, 0x7d76e559, 0x7d77e55a, 0x7d78e55b,
+    0x7d79e55c, 0x7d7ae55d, 0x7d7be55e, 0x7d7ce55f, 0x7d7de560, 0x7d7ee561, 0x7d7fe562, 0x7d81e563,
+    0x7d82e564, 0x7d83e565, 0x7d84e566, 0x7d85e567, 0x7d86e568, 0x7d87e569, 0x7d88e56a, 0x7d89e56b,
+    0x7d8ae56c, 0x7d8be56d, 0x7d8ce56e, 0x7d8de56f, 0x7d8ee570, 0x7d8fe571, 0x7d90e572, 0x7d91e573,
+    0x7d92e574, 0x7d93e575, 0x7d94e576, 0x7d95e577, 0x7d96e578, 0x7d97e579, 0x7d98e57a, 0x7d99e57b,
+    0x7d9ae57c, 0x7d9be57d, 0x7d9ce57e, 0x7d9de57f, 0x7d9ee580, 0x7d9fe581, 0x7da0e582, 0x7da1e583,
+    0x7da2e584, 0x7da3e585, 0x7da4e586, 0x7da5e587, 0x7da6e588, 0x7da7e589, 0x7da8e58a, 0x7da9e58b,
+    0x7daae58c, 0x7dabe58d, 0x7dace58e, 0x7dade58f, 0x7daee590, 0x7dafe591, 0x7db0e592, 0x7db1e593,
+    0x7db2e594, 0x7db3e595, 0x7db4e596, 0x7db5e597, 0x7db6e598, 0x7db7e599, 0x7db8e59a, 0x7db9e59b,
+    0x7dbae59c, 0x7dbbe59d, 0x7dbce59e, 0x7dbde59f, 0x7dbee5a0, 0x7dbfe5a1, 0x7dc0e5a2, 0x7dc1e5a3,
+    0x7dc2e5a4, 0x7dc3e5a5, 0x7dc4e5a6, 0x7dc5e5a7, 0x7dc6e5a8, 0x7dc7e5a9, 0x7dc8e5aa, 0x7dc9e5ab,
+    0x7dcae5ac, 0x7dcbe5ad, 0x7dcce5ae, 0x7dcde5af, 0x7dcee5b0, 0x7dcfe5b1, 0x7dd0e5b2, 0x7dd1e5b3,
+    0x7dd2e5b4, 0x7dd3e5b5, 0x7dd4e5b6, 0x7dd5e5b7, 0x7dd6e5b8, 0x7dd7e5b9, 0x7dd8e5ba, 0x7dd9e5bb,
+    0x7ddae5bc, 0x7ddbe5bd, 0x7ddce5be, 0x7ddde5bf, 0x7ddee5c0, 0x7ddfe5c1, 0x7de0e5c2, 0x7de1e5c3,
+    0x7de2e5c4, 0x7de3e5c5, 0x7de4e5c6, 0x7de5e5c7, 0x7de6e5c8, 0x7de7e5c9, 0x7de8e5ca, 0x7de9e5cb,
+    0x7deae5cc, 0x7debe5cd, 0x7dece5ce, 0x7dede5cf, 0x7deee5d0, 0x7defe5d1, 0x7df0e5d2, 0x7df1e5d3,
+    0x7df2e5d4, 0x7df3e5d5, 0x7df4e5d6, 0x7df5e5d7, 0x7df6e5d8, 0x7df7e5d9, 0x7df8e5da, 0x7df9e5db,
+    0x7dfae5dc, 0x7dfbe5dd, 0x7dfce5de, 0x7dfde5df, 0x7e41e5e0, 0x7e42e5e1, 0x7e43e5e2, 0x7e44e5e3,
+    0x7e45e5e4, 0x7e46e5e5, 0x7e47e5e6, 0x7e48e5e7, 0x7e49e5e8, 0x7e4ae5e9, 0x7e4be5ea, 0x7e4ce5eb,
+    0x7e4de5ec, 0x7e4ee5ed, 0x7e4fe5ee, 0x7e50e5ef, 0x7e51e5f0, 0x7e52e5f1, 0x7e53e5f2, 0x7e54e5f3,
+    0x7e55e5f4, 0x7e56e5f5, 0x7e57e5f6, 0x7e58e5f7, 0x7e59e5f8, 0x7e5ae5f9, 0x7e5be5fa, 0x7e5ce5fb,
+    0x7e5de5fc, 0x7e5ee5fd, 0x7e5fe5fe, 0x7e60e5ff, 0x7e61e600, 0x7e62e601, 0x7e63e602, 0x7e64e603,
+    0x7e65e604, 0x7e66e605, 0x7e67e606, 0x7e68e607, 0x7e69e608, 0x7e6ae609, 0x7e6be60a, 0x7e6ce60b,
+    0x7e6de60c, 0x7e6ee60d, 0x7e6fe60e, 0x7e70e60f, 0x7e71e610, 0x7e72e611, 0x7e73e612, 0x7e74e613,
+    0x7e75e614, 0x7e76e615, 0x7e77e616, 0x7e78e617, 0x7e79e618, 0x7e7ae619, 0x7e7be61a, 0x7e7ce61b,
+    0x7e7de61c, 0x7e7ee61d, 0x7e7fe61e, 0x7e81e61f, 0x7e82e620, 0x7e83e621, 0x7e84e622, 0x7e85e623,
+    0x7e86e624, 0x7e87e625, 0x7e88e626, 0x7e89e627, 0x7e8ae628, 0x7e8be629, 0x7e8ce62a, 0x7e8de62b,
+    0x7e8ee62c, 0x7e8fe62d, 0x7e90e62e, 0x7e91e62f, 0x7e92e630, 0x7e93e631, 0x7e94e632, 0x7e95e633,
+    0x7e96e634, 0x7e97e635, 0x7e98e636, 0x7e99e637, 0x7e9ae638, 0x7e9be639, 0x7e9ce63a, 0x7e9de63b,
+    0x7e9ee63c, 0x7e9fe63d, 0x7ea0e63e, 0x7ea1e63f, 0x7ea2e640, 0x7ea3e641, 0x7ea4e642, 0x7ea5e643,
+    0x7ea6e644, 0x7ea7e645, 0x7ea8e646, 0x7ea9e647, 0x7eaae648, 0x7eabe649, 0x7eace64a, 0x7eade64b,
+    0x7eaee64c, 0x7eafe64d, 0x7eb0e64e, 0x7eb1e64f, 0x7eb2e650, 0x7eb3e651, 0x7eb4e652, 0x7eb5e653,
+    0x7eb6e654, 0x7eb7e655, 0x7eb8e656, 0x7eb9e657, 0x7ebae658, 0x7ebbe659, 0x7ebce65a, 0x7ebde65b,
+    0x7ebee65c, 0x7ebfe65d, 0x7ec0e65e, 0x7ec1e65f, 0x7ec2e660, 0x7ec3e661, 0x7ec4e662, 0x7ec5e663,
+    0x7ec6e664, 0x7ec7e665, 0x7ec8e666, 0x7ec9e667, 0x7ecae668, 0x7ecbe669, 0x7ecce66a, 0x7ecde66b,
+    0x7ecee66c, 0x7ecfe66d, 0x7ed0e66e, 0x7ed1e66f, 0x7ed2e670, 0x7ed3e671, 0x7ed4e672, 0x7ed5e673,
+    0x7ed6e674, 0x7ed7e675, 0x7ed8e676, 0x7ed9e677, 0x7edae678, 0x7edbe679, 0x7edce67a, 0x7edde67b,
+    0x7edee67c, 0x7edfe67d, 0x7ee0e67e, 0x7ee1e67f, 0x7ee2e680, 0x7ee3e681, 0x7ee4e682, 0x7ee5e683,
+    0x7ee6e684, 0x7ee7e685, 0x7ee8e686, 0x7ee9e687, 0x7eeae688, 0x7eebe689, 0x7eece68a, 0x7eede68b,
+    0x7eeee68c, 0x7eefe68d, 0x7ef0e68e, 0x7ef1e68f, 0x7ef2e690, 0x7ef3e691, 0x7ef4e692, 0x7ef5e693,
+    0x7ef6e694, 0x7ef7e695, 0x7ef8e696, 0x7ef9e697, 0x7efae698, 0x7efbe699, 0x7efce69a, 0x7efde69b,
+    0x7f41e69c, 0x7f42e69d, 0x7f43e69e, 0x7f44e69f, 0x7f45e6a0, 0x7f46e6a1, 0x7f47e6a2, 0x7f48e6a3,
+    0x7f49e6a4, 0x7f4ae6a5, 0x7f4be6a6, 0x7f4ce6a7, 0x7f4de6a8, 0x7f4ee6a9, 0x7f4fe6aa, 0x7f50e6ab,
+    0x7f51e6ac, 0x7f52e6ad, 0x7f53e6ae, 0x7f54e6af, 0x7f55e6b0, 0x7f56e6b1, 0x7f57e6b2, 0x7f58e6b3,
+    0x7f59e6b4, 0x7f5ae6b5, 0x7f5be6b6, 0x7f5ce6b7, 0x7f5de6b8, 0x7f5ee6b9, 0x7f5fe6ba, 0x7f60e6bb,
+    0x7f61e6bc, 0x7f62e6bd, 0x7f63e6be, 0x7f64e6bf, 0x7f65e6c0, 0x7f66e6c1, 0x7f67e6c2, 0x7f68e6c3,
+    0x7f69e6c4, 0x7f6ae6c5, 0x7f6be6c6, 0x7f6ce6c7, 0x7f6de6c8, 0x7f6ee6c9, 0x7f6fe6ca, 0x7f70e6cb,
+    0x7f71e6cc, 0x7f72e6cd, 0x7f73e6ce, 0x7f74e6cf, 0x7f75e6d0, 0x7f76e6d1, 0x7f77e6d2, 0x7f78e6d3,
+    0x7f79e6d4, 0x7f7ae6d5, 0x7f7be6d6, 0x7f7ce6d7, 0x7f7de6d8, 0x7f7ee6d9, 0x7f7fe6da, 0x7f81e6db,
+    0x7f82e6dc, 0x7f83e6dd, 0x7f84e6de, 0x7f85e6df, 0x7f86e6e0, 0x7f87e6e1, 0x7f88e6e2, 0x7f89e6e3,
+    0x7f8ae6e4, 0x7f8be6e5, 0x7f8ce6e6, 0x7f8de6e7, 0x7f8ee6e8, 0x7f8fe6e9, 0x7f90e6ea, 0x7f91e6eb,
+    0x7f92e6ec, 0x7f93e6ed, 0x7f94e6ee, 0x7f95e6ef, 0x7f96e6f0, 0x7f97e6f1, 0x7f98e6f2, 0x7f99e6f3,
+    0x7f9ae6f4, 0x7f9be6f5, 0x7f9ce6f6, 0x7f9de6f7, 0x7f9ee6f8, 0x7f9fe6f9, 0x7fa0e6fa, 0x7fa1e6fb,
+    0x7fa2e6fc, 0x7fa3e6fd, 0x7fa4e6fe, 0x7fa5e6ff, 0x7fa6e700, 0x7fa7e701, 0x7fa8e702, 0x7fa9e703,
+    0x7faae704, 0x7fabe705, 0x7face706, 0x7fade707, 0x7faee708, 0x7fafe709, 0x7fb0e70a, 0x7fb1e70b,
+    0x7fb2e70c, 0x7fb3e70d, 0x7fb4e70e, 0x7fb5e70f, 0x7fb6e710, 0x7fb7e711, 0x7fb8e712, 0x7fb9e713,
+    0x7fbae714, 0x7fbbe715, 0x7fbce716, 0x7fbde717, 0x7fbee718, 0x7fbfe719, 0x7fc0e71a, 0x7fc1e71b,
+    0x7fc2e71c, 0x7fc3e71d, 0x7fc4e71e, 0x7fc5e71f, 0x7fc6e720, 0x7fc7e721, 0x7fc8e722, 0x7fc9e723,
+    0x7fcae724, 0x7fcbe725, 0x7fcce726, 0x7fcde727, 0x7fcee728, 0x7fcfe729, 0x7fd0e72a, 0x7fd1e72b,
+    0x7fd2e72c, 0x7fd3e72d, 0x7fd4e72e, 0x7fd5e72f, 0x7fd6e730, 0x7fd7e731, 0x7fd8e732, 0x7fd9e733,
+    0x7fdae734, 0x7fdbe735, 0x7fdce736, 0x7fdde737, 0x7fdee738, 0x7fdfe739, 0x7fe0e73a, 0x7fe1e73b,
+    0x7fe2e73c, 0x7fe3e73d, 0x7fe4e73e, 0x7fe5e73f, 0x7fe6e740, 0x7fe7e741, 0x7fe8e742, 0x7fe9e743,
+    0x7feae744, 0x7febe745, 0x7fece746, 0x7fede747, 0x7feee748, 0x7fefe749, 0x7ff0e74a, 0x7ff1e74b,
+    0x7ff2e74c, 0x7ff3e74d, 0x7ff4e74e, 0x7ff5e74f, 0x7ff6e750, 0x7ff7e751, 0x7ff8e752, 0x7ff9e753,
+    0x7ffae754, 0x7ffbe755, 0x7ffce756, 0x7ffde757,
+];
@@ -0,0 +1,306 @@
+use std::collections::{HashMap, HashSet};
+use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
+use std::sync::RwLock;
+
+use snafu::{ResultExt, Snafu, ensure};
+
+use crate::encoding::dbcs::{DbcsTable, REPLACEMENT, SI, SO};
+use crate::encoding::{Encoding, SUB};
+
+// Pages made known to `Encoding::from_ccsid` and friends by `CodePage::register`
+static REGISTRY: RwLock<Vec<&'static CodePage>> = RwLock::new(Vec::new());
+
+// Every page that became an encoding, so loading the same page again doesn't leak another copy
+static PAGES: RwLock<Vec<&'static CodePage>> = RwLock::new(Vec::new());
+
+#[derive(Debug, Snafu)]
+#[snafu(visibility(pub(crate)))]
+pub enum CodePageError {
+    #[snafu(display("Failed to read {}", path.display()))]
+    Read { path: PathBuf, source: std::io::Error },
+    #[snafu(display("Line {}: {}", line, message))]
+    Syntax { line: usize, message: String },
+    #[snafu(display("Unsupported conversion class {}", class))]
+    Unsupported { class: String },
+    // Two mappings decode the same bytes or encode the same character, so one of them can't
+    // round-trip
+    #[snafu(display("Line {}: {} is already mapped", line, mapping))]
+    Ambiguous { line: usize, mapping: String },
+    // The data stream relies on 0x40 being the space
+    #[snafu(display("0x40 isn't mapped to the space"))]
+    NoSpace,
+}
+
+// A code page read from a mapping file, e.g. one of the ICU .ucm files. Turn it into an `Encoding`
+// with `register` or `into_encoding`, which keep it for the rest of the program, once per page.
+#[derive(Debug)]
+pub struct CodePage {
+    // The <code_set_name> of the file
+    pub name: String,
+    // Taken from names like `ibm-1047_P100-1995`, 0 if the name has none
+    pub ccsid: u16,
+    // The code pages of the single and double byte halves as reported in CGCSGIDs, see
+    // `Encoding::code_pages`. The CCSID and none unless set.
+    pub code_pages: (u16, Option<u16>),
+    // U+FFFD for bytes without a character
+    decode: [u16; 256],
+    // Sorted by character, including fallbacks
+    encode: Vec<(u16, u8)>,
+    latin1: ([u8; 256], [u8; 256]),
+    dbcs: Option<DbcsTable>,
+}
+
+// Pages are kept forever once they are encodings, so they are the same if they are at the same
+// place
+impl PartialEq for CodePage {
+    fn eq(&self, other: &Self) -> bool {
+        std::ptr::eq(self, other)
+    }
+}
+
+impl Eq for CodePage {}
+
+impl Hash for CodePage {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.ccsid.hash(state);
+    }
+}
+
+// How a mapping is used, the precision indicator of a .ucm line
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+enum Precision {
+    RoundTrip,
+    // Only encoded
+    Fallback,
+    // Only decoded
+    ReverseFallback,
+}
+
+impl CodePage {
+    pub fn load_ucm(path: impl AsRef<Path>) -> Result<Self, CodePageError> {
+        let path = path.as_ref();
+        let text = std::fs::read_to_string(path).context(ReadSnafu { path })?;
+        Self::parse_ucm(&text)
+    }
+
+    // Reads the SBCS and EBCDIC_STATEFUL classes. Mappings of several code points and outside the
+    // Basic Multilingual Plane are skipped, as are substitution mappings (|2).
+    pub fn parse_ucm(text: &str) -> Result<Self, CodePageError> {
+        let mut name = String::new();
+        let mut stateful = false;
+        let mut decode = [u32::from(REPLACEMENT) as u16; 256];
+        let mut encode = HashMap::new();
+        let mut dbcs_decode = HashMap::new();
+        let mut dbcs_encoded = HashSet::new();
+        let mut dbcs_encode_only = vec![];
+        let mut dbcs_decode_only = vec![];
+        let mut decoded = [false; 256];
+        let mut in_charmap = false;
+        for (index, line) in text.lines().enumerate() {
+            let line_number = index + 1;
+            let syntax = |message: &str| SyntaxSnafu { line: line_number, message }.build();
+            let line = line.split('#').next().unwrap_or_default().trim();
+            if line.is_empty() {
+                continue;
+            }
+            if !in_charmap {
+                match line.split_once(char::is_whitespace) {
+                    _ if line == "CHARMAP" => in_charmap = true,
+                    Some(("<code_set_name>", value)) => {
+                        name = value.trim().trim_matches('"').into()
+                    }
+                    Some(("<uconv_class>", value)) => match value.trim().trim_matches('"') {
+                        "SBCS" => stateful = false,
+                        "EBCDIC_STATEFUL" => stateful = true,
+                        class => return UnsupportedSnafu { class }.fail(),
+                    },
+                    // Everything else in the header describes the file, not the mappings
+                    _ => {}
+                }
+                continue;
+            }
+            if line == "END CHARMAP" {
+                break;
+            }
+
+            let (ch, rest) =
+                line.split_once(char::is_whitespace).ok_or_else(|| syntax("no bytes"))?;
+            let rest = rest.trim();
+            let (bytes, precision) = match rest.split_once('|') {
+                Some((bytes, precision)) => (bytes.trim(), Some(precision.trim())),
+                None => (rest, None),
+            };
+            let precision = match precision {
+                None | Some("0") => Precision::RoundTrip,
+                Some("1") => Precision::Fallback,
+                Some("3") => Precision::ReverseFallback,
+                Some("2") => continue,
+                Some(_) => return Err(syntax("unknown precision indicator")),
+            };
+            let Some(ch) = parse_code_point(ch).map_err(syntax)? else {
+                continue;
+            };
+            let bytes = parse_bytes(bytes).map_err(syntax)?;
+
+            let ambiguous = || AmbiguousSnafu { line: line_number, mapping: line.to_owned() };
+            match bytes[..] {
+                [byte] => {
+                    if stateful && (byte == SO || byte == SI) {
+                        return Err(syntax("SO and SI can't be mapped"));
+                    }
+                    if precision != Precision::Fallback {
+                        ensure!(!decoded[byte as usize], ambiguous());
+                        decoded[byte as usize] = true;
+                        decode[byte as usize] = ch;
+                    }
+                    if precision != Precision::ReverseFallback {
+                        ensure!(encode.insert(ch, byte).is_none(), ambiguous());
+                    }
+                }
+                [hi, lo] if stateful && hi >= 0x40 && lo >= 0x40 => {
+                    let code = u16::from_be_bytes([hi, lo]);
+                    if precision != Precision::Fallback {
+                        ensure!(dbcs_decode.insert(code, ch).is_none(), ambiguous());
+                    }
+                    if precision != Precision::ReverseFallback {
+                        ensure!(dbcs_encoded.insert(ch), ambiguous());
+                    }
+                    match precision {
+                        Precision::RoundTrip => {}
+                        Precision::Fallback => {
+                            dbcs_encode_only.push(u32::from(ch) << 16 | code as u32)
+                        }
+                        Precision::ReverseFallback => dbcs_decode_only.push(code),
+                    }
+                }
+                _ => return Err(syntax("wrong number of bytes for the conversion class")),
+            }
+        }
+        ensure!(decode[0x40] == u16::from(b' ') && encode.get(&0x20) == Some(&0x40), NoSpaceSnafu);
+
+        let mut dbcs_decode: Vec<u32> =
+            dbcs_decode.into_iter().map(|(code, ch)| u32::from(code) << 16 | ch as u32).collect();
+        dbcs_decode.sort_unstable();
+        dbcs_decode_only.sort_unstable();
+        let mut encode: Vec<(u16, u8)> = encode.into_iter().collect();
+        encode.sort_unstable();
+        let dbcs = stateful
+            .then(|| DbcsTable::with_fallbacks(dbcs_decode, dbcs_decode_only, dbcs_encode_only));
+
+        let mut latin1 = ([SUB; 256], [0x1A; 256]);
+        for &(ch, byte) in encode.iter().filter(|&&(ch, _)| ch < 0x100) {
+            latin1.0[ch as usize] = byte;
+        }
+        for (byte, &ch) in decode.iter().enumerate().filter(|&(_, &ch)| ch < 0x100) {
+            latin1.1[byte] = ch as u8;
+        }
+
+        let ccsid = ccsid_of(&name).unwrap_or(0);
+        Ok(CodePage { name, ccsid, code_pages: (ccsid, None), decode, encode, latin1, dbcs })
+    }
+
+    // Makes the page an encoding that `Encoding::from_ccsid`, `from_code_pages` and `from_name`
+    // find, in place of a built-in page with the same CCSID. Registering a page again makes it
+    // the latest one.
+    pub fn register(self) -> Encoding {
+        let page = self.keep();
+        let mut registry = REGISTRY.write().unwrap_or_else(|error| error.into_inner());
+        registry.retain(|&other| !std::ptr::eq(other, page));
+        registry.push(page);
+        Encoding::Custom(page)
+    }
+
+    // Makes the page an encoding without registering it. Encodings are `Copy` and refer to their
+    // page, so it is leaked and stays for the rest of the program. A page that is the same as one
+    // kept before, e.g. the same file loaded again, is dropped for the one already kept.
+    pub fn into_encoding(self) -> Encoding {
+        Encoding::Custom(self.keep())
+    }
+
+    fn keep(self) -> &'static CodePage {
+        let mut pages = PAGES.write().unwrap_or_else(|error| error.into_inner());
+        if let Some(&page) = pages.iter().find(|page| page.same_mapping(&self)) {
+            return page;
+        }
+        let page = Box::leak(Box::new(self));
+        pages.push(page);
+        page
+    }
+
+    // The latin1 tables are made from the others
+    fn same_mapping(&self, other: &CodePage) -> bool {
+        let same_dbcs = match (&self.dbcs, &other.dbcs) {
+            (Some(dbcs), Some(other)) => dbcs.same_codes(other),
+            (dbcs, other) => dbcs.is_none() && other.is_none(),
+        };
+        self.ccsid == other.ccsid
+            && self.name == other.name
+            && self.code_pages == other.code_pages
+            && self.decode == other.decode
+            && self.encode == other.encode
+            && same_dbcs
+    }
+
+    pub fn is_dbcs(&self) -> bool {
+        self.dbcs.is_some()
+    }
+
+    pub(crate) fn decode_char(&self, byte: u8) -> char {
+        char::from_u32(self.decode[byte as usize].into()).unwrap_or(REPLACEMENT)
+    }
+
+    pub(crate) fn encode_char(&self, ch: char) -> Option<u8> {
+        let ch = u16::try_from(u32::from(ch)).ok()?;
+        let index = self.encode.binary_search_by_key(&ch, |&(ch, _)| ch).ok()?;
+        Some(self.encode[index].1)
+    }
+
+    pub(crate) fn latin1_tables(&self) -> &([u8; 256], [u8; 256]) {
+        &self.latin1
+    }
+
+    pub(crate) fn dbcs_table(&self) -> Option<&DbcsTable> {
+        self.dbcs.as_ref()
+    }
+}
+
+// The registered pages, latest first
+pub(crate) fn registered() -> Vec<Encoding> {
+    let registry = REGISTRY.read().unwrap_or_else(|error| error.into_inner());
+    registry.iter().rev().map(|&page| Encoding::Custom(page)).collect()
+}
+
+// `<U00A0>` as a BMP character. `None` for sequences and characters outside the BMP.
+fn parse_code_point(text: &str) -> Result<Option<u16>, &'static str> {
+    let hex = text
+        .strip_prefix("<U")
+        .and_then(|text| text.strip_suffix('>'))
+        .ok_or("expected a code point like <U0041>")?;
+    if hex.contains('>') {
+        return Ok(None);
+    }
+    let ch = u32::from_str_radix(hex, 16).map_err(|_| "invalid code point")?;
+    Ok(u16::try_from(ch).ok())
+}
+
+// `\x42\x5A`
+fn parse_bytes(text: &str) -> Result<Vec<u8>, &'static str> {
+    let bytes: Result<Vec<u8>, _> = text
+        .split("\\x")
+        .skip(1)
+        .map(|hex| u8::from_str_radix(hex.trim(), 16).map_err(|_| "invalid byte"))
+        .collect();
+    match bytes? {
+        bytes if bytes.is_empty() || !text.starts_with("\\x") => Err("expected bytes like \\x41"),
+        bytes => Ok(bytes),
+    }
+}
+
+// ICU names its IBM pages `ibm-<CCSID>_<variant>`
+fn ccsid_of(name: &str) -> Option<u16> {
+    let name = name.to_ascii_lowercase();
+    let number = name.strip_prefix("ibm-").or_else(|| name.strip_prefix("ibm"))?;
+    let end = number.find(|ch: char| !ch.is_ascii_digit()).unwrap_or(number.len());
+    number[..end].parse().ok()
+}
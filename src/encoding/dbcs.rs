@@ -0,0 +1,220 @@
+use std::borrow::Cow;
+use std::sync::OnceLock;
+
+use crate::encoding::Encoding;
+
+// Shift Out starts a run of double byte characters in mixed text, Shift In ends it. Both take a
+// buffer position of their own.
+pub const SO: u8 = 0x0E;
+pub const SI: u8 = 0x0F;
+
+// The double byte space, which is also written for characters a DBCS field can't show
+pub const DBCS_SPACE: u16 = 0x4040;
+
+// Shown for double byte codes without a character and for half a double byte code
+pub(crate) const REPLACEMENT: char = '\u{FFFD}';
+
+// The double byte half of a mixed code page. Entries hold the code in the high and the character
+// in the low half, sorted by code. Codes that share a character with another one are only
+// decoded, fallbacks are only encoded. The encoding side is built on first use.
+#[derive(Debug)]
+pub(crate) struct DbcsTable {
+    decode: Cow<'static, [u32]>,
+    decode_only: Cow<'static, [u16]>,
+    // The character in the high and the code in the low half
+    encode_only: Cow<'static, [u32]>,
+    encode: OnceLock<Vec<(u16, u16)>>,
+}
+
+impl DbcsTable {
+    pub(crate) const fn new(decode: &'static [u32], decode_only: &'static [u16]) -> Self {
+        DbcsTable {
+            decode: Cow::Borrowed(decode),
+            decode_only: Cow::Borrowed(decode_only),
+            encode_only: Cow::Borrowed(&[]),
+            encode: OnceLock::new(),
+        }
+    }
+
+    pub(crate) fn with_fallbacks(
+        decode: Vec<u32>,
+        decode_only: Vec<u16>,
+        encode_only: Vec<u32>,
+    ) -> Self {
+        DbcsTable {
+            decode: Cow::Owned(decode),
+            decode_only: Cow::Owned(decode_only),
+            encode_only: Cow::Owned(encode_only),
+            encode: OnceLock::new(),
+        }
+    }
+
+    // Whether both map the same codes, regardless of what was built so far
+    pub(crate) fn same_codes(&self, other: &DbcsTable) -> bool {
+        self.decode == other.decode
+            && self.decode_only == other.decode_only
+            && self.encode_only == other.encode_only
+    }
+
+    pub(crate) fn decode(&self, code: u16) -> Option<char> {
+        let index = self.decode.binary_search_by_key(&code, |&entry| (entry >> 16) as u16).ok()?;
+        char::from_u32(self.decode[index] & 0xFFFF)
+    }
+
+    pub(crate) fn encode(&self, ch: char) -> Option<u16> {
+        let ch = u16::try_from(u32::from(ch)).ok()?;
+        let encode = self.encode.get_or_init(|| {
+            let mut encode: Vec<(u16, u16)> = self
+                .decode
+                .iter()
+                .map(|&entry| (entry as u16, (entry >> 16) as u16))
+                .filter(|(_, code)| self.decode_only.binary_search(code).is_err())
+                .chain(self.encode_only.iter().map(|&entry| ((entry >> 16) as u16, entry as u16)))
+                .collect();
+            encode.sort_unstable();
+            encode
+        });
+        let index = encode.binary_search_by_key(&ch, |&(ch, _)| ch).ok()?;
+        Some(encode[index].1)
+    }
+}
+
+// The Latin-1 tables of a mixed code page for `Encoding::encode_table` and `decode_table`. Bytes
+// without a Latin-1 character decode to SUB, Latin-1 characters the page doesn't have encode to
+// the EBCDIC SUB.
+pub(crate) const fn latin1_tables(sbcs: &[u16; 256]) -> ([u8; 256], [u8; 256]) {
+    let mut encode = [0x3F; 256];
+    let mut decode = [0x1A; 256];
+    let mut byte = 0;
+    while byte < 256 {
+        let ch = sbcs[byte];
+        if ch < 0x100 && byte as u8 != SO && byte as u8 != SI {
+            encode[ch as usize] = byte as u8;
+            decode[byte] = ch as u8;
+        }
+        byte += 1;
+    }
+    (encode, decode)
+}
+
+// The single byte half of a mixed code page sorted by character, for `Encoding::encode_char`.
+// Insertion sort keeps the lowest byte first for characters on more than one.
+pub(crate) const fn sbcs_encode_table(sbcs: &[u16; 256]) -> [(u16, u8); 256] {
+    let mut table = [(0, 0); 256];
+    let mut byte = 0;
+    while byte < 256 {
+        let mut index = byte;
+        while index > 0 && table[index - 1].0 > sbcs[byte] {
+            table[index] = table[index - 1];
+            index -= 1;
+        }
+        table[index] = (sbcs[byte], byte as u8);
+        byte += 1;
+    }
+    table
+}
+
+pub(crate) fn encode_sbcs(table: &[(u16, u8); 256], ch: u16) -> Option<u8> {
+    let index = table.partition_point(|&(v, _)| v < ch);
+    table.get(index).filter(|&&(v, _)| v == ch).map(|&(_, byte)| byte)
+}
+
+// A character in a mixed code page
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub(crate) enum Code {
+    Single(u8),
+    Double(u16),
+}
+
+// Writes codes as mixed text, shifting out before double byte codes and back in before single
+// byte ones. In DBCS fields everything is double byte and there is no shifting.
+#[derive(Copy, Clone, Debug, Default)]
+pub(crate) struct Shifter {
+    dbcs_field: bool,
+    shifted: bool,
+}
+
+impl Shifter {
+    pub(crate) fn new(dbcs_field: bool) -> Self {
+        Shifter { dbcs_field, shifted: false }
+    }
+
+    pub(crate) fn push(&mut self, code: Code, output: &mut impl Extend<u8>) {
+        match code {
+            Code::Double(code) if self.dbcs_field => output.extend(code.to_be_bytes()),
+            Code::Single(_) if self.dbcs_field => output.extend(DBCS_SPACE.to_be_bytes()),
+            Code::Double(code) => {
+                if !self.shifted {
+                    output.extend([SO]);
+                    self.shifted = true;
+                }
+                output.extend(code.to_be_bytes());
+            }
+            Code::Single(byte) => {
+                if self.shifted {
+                    output.extend([SI]);
+                    self.shifted = false;
+                }
+                output.extend([byte]);
+            }
+        }
+    }
+
+    // Text always ends shifted in, so whatever follows is single byte again
+    pub(crate) fn finish(&mut self, output: &mut impl Extend<u8>) {
+        if self.shifted {
+            output.extend([SI]);
+            self.shifted = false;
+        }
+    }
+}
+
+// Encodes text of a mixed code page. Characters without a code become spaces.
+pub(crate) fn encode_mixed(
+    text: &str,
+    encoding: Encoding,
+    dbcs_field: bool,
+    output: &mut impl Extend<u8>,
+) {
+    let mut shifter = Shifter::new(dbcs_field);
+    for ch in text.chars() {
+        shifter.push(encoding.encode_code(ch).unwrap_or(Code::Single(0x40)), output);
+    }
+    shifter.finish(output);
+}
+
+// Decodes text of a mixed code page. SO and SI only switch between single and double byte and
+// aren't part of the text. `shifted` is set for the text of DBCS fields.
+pub(crate) fn decode_mixed(
+    data: &[u8],
+    encoding: Encoding,
+    shifted: bool,
+) -> impl Iterator<Item = char> + '_ {
+    let mut rest = data;
+    let mut shifted = shifted;
+    std::iter::from_fn(move || {
+        loop {
+            let (ch, len) = match *rest {
+                [] => return None,
+                [SO, ..] => {
+                    shifted = true;
+                    rest = &rest[1..];
+                    continue;
+                }
+                [SI, ..] => {
+                    shifted = false;
+                    rest = &rest[1..];
+                    continue;
+                }
+                [hi, lo, ..] if shifted && lo >= 0x40 => {
+                    let ch = encoding.decode_dbcs(u16::from_be_bytes([hi, lo]));
+                    (ch.unwrap_or(REPLACEMENT), 2)
+                }
+                [_, ..] if shifted => (REPLACEMENT, 1),
+                [byte, ..] => (encoding.decode_char(byte), 1),
+            };
+            rest = &rest[len..];
+            return Some(ch);
+        }
+    })
+}
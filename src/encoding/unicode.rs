@@ -0,0 +1,98 @@
+use snafu::Snafu;
+
+use crate::encoding::dbcs::{Code, Shifter};
+use crate::encoding::{Encoding, decode_into};
+
+// EBCDIC SUB, shown as a solid box or dot by most terminals
+pub const SUB: u8 = 0x3F;
+
+// What to write for a character the code page doesn't have
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub enum Substitute {
+    // Fail with `EncodeError::Unmappable` instead
+    #[default]
+    None,
+    Sub,
+    // A question mark in the target code page
+    QuestionMark,
+    // Any byte, it isn't checked against the code page
+    Custom(u8),
+}
+
+#[derive(Clone, Debug, Snafu, Eq, PartialEq)]
+pub enum EncodeError {
+    #[snafu(display("{:?} at offset {} has no mapping in {}", ch, offset, encoding.name()))]
+    Unmappable { ch: char, offset: usize, encoding: Encoding },
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Encoded {
+    pub data: Vec<u8>,
+    // Characters that were replaced by the substitute, with their byte offset in the text
+    pub lost: Vec<(usize, char)>,
+}
+
+impl Encoded {
+    pub fn is_lossless(&self) -> bool {
+        self.lost.is_empty()
+    }
+}
+
+// Encodes Unicode text for the data stream. Characters that map to the control codes below 0x40
+// count as unmappable, because the terminal would take them for orders. Mixed code pages write
+// double byte characters between SO and SI, substitutes are always single byte.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct Encoder {
+    pub encoding: Encoding,
+    pub substitute: Substitute,
+}
+
+impl Encoder {
+    pub fn new(encoding: Encoding, substitute: Substitute) -> Self {
+        Encoder { encoding, substitute }
+    }
+
+    pub fn encode_char(&self, ch: char) -> Option<u8> {
+        self.encoding.encode_char(ch).filter(|&byte| byte >= 0x40)
+    }
+
+    fn substitute(&self) -> Option<u8> {
+        match self.substitute {
+            Substitute::None => None,
+            Substitute::Sub => Some(SUB),
+            Substitute::QuestionMark => self.encoding.encode_char('?'),
+            Substitute::Custom(byte) => Some(byte),
+        }
+    }
+
+    pub fn encode(&self, text: &str) -> Result<Encoded, EncodeError> {
+        let mut encoded = Encoded { data: Vec::with_capacity(text.len()), lost: vec![] };
+        let mut shifter = Shifter::new(false);
+        for (offset, ch) in text.char_indices() {
+            let code = match self.encoding.encode_code(ch) {
+                Some(code) => code,
+                None => {
+                    let encoding = self.encoding;
+                    let byte = self.substitute().ok_or(EncodeError::Unmappable {
+                        ch,
+                        offset,
+                        encoding,
+                    })?;
+                    encoded.lost.push((offset, ch));
+                    Code::Single(byte)
+                }
+            };
+            shifter.push(code, &mut encoded.data);
+        }
+        shifter.finish(&mut encoded.data);
+        Ok(encoded)
+    }
+}
+
+// Every byte of a single byte code page stands for a character, so decoding can't fail. Double
+// byte codes of mixed pages without a character become U+FFFD.
+pub fn decode(data: &[u8], encoding: Encoding) -> String {
+    let mut decoded = String::new();
+    decode_into(data, encoding, &mut decoded);
+    decoded
+}
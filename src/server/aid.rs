@@ -44,6 +44,17 @@ pub enum AID {
     MagReaderNumber,
 }
 
+impl AID {
+    // Keys that only send the AID byte, without cursor address or data. The terminal still sends
+    // everything when they are answered to a Read Modified All.
+    pub fn is_short_read(self) -> bool {
+        matches!(
+            self,
+            AID::PA1 | AID::PA2 | AID::PA3 | AID::Clear | AID::ClearPartition | AID::SysReq
+        )
+    }
+}
+
 impl From<AID> for u8 {
     fn from(aid: AID) -> u8 {
         use self::AID::*;
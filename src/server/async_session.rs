@@ -0,0 +1,193 @@
+use std::time::Duration;
+
+use snafu::ResultExt;
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+
+use crate::debug_msg;
+use crate::encoding::Encoding;
+use crate::server::protocol::{Protocol, RecordWriter};
+use crate::server::query::{QueryReply, ReadPartitionQuery};
+use crate::server::stream::{OutputRecord, StreamOptions};
+use crate::server::terminal::TerminalModel;
+use crate::server::{
+    IoSnafu, SessionConfig, SessionError, StreamSnafu, negotiation_error, tn3270e,
+};
+
+type Error = SessionError;
+
+// Async counterpart of `Session`, usable over any tokio stream
+pub struct AsyncSession<S> {
+    protocol: Protocol,
+
+    stream: S,
+}
+
+impl<S: AsyncRead + AsyncWrite + Unpin> AsyncSession<S> {
+    pub async fn new(stream: S) -> Result<Self, Error> {
+        Self::with_config(stream, SessionConfig::default()).await
+    }
+
+    pub async fn with_config(stream: S, config: SessionConfig) -> Result<Self, Error> {
+        // Wrap the stream in a TLS acceptor before creating the session instead
+        #[cfg(feature = "tls")]
+        if config.starttls.is_some() {
+            return Err(SessionError::Unsupported {
+                message: "STARTTLS is not supported by AsyncSession".into(),
+            });
+        }
+        let mut session = AsyncSession { protocol: Protocol::new(config), stream };
+
+        debug_msg!("Negotiating...");
+        session.negotiate().await?;
+        debug_msg!("Negotiation complete.");
+        Ok(session)
+    }
+
+    pub fn tn3270e(&self) -> Option<&tn3270e::Device> {
+        self.protocol.tn3270e()
+    }
+
+    // Terminal type as announced by the client, e.g. `IBM-3279-2-E`
+    pub fn terminal_type(&self) -> Option<String> {
+        self.protocol.term_type_name()
+    }
+
+    pub fn terminal_model(&self) -> &TerminalModel {
+        self.protocol.terminal_model()
+    }
+
+    // Code page of the text in every record, see `SessionConfig::encoding`
+    pub fn encoding(&self) -> Encoding {
+        self.protocol.encoding()
+    }
+
+    pub fn set_encoding(&mut self, encoding: Encoding) {
+        self.protocol.set_encoding(encoding);
+    }
+
+    // Address mode and code page to write and parse records for this terminal with
+    pub fn stream_options(&self) -> StreamOptions {
+        self.protocol.stream_options()
+    }
+
+    async fn negotiate(&mut self) -> Result<(), Error> {
+        let timeout = self.protocol.config().negotiation_timeout;
+        tokio::time::timeout(timeout, self.negotiate_until_ready())
+            .await
+            .map_err(|_| SessionError::NegotiationTimeout)?
+    }
+
+    async fn negotiate_until_ready(&mut self) -> Result<(), Error> {
+        let initial_negotiation = self.protocol.start_negotiation()?;
+        self.write_negotiation(&initial_negotiation).await?;
+
+        let mut idata = vec![0; 2000];
+
+        while !self.protocol.is_ready() {
+            let len = self.stream.read(&mut idata).await.map_err(negotiation_error)?;
+            if len == 0 {
+                return Err(SessionError::Disconnected);
+            }
+            let reply = self.protocol.receive(&idata[..len])?;
+            if !reply.is_empty() {
+                self.write_negotiation(&reply).await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    // Flushed right away, the terminal waits for it before answering
+    async fn write_negotiation(&mut self, data: &[u8]) -> Result<(), Error> {
+        self.stream.write_all(data).await.map_err(negotiation_error)?;
+        self.stream.flush().await.map_err(negotiation_error)
+    }
+
+    pub async fn send_record(&mut self, record: impl Into<Vec<u8>>) -> std::io::Result<()> {
+        let send_data = self.protocol.encode_record(record);
+        self.stream.write_all(send_data.as_slice()).await?;
+        self.stream.flush().await
+    }
+
+    // See `Session::send`
+    pub async fn send<R: OutputRecord + ?Sized>(
+        &mut self,
+        record: &R,
+    ) -> Result<R::Response, Error> {
+        self.send_within(record, None).await?.ok_or(SessionError::Disconnected)
+    }
+
+    // See `Session::send_within`. The record is framed in memory, as the stream can't be written
+    // to while the record is being produced.
+    async fn send_within<R: OutputRecord + ?Sized>(
+        &mut self,
+        record: &R,
+        timeout: Option<Duration>,
+    ) -> Result<Option<R::Response>, Error> {
+        let options = self.stream_options();
+        if record.expects_response() {
+            self.protocol.discard_records();
+        }
+        let header = self.protocol.next_header();
+        let mut data = vec![];
+        let mut writer = RecordWriter::new(&mut data, header).context(IoSnafu)?;
+        record.write_to(&mut writer, options).context(IoSnafu)?;
+        writer.finish().context(IoSnafu)?;
+        self.stream.write_all(&data).await.context(IoSnafu)?;
+        self.stream.flush().await.context(IoSnafu)?;
+        if !record.expects_response() {
+            return record.parse_response(&[], options).map(Some).context(StreamSnafu);
+        }
+
+        let Some(reply) = self.receive_record(timeout).await.context(IoSnafu)? else {
+            return Ok(None);
+        };
+        record.parse_response(&reply, options).map(Some).context(StreamSnafu)
+    }
+
+    // See `Session::query`
+    pub async fn query(
+        &mut self,
+        query: &ReadPartitionQuery,
+        timeout: Option<Duration>,
+    ) -> Result<Option<Vec<QueryReply>>, Error> {
+        let Some(replies) = self.send_within(query, timeout).await? else {
+            return Ok(None);
+        };
+        self.protocol.apply_query_replies(&replies);
+        Ok(Some(replies))
+    }
+
+    // Waits until a complete record arrived. Returns `None` on timeout or when the peer closed
+    // the connection. The timeout only cancels waiting for data, replies to the peer's telnet
+    // negotiation are always written whole.
+    pub async fn receive_record(
+        &mut self,
+        timeout: Option<Duration>,
+    ) -> std::io::Result<Option<Vec<u8>>> {
+        let deadline = timeout.map(|timeout| tokio::time::Instant::now() + timeout);
+        let mut buf = vec![0; 1024];
+        while !self.protocol.has_record() {
+            let read = self.stream.read(buf.as_mut_slice());
+            let len = match deadline {
+                Some(deadline) => match tokio::time::timeout_at(deadline, read).await {
+                    Ok(len) => len?,
+                    Err(_) => return Ok(None),
+                },
+                None => read.await?,
+            };
+            if len == 0 {
+                return Ok(None);
+            }
+            let reply = self
+                .protocol
+                .receive(&buf[..len])
+                .map_err(|err| std::io::Error::new(std::io::ErrorKind::InvalidData, err))?;
+            if !reply.is_empty() {
+                self.stream.write_all(&reply).await?;
+                self.stream.flush().await?;
+            }
+        }
+        Ok(self.protocol.next_record())
+    }
+}
@@ -0,0 +1,168 @@
+use std::convert::TryFrom;
+
+use crate::encoding::Encoding;
+use crate::encoding::dbcs::decode_mixed;
+use crate::server::aid::AID;
+use crate::server::extended_field_attributes::ExtendedFieldAttribute;
+use crate::server::stream::{StreamFormatError, StreamOptions};
+use crate::server::wcc::FieldAttribute;
+
+#[derive(Clone, Debug)]
+pub struct BufferField {
+    // Address of the attribute byte, the field contents start at the next address
+    pub addr: u16,
+    pub attribute: FieldAttribute,
+    // Only sent by terminals in extended field reply mode
+    pub extended: Vec<ExtendedFieldAttribute>,
+}
+
+impl BufferField {
+    // Whether the contents are double byte only, without SO and SI
+    pub fn is_dbcs(&self) -> bool {
+        self.extended.iter().any(|attr| attr.is_dbcs())
+    }
+}
+
+// The terminal's buffer as reported in reply to a Read Buffer command
+#[derive(Clone, Debug)]
+pub struct BufferSnapshot {
+    pub aid: AID,
+    pub cursor: u16,
+    // Raw EBCDIC contents, one byte per buffer position. Attribute positions are 0x00.
+    pub data: Vec<u8>,
+    pub fields: Vec<BufferField>,
+    // Character attributes (SA orders) in character reply mode, with the address they start at
+    pub character_attributes: Vec<(u16, ExtendedFieldAttribute)>,
+    // Code page the contents are decoded with
+    pub encoding: Encoding,
+}
+
+impl BufferSnapshot {
+    pub fn parse(record: &[u8]) -> Result<Self, StreamFormatError> {
+        Self::parse_with(record, StreamOptions::default())
+    }
+
+    // Errors are `StreamFormatError::Malformed` with the position of the problem
+    pub fn parse_with(record: &[u8], options: StreamOptions) -> Result<Self, StreamFormatError> {
+        let header = record
+            .get(..3)
+            .ok_or_else(|| StreamFormatError::UnexpectedEOR.at(record.len(), None))?;
+        let aid = AID::try_from(header[0]).map_err(|error| error.at(0, None))?;
+        let cursor = options.mode.decode(&header[1..3]).map_err(|error| error.at(1, None))?;
+        let mut result = Self {
+            aid,
+            cursor,
+            data: vec![],
+            fields: vec![],
+            character_attributes: vec![],
+            encoding: options.encoding,
+        };
+
+        // The buffer is sent in order starting at address 0, so there are no SBA orders
+        let mut rest = &record[3..];
+        while let Some(&order) = rest.first() {
+            let offset = record.len() - rest.len();
+            let len = result.parse_order(rest).map_err(|error| error.at(offset, Some(order)))?;
+            rest = &rest[len..];
+        }
+        Ok(result)
+    }
+
+    // Adds the order or data at the start of `record`, returning the number of bytes it took
+    fn parse_order(&mut self, record: &[u8]) -> Result<usize, StreamFormatError> {
+        let addr = self.data.len() as u16;
+        Ok(match record[0] {
+            0x1D => {
+                let attribute = *record.get(1).ok_or(StreamFormatError::UnexpectedEOR)?;
+                self.fields.push(BufferField {
+                    addr,
+                    attribute: FieldAttribute::from_bits_truncate(attribute & 0x3F),
+                    extended: vec![],
+                });
+                self.data.push(0x00);
+                2
+            }
+            0x29 => {
+                let count = *record.get(1).ok_or(StreamFormatError::UnexpectedEOR)? as usize;
+                let pairs = record.get(2..2 + count * 2).ok_or(StreamFormatError::UnexpectedEOR)?;
+                let mut attribute = FieldAttribute::NONE;
+                let mut extended = vec![];
+                for pair in pairs.chunks(2) {
+                    // The basic field attribute is sent as type 0xC0
+                    if pair[0] == 0xC0 {
+                        attribute = FieldAttribute::from_bits_truncate(pair[1] & 0x3F);
+                    } else {
+                        extended.push(ExtendedFieldAttribute::try_from(pair)?);
+                    }
+                }
+                self.fields.push(BufferField { addr, attribute, extended });
+                self.data.push(0x00);
+                2 + count * 2
+            }
+            0x28 => {
+                let attr = record.get(1..3).ok_or(StreamFormatError::UnexpectedEOR)?;
+                self.character_attributes.push((addr, ExtendedFieldAttribute::try_from(attr)?));
+                3
+            }
+            _ => {
+                // Anything else is buffer contents, including nulls and control characters
+                let len = record
+                    .iter()
+                    .position(|&v| matches!(v, 0x1D | 0x28 | 0x29))
+                    .unwrap_or(record.len());
+                self.data.extend_from_slice(&record[..len]);
+                len
+            }
+        })
+    }
+
+    // The field containing the given address, if the buffer is formatted
+    pub fn field_at(&self, addr: u16) -> Option<&BufferField> {
+        // The last field wraps around to the start of the buffer
+        self.fields.iter().rev().find(|field| field.addr < addr).or_else(|| self.fields.last())
+    }
+
+    // The decoded contents of a field, up to the next attribute
+    pub fn field_contents(&self, field: &BufferField) -> String {
+        let start = field.addr as usize + 1;
+        match self.fields.iter().map(|f| f.addr as usize).find(|&addr| addr >= start) {
+            Some(end) => self.decode(&self.data[start..end], field.is_dbcs()),
+            None => {
+                let first = self.fields.first().map_or(0, |f| f.addr as usize);
+                let data = [self.data.get(start..).unwrap_or_default(), &self.data[..first]];
+                self.decode(&data.concat(), field.is_dbcs())
+            }
+        }
+    }
+
+    // The whole buffer as text, with attribute positions and nulls shown as spaces
+    pub fn text(&self) -> String {
+        let Some(last) = self.fields.last().filter(|_| self.encoding.is_dbcs()) else {
+            return self.decode(&self.data, false);
+        };
+        // Mixed text is decoded a field at a time, only some of them are double byte. The start
+        // of the buffer belongs to the last field.
+        let first = self.fields[0].addr as usize;
+        let mut text = self.decode(&self.data[..first], last.is_dbcs());
+        for (index, field) in self.fields.iter().enumerate() {
+            let end = self.fields.get(index + 1).map_or(self.data.len(), |next| next.addr as usize);
+            text.push(' ');
+            text.push_str(&self.decode(&self.data[field.addr as usize + 1..end], field.is_dbcs()));
+        }
+        text
+    }
+
+    fn decode(&self, data: &[u8], dbcs_field: bool) -> String {
+        if self.encoding.is_dbcs() {
+            // Nulls are spaces, a pair of them a double byte one in DBCS fields
+            let data: Vec<u8> = data.iter().map(|&v| if v == 0x00 { 0x40 } else { v }).collect();
+            return decode_mixed(&data, self.encoding, dbcs_field).collect();
+        }
+        data.iter()
+            .map(|&v| match v {
+                0x00 => ' ',
+                v => self.encoding.decode_char(v),
+            })
+            .collect()
+    }
+}
@@ -26,6 +26,10 @@ bitflags! {
     }
 }
 
+// Character Set attribute value of the DBCS character set, which terminals report with this local
+// ID in their Character Sets query reply. Text in fields with it is double byte without SO and SI.
+pub const DBCS_CHARACTER_SET: u8 = 0xF8;
+
 #[derive(Clone, Debug, Hash)]
 pub enum ExtendedFieldAttribute {
     AllAttributes,
@@ -69,6 +73,10 @@ impl TryFrom<&[u8]> for ExtendedFieldAttribute {
 }
 
 impl ExtendedFieldAttribute {
+    pub fn is_dbcs(&self) -> bool {
+        matches!(self, ExtendedFieldAttribute::CharacterSet(DBCS_CHARACTER_SET))
+    }
+
     pub fn encoded(self) -> (u8, u8) {
         match self {
             ExtendedFieldAttribute::AllAttributes => (0x00, 0x00),
@@ -0,0 +1,295 @@
+use std::collections::HashMap;
+use std::net::{Shutdown, SocketAddr, TcpListener, TcpStream, ToSocketAddrs};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::mpsc::{Receiver, SyncSender};
+use std::sync::{Arc, Condvar, Mutex, mpsc};
+use std::time::{Duration, Instant};
+
+use crate::debug_msg;
+use crate::server::{Session, SessionConfig, SessionError};
+
+// How often the accept loop checks for a shutdown request
+const POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+#[derive(Clone, Debug)]
+pub struct ServerConfig {
+    // Number of threads running sessions; further connections wait in a queue
+    pub workers: usize,
+    // Connections beyond this limit (running and queued) are closed right after accepting them
+    pub max_connections: usize,
+    // How long a shutdown waits for running sessions before their sockets are closed. `None`
+    // waits until every session finished.
+    pub shutdown_timeout: Option<Duration>,
+    pub session: SessionConfig,
+    // Implicit TLS for every accepted connection
+    #[cfg(feature = "tls")]
+    pub tls: Option<crate::server::tls::TlsConfig>,
+}
+
+impl Default for ServerConfig {
+    fn default() -> Self {
+        ServerConfig {
+            workers: 16,
+            max_connections: 64,
+            shutdown_timeout: Some(Duration::from_secs(30)),
+            session: SessionConfig::default(),
+            #[cfg(feature = "tls")]
+            tls: None,
+        }
+    }
+}
+
+// Invoked on a worker thread for every successfully negotiated session
+pub trait SessionHandler: Send + Sync + 'static {
+    fn handle(&self, session: Session, context: &SessionContext);
+}
+
+impl<F> SessionHandler for F
+where
+    F: Fn(Session, &SessionContext) + Send + Sync + 'static,
+{
+    fn handle(&self, session: Session, context: &SessionContext) {
+        self(session, context)
+    }
+}
+
+struct Shared {
+    shutdown: AtomicBool,
+    next_id: AtomicU64,
+    // Clones of all accepted sockets, used to enforce the limit and to close them on shutdown
+    connections: Mutex<HashMap<u64, TcpStream>>,
+    idle: Condvar,
+}
+
+impl Shared {
+    fn is_shutting_down(&self) -> bool {
+        self.shutdown.load(Ordering::SeqCst)
+    }
+
+    fn remove(&self, id: u64) {
+        let mut connections = self.connections.lock().unwrap();
+        connections.remove(&id);
+        if connections.is_empty() {
+            self.idle.notify_all();
+        }
+    }
+
+    // Waits for running sessions to end, closing their sockets once the shutdown timeout elapsed
+    fn drain(&self, timeout: Option<Duration>) {
+        let deadline = timeout.map(|timeout| Instant::now() + timeout);
+        let mut connections = self.connections.lock().unwrap();
+        while !connections.is_empty() {
+            connections = match deadline {
+                Some(deadline) => {
+                    let now = Instant::now();
+                    if now >= deadline {
+                        break;
+                    }
+                    self.idle.wait_timeout(connections, deadline - now).unwrap().0
+                }
+                None => self.idle.wait(connections).unwrap(),
+            };
+        }
+        for stream in connections.values() {
+            let _ = stream.shutdown(Shutdown::Both);
+        }
+    }
+}
+
+pub struct SessionContext {
+    peer_addr: SocketAddr,
+    shared: Arc<Shared>,
+}
+
+impl SessionContext {
+    pub fn peer_addr(&self) -> SocketAddr {
+        self.peer_addr
+    }
+
+    // Handlers should stop presenting new screens once this returns true
+    pub fn is_shutting_down(&self) -> bool {
+        self.shared.is_shutting_down()
+    }
+}
+
+#[derive(Clone)]
+pub struct ShutdownHandle {
+    shared: Arc<Shared>,
+}
+
+impl ShutdownHandle {
+    // Stops accepting connections and makes `Server::run` return once running sessions ended
+    pub fn shutdown(&self) {
+        self.shared.shutdown.store(true, Ordering::SeqCst);
+    }
+}
+
+struct Connection {
+    id: u64,
+    stream: TcpStream,
+    peer_addr: SocketAddr,
+}
+
+pub struct Server {
+    listener: TcpListener,
+    config: ServerConfig,
+    shared: Arc<Shared>,
+}
+
+impl Server {
+    pub fn bind(addr: impl ToSocketAddrs, config: ServerConfig) -> std::io::Result<Self> {
+        let listener = TcpListener::bind(addr)?;
+        listener.set_nonblocking(true)?;
+        Ok(Server {
+            listener,
+            config,
+            shared: Arc::new(Shared {
+                shutdown: AtomicBool::new(false),
+                next_id: AtomicU64::new(0),
+                connections: Mutex::new(HashMap::new()),
+                idle: Condvar::new(),
+            }),
+        })
+    }
+
+    pub fn local_addr(&self) -> std::io::Result<SocketAddr> {
+        self.listener.local_addr()
+    }
+
+    pub fn shutdown_handle(&self) -> ShutdownHandle {
+        ShutdownHandle { shared: self.shared.clone() }
+    }
+
+    // Accepts connections until a shutdown is requested through a `ShutdownHandle`
+    pub fn run(self, handler: impl SessionHandler) -> std::io::Result<()> {
+        let handler = Arc::new(handler);
+        let (sender, receiver) = mpsc::sync_channel(self.config.max_connections.max(1));
+        let receiver = Arc::new(Mutex::new(receiver));
+        let workers: Vec<_> = (0..self.config.workers.max(1))
+            .map(|_| {
+                let receiver = receiver.clone();
+                let handler = handler.clone();
+                let shared = self.shared.clone();
+                let config = self.config.clone();
+                std::thread::spawn(move || worker(&receiver, &*handler, &shared, &config))
+            })
+            .collect();
+
+        let result = self.accept_loop(&sender);
+        let Server { listener, config, shared } = self;
+        // Refuse new connections while the running sessions finish
+        drop(listener);
+        shared.shutdown.store(true, Ordering::SeqCst);
+        drop(sender);
+        shared.drain(config.shutdown_timeout);
+        for worker in workers {
+            let _ = worker.join();
+        }
+        result
+    }
+
+    fn accept_loop(&self, sender: &SyncSender<Connection>) -> std::io::Result<()> {
+        while !self.shared.is_shutting_down() {
+            let (stream, peer_addr) = match self.listener.accept() {
+                Ok(client) => client,
+                Err(err) if err.kind() == std::io::ErrorKind::WouldBlock => {
+                    std::thread::sleep(POLL_INTERVAL);
+                    continue;
+                }
+                Err(err)
+                    if matches!(
+                        err.kind(),
+                        std::io::ErrorKind::Interrupted
+                            | std::io::ErrorKind::ConnectionAborted
+                            | std::io::ErrorKind::ConnectionReset
+                    ) =>
+                {
+                    continue;
+                }
+                Err(err) => return Err(err),
+            };
+            // Some platforms let accepted sockets inherit the listener's non-blocking mode
+            if let Err(err) = stream.set_nonblocking(false) {
+                debug_msg!("Dropping connection from {}: {}", peer_addr, err);
+                continue;
+            }
+
+            let id = self.shared.next_id.fetch_add(1, Ordering::SeqCst);
+            {
+                let mut connections = self.shared.connections.lock().unwrap();
+                if connections.len() >= self.config.max_connections {
+                    debug_msg!("Connection limit reached, dropping {}", peer_addr);
+                    continue;
+                }
+                match stream.try_clone() {
+                    Ok(clone) => connections.insert(id, clone),
+                    Err(err) => {
+                        debug_msg!("Dropping connection from {}: {}", peer_addr, err);
+                        continue;
+                    }
+                };
+            }
+            if sender.send(Connection { id, stream, peer_addr }).is_err() {
+                self.shared.remove(id);
+            }
+        }
+        Ok(())
+    }
+}
+
+fn worker(
+    receiver: &Mutex<Receiver<Connection>>,
+    handler: &dyn SessionHandler,
+    shared: &Arc<Shared>,
+    config: &ServerConfig,
+) {
+    loop {
+        let connection = match receiver.lock().unwrap().recv() {
+            Ok(connection) => connection,
+            Err(_) => return,
+        };
+        let id = connection.id;
+        // Connections still queued when the shutdown started are not served anymore
+        if !shared.is_shutting_down() {
+            // A panicking handler must neither shrink the pool nor leak its connection slot
+            let served = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                serve(connection, handler, shared, config)
+            }));
+            if served.is_err() {
+                debug_msg!("Session handler panicked");
+            }
+        }
+        shared.remove(id);
+    }
+}
+
+fn serve(
+    connection: Connection,
+    handler: &dyn SessionHandler,
+    shared: &Arc<Shared>,
+    config: &ServerConfig,
+) {
+    let session = match start_session(connection.stream, config) {
+        Ok(session) => session,
+        Err(err) => {
+            debug_msg!("Error accepting session from {}: {}", connection.peer_addr, err);
+            return;
+        }
+    };
+    debug_msg!("New session established with {}.", connection.peer_addr);
+    let context = SessionContext { peer_addr: connection.peer_addr, shared: shared.clone() };
+    handler.handle(session, &context);
+}
+
+#[cfg(feature = "tls")]
+fn start_session(stream: TcpStream, config: &ServerConfig) -> Result<Session, SessionError> {
+    match &config.tls {
+        Some(tls) => Session::with_config_tls(stream, tls, config.session.clone()),
+        None => Session::with_config(stream, config.session.clone()),
+    }
+}
+
+#[cfg(not(feature = "tls"))]
+fn start_session(stream: TcpStream, config: &ServerConfig) -> Result<Session, SessionError> {
+    Session::with_config(stream, config.session.clone())
+}
@@ -1,54 +1,139 @@
 pub mod aid;
+#[cfg(feature = "async")]
+pub mod async_session;
+pub mod buffer;
 pub mod color;
 pub mod extended_field_attributes;
 pub mod highlighting;
+mod listener;
+mod protocol;
+pub mod query;
 pub mod screen;
 pub mod stream;
+pub mod structured_field;
+pub mod terminal;
+#[cfg(feature = "tls")]
+pub mod tls;
+pub mod tn3270e;
 pub mod transparency;
+pub mod transport;
+pub mod view;
 pub mod wcc;
 
-use std::collections::VecDeque;
 use std::io::{Read, Write};
 use std::net::TcpStream;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
-use libtelnet_rs::Parser;
-use libtelnet_rs::events::*;
-use libtelnet_rs::telnet::{op_command as tn_cmd, op_option as tn_opt};
+use snafu::{ResultExt, Snafu};
 
 use crate::debug_msg;
+use crate::encoding::Encoding;
+pub use crate::server::listener::{
+    Server, ServerConfig, SessionContext, SessionHandler, ShutdownHandle,
+};
+use crate::server::protocol::{Protocol, RecordWriter};
+use crate::server::query::{QueryReply, ReadPartitionQuery};
+use crate::server::stream::{OutputRecord, StreamFormatError, StreamOptions};
+use crate::server::terminal::TerminalModel;
+use crate::server::tn3270e::Functions;
+use crate::server::transport::{Stream, Transport, is_timeout};
 
-pub struct Session {
-    parser: Parser,
+#[derive(Clone, Debug)]
+pub struct SessionConfig {
+    // Offer TN3270E before falling back to TTYPE/EOR/BINARY
+    pub tn3270e: bool,
+    pub tn3270e_functions: Functions,
+    // Device name used when the client doesn't CONNECT to a specific one
+    pub device_name: Option<String>,
+    // Upper bound for the whole negotiation, including a STARTTLS handshake
+    pub negotiation_timeout: Duration,
+    // Code page of the terminal until its Character Sets query reply names a known one
+    pub encoding: Encoding,
+    // Offer the telnet START_TLS option and upgrade the connection before negotiating 3270
+    #[cfg(feature = "tls")]
+    pub starttls: Option<tls::TlsConfig>,
+}
 
-    stream: std::net::TcpStream,
+impl Default for SessionConfig {
+    fn default() -> Self {
+        SessionConfig {
+            tn3270e: true,
+            tn3270e_functions: Functions::RESPONSES,
+            device_name: None,
+            negotiation_timeout: Duration::from_secs(5),
+            encoding: Encoding::default(),
+            #[cfg(feature = "tls")]
+            starttls: None,
+        }
+    }
+}
 
-    term_type: Option<Vec<u8>>,
-    is_eor: bool,
-    is_bin: bool,
+#[derive(Debug, Snafu)]
+pub enum SessionError {
+    #[snafu(display("Negotiation timed out"))]
+    NegotiationTimeout,
+    #[snafu(display("Terminal refused the {} option", option))]
+    OptionRefused { option: &'static str },
+    #[snafu(display(
+        "Client is not a 3270 terminal ({})",
+        term_type.as_deref().unwrap_or("no terminal type")
+    ))]
+    NotA3270Terminal { term_type: Option<String> },
+    #[snafu(display("Connection closed by the terminal"))]
+    Disconnected,
+    #[snafu(display("Protocol violation: {}", message))]
+    ProtocolViolation { message: String },
+    // The configuration asks for something this kind of session can't do
+    #[snafu(display("Unsupported configuration: {}", message))]
+    Unsupported { message: String },
+    #[snafu(display("Invalid data stream: {}", source))]
+    Stream { source: StreamFormatError },
+    #[snafu(display("I/O error: {}", source))]
+    Io { source: std::io::Error },
+}
 
-    incoming_records: VecDeque<Vec<u8>>,
-    cur_record: Vec<u8>,
+// While negotiating, a read timing out means the terminal didn't answer in time
+fn negotiation_error(source: std::io::Error) -> SessionError {
+    if is_timeout(&source) { SessionError::NegotiationTimeout } else { SessionError::Io { source } }
 }
 
-type Error = std::io::Error;
-
-impl Session {
-    pub fn new(stream: TcpStream) -> Result<Self, Error> {
-        let mut session = Session {
-            parser: Parser::new(),
-            incoming_records: VecDeque::new(),
-            stream,
-            term_type: None,
-            is_bin: false,
-            is_eor: false,
-            cur_record: Vec::new(),
-        };
+pub struct Session<T: Transport = TcpStream> {
+    protocol: Protocol,
+
+    stream: Stream<T>,
+}
+
+type Error = SessionError;
+
+impl<T: Transport> Session<T> {
+    pub fn new(stream: T) -> Result<Self, Error> {
+        Self::with_config(stream, SessionConfig::default())
+    }
 
-        session.parser.options.support(tn_opt::EOR);
-        session.parser.options.support_remote(tn_opt::TTYPE);
-        session.parser.options.support(tn_opt::TTYPE);
-        session.parser.options.support(tn_opt::BINARY);
+    pub fn with_config(stream: T, config: SessionConfig) -> Result<Self, Error> {
+        Self::start(Stream::Plain(stream), config)
+    }
+
+    // Implicit TLS: the handshake runs before any telnet negotiation, as on port 992
+    #[cfg(feature = "tls")]
+    pub fn new_tls(stream: T, tls: &tls::TlsConfig) -> Result<Self, Error> {
+        Self::with_config_tls(stream, tls, SessionConfig::default())
+    }
+
+    #[cfg(feature = "tls")]
+    pub fn with_config_tls(
+        stream: T,
+        tls: &tls::TlsConfig,
+        config: SessionConfig,
+    ) -> Result<Self, Error> {
+        debug_msg!("TLS handshake...");
+        let deadline = Instant::now() + config.negotiation_timeout;
+        let stream = tls::handshake(tls, stream, &[], deadline).map_err(negotiation_error)?;
+        Self::start(Stream::Tls(Box::new(stream)), config)
+    }
+
+    fn start(stream: Stream<T>, config: SessionConfig) -> Result<Self, Error> {
+        let mut session = Session { protocol: Protocol::new(config), stream };
 
         debug_msg!("Negotiating...");
         session.negotiate()?;
@@ -56,142 +141,166 @@ impl Session {
         Ok(session)
     }
 
-    fn option_state(&self, opt: u8) -> bool {
-        let opt = self.parser.options.get_option(opt);
-        opt.local_state && opt.remote_state
-    }
-
-    fn process_events(&mut self, mut events: Vec<TelnetEvents>) -> Result<(), Error> {
-        let mut extra_events = Vec::new();
-        let mut sendbuf = Vec::new();
-        while !events.is_empty() || !extra_events.is_empty() {
-            events.append(&mut extra_events);
-            extra_events.truncate(0);
-            for mut event in events.drain(..) {
-                match event {
-                    TelnetEvents::DataSend(ref mut data) => sendbuf.extend(data.iter()),
-                    TelnetEvents::DataReceive(ref mut data) => {
-                        self.cur_record.extend_from_slice(&data[..])
-                    }
-                    TelnetEvents::IAC(TelnetIAC { command: tn_cmd::EOR }) => {
-                        self.incoming_records.push_back(std::mem::take(&mut self.cur_record))
-                    }
-                    TelnetEvents::IAC(iac) => debug_msg!("Unknown IAC {}", iac.command),
-                    TelnetEvents::Negotiation(TelnetNegotiation {
-                        command: tn_cmd::WILL,
-                        option: tn_opt::TTYPE,
-                    }) => {
-                        let sub = self.parser.subnegotiation(tn_opt::TTYPE, vec![1]);
-                        if let Some(event) = sub {
-                            debug_msg!("Sending subnegotiation");
-                            extra_events.push(event);
-                        } else {
-                            debug_msg!("Didn't do subnegotiation");
-                        }
-                    }
-                    TelnetEvents::Negotiation(TelnetNegotiation { command, option }) => {
-                        debug_msg!("Negotiate: {}/{}", command, option);
-                        self.is_eor = self.option_state(tn_opt::EOR);
-                        self.is_bin = self.option_state(tn_opt::BINARY);
-                    }
-                    TelnetEvents::Subnegotiation(TelnetSubnegotiation {
-                        option: tn_opt::TTYPE,
-                        buffer,
-                    }) => {
-                        if buffer[0] == 0 {
-                            self.term_type = Some(buffer[1..].to_vec());
-
-                            extra_events.extend(
-                                [
-                                    self.parser._will(tn_opt::EOR),
-                                    self.parser._do(tn_opt::EOR),
-                                    self.parser._will(tn_opt::BINARY),
-                                    self.parser._do(tn_opt::BINARY),
-                                ]
-                                .iter_mut()
-                                .flat_map(Option::take),
-                            );
-                            debug_msg!(
-                                "Terminal type: {}",
-                                String::from_utf8_lossy(self.term_type.as_ref().unwrap())
-                            );
-                        }
-                    }
-                    TelnetEvents::Subnegotiation(_) => {}
-                    TelnetEvents::DecompressImmediate(_) => unimplemented!("We don't support MCCP"),
-                }
-            }
-        }
+    pub fn tn3270e(&self) -> Option<&tn3270e::Device> {
+        self.protocol.tn3270e()
+    }
 
-        debug_msg!("Sending: {:?}", &sendbuf);
-        self.stream.write_all(sendbuf.as_slice())?;
-        Ok(())
+    // Terminal type as announced by the client, e.g. `IBM-3279-2-E`
+    pub fn terminal_type(&self) -> Option<String> {
+        self.protocol.term_type_name()
     }
 
-    fn is_ready(&self) -> bool {
-        self.term_type.is_some() && self.is_bin && self.is_eor
+    pub fn terminal_model(&self) -> &TerminalModel {
+        self.protocol.terminal_model()
     }
 
-    fn negotiate(&mut self) -> Result<bool, std::io::Error> {
-        let mut initial_negotiation = vec![];
-        initial_negotiation.extend(self.parser._do(tn_opt::TTYPE));
-        initial_negotiation.extend(self.parser._will(tn_opt::TTYPE));
+    // Code page of the text in every record, see `SessionConfig::encoding`
+    pub fn encoding(&self) -> Encoding {
+        self.protocol.encoding()
+    }
 
-        self.process_events(initial_negotiation)?;
+    pub fn set_encoding(&mut self, encoding: Encoding) {
+        self.protocol.set_encoding(encoding);
+    }
 
-        let mut idata = vec![0; 2000];
+    // Address mode and code page to write and parse records for this terminal with
+    pub fn stream_options(&self) -> StreamOptions {
+        self.protocol.stream_options()
+    }
+
+    fn negotiate(&mut self) -> Result<(), Error> {
+        let deadline = Instant::now() + self.protocol.config().negotiation_timeout;
+        let initial_negotiation = self.protocol.start_negotiation()?;
+        self.write(&initial_negotiation).map_err(negotiation_error)?;
 
-        self.stream.set_read_timeout(Some(Duration::from_secs(5)))?;
+        let mut idata = vec![0; 2000];
 
-        while !self.is_ready() {
-            let len = self.stream.read(&mut idata[..])?;
+        while !self.protocol.is_ready() {
+            let remaining = deadline
+                .checked_duration_since(Instant::now())
+                .filter(|remaining| !remaining.is_zero())
+                .ok_or(SessionError::NegotiationTimeout)?;
+            self.stream.set_read_timeout(Some(remaining)).map_err(negotiation_error)?;
+            let len = self.stream.read(&mut idata[..]).map_err(negotiation_error)?;
             if len == 0 {
-                return Ok(false);
+                return Err(SessionError::Disconnected);
+            }
+            let reply = self.protocol.receive(&idata[..len])?;
+            self.write(&reply).map_err(negotiation_error)?;
+            if let Some(prefix) = self.protocol.take_tls_upgrade() {
+                self.start_tls(&prefix, deadline)?;
             }
-            let events = self.parser.receive(&idata[..len]);
-            debug_msg!("Received {} events", events.len());
-            self.process_events(events)?;
         }
 
-        self.stream.set_read_timeout(None)?;
-        Ok(true)
+        self.stream.set_read_timeout(None).map_err(negotiation_error)?;
+        Ok(())
+    }
+
+    #[cfg(feature = "tls")]
+    fn start_tls(&mut self, prefix: &[u8], deadline: Instant) -> Result<(), Error> {
+        debug_msg!("STARTTLS handshake...");
+        let tls = self.protocol.config().starttls.clone().ok_or_else(|| {
+            SessionError::ProtocolViolation {
+                message: "STARTTLS requested without a TLS configuration".into(),
+            }
+        })?;
+        self.stream.start_tls(&tls, prefix, deadline).map_err(negotiation_error)?;
+        let negotiation = self.protocol.restart_negotiation()?;
+        self.write(&negotiation).map_err(negotiation_error)
+    }
+
+    #[cfg(not(feature = "tls"))]
+    fn start_tls(&mut self, _prefix: &[u8], _deadline: Instant) -> Result<(), Error> {
+        Err(SessionError::ProtocolViolation {
+            message: "STARTTLS requested without TLS support".into(),
+        })
     }
 
     pub fn send_record(&mut self, record: impl Into<Vec<u8>>) -> std::io::Result<()> {
-        let mut send_data = Parser::escape_iac(record.into()).to_vec();
-        send_data.extend_from_slice(&[
-            libtelnet_rs::telnet::op_command::IAC,
-            libtelnet_rs::telnet::op_command::EOR,
-        ]);
-        self.stream.write_all(send_data.as_slice())
+        let send_data = self.protocol.encode_record(record);
+        self.write(send_data.as_slice())
     }
 
-    pub fn receive_record(
+    // Writes the record straight to the connection and waits for the terminal's answer, if the
+    // record has one. Fails with `SessionError::Disconnected` if the connection closes first.
+    pub fn send<R: OutputRecord + ?Sized>(&mut self, record: &R) -> Result<R::Response, Error> {
+        self.send_within(record, None)?.ok_or(SessionError::Disconnected)
+    }
+
+    // Like `send`, but returns `None` if the answer doesn't arrive in time. Records that arrived
+    // before are dropped, they can't be the answer.
+    fn send_within<R: OutputRecord + ?Sized>(
         &mut self,
+        record: &R,
         timeout: Option<Duration>,
-    ) -> std::io::Result<Option<Vec<u8>>> {
-        if !self.incoming_records.is_empty() {
-            return Ok(self.incoming_records.pop_front());
+    ) -> Result<Option<R::Response>, Error> {
+        let options = self.stream_options();
+        if record.expects_response() {
+            self.protocol.discard_records();
+        }
+        let header = self.protocol.next_header();
+        let mut writer = RecordWriter::new(&mut self.stream, header).context(IoSnafu)?;
+        record.write_to(&mut writer, options).context(IoSnafu)?;
+        writer.finish().context(IoSnafu)?;
+        if !record.expects_response() {
+            return record.parse_response(&[], options).map(Some).context(StreamSnafu);
         }
 
-        self.stream.set_read_timeout(timeout)?;
+        let Some(reply) = self.receive_record(timeout).context(IoSnafu)? else {
+            return Ok(None);
+        };
+        record.parse_response(&reply, options).map(Some).context(StreamSnafu)
+    }
+
+    fn write(&mut self, data: &[u8]) -> std::io::Result<()> {
+        self.stream.write_all(data)?;
+        self.stream.flush()
+    }
+
+    // Sends a Read Partition Query and waits for the Query Replies, which also refine the terminal
+    // model. Returns `None` on timeout; terminals without the extended data stream never answer.
+    pub fn query(
+        &mut self,
+        query: &ReadPartitionQuery,
+        timeout: Option<Duration>,
+    ) -> Result<Option<Vec<QueryReply>>, Error> {
+        let Some(replies) = self.send_within(query, timeout)? else {
+            return Ok(None);
+        };
+        self.protocol.apply_query_replies(&replies);
+        Ok(Some(replies))
+    }
+
+    // Waits until a complete record arrived. Returns `None` on timeout or when the peer closed
+    // the connection.
+    pub fn receive_record(
+        &mut self,
+        timeout: Option<Duration>,
+    ) -> std::io::Result<Option<Vec<u8>>> {
+        let deadline = timeout.map(|timeout| Instant::now() + timeout);
         let mut buf = vec![0; 1024];
-        let mut len = self.stream.read(buf.as_mut_slice())?;
-        if len != 0 {
-            self.stream.set_nonblocking(true)?;
-            while len != 0 {
-                let events = self.parser.receive(&buf[..len]);
-                self.process_events(events)?;
-                len = match self.stream.read(buf.as_mut_slice()) {
-                    Ok(len) => len,
-                    Err(err) if err.kind() == std::io::ErrorKind::WouldBlock => 0,
-                    Err(err) => return Err(err),
-                };
-            }
-            self.stream.set_nonblocking(false)?;
+        while !self.protocol.has_record() {
+            let remaining = match deadline {
+                Some(deadline) => match deadline.checked_duration_since(Instant::now()) {
+                    Some(remaining) if !remaining.is_zero() => Some(remaining),
+                    _ => return Ok(None),
+                },
+                None => None,
+            };
+            self.stream.set_read_timeout(remaining)?;
+            let len = match self.stream.read(buf.as_mut_slice()) {
+                Ok(0) => return Ok(None),
+                Ok(len) => len,
+                Err(err) if is_timeout(&err) => return Ok(None),
+                Err(err) => return Err(err),
+            };
+            let reply = self
+                .protocol
+                .receive(&buf[..len])
+                .map_err(|err| std::io::Error::new(std::io::ErrorKind::InvalidData, err))?;
+            self.write(&reply)?;
         }
 
-        self.stream.set_read_timeout(None)?;
-        Ok(self.incoming_records.pop_front())
+        Ok(self.protocol.next_record())
     }
 }
@@ -0,0 +1,557 @@
+use std::collections::VecDeque;
+use std::io::Write;
+
+use libtelnet_rs::Parser;
+use libtelnet_rs::compatibility::CompatibilityEntry;
+use libtelnet_rs::events::*;
+use libtelnet_rs::telnet::{op_command as tn_cmd, op_option as tn_opt};
+
+use crate::debug_msg;
+use crate::encoding::Encoding;
+use crate::server::query::QueryReply;
+use crate::server::stream::StreamOptions;
+use crate::server::terminal::TerminalModel;
+use crate::server::tn3270e::{self, DeviceName, Functions, Header, Message, RejectReason, TN3270E};
+use crate::server::{SessionConfig, SessionError};
+
+// Telnet START_TLS option (draft-altman-telnet-starttls)
+const START_TLS: u8 = 46;
+const FOLLOWS: u8 = 1;
+const FOLLOWS_SEQ: [u8; 6] = [tn_cmd::IAC, tn_cmd::SB, START_TLS, FOLLOWS, tn_cmd::IAC, tn_cmd::SE];
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+enum StartTlsState {
+    Disabled,
+    Offered,
+    // We sent FOLLOWS and wait for the client's FOLLOWS, after which it starts the handshake
+    Follows,
+    Upgrade,
+}
+
+enum Tn3270eState {
+    Disabled,
+    Negotiating,
+    DeviceAccepted(tn3270e::Device),
+    Active(tn3270e::Device),
+}
+
+// Telnet and TN3270E state shared by the blocking and async sessions. It never touches the
+// transport: incoming bytes are fed in and the bytes to send back are returned.
+pub(crate) struct Protocol {
+    parser: Parser,
+
+    config: SessionConfig,
+
+    term_type: Option<Vec<u8>>,
+    terminal: TerminalModel,
+    encoding: Encoding,
+    is_eor: bool,
+    is_bin: bool,
+
+    tn3270e: Tn3270eState,
+    seq_number: u16,
+
+    starttls: StartTlsState,
+    // Tail of the previous read, in case FOLLOWS was split across reads
+    starttls_scan: Vec<u8>,
+    tls_prefix: Vec<u8>,
+
+    incoming_records: VecDeque<Vec<u8>>,
+    cur_record: Vec<u8>,
+}
+
+impl Protocol {
+    pub fn new(config: SessionConfig) -> Self {
+        let mut protocol = Protocol {
+            parser: Parser::new(),
+            incoming_records: VecDeque::new(),
+            encoding: config.encoding,
+            config,
+            term_type: None,
+            terminal: TerminalModel::default(),
+            is_bin: false,
+            is_eor: false,
+            tn3270e: Tn3270eState::Disabled,
+            seq_number: 0,
+            starttls: StartTlsState::Disabled,
+            starttls_scan: Vec::new(),
+            tls_prefix: Vec::new(),
+            cur_record: Vec::new(),
+        };
+        protocol.configure_parser();
+        protocol
+    }
+
+    fn configure_parser(&mut self) {
+        self.parser = Parser::new();
+        self.parser.options.support(tn_opt::EOR);
+        self.parser.options.support_remote(tn_opt::TTYPE);
+        self.parser.options.support(tn_opt::TTYPE);
+        self.parser.options.support(tn_opt::BINARY);
+        // The parser only reports subnegotiations for options that are enabled locally, but
+        // the server never sends WILL for these, so mark them enabled up front.
+        if self.config.tn3270e {
+            self.parser
+                .options
+                .set_option(TN3270E, CompatibilityEntry::new(true, true, true, false));
+        }
+        if self.starttls_configured() {
+            self.parser
+                .options
+                .set_option(START_TLS, CompatibilityEntry::new(true, true, true, false));
+        }
+    }
+
+    pub fn config(&self) -> &SessionConfig {
+        &self.config
+    }
+
+    #[cfg(feature = "tls")]
+    fn starttls_configured(&self) -> bool {
+        self.config.starttls.is_some()
+    }
+
+    #[cfg(not(feature = "tls"))]
+    fn starttls_configured(&self) -> bool {
+        false
+    }
+
+    pub fn start_negotiation(&mut self) -> Result<Vec<u8>, SessionError> {
+        let initial_negotiation = if self.starttls_configured() {
+            self.starttls = StartTlsState::Offered;
+            self.parser._do(START_TLS).into_iter().collect()
+        } else {
+            self.start_3270_negotiation()
+        };
+
+        self.process_events(initial_negotiation)
+    }
+
+    fn start_3270_negotiation(&mut self) -> Vec<TelnetEvents> {
+        if self.config.tn3270e {
+            self.tn3270e = Tn3270eState::Negotiating;
+            self.parser._do(TN3270E).into_iter().collect()
+        } else {
+            self.start_tn3270_negotiation()
+        }
+    }
+
+    // Returns the bytes that followed the client's FOLLOWS once the TLS handshake is due. They
+    // belong to the handshake and must not be seen by the telnet parser.
+    pub fn take_tls_upgrade(&mut self) -> Option<Vec<u8>> {
+        if self.starttls != StartTlsState::Upgrade {
+            return None;
+        }
+        self.starttls = StartTlsState::Disabled;
+        Some(std::mem::take(&mut self.tls_prefix))
+    }
+
+    // Telnet state starts over once TLS is up, then 3270 is negotiated over the encrypted channel
+    #[cfg(feature = "tls")]
+    pub fn restart_negotiation(&mut self) -> Result<Vec<u8>, SessionError> {
+        self.configure_parser();
+        let events = self.start_3270_negotiation();
+        self.process_events(events)
+    }
+
+    pub fn receive(&mut self, mut data: &[u8]) -> Result<Vec<u8>, SessionError> {
+        if self.starttls == StartTlsState::Follows {
+            let tail = self.starttls_scan.len();
+            self.starttls_scan.extend_from_slice(data);
+            if let Some(pos) =
+                self.starttls_scan.windows(FOLLOWS_SEQ.len()).position(|w| w == FOLLOWS_SEQ)
+            {
+                let end = pos + FOLLOWS_SEQ.len() - tail;
+                self.tls_prefix = data[end..].to_vec();
+                data = &data[..end];
+            } else {
+                let keep = self.starttls_scan.len().saturating_sub(FOLLOWS_SEQ.len() - 1);
+                self.starttls_scan.drain(..keep);
+            }
+        }
+        let events = self.parser.receive(data);
+        debug_msg!("Received {} events", events.len());
+        self.process_events(events)
+    }
+
+    fn option_state(&self, opt: u8) -> bool {
+        let opt = self.parser.options.get_option(opt);
+        opt.local_state && opt.remote_state
+    }
+
+    fn process_events(&mut self, mut events: Vec<TelnetEvents>) -> Result<Vec<u8>, SessionError> {
+        let mut extra_events = Vec::new();
+        let mut sendbuf = Vec::new();
+        while !events.is_empty() || !extra_events.is_empty() {
+            events.append(&mut extra_events);
+            extra_events.clear();
+            for mut event in events.drain(..) {
+                match event {
+                    TelnetEvents::DataSend(ref mut data) => sendbuf.extend(data.iter()),
+                    TelnetEvents::DataReceive(ref mut data) => {
+                        // Plain telnet clients start typing before any 3270 option was agreed
+                        if !self.is_ready() && !data.is_empty() {
+                            return Err(SessionError::NotA3270Terminal {
+                                term_type: self.term_type_name(),
+                            });
+                        }
+                        self.cur_record.extend_from_slice(&data[..])
+                    }
+                    TelnetEvents::IAC(TelnetIAC { command: tn_cmd::EOR }) => {
+                        let record = std::mem::take(&mut self.cur_record);
+                        self.push_record(record);
+                    }
+                    TelnetEvents::IAC(iac) => debug_msg!("Unknown IAC {}", iac.command),
+                    TelnetEvents::Negotiation(TelnetNegotiation {
+                        command: tn_cmd::WILL,
+                        option: tn_opt::TTYPE,
+                    }) => {
+                        let sub = self.parser.subnegotiation(tn_opt::TTYPE, vec![1]);
+                        if let Some(event) = sub {
+                            debug_msg!("Sending subnegotiation");
+                            extra_events.push(event);
+                        } else {
+                            debug_msg!("Didn't do subnegotiation");
+                        }
+                    }
+                    TelnetEvents::Negotiation(TelnetNegotiation { command, option: START_TLS }) => {
+                        debug_msg!("Negotiate START_TLS: {}", command);
+                        match (command, self.starttls) {
+                            (tn_cmd::WILL, StartTlsState::Offered) => {
+                                self.starttls = StartTlsState::Follows;
+                                extra_events
+                                    .extend(self.parser.subnegotiation(START_TLS, vec![FOLLOWS]))
+                            }
+                            (tn_cmd::WONT, StartTlsState::Offered) => {
+                                debug_msg!("START_TLS refused, continuing in plain text");
+                                self.starttls = StartTlsState::Disabled;
+                                extra_events.extend(self.start_3270_negotiation());
+                            }
+                            _ => {}
+                        }
+                    }
+                    TelnetEvents::Negotiation(TelnetNegotiation { command, option: TN3270E }) => {
+                        debug_msg!("Negotiate TN3270E: {}", command);
+                        match (command, &self.tn3270e) {
+                            (tn_cmd::WILL, Tn3270eState::Negotiating) => extra_events.extend(
+                                self.parser
+                                    .subnegotiation(TN3270E, Message::SendDeviceType.encode()),
+                            ),
+                            (tn_cmd::WONT, Tn3270eState::Negotiating)
+                            | (tn_cmd::WONT, Tn3270eState::DeviceAccepted(_)) => {
+                                debug_msg!("TN3270E refused, falling back to TN3270");
+                                self.tn3270e = Tn3270eState::Disabled;
+                                extra_events.extend(self.start_tn3270_negotiation());
+                            }
+                            _ => {}
+                        }
+                    }
+                    TelnetEvents::Negotiation(TelnetNegotiation { command, option }) => {
+                        debug_msg!("Negotiate: {}/{}", command, option);
+                        if matches!(command, tn_cmd::WONT | tn_cmd::DONT) && !self.is_ready() {
+                            match option {
+                                // The client may well refuse to send us our own terminal type
+                                tn_opt::TTYPE if command == tn_cmd::WONT => {
+                                    return Err(SessionError::NotA3270Terminal { term_type: None });
+                                }
+                                tn_opt::EOR => {
+                                    return Err(SessionError::OptionRefused { option: "EOR" });
+                                }
+                                tn_opt::BINARY => {
+                                    return Err(SessionError::OptionRefused { option: "BINARY" });
+                                }
+                                _ => {}
+                            }
+                        }
+                        self.is_eor = self.option_state(tn_opt::EOR);
+                        self.is_bin = self.option_state(tn_opt::BINARY);
+                    }
+                    TelnetEvents::Subnegotiation(TelnetSubnegotiation {
+                        option: tn_opt::TTYPE,
+                        buffer,
+                    }) => {
+                        if buffer.first() == Some(&0) {
+                            self.set_term_type(buffer[1..].to_vec());
+                            if !buffer[1..].starts_with(b"IBM-") {
+                                return Err(SessionError::NotA3270Terminal {
+                                    term_type: self.term_type_name(),
+                                });
+                            }
+
+                            extra_events.extend(
+                                [
+                                    self.parser._will(tn_opt::EOR),
+                                    self.parser._do(tn_opt::EOR),
+                                    self.parser._will(tn_opt::BINARY),
+                                    self.parser._do(tn_opt::BINARY),
+                                ]
+                                .iter_mut()
+                                .flat_map(Option::take),
+                            );
+                            debug_msg!(
+                                "Terminal type: {}",
+                                String::from_utf8_lossy(self.term_type.as_ref().unwrap())
+                            );
+                        }
+                    }
+                    TelnetEvents::Subnegotiation(TelnetSubnegotiation {
+                        option: TN3270E,
+                        buffer,
+                    }) => match Message::parse(&buffer[..]) {
+                        Ok(message) => {
+                            if let Some(reply) = self.process_tn3270e(message) {
+                                extra_events
+                                    .extend(self.parser.subnegotiation(TN3270E, reply.encode()));
+                            }
+                        }
+                        Err(err) => {
+                            return Err(SessionError::ProtocolViolation {
+                                message: format!("Invalid TN3270E subnegotiation: {}", err),
+                            });
+                        }
+                    },
+                    TelnetEvents::Subnegotiation(TelnetSubnegotiation {
+                        option: START_TLS,
+                        buffer,
+                    }) => {
+                        if self.starttls == StartTlsState::Follows && buffer[..] == [FOLLOWS] {
+                            self.starttls = StartTlsState::Upgrade;
+                            self.starttls_scan.clear();
+                        }
+                    }
+                    TelnetEvents::Subnegotiation(_) => {}
+                    TelnetEvents::DecompressImmediate(_) => {
+                        return Err(SessionError::ProtocolViolation {
+                            message: "MCCP compression was never negotiated".into(),
+                        });
+                    }
+                }
+            }
+        }
+
+        debug_msg!("Sending: {:?}", &sendbuf);
+        Ok(sendbuf)
+    }
+
+    fn process_tn3270e(&mut self, message: Message) -> Option<Message> {
+        debug_msg!("TN3270E: {:?}", message);
+        match message {
+            Message::DeviceTypeRequest { device_type, name } => {
+                if !tn3270e::is_supported_device_type(&device_type) {
+                    return Some(Message::DeviceTypeReject(RejectReason::InvalidDeviceType));
+                }
+                let (device_name, associate) = match name {
+                    Some(DeviceName::Connect(name)) => (name, None),
+                    Some(DeviceName::Associate(name)) => (self.default_device_name(), Some(name)),
+                    None => (self.default_device_name(), None),
+                };
+                self.set_term_type(device_type.as_bytes().to_vec());
+                let reply = Message::DeviceTypeIs {
+                    device_type: device_type.clone(),
+                    device_name: device_name.clone(),
+                };
+                self.tn3270e = Tn3270eState::DeviceAccepted(tn3270e::Device {
+                    device_type,
+                    device_name,
+                    associate,
+                    functions: Functions::empty(),
+                });
+                Some(reply)
+            }
+            Message::FunctionsRequest(requested) => {
+                let Tn3270eState::DeviceAccepted(device) = &mut self.tn3270e else {
+                    return None;
+                };
+                let agreed = requested & self.config.tn3270e_functions;
+                if agreed != requested {
+                    // Counter-propose the subset we support, the client answers with IS
+                    return Some(Message::FunctionsRequest(agreed));
+                }
+                device.functions = agreed;
+                self.activate_tn3270e();
+                Some(Message::FunctionsIs(agreed))
+            }
+            Message::FunctionsIs(functions) => {
+                let Tn3270eState::DeviceAccepted(device) = &mut self.tn3270e else {
+                    return None;
+                };
+                device.functions = functions & self.config.tn3270e_functions;
+                self.activate_tn3270e();
+                None
+            }
+            _ => None,
+        }
+    }
+
+    fn activate_tn3270e(&mut self) {
+        if let Tn3270eState::DeviceAccepted(device) =
+            std::mem::replace(&mut self.tn3270e, Tn3270eState::Disabled)
+        {
+            debug_msg!("TN3270E bound to {} as {}", device.device_type, device.device_name);
+            self.tn3270e = Tn3270eState::Active(device);
+        }
+    }
+
+    fn default_device_name(&self) -> String {
+        self.config.device_name.clone().unwrap_or_else(tn3270e::generate_device_name)
+    }
+
+    fn push_record(&mut self, record: Vec<u8>) {
+        if !matches!(self.tn3270e, Tn3270eState::Active(_)) {
+            self.incoming_records.push_back(record);
+            return;
+        }
+        match Header::parse(&record) {
+            Ok((Header { data_type: tn3270e::DataType::Data3270, .. }, body)) => {
+                self.incoming_records.push_back(body.to_vec())
+            }
+            Ok((header, _)) => debug_msg!("Ignoring TN3270E record {:?}", header),
+            Err(err) => debug_msg!("Invalid TN3270E header: {}", err),
+        }
+    }
+
+    fn set_term_type(&mut self, term_type: Vec<u8>) {
+        // Types we don't know are served like the smallest common display
+        self.terminal =
+            TerminalModel::parse(&String::from_utf8_lossy(&term_type)).unwrap_or_default();
+        self.term_type = Some(term_type);
+    }
+
+    pub fn term_type_name(&self) -> Option<String> {
+        self.term_type.as_ref().map(|term_type| String::from_utf8_lossy(term_type).into_owned())
+    }
+
+    fn start_tn3270_negotiation(&mut self) -> Vec<TelnetEvents> {
+        let mut events = vec![];
+        events.extend(self.parser._do(tn_opt::TTYPE));
+        events.extend(self.parser._will(tn_opt::TTYPE));
+        events
+    }
+
+    pub fn is_ready(&self) -> bool {
+        matches!(self.tn3270e, Tn3270eState::Active(_))
+            || (self.term_type.is_some() && self.is_bin && self.is_eor)
+    }
+
+    pub fn terminal_model(&self) -> &TerminalModel {
+        &self.terminal
+    }
+
+    pub fn encoding(&self) -> Encoding {
+        self.encoding
+    }
+
+    pub fn set_encoding(&mut self, encoding: Encoding) {
+        self.encoding = encoding;
+    }
+
+    pub fn stream_options(&self) -> StreamOptions {
+        StreamOptions { mode: self.terminal.address_mode(), encoding: self.encoding }
+    }
+
+    pub fn apply_query_replies(&mut self, replies: &[QueryReply]) {
+        self.terminal.apply_query_replies(replies);
+        // The terminal knows its code page better than the configuration
+        let encoding = replies.iter().find_map(|reply| match reply {
+            QueryReply::CharacterSets(sets) => sets.encoding(),
+            _ => None,
+        });
+        if let Some(encoding) = encoding {
+            self.encoding = encoding;
+        }
+    }
+
+    pub fn tn3270e(&self) -> Option<&tn3270e::Device> {
+        match &self.tn3270e {
+            Tn3270eState::Active(device) => Some(device),
+            _ => None,
+        }
+    }
+
+    pub fn encode_record(&mut self, record: impl Into<Vec<u8>>) -> Vec<u8> {
+        let mut record = record.into();
+        if let Some(header) = self.next_header() {
+            record.splice(0..0, header);
+        }
+        let mut send_data = Parser::escape_iac(record).to_vec();
+        send_data.extend_from_slice(&[tn_cmd::IAC, tn_cmd::EOR]);
+        send_data
+    }
+
+    // The TN3270E header for the next outbound record, if TN3270E is active
+    pub fn next_header(&mut self) -> Option<[u8; Header::LEN]> {
+        self.tn3270e()?;
+        let header = Header::data(self.seq_number).encode();
+        self.seq_number = (self.seq_number + 1) & 0x7FFF;
+        Some(header)
+    }
+
+    pub fn next_record(&mut self) -> Option<Vec<u8>> {
+        self.incoming_records.pop_front()
+    }
+
+    pub fn has_record(&self) -> bool {
+        !self.incoming_records.is_empty()
+    }
+
+    // Drops the records nobody asked for, so they aren't taken for the answer to the next command
+    pub fn discard_records(&mut self) {
+        for record in self.incoming_records.drain(..) {
+            debug_msg!("Discarding a record of {} bytes that arrived unasked", record.len());
+        }
+    }
+}
+
+// Writes a record to the stream as it is produced, escaping IAC and ending it with IAC EOR. The
+// data goes through a fixed buffer, so records of any size are sent without allocating.
+pub(crate) struct RecordWriter<'a, W: Write> {
+    stream: &'a mut W,
+    buf: [u8; 1024],
+    len: usize,
+}
+
+impl<'a, W: Write> RecordWriter<'a, W> {
+    pub fn new(stream: &'a mut W, header: Option<[u8; Header::LEN]>) -> std::io::Result<Self> {
+        let mut writer = RecordWriter { stream, buf: [0; 1024], len: 0 };
+        if let Some(header) = header {
+            writer.write_all(&header)?;
+        }
+        Ok(writer)
+    }
+
+    pub fn finish(mut self) -> std::io::Result<()> {
+        self.reserve(2)?;
+        self.buf[self.len..self.len + 2].copy_from_slice(&[tn_cmd::IAC, tn_cmd::EOR]);
+        self.len += 2;
+        self.flush()
+    }
+
+    fn reserve(&mut self, len: usize) -> std::io::Result<()> {
+        if self.len + len > self.buf.len() {
+            self.stream.write_all(&self.buf[..self.len])?;
+            self.len = 0;
+        }
+        Ok(())
+    }
+}
+
+impl<W: Write> Write for RecordWriter<'_, W> {
+    fn write(&mut self, data: &[u8]) -> std::io::Result<usize> {
+        for &v in data {
+            self.reserve(2)?;
+            self.buf[self.len] = v;
+            self.len += 1;
+            if v == tn_cmd::IAC {
+                self.buf[self.len] = tn_cmd::IAC;
+                self.len += 1;
+            }
+        }
+        Ok(data.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.stream.write_all(&self.buf[..self.len])?;
+        self.len = 0;
+        self.stream.flush()
+    }
+}
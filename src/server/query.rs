@@ -0,0 +1,535 @@
+use std::io::Write;
+
+use crate::encoding::Encoding;
+use crate::server::aid::AID;
+use crate::server::stream::{IncomingRecord, OutputRecord, StreamFormatError, StreamOptions};
+use crate::server::structured_field::{
+    ALL_PARTITIONS, QueryListRequest, ReadPartitionType, StructuredField, WriteStructuredField,
+};
+use crate::server::terminal::ScreenSize;
+
+// Structured field ID of an inbound Query Reply
+const QUERY_REPLY: u8 = 0x81;
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum QueryCode {
+    Summary,
+    UsableArea,
+    ImageResolution,
+    CharacterSets,
+    Color,
+    Highlight,
+    ReplyModes,
+    FieldValidation,
+    FieldOutlining,
+    PartitionCharacteristics,
+    DbcsAsia,
+    AuxiliaryDevice,
+    RpqNames,
+    ImplicitPartition,
+    Transparency,
+    Null,
+    Other(u8),
+}
+
+impl From<QueryCode> for u8 {
+    fn from(code: QueryCode) -> u8 {
+        match code {
+            QueryCode::Summary => 0x80,
+            QueryCode::UsableArea => 0x81,
+            QueryCode::ImageResolution => 0x82,
+            QueryCode::CharacterSets => 0x85,
+            QueryCode::Color => 0x86,
+            QueryCode::Highlight => 0x87,
+            QueryCode::ReplyModes => 0x88,
+            QueryCode::FieldValidation => 0x8A,
+            QueryCode::FieldOutlining => 0x8C,
+            QueryCode::PartitionCharacteristics => 0x8E,
+            QueryCode::DbcsAsia => 0x91,
+            QueryCode::AuxiliaryDevice => 0x99,
+            QueryCode::RpqNames => 0xA1,
+            QueryCode::ImplicitPartition => 0xA6,
+            QueryCode::Transparency => 0xA8,
+            QueryCode::Null => 0xFF,
+            QueryCode::Other(code) => code,
+        }
+    }
+}
+
+impl From<u8> for QueryCode {
+    fn from(code: u8) -> QueryCode {
+        match code {
+            0x80 => QueryCode::Summary,
+            0x81 => QueryCode::UsableArea,
+            0x82 => QueryCode::ImageResolution,
+            0x85 => QueryCode::CharacterSets,
+            0x86 => QueryCode::Color,
+            0x87 => QueryCode::Highlight,
+            0x88 => QueryCode::ReplyModes,
+            0x8A => QueryCode::FieldValidation,
+            0x8C => QueryCode::FieldOutlining,
+            0x8E => QueryCode::PartitionCharacteristics,
+            0x91 => QueryCode::DbcsAsia,
+            0x99 => QueryCode::AuxiliaryDevice,
+            0xA1 => QueryCode::RpqNames,
+            0xA6 => QueryCode::ImplicitPartition,
+            0xA8 => QueryCode::Transparency,
+            0xFF => QueryCode::Null,
+            code => QueryCode::Other(code),
+        }
+    }
+}
+
+// A Write Structured Field carrying a single Read Partition for the whole device. The terminal
+// answers with an `AID::StructuredField` record containing the Query Replies.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ReadPartitionQuery {
+    // Everything the terminal supports
+    Query,
+    // Only the listed replies
+    List(Vec<QueryCode>),
+    // The listed replies plus those the terminal considers equivalent
+    EquivalentAndList(Vec<QueryCode>),
+    // Everything, using the Query List format
+    All,
+}
+
+impl From<&ReadPartitionQuery> for StructuredField {
+    fn from(query: &ReadPartitionQuery) -> Self {
+        let kind = match query {
+            ReadPartitionQuery::Query => ReadPartitionType::Query,
+            ReadPartitionQuery::List(codes) => ReadPartitionType::QueryList {
+                request: QueryListRequest::List,
+                codes: codes.clone(),
+            },
+            ReadPartitionQuery::EquivalentAndList(codes) => ReadPartitionType::QueryList {
+                request: QueryListRequest::EquivalentAndList,
+                codes: codes.clone(),
+            },
+            ReadPartitionQuery::All => {
+                ReadPartitionType::QueryList { request: QueryListRequest::All, codes: vec![] }
+            }
+        };
+        StructuredField::ReadPartition { partition: ALL_PARTITIONS, kind }
+    }
+}
+
+impl ReadPartitionQuery {
+    pub fn serialize(&self, output: &mut Vec<u8>) {
+        WriteStructuredField { fields: vec![self.into()] }.serialize(output);
+    }
+}
+
+// Terminals without the extended data stream never answer, see `Session::query`
+impl OutputRecord for ReadPartitionQuery {
+    type Response = Vec<QueryReply>;
+
+    fn write_to(&self, writer: &mut dyn Write, options: StreamOptions) -> std::io::Result<()> {
+        WriteStructuredField { fields: vec![self.into()] }.write_with(writer, options)
+    }
+
+    fn parse_response(
+        &self,
+        record: &[u8],
+        options: StreamOptions,
+    ) -> Result<Vec<QueryReply>, StreamFormatError> {
+        let record = IncomingRecord::parse_record_with(record, options)?;
+        match record.aid {
+            AID::StructuredField => Ok(record.query_replies),
+            aid => Err(StreamFormatError::NotQueryReplies { aid }),
+        }
+    }
+}
+
+impl From<&ReadPartitionQuery> for Vec<u8> {
+    fn from(val: &ReadPartitionQuery) -> Self {
+        let mut result = vec![];
+        val.serialize(&mut result);
+        result
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UsableArea {
+    pub flags1: u8,
+    pub flags2: u8,
+    pub width: u16,
+    pub height: u16,
+    // 0 for inches, 1 for millimetres
+    pub units: u8,
+    // Distance between points as numerator/denominator pairs
+    pub x_resolution: u32,
+    pub y_resolution: u32,
+    pub cell_width: u8,
+    pub cell_height: u8,
+    pub buffer_size: u16,
+}
+
+impl UsableArea {
+    pub fn screen_size(&self) -> ScreenSize {
+        ScreenSize { rows: self.height, cols: self.width }
+    }
+
+    pub fn supports_14bit_addressing(&self) -> bool {
+        matches!(self.flags1 & 0x0F, 0x01 | 0x03)
+    }
+
+    pub fn supports_16bit_addressing(&self) -> bool {
+        self.flags1 & 0x0F == 0x03
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CharacterSet {
+    // Character set ID as used in the Character Set attribute
+    pub set: u8,
+    pub flags: u8,
+    // Local character set ID
+    pub lcid: u8,
+    // Cell size, only reported by terminals with several cell sizes
+    pub cell_size: Option<(u8, u8)>,
+    // Range of the subsection ID, reported for DBCS sets
+    pub subsection: Option<(u8, u8)>,
+    // Coded graphic character set global ID: character set in the high, code page in the low half
+    pub cgcsgid: Option<u32>,
+}
+
+impl CharacterSet {
+    pub fn code_page(&self) -> Option<u16> {
+        self.cgcsgid.map(|cgcsgid| cgcsgid as u16)
+    }
+
+    // Only known for the supported code pages, a DBCS set alone is none. See
+    // `CharacterSets::encoding` for the mixed ones.
+    pub fn encoding(&self) -> Option<Encoding> {
+        Encoding::from_ccsid(self.code_page()?)
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CharacterSets {
+    pub flags1: u8,
+    pub flags2: u8,
+    pub default_cell_width: u8,
+    pub default_cell_height: u8,
+    pub load_formats: u32,
+    pub sets: Vec<CharacterSet>,
+}
+
+impl CharacterSets {
+    // GF flag: every descriptor ends with a CGCSGID
+    const CGCSGID: u8 = 0x02;
+
+    // The code page of the base set, which is a mixed one together with a DBCS set
+    pub fn encoding(&self) -> Option<Encoding> {
+        let base = self.sets.iter().find(|set| set.set == 0x00)?.code_page()?;
+        let mut dbcs =
+            self.sets.iter().filter(|set| set.set != 0x00).filter_map(CharacterSet::code_page);
+        dbcs.find_map(|dbcs| Encoding::from_code_pages(base, Some(dbcs)))
+            .or_else(|| Encoding::from_code_pages(base, None))
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DbcsAsia {
+    pub flags: u8,
+    // Character set the terminal uses between SO and SI, if it supports them
+    pub sosi_set: Option<u8>,
+    pub input_control: Option<u8>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum QueryReply {
+    Summary(Vec<QueryCode>),
+    UsableArea(UsableArea),
+    ImplicitPartition { default: ScreenSize, alternate: ScreenSize },
+    // Pairs of color attribute value and the color shown for it
+    Color { flags: u8, pairs: Vec<(u8, u8)> },
+    // Pairs of highlighting attribute value and the highlighting shown for it
+    Highlight(Vec<(u8, u8)>),
+    CharacterSets(CharacterSets),
+    // 0 field, 1 extended field, 2 character mode
+    ReplyModes(Vec<u8>),
+    DbcsAsia(DbcsAsia),
+    Null,
+    Other { code: QueryCode, data: Vec<u8> },
+}
+
+fn take<'a>(data: &mut &'a [u8], len: usize) -> Result<&'a [u8], StreamFormatError> {
+    if data.len() < len {
+        return Err(StreamFormatError::UnexpectedEOR);
+    }
+    let (head, rest) = data.split_at(len);
+    *data = rest;
+    Ok(head)
+}
+
+fn u16_at(data: &[u8], pos: usize) -> u16 {
+    u16::from_be_bytes([data[pos], data[pos + 1]])
+}
+
+fn u32_at(data: &[u8], pos: usize) -> u32 {
+    u32::from_be_bytes([data[pos], data[pos + 1], data[pos + 2], data[pos + 3]])
+}
+
+fn pairs(data: &[u8]) -> Result<Vec<(u8, u8)>, StreamFormatError> {
+    let (&count, data) = data.split_first().ok_or(StreamFormatError::UnexpectedEOR)?;
+    let data = data.get(..count as usize * 2).ok_or(StreamFormatError::UnexpectedEOR)?;
+    Ok(data.chunks(2).map(|pair| (pair[0], pair[1])).collect())
+}
+
+// Self-defining parameters: length (including itself), ID and data
+fn self_defining_parameters(
+    mut data: &[u8],
+) -> impl Iterator<Item = Result<(u8, &[u8]), StreamFormatError>> {
+    std::iter::from_fn(move || {
+        if data.is_empty() {
+            return None;
+        }
+        let len = data[0] as usize;
+        if len < 2 || len > data.len() {
+            data = &[];
+            return Some(Err(StreamFormatError::InvalidData));
+        }
+        let (param, rest) = data.split_at(len);
+        data = rest;
+        Some(Ok((param[1], &param[2..])))
+    })
+}
+
+impl QueryReply {
+    // Parses the body of a Query Reply structured field, starting with the query code
+    pub fn parse(field: &[u8]) -> Result<Self, StreamFormatError> {
+        let (&code, data) = field.split_first().ok_or(StreamFormatError::UnexpectedEOR)?;
+        let code = QueryCode::from(code);
+        Ok(match code {
+            QueryCode::Summary => QueryReply::Summary(data.iter().map(|&c| c.into()).collect()),
+            QueryCode::UsableArea => {
+                if data.len() < 19 {
+                    return Err(StreamFormatError::UnexpectedEOR);
+                }
+                QueryReply::UsableArea(UsableArea {
+                    flags1: data[0],
+                    flags2: data[1],
+                    width: u16_at(data, 2),
+                    height: u16_at(data, 4),
+                    units: data[6],
+                    x_resolution: u32_at(data, 7),
+                    y_resolution: u32_at(data, 11),
+                    cell_width: data[15],
+                    cell_height: data[16],
+                    buffer_size: u16_at(data, 17),
+                })
+            }
+            QueryCode::ImplicitPartition => {
+                let params = data.get(2..).ok_or(StreamFormatError::UnexpectedEOR)?;
+                let mut sizes = None;
+                for param in self_defining_parameters(params) {
+                    let (id, param) = param?;
+                    if id == 0x01 {
+                        if param.len() < 9 {
+                            return Err(StreamFormatError::UnexpectedEOR);
+                        }
+                        sizes = Some((
+                            ScreenSize { cols: u16_at(param, 1), rows: u16_at(param, 3) },
+                            ScreenSize { cols: u16_at(param, 5), rows: u16_at(param, 7) },
+                        ));
+                    }
+                }
+                let (default, alternate) = sizes.ok_or(StreamFormatError::InvalidData)?;
+                QueryReply::ImplicitPartition { default, alternate }
+            }
+            QueryCode::Color => {
+                let (&flags, data) = data.split_first().ok_or(StreamFormatError::UnexpectedEOR)?;
+                QueryReply::Color { flags, pairs: pairs(data)? }
+            }
+            QueryCode::Highlight => QueryReply::Highlight(pairs(data)?),
+            QueryCode::CharacterSets => QueryReply::CharacterSets(parse_character_sets(data)?),
+            QueryCode::ReplyModes => QueryReply::ReplyModes(data.to_vec()),
+            QueryCode::DbcsAsia => {
+                let (&flags, params) =
+                    data.split_first().ok_or(StreamFormatError::UnexpectedEOR)?;
+                let mut reply = DbcsAsia { flags, sosi_set: None, input_control: None };
+                for param in self_defining_parameters(params) {
+                    match param? {
+                        (0x01, [set, ..]) => reply.sosi_set = Some(*set),
+                        (0x02, [control, ..]) => reply.input_control = Some(*control),
+                        _ => {}
+                    }
+                }
+                QueryReply::DbcsAsia(reply)
+            }
+            QueryCode::Null => QueryReply::Null,
+            code => QueryReply::Other { code, data: data.to_vec() },
+        })
+    }
+
+    // Appends the reply as a Query Reply structured field
+    pub fn serialize(&self, output: &mut Vec<u8>) {
+        let start = output.len();
+        output.extend_from_slice(&[0, 0, QUERY_REPLY, self.code().into()]);
+        match self {
+            QueryReply::Summary(codes) => output.extend(codes.iter().map(|&code| u8::from(code))),
+            QueryReply::UsableArea(area) => {
+                output.extend_from_slice(&[area.flags1, area.flags2]);
+                output.extend_from_slice(&area.width.to_be_bytes());
+                output.extend_from_slice(&area.height.to_be_bytes());
+                output.push(area.units);
+                output.extend_from_slice(&area.x_resolution.to_be_bytes());
+                output.extend_from_slice(&area.y_resolution.to_be_bytes());
+                output.extend_from_slice(&[area.cell_width, area.cell_height]);
+                output.extend_from_slice(&area.buffer_size.to_be_bytes());
+            }
+            QueryReply::ImplicitPartition { default, alternate } => {
+                output.extend_from_slice(&[0x00, 0x00, 0x0B, 0x01, 0x00]);
+                for value in [default.cols, default.rows, alternate.cols, alternate.rows] {
+                    output.extend_from_slice(&value.to_be_bytes());
+                }
+            }
+            QueryReply::Color { flags, pairs } => {
+                output.extend_from_slice(&[*flags, pairs.len() as u8]);
+                output.extend(pairs.iter().flat_map(|&(value, color)| [value, color]));
+            }
+            QueryReply::Highlight(pairs) => {
+                output.push(pairs.len() as u8);
+                output.extend(pairs.iter().flat_map(|&(value, highlight)| [value, highlight]));
+            }
+            QueryReply::CharacterSets(sets) => serialize_character_sets(sets, output),
+            QueryReply::ReplyModes(modes) => output.extend_from_slice(modes),
+            QueryReply::DbcsAsia(dbcs) => {
+                output.push(dbcs.flags);
+                if let Some(set) = dbcs.sosi_set {
+                    output.extend_from_slice(&[0x03, 0x01, set]);
+                }
+                if let Some(control) = dbcs.input_control {
+                    output.extend_from_slice(&[0x03, 0x02, control]);
+                }
+            }
+            QueryReply::Null => {}
+            QueryReply::Other { data, .. } => output.extend_from_slice(data),
+        }
+        let len = (output.len() - start) as u16;
+        output[start..start + 2].copy_from_slice(&len.to_be_bytes());
+    }
+
+    pub fn code(&self) -> QueryCode {
+        match self {
+            QueryReply::Summary(_) => QueryCode::Summary,
+            QueryReply::UsableArea(_) => QueryCode::UsableArea,
+            QueryReply::ImplicitPartition { .. } => QueryCode::ImplicitPartition,
+            QueryReply::Color { .. } => QueryCode::Color,
+            QueryReply::Highlight(_) => QueryCode::Highlight,
+            QueryReply::CharacterSets(_) => QueryCode::CharacterSets,
+            QueryReply::ReplyModes(_) => QueryCode::ReplyModes,
+            QueryReply::DbcsAsia(_) => QueryCode::DbcsAsia,
+            QueryReply::Null => QueryCode::Null,
+            QueryReply::Other { code, .. } => *code,
+        }
+    }
+}
+
+fn parse_character_sets(mut data: &[u8]) -> Result<CharacterSets, StreamFormatError> {
+    let header = take(&mut data, 9)?;
+    let mut reply = CharacterSets {
+        flags1: header[0],
+        flags2: header[1],
+        default_cell_width: header[2],
+        default_cell_height: header[3],
+        load_formats: u32_at(header, 4),
+        sets: vec![],
+    };
+    let descriptor_len = header[8] as usize;
+    if descriptor_len < 3 {
+        return Err(StreamFormatError::InvalidData);
+    }
+    let has_cgcsgid = reply.flags1 & CharacterSets::CGCSGID != 0;
+    while !data.is_empty() {
+        let descriptor = take(&mut data, descriptor_len)?;
+        let (mut optional, cgcsgid) = match has_cgcsgid {
+            true if descriptor_len >= 7 => {
+                let (optional, cgcsgid) = descriptor[3..].split_at(descriptor_len - 7);
+                (optional, Some(u32_at(cgcsgid, 0)))
+            }
+            true => return Err(StreamFormatError::InvalidData),
+            false => (&descriptor[3..], None),
+        };
+        let cell_size = take(&mut optional, 2).ok().map(|size| (size[0], size[1]));
+        let subsection = take(&mut optional, 2).ok().map(|range| (range[0], range[1]));
+        reply.sets.push(CharacterSet {
+            set: descriptor[0],
+            flags: descriptor[1],
+            lcid: descriptor[2],
+            cell_size,
+            subsection,
+            cgcsgid,
+        });
+    }
+    Ok(reply)
+}
+
+fn serialize_character_sets(reply: &CharacterSets, output: &mut Vec<u8>) {
+    // All descriptors have the same length, so optional parts are written for every set as soon
+    // as one of them has it
+    let subsection = reply.sets.iter().any(|set| set.subsection.is_some());
+    let cell_size = subsection || reply.sets.iter().any(|set| set.cell_size.is_some());
+    let cgcsgid = reply.sets.iter().any(|set| set.cgcsgid.is_some());
+    let descriptor_len = 3 + 2 * cell_size as u8 + 2 * subsection as u8 + 4 * cgcsgid as u8;
+
+    let flags1 = match cgcsgid {
+        true => reply.flags1 | CharacterSets::CGCSGID,
+        false => reply.flags1 & !CharacterSets::CGCSGID,
+    };
+    output.extend_from_slice(&[
+        flags1,
+        reply.flags2,
+        reply.default_cell_width,
+        reply.default_cell_height,
+    ]);
+    output.extend_from_slice(&reply.load_formats.to_be_bytes());
+    output.push(descriptor_len);
+    for set in reply.sets.iter() {
+        output.extend_from_slice(&[set.set, set.flags, set.lcid]);
+        if cell_size {
+            let (width, height) = set.cell_size.unwrap_or_default();
+            output.extend_from_slice(&[width, height]);
+        }
+        if subsection {
+            let (low, high) = set.subsection.unwrap_or_default();
+            output.extend_from_slice(&[low, high]);
+        }
+        if cgcsgid {
+            output.extend_from_slice(&set.cgcsgid.unwrap_or_default().to_be_bytes());
+        }
+    }
+}
+
+// Parses the structured fields following an `AID::StructuredField`. Fields other than Query
+// Replies are skipped. Errors carry the offset of the field and its ID, if it was read.
+pub fn parse_query_replies(record: &[u8]) -> Result<Vec<QueryReply>, StreamFormatError> {
+    let mut replies = vec![];
+    let mut data = record;
+    while !data.is_empty() {
+        let offset = record.len() - data.len();
+        let id = data.get(2).copied();
+        parse_query_reply_field(&mut data, &mut replies).map_err(|error| error.at(offset, id))?;
+    }
+    Ok(replies)
+}
+
+fn parse_query_reply_field(
+    data: &mut &[u8],
+    replies: &mut Vec<QueryReply>,
+) -> Result<(), StreamFormatError> {
+    let header = take(data, 2)?;
+    let len = match u16_at(header, 0) as usize {
+        // A length of zero means the field extends to the end of the record
+        0 if !data.is_empty() => data.len(),
+        len if len >= 3 => len - 2,
+        _ => return Err(StreamFormatError::InvalidData),
+    };
+    let field = take(data, len)?;
+    if field[0] == QUERY_REPLY {
+        replies.push(QueryReply::parse(&field[1..])?);
+    }
+    Ok(())
+}
@@ -1,12 +1,17 @@
-use snafu::{ResultExt, Snafu};
+use snafu::{ResultExt, Snafu, ensure};
 
+use crate::encoding::Encoding;
 use crate::server::Session;
 use crate::server::aid::AID;
+#[cfg(feature = "async")]
+use crate::server::async_session::AsyncSession;
 use crate::server::extended_field_attributes::ExtendedFieldAttribute;
 use crate::server::stream::{
-    BufferAddressCalculator, IncomingRecord, StreamFormatError, WriteCommand, WriteCommandCode,
-    WriteOrder,
+    BufferAddressCalculator, IncomingRecord, StreamFormatError, StreamOptions, WriteCommand,
+    WriteCommandCode, WriteOrder,
 };
+use crate::server::terminal::TerminalModel;
+use crate::server::transport::Transport;
 use crate::server::wcc::{FieldAttribute, WCC};
 
 #[derive(Copy, Clone, Debug)]
@@ -53,6 +58,16 @@ impl<'a> Field<'a> {
         self.attrs.push(attr);
         self
     }
+
+    // The buffer positions the text takes, which is more than its characters with double byte
+    // ones. Fields with the DBCS character set only hold those and need no SO and SI.
+    pub fn width(&self, encoding: Encoding) -> usize {
+        let text = self.data.as_ref();
+        match encoding.is_dbcs() && self.attrs.iter().any(ExtendedFieldAttribute::is_dbcs) {
+            true => text.chars().count() * 2,
+            false => encoding.cells(text),
+        }
+    }
 }
 
 pub struct Screen<'a> {
@@ -62,71 +77,174 @@ pub struct Screen<'a> {
 pub struct Response {
     pub address: Address,
     pub aid: AID,
+    // PA keys, Clear and SysReq send no cursor address or field data, the fields are unchanged
+    pub short_read: bool,
 }
 
 #[derive(Snafu, Debug)]
 pub enum ScreenError {
-    IoError { context: &'static str, source: std::io::Error },
-    StreamError { source: StreamFormatError },
+    IoError {
+        context: &'static str,
+        source: std::io::Error,
+    },
+    StreamError {
+        source: StreamFormatError,
+    },
+    #[snafu(display("Field at {},{} doesn't fit on the screen", address.row, address.col))]
+    FieldOverflow {
+        address: Address,
+    },
+    #[snafu(display("Connection closed by the terminal"))]
+    Disconnected,
 }
 
 impl<'a> Screen<'a> {
-    pub fn present(&mut self, session: &mut Session) -> Result<Response, ScreenError> {
-        let acalc = BufferAddressCalculator { width: 80, height: 24 };
-
-        {
-            let command = WriteCommand {
-                command: WriteCommandCode::EraseWrite,
-                wcc: WCC::RESET_MDT | WCC::KBD_RESTORE,
-                orders: self
-                    .fields
-                    .iter()
-                    .flat_map(|field| {
-                        use std::iter::*;
-                        let Address { row, col } = field.address;
-                        let bufaddr = acalc.encode_address(row, col);
-
-                        let ro = matches!(field.data, FieldData::RO(_));
-
-                        let mut field_attr = field.attrs.clone();
-                        let mut have_fa = false;
-                        for attr in field_attr.iter_mut() {
-                            if let ExtendedFieldAttribute::FieldAttribute(attr) = attr {
-                                attr.set(FieldAttribute::PROTECTED, ro);
-                                have_fa = true;
-                            }
-                        }
-                        if !have_fa {
-                            field_attr.insert(
-                                0,
-                                ExtendedFieldAttribute::FieldAttribute(if ro {
-                                    FieldAttribute::PROTECTED
-                                } else {
-                                    FieldAttribute::NONE
-                                }),
-                            );
-                        }
+    pub fn present<T: Transport>(
+        &mut self,
+        session: &mut Session<T>,
+    ) -> Result<Response, ScreenError> {
+        let model = *session.terminal_model();
+        let acalc = model.screen_size().address_calculator();
 
-                        vec![
-                            WriteOrder::SetBufferAddress(bufaddr),
-                            WriteOrder::StartFieldExtended(field_attr),
-                            WriteOrder::SendText(field.data.as_ref().to_owned()),
-                            WriteOrder::StartField(FieldAttribute::PROTECTED),
-                        ]
-                        .into_iter()
-                    })
-                    .collect(),
-            };
-            //debug_msg!("Sending command: {:#?}", &command);
-            session.send_record(&command).context(IoSnafu { context: "Failed to send screen" })?;
-        }
+        let options = session.stream_options();
+        self.check_layout(&model, options.encoding)?;
+
+        let command = self.write_command(&model, acalc);
+        //debug_msg!("Sending command: {:#?}", &command);
+        let mut record = vec![];
+        command.serialize_with(&mut record, options);
+        session.send_record(record).context(IoSnafu { context: "Failed to send screen" })?;
+
+        let response = session
+            .receive_record(None)
+            .context(IoSnafu { context: "Failed to read response" })?
+            .ok_or(ScreenError::Disconnected)?;
+
+        self.process_response(response.as_slice(), acalc, options)
+    }
+
+    #[cfg(feature = "async")]
+    pub async fn present_async<S>(
+        &mut self,
+        session: &mut AsyncSession<S>,
+    ) -> Result<Response, ScreenError>
+    where
+        S: tokio::io::AsyncRead + tokio::io::AsyncWrite + Unpin,
+    {
+        let model = *session.terminal_model();
+        let acalc = model.screen_size().address_calculator();
+
+        let options = session.stream_options();
+        self.check_layout(&model, options.encoding)?;
+
+        let command = self.write_command(&model, acalc);
+        let mut record = vec![];
+        command.serialize_with(&mut record, options);
+        session.send_record(record).await.context(IoSnafu { context: "Failed to send screen" })?;
 
         let response = session
             .receive_record(None)
+            .await
             .context(IoSnafu { context: "Failed to read response" })?
-            .unwrap(); // We can't get a None if we don't have a timeout
+            .ok_or(ScreenError::Disconnected)?;
+
+        self.process_response(response.as_slice(), acalc, options)
+    }
+
+    // Text running past the end of the buffer would wrap around to the top of the screen
+    fn check_layout(&self, model: &TerminalModel, encoding: Encoding) -> Result<(), ScreenError> {
+        let size = model.screen_size();
+        let acalc = size.address_calculator();
+        let buffer_size = size.rows as usize * size.cols as usize;
+        for field in self.fields.iter() {
+            let Address { row, col } = field.address;
+            let start = acalc.encode_address(row, col) as usize;
+            // Terminals without the extended data stream get no Character Set attributes
+            let width = match model.extended {
+                true => field.width(encoding),
+                false => encoding.cells(field.data.as_ref()),
+            };
+            ensure!(
+                start + 1 + width <= buffer_size,
+                FieldOverflowSnafu { address: field.address }
+            );
+        }
+        Ok(())
+    }
+
+    fn write_command(&self, model: &TerminalModel, acalc: BufferAddressCalculator) -> WriteCommand {
+        WriteCommand {
+            command: if model.uses_alternate() {
+                WriteCommandCode::EraseWriteAlternate
+            } else {
+                WriteCommandCode::EraseWrite
+            },
+            wcc: WCC::RESET_MDT | WCC::KBD_RESTORE,
+            orders: self
+                .fields
+                .iter()
+                .flat_map(|field| {
+                    use std::iter::*;
+                    let Address { row, col } = field.address;
+                    let bufaddr = acalc.encode_address(row, col);
+
+                    let ro = matches!(field.data, FieldData::RO(_));
+
+                    let mut field_attr = field.attrs.clone();
+                    let mut have_fa = false;
+                    for attr in field_attr.iter_mut() {
+                        if let ExtendedFieldAttribute::FieldAttribute(attr) = attr {
+                            attr.set(FieldAttribute::PROTECTED, ro);
+                            have_fa = true;
+                        }
+                    }
+                    if !have_fa {
+                        field_attr.insert(
+                            0,
+                            ExtendedFieldAttribute::FieldAttribute(if ro {
+                                FieldAttribute::PROTECTED
+                            } else {
+                                FieldAttribute::NONE
+                            }),
+                        );
+                    }
+
+                    // Terminals without the extended data stream only understand plain SF
+                    let start_field = if model.extended {
+                        WriteOrder::StartFieldExtended(field_attr)
+                    } else {
+                        WriteOrder::StartField(
+                            field_attr
+                                .iter()
+                                .find_map(|attr| match attr {
+                                    ExtendedFieldAttribute::FieldAttribute(attr) => {
+                                        Some(attr.clone())
+                                    }
+                                    _ => None,
+                                })
+                                .unwrap_or(FieldAttribute::NONE),
+                        )
+                    };
+
+                    vec![
+                        WriteOrder::SetBufferAddress(bufaddr),
+                        start_field,
+                        WriteOrder::SendText(field.data.as_ref().to_owned()),
+                        WriteOrder::StartField(FieldAttribute::PROTECTED),
+                    ]
+                    .into_iter()
+                })
+                .collect(),
+        }
+    }
 
-        let incoming = IncomingRecord::parse_record(response.as_slice()).context(StreamSnafu)?;
+    fn process_response(
+        &mut self,
+        response: &[u8],
+        acalc: BufferAddressCalculator,
+        options: StreamOptions,
+    ) -> Result<Response, ScreenError> {
+        let incoming = IncomingRecord::parse_record_with(response, options).context(StreamSnafu)?;
 
         //debug_msg!("Received: {:?}", incoming);
 
@@ -138,9 +256,10 @@ impl<'a> Screen<'a> {
                 }
                 WriteOrder::SendText(text) => {
                     // TODO: Handle text that comes as multiple orders
+                    // The attribute is right before the text, at the last address for address 0
+                    let attr_addr = incoming_addr.checked_sub(1).unwrap_or(acalc.last_address());
                     for field in self.fields.iter_mut() {
-                        if acalc.encode_address(field.address.row, field.address.col)
-                            == incoming_addr - 1
+                        if acalc.encode_address(field.address.row, field.address.col) == attr_addr
                             && let FieldData::RW(ref mut data) = field.data
                         {
                             **data = text.clone();
@@ -153,6 +272,10 @@ impl<'a> Screen<'a> {
 
         let (row, col) = acalc.decode_address(incoming.addr);
 
-        Ok(Response { address: Address { row, col }, aid: incoming.aid })
+        Ok(Response {
+            address: Address { row, col },
+            aid: incoming.aid,
+            short_read: incoming.short_read,
+        })
     }
 }
@@ -3,12 +3,18 @@ use std::io::Write;
 
 use snafu::{Snafu, ensure};
 
-use crate::encoding::Encoding;
+use crate::encoding::bulk::encode_to;
+use crate::encoding::dbcs::decode_mixed;
+use crate::encoding::{Encoding, decode_into};
 use crate::server::aid::AID;
+use crate::server::buffer::BufferSnapshot;
 use crate::server::extended_field_attributes::ExtendedFieldAttribute;
-use crate::server::wcc::{FieldAttribute, WCC};
+use crate::server::query::QueryReply;
+use crate::server::view::RecordView;
+use crate::server::wcc::{FieldAttribute, WCC, make_ascii_translatable};
 
 #[derive(Clone, Debug, Snafu, Eq, PartialEq)]
+#[snafu(visibility(pub(crate)))]
 pub enum StreamFormatError {
     #[snafu(display("Invalid AID: {:02x}", aid))]
     InvalidAID { aid: u8 },
@@ -16,12 +22,127 @@ pub enum StreamFormatError {
     UnexpectedEOR,
     #[snafu(display("Invalid data"))]
     InvalidData,
+    #[snafu(display("Invalid command: {:02x}", command))]
+    InvalidCommand { command: u8 },
+    #[snafu(display("Expected query replies, got {:?}", aid))]
+    NotQueryReplies { aid: AID },
+    // Where in the record parsing failed. The order is the order code, or the structured field
+    // ID, being parsed; there is none for the AID and cursor address.
+    #[snafu(display(
+        "{} at offset {}{}",
+        source,
+        offset,
+        order.map(|order| format!(" in order {:02x}", order)).unwrap_or_default()
+    ))]
+    Malformed { offset: usize, order: Option<u8>, source: Box<StreamFormatError> },
+}
+
+impl StreamFormatError {
+    pub(crate) fn at(self, offset: usize, order: Option<u8>) -> Self {
+        match self {
+            // Keep the innermost position, it is the most precise one
+            StreamFormatError::Malformed { offset: inner, order, source } => {
+                StreamFormatError::Malformed { offset: offset + inner, order, source }
+            }
+            error => StreamFormatError::Malformed { offset, order, source: Box::new(error) },
+        }
+    }
+
+    // The error without its position
+    pub fn kind(&self) -> &StreamFormatError {
+        match self {
+            StreamFormatError::Malformed { source, .. } => source.kind(),
+            error => error,
+        }
+    }
+
+    pub fn offset(&self) -> Option<usize> {
+        match self {
+            StreamFormatError::Malformed { offset, .. } => Some(*offset),
+            _ => None,
+        }
+    }
+
+    pub fn order(&self) -> Option<u8> {
+        match self {
+            StreamFormatError::Malformed { order, .. } => *order,
+            _ => None,
+        }
+    }
 }
 
 pub trait OutputRecord {
     type Response;
 
-    fn write_to(&self, writer: &mut dyn Write) -> std::io::Result<()>;
+    fn write_to(&self, writer: &mut dyn Write, options: StreamOptions) -> std::io::Result<()>;
+
+    // Records without an answer are never waited for, see `Session::send`
+    fn expects_response(&self) -> bool {
+        true
+    }
+
+    // Parses the record the terminal answered with, an empty one if no answer is expected
+    fn parse_response(
+        &self,
+        record: &[u8],
+        options: StreamOptions,
+    ) -> Result<Self::Response, StreamFormatError>;
+}
+
+// How the data stream of a session is written and read. Both depend on the terminal, see
+// `Session::stream_options`.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct StreamOptions {
+    pub mode: AddressMode,
+    // Code page of all text, including field contents and RA characters
+    pub encoding: Encoding,
+}
+
+// Whether text is double byte only, which it is in DBCS fields and after an SA selecting the DBCS
+// character set. Only tracked for mixed code pages.
+#[derive(Copy, Clone, Debug, Default)]
+pub(crate) struct CharacterSetState {
+    field: bool,
+    character: Option<bool>,
+}
+
+impl CharacterSetState {
+    pub(crate) fn start_field(&mut self, attrs: impl IntoIterator<Item = ExtendedFieldAttribute>) {
+        self.field = attrs.into_iter().any(|attr| attr.is_dbcs());
+    }
+
+    pub(crate) fn modify_field(&mut self, attrs: impl IntoIterator<Item = ExtendedFieldAttribute>) {
+        for attr in attrs {
+            if let ExtendedFieldAttribute::CharacterSet(_) = attr {
+                self.field = attr.is_dbcs();
+            }
+        }
+    }
+
+    // A character set of 0 goes back to the one of the field
+    pub(crate) fn set_attribute(&mut self, attr: &ExtendedFieldAttribute) {
+        match attr {
+            ExtendedFieldAttribute::AllAttributes | ExtendedFieldAttribute::CharacterSet(0x00) => {
+                self.character = None
+            }
+            ExtendedFieldAttribute::CharacterSet(_) => self.character = Some(attr.is_dbcs()),
+            _ => {}
+        }
+    }
+
+    pub(crate) fn apply(&mut self, order: &WriteOrder) {
+        match order {
+            WriteOrder::StartField(_) => self.start_field([]),
+            WriteOrder::StartFieldExtended(attrs) => self.start_field(attrs.iter().cloned()),
+            WriteOrder::ModifyField(attrs) => self.modify_field(attrs.iter().cloned()),
+            WriteOrder::SetAttribute(attr) => self.set_attribute(attr),
+            _ => {}
+        }
+    }
+
+    pub(crate) fn is_dbcs(&self) -> bool {
+        self.character.unwrap_or(self.field)
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -31,12 +152,38 @@ pub struct WriteCommand {
     pub orders: Vec<WriteOrder>,
 }
 
-#[derive(Copy, Clone, Debug)]
+// Only a write that restores the keyboard is answered, once the operator presses an AID key.
+// Writes that leave the keyboard locked have no response.
+impl OutputRecord for WriteCommand {
+    type Response = Option<IncomingRecord>;
+
+    fn write_to(&self, writer: &mut dyn Write, options: StreamOptions) -> std::io::Result<()> {
+        self.write_with(writer, options)
+    }
+
+    fn expects_response(&self) -> bool {
+        self.command == WriteCommandCode::EraseAllUnprotected || self.wcc.contains(WCC::KBD_RESTORE)
+    }
+
+    fn parse_response(
+        &self,
+        record: &[u8],
+        options: StreamOptions,
+    ) -> Result<Self::Response, StreamFormatError> {
+        match self.expects_response() {
+            true => IncomingRecord::parse_record_with(record, options).map(Some),
+            false => Ok(None),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
 pub enum WriteCommandCode {
     Write,
     EraseWrite,
     EraseWriteAlternate,
     EraseAllUnprotected,
+    // Only used for the command byte, see `structured_field::WriteStructuredField` for the record
     WriteStructuredField,
 }
 
@@ -52,6 +199,84 @@ impl WriteCommandCode {
     }
 }
 
+impl TryFrom<u8> for WriteCommandCode {
+    type Error = StreamFormatError;
+
+    // Accepts the SNA command codes as well as the CCW codes used by local non-SNA controllers
+    fn try_from(command: u8) -> Result<Self, Self::Error> {
+        Ok(match command {
+            0xF1 | 0x01 => WriteCommandCode::Write,
+            0xF5 | 0x05 => WriteCommandCode::EraseWrite,
+            0x7E | 0x0D => WriteCommandCode::EraseWriteAlternate,
+            0x6F | 0x0F => WriteCommandCode::EraseAllUnprotected,
+            0xF3 | 0x11 => WriteCommandCode::WriteStructuredField,
+            _ => return Err(StreamFormatError::InvalidCommand { command }),
+        })
+    }
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum ReadCommandCode {
+    ReadBuffer,
+    ReadModified,
+    ReadModifiedAll,
+}
+
+impl ReadCommandCode {
+    pub fn to_command_code(self) -> u8 {
+        match self {
+            ReadCommandCode::ReadBuffer => 0xF2,
+            ReadCommandCode::ReadModified => 0xF6,
+            ReadCommandCode::ReadModifiedAll => 0x6E,
+        }
+    }
+}
+
+// Reads the whole buffer including field attributes
+#[derive(Copy, Clone, Debug)]
+pub struct ReadBuffer;
+
+impl OutputRecord for ReadBuffer {
+    type Response = BufferSnapshot;
+
+    fn write_to(&self, writer: &mut dyn Write, _options: StreamOptions) -> std::io::Result<()> {
+        writer.write_all(&[ReadCommandCode::ReadBuffer.to_command_code()])
+    }
+
+    fn parse_response(
+        &self,
+        record: &[u8],
+        options: StreamOptions,
+    ) -> Result<BufferSnapshot, StreamFormatError> {
+        BufferSnapshot::parse_with(record, options)
+    }
+}
+
+// Reads the modified fields, the same way the terminal sends them when an AID key is pressed
+#[derive(Copy, Clone, Debug)]
+pub struct ReadModified {
+    // Read Modified All also returns the fields after PA keys and Clear
+    pub all: bool,
+}
+
+impl OutputRecord for ReadModified {
+    type Response = IncomingRecord;
+
+    fn write_to(&self, writer: &mut dyn Write, _options: StreamOptions) -> std::io::Result<()> {
+        let command =
+            if self.all { ReadCommandCode::ReadModifiedAll } else { ReadCommandCode::ReadModified };
+        writer.write_all(&[command.to_command_code()])
+    }
+
+    fn parse_response(
+        &self,
+        record: &[u8],
+        options: StreamOptions,
+    ) -> Result<IncomingRecord, StreamFormatError> {
+        IncomingRecord::parse_record_with(record, options)
+    }
+}
+
 #[derive(Copy, Clone, Debug)]
 pub struct BufferAddressCalculator {
     pub width: u16,
@@ -72,6 +297,48 @@ impl BufferAddressCalculator {
     }
 }
 
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Ord, PartialOrd)]
+pub enum AddressMode {
+    // 6 bits per byte through the translation table, up to 4095. Understood by every terminal.
+    TwelveBit,
+    // Up to 16383 with the top two bits cleared
+    #[default]
+    FourteenBit,
+    // All 16 bits, only for terminals that report it in their Usable Area reply
+    SixteenBit,
+}
+
+impl AddressMode {
+    // The narrowest mode able to address every position of a buffer of the given size
+    pub fn for_buffer_size(size: usize) -> Self {
+        match size {
+            0..=0x1000 => AddressMode::TwelveBit,
+            0x1001..=0x4000 => AddressMode::FourteenBit,
+            _ => AddressMode::SixteenBit,
+        }
+    }
+
+    // Addresses that don't fit 12 bits are written in 14-bit form, which every terminal detects
+    pub fn encode(self, addr: u16) -> [u8; 2] {
+        match self {
+            AddressMode::TwelveBit if addr < 0x1000 => {
+                [make_ascii_translatable((addr >> 6) as u8), make_ascii_translatable(addr as u8)]
+            }
+            AddressMode::SixteenBit => addr.to_be_bytes(),
+            _ => [(addr >> 8) as u8 & 0x3F, addr as u8],
+        }
+    }
+
+    // 12- and 14-bit addresses are told apart by their top two bits, 16-bit ones have no room
+    // for that
+    pub fn decode(self, encoded: &[u8]) -> Result<u16, StreamFormatError> {
+        match (self, encoded) {
+            (AddressMode::SixteenBit, &[hi, lo, ..]) => Ok(u16::from_be_bytes([hi, lo])),
+            _ => parse_addr(encoded),
+        }
+    }
+}
+
 #[derive(Clone, Debug)]
 pub enum WriteOrder {
     StartField(FieldAttribute),
@@ -80,8 +347,12 @@ pub enum WriteOrder {
     SetAttribute(ExtendedFieldAttribute),
     ModifyField(Vec<ExtendedFieldAttribute>),
     InsertCursor(u16),
+    // A bare IC, which inserts the cursor wherever the previous orders left the buffer address
+    InsertCursorHere,
     ProgramTab,
     RepeatToAddress(u16, char),
+    // RA of a character from the GE set, e.g. an APL or line drawing graphic
+    RepeatGraphicEscape(u16, u8),
     EraseUnprotectedToAddress(u16),
     GraphicEscape(u8),
     SendText(String),
@@ -89,58 +360,305 @@ pub enum WriteOrder {
 
 impl WriteOrder {
     pub fn serialize(&self, output: &mut Vec<u8>) {
+        self.serialize_with(output, StreamOptions::default());
+    }
+
+    pub fn serialize_with(&self, output: &mut Vec<u8>, options: StreamOptions) {
+        // Writing to a Vec can't fail
+        let _ = self.write_with(output, options);
+    }
+
+    pub fn write_with(
+        &self,
+        writer: &mut dyn Write,
+        options: StreamOptions,
+    ) -> std::io::Result<()> {
+        let mode = options.mode;
         match self {
-            WriteOrder::StartField(attr) => output.extend_from_slice(&[0x1D, attr.bits()]),
+            WriteOrder::StartField(attr) => writer.write_all(&[0x1D, attr.bits()]),
             WriteOrder::StartFieldExtended(attrs) => {
-                output.extend_from_slice(&[0x29, attrs.len() as u8]);
-                for attr in attrs {
-                    attr.encode_into(&mut *output);
-                }
+                writer.write_all(&[0x29, attrs.len() as u8])?;
+                write_attributes(writer, attrs)
             }
             WriteOrder::SetBufferAddress(addr) => {
-                output.extend_from_slice(&[0x11, (addr >> 8) as u8, (addr & 0xff) as u8])
+                let [hi, lo] = mode.encode(*addr);
+                writer.write_all(&[0x11, hi, lo])
             }
             WriteOrder::SetAttribute(attr) => {
                 let (typ, val) = attr.clone().encoded();
-                output.extend_from_slice(&[0x28, typ, val]);
+                writer.write_all(&[0x28, typ, val])
             }
             WriteOrder::ModifyField(attrs) => {
-                output.extend_from_slice(&[0x2C, attrs.len() as u8]);
-                for attr in attrs {
-                    attr.encode_into(&mut *output);
-                }
+                writer.write_all(&[0x2C, attrs.len() as u8])?;
+                write_attributes(writer, attrs)
             }
+            // IC inserts the cursor at the current buffer address, so it is preceded by an SBA
             WriteOrder::InsertCursor(addr) => {
-                output.extend_from_slice(&[0x11, (addr >> 8) as u8, (addr & 0xff) as u8])
+                let [hi, lo] = mode.encode(*addr);
+                writer.write_all(&[0x11, hi, lo, 0x13])
+            }
+            WriteOrder::InsertCursorHere => writer.write_all(&[0x13]),
+            WriteOrder::ProgramTab => writer.write_all(&[0x05]),
+            WriteOrder::RepeatToAddress(addr, ch) => {
+                let [hi, lo] = mode.encode(*addr);
+                let encoded = options.encoding.encode_char(*ch);
+                writer.write_all(&[0x3C, hi, lo, encoded.unwrap_or(0x40)])
+            }
+            WriteOrder::RepeatGraphicEscape(addr, ch) => {
+                let [hi, lo] = mode.encode(*addr);
+                writer.write_all(&[0x3C, hi, lo, 0x08, *ch])
             }
-            WriteOrder::ProgramTab => output.push(0x05),
-            WriteOrder::RepeatToAddress(addr, ch) => output.extend_from_slice(&[
-                0x3C,
-                (addr >> 8) as u8,
-                (addr & 0xff) as u8,
-                crate::encoding::cp037::ENCODE_TBL[*ch as usize],
-            ]),
             WriteOrder::EraseUnprotectedToAddress(addr) => {
-                output.extend_from_slice(&[0x12, (addr >> 8) as u8, (addr & 0xff) as u8])
+                let [hi, lo] = mode.encode(*addr);
+                writer.write_all(&[0x12, hi, lo])
             }
-            WriteOrder::GraphicEscape(ch) => output.extend_from_slice(&[0x08, *ch]),
-            WriteOrder::SendText(text) => {
-                output.extend(crate::encoding::encode_ascii_to(text.chars(), &Encoding::CP037));
+            WriteOrder::GraphicEscape(ch) => writer.write_all(&[0x08, *ch]),
+            WriteOrder::SendText(text) => write_text(writer, text, options, false),
+        }
+    }
+
+    // Like `write_with`, but text is written as the character set of the field requires
+    fn write_in(
+        &self,
+        writer: &mut dyn Write,
+        options: StreamOptions,
+        charset: &mut CharacterSetState,
+    ) -> std::io::Result<()> {
+        charset.apply(self);
+        match self {
+            WriteOrder::SendText(text) => write_text(writer, text, options, charset.is_dbcs()),
+            order => order.write_with(writer, options),
+        }
+    }
+}
+
+// Characters the code page doesn't have become spaces, see `Encoder` for more control. Text of
+// mixed code pages shifts out for double byte characters, except in DBCS fields.
+fn write_text(
+    writer: &mut dyn Write,
+    text: &str,
+    options: StreamOptions,
+    dbcs_field: bool,
+) -> std::io::Result<()> {
+    let mut chunks = Chunks { writer, buf: [0; 64], len: 0, error: None };
+    encode_to(text, options.encoding, dbcs_field, &mut chunks);
+    chunks.finish()
+}
+
+// Passes encoded text on to the writer through a small buffer, so text of any length is written
+// without allocating. The first write error is kept until `finish`.
+struct Chunks<'a> {
+    writer: &'a mut dyn Write,
+    buf: [u8; 64],
+    len: usize,
+    error: Option<std::io::Error>,
+}
+
+impl Chunks<'_> {
+    fn flush_buf(&mut self) {
+        if self.error.is_none() {
+            self.error = self.writer.write_all(&self.buf[..self.len]).err();
+        }
+        self.len = 0;
+    }
+
+    fn finish(mut self) -> std::io::Result<()> {
+        self.flush_buf();
+        self.error.map_or(Ok(()), Err)
+    }
+}
+
+impl Extend<u8> for Chunks<'_> {
+    fn extend<I: IntoIterator<Item = u8>>(&mut self, iter: I) {
+        for byte in iter {
+            if self.len == self.buf.len() {
+                self.flush_buf();
             }
+            self.buf[self.len] = byte;
+            self.len += 1;
         }
     }
 }
 
+fn write_attributes(
+    writer: &mut dyn Write,
+    attrs: &[ExtendedFieldAttribute],
+) -> std::io::Result<()> {
+    for attr in attrs {
+        let (typ, val) = attr.clone().encoded();
+        writer.write_all(&[typ, val])?;
+    }
+    Ok(())
+}
+
+// Order codes of the outbound data stream, every other byte is data
+fn is_order(code: u8) -> bool {
+    matches!(code, 0x05 | 0x08 | 0x11 | 0x12 | 0x13 | 0x1D | 0x28 | 0x29 | 0x2C | 0x3C)
+}
+
+fn parse_attributes(attrs: &[u8]) -> Result<Vec<ExtendedFieldAttribute>, StreamFormatError> {
+    attrs.chunks(2).map(ExtendedFieldAttribute::try_from).collect()
+}
+
 impl WriteCommand {
     pub fn serialize(&self, output: &mut Vec<u8>) {
-        output.push(self.command.to_command_code());
-        output.push(self.wcc.to_ascii_compat());
+        self.serialize_with(output, StreamOptions::default());
+    }
+
+    pub fn serialize_with(&self, output: &mut Vec<u8>, options: StreamOptions) {
+        // Writing to a Vec can't fail
+        let _ = self.write_with(output, options);
+    }
+
+    pub fn write_with(
+        &self,
+        writer: &mut dyn Write,
+        options: StreamOptions,
+    ) -> std::io::Result<()> {
+        // Erase All Unprotected is the only command without a WCC
+        if self.command == WriteCommandCode::EraseAllUnprotected {
+            return writer.write_all(&[self.command.to_command_code()]);
+        }
+        writer.write_all(&[self.command.to_command_code(), self.wcc.to_ascii_compat()])?;
+        let mut charset = CharacterSetState::default();
         for order in self.orders.iter() {
-            order.serialize(&mut *output);
+            order.write_in(writer, options, &mut charset)?;
         }
+        Ok(())
+    }
+
+    // Parses an outbound record, as written by `serialize` or any other host. Write Structured
+    // Field records are not write commands in this sense and are rejected.
+    pub fn parse(record: &[u8]) -> Result<Self, StreamFormatError> {
+        Self::parse_with(record, StreamOptions::default())
+    }
+
+    pub fn parse_with(record: &[u8], options: StreamOptions) -> Result<Self, StreamFormatError> {
+        let full = record;
+        let (&code, record) =
+            record.split_first().ok_or_else(|| StreamFormatError::UnexpectedEOR.at(0, None))?;
+        let command = WriteCommandCode::try_from(code).map_err(|error| error.at(0, None))?;
+        match command {
+            WriteCommandCode::WriteStructuredField => {
+                return Err(StreamFormatError::InvalidCommand { command: code }.at(0, None));
+            }
+            WriteCommandCode::EraseAllUnprotected => {
+                if !record.is_empty() {
+                    return Err(StreamFormatError::InvalidData.at(1, None));
+                }
+                return Ok(Self { command, wcc: WCC::empty(), orders: vec![] });
+            }
+            _ => {}
+        }
+        let (&wcc, mut record) =
+            record.split_first().ok_or_else(|| StreamFormatError::UnexpectedEOR.at(1, None))?;
+        let wcc = WCC::from_ascii_compat(wcc);
+
+        // The buffer address is tracked for IC orders. Erase/Write starts at 0, Write at the
+        // cursor and a PT moves to the next unprotected field, neither of which is known here.
+        let mut addr = (command != WriteCommandCode::Write).then_some(0u16);
+        let mut orders = vec![];
+        let mut charset = CharacterSetState::default();
+        while let Some(&code) = record.first() {
+            let offset = full.len() - record.len();
+            let (order, len) = parse_write_order(record, options, &mut addr, charset.is_dbcs())
+                .map_err(|error| error.at(offset, Some(code)))?;
+            charset.apply(&order);
+            orders.push(order);
+            record = &record[len..];
+        }
+        Ok(Self { command, wcc, orders })
     }
 }
 
+// Parses the order at the start of `record` and returns it with the number of bytes it took.
+// `addr` is the buffer address as far as it is known, see `WriteCommand::parse_with`.
+fn parse_write_order(
+    record: &[u8],
+    options: StreamOptions,
+    addr: &mut Option<u16>,
+    dbcs: bool,
+) -> Result<(WriteOrder, usize), StreamFormatError> {
+    let (mode, encoding) = (options.mode, options.encoding);
+    let order = record[0];
+    let advance = |addr: &mut Option<u16>, len: usize| {
+        *addr = addr.map(|addr| addr.wrapping_add(len as u16));
+    };
+    Ok(match order {
+        0x1D => {
+            ensure!(record.len() >= 2, UnexpectedEORSnafu);
+            advance(addr, 1);
+            (WriteOrder::StartField(FieldAttribute::from_bits_truncate(record[1] & 0x3F)), 2)
+        }
+        0x29 | 0x2C => {
+            ensure!(record.len() >= 2, UnexpectedEORSnafu);
+            let len = 2 + record[1] as usize * 2;
+            ensure!(record.len() >= len, UnexpectedEORSnafu);
+            let attrs = parse_attributes(&record[2..len])?;
+            advance(addr, 1);
+            match order {
+                0x29 => (WriteOrder::StartFieldExtended(attrs), len),
+                _ => (WriteOrder::ModifyField(attrs), len),
+            }
+        }
+        0x11 => {
+            ensure!(record.len() >= 3, UnexpectedEORSnafu);
+            let target = mode.decode(&record[1..3])?;
+            *addr = Some(target);
+            // SBA followed by IC is how `InsertCursor` is written
+            match record.get(3) {
+                Some(0x13) => (WriteOrder::InsertCursor(target), 4),
+                _ => (WriteOrder::SetBufferAddress(target), 3),
+            }
+        }
+        0x13 => match *addr {
+            Some(addr) => (WriteOrder::InsertCursor(addr), 1),
+            None => (WriteOrder::InsertCursorHere, 1),
+        },
+        0x28 => {
+            ensure!(record.len() >= 3, UnexpectedEORSnafu);
+            (WriteOrder::SetAttribute(ExtendedFieldAttribute::try_from(&record[1..3])?), 3)
+        }
+        0x05 => {
+            *addr = None;
+            (WriteOrder::ProgramTab, 1)
+        }
+        0x3C => {
+            ensure!(record.len() >= 4, UnexpectedEORSnafu);
+            let target = mode.decode(&record[1..3])?;
+            *addr = Some(target);
+            if record[3] == 0x08 {
+                ensure!(record.len() >= 5, UnexpectedEORSnafu);
+                (WriteOrder::RepeatGraphicEscape(target, record[4]), 5)
+            } else {
+                (WriteOrder::RepeatToAddress(target, encoding.decode_char(record[3])), 4)
+            }
+        }
+        0x12 => {
+            ensure!(record.len() >= 3, UnexpectedEORSnafu);
+            let target = mode.decode(&record[1..3])?;
+            *addr = Some(target);
+            (WriteOrder::EraseUnprotectedToAddress(target), 3)
+        }
+        0x08 => {
+            ensure!(record.len() >= 2, UnexpectedEORSnafu);
+            advance(addr, 1);
+            (WriteOrder::GraphicEscape(record[1]), 2)
+        }
+        _ => {
+            let len = record.iter().position(|&v| is_order(v)).unwrap_or(record.len());
+            let text = &record[..len];
+            let mut decoded = String::new();
+            match encoding.is_dbcs() {
+                true => decoded.extend(decode_mixed(text, encoding, dbcs)),
+                false => decode_into(text, encoding, &mut decoded),
+            }
+            advance(addr, len);
+            (WriteOrder::SendText(decoded), len)
+        }
+    })
+}
+
 impl From<&WriteCommand> for Vec<u8> {
     fn from(val: &WriteCommand) -> Self {
         let mut result = vec![];
@@ -154,124 +672,90 @@ pub struct IncomingRecord {
     pub aid: AID,
     pub addr: u16,
     pub orders: Vec<WriteOrder>,
+    // Only the AID was sent, see `AID::is_short_read`. The address is 0 then.
+    pub short_read: bool,
+    // Only filled for `AID::StructuredField`, which carries no cursor address or orders
+    pub query_replies: Vec<QueryReply>,
 }
 
-fn parse_addr(encoded: &[u8]) -> Result<u16, StreamFormatError> {
-    match encoded[0] >> 6 {
-        0b00 => Ok(((encoded[0] as u16) << 8) + encoded[1] as u16),
-        0b01 | 0b11 => Ok((encoded[0] as u16 & 0x3F) << 6 | (encoded[1] as u16 & 0x3F)),
+pub(crate) fn parse_addr(encoded: &[u8]) -> Result<u16, StreamFormatError> {
+    let &[hi, lo, ..] = encoded else {
+        return Err(StreamFormatError::UnexpectedEOR);
+    };
+    match hi >> 6 {
+        0b00 => Ok(((hi as u16) << 8) + lo as u16),
+        0b01 | 0b11 => Ok((hi as u16 & 0x3F) << 6 | (lo as u16 & 0x3F)),
         _ => Err(StreamFormatError::InvalidData),
     }
 }
 
-impl IncomingRecord {
-    pub fn parse_record(mut record: &[u8]) -> Result<Self, StreamFormatError> {
-        if record.len() < 3 {
-            return Err(StreamFormatError::UnexpectedEOR);
-        }
-
-        let aid = AID::try_from(record[0])?;
-        let addr = parse_addr(&record[1..3])?;
-
-        let mut result = Self { aid, addr, orders: vec![] };
+impl From<&IncomingRecord> for Vec<u8> {
+    fn from(val: &IncomingRecord) -> Self {
+        let mut result = vec![];
+        val.serialize(&mut result);
+        result
+    }
+}
 
-        record = &record[3..];
+impl IncomingRecord {
+    // Writes the record as a terminal would send it. Query replies are only written for
+    // `AID::StructuredField`, the cursor address and orders for everything else.
+    pub fn serialize(&self, output: &mut Vec<u8>) {
+        self.serialize_with(
+            output,
+            StreamOptions { mode: AddressMode::TwelveBit, ..StreamOptions::default() },
+        );
+    }
 
-        while !record.is_empty() {
-            match record[0] {
-                0x1D => {
-                    ensure!(record.len() >= 2, UnexpectedEORSnafu);
-                    result.orders.push(WriteOrder::StartField(
-                        FieldAttribute::from_bits(record[1] & 0x3F)
-                            .ok_or(StreamFormatError::InvalidData)?,
-                    ));
-                    record = &record[2..];
-                }
-                0x29 => {
-                    ensure!(record.len() >= 2, UnexpectedEORSnafu);
-                    let (header, body) = record.split_at(2);
-                    let count = header[1] as usize;
-                    ensure!(body.len() >= count * 2, UnexpectedEORSnafu);
-                    let (attrs, rest) = body.split_at(2 * count);
-                    record = rest;
-
-                    result.orders.push(WriteOrder::StartFieldExtended(
-                        attrs.chunks(2).map(ExtendedFieldAttribute::try_from).collect::<Result<
-                            Vec<ExtendedFieldAttribute>,
-                            StreamFormatError,
-                        >>(
-                        )?,
-                    ))
-                }
-                0x11 => {
-                    ensure!(record.len() >= 3, UnexpectedEORSnafu);
-                    result.orders.push(WriteOrder::SetBufferAddress(parse_addr(&record[1..3])?));
-                    record = &record[3..];
-                }
-                0x28 => {
-                    ensure!(record.len() >= 3, UnexpectedEORSnafu);
-                    result.orders.push(WriteOrder::SetAttribute(ExtendedFieldAttribute::try_from(
-                        &record[1..3],
-                    )?));
-                    record = &record[3..];
-                }
-                0x2C => {
-                    ensure!(record.len() >= 2, UnexpectedEORSnafu);
-                    let (header, body) = record.split_at(2);
-                    let count = header[1] as usize;
-                    ensure!(body.len() >= count * 2, UnexpectedEORSnafu);
-                    let (attrs, rest) = body.split_at(2 * count);
-                    record = rest;
-
-                    result.orders.push(WriteOrder::ModifyField(
-                        attrs.chunks(2).map(ExtendedFieldAttribute::try_from).collect::<Result<
-                            Vec<ExtendedFieldAttribute>,
-                            StreamFormatError,
-                        >>(
-                        )?,
-                    ))
-                }
-                0x13 => {
-                    ensure!(record.len() >= 3, UnexpectedEORSnafu);
-                    result.orders.push(WriteOrder::InsertCursor(parse_addr(&record[1..3])?));
-                    record = &record[3..];
-                }
-                0x05 => {
-                    result.orders.push(WriteOrder::ProgramTab);
-                    record = &record[1..];
-                }
-                0x3C => {
-                    ensure!(record.len() >= 4, UnexpectedEORSnafu);
-                    result.orders.push(WriteOrder::RepeatToAddress(
-                        parse_addr(&record[1..3])?,
-                        crate::encoding::cp037::DECODE_TBL[record[4] as usize] as char,
-                    ));
-                    record = &record[4..]
-                }
-                0x12 => {
-                    ensure!(record.len() >= 3, UnexpectedEORSnafu);
-                    result
-                        .orders
-                        .push(WriteOrder::EraseUnprotectedToAddress(parse_addr(&record[1..3])?));
-                    record = &record[3..];
-                }
-                0x08 => {
-                    ensure!(record.len() >= 2, UnexpectedEORSnafu);
-                    result.orders.push(WriteOrder::GraphicEscape(record[2]));
-                    record = &record[2..];
+    pub fn serialize_with(&self, output: &mut Vec<u8>, options: StreamOptions) {
+        output.push(self.aid.into());
+        if self.aid == AID::StructuredField {
+            for reply in self.query_replies.iter() {
+                reply.serialize(&mut *output);
+            }
+            return;
+        }
+        if self.short_read {
+            return;
+        }
+        output.extend_from_slice(&options.mode.encode(self.addr));
+        let mut charset = CharacterSetState::default();
+        for order in self.orders.iter() {
+            match order {
+                // Field attributes are sent as graphic characters in Read Buffer replies
+                WriteOrder::StartField(attr) => {
+                    charset.apply(order);
+                    output.extend_from_slice(&[0x1D, make_ascii_translatable(attr.bits())])
                 }
-                0x40..=0xFF => {
-                    let len = record.iter().position(|&v| v < 0x40).unwrap_or(record.len());
-                    let data = record[..len]
-                        .iter()
-                        .map(|&v| crate::encoding::cp037::DECODE_TBL[v as usize] as char)
-                        .collect();
-                    result.orders.push(WriteOrder::SendText(data));
-                    record = &record[len..];
+                // Writing to a Vec can't fail
+                order => {
+                    let _ = order.write_in(&mut *output, options, &mut charset);
                 }
-                _ => return Err(StreamFormatError::InvalidData),
             }
         }
-        Ok(result)
+    }
+
+    pub fn parse_record(record: &[u8]) -> Result<Self, StreamFormatError> {
+        Self::parse_record_with(record, StreamOptions::default())
+    }
+
+    // Only `AddressMode::SixteenBit` needs to be known, the other modes are detected. Errors are
+    // `StreamFormatError::Malformed` with the position of the problem. See `RecordView` for a
+    // parser that doesn't allocate.
+    pub fn parse_record_with(
+        record: &[u8],
+        options: StreamOptions,
+    ) -> Result<Self, StreamFormatError> {
+        let view = RecordView::parse_with(record, options)?;
+        Ok(Self {
+            aid: view.aid,
+            addr: view.addr,
+            orders: view
+                .orders()
+                .map(|order| order.map(WriteOrder::from))
+                .collect::<Result<_, _>>()?,
+            short_read: view.short_read,
+            query_replies: view.query_replies()?,
+        })
     }
 }
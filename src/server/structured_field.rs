@@ -0,0 +1,294 @@
+use std::io::Write;
+
+use crate::server::query::QueryCode;
+use crate::server::stream::{
+    IncomingRecord, OutputRecord, StreamFormatError, StreamOptions, WriteCommand, WriteCommandCode,
+};
+
+// Partition ID addressing the whole device, only valid for queries
+pub const ALL_PARTITIONS: u8 = 0xFF;
+// The implicit partition every terminal starts with
+pub const IMPLICIT_PARTITION: u8 = 0x00;
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum QueryListRequest {
+    // Only the listed replies
+    List,
+    // The listed replies plus those the terminal considers equivalent
+    EquivalentAndList,
+    // Everything, the list is ignored
+    All,
+}
+
+impl From<QueryListRequest> for u8 {
+    fn from(request: QueryListRequest) -> u8 {
+        match request {
+            QueryListRequest::List => 0x00,
+            QueryListRequest::EquivalentAndList => 0x40,
+            QueryListRequest::All => 0x80,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ReadPartitionType {
+    Query,
+    QueryList { request: QueryListRequest, codes: Vec<QueryCode> },
+    ReadBuffer,
+    ReadModified,
+    ReadModifiedAll,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ReplyMode {
+    Field,
+    ExtendedField,
+    // Character mode also reports the listed attribute types (e.g. 0x42 for color) per character
+    Character(Vec<u8>),
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CreatePartition {
+    pub partition: u8,
+    // Unit of measure for the cell size, in the low nibble
+    pub unit: u8,
+    pub flags: u8,
+    // Presentation space
+    pub height: u16,
+    pub width: u16,
+    // Viewport origin and size on the screen
+    pub viewport_row: u16,
+    pub viewport_col: u16,
+    pub viewport_height: u16,
+    pub viewport_width: u16,
+    // Origin of the window into the presentation space
+    pub window_row: u16,
+    pub window_col: u16,
+    pub scroll_rows: u16,
+    pub cell_width: u16,
+    pub cell_height: u16,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LoadProgrammedSymbols {
+    pub flags: u8,
+    // Local character set ID the symbols are loaded into
+    pub lcid: u8,
+    // Code point of the first symbol
+    pub first_char: u8,
+    // Extended parameters; the EX flag is set when present
+    pub extensions: Option<Vec<u8>>,
+    // Symbol definitions
+    pub data: Vec<u8>,
+}
+
+impl LoadProgrammedSymbols {
+    const EXTENDED: u8 = 0x80;
+}
+
+#[derive(Clone, Debug)]
+pub enum StructuredField {
+    ReadPartition { partition: u8, kind: ReadPartitionType },
+    // Resets all partitions and sets the implicit partition to its default or alternate size
+    EraseReset { alternate: bool },
+    SetReplyMode { partition: u8, mode: ReplyMode },
+    // A write command directed at a partition
+    Outbound3270DS { partition: u8, command: WriteCommand },
+    CreatePartition(CreatePartition),
+    DestroyPartition { partition: u8 },
+    LoadProgrammedSymbols(LoadProgrammedSymbols),
+}
+
+impl StructuredField {
+    pub fn id(&self) -> u8 {
+        match self {
+            StructuredField::ReadPartition { .. } => 0x01,
+            StructuredField::EraseReset { .. } => 0x03,
+            StructuredField::LoadProgrammedSymbols(_) => 0x06,
+            StructuredField::SetReplyMode { .. } => 0x09,
+            StructuredField::CreatePartition(_) => 0x0C,
+            StructuredField::DestroyPartition { .. } => 0x0D,
+            StructuredField::Outbound3270DS { .. } => 0x40,
+        }
+    }
+
+    // Appends the field including its length prefix
+    pub fn serialize(&self, output: &mut Vec<u8>) {
+        self.serialize_with(output, StreamOptions::default());
+    }
+
+    pub fn serialize_with(&self, output: &mut Vec<u8>, options: StreamOptions) {
+        // Writing to a Vec can't fail
+        let _ = self.write_with(output, options);
+    }
+
+    // The length comes first, so the field is measured before it is written
+    pub fn write_with(
+        &self,
+        writer: &mut dyn Write,
+        options: StreamOptions,
+    ) -> std::io::Result<()> {
+        let mut len = Length(3);
+        self.write_body(&mut len, options)?;
+        debug_assert!(len.0 <= u16::MAX as usize, "structured field too long");
+        let [hi, lo] = (len.0 as u16).to_be_bytes();
+        writer.write_all(&[hi, lo, self.id()])?;
+        self.write_body(writer, options)
+    }
+
+    // Everything after the length and ID
+    fn write_body(&self, writer: &mut dyn Write, options: StreamOptions) -> std::io::Result<()> {
+        match self {
+            StructuredField::ReadPartition { partition, kind } => {
+                writer.write_all(&[*partition])?;
+                match kind {
+                    ReadPartitionType::Query => writer.write_all(&[0x02]),
+                    ReadPartitionType::QueryList { request, codes } => {
+                        writer.write_all(&[0x03, u8::from(*request)])?;
+                        for &code in codes {
+                            writer.write_all(&[u8::from(code)])?;
+                        }
+                        Ok(())
+                    }
+                    ReadPartitionType::ReadBuffer => writer.write_all(&[0xF2]),
+                    ReadPartitionType::ReadModified => writer.write_all(&[0xF6]),
+                    ReadPartitionType::ReadModifiedAll => writer.write_all(&[0x6E]),
+                }
+            }
+            StructuredField::EraseReset { alternate } => {
+                writer.write_all(&[if *alternate { 0x80 } else { 0x00 }])
+            }
+            StructuredField::SetReplyMode { partition, mode } => {
+                writer.write_all(&[*partition])?;
+                match mode {
+                    ReplyMode::Field => writer.write_all(&[0x00]),
+                    ReplyMode::ExtendedField => writer.write_all(&[0x01]),
+                    ReplyMode::Character(types) => {
+                        writer.write_all(&[0x02])?;
+                        writer.write_all(types)
+                    }
+                }
+            }
+            StructuredField::Outbound3270DS { partition, command } => {
+                writer.write_all(&[*partition])?;
+                command.write_with(writer, options)
+            }
+            StructuredField::CreatePartition(partition) => {
+                writer.write_all(&[partition.partition, partition.unit, partition.flags])?;
+                for value in [
+                    partition.height,
+                    partition.width,
+                    partition.viewport_row,
+                    partition.viewport_col,
+                    partition.viewport_height,
+                    partition.viewport_width,
+                    partition.window_row,
+                    partition.window_col,
+                    partition.scroll_rows,
+                    0,
+                    partition.cell_width,
+                    partition.cell_height,
+                ] {
+                    writer.write_all(&value.to_be_bytes())?;
+                }
+                Ok(())
+            }
+            StructuredField::DestroyPartition { partition } => writer.write_all(&[*partition]),
+            StructuredField::LoadProgrammedSymbols(symbols) => {
+                let flags = match symbols.extensions {
+                    Some(_) => symbols.flags | LoadProgrammedSymbols::EXTENDED,
+                    None => symbols.flags & !LoadProgrammedSymbols::EXTENDED,
+                };
+                writer.write_all(&[flags, symbols.lcid, symbols.first_char])?;
+                if let Some(extensions) = &symbols.extensions {
+                    // The length byte counts itself
+                    writer.write_all(&[extensions.len() as u8 + 1])?;
+                    writer.write_all(extensions)?;
+                }
+                writer.write_all(&symbols.data)
+            }
+        }
+    }
+}
+
+// Counts the bytes written instead of keeping them
+struct Length(usize);
+
+impl Write for Length {
+    fn write(&mut self, data: &[u8]) -> std::io::Result<usize> {
+        self.0 += data.len();
+        Ok(data.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+// A Write Structured Field record. Unlike the other write commands it has no WCC and carries
+// structured fields instead of orders.
+#[derive(Clone, Debug)]
+pub struct WriteStructuredField {
+    pub fields: Vec<StructuredField>,
+}
+
+impl WriteStructuredField {
+    pub fn serialize(&self, output: &mut Vec<u8>) {
+        self.serialize_with(output, StreamOptions::default());
+    }
+
+    // The options apply to the write commands of Outbound 3270DS fields
+    pub fn serialize_with(&self, output: &mut Vec<u8>, options: StreamOptions) {
+        // Writing to a Vec can't fail
+        let _ = self.write_with(output, options);
+    }
+
+    pub fn write_with(
+        &self,
+        writer: &mut dyn Write,
+        options: StreamOptions,
+    ) -> std::io::Result<()> {
+        writer.write_all(&[WriteCommandCode::WriteStructuredField.to_command_code()])?;
+        for field in self.fields.iter() {
+            field.write_with(writer, options)?;
+        }
+        Ok(())
+    }
+}
+
+// Read Partition fields are answered, and so are write commands that restore the keyboard, see
+// `WriteCommand`
+impl OutputRecord for WriteStructuredField {
+    type Response = Option<IncomingRecord>;
+
+    fn write_to(&self, writer: &mut dyn Write, options: StreamOptions) -> std::io::Result<()> {
+        self.write_with(writer, options)
+    }
+
+    fn expects_response(&self) -> bool {
+        self.fields.iter().any(|field| match field {
+            StructuredField::ReadPartition { .. } => true,
+            StructuredField::Outbound3270DS { command, .. } => command.expects_response(),
+            _ => false,
+        })
+    }
+
+    fn parse_response(
+        &self,
+        record: &[u8],
+        options: StreamOptions,
+    ) -> Result<Self::Response, StreamFormatError> {
+        match self.expects_response() {
+            true => IncomingRecord::parse_record_with(record, options).map(Some),
+            false => Ok(None),
+        }
+    }
+}
+
+impl From<&WriteStructuredField> for Vec<u8> {
+    fn from(val: &WriteStructuredField) -> Self {
+        let mut result = vec![];
+        val.serialize(&mut result);
+        result
+    }
+}
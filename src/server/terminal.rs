@@ -0,0 +1,161 @@
+use crate::server::query::QueryReply;
+use crate::server::stream::{AddressMode, BufferAddressCalculator};
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct ScreenSize {
+    pub rows: u16,
+    pub cols: u16,
+}
+
+impl ScreenSize {
+    // Every display supports this size as its primary screen
+    pub const DEFAULT: ScreenSize = ScreenSize { rows: 24, cols: 80 };
+
+    pub fn address_calculator(self) -> BufferAddressCalculator {
+        BufferAddressCalculator { width: self.cols, height: self.rows }
+    }
+
+    // Size of the alternate screen of a 3278/3279 model
+    pub fn for_model(model: u8) -> Option<ScreenSize> {
+        match model {
+            2 => Some(ScreenSize { rows: 24, cols: 80 }),
+            3 => Some(ScreenSize { rows: 32, cols: 80 }),
+            4 => Some(ScreenSize { rows: 43, cols: 80 }),
+            5 => Some(ScreenSize { rows: 27, cols: 132 }),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum TerminalKind {
+    Ibm3278,
+    Ibm3279,
+    // The size is not implied by the name and has to be queried
+    Dynamic,
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct TerminalModel {
+    pub kind: TerminalKind,
+    // 2 to 5 for 3278/3279 terminals
+    pub model: Option<u8>,
+    pub primary: ScreenSize,
+    pub alternate: ScreenSize,
+    // Supports the extended data stream (SFE, SA, MF and structured fields)
+    pub extended: bool,
+    pub color: bool,
+    // The widest buffer addressing the terminal supports
+    pub addressing: AddressMode,
+}
+
+impl Default for TerminalModel {
+    // IBM-3278-2, which every 3270 emulator can handle
+    fn default() -> Self {
+        TerminalModel {
+            kind: TerminalKind::Ibm3278,
+            model: Some(2),
+            primary: ScreenSize::DEFAULT,
+            alternate: ScreenSize::DEFAULT,
+            extended: false,
+            color: false,
+            addressing: AddressMode::FourteenBit,
+        }
+    }
+}
+
+impl TerminalModel {
+    // Parses a terminal type as sent in TTYPE or a TN3270E DEVICE-TYPE, e.g. `IBM-3279-4-E`
+    pub fn parse(term_type: &str) -> Option<Self> {
+        let term_type = term_type.to_ascii_uppercase();
+        let name = term_type.strip_prefix("IBM-")?;
+        if name == "DYNAMIC" {
+            return Some(TerminalModel {
+                kind: TerminalKind::Dynamic,
+                model: None,
+                primary: ScreenSize::DEFAULT,
+                alternate: ScreenSize::DEFAULT,
+                extended: true,
+                color: true,
+                addressing: AddressMode::FourteenBit,
+            });
+        }
+
+        let (name, extended) = match name.strip_suffix("-E") {
+            Some(name) => (name, true),
+            None => (name, false),
+        };
+        let (kind, model) = name.split_once('-')?;
+        let kind = match kind {
+            "3278" => TerminalKind::Ibm3278,
+            "3279" => TerminalKind::Ibm3279,
+            _ => return None,
+        };
+        let model = model.parse().ok()?;
+        Some(TerminalModel {
+            kind,
+            model: Some(model),
+            primary: ScreenSize::DEFAULT,
+            alternate: ScreenSize::for_model(model)?,
+            extended,
+            color: kind == TerminalKind::Ibm3279,
+            addressing: AddressMode::FourteenBit,
+        })
+    }
+
+    // The largest screen the terminal offers, which is the one screens are drawn on
+    pub fn screen_size(&self) -> ScreenSize {
+        self.alternate
+    }
+
+    // Whether the alternate screen differs from the primary one and needs EraseWriteAlternate
+    pub fn uses_alternate(&self) -> bool {
+        self.alternate != self.primary
+    }
+
+    // Addressing used for records sent to the terminal: 12-bit wherever the screen allows it,
+    // 16-bit only for screens too large for 14 bits and if the terminal supports it
+    pub fn address_mode(&self) -> AddressMode {
+        let size = self.screen_size();
+        AddressMode::for_buffer_size(size.rows as usize * size.cols as usize).min(self.addressing)
+    }
+
+    // Refines the model with what the terminal reported, which is the only way to learn the size
+    // of an IBM-DYNAMIC terminal
+    pub fn apply_query_replies(&mut self, replies: &[QueryReply]) {
+        if replies.is_empty() {
+            return;
+        }
+        // Only terminals with the extended data stream answer a query
+        self.extended = true;
+        for reply in replies {
+            match reply {
+                QueryReply::UsableArea(area) => {
+                    self.addressing = if area.supports_16bit_addressing() {
+                        AddressMode::SixteenBit
+                    } else if area.supports_14bit_addressing() {
+                        AddressMode::FourteenBit
+                    } else {
+                        AddressMode::TwelveBit
+                    };
+                    if !replies.iter().any(|r| matches!(r, QueryReply::ImplicitPartition { .. })) {
+                        self.alternate = area.screen_size();
+                    }
+                }
+                QueryReply::ImplicitPartition { default, alternate } => {
+                    self.primary = *default;
+                    self.alternate = *alternate;
+                }
+                QueryReply::Color { pairs, .. } => {
+                    // Monochrome terminals map every color attribute to the same one
+                    let mut colors: Vec<u8> =
+                        pairs.iter().map(|&(_, color)| color).filter(|&c| c != 0).collect();
+                    colors.sort_unstable();
+                    colors.dedup();
+                    self.color = colors.len() > 1;
+                }
+                _ => {}
+            }
+        }
+    }
+}
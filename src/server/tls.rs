@@ -0,0 +1,97 @@
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Instant;
+
+use rustls::pki_types::pem::PemObject;
+use rustls::pki_types::{CertificateDer, PrivateKeyDer};
+use rustls::{ServerConfig, ServerConnection, StreamOwned};
+use snafu::{ResultExt, Snafu};
+
+use crate::server::transport::Transport;
+
+#[derive(Snafu, Debug)]
+pub enum TlsError {
+    #[snafu(display("Failed to read PEM file {}: {}", path.display(), source))]
+    Pem { path: PathBuf, source: rustls::pki_types::pem::Error },
+    #[snafu(display("No certificate found in {}", path.display()))]
+    NoCertificate { path: PathBuf },
+    #[snafu(display("Invalid TLS configuration: {}", source))]
+    Config { source: rustls::Error },
+}
+
+#[derive(Clone, Debug)]
+pub struct TlsConfig {
+    config: Arc<ServerConfig>,
+}
+
+impl TlsConfig {
+    pub fn from_pem_files(
+        cert_path: impl AsRef<Path>,
+        key_path: impl AsRef<Path>,
+    ) -> Result<Self, TlsError> {
+        let cert_path = cert_path.as_ref();
+        let key_path = key_path.as_ref();
+
+        let certs = CertificateDer::pem_file_iter(cert_path)
+            .and_then(|certs| certs.collect::<Result<Vec<_>, _>>())
+            .context(PemSnafu { path: cert_path })?;
+        if certs.is_empty() {
+            return NoCertificateSnafu { path: cert_path }.fail();
+        }
+        let key = PrivateKeyDer::from_pem_file(key_path).context(PemSnafu { path: key_path })?;
+
+        let config =
+            ServerConfig::builder_with_provider(Arc::new(rustls::crypto::ring::default_provider()))
+                .with_safe_default_protocol_versions()
+                .context(ConfigSnafu)?
+                .with_no_client_auth()
+                .with_single_cert(certs, key)
+                .context(ConfigSnafu)?;
+
+        Ok(Self::from_server_config(Arc::new(config)))
+    }
+
+    // For callers that need client certificates, ALPN or a custom crypto provider
+    pub fn from_server_config(config: Arc<ServerConfig>) -> Self {
+        TlsConfig { config }
+    }
+
+    // `prefix` holds TLS bytes that were already read from the stream, e.g. a ClientHello that
+    // arrived together with the STARTTLS FOLLOWS subnegotiation. Every read waits until the
+    // deadline at most.
+    pub(crate) fn accept<S: Transport>(
+        &self,
+        mut stream: S,
+        mut prefix: &[u8],
+        deadline: Instant,
+    ) -> std::io::Result<StreamOwned<ServerConnection, S>> {
+        let mut conn = ServerConnection::new(self.config.clone()).map_err(std::io::Error::other)?;
+        while !prefix.is_empty() {
+            conn.read_tls(&mut prefix)?;
+            conn.process_new_packets().map_err(std::io::Error::other)?;
+        }
+        while conn.is_handshaking() {
+            let remaining = deadline
+                .checked_duration_since(Instant::now())
+                .filter(|remaining| !remaining.is_zero())
+                .ok_or(std::io::ErrorKind::TimedOut)?;
+            stream.set_read_timeout(Some(remaining))?;
+            conn.complete_io(&mut stream)?;
+        }
+        Ok(StreamOwned::new(conn, stream))
+    }
+}
+
+// Runs the handshake within the rest of the negotiation timeout and puts the read timeout the
+// stream had back afterwards
+pub(crate) fn handshake<T: Transport>(
+    config: &TlsConfig,
+    stream: T,
+    prefix: &[u8],
+    deadline: Instant,
+) -> std::io::Result<StreamOwned<ServerConnection, T>> {
+    let timeout = stream.read_timeout()?;
+    let mut stream = config.accept(stream, prefix, deadline)?;
+    stream.sock.set_read_timeout(timeout)?;
+    Ok(stream)
+}
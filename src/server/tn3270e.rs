@@ -0,0 +1,315 @@
+use std::sync::atomic::{AtomicU32, Ordering};
+
+use bitflags::bitflags;
+
+use crate::server::stream::StreamFormatError;
+
+// Telnet option code for TN3270E (RFC 2355)
+pub const TN3270E: u8 = 40;
+
+pub mod op {
+    pub const ASSOCIATE: u8 = 0x00;
+    pub const CONNECT: u8 = 0x01;
+    pub const DEVICE_TYPE: u8 = 0x02;
+    pub const FUNCTIONS: u8 = 0x03;
+    pub const IS: u8 = 0x04;
+    pub const REASON: u8 = 0x05;
+    pub const REJECT: u8 = 0x06;
+    pub const REQUEST: u8 = 0x07;
+    pub const SEND: u8 = 0x08;
+}
+
+bitflags! {
+    #[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+    pub struct Functions: u8 {
+        const BIND_IMAGE = 1 << 0x00;
+        const DATA_STREAM_CTL = 1 << 0x01;
+        const RESPONSES = 1 << 0x02;
+        const SCS_CTL_CODES = 1 << 0x03;
+        const SYSREQ = 1 << 0x04;
+    }
+}
+
+impl Functions {
+    // Unknown function codes are dropped, which means we never agree to them
+    pub fn from_codes(codes: &[u8]) -> Self {
+        codes
+            .iter()
+            .filter(|&&code| code < 8)
+            .fold(Functions::empty(), |acc, &code| acc | Functions::from_bits_truncate(1 << code))
+    }
+
+    pub fn to_codes(self) -> Vec<u8> {
+        (0..8u8).filter(|code| self.bits() & (1 << code) != 0).collect()
+    }
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum RejectReason {
+    ConnPartner,
+    DeviceInUse,
+    InvalidAssociate,
+    InvalidName,
+    InvalidDeviceType,
+    TypeNameError,
+    UnknownError,
+    UnsupportedRequest,
+}
+
+impl From<RejectReason> for u8 {
+    fn from(reason: RejectReason) -> u8 {
+        match reason {
+            RejectReason::ConnPartner => 0x00,
+            RejectReason::DeviceInUse => 0x01,
+            RejectReason::InvalidAssociate => 0x02,
+            RejectReason::InvalidName => 0x03,
+            RejectReason::InvalidDeviceType => 0x04,
+            RejectReason::TypeNameError => 0x05,
+            RejectReason::UnknownError => 0x06,
+            RejectReason::UnsupportedRequest => 0x07,
+        }
+    }
+}
+
+impl TryFrom<u8> for RejectReason {
+    type Error = StreamFormatError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        Ok(match value {
+            0x00 => RejectReason::ConnPartner,
+            0x01 => RejectReason::DeviceInUse,
+            0x02 => RejectReason::InvalidAssociate,
+            0x03 => RejectReason::InvalidName,
+            0x04 => RejectReason::InvalidDeviceType,
+            0x05 => RejectReason::TypeNameError,
+            0x06 => RejectReason::UnknownError,
+            0x07 => RejectReason::UnsupportedRequest,
+            _ => return Err(StreamFormatError::InvalidData),
+        })
+    }
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum DataType {
+    Data3270,
+    ScsData,
+    Response,
+    BindImage,
+    Unbind,
+    NvtData,
+    Request,
+    SscpLuData,
+    PrintEoj,
+}
+
+impl From<DataType> for u8 {
+    fn from(typ: DataType) -> u8 {
+        match typ {
+            DataType::Data3270 => 0x00,
+            DataType::ScsData => 0x01,
+            DataType::Response => 0x02,
+            DataType::BindImage => 0x03,
+            DataType::Unbind => 0x04,
+            DataType::NvtData => 0x05,
+            DataType::Request => 0x06,
+            DataType::SscpLuData => 0x07,
+            DataType::PrintEoj => 0x08,
+        }
+    }
+}
+
+impl TryFrom<u8> for DataType {
+    type Error = StreamFormatError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        Ok(match value {
+            0x00 => DataType::Data3270,
+            0x01 => DataType::ScsData,
+            0x02 => DataType::Response,
+            0x03 => DataType::BindImage,
+            0x04 => DataType::Unbind,
+            0x05 => DataType::NvtData,
+            0x06 => DataType::Request,
+            0x07 => DataType::SscpLuData,
+            0x08 => DataType::PrintEoj,
+            _ => return Err(StreamFormatError::InvalidData),
+        })
+    }
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Header {
+    pub data_type: DataType,
+    pub request_flag: u8,
+    pub response_flag: u8,
+    pub seq_number: u16,
+}
+
+impl Header {
+    pub const LEN: usize = 5;
+
+    pub fn data(seq_number: u16) -> Self {
+        Header { data_type: DataType::Data3270, request_flag: 0, response_flag: 0, seq_number }
+    }
+
+    pub fn encode(&self) -> [u8; Header::LEN] {
+        [
+            self.data_type.into(),
+            self.request_flag,
+            self.response_flag,
+            (self.seq_number >> 8) as u8,
+            (self.seq_number & 0xff) as u8,
+        ]
+    }
+
+    pub fn parse(record: &[u8]) -> Result<(Self, &[u8]), StreamFormatError> {
+        if record.len() < Header::LEN {
+            return Err(StreamFormatError::UnexpectedEOR);
+        }
+        let (header, body) = record.split_at(Header::LEN);
+        Ok((
+            Header {
+                data_type: DataType::try_from(header[0])?,
+                request_flag: header[1],
+                response_flag: header[2],
+                seq_number: ((header[3] as u16) << 8) | header[4] as u16,
+            },
+            body,
+        ))
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DeviceName {
+    Connect(String),
+    Associate(String),
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Message {
+    SendDeviceType,
+    DeviceTypeRequest { device_type: String, name: Option<DeviceName> },
+    DeviceTypeIs { device_type: String, device_name: String },
+    DeviceTypeReject(RejectReason),
+    FunctionsRequest(Functions),
+    FunctionsIs(Functions),
+}
+
+fn parse_name(data: &[u8]) -> Result<String, StreamFormatError> {
+    if !data.iter().all(|b| b.is_ascii_graphic()) {
+        return Err(StreamFormatError::InvalidData);
+    }
+    Ok(String::from_utf8_lossy(data).into_owned())
+}
+
+impl Message {
+    pub fn parse(buffer: &[u8]) -> Result<Self, StreamFormatError> {
+        if buffer.len() < 2 {
+            return Err(StreamFormatError::UnexpectedEOR);
+        }
+        let body = &buffer[2..];
+        Ok(match (buffer[0], buffer[1]) {
+            (op::SEND, op::DEVICE_TYPE) => Message::SendDeviceType,
+            (op::DEVICE_TYPE, op::REQUEST) => {
+                let split = body.iter().position(|&b| b == op::CONNECT || b == op::ASSOCIATE);
+                let (device_type, name) = match split {
+                    Some(pos) => {
+                        let name = parse_name(&body[pos + 1..])?;
+                        let name = if body[pos] == op::CONNECT {
+                            DeviceName::Connect(name)
+                        } else {
+                            DeviceName::Associate(name)
+                        };
+                        (&body[..pos], Some(name))
+                    }
+                    None => (body, None),
+                };
+                Message::DeviceTypeRequest { device_type: parse_name(device_type)?, name }
+            }
+            (op::DEVICE_TYPE, op::IS) => {
+                let pos = body
+                    .iter()
+                    .position(|&b| b == op::CONNECT)
+                    .ok_or(StreamFormatError::InvalidData)?;
+                Message::DeviceTypeIs {
+                    device_type: parse_name(&body[..pos])?,
+                    device_name: parse_name(&body[pos + 1..])?,
+                }
+            }
+            (op::DEVICE_TYPE, op::REJECT) => {
+                if body.len() < 2 {
+                    return Err(StreamFormatError::UnexpectedEOR);
+                }
+                if body[0] != op::REASON {
+                    return Err(StreamFormatError::InvalidData);
+                }
+                Message::DeviceTypeReject(RejectReason::try_from(body[1])?)
+            }
+            (op::FUNCTIONS, op::REQUEST) => Message::FunctionsRequest(Functions::from_codes(body)),
+            (op::FUNCTIONS, op::IS) => Message::FunctionsIs(Functions::from_codes(body)),
+            _ => return Err(StreamFormatError::InvalidData),
+        })
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+        let mut output = vec![];
+        match self {
+            Message::SendDeviceType => output.extend_from_slice(&[op::SEND, op::DEVICE_TYPE]),
+            Message::DeviceTypeRequest { device_type, name } => {
+                output.extend_from_slice(&[op::DEVICE_TYPE, op::REQUEST]);
+                output.extend_from_slice(device_type.as_bytes());
+                match name {
+                    Some(DeviceName::Connect(name)) => {
+                        output.push(op::CONNECT);
+                        output.extend_from_slice(name.as_bytes());
+                    }
+                    Some(DeviceName::Associate(name)) => {
+                        output.push(op::ASSOCIATE);
+                        output.extend_from_slice(name.as_bytes());
+                    }
+                    None => {}
+                }
+            }
+            Message::DeviceTypeIs { device_type, device_name } => {
+                output.extend_from_slice(&[op::DEVICE_TYPE, op::IS]);
+                output.extend_from_slice(device_type.as_bytes());
+                output.push(op::CONNECT);
+                output.extend_from_slice(device_name.as_bytes());
+            }
+            Message::DeviceTypeReject(reason) => output.extend_from_slice(&[
+                op::DEVICE_TYPE,
+                op::REJECT,
+                op::REASON,
+                (*reason).into(),
+            ]),
+            Message::FunctionsRequest(functions) => {
+                output.extend_from_slice(&[op::FUNCTIONS, op::REQUEST]);
+                output.extend(functions.to_codes());
+            }
+            Message::FunctionsIs(functions) => {
+                output.extend_from_slice(&[op::FUNCTIONS, op::IS]);
+                output.extend(functions.to_codes());
+            }
+        }
+        output
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Device {
+    pub device_type: String,
+    pub device_name: String,
+    pub associate: Option<String>,
+    pub functions: Functions,
+}
+
+pub fn is_supported_device_type(device_type: &str) -> bool {
+    device_type == "IBM-DYNAMIC"
+        || ["IBM-3278-", "IBM-3279-", "IBM-3287-"].iter().any(|p| device_type.starts_with(p))
+}
+
+static NEXT_DEVICE_NAME: AtomicU32 = AtomicU32::new(1);
+
+pub fn generate_device_name() -> String {
+    format!("RS{:06}", NEXT_DEVICE_NAME.fetch_add(1, Ordering::Relaxed) % 1_000_000)
+}
@@ -0,0 +1,260 @@
+use std::collections::VecDeque;
+use std::io::{Read, Write};
+use std::net::TcpStream;
+use std::sync::{Arc, Condvar, Mutex};
+use std::time::{Duration, Instant};
+
+// Anything a `Session` can run over. Implementors without a notion of timeouts (files, recorded
+// streams, ...) can rely on the default implementation.
+pub trait Transport: Read + Write {
+    fn set_read_timeout(&mut self, timeout: Option<Duration>) -> std::io::Result<()> {
+        let _ = timeout;
+        Ok(())
+    }
+
+    // The timeout last set, so it can be put back after a temporary one
+    fn read_timeout(&self) -> std::io::Result<Option<Duration>> {
+        Ok(None)
+    }
+}
+
+impl Transport for TcpStream {
+    fn set_read_timeout(&mut self, timeout: Option<Duration>) -> std::io::Result<()> {
+        TcpStream::set_read_timeout(self, timeout)
+    }
+
+    fn read_timeout(&self) -> std::io::Result<Option<Duration>> {
+        TcpStream::read_timeout(self)
+    }
+}
+
+#[cfg(unix)]
+impl Transport for std::os::unix::net::UnixStream {
+    fn set_read_timeout(&mut self, timeout: Option<Duration>) -> std::io::Result<()> {
+        std::os::unix::net::UnixStream::set_read_timeout(self, timeout)
+    }
+
+    fn read_timeout(&self) -> std::io::Result<Option<Duration>> {
+        std::os::unix::net::UnixStream::read_timeout(self)
+    }
+}
+
+#[cfg(feature = "tls")]
+impl<T: Transport> Transport for rustls::StreamOwned<rustls::ServerConnection, T> {
+    fn set_read_timeout(&mut self, timeout: Option<Duration>) -> std::io::Result<()> {
+        self.sock.set_read_timeout(timeout)
+    }
+
+    fn read_timeout(&self) -> std::io::Result<Option<Duration>> {
+        self.sock.read_timeout()
+    }
+}
+
+impl<T: Transport + ?Sized> Transport for Box<T> {
+    fn set_read_timeout(&mut self, timeout: Option<Duration>) -> std::io::Result<()> {
+        (**self).set_read_timeout(timeout)
+    }
+
+    fn read_timeout(&self) -> std::io::Result<Option<Duration>> {
+        (**self).read_timeout()
+    }
+}
+
+impl<T: Transport + ?Sized> Transport for &mut T {
+    fn set_read_timeout(&mut self, timeout: Option<Duration>) -> std::io::Result<()> {
+        (**self).set_read_timeout(timeout)
+    }
+
+    fn read_timeout(&self) -> std::io::Result<Option<Duration>> {
+        (**self).read_timeout()
+    }
+}
+
+pub(crate) fn is_timeout(err: &std::io::Error) -> bool {
+    matches!(err.kind(), std::io::ErrorKind::WouldBlock | std::io::ErrorKind::TimedOut)
+}
+
+#[derive(Default)]
+struct Pipe {
+    data: VecDeque<u8>,
+    closed: bool,
+}
+
+#[derive(Default)]
+struct Channel {
+    pipe: Mutex<Pipe>,
+    ready: Condvar,
+}
+
+impl Channel {
+    fn close(&self) {
+        self.pipe.lock().unwrap().closed = true;
+        self.ready.notify_all();
+    }
+}
+
+// One end of an in-memory connection created by `duplex`
+pub struct MemoryStream {
+    incoming: Arc<Channel>,
+    outgoing: Arc<Channel>,
+    timeout: Option<Duration>,
+}
+
+pub fn duplex() -> (MemoryStream, MemoryStream) {
+    let a = Arc::new(Channel::default());
+    let b = Arc::new(Channel::default());
+    (
+        MemoryStream { incoming: a.clone(), outgoing: b.clone(), timeout: None },
+        MemoryStream { incoming: b, outgoing: a, timeout: None },
+    )
+}
+
+impl Read for MemoryStream {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let deadline = self.timeout.map(|timeout| Instant::now() + timeout);
+        let mut pipe = self.incoming.pipe.lock().unwrap();
+        while pipe.data.is_empty() && !pipe.closed {
+            pipe = match deadline {
+                Some(deadline) => {
+                    let now = Instant::now();
+                    if now >= deadline {
+                        return Err(std::io::ErrorKind::TimedOut.into());
+                    }
+                    self.incoming.ready.wait_timeout(pipe, deadline - now).unwrap().0
+                }
+                None => self.incoming.ready.wait(pipe).unwrap(),
+            };
+        }
+        let len = buf.len().min(pipe.data.len());
+        for (dst, src) in buf.iter_mut().zip(pipe.data.drain(..len)) {
+            *dst = src;
+        }
+        Ok(len)
+    }
+}
+
+impl Write for MemoryStream {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let mut pipe = self.outgoing.pipe.lock().unwrap();
+        if pipe.closed {
+            return Err(std::io::ErrorKind::BrokenPipe.into());
+        }
+        pipe.data.extend(buf);
+        self.outgoing.ready.notify_all();
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+impl Transport for MemoryStream {
+    fn set_read_timeout(&mut self, timeout: Option<Duration>) -> std::io::Result<()> {
+        self.timeout = timeout;
+        Ok(())
+    }
+
+    fn read_timeout(&self) -> std::io::Result<Option<Duration>> {
+        Ok(self.timeout)
+    }
+}
+
+impl Drop for MemoryStream {
+    fn drop(&mut self) {
+        self.incoming.close();
+        self.outgoing.close();
+    }
+}
+
+pub(crate) enum Stream<T: Read + Write> {
+    Plain(T),
+    #[cfg(feature = "tls")]
+    Tls(Box<rustls::StreamOwned<rustls::ServerConnection, T>>),
+    // Left behind when a STARTTLS handshake fails
+    #[cfg(feature = "tls")]
+    Closed,
+}
+
+#[cfg(feature = "tls")]
+fn not_connected() -> std::io::Error {
+    std::io::Error::from(std::io::ErrorKind::NotConnected)
+}
+
+impl<T: Transport> Stream<T> {
+    #[cfg(feature = "tls")]
+    pub fn start_tls(
+        &mut self,
+        config: &crate::server::tls::TlsConfig,
+        prefix: &[u8],
+        deadline: Instant,
+    ) -> std::io::Result<()> {
+        match std::mem::replace(self, Stream::Closed) {
+            Stream::Plain(stream) => {
+                let stream = crate::server::tls::handshake(config, stream, prefix, deadline)?;
+                *self = Stream::Tls(Box::new(stream));
+                Ok(())
+            }
+            other => {
+                *self = other;
+                Err(std::io::Error::other("TLS is already active"))
+            }
+        }
+    }
+}
+
+impl<T: Transport> Transport for Stream<T> {
+    fn set_read_timeout(&mut self, timeout: Option<Duration>) -> std::io::Result<()> {
+        match self {
+            Stream::Plain(stream) => stream.set_read_timeout(timeout),
+            #[cfg(feature = "tls")]
+            Stream::Tls(stream) => stream.set_read_timeout(timeout),
+            #[cfg(feature = "tls")]
+            Stream::Closed => Err(not_connected()),
+        }
+    }
+
+    fn read_timeout(&self) -> std::io::Result<Option<Duration>> {
+        match self {
+            Stream::Plain(stream) => stream.read_timeout(),
+            #[cfg(feature = "tls")]
+            Stream::Tls(stream) => stream.read_timeout(),
+            #[cfg(feature = "tls")]
+            Stream::Closed => Err(not_connected()),
+        }
+    }
+}
+
+impl<T: Read + Write> Read for Stream<T> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        match self {
+            Stream::Plain(stream) => stream.read(buf),
+            #[cfg(feature = "tls")]
+            Stream::Tls(stream) => stream.read(buf),
+            #[cfg(feature = "tls")]
+            Stream::Closed => Err(not_connected()),
+        }
+    }
+}
+
+impl<T: Read + Write> Write for Stream<T> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        match self {
+            Stream::Plain(stream) => stream.write(buf),
+            #[cfg(feature = "tls")]
+            Stream::Tls(stream) => stream.write(buf),
+            #[cfg(feature = "tls")]
+            Stream::Closed => Err(not_connected()),
+        }
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        match self {
+            Stream::Plain(stream) => stream.flush(),
+            #[cfg(feature = "tls")]
+            Stream::Tls(stream) => stream.flush(),
+            #[cfg(feature = "tls")]
+            Stream::Closed => Err(not_connected()),
+        }
+    }
+}
@@ -0,0 +1,278 @@
+use std::convert::TryFrom;
+use std::fmt;
+
+use crate::encoding::dbcs::decode_mixed;
+use crate::encoding::{Encoding, SI, SO};
+use crate::server::aid::AID;
+use crate::server::extended_field_attributes::ExtendedFieldAttribute;
+use crate::server::query::{QueryReply, parse_query_replies};
+use crate::server::stream::{CharacterSetState, StreamFormatError, StreamOptions, WriteOrder};
+use crate::server::wcc::FieldAttribute;
+
+// EBCDIC text borrowed from a record, decoded on demand. Text of mixed code pages keeps its SO
+// and SI, so its length is the number of buffer positions it takes.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct TextView<'a> {
+    raw: &'a [u8],
+    encoding: Encoding,
+    // Double byte from the start, as in DBCS fields
+    shifted: bool,
+}
+
+impl<'a> TextView<'a> {
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.raw
+    }
+
+    pub fn encoding(&self) -> Encoding {
+        self.encoding
+    }
+
+    pub fn len(&self) -> usize {
+        self.raw.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.raw.is_empty()
+    }
+
+    // SO and SI aren't characters and are left out
+    pub fn chars(&self) -> impl Iterator<Item = char> + 'a {
+        decode_mixed(self.raw, self.encoding, self.shifted)
+    }
+}
+
+impl fmt::Display for TextView<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.chars().try_for_each(|ch| fmt::Write::write_char(f, ch))
+    }
+}
+
+// Attribute type/value pairs borrowed from an SFE or MF order. They are validated when the order
+// is parsed.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct AttributesView<'a>(&'a [u8]);
+
+impl<'a> AttributesView<'a> {
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.0
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len() / 2
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = ExtendedFieldAttribute> + 'a {
+        self.0.chunks(2).filter_map(|pair| ExtendedFieldAttribute::try_from(pair).ok())
+    }
+}
+
+// An order of an inbound record, borrowing its data from the record
+#[derive(Clone, Debug)]
+pub enum OrderView<'a> {
+    StartField(FieldAttribute),
+    StartFieldExtended(AttributesView<'a>),
+    SetBufferAddress(u16),
+    SetAttribute(ExtendedFieldAttribute),
+    ModifyField(AttributesView<'a>),
+    InsertCursor(u16),
+    // See `WriteOrder::InsertCursorHere`
+    InsertCursorHere,
+    ProgramTab,
+    RepeatToAddress(u16, char),
+    RepeatGraphicEscape(u16, u8),
+    EraseUnprotectedToAddress(u16),
+    GraphicEscape(u8),
+    Text(TextView<'a>),
+}
+
+impl From<OrderView<'_>> for WriteOrder {
+    fn from(order: OrderView<'_>) -> Self {
+        match order {
+            OrderView::StartField(attr) => WriteOrder::StartField(attr),
+            OrderView::StartFieldExtended(attrs) => {
+                WriteOrder::StartFieldExtended(attrs.iter().collect())
+            }
+            OrderView::SetBufferAddress(addr) => WriteOrder::SetBufferAddress(addr),
+            OrderView::SetAttribute(attr) => WriteOrder::SetAttribute(attr),
+            OrderView::ModifyField(attrs) => WriteOrder::ModifyField(attrs.iter().collect()),
+            OrderView::InsertCursor(addr) => WriteOrder::InsertCursor(addr),
+            OrderView::InsertCursorHere => WriteOrder::InsertCursorHere,
+            OrderView::ProgramTab => WriteOrder::ProgramTab,
+            OrderView::RepeatToAddress(addr, ch) => WriteOrder::RepeatToAddress(addr, ch),
+            OrderView::RepeatGraphicEscape(addr, ch) => WriteOrder::RepeatGraphicEscape(addr, ch),
+            OrderView::EraseUnprotectedToAddress(addr) => {
+                WriteOrder::EraseUnprotectedToAddress(addr)
+            }
+            OrderView::GraphicEscape(ch) => WriteOrder::GraphicEscape(ch),
+            OrderView::Text(text) => WriteOrder::SendText(text.to_string()),
+        }
+    }
+}
+
+// The header of an inbound record. The orders are only parsed while iterating over them.
+#[derive(Copy, Clone, Debug)]
+pub struct RecordView<'a> {
+    pub aid: AID,
+    pub addr: u16,
+    // Only the AID was sent, see `AID::is_short_read`. The address is 0 then.
+    pub short_read: bool,
+    record: &'a [u8],
+    options: StreamOptions,
+}
+
+impl<'a> RecordView<'a> {
+    pub fn parse(record: &'a [u8]) -> Result<Self, StreamFormatError> {
+        Self::parse_with(record, StreamOptions::default())
+    }
+
+    // Only `AddressMode::SixteenBit` needs to be known, the other modes are detected
+    pub fn parse_with(record: &'a [u8], options: StreamOptions) -> Result<Self, StreamFormatError> {
+        let (&aid, rest) =
+            record.split_first().ok_or_else(|| StreamFormatError::UnexpectedEOR.at(0, None))?;
+        let aid = AID::try_from(aid).map_err(|error| error.at(0, None))?;
+        let short_read = rest.is_empty() && aid.is_short_read();
+        if aid == AID::StructuredField || short_read {
+            return Ok(Self { aid, addr: 0, short_read, record, options });
+        }
+
+        let addr =
+            rest.get(..2).ok_or_else(|| StreamFormatError::UnexpectedEOR.at(record.len(), None))?;
+        let addr = options.mode.decode(addr).map_err(|error| error.at(1, None))?;
+        Ok(Self { aid, addr, short_read, record, options })
+    }
+
+    // Records with `AID::StructuredField` and short reads have no orders
+    pub fn orders(&self) -> OrderIter<'a> {
+        let rest = match self.aid == AID::StructuredField || self.short_read {
+            true => &[],
+            false => &self.record[3..],
+        };
+        let charset = CharacterSetState::default();
+        OrderIter { record: self.record, rest, options: self.options, charset }
+    }
+
+    // Only records with `AID::StructuredField` have replies
+    pub fn query_replies(&self) -> Result<Vec<QueryReply>, StreamFormatError> {
+        match self.aid {
+            AID::StructuredField => {
+                parse_query_replies(&self.record[1..]).map_err(|error| error.at(1, None))
+            }
+            _ => Ok(vec![]),
+        }
+    }
+}
+
+// Iterates over the orders of a record. It stops after the first error, which carries the
+// offset and code of the order.
+#[derive(Clone, Debug)]
+pub struct OrderIter<'a> {
+    record: &'a [u8],
+    rest: &'a [u8],
+    options: StreamOptions,
+    // Read Buffer replies have the attributes of DBCS fields, whose text has no SO and SI
+    charset: CharacterSetState,
+}
+
+impl<'a> Iterator for OrderIter<'a> {
+    type Item = Result<OrderView<'a>, StreamFormatError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let &code = self.rest.first()?;
+        let offset = self.record.len() - self.rest.len();
+        match parse_order(self.rest, self.options, self.charset.is_dbcs()) {
+            Ok((order, len)) => {
+                match &order {
+                    OrderView::StartField(_) => self.charset.start_field([]),
+                    OrderView::StartFieldExtended(attrs) => self.charset.start_field(attrs.iter()),
+                    OrderView::ModifyField(attrs) => self.charset.modify_field(attrs.iter()),
+                    OrderView::SetAttribute(attr) => self.charset.set_attribute(attr),
+                    _ => {}
+                }
+                self.rest = &self.rest[len..];
+                Some(Ok(order))
+            }
+            Err(error) => {
+                self.rest = &[];
+                Some(Err(error.at(offset, Some(code))))
+            }
+        }
+    }
+}
+
+// Parses the order at the start of `record` and returns it with the number of bytes it took
+fn parse_order(
+    record: &[u8],
+    options: StreamOptions,
+    dbcs_field: bool,
+) -> Result<(OrderView<'_>, usize), StreamFormatError> {
+    let mixed = options.encoding.is_dbcs();
+    let address = || match record.get(1..3) {
+        Some(addr) => options.mode.decode(addr),
+        None => Err(StreamFormatError::UnexpectedEOR),
+    };
+    let attributes = || {
+        let count = *record.get(1).ok_or(StreamFormatError::UnexpectedEOR)? as usize;
+        let attrs = record.get(2..2 + count * 2).ok_or(StreamFormatError::UnexpectedEOR)?;
+        for pair in attrs.chunks(2) {
+            ExtendedFieldAttribute::try_from(pair)?;
+        }
+        Ok::<_, StreamFormatError>((AttributesView(attrs), 2 + count * 2))
+    };
+    Ok(match record[0] {
+        0x1D => {
+            let attr = *record.get(1).ok_or(StreamFormatError::UnexpectedEOR)?;
+            let attr =
+                FieldAttribute::from_bits(attr & 0x3F).ok_or(StreamFormatError::InvalidData)?;
+            (OrderView::StartField(attr), 2)
+        }
+        0x29 => {
+            let (attrs, len) = attributes()?;
+            (OrderView::StartFieldExtended(attrs), len)
+        }
+        // SBA followed by IC is how `WriteOrder::InsertCursor` is written, IC has no address
+        0x11 => match record.get(3) {
+            Some(0x13) => (OrderView::InsertCursor(address()?), 4),
+            _ => (OrderView::SetBufferAddress(address()?), 3),
+        },
+        0x28 => {
+            let attr = record.get(1..3).ok_or(StreamFormatError::UnexpectedEOR)?;
+            (OrderView::SetAttribute(ExtendedFieldAttribute::try_from(attr)?), 3)
+        }
+        0x2C => {
+            let (attrs, len) = attributes()?;
+            (OrderView::ModifyField(attrs), len)
+        }
+        0x13 => (OrderView::InsertCursorHere, 1),
+        0x05 => (OrderView::ProgramTab, 1),
+        0x3C => {
+            let addr = address()?;
+            match record.get(3..) {
+                Some([0x08, ch, ..]) => (OrderView::RepeatGraphicEscape(addr, *ch), 5),
+                Some([0x08]) | Some([]) | None => return Err(StreamFormatError::UnexpectedEOR),
+                Some([ch, ..]) => {
+                    (OrderView::RepeatToAddress(addr, options.encoding.decode_char(*ch)), 4)
+                }
+            }
+        }
+        0x12 => (OrderView::EraseUnprotectedToAddress(address()?), 3),
+        0x08 => {
+            let ch = *record.get(1).ok_or(StreamFormatError::UnexpectedEOR)?;
+            (OrderView::GraphicEscape(ch), 2)
+        }
+        // Only mixed code pages shift, SO and SI are invalid in any other text
+        SO | SI if mixed => text(record, options.encoding, dbcs_field),
+        0x40..=0xFF => text(record, options.encoding, dbcs_field && mixed),
+        _ => return Err(StreamFormatError::InvalidData),
+    })
+}
+
+fn text(record: &[u8], encoding: Encoding, shifted: bool) -> (OrderView<'_>, usize) {
+    let is_text = |v: u8| v >= 0x40 || (encoding.is_dbcs() && matches!(v, SO | SI));
+    let len = record.iter().position(|&v| !is_text(v)).unwrap_or(record.len());
+    (OrderView::Text(TextView { raw: &record[..len], encoding, shifted }), len)
+}
@@ -0,0 +1,174 @@
+#![cfg(feature = "async")]
+
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use rust3270::server::aid::AID;
+    use rust3270::server::async_session::AsyncSession;
+    use rust3270::server::query::ReadPartitionQuery;
+    use rust3270::server::screen::{Field, Screen};
+    use rust3270::server::stream::{WriteCommand, WriteCommandCode, WriteOrder};
+    use rust3270::server::wcc::WCC;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt, BufStream};
+
+    use crate::common::{DO, EOR, IAC, WONT, expect_async, negotiate_client_async};
+
+    #[tokio::test]
+    async fn test_negotiation_and_records() {
+        let (mut client, server) = tokio::io::duplex(4096);
+        let (session, ()) = tokio::join!(
+            AsyncSession::new(server),
+            negotiate_client_async(&mut client, "IBM-3278-2-E")
+        );
+        let mut session = session.unwrap();
+        assert_eq!(session.tn3270e().unwrap().device_name, "TERM01");
+
+        session.send_record(vec![0xF5, 0xC3]).await.unwrap();
+        expect_async(&mut client, &[0, 0, 0, 0, 0, 0xF5, 0xC3, IAC, EOR]).await;
+
+        assert_eq!(session.receive_record(Some(Duration::from_millis(50))).await.unwrap(), None);
+
+        // A record split over several reads is reassembled
+        client.write_all(&[0, 0, 0, 0, 0, 0x7D]).await.unwrap();
+        client.write_all(&[0x40, 0x40, IAC, EOR]).await.unwrap();
+        assert_eq!(session.receive_record(None).await.unwrap(), Some(vec![0x7D, 0x40, 0x40]));
+    }
+
+    #[tokio::test]
+    async fn test_buffered_stream() {
+        // Nothing reaches the client until the session flushes
+        let (mut client, server) = tokio::io::duplex(4096);
+        let negotiation = async {
+            tokio::join!(
+                AsyncSession::new(BufStream::new(server)),
+                negotiate_client_async(&mut client, "IBM-3278-2-E")
+            )
+        };
+        let (session, ()) =
+            tokio::time::timeout(Duration::from_secs(2), negotiation).await.unwrap();
+        let mut session = session.unwrap();
+
+        session.send_record(vec![0xF5, 0xC3]).await.unwrap();
+        expect_async(&mut client, &[0, 0, 0, 0, 0, 0xF5, 0xC3, IAC, EOR]).await;
+    }
+
+    #[tokio::test]
+    async fn test_present_screen() {
+        let (mut client, server) = tokio::io::duplex(4096);
+        let (session, ()) = tokio::join!(
+            AsyncSession::new(server),
+            negotiate_client_async(&mut client, "IBM-3278-2-E")
+        );
+        let mut session = session.unwrap();
+
+        let mut name = String::new();
+        let mut screen = Screen {
+            fields: vec![Field::at(1, 1).ro_text("Name:"), Field::at(1, 10).rw_text(&mut name)],
+        };
+        let terminal = async {
+            let mut buf = [0; 512];
+            let len = client.read(&mut buf).await.unwrap();
+            assert_eq!(&buf[5..7], [0xF5, 0xC3]);
+            assert_eq!(&buf[len - 2..len], [IAC, EOR]);
+            // Enter with cursor at 1,13 and "ABC" typed into the field at 1,10
+            client
+                .write_all(&[0, 0, 0, 0, 0, 0x7D, 0x40, 0xCC, 0x11, 0x40, 0xCA, 0xC1, 0xC2, 0xC3])
+                .await
+                .unwrap();
+            client.write_all(&[IAC, EOR]).await.unwrap();
+        };
+        let (response, ()) = tokio::join!(screen.present_async(&mut session), terminal);
+        let response = response.unwrap();
+        drop(screen);
+
+        assert_eq!(response.aid, AID::Enter);
+        assert_eq!((response.address.row, response.address.col), (0, 12));
+        assert_eq!(name, "ABC");
+    }
+
+    #[tokio::test]
+    async fn test_timeout_keeps_replies_whole() {
+        let (mut client, server) = tokio::io::duplex(64);
+        let (session, ()) = tokio::join!(
+            AsyncSession::new(server),
+            negotiate_client_async(&mut client, "IBM-3278-2-E")
+        );
+        let mut session = session.unwrap();
+
+        // The unread record leaves too little room in the pipe for the refusals until the client
+        // reads, long after the timeout
+        session.send_record(vec![0x40; 40]).await.unwrap();
+        let requests: Vec<u8> = (100..120).flat_map(|option| [IAC, DO, option]).collect();
+        let terminal = async {
+            client.write_all(&requests).await.unwrap();
+            tokio::time::sleep(Duration::from_millis(100)).await;
+            let mut output = vec![0; 5 + 40 + 2 + requests.len()];
+            // A reply cut off by the timeout would never arrive
+            let read = client.read_exact(&mut output);
+            tokio::time::timeout(Duration::from_secs(1), read).await.unwrap().unwrap();
+            output
+        };
+        let (record, output) =
+            tokio::join!(session.receive_record(Some(Duration::from_millis(20))), terminal);
+        assert_eq!(record.unwrap(), None);
+        let replies: Vec<u8> = (100..120).flat_map(|option| [IAC, WONT, option]).collect();
+        assert_eq!(output[47..], replies);
+
+        client.write_all(&[0, 0, 0, 0, 0, 0x7D, IAC, EOR]).await.unwrap();
+        assert_eq!(session.receive_record(None).await.unwrap(), Some(vec![0x7D]));
+    }
+
+    #[tokio::test]
+    async fn test_send() {
+        let (mut client, server) = tokio::io::duplex(4096);
+        let (session, ()) = tokio::join!(
+            AsyncSession::new(server),
+            negotiate_client_async(&mut client, "IBM-3278-2-E")
+        );
+        let mut session = session.unwrap();
+
+        // Without a keyboard restore nothing is waited for
+        let write = |wcc| WriteCommand {
+            command: WriteCommandCode::Write,
+            wcc,
+            orders: vec![WriteOrder::SetBufferAddress(80), WriteOrder::SendText("OK".into())],
+        };
+        assert!(session.send(&write(WCC::RESET_MDT)).await.unwrap().is_none());
+        expect_async(
+            &mut client,
+            &[0, 0, 0, 0, 0, 0xF1, 0xC1, 0x11, 0xC1, 0x50, 0xD6, 0xD2, IAC, EOR],
+        )
+        .await;
+
+        // A record that arrived before the command isn't taken for its answer
+        client.write_all(&[0, 0, 0, 0, 0, 0x7D, 0x40, 0x40, IAC, EOR]).await.unwrap();
+        client.write_all(&[0, 0, 0, 0, 0, 0x6D, IAC, EOR]).await.unwrap();
+        let record = session.receive_record(Some(Duration::from_secs(5))).await.unwrap();
+        assert_eq!(record, Some(vec![0x7D, 0x40, 0x40]));
+        let terminal = async {
+            expect_async(
+                &mut client,
+                &[0, 0, 0, 0, 1, 0xF1, 0xC2, 0x11, 0xC1, 0x50, 0xD6, 0xD2, IAC, EOR],
+            )
+            .await;
+            client.write_all(&[0, 0, 0, 0, 0, 0xF1, 0x40, 0x40, IAC, EOR]).await.unwrap();
+        };
+        let restore = write(WCC::KBD_RESTORE);
+        let (reply, ()) = tokio::join!(session.send(&restore), terminal);
+        assert_eq!(reply.unwrap().unwrap().aid, AID::PF1);
+
+        // Terminals without the extended data stream never answer queries
+        let terminal = expect_async(
+            &mut client,
+            &[0, 0, 0, 0, 2, 0xF3, 0x00, 0x05, 0x01, 0xFF, 0xFF, 0x02, IAC, EOR],
+        );
+        let (replies, ()) = tokio::join!(
+            session.query(&ReadPartitionQuery::Query, Some(Duration::from_millis(50))),
+            terminal
+        );
+        assert!(replies.unwrap().is_none());
+    }
+}
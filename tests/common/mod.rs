@@ -0,0 +1,85 @@
+// Terminal side of the TN3270E negotiation, shared by the session tests. Not every test file uses
+// all of it.
+#![allow(dead_code)]
+
+use std::io::{Read, Write};
+use std::thread;
+
+use rust3270::server::Session;
+use rust3270::server::tn3270e::{DeviceName, Functions, Message, TN3270E};
+use rust3270::server::transport::{MemoryStream, duplex};
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+
+pub const IAC: u8 = 255;
+pub const SB: u8 = 250;
+pub const SE: u8 = 240;
+pub const WILL: u8 = 251;
+pub const WONT: u8 = 252;
+pub const DO: u8 = 253;
+pub const EOR: u8 = 239;
+
+// Every test terminal asks for this name, so the DEVICE-TYPE IS reply is known
+pub const DEVICE_NAME: &str = "TERM01";
+
+pub fn sub(message: &Message) -> Vec<u8> {
+    let mut out = vec![IAC, SB, TN3270E];
+    out.extend(message.encode());
+    out.extend_from_slice(&[IAC, SE]);
+    out
+}
+
+fn device_type_request(device_type: &str) -> Vec<u8> {
+    sub(&Message::DeviceTypeRequest {
+        device_type: device_type.into(),
+        name: Some(DeviceName::Connect(DEVICE_NAME.into())),
+    })
+}
+
+fn device_type_is(device_type: &str) -> Vec<u8> {
+    sub(&Message::DeviceTypeIs { device_type: device_type.into(), device_name: DEVICE_NAME.into() })
+}
+
+pub fn expect<T: Read>(client: &mut T, expected: &[u8]) {
+    let mut buf = vec![0; expected.len()];
+    client.read_exact(&mut buf).unwrap();
+    assert_eq!(buf, expected);
+}
+
+pub fn negotiate_client<T: Read + Write>(client: &mut T, device_type: &str) {
+    expect(client, &[IAC, DO, TN3270E]);
+    client.write_all(&[IAC, WILL, TN3270E]).unwrap();
+    expect(client, &[IAC, DO, TN3270E]);
+    expect(client, &sub(&Message::SendDeviceType));
+    client.write_all(&device_type_request(device_type)).unwrap();
+    expect(client, &device_type_is(device_type));
+    client.write_all(&sub(&Message::FunctionsRequest(Functions::RESPONSES))).unwrap();
+    expect(client, &sub(&Message::FunctionsIs(Functions::RESPONSES)));
+}
+
+// A session over a memory stream, with the client end negotiated as `device_type`
+pub fn connect(device_type: &str) -> (MemoryStream, Session<MemoryStream>) {
+    let (mut client, server) = duplex();
+    let server = thread::spawn(move || Session::new(server).unwrap());
+    negotiate_client(&mut client, device_type);
+    (client, server.join().unwrap())
+}
+
+pub async fn expect_async<T: AsyncRead + Unpin>(client: &mut T, expected: &[u8]) {
+    let mut buf = vec![0; expected.len()];
+    client.read_exact(&mut buf).await.unwrap();
+    assert_eq!(buf, expected);
+}
+
+pub async fn negotiate_client_async<T: AsyncRead + AsyncWrite + Unpin>(
+    client: &mut T,
+    device_type: &str,
+) {
+    expect_async(client, &[IAC, DO, TN3270E]).await;
+    client.write_all(&[IAC, WILL, TN3270E]).await.unwrap();
+    expect_async(client, &[IAC, DO, TN3270E]).await;
+    expect_async(client, &sub(&Message::SendDeviceType)).await;
+    client.write_all(&device_type_request(device_type)).await.unwrap();
+    expect_async(client, &device_type_is(device_type)).await;
+    client.write_all(&sub(&Message::FunctionsRequest(Functions::RESPONSES))).await.unwrap();
+    expect_async(client, &sub(&Message::FunctionsIs(Functions::RESPONSES))).await;
+}
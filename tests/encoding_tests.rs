@@ -1,6 +1,14 @@
 #[cfg(test)]
 mod tests {
-    use rust3270::encoding::{Encoding, decode_to_ascii, encode_ascii_to};
+    use rust3270::encoding::{
+        CodePage, CodePageError, DBCS_SPACE, EncodeError, Encoder, Encoding, SI, SO, SUB,
+        Substitute, decode, decode_into, decode_to_ascii, ebcdic_to_latin1, encode_ascii_to,
+        encode_into, latin1_to_ebcdic,
+    };
+    use rust3270::server::extended_field_attributes::{DBCS_CHARACTER_SET, ExtendedFieldAttribute};
+    use rust3270::server::query::{CharacterSet, CharacterSets};
+    use rust3270::server::screen::Field;
+    use rust3270::server::stream::{StreamOptions, WriteOrder};
 
     fn encoding_under_test() -> Encoding {
         Encoding::CP037
@@ -38,7 +46,7 @@ mod tests {
     fn check_encode_table_consistency(encoding: &Encoding) {
         let tbl = encoding.encode_table();
         for (i, &b) in tbl.iter().enumerate() {
-            if i >= 0x20 && i <= 0x7E {
+            if (0x20..=0x7E).contains(&i) {
                 let c = i as u8 as char;
                 let encoded: Vec<u8> = encode_ascii_to(std::iter::once(c), encoding).collect();
                 assert_eq!(encoded[0], b, "Encoding mismatch for '{}'", c);
@@ -94,4 +102,414 @@ mod tests {
     fn test_decode_invalid_bytes() {
         check_decode_invalid_bytes(&encoding_under_test());
     }
+
+    // A character that tells the code page apart from CP037, with its code point there
+    const NATIONAL_CHARACTERS: [(Encoding, char, u8); 11] = [
+        (Encoding::CP037, '[', 0xBA),
+        (Encoding::CP273, 'Ä', 0x4A),
+        (Encoding::CP277, 'Æ', 0x7B),
+        (Encoding::CP278, 'Ä', 0x7B),
+        (Encoding::CP280, 'é', 0x5A),
+        (Encoding::CP284, 'Ñ', 0x7B),
+        (Encoding::CP285, '£', 0x5B),
+        (Encoding::CP297, 'à', 0x7C),
+        (Encoding::CP500, '[', 0x4A),
+        (Encoding::CP871, 'Þ', 0xC0),
+        (Encoding::CP1047, '[', 0xAD),
+    ];
+
+    #[test]
+    fn test_all_code_pages() {
+        for encoding in Encoding::ALL {
+            check_ascii_letters_encoding(&encoding);
+            check_ascii_letters_decoding(&encoding);
+            check_unmappable_char(&encoding);
+            check_encode_decode_round_trip_ascii(&encoding);
+            check_encode_table_consistency(&encoding);
+            check_decode_table_consistency(&encoding);
+            check_decode_invalid_bytes(&encoding);
+        }
+    }
+
+    #[test]
+    fn test_code_page_round_trip() {
+        // Every page covers all of Latin-1, so each byte decodes to a different character
+        for encoding in Encoding::ALL {
+            let (encode, decode) = (encoding.encode_table(), encoding.decode_table());
+            for byte in 0..=255u8 {
+                assert_eq!(
+                    encode[decode[byte as usize] as usize], byte,
+                    "{:?} {:02X}",
+                    encoding, byte
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_national_characters() {
+        for (encoding, ch, byte) in NATIONAL_CHARACTERS {
+            let encoded: Vec<u8> = encode_ascii_to(std::iter::once(ch), &encoding).collect();
+            assert_eq!(encoded, [byte], "{:?}", encoding);
+            let decoded: Vec<char> = decode_to_ascii(std::iter::once(byte), &encoding).collect();
+            assert_eq!(decoded, [ch], "{:?}", encoding);
+        }
+    }
+
+    #[test]
+    fn test_lookup() {
+        for encoding in Encoding::ALL {
+            assert_eq!(Encoding::from_ccsid(encoding.ccsid()), Some(encoding));
+            assert_eq!(Encoding::from_name(encoding.name()), Some(encoding));
+        }
+        assert_eq!(Encoding::from_ccsid(37), Some(Encoding::CP037));
+        assert_eq!(Encoding::from_ccsid(1140), Some(Encoding::CP1140));
+        assert_eq!(Encoding::from_ccsid(1150), None);
+        for name in ["cp273", "IBM273", "ibm-273", "CCSID 273", "273", " 0273 "] {
+            assert_eq!(Encoding::from_name(name), Some(Encoding::CP273), "{}", name);
+        }
+        assert_eq!(Encoding::from_name("IBM-1047"), Some(Encoding::CP1047));
+        for name in ["", "CP", "UTF-8", "cp1252", "37x"] {
+            assert_eq!(Encoding::from_name(name), None, "{}", name);
+        }
+    }
+
+    #[test]
+    fn test_encoder() {
+        let encoder = Encoder::new(Encoding::CP273, Substitute::None);
+        let encoded = encoder.encode("Grüße, Ä!").unwrap();
+        assert_eq!(encoded.data, [0xC7, 0x99, 0xD0, 0xA1, 0x85, 0x6B, 0x40, 0x4A, 0x4F]);
+        assert!(encoded.is_lossless());
+        assert_eq!(decode(&encoded.data, Encoding::CP273), "Grüße, Ä!");
+        // The same bytes mean something else in CP037
+        assert_eq!(decode(&encoded.data, Encoding::CP037), "Gr}~e, ¢|");
+
+        assert_eq!(
+            encoder.encode("10 €").unwrap_err(),
+            EncodeError::Unmappable { ch: '€', offset: 3, encoding: Encoding::CP273 }
+        );
+        assert_eq!(
+            encoder.encode("a\tb").unwrap_err().to_string(),
+            "'\\t' at offset 1 has no mapping in CP273"
+        );
+    }
+
+    #[test]
+    fn test_substitutes() {
+        let text = "€ ☃\n";
+        for (substitute, byte) in [
+            (Substitute::Sub, SUB),
+            (Substitute::QuestionMark, 0x6F),
+            (Substitute::Custom(0x5C), 0x5C),
+        ] {
+            let encoded = Encoder::new(Encoding::CP037, substitute).encode(text).unwrap();
+            assert_eq!(encoded.data, [byte, 0x40, byte, byte]);
+            // Offsets are in bytes of the UTF-8 text
+            assert_eq!(encoded.lost, [(0, '€'), (4, '☃'), (7, '\n')]);
+        }
+
+        // The question mark is looked up in the target code page
+        let encoder = Encoder::new(Encoding::CP1047, Substitute::QuestionMark);
+        assert_eq!(encoder.encode("Ł").unwrap().data, [Encoding::CP1047.encode_char('?').unwrap()]);
+    }
+
+    #[test]
+    fn test_char_mapping() {
+        for encoding in Encoding::ALL {
+            for byte in 0..=255u8 {
+                assert_eq!(encoding.encode_char(encoding.decode_char(byte)), Some(byte));
+            }
+            assert_eq!(encoding.encode_char('€'), encoding.euro());
+        }
+    }
+
+    #[test]
+    fn test_euro_code_pages() {
+        let pairs = [
+            (Encoding::CP1140, Encoding::CP037),
+            (Encoding::CP1141, Encoding::CP273),
+            (Encoding::CP1142, Encoding::CP277),
+            (Encoding::CP1143, Encoding::CP278),
+            (Encoding::CP1144, Encoding::CP280),
+            (Encoding::CP1145, Encoding::CP284),
+            (Encoding::CP1146, Encoding::CP285),
+            (Encoding::CP1147, Encoding::CP297),
+            (Encoding::CP1148, Encoding::CP500),
+            (Encoding::CP1149, Encoding::CP871),
+        ];
+        for (euro, base) in pairs {
+            let position = euro.euro().unwrap();
+            assert_eq!(base.euro(), None);
+            assert_eq!(base.decode_char(position), '¤', "{:?}", base);
+            assert_eq!(euro.decode_char(position), '€', "{:?}", euro);
+            assert_eq!(euro.encode_char('¤'), None, "{:?}", euro);
+
+            // Apart from the euro sign the pages only differ where the published tables of the
+            // euro page moved a character
+            let swapped: &[u8] = match euro {
+                Encoding::CP1143 => &[0x71, 0xE0],
+                Encoding::CP1149 => &[0x4A, 0xC0],
+                _ => &[],
+            };
+            for byte in (0..=255u8).filter(|byte| *byte != position && !swapped.contains(byte)) {
+                assert_eq!(
+                    euro.decode_char(byte),
+                    base.decode_char(byte),
+                    "{:?} {:#04X}",
+                    euro,
+                    byte
+                );
+            }
+        }
+
+        let encoder = Encoder::new(Encoding::CP1141, Substitute::None);
+        let encoded = encoder.encode("10 €").unwrap();
+        assert_eq!(encoded.data, [0xF1, 0xF0, 0x40, 0x9F]);
+        assert_eq!(decode(&encoded.data, Encoding::CP1141), "10 €");
+        assert_eq!(decode(&encoded.data, Encoding::CP273), "10 ¤");
+    }
+
+    #[test]
+    fn test_mixed_code_pages() {
+        let samples: [(Encoding, &str, &[u8]); 5] = [
+            (Encoding::CP930, "ｱｲ日本", &[0x81, 0x82, SO, 0x45, 0x62, 0x45, 0x66, SI]),
+            (Encoding::CP933, "한국", &[SO, 0xD0, 0x65, 0x8A, 0x82, SI]),
+            (Encoding::CP935, "中国", &[SO, 0x5B, 0xCF, 0x4D, 0x9B, SI]),
+            (Encoding::CP937, "中國", &[SO, 0x4C, 0x84, 0x56, 0xCE, SI]),
+            (Encoding::CP939, "ABC日本abc", b"\xC1\xC2\xC3\x0E\x45\x62\x45\x66\x0F\x81\x82\x83"),
+        ];
+        for (encoding, text, bytes) in samples {
+            assert!(encoding.is_dbcs());
+            let encoded = Encoder::new(encoding, Substitute::None).encode(text).unwrap();
+            assert_eq!(encoded.data, bytes, "{:?}", encoding);
+            assert_eq!(decode(bytes, encoding), text, "{:?}", encoding);
+            assert_eq!(encoding.cells(text), bytes.len(), "{:?}", encoding);
+            assert_eq!(encoding.decode_dbcs(DBCS_SPACE), Some('\u{3000}'));
+            assert_eq!(Encoding::from_ccsid(encoding.ccsid()), Some(encoding));
+            let (sbcs, dbcs) = encoding.code_pages();
+            assert_eq!(Encoding::from_code_pages(sbcs, dbcs), Some(encoding));
+
+            for byte in (0x40..=0xFFu8).filter(|&byte| encoding.decode_char(byte) != '\u{FFFD}') {
+                assert_eq!(encoding.encode_char(encoding.decode_char(byte)), Some(byte));
+            }
+        }
+        assert!(Encoding::ALL.iter().all(|encoding| !encoding.is_dbcs()));
+        assert_eq!(Encoding::from_code_pages(1027, Some(300)), Some(Encoding::CP939));
+        assert_eq!(Encoding::from_code_pages(37, None), Some(Encoding::CP037));
+        assert_eq!(Encoding::from_name("IBM-930"), Some(Encoding::CP930));
+
+        // Neither half has it
+        assert_eq!(
+            Encoder::new(Encoding::CP930, Substitute::None).encode("ｱ☃").unwrap_err(),
+            EncodeError::Unmappable { ch: '☃', offset: 3, encoding: Encoding::CP930 }
+        );
+        // Characters with two codes are encoded to the preferred one
+        assert_eq!(Encoding::CP937.decode_dbcs(0x454A), Some('十'));
+        assert_eq!(Encoding::CP937.encode_dbcs('十'), Some(0x4C52));
+        // Half a double byte code and codes without a character
+        assert_eq!(decode(&[SO, 0x45, 0x62, 0x45, SI, 0xC1], Encoding::CP939), "日\u{FFFD}A");
+        assert_eq!(Encoding::CP939.decode_dbcs(0xFEFE), None);
+        assert_eq!(Encoding::CP037.decode_dbcs(DBCS_SPACE), None);
+    }
+
+    #[test]
+    fn test_field_width() {
+        let text = "日本 AB";
+        let field = Field::at(0, 0).ro_text(text);
+        assert_eq!(field.width(Encoding::CP939), 9);
+        assert_eq!(field.width(Encoding::CP037), 5);
+        // Only double byte characters and no SO or SI in DBCS fields
+        let field = field.with_attr(ExtendedFieldAttribute::CharacterSet(DBCS_CHARACTER_SET));
+        assert_eq!(field.width(Encoding::CP939), 10);
+    }
+
+    // A .ucm file with the mappings of a built-in page
+    fn ucm_of(encoding: Encoding, name: &str) -> String {
+        let mut ucm = format!(
+            "# Generated from {}\n<code_set_name> \"{}\"\n<uconv_class> \"SBCS\"\n\
+             <subchar> \\x3F\nCHARMAP\n",
+            encoding.name(),
+            name
+        );
+        for byte in 0..=255u8 {
+            let ch = encoding.decode_char(byte) as u32;
+            ucm.push_str(&format!("<U{:04X}> \\x{:02X} |0\n", ch, byte));
+        }
+        ucm + "END CHARMAP\n"
+    }
+
+    #[test]
+    fn test_ucm_code_page() {
+        let page = CodePage::parse_ucm(&ucm_of(Encoding::CP037, "ibm-37_P100-1995")).unwrap();
+        assert_eq!(
+            (page.name.as_str(), page.ccsid, page.is_dbcs()),
+            ("ibm-37_P100-1995", 37, false)
+        );
+        // Not registered, so it doesn't replace the built-in page
+        let encoding = page.into_encoding();
+        assert_eq!(Encoding::from_ccsid(37), Some(Encoding::CP037));
+        assert_ne!(encoding, Encoding::CP037);
+
+        // Loading the page again gives the same encoding, a different page doesn't
+        let ucm = ucm_of(Encoding::CP037, "ibm-37_P100-1995");
+        assert_eq!(CodePage::parse_ucm(&ucm).unwrap().into_encoding(), encoding);
+        let mut page = CodePage::parse_ucm(&ucm).unwrap();
+        page.code_pages = (37, Some(300));
+        assert_ne!(page.into_encoding(), encoding);
+        let ucm =
+            ucm.replace("<U005B> \\xBA", "<U005B> \\x4A").replace("<U00A2> \\x4A", "<U00A2> \\xBA");
+        assert_ne!(CodePage::parse_ucm(&ucm).unwrap().into_encoding(), encoding);
+
+        check_ascii_letters_encoding(&encoding);
+        check_ascii_letters_decoding(&encoding);
+        check_unmappable_char(&encoding);
+        check_encode_decode_round_trip_ascii(&encoding);
+        check_encode_table_consistency(&encoding);
+        check_decode_table_consistency(&encoding);
+        check_decode_invalid_bytes(&encoding);
+        assert_eq!(encoding.encode_table(), Encoding::CP037.encode_table());
+        assert_eq!(encoding.decode_table(), Encoding::CP037.decode_table());
+        for byte in 0..=255u8 {
+            assert_eq!(encoding.encode_char(encoding.decode_char(byte)), Some(byte));
+        }
+
+        // Anywhere a built-in page goes
+        let options = StreamOptions { encoding, ..StreamOptions::default() };
+        let mut output = vec![];
+        WriteOrder::SendText("[a]".into()).serialize_with(&mut output, options);
+        assert_eq!(output, [0xBA, 0x81, 0xBB]);
+    }
+
+    #[test]
+    fn test_register_code_page() {
+        // A site specific page: CP273 with the brackets of CP037
+        let ucm = ucm_of(Encoding::CP273, "ibm-65001_X100")
+            .replace("<U005B> \\x63", "<U005B> \\xBA")
+            .replace("<U005D> \\xFC", "<U005D> \\xBB")
+            .replace("<U00AC> \\xBA", "<U00AC> \\x63")
+            .replace("<U007C> \\xBB", "<U007C> \\xFC");
+        let path = std::env::temp_dir().join(format!("rust3270-{}.ucm", std::process::id()));
+        std::fs::write(&path, &ucm).unwrap();
+        let page = CodePage::load_ucm(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert!(matches!(CodePage::load_ucm(&path), Err(CodePageError::Read { .. })));
+
+        assert_eq!(page.ccsid, 65001);
+        let encoding = page.register();
+        assert_eq!(Encoding::from_ccsid(65001), Some(encoding));
+        // Registering the same page again keeps one copy
+        assert_eq!(CodePage::parse_ucm(&ucm).unwrap().register(), encoding);
+        assert_eq!(Encoding::from_name("IBM-65001_x100"), Some(encoding));
+        assert_eq!(Encoding::from_name("CCSID 65001"), Some(encoding));
+        assert_eq!(encoding.name(), "ibm-65001_X100");
+        assert_eq!(decode(&[0x4A, 0xBA, 0xBB], encoding), "Ä[]");
+
+        // Terminals reporting the page in their Character Sets reply get it
+        let set = CharacterSet {
+            set: 0x00,
+            flags: 0x00,
+            lcid: 0x00,
+            cell_size: None,
+            subsection: None,
+            cgcsgid: Some(0x0001_FDE9),
+        };
+        let sets = CharacterSets {
+            flags1: 0x82,
+            flags2: 0,
+            default_cell_width: 9,
+            default_cell_height: 12,
+            load_formats: 0,
+            sets: vec![set],
+        };
+        assert_eq!(sets.encoding(), Some(encoding));
+    }
+
+    #[test]
+    fn test_stateful_ucm_code_page() {
+        let ucm = "<code_set_name> \"ibm-65002\"\n<uconv_class> \"EBCDIC_STATEFUL\"\nCHARMAP\n\
+                   <U0020> \\x40 |0\n<U0041> \\xC1 |0\n<U3000> \\x40\\x40 |0\n\
+                   <U65E5> \\x45\\x62 |0\n<U672C> \\x45\\x66 |0\n\
+                   <U2F47> \\x45\\x62 |1 # Kangxi radical sun\n<U5186> \\x45\\x67 |3\n\
+                   <U0041><U030A> \\xC1 |0\nEND CHARMAP\n";
+        let encoding = CodePage::parse_ucm(ucm).unwrap().into_encoding();
+        assert!(encoding.is_dbcs());
+        let encoded = Encoder::new(encoding, Substitute::None).encode("A日本 ⽇").unwrap();
+        assert_eq!(encoded.data, [0xC1, SO, 0x45, 0x62, 0x45, 0x66, SI, 0x40, SO, 0x45, 0x62, SI]);
+        assert_eq!(decode(&encoded.data, encoding), "A日本 日");
+        assert_eq!(encoding.decode_dbcs(0x4567), Some('円'));
+        assert_eq!(encoding.encode_dbcs('円'), None);
+        assert_eq!(encoding.cells("A日"), 5);
+    }
+
+    #[test]
+    fn test_invalid_ucm_code_pages() {
+        let ucm = ucm_of(Encoding::CP037, "ibm-37");
+        let error =
+            CodePage::parse_ucm(&ucm.replace("<U0041> \\xC1", "<U0042> \\xC1")).unwrap_err();
+        assert!(matches!(error, CodePageError::Ambiguous { .. }), "{}", error);
+        let error = CodePage::parse_ucm(&ucm.replace("<U0041> \\xC1 |0", "<U0041> \\xC2 |0"));
+        assert!(matches!(error, Err(CodePageError::Ambiguous { line: 200, .. })));
+        // Fallbacks don't make a mapping ambiguous
+        let ucm_with_fallback = ucm.replace("END CHARMAP", "<U0100> \\xC1 |1\nEND CHARMAP");
+        let encoding = CodePage::parse_ucm(&ucm_with_fallback).unwrap().into_encoding();
+        assert_eq!((encoding.encode_char('Ā'), encoding.decode_char(0xC1)), (Some(0xC1), 'A'));
+
+        let error = CodePage::parse_ucm(&ucm.replace("<U0020> \\x40 |0\n", ""));
+        assert!(matches!(error, Err(CodePageError::NoSpace)));
+        let error = CodePage::parse_ucm(&ucm.replace("\"SBCS\"", "\"MBCS\"")).unwrap_err();
+        assert_eq!(error.to_string(), "Unsupported conversion class MBCS");
+        let error = CodePage::parse_ucm(&ucm.replace("<U0041> \\xC1", "<U0041> C1")).unwrap_err();
+        assert_eq!(error.to_string(), "Line 199: expected bytes like \\x41");
+        let error = CodePage::parse_ucm(&ucm.replace("<U0041> \\xC1", "<U0041> \\xC1\\xC1"));
+        assert!(matches!(error, Err(CodePageError::Syntax { line: 199, .. })));
+    }
+
+    #[test]
+    fn test_bulk_code_pages() {
+        let text = "Hello, World! Ünïcödé € [x] 日本\u{7}";
+        let all_bytes: Vec<u8> = (0..=255).collect();
+        for encoding in Encoding::ALL {
+            let mut encoded = vec![0xFF];
+            encode_into(text, encoding, &mut encoded);
+            let expected = text
+                .chars()
+                .map(|ch| Encoder::new(encoding, Substitute::None).encode_char(ch).unwrap_or(0x40));
+            assert!(encoded[1..].iter().copied().eq(expected), "{}", encoding.name());
+            assert_eq!(encoded[0], 0xFF, "appends");
+
+            let mut decoded = String::from(">");
+            decode_into(&all_bytes, encoding, &mut decoded);
+            let expected: String = all_bytes.iter().map(|&v| encoding.decode_char(v)).collect();
+            assert_eq!(decoded, format!(">{}", expected), "{}", encoding.name());
+
+            let mut data = all_bytes.clone();
+            ebcdic_to_latin1(&mut data, encoding);
+            assert_eq!(data, encoding.decode_table());
+            latin1_to_ebcdic(&mut data, encoding);
+            assert_eq!(data, all_bytes, "{}", encoding.name());
+        }
+
+        // Mixed pages shift like `Encoder`
+        let mut encoded = vec![];
+        encode_into("ABC日本abc", Encoding::CP939, &mut encoded);
+        assert_eq!(encoded, [0xC1, 0xC2, 0xC3, SO, 0x45, 0x62, 0x45, 0x66, SI, 0x81, 0x82, 0x83]);
+        let mut decoded = String::new();
+        decode_into(&encoded, Encoding::CP939, &mut decoded);
+        assert_eq!(decoded, "ABC日本abc");
+        let mut encoded = vec![];
+        encode_into("日A本\u{7}b", Encoding::CP939, &mut encoded);
+        assert_eq!(encoded, [SO, 0x45, 0x62, SI, 0xC1, SO, 0x45, 0x66, SI, 0x40, 0x82]);
+
+        // Single bytes of mixed pages are found by character, like a scan of the page would
+        for encoding in
+            [Encoding::CP930, Encoding::CP933, Encoding::CP935, Encoding::CP937, Encoding::CP939]
+        {
+            for byte in 0..=255 {
+                let ch = encoding.decode_char(byte);
+                let expected = (ch != '\u{FFFD}').then_some(byte);
+                assert_eq!(encoding.encode_char(ch), expected, "{} {:02x}", encoding.name(), byte);
+            }
+            assert_eq!(encoding.encode_char('日'), None);
+        }
+    }
 }
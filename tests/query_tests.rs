@@ -0,0 +1,218 @@
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+    use std::thread;
+    use std::time::Duration;
+
+    use rust3270::encoding::Encoding;
+    use rust3270::server::aid::AID;
+    use rust3270::server::query::{
+        CharacterSet, CharacterSets, DbcsAsia, QueryCode, QueryReply, ReadPartitionQuery,
+    };
+    use rust3270::server::stream::{
+        IncomingRecord, OutputRecord, StreamFormatError, StreamOptions,
+    };
+    use rust3270::server::terminal::ScreenSize;
+
+    use crate::common::{EOR, IAC, connect, expect};
+
+    // Query Replies of a 3279 model 4 with APL and DBCS support
+    const REPLIES: &[u8] = &[
+        0x88, // AID
+        0x00, 0x0C, 0x81, 0x80, 0x80, 0x81, 0x85, 0x86, 0x87, 0x88, 0x91, 0xA6, // Summary
+        0x00, 0x17, 0x81, 0x81, 0x01, 0x00, 0x00, 0x50, 0x00, 0x2B, 0x01, 0x00, 0x0A, 0x02, 0xE5,
+        0x00, 0x02, 0x00, 0x6F, 0x09, 0x0C, 0x0D, 0x70, // Usable Area
+        0x00, 0x11, 0x81, 0xA6, 0x00, 0x00, 0x0B, 0x01, 0x00, 0x00, 0x50, 0x00, 0x18, 0x00, 0x50,
+        0x00, 0x2B, // Implicit Partition
+        0x00, 0x0A, 0x81, 0x86, 0x00, 0x02, 0x00, 0xF4, 0xF1, 0xF1, // Color
+        0x00, 0x09, 0x81, 0x87, 0x02, 0x00, 0xF0, 0xF1, 0xF1, // Highlight
+        0x00, 0x23, 0x81, 0x85, 0x82, 0x00, 0x07, 0x0C, 0x00, 0x00, 0x00, 0x00, 0x0B, 0x00, 0x10,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0xB9, 0x00, 0x25, 0x80, 0x00, 0xF8, 0x00, 0x00, 0x41,
+        0x7F, 0x02, 0xB9, 0x01, 0x2C, // Character Sets
+        0x00, 0x07, 0x81, 0x88, 0x00, 0x01, 0x02, // Reply Modes
+        0x00, 0x0B, 0x81, 0x91, 0x00, 0x03, 0x01, 0x80, 0x03, 0x02, 0x01, // DBCS-Asia
+    ];
+
+    #[test]
+    fn test_serialize_queries() {
+        assert_eq!(
+            Vec::<u8>::from(&ReadPartitionQuery::Query),
+            [0xF3, 0x00, 0x05, 0x01, 0xFF, 0x02]
+        );
+        assert_eq!(
+            Vec::<u8>::from(&ReadPartitionQuery::List(vec![
+                QueryCode::UsableArea,
+                QueryCode::CharacterSets
+            ])),
+            [0xF3, 0x00, 0x08, 0x01, 0xFF, 0x03, 0x00, 0x81, 0x85]
+        );
+        assert_eq!(
+            Vec::<u8>::from(&ReadPartitionQuery::All),
+            [0xF3, 0x00, 0x06, 0x01, 0xFF, 0x03, 0x80]
+        );
+        let mut written = vec![];
+        ReadPartitionQuery::All.write_to(&mut written, StreamOptions::default()).unwrap();
+        assert_eq!(written, [0xF3, 0x00, 0x06, 0x01, 0xFF, 0x03, 0x80]);
+
+        // Anything but query replies is the wrong answer
+        let error =
+            ReadPartitionQuery::All.parse_response(&[0x7D, 0x40, 0x40], StreamOptions::default());
+        assert_eq!(error.unwrap_err(), StreamFormatError::NotQueryReplies { aid: AID::Enter });
+    }
+
+    #[test]
+    fn test_parse_replies() {
+        let record = IncomingRecord::parse_record(REPLIES).unwrap();
+        assert_eq!(record.aid, AID::StructuredField);
+        assert!(record.orders.is_empty());
+
+        let replies = record.query_replies;
+        assert_eq!(replies.len(), 8);
+        assert_eq!(replies[0].code(), QueryCode::Summary);
+        let QueryReply::Summary(codes) = &replies[0] else { panic!() };
+        assert!(codes.contains(&QueryCode::DbcsAsia));
+
+        let QueryReply::UsableArea(area) = &replies[1] else { panic!() };
+        assert_eq!(area.screen_size(), ScreenSize { rows: 43, cols: 80 });
+        assert!(area.supports_14bit_addressing() && !area.supports_16bit_addressing());
+        assert_eq!((area.cell_width, area.cell_height, area.buffer_size), (9, 12, 3440));
+
+        assert_eq!(
+            replies[2],
+            QueryReply::ImplicitPartition {
+                default: ScreenSize { rows: 24, cols: 80 },
+                alternate: ScreenSize { rows: 43, cols: 80 },
+            }
+        );
+        assert_eq!(
+            replies[3],
+            QueryReply::Color { flags: 0, pairs: vec![(0x00, 0xF4), (0xF1, 0xF1)] }
+        );
+        assert_eq!(replies[4], QueryReply::Highlight(vec![(0x00, 0xF0), (0xF1, 0xF1)]));
+
+        let QueryReply::CharacterSets(sets) = &replies[5] else { panic!() };
+        assert_eq!((sets.default_cell_width, sets.default_cell_height), (7, 12));
+        assert_eq!(
+            sets.sets,
+            [
+                CharacterSet {
+                    set: 0x00,
+                    flags: 0x10,
+                    lcid: 0x00,
+                    cell_size: Some((0, 0)),
+                    subsection: Some((0, 0)),
+                    cgcsgid: Some(0x02B9_0025),
+                },
+                CharacterSet {
+                    set: 0x80,
+                    flags: 0x00,
+                    lcid: 0xF8,
+                    cell_size: Some((0, 0)),
+                    subsection: Some((0x41, 0x7F)),
+                    cgcsgid: Some(0x02B9_012C),
+                },
+            ]
+        );
+        assert_eq!(sets.sets[1].code_page(), Some(300));
+        assert_eq!(sets.sets[0].encoding(), Some(Encoding::CP037));
+        // DBCS pages aren't single byte encodings
+        assert_eq!(sets.sets[1].encoding(), None);
+        // There is no mixed page of CP037 and CCSID 300, but there is one with CCSID 1027
+        assert_eq!(sets.encoding(), Some(Encoding::CP037));
+        let mut mixed = sets.clone();
+        mixed.sets[0].cgcsgid = Some(0x0494_0403);
+        assert_eq!(mixed.encoding(), Some(Encoding::CP939));
+        mixed.sets.truncate(1);
+        assert_eq!(mixed.encoding(), None);
+
+        assert_eq!(replies[6], QueryReply::ReplyModes(vec![0, 1, 2]));
+        assert_eq!(
+            replies[7],
+            QueryReply::DbcsAsia(DbcsAsia {
+                flags: 0,
+                sosi_set: Some(0x80),
+                input_control: Some(1)
+            })
+        );
+    }
+
+    #[test]
+    fn test_serialize_replies() {
+        let record = IncomingRecord::parse_record(REPLIES).unwrap();
+        assert_eq!(Vec::<u8>::from(&record), REPLIES);
+
+        // Descriptors without a CGCSGID drop the GF flag
+        let sets = QueryReply::CharacterSets(CharacterSets {
+            flags1: 0x82,
+            flags2: 0x00,
+            default_cell_width: 9,
+            default_cell_height: 12,
+            load_formats: 0,
+            sets: vec![CharacterSet {
+                set: 0,
+                flags: 0x10,
+                lcid: 0,
+                cell_size: None,
+                subsection: None,
+                cgcsgid: None,
+            }],
+        });
+        let mut field = vec![];
+        sets.serialize(&mut field);
+        assert_eq!(
+            field,
+            [0x00, 0x10, 0x81, 0x85, 0x80, 0x00, 0x09, 0x0C, 0, 0, 0, 0, 0x03, 0x00, 0x10, 0x00]
+        );
+        let QueryReply::CharacterSets(parsed) = QueryReply::parse(&field[3..]).unwrap() else {
+            panic!()
+        };
+        assert_eq!((parsed.flags1, parsed.sets.len()), (0x80, 1));
+    }
+
+    #[test]
+    fn test_truncated_replies() {
+        // Records cut off between two fields are fine, anywhere else they are not
+        let mut boundaries = vec![1];
+        while let Some(&end) = boundaries.last().filter(|&&end| end < REPLIES.len()) {
+            boundaries.push(end + u16::from_be_bytes([REPLIES[end], REPLIES[end + 1]]) as usize);
+        }
+        for len in 1..REPLIES.len() {
+            let result = IncomingRecord::parse_record(&REPLIES[..len]);
+            assert_eq!(result.is_ok(), boundaries.contains(&len), "{}", len);
+        }
+        let error = IncomingRecord::parse_record(&[0x88, 0x00, 0x01, 0x81]).unwrap_err();
+        assert_eq!(*error.kind(), StreamFormatError::InvalidData);
+        assert_eq!((error.offset(), error.order()), (Some(1), Some(0x81)));
+    }
+
+    #[test]
+    fn test_session_query() {
+        let (mut client, mut session) = connect("IBM-DYNAMIC");
+        assert_eq!(session.terminal_model().screen_size(), ScreenSize::DEFAULT);
+        session.set_encoding(Encoding::CP500);
+
+        let terminal = thread::spawn(move || {
+            // The partition ID 0xFF is escaped as IAC IAC
+            expect(
+                &mut client,
+                &[0, 0, 0, 0, 0, 0xF3, 0x00, 0x05, 0x01, 0xFF, 0xFF, 0x02, IAC, EOR],
+            );
+            client.write_all(&[0, 0, 0, 0, 0]).unwrap();
+            client.write_all(REPLIES).unwrap();
+            client.write_all(&[IAC, EOR]).unwrap();
+            client
+        });
+
+        let replies =
+            session.query(&ReadPartitionQuery::Query, Some(Duration::from_secs(5))).unwrap();
+        terminal.join().unwrap();
+        assert_eq!(replies.unwrap().len(), 8);
+        let model = session.terminal_model();
+        assert_eq!(model.screen_size(), ScreenSize { rows: 43, cols: 80 });
+        assert!(model.color && model.uses_alternate());
+        // Taken from the base character set
+        assert_eq!(session.encoding(), Encoding::CP037);
+    }
+}
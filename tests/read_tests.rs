@@ -0,0 +1,228 @@
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+    use std::thread;
+    use std::time::Duration;
+
+    use rust3270::encoding::{Encoding, SI, SO};
+    use rust3270::server::aid::AID;
+    use rust3270::server::buffer::BufferSnapshot;
+    use rust3270::server::color::Color;
+    use rust3270::server::extended_field_attributes::ExtendedFieldAttribute;
+    use rust3270::server::highlighting::Highlighting;
+    use rust3270::server::stream::{
+        OutputRecord, ReadBuffer, ReadModified, StreamOptions, WriteCommand, WriteCommandCode,
+        WriteOrder,
+    };
+    use rust3270::server::wcc::{FieldAttribute, WCC};
+
+    use crate::common::{EOR, IAC, connect, expect};
+
+    // Enter with the cursor at 85, a protected field "AB", a red field "C D" with a blinking "D"
+    const BUFFER: &[u8] = &[
+        0x7D, 0xC1, 0xD5, // AID and cursor
+        0x1D, 0x20, 0xC1, 0xC2, // SF, "AB"
+        0x29, 0x02, 0xC0, 0x00, 0x42, 0xF2, 0xC3, 0x00, // SFE, "C", null
+        0x28, 0x41, 0xF1, 0xC4, // SA, "D"
+    ];
+
+    fn command(record: &impl OutputRecord) -> Vec<u8> {
+        let mut out = vec![];
+        record.write_to(&mut out, StreamOptions::default()).unwrap();
+        out
+    }
+
+    #[test]
+    fn test_read_commands() {
+        assert_eq!(command(&ReadBuffer), [0xF2]);
+        assert_eq!(command(&ReadModified { all: false }), [0xF6]);
+        assert_eq!(command(&ReadModified { all: true }), [0x6E]);
+    }
+
+    #[test]
+    fn test_parse_buffer() {
+        let snapshot = BufferSnapshot::parse(BUFFER).unwrap();
+        assert_eq!((snapshot.aid, snapshot.cursor), (AID::Enter, 85));
+        assert_eq!(snapshot.data, [0x00, 0xC1, 0xC2, 0x00, 0xC3, 0x00, 0xC4]);
+        assert_eq!(snapshot.text(), " AB C D");
+
+        assert_eq!(snapshot.fields.len(), 2);
+        let (first, second) = (&snapshot.fields[0], &snapshot.fields[1]);
+        assert_eq!((first.addr, first.attribute.bits()), (0, FieldAttribute::PROTECTED.bits()));
+        assert!(first.extended.is_empty());
+        assert_eq!((second.addr, second.attribute.bits()), (3, 0));
+        assert!(matches!(
+            second.extended[..],
+            [ExtendedFieldAttribute::ForegroundColor(Color::Red)]
+        ));
+        assert!(matches!(
+            snapshot.character_attributes[..],
+            [(6, ExtendedFieldAttribute::ExtendedHighlighting(Highlighting::Blink))]
+        ));
+
+        assert_eq!(snapshot.field_contents(first), "AB");
+        assert_eq!(snapshot.field_contents(second), "C D");
+        assert_eq!(snapshot.field_at(2).unwrap().addr, 0);
+        assert_eq!(snapshot.field_at(5).unwrap().addr, 3);
+        // The attribute of the first field belongs to the last one, which wraps around
+        assert_eq!(snapshot.field_at(0).unwrap().addr, 3);
+    }
+
+    #[test]
+    fn test_parse_unformatted_buffer() {
+        let snapshot = BufferSnapshot::parse(&[0x6D, 0x40, 0x40, 0xC8, 0xC9]).unwrap();
+        assert_eq!((snapshot.aid, snapshot.cursor), (AID::Clear, 0));
+        assert_eq!(snapshot.text(), "HI");
+        assert!(snapshot.field_at(1).is_none());
+
+        for len in 3..BUFFER.len() {
+            let result = BufferSnapshot::parse(&BUFFER[..len]);
+            let truncated = [4, 8, 9, 10, 11, 12, 16, 17].contains(&len);
+            assert_eq!(result.is_err(), truncated, "{}", len);
+        }
+    }
+
+    #[test]
+    fn test_parse_dbcs_buffer() {
+        // A DBCS field "日本" with a null pair, then a mixed field "A日B"
+        #[rustfmt::skip]
+        let record = [
+            0x7D, 0x40, 0x40,
+            0x29, 0x02, 0xC0, 0x20, 0x43, 0xF8, 0x45, 0x62, 0x45, 0x66, 0x00, 0x00,
+            0x1D, 0x20, 0xC1, SO, 0x45, 0x62, SI, 0xC2,
+        ];
+        let options = StreamOptions { encoding: Encoding::CP939, ..StreamOptions::default() };
+        let snapshot = BufferSnapshot::parse_with(&record, options).unwrap();
+        assert!(snapshot.fields[0].is_dbcs() && !snapshot.fields[1].is_dbcs());
+        assert_eq!(snapshot.field_contents(&snapshot.fields[0]), "日本\u{3000}");
+        assert_eq!(snapshot.field_contents(&snapshot.fields[1]), "A日B");
+        assert_eq!(snapshot.text(), " 日本\u{3000} A日B");
+
+        // The contents before the first attribute are the end of the last field
+        let record = [&record[..3], &[0x45, 0x66], &record[3..15]].concat();
+        let snapshot = BufferSnapshot::parse_with(&record, options).unwrap();
+        assert_eq!(snapshot.field_contents(&snapshot.fields[0]), "日本\u{3000}本");
+        assert_eq!(snapshot.text(), "本 日本\u{3000}");
+    }
+
+    #[test]
+    fn test_session_read_buffer() {
+        let (mut client, mut session) = connect("IBM-3278-2-E");
+
+        let terminal = thread::spawn(move || {
+            expect(&mut client, &[0, 0, 0, 0, 0, 0xF2, IAC, EOR]);
+            client.write_all(&[0, 0, 0, 0, 0]).unwrap();
+            client.write_all(BUFFER).unwrap();
+            client.write_all(&[IAC, EOR]).unwrap();
+
+            expect(&mut client, &[0, 0, 0, 0, 1, 0xF6, IAC, EOR]);
+            client.write_all(&[0, 0, 0, 0, 0, 0x7D, 0xC1, 0xD5, 0x11, 0x40, 0xC4]).unwrap();
+            client.write_all(&[0xC1, IAC, EOR]).unwrap();
+            client
+        });
+
+        let mut record = vec![];
+        ReadBuffer.write_to(&mut record, StreamOptions::default()).unwrap();
+        session.send_record(record).unwrap();
+        let reply = session.receive_record(Some(Duration::from_secs(5))).unwrap().unwrap();
+        let snapshot = ReadBuffer.parse_response(&reply, StreamOptions::default()).unwrap();
+        assert_eq!(snapshot.field_contents(&snapshot.fields[1]), "C D");
+
+        let read = ReadModified { all: false };
+        let mut record = vec![];
+        read.write_to(&mut record, StreamOptions::default()).unwrap();
+        session.send_record(record).unwrap();
+        let reply = session.receive_record(Some(Duration::from_secs(5))).unwrap().unwrap();
+        let modified = read.parse_response(&reply, StreamOptions::default()).unwrap();
+        assert_eq!((modified.aid, modified.addr), (AID::Enter, 85));
+        assert!(matches!(
+            &modified.orders[..],
+            [WriteOrder::SetBufferAddress(4), WriteOrder::SendText(text)] if text == "A"
+        ));
+        terminal.join().unwrap();
+    }
+
+    #[test]
+    fn test_session_send() {
+        let (mut client, mut session) = connect("IBM-3278-2-E");
+
+        // Long enough to go through the record writer's buffer more than once, with IACs to escape
+        let mut orders = vec![WriteOrder::SetBufferAddress(80)];
+        orders.extend((0..600).map(|_| WriteOrder::GraphicEscape(0xFF)));
+        let locked = WriteCommand { command: WriteCommandCode::Write, wcc: WCC::RESET_MDT, orders };
+        let restore = WriteCommand {
+            command: WriteCommandCode::EraseWrite,
+            wcc: WCC::KBD_RESTORE,
+            orders: vec![WriteOrder::SendText("OK".into())],
+        };
+        assert!(!locked.expects_response());
+        assert!(restore.expects_response());
+
+        // Written with the session's address mode, 12-bit for a model 2, and code page
+        assert_eq!(session.stream_options().encoding, Encoding::CP037);
+        let mut written = vec![];
+        locked.write_to(&mut written, session.stream_options()).unwrap();
+        assert_eq!(written[2..5], [0x11, 0xC1, 0x50]);
+        let mut expected = vec![0, 0, 0, 0, 0];
+        expected.extend(written.iter().flat_map(|&v| match v {
+            IAC => vec![IAC, IAC],
+            v => vec![v],
+        }));
+        expected.extend_from_slice(&[IAC, EOR]);
+        let mut restored = vec![0, 0, 0, 0, 1];
+        restored.extend(command(&restore));
+        restored.extend_from_slice(&[IAC, EOR]);
+
+        let terminal = thread::spawn(move || {
+            expect(&mut client, &expected);
+            expect(&mut client, &restored);
+            client.write_all(&[0, 0, 0, 0, 0, 0x6D, IAC, EOR]).unwrap();
+
+            expect(&mut client, &[0, 0, 0, 0, 2, 0xF2, IAC, EOR]);
+            client.write_all(&[0, 0, 0, 0, 0]).unwrap();
+            client.write_all(BUFFER).unwrap();
+            client.write_all(&[IAC, EOR]).unwrap();
+            client
+        });
+
+        // Nothing is waited for while the keyboard stays locked
+        assert!(session.send(&locked).unwrap().is_none());
+        let reply = session.send(&restore).unwrap().unwrap();
+        assert!(reply.aid == AID::Clear && reply.short_read);
+        let snapshot = session.send(&ReadBuffer).unwrap();
+        assert_eq!(snapshot.text(), " AB C D");
+        terminal.join().unwrap();
+    }
+
+    #[test]
+    fn test_send_discards_stale_records() {
+        let (mut client, mut session) = connect("IBM-3278-2-E");
+
+        // Two records in one segment, so the Clear is still queued after the Enter was received
+        client.write_all(&[0, 0, 0, 0, 0, 0x7D, 0x40, 0x40, IAC, EOR]).unwrap();
+        client.write_all(&[0, 0, 0, 0, 0, 0x6D, IAC, EOR]).unwrap();
+        let record = session.receive_record(Some(Duration::from_secs(5))).unwrap().unwrap();
+        assert_eq!(record, [0x7D, 0x40, 0x40]);
+
+        let restore = WriteCommand {
+            command: WriteCommandCode::Write,
+            wcc: WCC::KBD_RESTORE,
+            orders: vec![],
+        };
+        let mut expected = vec![0, 0, 0, 0, 0];
+        expected.extend(command(&restore));
+        expected.extend_from_slice(&[IAC, EOR]);
+        let terminal = thread::spawn(move || {
+            expect(&mut client, &expected);
+            client.write_all(&[0, 0, 0, 0, 0, 0xF1, 0x40, 0x40, IAC, EOR]).unwrap();
+            client
+        });
+
+        // The answer is the PF1 sent after the command, not the Clear from before it
+        let reply = session.send(&restore).unwrap().unwrap();
+        assert_eq!(reply.aid, AID::PF1);
+        terminal.join().unwrap();
+    }
+}
@@ -0,0 +1,124 @@
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Read, Write};
+    use std::net::{SocketAddr, TcpStream};
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::thread;
+    use std::time::Duration;
+
+    use rust3270::server::{Server, ServerConfig, SessionContext, SessionHandler, ShutdownHandle};
+
+    use crate::common::{EOR, IAC, expect, negotiate_client};
+
+    fn expect_closed(client: &mut TcpStream) {
+        client.set_read_timeout(Some(Duration::from_secs(5))).unwrap();
+        let mut buf = [0; 16];
+        assert!(matches!(client.read(&mut buf), Ok(0) | Err(_)));
+    }
+
+    fn negotiate(addr: SocketAddr) -> TcpStream {
+        let mut client = TcpStream::connect(addr).unwrap();
+        negotiate_client(&mut client, "IBM-3278-2-E");
+        client
+    }
+
+    fn start(
+        config: ServerConfig,
+        handler: impl SessionHandler,
+    ) -> (SocketAddr, ShutdownHandle, thread::JoinHandle<std::io::Result<()>>) {
+        let server = Server::bind("127.0.0.1:0", config).unwrap();
+        let addr = server.local_addr().unwrap();
+        let shutdown = server.shutdown_handle();
+        (addr, shutdown, thread::spawn(move || server.run(handler)))
+    }
+
+    // Sends one record and waits for the answer
+    fn echo(mut session: rust3270::server::Session, _: &SessionContext) {
+        session.send_record(vec![0xF5, 0xC3]).unwrap();
+        if let Ok(Some(record)) = session.receive_record(None) {
+            let _ = session.send_record(record);
+        }
+    }
+
+    #[test]
+    fn test_multiple_clients() {
+        let config = ServerConfig { workers: 2, ..ServerConfig::default() };
+        let (addr, shutdown, server) = start(config, echo);
+
+        let mut first = negotiate(addr);
+        let mut second = negotiate(addr);
+        for client in [&mut first, &mut second] {
+            expect(client, &[0, 0, 0, 0, 0, 0xF5, 0xC3, IAC, EOR]);
+            client.write_all(&[0, 0, 0, 0, 0, 0x7D, 0x40, 0x40, IAC, EOR]).unwrap();
+            expect(client, &[0, 0, 0, 0, 1, 0x7D, 0x40, 0x40, IAC, EOR]);
+        }
+
+        shutdown.shutdown();
+        server.join().unwrap().unwrap();
+    }
+
+    #[test]
+    fn test_connection_limit() {
+        let config = ServerConfig { workers: 1, max_connections: 1, ..ServerConfig::default() };
+        let (addr, shutdown, server) = start(config, echo);
+
+        let mut first = negotiate(addr);
+        expect(&mut first, &[0, 0, 0, 0, 0, 0xF5, 0xC3, IAC, EOR]);
+
+        let mut second = TcpStream::connect(addr).unwrap();
+        expect_closed(&mut second);
+
+        first.write_all(&[0, 0, 0, 0, 0, 0x6D, IAC, EOR]).unwrap();
+        expect(&mut first, &[0, 0, 0, 0, 1, 0x6D, IAC, EOR]);
+        drop(first);
+
+        shutdown.shutdown();
+        server.join().unwrap().unwrap();
+    }
+
+    #[test]
+    fn test_graceful_shutdown() {
+        let noticed = Arc::new(AtomicBool::new(false));
+        let handler = {
+            let noticed = noticed.clone();
+            move |mut session: rust3270::server::Session, context: &SessionContext| {
+                session.send_record(vec![0xF5, 0xC3]).unwrap();
+                session.receive_record(None).unwrap();
+                noticed.store(context.is_shutting_down(), Ordering::SeqCst);
+            }
+        };
+        let (addr, shutdown, server) = start(ServerConfig::default(), handler);
+
+        let mut client = negotiate(addr);
+        expect(&mut client, &[0, 0, 0, 0, 0, 0xF5, 0xC3, IAC, EOR]);
+
+        // The running screen is allowed to finish
+        shutdown.shutdown();
+        thread::sleep(Duration::from_millis(200));
+        assert!(!server.is_finished());
+        assert!(TcpStream::connect(addr).is_err());
+
+        client.write_all(&[0, 0, 0, 0, 0, 0x7D, 0x40, 0x40, IAC, EOR]).unwrap();
+        server.join().unwrap().unwrap();
+        assert!(noticed.load(Ordering::SeqCst));
+    }
+
+    #[test]
+    fn test_shutdown_timeout() {
+        let config = ServerConfig {
+            shutdown_timeout: Some(Duration::from_millis(100)),
+            ..ServerConfig::default()
+        };
+        let (addr, shutdown, server) = start(config, echo);
+
+        let mut client = negotiate(addr);
+        expect(&mut client, &[0, 0, 0, 0, 0, 0xF5, 0xC3, IAC, EOR]);
+
+        shutdown.shutdown();
+        server.join().unwrap().unwrap();
+        expect_closed(&mut client);
+    }
+}
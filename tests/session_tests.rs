@@ -0,0 +1,124 @@
+#[cfg(test)]
+mod tests {
+    use std::io::{Read, Write};
+    use std::thread;
+    use std::time::Duration;
+
+    use rust3270::server::tn3270e::TN3270E;
+    use rust3270::server::transport::{MemoryStream, duplex};
+    use rust3270::server::{Session, SessionConfig, SessionError};
+
+    const IAC: u8 = 255;
+    const SB: u8 = 250;
+    const SE: u8 = 240;
+    const WILL: u8 = 251;
+    const WONT: u8 = 252;
+    const DO: u8 = 253;
+    const DONT: u8 = 254;
+    const TTYPE: u8 = 24;
+    const EOR: u8 = 25;
+    const BINARY: u8 = 0;
+
+    fn expect(client: &mut MemoryStream, expected: &[u8]) {
+        let mut buf = vec![0; expected.len()];
+        client.read_exact(&mut buf).unwrap();
+        assert_eq!(buf, expected);
+    }
+
+    // Runs the negotiation against a scripted client and returns its result
+    fn negotiate(
+        config: SessionConfig,
+        client: impl FnOnce(&mut MemoryStream) + Send + 'static,
+    ) -> Result<Session<MemoryStream>, SessionError> {
+        let (mut terminal, server) = duplex();
+        let terminal = thread::spawn(move || client(&mut terminal));
+        let result = Session::with_config(server, config);
+        terminal.join().unwrap();
+        result
+    }
+
+    fn tn3270() -> SessionConfig {
+        SessionConfig { tn3270e: false, ..SessionConfig::default() }
+    }
+
+    fn send_ttype(client: &mut MemoryStream, term_type: &[u8]) {
+        expect(client, &[IAC, DO, TTYPE, IAC, WILL, TTYPE]);
+        client.write_all(&[IAC, WILL, TTYPE]).unwrap();
+        client.write_all(&[IAC, SB, TTYPE, 0]).unwrap();
+        client.write_all(term_type).unwrap();
+        client.write_all(&[IAC, SE]).unwrap();
+    }
+
+    #[test]
+    fn test_disconnect() {
+        let result = negotiate(tn3270(), |client| {
+            expect(client, &[IAC, DO, TTYPE, IAC, WILL, TTYPE]);
+        });
+        assert!(matches!(result, Err(SessionError::Disconnected)));
+    }
+
+    #[test]
+    fn test_timeout() {
+        let config = SessionConfig { negotiation_timeout: Duration::from_millis(100), ..tn3270() };
+        let result = negotiate(config, |client| {
+            thread::sleep(Duration::from_millis(300));
+            let _ = client.write_all(&[IAC, WILL, TTYPE]);
+        });
+        assert!(matches!(result, Err(SessionError::NegotiationTimeout)));
+    }
+
+    #[test]
+    fn test_refused_binary() {
+        let result = negotiate(tn3270(), |client| {
+            send_ttype(client, b"IBM-3278-2");
+            client.write_all(&[IAC, DO, EOR, IAC, WILL, EOR, IAC, DONT, BINARY]).unwrap();
+            let mut buf = [0; 64];
+            while client.read(&mut buf).is_ok_and(|len| len > 0) {}
+        });
+        assert!(matches!(result, Err(SessionError::OptionRefused { option: "BINARY" })));
+    }
+
+    #[test]
+    fn test_refused_terminal_type() {
+        let result = negotiate(tn3270(), |client| {
+            expect(client, &[IAC, DO, TTYPE, IAC, WILL, TTYPE]);
+            client.write_all(&[IAC, WONT, TTYPE]).unwrap();
+        });
+        assert!(matches!(result, Err(SessionError::NotA3270Terminal { term_type: None })));
+    }
+
+    #[test]
+    fn test_not_a_3270_terminal() {
+        let result = negotiate(tn3270(), |client| {
+            send_ttype(client, b"XTERM-256COLOR");
+            let mut buf = [0; 64];
+            while client.read(&mut buf).is_ok_and(|len| len > 0) {}
+        });
+        match result {
+            Err(SessionError::NotA3270Terminal { term_type }) => {
+                assert_eq!(term_type.as_deref(), Some("XTERM-256COLOR"))
+            }
+            other => panic!("Expected NotA3270Terminal, got {:?}", other.err()),
+        }
+
+        // Some clients just start sending text
+        let result = negotiate(SessionConfig::default(), |client| {
+            expect(client, &[IAC, DO, TN3270E]);
+            client.write_all(b"GET / HTTP/1.1\r\n\r\n").unwrap();
+        });
+        assert!(matches!(result, Err(SessionError::NotA3270Terminal { .. })));
+    }
+
+    #[test]
+    fn test_protocol_violation() {
+        let result = negotiate(SessionConfig::default(), |client| {
+            expect(client, &[IAC, DO, TN3270E]);
+            client.write_all(&[IAC, WILL, TN3270E]).unwrap();
+            // A DEVICE-TYPE without an operation
+            client.write_all(&[IAC, SB, TN3270E, 2, IAC, SE]).unwrap();
+            let mut buf = [0; 64];
+            while client.read(&mut buf).is_ok_and(|len| len > 0) {}
+        });
+        assert!(matches!(result, Err(SessionError::ProtocolViolation { .. })));
+    }
+}
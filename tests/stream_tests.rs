@@ -0,0 +1,601 @@
+#[cfg(test)]
+mod tests {
+    use std::io::ErrorKind;
+
+    use rust3270::encoding::{Encoding, SI, SO, encode_into};
+    use rust3270::server::aid::AID;
+    use rust3270::server::buffer::BufferSnapshot;
+    use rust3270::server::color::Color;
+    use rust3270::server::extended_field_attributes::{DBCS_CHARACTER_SET, ExtendedFieldAttribute};
+    use rust3270::server::highlighting::Highlighting;
+    use rust3270::server::stream::{
+        AddressMode, IncomingRecord, StreamFormatError, StreamOptions, WriteCommand,
+        WriteCommandCode, WriteOrder,
+    };
+    use rust3270::server::view::{OrderView, RecordView};
+    use rust3270::server::wcc::{FieldAttribute, WCC};
+
+    #[test]
+    fn test_short_reads() {
+        for aid in [AID::PA1, AID::PA2, AID::PA3, AID::Clear, AID::ClearPartition, AID::SysReq] {
+            let record = IncomingRecord::parse_record(&[aid.into()]).unwrap();
+            assert_eq!(record.aid, aid);
+            assert!(record.short_read);
+            assert_eq!(record.addr, 0);
+            assert!(record.orders.is_empty());
+        }
+
+        // Other keys always send the cursor address
+        assert_eq!(
+            *IncomingRecord::parse_record(&[AID::Enter.into()]).unwrap_err().kind(),
+            StreamFormatError::UnexpectedEOR
+        );
+        assert_eq!(
+            *IncomingRecord::parse_record(&[AID::PA1.into(), 0x40]).unwrap_err().kind(),
+            StreamFormatError::UnexpectedEOR
+        );
+
+        // A Read Modified All after a PA key is a full read
+        let record = IncomingRecord::parse_record(&[0x6C, 0x40, 0x40, 0x11, 0x40, 0xC1]).unwrap();
+        assert_eq!(record.aid, AID::PA1);
+        assert!(!record.short_read);
+        assert_eq!(record.orders.len(), 1);
+    }
+
+    #[test]
+    fn test_write_command_round_trip() {
+        let command = WriteCommand {
+            command: WriteCommandCode::EraseWrite,
+            wcc: WCC::RESET_MDT | WCC::KBD_RESTORE,
+            orders: vec![
+                WriteOrder::EraseUnprotectedToAddress(1919),
+                WriteOrder::SetBufferAddress(80),
+                WriteOrder::StartField(FieldAttribute::PROTECTED),
+                WriteOrder::SendText("Name:".into()),
+                WriteOrder::StartFieldExtended(vec![
+                    ExtendedFieldAttribute::FieldAttribute(FieldAttribute::NONE),
+                    ExtendedFieldAttribute::ForegroundColor(Color::Red),
+                ]),
+                WriteOrder::InsertCursor(87),
+                WriteOrder::SetAttribute(ExtendedFieldAttribute::ExtendedHighlighting(
+                    Highlighting::Reverse,
+                )),
+                WriteOrder::RepeatToAddress(100, '_'),
+                WriteOrder::ModifyField(vec![ExtendedFieldAttribute::BackgroundColor(Color::Blue)]),
+                WriteOrder::GraphicEscape(0xAD),
+                WriteOrder::ProgramTab,
+            ],
+        };
+        let bytes = Vec::<u8>::from(&command);
+        let parsed = WriteCommand::parse(&bytes).unwrap();
+        assert_eq!(parsed.command, command.command);
+        assert_eq!(parsed.wcc.bits(), command.wcc.bits());
+        assert_eq!(parsed.orders.len(), command.orders.len());
+        assert_eq!(Vec::<u8>::from(&parsed), bytes);
+
+        let eau = WriteCommand {
+            command: WriteCommandCode::EraseAllUnprotected,
+            wcc: WCC::RESET,
+            orders: vec![],
+        };
+        assert_eq!(Vec::<u8>::from(&eau), [0x6F]);
+        assert_eq!(WriteCommand::parse(&[0x6F]).unwrap().command, eau.command);
+    }
+
+    #[test]
+    fn test_parse_foreign_write_command() {
+        // CCW Erase/Write, 12-bit addresses, IC after a field and RA with a GE character
+        let command = WriteCommand::parse(&[
+            0x05, 0xC3, 0x11, 0x40, 0x50, 0x1D, 0x60, 0x13, 0xC1, 0x00, 0x3C, 0x40, 0x5A, 0x08,
+            0xAD,
+        ])
+        .unwrap();
+        assert_eq!(command.command, WriteCommandCode::EraseWrite);
+        assert_eq!(command.wcc.bits(), (WCC::RESET_MDT | WCC::KBD_RESTORE).bits());
+        assert!(matches!(
+            &command.orders[..],
+            [
+                WriteOrder::SetBufferAddress(16),
+                WriteOrder::StartField(attr),
+                WriteOrder::InsertCursor(17),
+                WriteOrder::SendText(text),
+                WriteOrder::RepeatGraphicEscape(26, 0xAD),
+            ] if attr.bits() == FieldAttribute::PROTECTED.bits() && text.len() == 2
+        ));
+
+        // Without an SBA the address of a Write is the unknown cursor position
+        let bytes = [0xF1, 0xC3, 0x13, 0xC1, 0x3C, 0x00, 0x1A, 0x08, 0xAD];
+        let command = WriteCommand::parse(&bytes).unwrap();
+        assert!(matches!(
+            &command.orders[..],
+            [
+                WriteOrder::InsertCursorHere,
+                WriteOrder::SendText(_),
+                WriteOrder::RepeatGraphicEscape(26, 0xAD)
+            ]
+        ));
+        assert_eq!(Vec::<u8>::from(&command), bytes);
+        assert_eq!(
+            WriteCommand::parse(&[0xF3, 0x00, 0x05, 0x01, 0xFF, 0x02]).unwrap_err().kind(),
+            &StreamFormatError::InvalidCommand { command: 0xF3 }
+        );
+        assert_eq!(
+            WriteCommand::parse(&[0x42, 0xC3]).unwrap_err().kind(),
+            &StreamFormatError::InvalidCommand { command: 0x42 }
+        );
+        let error = WriteCommand::parse(&[0xF5]).unwrap_err();
+        assert_eq!((error.kind(), error.offset()), (&StreamFormatError::UnexpectedEOR, Some(1)));
+        let error = WriteCommand::parse(&[0xF5, 0xC3, 0x11, 0x40]).unwrap_err();
+        assert_eq!(error.kind(), &StreamFormatError::UnexpectedEOR);
+        assert_eq!((error.offset(), error.order()), (Some(2), Some(0x11)));
+        let error = WriteCommand::parse(&[0xF5, 0xC3, 0xC1, 0x28, 0x42, 0xF2, 0x29, 0x01, 0x41])
+            .unwrap_err();
+        assert_eq!(error.to_string(), "Record ended early at offset 6 in order 29");
+    }
+
+    #[test]
+    fn test_serialize_incoming_record() {
+        // Enter at 1,13 with "ABC" typed into the field at 1,10
+        let record = IncomingRecord {
+            aid: AID::Enter,
+            addr: 12,
+            orders: vec![WriteOrder::SetBufferAddress(10), WriteOrder::SendText("ABC".into())],
+            short_read: false,
+            query_replies: vec![],
+        };
+        let bytes = Vec::<u8>::from(&record);
+        assert_eq!(bytes, [0x7D, 0x40, 0x4C, 0x11, 0x40, 0x4A, 0xC1, 0xC2, 0xC3]);
+        let parsed = IncomingRecord::parse_record(&bytes).unwrap();
+        assert_eq!((parsed.aid, parsed.addr), (AID::Enter, 12));
+        assert!(matches!(
+            &parsed.orders[..],
+            [WriteOrder::SetBufferAddress(10), WriteOrder::SendText(text)] if text == "ABC"
+        ));
+
+        // IC is written and read the way write commands have it, as SBA and a bare IC
+        let record = IncomingRecord {
+            orders: vec![
+                WriteOrder::InsertCursor(20),
+                WriteOrder::SendText("A".into()),
+                WriteOrder::InsertCursorHere,
+                WriteOrder::SendText("B".into()),
+            ],
+            ..record
+        };
+        let bytes = Vec::<u8>::from(&record);
+        assert_eq!(bytes, [0x7D, 0x40, 0x4C, 0x11, 0x40, 0xD4, 0x13, 0xC1, 0x13, 0xC2]);
+        let parsed = IncomingRecord::parse_record(&bytes).unwrap();
+        assert!(matches!(
+            &parsed.orders[..],
+            [
+                WriteOrder::InsertCursor(20),
+                WriteOrder::SendText(a),
+                WriteOrder::InsertCursorHere,
+                WriteOrder::SendText(b),
+            ] if a == "A" && b == "B"
+        ));
+        assert_eq!(Vec::<u8>::from(&parsed), bytes);
+
+        // Addresses past 4095 don't fit 12 bits
+        let record = IncomingRecord { addr: 5000, orders: vec![], ..record };
+        assert_eq!(Vec::<u8>::from(&record), [0x7D, 0x13, 0x88]);
+        assert_eq!(IncomingRecord::parse_record(&[0x7D, 0x13, 0x88]).unwrap().addr, 5000);
+
+        let short = IncomingRecord { aid: AID::Clear, short_read: true, ..record };
+        assert_eq!(Vec::<u8>::from(&short), [0x6D]);
+    }
+
+    #[test]
+    fn test_serialize_read_buffer_reply() {
+        let record = IncomingRecord {
+            aid: AID::NoAIDGenerated,
+            addr: 0,
+            orders: vec![
+                WriteOrder::StartField(FieldAttribute::PROTECTED),
+                WriteOrder::SendText("ID".into()),
+                WriteOrder::StartFieldExtended(vec![
+                    ExtendedFieldAttribute::FieldAttribute(FieldAttribute::NUMERIC),
+                    ExtendedFieldAttribute::ForegroundColor(Color::Green),
+                ]),
+                WriteOrder::SendText("42".into()),
+            ],
+            short_read: false,
+            query_replies: vec![],
+        };
+        let bytes = Vec::<u8>::from(&record);
+        assert_eq!(&bytes[..5], [0x60, 0x40, 0x40, 0x1D, 0x60]);
+
+        let snapshot = BufferSnapshot::parse(&bytes).unwrap();
+        assert_eq!(snapshot.text(), " ID 42");
+        assert_eq!(snapshot.fields[1].attribute.bits(), FieldAttribute::NUMERIC.bits());
+        assert!(matches!(
+            snapshot.fields[1].extended[..],
+            [ExtendedFieldAttribute::ForegroundColor(Color::Green)]
+        ));
+        assert_eq!(snapshot.field_contents(&snapshot.fields[1]), "42");
+    }
+
+    #[test]
+    fn test_address_modes() {
+        assert_eq!(AddressMode::TwelveBit.encode(85), [0xC1, 0xD5]);
+        assert_eq!(AddressMode::TwelveBit.encode(5000), [0x13, 0x88]);
+        assert_eq!(AddressMode::FourteenBit.encode(85), [0x00, 0x55]);
+        assert_eq!(AddressMode::SixteenBit.encode(0xC1D5), [0xC1, 0xD5]);
+        assert_eq!(AddressMode::FourteenBit.decode(&[0xC1, 0xD5]).unwrap(), 85);
+        assert_eq!(AddressMode::SixteenBit.decode(&[0xC1, 0xD5]).unwrap(), 0xC1D5);
+        assert_eq!(
+            AddressMode::FourteenBit.decode(&[0x80, 0x00]).unwrap_err(),
+            StreamFormatError::InvalidData
+        );
+        for mode in [AddressMode::TwelveBit, AddressMode::FourteenBit, AddressMode::SixteenBit] {
+            assert_eq!(mode.decode(&[0x40]).unwrap_err(), StreamFormatError::UnexpectedEOR);
+            assert_eq!(mode.decode(&[]).unwrap_err(), StreamFormatError::UnexpectedEOR);
+        }
+
+        assert_eq!(AddressMode::for_buffer_size(24 * 80), AddressMode::TwelveBit);
+        assert_eq!(AddressMode::for_buffer_size(62 * 160), AddressMode::FourteenBit);
+        assert_eq!(AddressMode::for_buffer_size(80 * 240), AddressMode::SixteenBit);
+
+        let command = WriteCommand {
+            command: WriteCommandCode::EraseWrite,
+            wcc: WCC::KBD_RESTORE,
+            orders: vec![
+                WriteOrder::SetBufferAddress(19000),
+                WriteOrder::RepeatToAddress(19199, ' '),
+                WriteOrder::InsertCursor(85),
+            ],
+        };
+        let mut twelve = vec![];
+        command.serialize_with(
+            &mut twelve,
+            StreamOptions { mode: AddressMode::TwelveBit, ..StreamOptions::default() },
+        );
+        assert_eq!(
+            &twelve[2..],
+            [0x11, 0x0A, 0x38, 0x3C, 0x0A, 0xFF, 0x40, 0x11, 0xC1, 0xD5, 0x13]
+        );
+
+        let mut sixteen = vec![];
+        command.serialize_with(
+            &mut sixteen,
+            StreamOptions { mode: AddressMode::SixteenBit, ..StreamOptions::default() },
+        );
+        assert_eq!(
+            &sixteen[2..],
+            [0x11, 0x4A, 0x38, 0x3C, 0x4A, 0xFF, 0x40, 0x11, 0x00, 0x55, 0x13]
+        );
+        let parsed = WriteCommand::parse_with(
+            &sixteen,
+            StreamOptions { mode: AddressMode::SixteenBit, ..StreamOptions::default() },
+        )
+        .unwrap();
+        assert!(matches!(
+            parsed.orders[..],
+            [
+                WriteOrder::SetBufferAddress(19000),
+                WriteOrder::RepeatToAddress(19199, ' '),
+                WriteOrder::InsertCursor(85)
+            ]
+        ));
+
+        let record = IncomingRecord {
+            aid: AID::Enter,
+            addr: 19000,
+            orders: vec![WriteOrder::SetBufferAddress(19199), WriteOrder::SendText("A".into())],
+            short_read: false,
+            query_replies: vec![],
+        };
+        let mut bytes = vec![];
+        record.serialize_with(
+            &mut bytes,
+            StreamOptions { mode: AddressMode::SixteenBit, ..StreamOptions::default() },
+        );
+        assert_eq!(bytes, [0x7D, 0x4A, 0x38, 0x11, 0x4A, 0xFF, 0xC1]);
+        let parsed = IncomingRecord::parse_record_with(
+            &bytes,
+            StreamOptions { mode: AddressMode::SixteenBit, ..StreamOptions::default() },
+        )
+        .unwrap();
+        assert_eq!(parsed.addr, 19000);
+        assert!(matches!(parsed.orders[0], WriteOrder::SetBufferAddress(19199)));
+    }
+
+    // Malformed records with the error, offset and order code they are rejected with
+    const MALFORMED: &[(&[u8], StreamFormatError, usize, Option<u8>)] = &[
+        (&[], StreamFormatError::UnexpectedEOR, 0, None),
+        (&[0x00], StreamFormatError::InvalidAID { aid: 0x00 }, 0, None),
+        (&[0x7D, 0x40], StreamFormatError::UnexpectedEOR, 2, None),
+        (&[0x7D, 0x80, 0x00], StreamFormatError::InvalidData, 1, None),
+        (&[0x7D, 0x40, 0x40, 0x1D], StreamFormatError::UnexpectedEOR, 3, Some(0x1D)),
+        (&[0x7D, 0x40, 0x40, 0x1D, 0x02], StreamFormatError::InvalidData, 3, Some(0x1D)),
+        (
+            &[0x7D, 0x40, 0x40, 0xC1, 0xC2, 0x11, 0x40],
+            StreamFormatError::UnexpectedEOR,
+            5,
+            Some(0x11),
+        ),
+        (&[0x7D, 0x40, 0x40, 0x11, 0x80, 0x00], StreamFormatError::InvalidData, 3, Some(0x11)),
+        (
+            &[0x7D, 0x40, 0x40, 0x29, 0x02, 0x42, 0xF2],
+            StreamFormatError::UnexpectedEOR,
+            3,
+            Some(0x29),
+        ),
+        (
+            &[0x7D, 0x40, 0x40, 0x29, 0x01, 0x42, 0x99],
+            StreamFormatError::InvalidData,
+            3,
+            Some(0x29),
+        ),
+        (&[0x7D, 0x40, 0x40, 0x2C], StreamFormatError::UnexpectedEOR, 3, Some(0x2C)),
+        (&[0x7D, 0x40, 0x40, 0x28, 0x00, 0x01], StreamFormatError::InvalidData, 3, Some(0x28)),
+        (
+            &[0x7D, 0x40, 0x40, 0x11, 0x80, 0x00, 0x13],
+            StreamFormatError::InvalidData,
+            3,
+            Some(0x11),
+        ),
+        (&[0x7D, 0x40, 0x40, 0x3C, 0x40, 0x40], StreamFormatError::UnexpectedEOR, 3, Some(0x3C)),
+        (
+            &[0x7D, 0x40, 0x40, 0x3C, 0x40, 0x40, 0x08],
+            StreamFormatError::UnexpectedEOR,
+            3,
+            Some(0x3C),
+        ),
+        (&[0x7D, 0x40, 0x40, 0x12, 0xC1], StreamFormatError::UnexpectedEOR, 3, Some(0x12)),
+        (&[0x7D, 0x40, 0x40, 0xC1, 0x08], StreamFormatError::UnexpectedEOR, 4, Some(0x08)),
+        (&[0x7D, 0x40, 0x40, 0xC1, 0x01], StreamFormatError::InvalidData, 4, Some(0x01)),
+        (&[0x88, 0x00], StreamFormatError::UnexpectedEOR, 1, None),
+        (&[0x88, 0x00, 0x05, 0x81, 0x86, 0x00], StreamFormatError::UnexpectedEOR, 1, Some(0x81)),
+        (&[0x88, 0x00, 0x04, 0x81, 0x80, 0x00, 0x02], StreamFormatError::InvalidData, 5, None),
+        (&[0x88, 0x00, 0x0B, 0x81, 0x81, 0x01], StreamFormatError::UnexpectedEOR, 1, Some(0x81)),
+    ];
+
+    #[test]
+    fn test_malformed_records() {
+        for (record, error, offset, order) in MALFORMED {
+            let result = IncomingRecord::parse_record(record).unwrap_err();
+            assert_eq!(result.kind(), error, "{:02x?}", record);
+            assert_eq!(
+                (result.offset(), result.order()),
+                (Some(*offset), *order),
+                "{:02x?}",
+                record
+            );
+
+            // The view reports the same error, either for the header or for the first bad order
+            let view = RecordView::parse(record).and_then(|view| {
+                view.query_replies()?;
+                view.orders().try_for_each(|order| order.map(drop))
+            });
+            assert_eq!(view.unwrap_err(), result, "{:02x?}", record);
+        }
+
+        let error = IncomingRecord::parse_record(&[0x7D, 0x40, 0x40, 0x3C, 0x40]).unwrap_err();
+        assert_eq!(error.to_string(), "Record ended early at offset 3 in order 3c");
+    }
+
+    #[test]
+    fn test_record_view() {
+        let record = [
+            0x7D, 0xC1, 0xD5, // Enter and cursor
+            0x11, 0x40, 0xC4, 0xC8, 0x89, // SBA, "Hi"
+            0x29, 0x01, 0x42, 0xF2, // SFE with red
+            0x3C, 0x40, 0x50, 0x08, 0xAD, // RA with a GE
+            0x01, // Invalid
+            0xC1,
+        ];
+        let view = RecordView::parse(&record).unwrap();
+        assert_eq!((view.aid, view.addr, view.short_read), (AID::Enter, 85, false));
+
+        let mut orders = view.orders();
+        assert!(matches!(orders.next(), Some(Ok(OrderView::SetBufferAddress(4)))));
+        match orders.next() {
+            Some(Ok(OrderView::Text(text))) => {
+                // Borrowed from the record and only decoded when asked for
+                assert_eq!(text.as_bytes().as_ptr(), record[6..].as_ptr());
+                assert_eq!(text.to_string(), "Hi");
+                assert!(text.chars().eq("Hi".chars()));
+            }
+            other => panic!("{:?}", other),
+        }
+        match orders.next() {
+            Some(Ok(OrderView::StartFieldExtended(attrs))) => {
+                assert_eq!((attrs.len(), attrs.as_bytes()), (1, &[0x42, 0xF2][..]));
+                assert!(matches!(
+                    attrs.iter().collect::<Vec<_>>()[..],
+                    [ExtendedFieldAttribute::ForegroundColor(Color::Red)]
+                ));
+            }
+            other => panic!("{:?}", other),
+        }
+        assert!(matches!(orders.next(), Some(Ok(OrderView::RepeatGraphicEscape(16, 0xAD)))));
+        let error = orders.next().unwrap().unwrap_err();
+        assert_eq!((error.offset(), error.order()), (Some(17), Some(0x01)));
+        // Nothing after an error
+        assert!(orders.next().is_none());
+
+        // The owned record is built from the view
+        let owned = IncomingRecord::parse_record(&record[..17]).unwrap();
+        assert_eq!(owned.orders.len(), 4);
+        assert!(matches!(&owned.orders[1], WriteOrder::SendText(text) if text == "Hi"));
+
+        let record = [AID::PA2.into()];
+        let short = RecordView::parse(&record).unwrap();
+        assert!(short.short_read);
+        assert!(short.orders().next().is_none());
+    }
+
+    #[test]
+    fn test_euro_sign() {
+        let euro = StreamOptions { encoding: Encoding::CP1140, ..StreamOptions::default() };
+        let mut output = vec![];
+        WriteOrder::SendText("5 €".into()).serialize_with(&mut output, euro);
+        WriteOrder::RepeatToAddress(80, '€').serialize_with(&mut output, euro);
+        assert_eq!(output, [0xF5, 0x40, 0x9F, 0x3C, 0x00, 0x50, 0x9F]);
+
+        // The same bytes are the currency sign in CP037, and CP037 has no euro sign
+        let record = [&[0x7D, 0x40, 0x40][..], &output].concat();
+        let parsed = IncomingRecord::parse_record_with(&record, euro).unwrap();
+        assert!(matches!(&parsed.orders[0], WriteOrder::SendText(text) if text == "5 €"));
+        assert!(matches!(parsed.orders[1], WriteOrder::RepeatToAddress(80, '€')));
+        let parsed = IncomingRecord::parse_record(&record).unwrap();
+        assert!(matches!(&parsed.orders[0], WriteOrder::SendText(text) if text == "5 ¤"));
+        let mut output = vec![];
+        WriteOrder::SendText("5 €".into()).serialize_with(&mut output, StreamOptions::default());
+        assert_eq!(output, [0xF5, 0x40, 0x40]);
+    }
+
+    #[test]
+    fn test_dbcs_text() {
+        let options = StreamOptions { encoding: Encoding::CP939, ..StreamOptions::default() };
+        let dbcs = ExtendedFieldAttribute::CharacterSet(DBCS_CHARACTER_SET);
+        let command = WriteCommand {
+            command: WriteCommandCode::EraseWrite,
+            wcc: WCC::empty(),
+            orders: vec![
+                WriteOrder::StartFieldExtended(vec![dbcs.clone()]),
+                WriteOrder::SendText("日本".into()),
+                WriteOrder::StartField(FieldAttribute::PROTECTED),
+                WriteOrder::SendText("A日B".into()),
+                WriteOrder::SetAttribute(dbcs),
+                WriteOrder::SendText("本".into()),
+                WriteOrder::SetAttribute(ExtendedFieldAttribute::CharacterSet(0x00)),
+                WriteOrder::SendText("本".into()),
+            ],
+        };
+        let mut output = vec![];
+        command.serialize_with(&mut output, options);
+        #[rustfmt::skip]
+        assert_eq!(output, [
+            0xF5, 0x40,
+            0x29, 0x01, 0x43, 0xF8, 0x45, 0x62, 0x45, 0x66, // Double byte only in DBCS fields
+            0x1D, 0x20, 0xC1, SO, 0x45, 0x62, SI, 0xC2, // Shifted in the others
+            0x28, 0x43, 0xF8, 0x45, 0x66,
+            0x28, 0x43, 0x00, SO, 0x45, 0x66, SI,
+        ]);
+
+        let parsed = WriteCommand::parse_with(&output, options).unwrap();
+        let texts: Vec<_> = parsed
+            .orders
+            .iter()
+            .filter_map(|order| match order {
+                WriteOrder::SendText(text) => Some(text.as_str()),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(texts, ["日本", "A日B", "本", "本"]);
+
+        // Read Buffer replies have the attributes, Read Modified ones only SO and SI
+        let reply = IncomingRecord {
+            aid: AID::Enter,
+            addr: 0,
+            orders: command.orders,
+            short_read: false,
+            query_replies: vec![],
+        };
+        let mut record = vec![];
+        reply.serialize_with(&mut record, options);
+        assert_eq!(record[3..11], output[2..10]);
+        let parsed = IncomingRecord::parse_record_with(&record, options).unwrap();
+        assert!(matches!(&parsed.orders[1], WriteOrder::SendText(text) if text == "日本"));
+
+        let record = [0x7D, 0x40, 0x40, 0x11, 0x40, 0x4A, 0xC1, SO, 0x45, 0x62, SI, 0xC2];
+        let view = RecordView::parse_with(&record, options).unwrap();
+        match view.orders().nth(1) {
+            Some(Ok(OrderView::Text(text))) => {
+                // SO and SI take buffer positions, but aren't characters
+                assert_eq!(text.len(), 6);
+                assert_eq!(text.to_string(), "A日B");
+            }
+            other => panic!("{:?}", other),
+        }
+        // Single byte code pages don't shift
+        let error = IncomingRecord::parse_record(&record).unwrap_err();
+        assert_eq!((error.kind(), error.offset()), (&StreamFormatError::InvalidData, Some(7)));
+    }
+
+    #[test]
+    fn test_long_text() {
+        // Longer than the buffer text goes through on its way to the writer
+        for (encoding, text) in [
+            (Encoding::CP037, "Ünïcode text ".repeat(20)),
+            (Encoding::CP939, "日本 AB ".repeat(30)),
+        ] {
+            let options = StreamOptions { encoding, ..StreamOptions::default() };
+            let mut output = vec![];
+            WriteOrder::SendText(text.clone()).write_with(&mut output, options).unwrap();
+            let mut expected = vec![];
+            encode_into(&text, encoding, &mut expected);
+            assert_eq!(output, expected);
+            let mut rest = &mut [0; 100][..];
+            let error = WriteOrder::SendText(text).write_with(&mut rest, options).unwrap_err();
+            assert_eq!(error.kind(), ErrorKind::WriteZero);
+        }
+    }
+
+    #[test]
+    fn test_formerly_panicking_records() {
+        let record =
+            IncomingRecord::parse_record(&[0x7D, 0x40, 0x40, 0x3C, 0x40, 0x50, 0xC1, 0x08, 0xAD])
+                .unwrap();
+        assert!(matches!(
+            record.orders[..],
+            [WriteOrder::RepeatToAddress(16, 'A'), WriteOrder::GraphicEscape(0xAD)]
+        ));
+
+        let record =
+            IncomingRecord::parse_record(&[0x7D, 0x40, 0x40, 0x3C, 0x40, 0x50, 0x08, 0xC2, 0x05])
+                .unwrap();
+        assert!(matches!(
+            record.orders[..],
+            [WriteOrder::RepeatGraphicEscape(16, 0xC2), WriteOrder::ProgramTab]
+        ));
+    }
+
+    #[test]
+    fn test_no_panics() {
+        // Every two byte tail after a header, including all order codes with truncated operands
+        for header in [&[0x7D, 0x40, 0x40][..], &[0x88], &[0xF5, 0xC3]] {
+            for tail in 0..=u16::MAX {
+                let record = [header, &tail.to_be_bytes()].concat();
+                let _ = IncomingRecord::parse_record(&record);
+                let _ = IncomingRecord::parse_record_with(
+                    &record,
+                    StreamOptions { mode: AddressMode::SixteenBit, ..StreamOptions::default() },
+                );
+                let _ = BufferSnapshot::parse(&record);
+                let _ = WriteCommand::parse(&record);
+            }
+        }
+
+        // Pseudo-random records of every length
+        let mut state = 0x2545_F491_4F6C_DD1Du64;
+        for _ in 0..20000 {
+            let len = (state % 40) as usize;
+            let record: Vec<u8> = (0..len)
+                .map(|_| {
+                    state ^= state << 13;
+                    state ^= state >> 7;
+                    state ^= state << 17;
+                    // Mostly order codes and attribute values to get past the first byte or two
+                    match state % 4 {
+                        0 => [0x05, 0x08, 0x11, 0x12, 0x13, 0x1D, 0x28, 0x29, 0x2C, 0x3C]
+                            [(state >> 8) as usize % 10],
+                        1 => [0x7D, 0x88, 0x81, 0x00, 0x40, 0x42, 0xC0, 0xF2]
+                            [(state >> 8) as usize % 8],
+                        _ => (state >> 8) as u8,
+                    }
+                })
+                .collect();
+            for start in 0..record.len() {
+                let _ = IncomingRecord::parse_record(&record[start..]);
+                if let Ok(view) = RecordView::parse(&record[start..]) {
+                    view.orders().for_each(drop);
+                }
+                let _ = BufferSnapshot::parse(&record[start..]);
+                let _ = WriteCommand::parse(&record[start..]);
+            }
+        }
+    }
+}
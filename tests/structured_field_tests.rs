@@ -0,0 +1,182 @@
+#[cfg(test)]
+mod tests {
+    use rust3270::encoding::Encoding;
+    use rust3270::server::query::QueryCode;
+    use rust3270::server::stream::{
+        AddressMode, OutputRecord, StreamOptions, WriteCommand, WriteCommandCode, WriteOrder,
+    };
+    use rust3270::server::structured_field::{
+        ALL_PARTITIONS, CreatePartition, IMPLICIT_PARTITION, LoadProgrammedSymbols,
+        QueryListRequest, ReadPartitionType, ReplyMode, StructuredField, WriteStructuredField,
+    };
+    use rust3270::server::wcc::WCC;
+
+    fn serialize(field: StructuredField) -> Vec<u8> {
+        let mut out = vec![];
+        field.serialize(&mut out);
+        out
+    }
+
+    #[test]
+    fn test_read_partition() {
+        assert_eq!(
+            serialize(StructuredField::ReadPartition {
+                partition: ALL_PARTITIONS,
+                kind: ReadPartitionType::QueryList {
+                    request: QueryListRequest::EquivalentAndList,
+                    codes: vec![QueryCode::Color, QueryCode::Highlight],
+                },
+            }),
+            [0x00, 0x08, 0x01, 0xFF, 0x03, 0x40, 0x86, 0x87]
+        );
+        assert_eq!(
+            serialize(StructuredField::ReadPartition {
+                partition: IMPLICIT_PARTITION,
+                kind: ReadPartitionType::ReadModifiedAll,
+            }),
+            [0x00, 0x05, 0x01, 0x00, 0x6E]
+        );
+    }
+
+    #[test]
+    fn test_simple_fields() {
+        assert_eq!(
+            serialize(StructuredField::EraseReset { alternate: true }),
+            [0x00, 0x04, 0x03, 0x80]
+        );
+        assert_eq!(
+            serialize(StructuredField::SetReplyMode {
+                partition: IMPLICIT_PARTITION,
+                mode: ReplyMode::Character(vec![0x41, 0x42]),
+            }),
+            [0x00, 0x07, 0x09, 0x00, 0x02, 0x41, 0x42]
+        );
+        assert_eq!(
+            serialize(StructuredField::DestroyPartition { partition: 1 }),
+            [0x00, 0x04, 0x0D, 0x01]
+        );
+    }
+
+    #[test]
+    fn test_outbound_3270ds() {
+        assert_eq!(
+            serialize(StructuredField::Outbound3270DS {
+                partition: IMPLICIT_PARTITION,
+                command: WriteCommand {
+                    command: WriteCommandCode::EraseWrite,
+                    wcc: WCC::RESET_MDT | WCC::KBD_RESTORE,
+                    orders: vec![WriteOrder::SetBufferAddress(80), WriteOrder::ProgramTab],
+                },
+            }),
+            [0x00, 0x0A, 0x40, 0x00, 0xF5, 0xC3, 0x11, 0x00, 0x50, 0x05]
+        );
+        // Erase All Unprotected has no WCC
+        assert_eq!(
+            serialize(StructuredField::Outbound3270DS {
+                partition: 2,
+                command: WriteCommand {
+                    command: WriteCommandCode::EraseAllUnprotected,
+                    wcc: WCC::RESET,
+                    orders: vec![],
+                },
+            }),
+            [0x00, 0x05, 0x40, 0x02, 0x6F]
+        );
+
+        // The write command uses the address mode and code page of the session
+        let record = WriteStructuredField {
+            fields: vec![StructuredField::Outbound3270DS {
+                partition: IMPLICIT_PARTITION,
+                command: WriteCommand {
+                    command: WriteCommandCode::Write,
+                    wcc: WCC::empty(),
+                    orders: vec![
+                        WriteOrder::SetBufferAddress(80),
+                        WriteOrder::SendText("[]".into()),
+                    ],
+                },
+            }],
+        };
+        let options = StreamOptions { mode: AddressMode::TwelveBit, encoding: Encoding::CP500 };
+        let mut output = vec![];
+        record.serialize_with(&mut output, options);
+        assert_eq!(
+            output,
+            [0xF3, 0x00, 0x0B, 0x40, 0x00, 0xF1, 0x40, 0x11, 0xC1, 0x50, 0x4A, 0x5A]
+        );
+    }
+
+    #[test]
+    fn test_create_partition() {
+        let field = serialize(StructuredField::CreatePartition(CreatePartition {
+            partition: 1,
+            unit: 0,
+            flags: 0,
+            height: 12,
+            width: 80,
+            viewport_row: 12,
+            viewport_col: 0,
+            viewport_height: 12,
+            viewport_width: 80,
+            window_row: 0,
+            window_col: 0,
+            scroll_rows: 1,
+            cell_width: 9,
+            cell_height: 12,
+        }));
+        assert_eq!(field.len(), 30);
+        assert_eq!(&field[..6], [0x00, 0x1E, 0x0C, 0x01, 0x00, 0x00]);
+        assert_eq!(&field[6..10], [0x00, 0x0C, 0x00, 0x50]);
+        assert_eq!(&field[22..], [0x00, 0x01, 0x00, 0x00, 0x00, 0x09, 0x00, 0x0C]);
+    }
+
+    #[test]
+    fn test_load_programmed_symbols() {
+        let symbols = LoadProgrammedSymbols {
+            flags: 0x00,
+            lcid: 0xC1,
+            first_char: 0x41,
+            extensions: Some(vec![0x00, 0x09, 0x0C]),
+            data: vec![0xAA, 0x55],
+        };
+        assert_eq!(
+            serialize(StructuredField::LoadProgrammedSymbols(symbols)),
+            [0x00, 0x0C, 0x06, 0x80, 0xC1, 0x41, 0x04, 0x00, 0x09, 0x0C, 0xAA, 0x55]
+        );
+    }
+
+    #[test]
+    fn test_write_structured_field() {
+        let record = WriteStructuredField {
+            fields: vec![
+                StructuredField::EraseReset { alternate: false },
+                StructuredField::ReadPartition {
+                    partition: ALL_PARTITIONS,
+                    kind: ReadPartitionType::Query,
+                },
+            ],
+        };
+        assert_eq!(
+            Vec::<u8>::from(&record),
+            [0xF3, 0x00, 0x04, 0x03, 0x00, 0x00, 0x05, 0x01, 0xFF, 0x02]
+        );
+        let mut written = vec![];
+        record.write_to(&mut written, StreamOptions::default()).unwrap();
+        assert_eq!(written, Vec::<u8>::from(&record));
+
+        // Only answered when a field reads or a write restores the keyboard
+        assert!(record.expects_response());
+        let erase =
+            WriteStructuredField { fields: vec![StructuredField::EraseReset { alternate: true }] };
+        assert!(!erase.expects_response());
+        assert!(erase.parse_response(&[], StreamOptions::default()).unwrap().is_none());
+        let write = |wcc| WriteStructuredField {
+            fields: vec![StructuredField::Outbound3270DS {
+                partition: IMPLICIT_PARTITION,
+                command: WriteCommand { command: WriteCommandCode::Write, wcc, orders: vec![] },
+            }],
+        };
+        assert!(!write(WCC::empty()).expects_response());
+        assert!(write(WCC::KBD_RESTORE).expects_response());
+    }
+}
@@ -0,0 +1,144 @@
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Read, Write};
+    use std::thread;
+
+    use rust3270::server::query::{QueryReply, UsableArea};
+    use rust3270::server::screen::{Field, Screen};
+    use rust3270::server::stream::AddressMode;
+    use rust3270::server::terminal::{ScreenSize, TerminalKind, TerminalModel};
+
+    use crate::common::{EOR, IAC, connect};
+
+    #[test]
+    fn test_parse_models() {
+        let model = TerminalModel::parse("IBM-3278-2-E").unwrap();
+        assert_eq!(model.kind, TerminalKind::Ibm3278);
+        assert_eq!(model.model, Some(2));
+        assert_eq!(model.alternate, ScreenSize { rows: 24, cols: 80 });
+        assert!(model.extended && !model.color && !model.uses_alternate());
+
+        let model = TerminalModel::parse("IBM-3279-4-E").unwrap();
+        assert_eq!(model.kind, TerminalKind::Ibm3279);
+        assert_eq!(model.primary, ScreenSize::DEFAULT);
+        assert_eq!(model.alternate, ScreenSize { rows: 43, cols: 80 });
+        assert!(model.extended && model.color && model.uses_alternate());
+
+        let model = TerminalModel::parse("ibm-3278-5").unwrap();
+        assert_eq!(model.screen_size(), ScreenSize { rows: 27, cols: 132 });
+        assert!(!model.extended);
+
+        let model = TerminalModel::parse("IBM-3279-3").unwrap();
+        assert_eq!(model.screen_size(), ScreenSize { rows: 32, cols: 80 });
+        assert!(model.color && !model.extended);
+
+        let model = TerminalModel::parse("IBM-DYNAMIC").unwrap();
+        assert_eq!(model.kind, TerminalKind::Dynamic);
+        assert_eq!(model.screen_size(), ScreenSize::DEFAULT);
+        assert!(model.extended);
+
+        for term_type in ["IBM-3278-6", "IBM-3287-1", "IBM-3278", "VT100", "IBM-3279-X-E"] {
+            assert_eq!(TerminalModel::parse(term_type), None, "{}", term_type);
+        }
+    }
+
+    #[test]
+    fn test_session_model() {
+        let (_client, session) = connect("IBM-3279-4-E");
+        assert_eq!(session.terminal_type().as_deref(), Some("IBM-3279-4-E"));
+        assert_eq!(session.terminal_model().screen_size(), ScreenSize { rows: 43, cols: 80 });
+
+        // Unknown models are addressed like a 3278-2
+        let (_client, session) = connect("IBM-3278-9");
+        assert_eq!(*session.terminal_model(), TerminalModel::default());
+    }
+
+    #[test]
+    fn test_address_mode() {
+        assert_eq!(TerminalModel::default().address_mode(), AddressMode::TwelveBit);
+        assert_eq!(
+            TerminalModel::parse("IBM-3278-5").unwrap().address_mode(),
+            AddressMode::TwelveBit
+        );
+
+        let area = |width, height, flags1| {
+            QueryReply::UsableArea(UsableArea {
+                flags1,
+                flags2: 0,
+                width,
+                height,
+                units: 1,
+                x_resolution: 0,
+                y_resolution: 0,
+                cell_width: 9,
+                cell_height: 12,
+                buffer_size: width * height,
+            })
+        };
+        let mut model = TerminalModel::parse("IBM-DYNAMIC").unwrap();
+        model.apply_query_replies(&[area(160, 62, 0x01)]);
+        assert_eq!(model.address_mode(), AddressMode::FourteenBit);
+        // Too large for 14 bits, but the terminal can't do better
+        model.apply_query_replies(&[area(240, 80, 0x01)]);
+        assert_eq!(model.address_mode(), AddressMode::FourteenBit);
+        model.apply_query_replies(&[area(240, 80, 0x03)]);
+        assert_eq!(model.address_mode(), AddressMode::SixteenBit);
+        model.apply_query_replies(&[area(80, 24, 0x03)]);
+        assert_eq!(model.address_mode(), AddressMode::TwelveBit);
+    }
+
+    #[test]
+    fn test_present_on_alternate_screen() {
+        let (mut client, mut session) = connect("IBM-3278-5-E");
+
+        let terminal = thread::spawn(move || {
+            let mut buf = [0; 512];
+            let len = client.read(&mut buf).unwrap();
+            // EraseWriteAlternate, then SBA to row 2, column 1 of a 132 column screen, which is
+            // small enough for 12-bit addresses
+            assert_eq!(&buf[5..10], [0x7E, 0xC3, 0x11, 0xC2, 0xC4]);
+            assert_eq!(&buf[len - 2..len], [IAC, EOR]);
+            // Enter with "ABC" typed into the field at 2,10
+            client
+                .write_all(&[0, 0, 0, 0, 0, 0x7D, 0x40, 0xCC, 0x11, 0x00, 0x8E, 0xC1, 0xC2, 0xC3])
+                .unwrap();
+            client.write_all(&[IAC, EOR]).unwrap();
+            client
+        });
+
+        let mut name = String::new();
+        let mut screen = Screen {
+            fields: vec![Field::at(2, 1).ro_text("Name:"), Field::at(2, 10).rw_text(&mut name)],
+        };
+        screen.present(&mut session).unwrap();
+        drop(screen);
+        terminal.join().unwrap();
+        assert_eq!(name, "ABC");
+    }
+
+    #[test]
+    fn test_present_field_wrapping_to_start() {
+        let (mut client, mut session) = connect("IBM-3278-2-E");
+
+        let terminal = thread::spawn(move || {
+            let mut buf = [0; 512];
+            let len = client.read(&mut buf).unwrap();
+            assert_eq!(&buf[len - 2..len], [IAC, EOR]);
+            // Enter with "ABC" typed at address 0, in the field whose attribute is at 24,80
+            client
+                .write_all(&[0, 0, 0, 0, 0, 0x7D, 0x40, 0xC3, 0x11, 0x40, 0x40, 0xC1, 0xC2, 0xC3])
+                .unwrap();
+            client.write_all(&[IAC, EOR]).unwrap();
+            client
+        });
+
+        let mut name = String::new();
+        let mut screen = Screen { fields: vec![Field::at(24, 80).rw_text(&mut name)] };
+        screen.present(&mut session).unwrap();
+        drop(screen);
+        terminal.join().unwrap();
+        assert_eq!(name, "ABC");
+    }
+}
@@ -0,0 +1,206 @@
+#![cfg(feature = "tls")]
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Read, Write};
+    use std::net::{TcpListener, TcpStream};
+    use std::path::PathBuf;
+    use std::sync::Arc;
+    use std::thread;
+    use std::time::{Duration, Instant};
+
+    #[cfg(feature = "async")]
+    use rust3270::server::async_session::AsyncSession;
+    use rust3270::server::tls::{TlsConfig, TlsError};
+    use rust3270::server::{Session, SessionConfig, SessionError};
+    use rustls::pki_types::{CertificateDer, ServerName};
+    use rustls::{ClientConfig, ClientConnection, RootCertStore, StreamOwned};
+
+    const IAC: u8 = 255;
+    const SB: u8 = 250;
+    const SE: u8 = 240;
+    const WILL: u8 = 251;
+    const DO: u8 = 253;
+    const EOR: u8 = 239;
+    const START_TLS: u8 = 46;
+    const FOLLOWS: u8 = 1;
+
+    fn write_certificate(name: &str) -> (PathBuf, PathBuf, CertificateDer<'static>) {
+        let cert = rcgen::generate_simple_self_signed(vec!["localhost".into()]).unwrap();
+        let dir = std::env::temp_dir().join(format!("rust3270-{}-{}", name, std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let cert_path = dir.join("cert.pem");
+        let key_path = dir.join("key.pem");
+        std::fs::write(&cert_path, cert.cert.pem()).unwrap();
+        std::fs::write(&key_path, cert.signing_key.serialize_pem()).unwrap();
+        (cert_path, key_path, cert.cert.der().clone())
+    }
+
+    fn client_connection(cert: CertificateDer<'static>) -> ClientConnection {
+        let mut roots = RootCertStore::empty();
+        roots.add(cert).unwrap();
+        let config =
+            ClientConfig::builder_with_provider(Arc::new(rustls::crypto::ring::default_provider()))
+                .with_safe_default_protocol_versions()
+                .unwrap()
+                .with_root_certificates(roots)
+                .with_no_client_auth();
+        ClientConnection::new(Arc::new(config), ServerName::try_from("localhost").unwrap()).unwrap()
+    }
+
+    fn tls_client(
+        addr: std::net::SocketAddr,
+        cert: CertificateDer<'static>,
+    ) -> StreamOwned<ClientConnection, TcpStream> {
+        StreamOwned::new(client_connection(cert), TcpStream::connect(addr).unwrap())
+    }
+
+    fn negotiate_tn3270(client: &mut impl Write) {
+        client.write_all(&[IAC, WILL, 24, IAC, SB, 24, 0]).unwrap();
+        client.write_all(b"IBM-3278-2").unwrap();
+        client.write_all(&[IAC, SE, IAC, WILL, 25, IAC, DO, 25, IAC, WILL, 0, IAC, DO, 0]).unwrap();
+        client.flush().unwrap();
+    }
+
+    fn expect_record(client: &mut impl Read, record: &[u8]) {
+        // Skip the remaining negotiation replies up to the record
+        let mut received = vec![];
+        let mut buf = [0; 256];
+        while !received.ends_with(record) {
+            let len = client.read(&mut buf).unwrap();
+            assert_ne!(len, 0);
+            received.extend_from_slice(&buf[..len]);
+        }
+    }
+
+    fn expect(client: &mut impl Read, expected: &[u8]) {
+        let mut buf = vec![0; expected.len()];
+        client.read_exact(&mut buf).unwrap();
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn test_missing_files() {
+        assert!(matches!(
+            TlsConfig::from_pem_files("/nonexistent/cert.pem", "/nonexistent/key.pem"),
+            Err(TlsError::Pem { .. })
+        ));
+    }
+
+    #[test]
+    fn test_implicit_tls_session() {
+        let (cert_path, key_path, cert) = write_certificate("implicit");
+        let tls = TlsConfig::from_pem_files(&cert_path, &key_path).unwrap();
+
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let server = thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let config = SessionConfig { tn3270e: false, ..SessionConfig::default() };
+            Session::with_config_tls(stream, &tls, config).unwrap()
+        });
+
+        let mut client = tls_client(addr, cert);
+        expect(&mut client, &[IAC, DO, 24, IAC, WILL, 24]);
+        negotiate_tn3270(&mut client);
+
+        let mut session = server.join().unwrap();
+        session.send_record(vec![0xF5, 0xC3]).unwrap();
+        expect_record(&mut client, &[0xF5, 0xC3, IAC, EOR]);
+
+        client.write_all(&[0x7D, 0x40, 0x40, IAC, EOR]).unwrap();
+        client.flush().unwrap();
+        assert_eq!(session.receive_record(None).unwrap(), Some(vec![0x7D, 0x40, 0x40]));
+
+        std::fs::remove_dir_all(cert_path.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn test_starttls_upgrade() {
+        let (cert_path, key_path, cert) = write_certificate("starttls");
+        let tls = TlsConfig::from_pem_files(&cert_path, &key_path).unwrap();
+
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let server = thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let config =
+                SessionConfig { tn3270e: false, starttls: Some(tls), ..SessionConfig::default() };
+            Session::with_config(stream, config).unwrap()
+        });
+
+        let mut socket = TcpStream::connect(addr).unwrap();
+        expect(&mut socket, &[IAC, DO, START_TLS]);
+        socket.write_all(&[IAC, WILL, START_TLS]).unwrap();
+        expect(&mut socket, &[IAC, DO, START_TLS, IAC, SB, START_TLS, FOLLOWS, IAC, SE]);
+
+        // Send the ClientHello in the same segment as FOLLOWS
+        let mut conn = client_connection(cert);
+        let mut hello = vec![IAC, SB, START_TLS, FOLLOWS, IAC, SE];
+        conn.write_tls(&mut hello).unwrap();
+        socket.write_all(&hello).unwrap();
+
+        let mut client = StreamOwned::new(conn, socket);
+        expect(&mut client, &[IAC, DO, 24, IAC, WILL, 24]);
+        negotiate_tn3270(&mut client);
+
+        let mut session = server.join().unwrap();
+        session.send_record(vec![0xF5, 0xC3]).unwrap();
+        expect_record(&mut client, &[0xF5, 0xC3, IAC, EOR]);
+
+        client.write_all(&[0x7D, 0x40, 0x40, IAC, EOR]).unwrap();
+        client.flush().unwrap();
+        assert_eq!(session.receive_record(None).unwrap(), Some(vec![0x7D, 0x40, 0x40]));
+
+        std::fs::remove_dir_all(cert_path.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn test_starttls_handshake_timeout() {
+        let (cert_path, key_path, _) = write_certificate("handshake-timeout");
+        let tls = TlsConfig::from_pem_files(&cert_path, &key_path).unwrap();
+        std::fs::remove_dir_all(cert_path.parent().unwrap()).unwrap();
+
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let server = thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let config = SessionConfig {
+                tn3270e: false,
+                starttls: Some(tls),
+                negotiation_timeout: Duration::from_millis(300),
+                ..SessionConfig::default()
+            };
+            let start = Instant::now();
+            (Session::with_config(stream, config).err(), start.elapsed())
+        });
+
+        // Agree to STARTTLS, then never send the ClientHello
+        let mut socket = TcpStream::connect(addr).unwrap();
+        expect(&mut socket, &[IAC, DO, START_TLS]);
+        socket.write_all(&[IAC, WILL, START_TLS]).unwrap();
+        expect(&mut socket, &[IAC, DO, START_TLS, IAC, SB, START_TLS, FOLLOWS, IAC, SE]);
+        socket.write_all(&[IAC, SB, START_TLS, FOLLOWS, IAC, SE]).unwrap();
+
+        let (error, elapsed) = server.join().unwrap();
+        assert!(matches!(error, Some(SessionError::NegotiationTimeout)), "{:?}", error);
+        assert!(elapsed < Duration::from_secs(2), "{:?}", elapsed);
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn test_async_session_rejects_starttls() {
+        let (cert_path, key_path, _) = write_certificate("async");
+        let tls = TlsConfig::from_pem_files(&cert_path, &key_path).unwrap();
+        std::fs::remove_dir_all(cert_path.parent().unwrap()).unwrap();
+
+        // Nothing is offered to the client
+        let (mut client, server) = tokio::io::duplex(64);
+        let config = SessionConfig { starttls: Some(tls), ..SessionConfig::default() };
+        let result = AsyncSession::with_config(server, config).await;
+        assert!(matches!(result, Err(SessionError::Unsupported { .. })));
+        let mut buf = vec![];
+        tokio::io::AsyncReadExt::read_to_end(&mut client, &mut buf).await.unwrap();
+        assert!(buf.is_empty());
+    }
+}
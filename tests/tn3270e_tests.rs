@@ -0,0 +1,129 @@
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Read, Write};
+    use std::net::{TcpListener, TcpStream};
+    use std::thread;
+
+    use rust3270::server::Session;
+    use rust3270::server::tn3270e::{
+        DeviceName, Functions, Header, Message, RejectReason, TN3270E,
+    };
+
+    use crate::common::{DO, EOR, IAC, SB, SE, WILL, expect, sub};
+
+    fn connect() -> (TcpStream, thread::JoinHandle<Session>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let server = thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            Session::new(stream).unwrap()
+        });
+        (TcpStream::connect(addr).unwrap(), server)
+    }
+
+    #[test]
+    fn test_message_round_trip() {
+        let messages = [
+            Message::SendDeviceType,
+            Message::DeviceTypeRequest {
+                device_type: "IBM-3278-2-E".into(),
+                name: Some(DeviceName::Connect("LU1".into())),
+            },
+            Message::DeviceTypeRequest {
+                device_type: "IBM-3287-1".into(),
+                name: Some(DeviceName::Associate("LU1".into())),
+            },
+            Message::DeviceTypeRequest { device_type: "IBM-DYNAMIC".into(), name: None },
+            Message::DeviceTypeIs { device_type: "IBM-3279-4-E".into(), device_name: "LU2".into() },
+            Message::DeviceTypeReject(RejectReason::InvalidDeviceType),
+            Message::FunctionsRequest(Functions::BIND_IMAGE | Functions::SYSREQ),
+            Message::FunctionsIs(Functions::empty()),
+        ];
+        for message in messages {
+            assert_eq!(Message::parse(&message.encode()), Ok(message));
+        }
+    }
+
+    #[test]
+    fn test_header_round_trip() {
+        let header = Header::data(0x1234);
+        let mut record = header.encode().to_vec();
+        record.push(0x7D);
+        let (parsed, body) = Header::parse(&record).unwrap();
+        assert_eq!(parsed, header);
+        assert_eq!(body, [0x7D]);
+    }
+
+    #[test]
+    fn test_negotiation_and_framing() {
+        let (mut client, server) = connect();
+
+        expect(&mut client, &[IAC, DO, TN3270E]);
+        client.write_all(&[IAC, WILL, TN3270E]).unwrap();
+        expect(&mut client, &[IAC, DO, TN3270E]);
+        expect(&mut client, &sub(&Message::SendDeviceType));
+
+        client
+            .write_all(&sub(&Message::DeviceTypeRequest {
+                device_type: "IBM-3278-2-E".into(),
+                name: Some(DeviceName::Connect("TERM01".into())),
+            }))
+            .unwrap();
+        expect(
+            &mut client,
+            &sub(&Message::DeviceTypeIs {
+                device_type: "IBM-3278-2-E".into(),
+                device_name: "TERM01".into(),
+            }),
+        );
+
+        client
+            .write_all(&sub(&Message::FunctionsRequest(
+                Functions::BIND_IMAGE | Functions::RESPONSES | Functions::SYSREQ,
+            )))
+            .unwrap();
+        expect(&mut client, &sub(&Message::FunctionsRequest(Functions::RESPONSES)));
+        client.write_all(&sub(&Message::FunctionsIs(Functions::RESPONSES))).unwrap();
+
+        let mut session = server.join().unwrap();
+        let device = session.tn3270e().unwrap();
+        assert_eq!(device.device_type, "IBM-3278-2-E");
+        assert_eq!(device.device_name, "TERM01");
+        assert_eq!(device.functions, Functions::RESPONSES);
+
+        session.send_record(vec![0xF5, 0xC3]).unwrap();
+        expect(&mut client, &[0, 0, 0, 0, 0, 0xF5, 0xC3, IAC, EOR]);
+        session.send_record(vec![0xF1, 0xC3]).unwrap();
+        expect(&mut client, &[0, 0, 0, 0, 1, 0xF1, 0xC3, IAC, EOR]);
+
+        client.write_all(&[0, 0, 0, 0, 0, 0x7D, 0x40, 0x40, IAC, EOR]).unwrap();
+        assert_eq!(session.receive_record(None).unwrap(), Some(vec![0x7D, 0x40, 0x40]));
+    }
+
+    #[test]
+    fn test_fallback_to_tn3270() {
+        let (mut client, server) = connect();
+
+        expect(&mut client, &[IAC, DO, TN3270E]);
+        client.write_all(&[IAC, 252, TN3270E]).unwrap();
+        expect(&mut client, &[IAC, DO, 24, IAC, WILL, 24]);
+        client.write_all(&[IAC, WILL, 24]).unwrap();
+        client.write_all(&[IAC, SB, 24, 0]).unwrap();
+        client.write_all(b"IBM-3278-2").unwrap();
+        client.write_all(&[IAC, SE, IAC, WILL, 25, IAC, DO, 25, IAC, WILL, 0, IAC, DO, 0]).unwrap();
+
+        let mut session = server.join().unwrap();
+        assert!(session.tn3270e().is_none());
+
+        // Skip the remaining negotiation replies
+        client.set_read_timeout(Some(std::time::Duration::from_millis(200))).unwrap();
+        let mut buf = [0; 256];
+        while client.read(&mut buf).is_ok_and(|len| len > 0) {}
+
+        session.send_record(vec![0xF5, 0xC3]).unwrap();
+        client.set_read_timeout(None).unwrap();
+        expect(&mut client, &[0xF5, 0xC3, IAC, EOR]);
+    }
+}
@@ -0,0 +1,119 @@
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Read, Write};
+    use std::thread;
+    use std::time::Duration;
+
+    use rust3270::server::Session;
+    use rust3270::server::aid::AID;
+    use rust3270::server::screen::{Field, Screen};
+    use rust3270::server::stream::{IncomingRecord, WriteOrder};
+    use rust3270::server::transport::{MemoryStream, Transport, duplex};
+
+    use crate::common::{EOR, IAC, expect, negotiate_client};
+
+    fn connect<T: Transport + Send + 'static>(server: T) -> thread::JoinHandle<Session<T>> {
+        thread::spawn(move || Session::new(server).unwrap())
+    }
+
+    #[test]
+    fn test_memory_stream_session() {
+        let (mut client, server) = duplex();
+        let server = connect(server);
+        negotiate_client(&mut client, "IBM-3278-2-E");
+        let mut session: Session<MemoryStream> = server.join().unwrap();
+        assert_eq!(session.tn3270e().unwrap().device_name, "TERM01");
+
+        session.send_record(vec![0xF5, 0xC3]).unwrap();
+        expect(&mut client, &[0, 0, 0, 0, 0, 0xF5, 0xC3, IAC, EOR]);
+
+        assert_eq!(session.receive_record(Some(Duration::from_millis(50))).unwrap(), None);
+
+        // A record split over several reads is reassembled
+        client.write_all(&[0, 0, 0, 0, 0, 0x7D]).unwrap();
+        client.write_all(&[0x40, 0x40, IAC, EOR]).unwrap();
+        assert_eq!(session.receive_record(None).unwrap(), Some(vec![0x7D, 0x40, 0x40]));
+
+        drop(client);
+        assert_eq!(session.receive_record(None).unwrap(), None);
+    }
+
+    #[test]
+    fn test_memory_stream_screen() {
+        let (mut client, server) = duplex();
+        let server = connect(server);
+        negotiate_client(&mut client, "IBM-3278-2-E");
+        let mut session = server.join().unwrap();
+
+        let terminal = thread::spawn(move || {
+            let mut buf = [0; 512];
+            let len = client.read(&mut buf).unwrap();
+            assert_eq!(&buf[5..7], [0xF5, 0xC3]);
+            assert_eq!(&buf[len - 2..len], [IAC, EOR]);
+            // Enter with cursor at 1,13 and "ABC" typed into the field at 1,10
+            let record = IncomingRecord {
+                aid: AID::Enter,
+                addr: 12,
+                orders: vec![WriteOrder::SetBufferAddress(10), WriteOrder::SendText("ABC".into())],
+                short_read: false,
+                query_replies: vec![],
+            };
+            client.write_all(&[0, 0, 0, 0, 0]).unwrap();
+            client.write_all(&Vec::from(&record)).unwrap();
+            client.write_all(&[IAC, EOR]).unwrap();
+            client
+        });
+
+        let mut name = String::new();
+        let mut screen = Screen {
+            fields: vec![Field::at(1, 1).ro_text("Name:"), Field::at(1, 10).rw_text(&mut name)],
+        };
+        let response = screen.present(&mut session).unwrap();
+        drop(screen);
+        terminal.join().unwrap();
+
+        assert_eq!(response.aid, AID::Enter);
+        assert_eq!((response.address.row, response.address.col), (0, 12));
+        assert_eq!(name, "ABC");
+    }
+
+    #[test]
+    fn test_memory_stream_screen_short_read() {
+        let (mut client, server) = duplex();
+        let server = connect(server);
+        negotiate_client(&mut client, "IBM-3278-2-E");
+        let mut session = server.join().unwrap();
+
+        let terminal = thread::spawn(move || {
+            let mut buf = [0; 512];
+            let _ = client.read(&mut buf).unwrap();
+            // PA1 only sends its AID
+            client.write_all(&[0, 0, 0, 0, 0, 0x6C, IAC, EOR]).unwrap();
+            client
+        });
+
+        let mut name = String::from("Old");
+        let mut screen = Screen { fields: vec![Field::at(1, 10).rw_text(&mut name)] };
+        let response = screen.present(&mut session).unwrap();
+        drop(screen);
+        terminal.join().unwrap();
+
+        assert_eq!(response.aid, AID::PA1);
+        assert!(response.short_read);
+        assert_eq!(name, "Old");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_unix_stream_session() {
+        let (mut client, server) = std::os::unix::net::UnixStream::pair().unwrap();
+        let server = connect(server);
+        negotiate_client(&mut client, "IBM-3278-2-E");
+        let mut session = server.join().unwrap();
+
+        client.write_all(&[0, 0, 0, 0, 0, 0x6D, IAC, EOR]).unwrap();
+        assert_eq!(session.receive_record(Some(Duration::from_secs(5))).unwrap(), Some(vec![0x6D]));
+    }
+}